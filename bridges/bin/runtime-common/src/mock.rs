@@ -103,7 +103,7 @@ frame_support::construct_runtime! {
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Utility: pallet_utility,
-		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>, HoldReason},
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
 		BridgeRelayers: pallet_bridge_relayers::{Pallet, Call, Storage, Event<T>},
 		BridgeGrandpa: pallet_bridge_grandpa::{Pallet, Call, Storage, Event<T>},
@@ -18,7 +18,7 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::{Bridge, BridgeState, Call};
+use crate::{Bridge, BridgeState, Call, IsSuspended};
 use frame_benchmarking::{benchmarks_instance_pallet, BenchmarkError};
 use frame_support::traits::{EnsureOrigin, Get, Hooks, UnfilteredDispatchable};
 use polkadot_runtime_parachains::FeeTracker;
@@ -77,4 +77,24 @@ benchmarks_instance_pallet! {
 	verify {
 		assert!(Bridge::<T, I>::get().is_congested);
 	}
+
+	suspend_bridge {
+		let origin: T::RuntimeOrigin = T::BridgeHubOrigin::try_successful_origin().expect("expected valid BridgeHubOrigin");
+
+		let call = Call::<T, I>::suspend_bridge {};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(IsSuspended::<T, I>::get());
+	}
+
+	resume_bridge {
+		IsSuspended::<T, I>::put(true);
+
+		let origin: T::RuntimeOrigin = T::BridgeHubOrigin::try_successful_origin().expect("expected valid BridgeHubOrigin");
+
+		let call = Call::<T, I>::resume_bridge {};
+	}: { call.dispatch_bypass_filter(origin)? }
+	verify {
+		assert!(!IsSuspended::<T, I>::get());
+	}
 }
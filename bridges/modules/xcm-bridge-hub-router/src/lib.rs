@@ -23,6 +23,14 @@
 //! of all queues is congested, it will eventually lead to the growth of the queue at
 //! this chain.
 //!
+//! The bridge fee is always quoted in the `Config::FeeAsset`. A sender that wants to pay it with
+//! another asset nominates that asset explicitly, with the `PayFees` (or `BuyExecution`)
+//! instruction of the program that it executes on this chain. The `XcmExecutor` then swaps at most
+//! the nominated amount for the `Config::FeeAsset` through its `AssetExchanger` (on the asset hubs,
+//! `SingleAssetExchangeAdapter` over `pallet_asset_conversion`) before charging the fee. What is
+//! paid for the execution at the bridged destination has no effect on the asset that is charged
+//! here.
+//!
 //! **A note on terminology**: when we mention the bridge hub here, we mean the chain that
 //! has the messages pallet deployed (`pallet-bridge-grandpa`, `pallet-bridge-messages`,
 //! `pallet-xcm-bridge-hub`, ...). It may be the system bridge hub parachain or any other
@@ -171,6 +179,35 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Suspend the bridge. All messages that are sent over the bridge are rejected until it
+		/// is resumed with [`Pallet::resume_bridge`].
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::suspend_bridge())]
+		pub fn suspend_bridge(origin: OriginFor<T>) -> DispatchResult {
+			T::BridgeHubOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_suspended(), Error::<T, I>::BridgeAlreadySuspended);
+
+			tracing::info!(target: LOG_TARGET, "Bridge is suspended");
+
+			IsSuspended::<T, I>::put(true);
+			Self::deposit_event(Event::BridgeSuspended);
+			Ok(())
+		}
+
+		/// Resume the bridge that has been suspended with [`Pallet::suspend_bridge`].
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::resume_bridge())]
+		pub fn resume_bridge(origin: OriginFor<T>) -> DispatchResult {
+			T::BridgeHubOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_suspended(), Error::<T, I>::BridgeNotSuspended);
+
+			tracing::info!(target: LOG_TARGET, "Bridge is resumed");
+
+			IsSuspended::<T, I>::kill();
+			Self::deposit_event(Event::BridgeResumed);
+			Ok(())
+		}
 	}
 
 	/// Bridge that we are using.
@@ -183,12 +220,23 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Bridge<T: Config<I>, I: 'static = ()> = StorageValue<_, BridgeState, ValueQuery>;
 
+	/// Whether the bridge has been suspended by the `BridgeHubOrigin`.
+	///
+	/// Messages are not sent over a suspended bridge.
+	#[pallet::storage]
+	pub type IsSuspended<T: Config<I>, I: 'static = ()> = StorageValue<_, bool, ValueQuery>;
+
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Bridge that we are using.
 		pub fn bridge() -> BridgeState {
 			Bridge::<T, I>::get()
 		}
 
+		/// Whether the bridge is suspended.
+		pub fn is_suspended() -> bool {
+			IsSuspended::<T, I>::get()
+		}
+
 		/// Called when new message is sent (queued to local outbound XCM queue) over the bridge.
 		pub(crate) fn on_message_sent_to_bridge(message_size: u32) {
 			tracing::trace!(
@@ -240,6 +288,18 @@ pub mod pallet {
 			/// New value of the `DeliveryFeeFactor`.
 			new_value: FixedU128,
 		},
+		/// The bridge has been suspended.
+		BridgeSuspended,
+		/// The bridge has been resumed.
+		BridgeResumed,
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The bridge is already suspended.
+		BridgeAlreadySuspended,
+		/// The bridge is not suspended.
+		BridgeNotSuspended,
 	}
 }
 
@@ -365,6 +425,12 @@ impl<T: Config<I>, I: 'static> SendXcm for Pallet<T, I> {
 			Ok((ticket, cost)) => {
 				// If the ticket is ok, it means we are routing with this router, so we need to
 				// apply more validations to the cloned `dest` and `xcm`, which are required here.
+				// The bridge has been suspended by the governance, so we don't send anything.
+				if Self::is_suspended() {
+					tracing::debug!(target: LOG_TARGET, destination=?dest_clone, "Bridge is suspended");
+					return Err(SendError::Transport("BridgeSuspended"));
+				}
+
 				let xcm_to_dest_clone = xcm_to_dest_clone.ok_or(SendError::MissingArgument)?;
 				let dest_clone = dest_clone.ok_or(SendError::MissingArgument)?;
 
@@ -445,12 +511,12 @@ impl<T: Config<I>, I: 'static> FeeTracker for Pallet<T, I> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use frame_support::assert_ok;
+	use frame_support::{assert_noop, assert_ok};
 	use mock::*;
 
 	use frame_support::traits::Hooks;
 	use frame_system::{EventRecord, Phase};
-	use sp_runtime::traits::One;
+	use sp_runtime::{traits::One, DispatchError};
 
 	fn congested_bridge(delivery_fee_factor: FixedU128) -> BridgeState {
 		BridgeState { is_congested: true, delivery_fee_factor }
@@ -648,6 +714,28 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn returns_delivery_price_in_fee_asset_whatever_pays_for_remote_execution() {
+		run_test(|| {
+			let dest =
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]);
+			// the remote execution is paid with the native asset of this chain
+			let remote_fee_asset: Asset = (
+				Location::new(2, [GlobalConsensus(ThisNetworkId::get()), Parachain(1000)]),
+				1_000_000,
+			)
+				.into();
+			let xcm: Xcm<()> = vec![PayFees { asset: remote_fee_asset }, ClearOrigin].into();
+			let msg_size = xcm.encoded_size();
+
+			let expected_fee = BASE_FEE + BYTE_FEE * (msg_size as u128) + HRMP_FEE;
+			assert_eq!(
+				XcmBridgeHubRouter::validate(&mut Some(dest), &mut Some(xcm)).unwrap().1,
+				(BridgeFeeAsset::get(), expected_fee).into(),
+			);
+		});
+	}
+
 	#[test]
 	fn sent_message_doesnt_increase_factor_if_queue_is_uncongested() {
 		run_test(|| {
@@ -734,6 +822,59 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn suspend_and_resume_bridge_works() {
+		run_test(|| {
+			let dest =
+				Location::new(2, [GlobalConsensus(BridgedNetworkId::get()), Parachain(1000)]);
+
+			// only the `BridgeHubOrigin` may suspend the bridge
+			assert_noop!(
+				XcmBridgeHubRouter::suspend_bridge(RuntimeOrigin::signed(1)),
+				DispatchError::BadOrigin,
+			);
+			assert_ok!(XcmBridgeHubRouter::suspend_bridge(RuntimeOrigin::root()));
+			assert!(XcmBridgeHubRouter::is_suspended());
+			assert_noop!(
+				XcmBridgeHubRouter::suspend_bridge(RuntimeOrigin::root()),
+				Error::<TestRuntime, ()>::BridgeAlreadySuspended,
+			);
+			System::assert_last_event(RuntimeEvent::XcmBridgeHubRouter(Event::BridgeSuspended));
+
+			// messages are not sent over the suspended bridge
+			assert_eq!(
+				send_xcm::<XcmBridgeHubRouter>(dest.clone(), vec![ClearOrigin].into()),
+				Err(SendError::Transport("BridgeSuspended")),
+			);
+			assert!(!TestToBridgeHubSender::is_message_sent());
+
+			// other destinations are still not applicable
+			assert_eq!(
+				send_xcm::<XcmBridgeHubRouter>(
+					Location::new(2, [GlobalConsensus(ByGenesis([0; 32])), Parachain(1000)]),
+					vec![ClearOrigin].into(),
+				),
+				Err(SendError::NotApplicable),
+			);
+
+			// resume the bridge
+			assert_noop!(
+				XcmBridgeHubRouter::resume_bridge(RuntimeOrigin::signed(1)),
+				DispatchError::BadOrigin,
+			);
+			assert_ok!(XcmBridgeHubRouter::resume_bridge(RuntimeOrigin::root()));
+			assert!(!XcmBridgeHubRouter::is_suspended());
+			assert_noop!(
+				XcmBridgeHubRouter::resume_bridge(RuntimeOrigin::root()),
+				Error::<TestRuntime, ()>::BridgeNotSuspended,
+			);
+			System::assert_last_event(RuntimeEvent::XcmBridgeHubRouter(Event::BridgeResumed));
+
+			assert_ok!(send_xcm::<XcmBridgeHubRouter>(dest, vec![ClearOrigin].into()));
+			assert!(TestToBridgeHubSender::is_message_sent());
+		});
+	}
+
 	#[test]
 	fn get_messages_does_not_return_anything() {
 		run_test(|| {
@@ -42,7 +42,7 @@ construct_runtime! {
 	pub enum TestRuntime
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		XcmBridgeHubRouter: pallet_xcm_bridge_hub_router::{Pallet, Storage, Event<T>},
+		XcmBridgeHubRouter: pallet_xcm_bridge_hub_router::{Pallet, Call, Storage, Event<T>},
 	}
 }
 
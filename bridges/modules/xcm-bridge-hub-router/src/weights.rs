@@ -53,6 +53,8 @@ pub trait WeightInfo {
 	fn on_initialize_when_non_congested() -> Weight;
 	fn on_initialize_when_congested() -> Weight;
 	fn report_bridge_status() -> Weight;
+	fn suspend_bridge() -> Weight;
+	fn resume_bridge() -> Weight;
 }
 
 /// Weights for `pallet_xcm_bridge_hub_router` that are generated using one of the Bridge testnets.
@@ -99,6 +101,32 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `XcmBridgeHubRouter::IsSuspended` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::IsSuspended` (`max_values`: Some(1), `max_size`: Some(1), added:
+	/// 496, mode: `MaxEncodedLen`)
+	fn suspend_bridge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1486`
+		// Minimum execution time: 9_873 nanoseconds.
+		Weight::from_parts(10_194_000, 1486)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XcmBridgeHubRouter::IsSuspended` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::IsSuspended` (`max_values`: Some(1), `max_size`: Some(1), added:
+	/// 496, mode: `MaxEncodedLen`)
+	fn resume_bridge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `77`
+		//  Estimated: `1486`
+		// Minimum execution time: 10_012 nanoseconds.
+		Weight::from_parts(10_311_000, 1486)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -147,4 +175,30 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `XcmBridgeHubRouter::IsSuspended` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::IsSuspended` (`max_values`: Some(1), `max_size`: Some(1), added:
+	/// 496, mode: `MaxEncodedLen`)
+	fn suspend_bridge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1486`
+		// Minimum execution time: 9_873 nanoseconds.
+		Weight::from_parts(10_194_000, 1486)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `XcmBridgeHubRouter::IsSuspended` (r:1 w:1)
+	///
+	/// Proof: `XcmBridgeHubRouter::IsSuspended` (`max_values`: Some(1), `max_size`: Some(1), added:
+	/// 496, mode: `MaxEncodedLen`)
+	fn resume_bridge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `77`
+		//  Estimated: `1486`
+		// Minimum execution time: 10_012 nanoseconds.
+		Weight::from_parts(10_311_000, 1486)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -60,7 +60,7 @@ pub const BRIDGED_ASSET_HUB_ID: u32 = 1001;
 frame_support::construct_runtime! {
 	pub enum TestRuntime {
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		Balances: pallet_balances::{Pallet, Event<T>},
+		Balances: pallet_balances::{Pallet, Event<T>, HoldReason},
 		Messages: pallet_bridge_messages::{Pallet, Call, Event<T>},
 		XcmOverBridge: pallet_xcm_bridge_hub::{Pallet, Call, HoldReason, Event<T>},
 		XcmOverBridgeWrappedWithExportMessageRouter: pallet_xcm_bridge_hub_router = 57,
@@ -95,7 +95,7 @@ pub fn get_message_verification_payload() -> (Log, Proof) {
 frame_support::construct_runtime!(
 	pub enum Test {
 		System: frame_system::{Pallet, Call, Storage, Event<T>},
-		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Event<T>, Inherent},
 		EthereumBeaconClient: ethereum_beacon_client::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -109,6 +109,8 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ();
+	type ExpectedMoment = ();
+	type AnomalyTolerance = frame_support::traits::ConstU64<0>;
 	type WeightInfo = ();
 }
 
@@ -4,7 +4,7 @@ use super::*;
 
 use crate::Pallet as InboundQueue;
 use frame_benchmarking::v2::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::Get};
 use frame_system::RawOrigin;
 use snowbridge_pallet_inbound_queue_v2_fixtures::register_token::make_register_token_message;
 
@@ -34,5 +34,22 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn prune_nonces(n: Linear<1, 1_000>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+
+		for bucket in 0..n as u64 {
+			InboundQueue::<T>::mark_nonce_processed(bucket * Nonce::<T>::BUCKET_SIZE);
+		}
+		let latest = (n as u64) * Nonce::<T>::BUCKET_SIZE + T::NonceReplayWindow::get();
+		InboundQueue::<T>::mark_nonce_processed(latest);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), n);
+
+		assert_eq!(OldestTrackedNonce::<T>::get(), (n as u64) * Nonce::<T>::BUCKET_SIZE);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(InboundQueue, crate::mock::new_tester(), crate::mock::Test);
 }
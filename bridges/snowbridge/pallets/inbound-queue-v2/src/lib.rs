@@ -21,12 +21,18 @@
 //!
 //! * [`Call::submit`]: Submit a message for verification and dispatch to the final destination
 //!   parachain.
+//!
+//! ## Maintenance
+//!
+//! * [`Call::prune_nonces`]: Prune the bitmaps of processed nonces which are older than the replay
+//!   window, see [`Config::NonceReplayWindow`].
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 pub mod weights;
 
 #[cfg(test)]
@@ -70,6 +76,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	#[pallet::pallet]
+	#[pallet::storage_version(migration::STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -105,6 +112,11 @@ pub mod pallet {
 		type RewardPayment: RewardLedger<Self::AccountId, Self::RewardKind, u128>;
 		/// AccountId to Location converter
 		type AccountToLocation: for<'a> TryConvert<&'a Self::AccountId, Location>;
+		/// Number of nonces below the latest processed nonce which are protected against replays
+		/// by the nonce bitmap. The bitmaps of older nonces can be pruned, after which all nonces
+		/// below [`OldestTrackedNonce`] are treated as processed.
+		#[pallet::constant]
+		type NonceReplayWindow: Get<u64>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -120,6 +132,13 @@ pub mod pallet {
 		},
 		/// Set OperatingMode
 		OperatingModeChanged { mode: BasicOperatingMode },
+		/// Nonce bitmaps outside of the replay window were pruned
+		NoncesPruned {
+			/// The number of removed bitmap buckets
+			buckets: u32,
+			/// All nonces below this nonce are treated as processed
+			oldest_tracked_nonce: u64,
+		},
 	}
 
 	#[pallet::error]
@@ -160,6 +179,8 @@ pub mod pallet {
 		CannotReanchor,
 		/// Message verification error
 		Verification(VerificationError),
+		/// There are no nonce bitmaps outside of the replay window
+		NothingToPrune,
 	}
 
 	impl<T: Config> From<SendError> for Error<T> {
@@ -187,6 +208,19 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NonceBitmap<T: Config> = StorageMap<_, Twox64Concat, u64, u128, ValueQuery>;
 
+	/// The highest nonce which has been processed.
+	#[pallet::storage]
+	pub type LatestNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The lowest nonce which is still tracked by the [`NonceBitmap`]. The bitmaps of all lower
+	/// nonces were pruned and these nonces are treated as processed.
+	#[pallet::storage]
+	pub type OldestTrackedNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The number of buckets stored in the [`NonceBitmap`].
+	#[pallet::storage]
+	pub type NonceBitmapSize<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	/// The current operating mode of the pallet.
 	#[pallet::storage]
 	pub type OperatingMode<T: Config> = StorageValue<_, BasicOperatingMode, ValueQuery>;
@@ -229,6 +263,17 @@ pub mod pallet {
 			Self::deposit_event(Event::OperatingModeChanged { mode });
 			Ok(())
 		}
+
+		/// Prune up to `max_buckets` buckets of the nonce bitmap which only track nonces outside of
+		/// the replay window. Can be called by anyone.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::prune_nonces(*max_buckets))]
+		pub fn prune_nonces(origin: OriginFor<T>, max_buckets: u32) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let pruned = Self::do_prune_nonces(max_buckets);
+			ensure!(pruned > 0, Error::<T>::NothingToPrune);
+			Ok(Some(T::WeightInfo::prune_nonces(pruned)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -239,7 +284,7 @@ pub mod pallet {
 			let (nonce, relayer_fee) = (message.nonce, message.relayer_fee);
 
 			// Verify the message has not been processed
-			ensure!(!Nonce::<T>::get(nonce), Error::<T>::InvalidNonce);
+			ensure!(!Self::is_nonce_consumed(nonce), Error::<T>::InvalidNonce);
 
 			let xcm =
 				T::MessageConverter::convert(message).map_err(|error| Error::<T>::from(error))?;
@@ -248,7 +293,7 @@ pub mod pallet {
 			let dest = Location::new(1, [Parachain(T::AssetHubParaId::get())]);
 
 			// Mark message as received
-			Nonce::<T>::set(nonce);
+			Self::mark_nonce_processed(nonce);
 
 			let message_id =
 				Self::send_xcm(dest.clone(), &relayer, xcm.clone()).map_err(|error| {
@@ -294,13 +339,69 @@ pub mod pallet {
 			})?;
 			T::XcmSender::deliver(ticket)
 		}
+
+		/// Whether the message with the given nonce was already processed, or is too old to be
+		/// tracked by the nonce bitmap anymore.
+		pub fn is_nonce_consumed(nonce: u64) -> bool {
+			nonce < OldestTrackedNonce::<T>::get() || Nonce::<T>::get(nonce)
+		}
+
+		/// The lowest nonce which is still tracked by the nonce bitmap.
+		pub fn oldest_tracked_nonce() -> u64 {
+			OldestTrackedNonce::<T>::get()
+		}
+
+		/// The number of buckets stored in the nonce bitmap.
+		pub fn nonce_bitmap_size() -> u32 {
+			NonceBitmapSize::<T>::get()
+		}
+
+		pub(crate) fn mark_nonce_processed(nonce: u64) {
+			if !NonceBitmap::<T>::contains_key(Nonce::<T>::bucket(nonce)) {
+				NonceBitmapSize::<T>::mutate(|size| *size = size.saturating_add(1));
+			}
+			Nonce::<T>::set(nonce);
+			LatestNonce::<T>::mutate(|latest| *latest = nonce.max(*latest));
+		}
+
+		/// Prune up to `max_buckets` buckets of the nonce bitmap, starting at the oldest tracked
+		/// one. Only buckets whose nonces are all outside of the replay window are pruned.
+		///
+		/// Returns the number of pruned buckets.
+		pub(crate) fn do_prune_nonces(max_buckets: u32) -> u32 {
+			let Some(prune_below) =
+				LatestNonce::<T>::get().checked_sub(T::NonceReplayWindow::get())
+			else {
+				return 0
+			};
+			let start = Nonce::<T>::bucket(OldestTrackedNonce::<T>::get());
+			let end = Nonce::<T>::bucket(prune_below).min(start.saturating_add(max_buckets.into()));
+			if end <= start {
+				return 0
+			}
+
+			let mut removed = 0u32;
+			for bucket in start..end {
+				if NonceBitmap::<T>::contains_key(bucket) {
+					NonceBitmap::<T>::remove(bucket);
+					removed += 1;
+				}
+			}
+			NonceBitmapSize::<T>::mutate(|size| *size = size.saturating_sub(removed));
+
+			let oldest_tracked_nonce = end.saturating_mul(Nonce::<T>::BUCKET_SIZE);
+			OldestTrackedNonce::<T>::set(oldest_tracked_nonce);
+			Self::deposit_event(Event::NoncesPruned { buckets: removed, oldest_tracked_nonce });
+
+			(end - start) as u32
+		}
 	}
 
 	impl<T: Config> AddTip for Pallet<T> {
 		fn add_tip(nonce: u64, amount: u128) -> Result<(), AddTipError> {
 			ensure!(amount > 0, AddTipError::AmountZero);
 			// If the nonce is already processed, return an error
-			ensure!(!Nonce::<T>::get(nonce.into()), AddTipError::NonceConsumed);
+			ensure!(!Self::is_nonce_consumed(nonce), AddTipError::NonceConsumed);
 			// Otherwise add the tip.
 			Tips::<T>::mutate(nonce, |tip| {
 				*tip = Some(tip.unwrap_or_default().saturating_add(amount));
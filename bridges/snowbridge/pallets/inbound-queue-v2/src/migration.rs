@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+//! Migrations of the inbound queue.
+use super::*;
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "snowbridge_pallet_inbound_queue_v2::migration";
+
+/// The in-code storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+pub mod v1 {
+	use super::*;
+
+	/// Initializes [`NonceBitmapSize`] and [`LatestNonce`] from the existing [`NonceBitmap`].
+	///
+	/// Iterates the whole bitmap in a single block. Each bucket tracks 128 nonces, so this is only
+	/// suitable for chains which did not process many messages yet, like the testnets.
+	pub struct InitializeNonceTracking<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InitializeNonceTracking<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut buckets = 0u32;
+			let mut latest_nonce = None;
+			for (bucket, bits) in NonceBitmap::<T>::iter() {
+				buckets = buckets.saturating_add(1);
+				if bits == 0 {
+					continue
+				}
+				let highest_bit = 127 - u64::from(bits.leading_zeros());
+				let nonce =
+					bucket.saturating_mul(Nonce::<T>::BUCKET_SIZE).saturating_add(highest_bit);
+				latest_nonce = latest_nonce.max(Some(nonce));
+			}
+
+			NonceBitmapSize::<T>::put(buckets);
+			if let Some(latest_nonce) = latest_nonce {
+				LatestNonce::<T>::put(latest_nonce);
+			}
+			log::info!(
+				target: LOG_TARGET,
+				"Initialized the nonce tracking: buckets = {buckets}, latest nonce = {latest_nonce:?}."
+			);
+			T::DbWeight::get().reads_writes(u64::from(buckets), 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				NonceBitmapSize::<T>::get() as usize == NonceBitmap::<T>::iter_keys().count(),
+				"The nonce bitmap size matches the number of buckets."
+			);
+			let latest_nonce = LatestNonce::<T>::get();
+			ensure!(
+				NonceBitmap::<T>::iter_keys().count() == 0 || Nonce::<T>::get(latest_nonce),
+				"The latest nonce was processed."
+			);
+			ensure!(
+				NonceBitmap::<T>::iter_keys()
+					.all(|bucket| bucket <= Nonce::<T>::bucket(latest_nonce)),
+				"No nonce above the latest nonce was processed."
+			);
+			Ok(())
+		}
+	}
+}
+
+/// Initialize the nonce tracking and increment the pallet version so it cannot be re-run.
+pub type InitializeNonceTrackingV0ToV1<T> = VersionedMigration<
+	0,
+	1,
+	v1::InitializeNonceTracking<T>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
@@ -3,7 +3,10 @@
 use super::*;
 
 use crate::{self as inbound_queue_v2};
-use frame_support::{derive_impl, parameter_types, traits::ConstU32};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
 use hex_literal::hex;
 use snowbridge_beacon_primitives::{
 	types::deneb, BeaconHeader, ExecutionProof, VersionedExecutionPayloadHeader,
@@ -25,7 +28,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system::{Pallet, Call, Storage, Event<T>},
-		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>, HoldReason},
 		InboundQueue: inbound_queue_v2::{Pallet, Call, Storage, Event<T>},
 	}
 );
@@ -100,6 +103,7 @@ parameter_types! {
 	pub AssetHubUniversalLocation: InteriorLocation = [GlobalConsensus(ByGenesis(WESTEND_GENESIS_HASH)), Parachain(1000)].into();
 	pub SnowbridgeReward: BridgeReward = BridgeReward::Snowbridge;
 	pub const CreateAssetCall: [u8;2] = [53, 0];
+	pub const SetAssetMetadataCall: [u8;2] = [53, 17];
 	pub const CreateAssetDeposit: u128 = 10_000_000_000u128;
 }
 
@@ -112,7 +116,9 @@ impl inbound_queue_v2::Config for Test {
 	type AssetHubParaId = ConstU32<1000>;
 	type MessageConverter = MessageToXcm<
 		CreateAssetCall,
+		SetAssetMetadataCall,
 		CreateAssetDeposit,
+		(),
 		EthereumNetwork,
 		InboundQueueLocation,
 		MockTokenIdConvert,
@@ -129,6 +135,7 @@ impl inbound_queue_v2::Config for Test {
 	type RewardKind = BridgeReward;
 	type DefaultRewardKind = SnowbridgeReward;
 	type RewardPayment = MockRewardLedger;
+	type NonceReplayWindow = ConstU64<256>;
 }
 
 pub fn setup() {
@@ -389,3 +389,77 @@ fn test_add_tip_amount_zero() {
 		assert_eq!(Tips::<Test>::get(nonce), None);
 	});
 }
+
+#[test]
+fn test_prune_nonces() {
+	new_tester().execute_with(|| {
+		let origin = RuntimeOrigin::signed(Keyring::Bob.into());
+
+		// Nothing was processed yet, so everything is within the replay window.
+		assert_noop!(InboundQueue::prune_nonces(origin.clone(), 10), Error::<Test>::NothingToPrune);
+
+		// Fill 5 buckets, the latest nonce is 639 and the replay window covers nonces from 383.
+		for nonce in 0..640 {
+			InboundQueue::mark_nonce_processed(nonce);
+		}
+		assert_eq!(LatestNonce::<Test>::get(), 639);
+		assert_eq!(InboundQueue::nonce_bitmap_size(), 5);
+		assert_eq!(InboundQueue::oldest_tracked_nonce(), 0);
+
+		// Pruning is bounded by `max_buckets`.
+		assert_ok!(InboundQueue::prune_nonces(origin.clone(), 1));
+		assert_eq!(InboundQueue::oldest_tracked_nonce(), 128);
+		assert_eq!(InboundQueue::nonce_bitmap_size(), 4);
+		System::assert_last_event(RuntimeEvent::InboundQueue(Event::NoncesPruned {
+			buckets: 1,
+			oldest_tracked_nonce: 128,
+		}));
+
+		// The bucket containing the start of the replay window is kept.
+		assert_ok!(InboundQueue::prune_nonces(origin.clone(), 10));
+		assert_eq!(InboundQueue::oldest_tracked_nonce(), 256);
+		assert_eq!(InboundQueue::nonce_bitmap_size(), 3);
+		assert!(!NonceBitmap::<Test>::contains_key(1));
+		assert!(NonceBitmap::<Test>::contains_key(2));
+		assert_noop!(InboundQueue::prune_nonces(origin.clone(), 10), Error::<Test>::NothingToPrune);
+
+		// Pruned nonces are still treated as processed.
+		assert!(InboundQueue::is_nonce_consumed(5));
+		assert!(!InboundQueue::is_nonce_consumed(640));
+		assert_noop!(InboundQueue::add_tip(5, 100), AddTipError::NonceConsumed);
+		let event = EventProof {
+			event_log: mock_event_log(),
+			proof: Proof {
+				receipt_proof: Default::default(),
+				execution_proof: mock_execution_proof(),
+			},
+		};
+		assert_noop!(InboundQueue::submit(origin, Box::new(event)), Error::<Test>::InvalidNonce);
+	});
+}
+
+#[test]
+fn test_initialize_nonce_tracking_migration() {
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	new_tester().execute_with(|| {
+		StorageVersion::new(0).put::<InboundQueue>();
+		// The bitmap as written before the nonce tracking was added.
+		for nonce in [1, 5, 130, 700, 301] {
+			Nonce::<Test>::set(nonce);
+		}
+
+		migration::InitializeNonceTrackingV0ToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(InboundQueue::nonce_bitmap_size(), 4);
+		assert_eq!(LatestNonce::<Test>::get(), 700);
+		assert_eq!(InboundQueue::on_chain_storage_version(), migration::STORAGE_VERSION);
+
+		// Processing a new nonce keeps the tracking consistent.
+		InboundQueue::mark_nonce_processed(701);
+		assert_eq!(InboundQueue::nonce_bitmap_size(), 4);
+		InboundQueue::mark_nonce_processed(1000);
+		assert_eq!(InboundQueue::nonce_bitmap_size(), 5);
+		assert_eq!(LatestNonce::<Test>::get(), 1000);
+	});
+}
@@ -39,6 +39,11 @@
 //! 	   same as the inbound queue verification flow
 //! 	b. Fetch the pending order by nonce of the message, pay reward with fee attached in the order
 //!    	c. Remove the order from `PendingOrders` map storage by nonce
+//! 	d. Keep the assets escrowed for the message in the `Escrows` map storage, if the receipt
+//! 	   proves that the dispatch of the message failed
+//! 11. The origin of a message whose dispatch failed can abort it through
+//!     [`AbortMessage::abort`], to get back the assets escrowed for the message. The Gateway
+//!     consumed the nonce of the message, so it can never be dispatched on Ethereum again.
 //!
 //!
 //! # Extrinsics
@@ -87,7 +92,8 @@ use snowbridge_merkle_tree::merkle_root;
 use snowbridge_outbound_queue_primitives::{
 	v2::{
 		abi::{CommandWrapper, OutboundMessageWrapper},
-		DeliveryReceipt, GasMeter, Message, OutboundCommandWrapper, OutboundMessage,
+		AbortMessage, AbortMessageError, DeliveryReceipt, EscrowedAsset, GasMeter, Message,
+		OutboundCommandWrapper, OutboundMessage,
 	},
 	EventProof, VerificationError, Verifier,
 };
@@ -97,7 +103,7 @@ use sp_runtime::{
 	DigestItem,
 };
 use sp_std::prelude::*;
-pub use types::{PendingOrder, ProcessMessageOriginOf};
+pub use types::{Escrow, PendingOrder, ProcessMessageOriginOf};
 pub use weights::WeightInfo;
 use xcm::prelude::NetworkId;
 
@@ -208,6 +214,13 @@ pub mod pallet {
 		OperatingModeChanged { mode: BasicOperatingMode },
 		/// Delivery Proof received
 		MessageDelivered { nonce: u64 },
+		/// Delivery Proof received, which proves that the dispatch of the message failed
+		MessageDispatchFailed { nonce: u64 },
+		/// A message whose dispatch failed was aborted by its origin
+		MessageAborted {
+			/// The nonce of the aborted message
+			nonce: u64,
+		},
 	}
 
 	#[pallet::error]
@@ -258,6 +271,11 @@ pub mod pallet {
 	pub type PendingOrders<T: Config> =
 		StorageMap<_, Twox64Concat, u64, PendingOrder<BlockNumberFor<T>>, OptionQuery>;
 
+	/// The assets escrowed for messages, by nonce. Removed once the message is dispatched on
+	/// Ethereum, or kept until its origin aborts it if the dispatch failed.
+	#[pallet::storage]
+	pub type Escrows<T: Config> = StorageMap<_, Twox64Concat, u64, Escrow, OptionQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
@@ -354,6 +372,9 @@ pub mod pallet {
 					Corrupt
 				})?;
 
+			let escrowed_assets: Vec<EscrowedAsset> =
+				commands.iter().filter_map(|command| command.escrowed_asset()).collect();
+
 			// Convert it to OutboundMessage and save into Messages storage
 			let commands: Vec<OutboundCommandWrapper> = commands
 				.into_iter()
@@ -420,6 +441,11 @@ pub mod pallet {
 				block_number: frame_system::Pallet::<T>::current_block_number(),
 			};
 			<PendingOrders<T>>::insert(nonce, order);
+			if !escrowed_assets.is_empty() {
+				// There is at most one escrowed asset per command.
+				let assets = BoundedVec::truncate_from(escrowed_assets);
+				<Escrows<T>>::insert(nonce, Escrow { origin, assets, failed: false });
+			}
 
 			<Nonce<T>>::set(nonce);
 
@@ -462,6 +488,15 @@ pub mod pallet {
 
 			Self::deposit_event(Event::MessageDelivered { nonce });
 
+			if receipt.success {
+				<Escrows<T>>::remove(nonce);
+			} else {
+				// The assets were not released on Ethereum, keep them until the origin aborts
+				// the message.
+				<Escrows<T>>::mutate_extant(nonce, |escrow| escrow.failed = true);
+				Self::deposit_event(Event::MessageDispatchFailed { nonce });
+			}
+
 			Ok(())
 		}
 	}
@@ -480,4 +515,18 @@ pub mod pallet {
 			})
 		}
 	}
+
+	impl<T: Config> AbortMessage for Pallet<T> {
+		fn abort(origin: H256, nonce: u64) -> Result<Vec<EscrowedAsset>, AbortMessageError> {
+			let escrow = <Escrows<T>>::get(nonce).ok_or(AbortMessageError::UnknownMessage)?;
+			ensure!(escrow.origin == origin, AbortMessageError::InvalidOrigin);
+			ensure!(escrow.failed, AbortMessageError::NotFailed);
+
+			<Escrows<T>>::remove(nonce);
+
+			Self::deposit_event(Event::MessageAborted { nonce });
+
+			Ok(escrow.assets.into_inner())
+		}
+	}
 }
@@ -70,6 +70,9 @@ impl pallet_message_queue::Config for Test {
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type MaxDeadLetters = ();
+	type DeadLetterDepositor = ();
+	type DeadLetterConsideration = ();
 	type QueuePausedQuery = ();
 }
 
@@ -83,7 +86,7 @@ impl Verifier for MockVerifier {
 }
 
 const GATEWAY_ADDRESS: [u8; 20] = hex!["b1185ede04202fe62d38f5db72f71e38ff3e8305"];
-const WETH: [u8; 20] = hex!["C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"];
+pub const WETH: [u8; 20] = hex!["C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"];
 
 parameter_types! {
 	pub const OwnParaId: ParaId = ParaId::new(1013);
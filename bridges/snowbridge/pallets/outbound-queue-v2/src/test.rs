@@ -15,7 +15,9 @@ use snowbridge_outbound_queue_primitives::{
 	v2::{abi::OutboundMessageWrapper, Command, Initializer, SendMessage},
 	SendError,
 };
+use snowbridge_test_utils::mock_rewards::RegisteredRewardsCount;
 use sp_core::{hexdisplay::HexDisplay, H256};
+use sp_runtime::AccountId32;
 
 #[test]
 fn submit_messages_and_commit() {
@@ -345,3 +347,86 @@ fn test_add_tip_fails_amount_zero() {
 		assert_eq!(order_after.fee, initial_fee);
 	});
 }
+
+#[test]
+fn abort_failed_message() {
+	new_tester().execute_with(|| {
+		let message = mock_message(1000);
+		let ticket = OutboundQueue::validate(&message).unwrap();
+		assert_ok!(OutboundQueue::deliver(ticket));
+
+		ServiceWeight::set(Some(Weight::MAX));
+		run_to_end_of_next_block();
+
+		let nonce = Nonce::<Test>::get();
+		let escrowed_assets =
+			vec![EscrowedAsset::NativeToken { token: H160(WETH), amount: 1_000_000 }];
+		assert_eq!(
+			Escrows::<Test>::get(nonce).map(|escrow| escrow.assets.into_inner()),
+			Some(escrowed_assets.clone())
+		);
+
+		assert_noop!(
+			OutboundQueue::abort(message.origin, nonce + 1),
+			AbortMessageError::UnknownMessage
+		);
+		assert_noop!(OutboundQueue::abort(H256::zero(), nonce), AbortMessageError::InvalidOrigin);
+		// The message can still be delivered.
+		assert_noop!(OutboundQueue::abort(message.origin, nonce), AbortMessageError::NotFailed);
+
+		// The relayer is rewarded for delivering the message, even though its dispatch failed.
+		let relayer: AccountId32 = [1; 32].into();
+		let receipt = DeliveryReceipt {
+			gateway: GatewayAddress::get(),
+			nonce,
+			topic: message.id,
+			success: false,
+			reward_address: [0; 32],
+		};
+		let rewards_before = RegisteredRewardsCount::get();
+		assert_ok!(OutboundQueue::process_delivery_receipt(relayer, receipt));
+		assert_eq!(RegisteredRewardsCount::get(), rewards_before + 1);
+		assert!(PendingOrders::<Test>::get(nonce).is_none());
+		System::assert_last_event(RuntimeEvent::OutboundQueue(Event::MessageDispatchFailed {
+			nonce,
+		}));
+
+		assert_eq!(OutboundQueue::abort(message.origin, nonce), Ok(escrowed_assets));
+		assert!(Escrows::<Test>::get(nonce).is_none());
+		System::assert_last_event(RuntimeEvent::OutboundQueue(Event::MessageAborted { nonce }));
+
+		// The message can only be aborted once.
+		assert_noop!(
+			OutboundQueue::abort(message.origin, nonce),
+			AbortMessageError::UnknownMessage
+		);
+	});
+}
+
+#[test]
+fn dispatched_message_cannot_be_aborted() {
+	new_tester().execute_with(|| {
+		let message = mock_message(1000);
+		let ticket = OutboundQueue::validate(&message).unwrap();
+		assert_ok!(OutboundQueue::deliver(ticket));
+
+		ServiceWeight::set(Some(Weight::MAX));
+		run_to_end_of_next_block();
+
+		let nonce = Nonce::<Test>::get();
+		let receipt = DeliveryReceipt {
+			gateway: GatewayAddress::get(),
+			nonce,
+			topic: message.id,
+			success: true,
+			reward_address: [0; 32],
+		};
+		assert_ok!(OutboundQueue::process_delivery_receipt([1; 32].into(), receipt));
+		assert!(Escrows::<Test>::get(nonce).is_none());
+
+		assert_noop!(
+			OutboundQueue::abort(message.origin, nonce),
+			AbortMessageError::UnknownMessage
+		);
+	});
+}
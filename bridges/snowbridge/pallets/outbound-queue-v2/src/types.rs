@@ -2,9 +2,11 @@
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 use super::Pallet;
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::traits::ProcessMessage;
+use frame_support::{pallet_prelude::ConstU32, traits::ProcessMessage, BoundedVec};
 use scale_info::TypeInfo;
 pub use snowbridge_merkle_tree::MerkleProof;
+use snowbridge_outbound_queue_primitives::v2::{EscrowedAsset, MAX_COMMANDS};
+use sp_core::H256;
 use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 
@@ -21,3 +23,14 @@ pub struct PendingOrder<BlockNumber> {
 	#[codec(compact)]
 	pub fee: u128,
 }
+
+/// The assets escrowed for a message
+#[derive(Encode, Decode, TypeInfo, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub struct Escrow {
+	/// The origin of the message
+	pub origin: H256,
+	/// The assets escrowed on Polkadot, which are released on Ethereum by the message
+	pub assets: BoundedVec<EscrowedAsset, ConstU32<MAX_COMMANDS>>,
+	/// Whether a delivery receipt proved that the dispatch of the message failed
+	pub failed: bool,
+}
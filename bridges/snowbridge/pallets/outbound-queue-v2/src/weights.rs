@@ -56,7 +56,7 @@ impl WeightInfo for () {
 		// Minimum execution time: 39_000_000 picoseconds.
 		Weight::from_parts(39_000_000, 3485)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: EthereumOutboundQueue MessageLeaves (r:1 w:0)
 	/// Proof Skipped: EthereumOutboundQueue MessageLeaves (max_values: Some(1), max_size: None, mode: Measured)
@@ -85,8 +85,8 @@ impl WeightInfo for () {
 	fn submit_delivery_receipt() -> Weight {
 		Weight::from_parts(70_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3601))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
 	}
 
 	fn on_initialize() -> Weight {
@@ -99,6 +99,6 @@ impl WeightInfo for () {
 		Weight::from_parts(506_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 1493))
 			.saturating_add(RocksDbWeight::get().reads(1))
-			.saturating_add(RocksDbWeight::get().writes(35))
+			.saturating_add(RocksDbWeight::get().writes(67))
 	}
 }
@@ -65,6 +65,9 @@ impl pallet_message_queue::Config for Test {
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type MaxDeadLetters = ();
+	type DeadLetterDepositor = ();
+	type DeadLetterConsideration = ();
 	type QueuePausedQuery = ();
 }
 
@@ -10,6 +10,9 @@ pub trait BackendWeightInfo {
 	/// using `Transact`.
 	fn transact_register_token() -> Weight;
 	fn transact_add_tip() -> Weight;
+	/// Execution weight for remote xcm that dispatches `EthereumSystemCall::ReclaimMessage`
+	/// using `Transact`.
+	fn transact_reclaim_message() -> Weight;
 	fn do_process_message() -> Weight;
 	fn commit_single() -> Weight;
 	fn submit_delivery_receipt() -> Weight;
@@ -22,6 +25,9 @@ impl BackendWeightInfo for () {
 	fn transact_add_tip() -> Weight {
 		Weight::from_parts(100_000_000, 10000)
 	}
+	fn transact_reclaim_message() -> Weight {
+		Weight::from_parts(100_000_000, 10000)
+	}
 	fn do_process_message() -> Weight {
 		Weight::from_parts(39_000_000, 3485)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
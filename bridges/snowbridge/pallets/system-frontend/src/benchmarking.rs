@@ -56,6 +56,17 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn reclaim_message() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Helper::setup_pools(caller.clone(), T::EthereumLocation::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 1);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		SnowbridgeControlFrontend,
 		crate::mock::new_test_ext(),
@@ -8,6 +8,8 @@
 //! # Extrinsics
 //!
 //! * [`Call::register_token`]: Register Polkadot native asset as a wrapped ERC20 token on Ethereum.
+//! * [`Call::reclaim_message`]: Abort a message to Ethereum whose dispatch failed and get back the
+//!   assets escrowed for it.
 #![cfg_attr(not(feature = "std"), no_std)]
 #[cfg(test)]
 mod mock;
@@ -66,6 +68,8 @@ pub enum EthereumSystemCall<T: frame_system::Config> {
 	},
 	#[codec(index = 3)]
 	AddTip { sender: AccountIdOf<T>, message_id: MessageId, amount: u128 },
+	#[codec(index = 4)]
+	ReclaimMessage { sender: Box<VersionedLocation>, nonce: u64 },
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -97,6 +101,9 @@ pub mod pallet {
 			Success = Location,
 		>;
 
+		/// Origin that can change the operating mode of the pallet.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// XCM message sender
 		type XcmSender: SendXcm;
 
@@ -202,7 +209,7 @@ pub mod pallet {
 		#[pallet::call_index(0)]
 		#[pallet::weight((T::DbWeight::get().reads_writes(1, 1), DispatchClass::Operational))]
 		pub fn set_operating_mode(origin: OriginFor<T>, mode: OperatingMode) -> DispatchResult {
-			ensure_root(origin)?;
+			T::AdminOrigin::ensure_origin(origin)?;
 			ExportOperatingMode::<T>::put(mode);
 			Self::deposit_event(Event::ExportOperatingModeChanged { mode });
 			Ok(())
@@ -272,6 +279,24 @@ pub mod pallet {
 			let call = Self::build_add_tip_call(who.clone(), message_id.clone(), ether_gained);
 			Self::send_transact_call(who.into(), call)
 		}
+
+		/// Abort a message to Ethereum with the given `nonce` which was sent by the caller, but
+		/// whose dispatch failed on Ethereum as proven by its delivery receipt on BH. The assets
+		/// escrowed for the message are sent back to the caller.
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			T::WeightInfo::reclaim_message()
+				.saturating_add(T::BackendWeightInfo::transact_reclaim_message())
+		)]
+		pub fn reclaim_message(origin: OriginFor<T>, nonce: u64) -> DispatchResult
+		where
+			<T as frame_system::Config>::AccountId: Into<Location>,
+		{
+			let who = ensure_signed(origin)?;
+
+			let call = Self::build_reclaim_message_call(who.clone(), nonce)?;
+			Self::send_transact_call(who.into(), call)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -351,6 +376,25 @@ pub mod pallet {
 			})
 		}
 
+		// Build the call to dispatch the `EthereumSystem::reclaim_message` extrinsic on BH
+		fn build_reclaim_message_call(
+			sender: AccountIdOf<T>,
+			nonce: u64,
+		) -> Result<BridgeHubRuntime<T>, Error<T>>
+		where
+			<T as frame_system::Config>::AccountId: Into<Location>,
+		{
+			// reanchor the sender relative to BH
+			let sender_location = Self::reanchored(sender.into())?;
+
+			let call = BridgeHubRuntime::EthereumSystem(EthereumSystemCall::ReclaimMessage {
+				sender: Box::new(VersionedLocation::from(sender_location)),
+				nonce,
+			});
+
+			Ok(call)
+		}
+
 		fn build_remote_xcm(call: &impl Encode) -> Xcm<()> {
 			Xcm(vec![
 				DescendOrigin(T::PalletLocation::get()),
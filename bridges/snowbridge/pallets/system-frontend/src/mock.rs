@@ -91,6 +91,7 @@ impl xcm_executor::traits::ConvertLocation<AccountId> for AccountIdConverter {
 impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RegisterTokenOrigin = AsEnsureOriginWithArg<pallet_xcm_origin::EnsureXcm<Everything>>;
+	type AdminOrigin = frame_system::EnsureRoot<AccountId>;
 	type XcmSender = MockXcmSender;
 	type AssetTransactor = SuccessfulTransactor;
 	type EthereumLocation = Ether;
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
-use crate::{mock::*, DispatchError::Other, Error};
+use crate::{mock::*, DispatchError, DispatchError::Other, Error};
 use frame_support::{assert_err, assert_noop, assert_ok};
 use frame_system::RawOrigin;
 use snowbridge_core::{reward::MessageId, AssetMetadata, BasicOperatingMode};
@@ -146,6 +146,20 @@ fn test_switch_operating_mode() {
 	});
 }
 
+#[test]
+fn switch_operating_mode_with_bad_origin_fails() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Keyring::Alice.into();
+		assert_noop!(
+			EthereumSystemFrontend::set_operating_mode(
+				RuntimeOrigin::signed(who),
+				BasicOperatingMode::Halted,
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
 #[test]
 fn add_tip_ether_asset_succeeds() {
 	new_test_ext().execute_with(|| {
@@ -290,3 +304,32 @@ fn register_token_with_non_ether_fee_asset_succeeds() {
 		));
 	});
 }
+
+#[test]
+fn reclaim_message_succeeds() {
+	new_test_ext().execute_with(|| {
+		let who: AccountId = Keyring::Alice.into();
+
+		assert_ok!(EthereumSystemFrontend::reclaim_message(RuntimeOrigin::signed(who), 1));
+
+		let events = System::events();
+		let event_record = events.last().expect("Expected at least one event").event.clone();
+
+		if !matches!(
+			event_record,
+			RuntimeEvent::EthereumSystemFrontend(crate::Event::MessageSent { .. })
+		) {
+			panic!("Expected MessageSent event, got: {:?}", event_record);
+		}
+	});
+}
+
+#[test]
+fn reclaim_message_origin_not_signed_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EthereumSystemFrontend::reclaim_message(RuntimeOrigin::root(), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
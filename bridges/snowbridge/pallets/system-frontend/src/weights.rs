@@ -34,6 +34,7 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn register_token() -> Weight;
 	fn add_tip() -> Weight;
+	fn reclaim_message() -> Weight;
 }
 
 // For backwards compatibility and tests.
@@ -57,4 +58,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+
+	fn reclaim_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `256`
+		//  Estimated: `3593`
+		// Minimum execution time: 30_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
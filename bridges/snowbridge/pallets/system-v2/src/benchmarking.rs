@@ -72,6 +72,24 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn reclaim_message() -> Result<(), BenchmarkError> {
+		let origin_location = Location::new(1, [Parachain(1000), PalletInstance(36)]);
+		let origin = <T as Config>::Helper::make_xcm_origin(origin_location);
+		let sender_location =
+			Location::new(1, [Parachain(1000), AccountId32 { network: None, id: [1; 32] }]);
+		let message_origin =
+			SnowbridgeControl::<T>::location_to_message_origin(sender_location.clone())
+				.map_err(|_| BenchmarkError::Weightless)?;
+		<T as Config>::Helper::initialize_failed_message(message_origin, 1);
+		let sender = Box::new(VersionedLocation::from(sender_location));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, sender, 1);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		SnowbridgeControl,
 		crate::mock::new_test_ext(true),
@@ -15,6 +15,11 @@
 //! prerequisite, the token should be registered first.
 //!
 //! * [`Call::register_token`]: Register a token location as a wrapped ERC20 contract on Ethereum.
+//!
+//! ## Failed messages
+//!
+//! * [`Call::reclaim_message`]: Abort a message whose dispatch failed on Ethereum, and return the
+//!   assets escrowed for it to the sender on AssetHub.
 #![cfg_attr(not(feature = "std"), no_std)]
 #[cfg(test)]
 mod mock;
@@ -40,7 +45,9 @@ use snowbridge_core::{
 	AgentIdOf as LocationHashOf, AssetMetadata, TokenId, TokenIdOf,
 };
 use snowbridge_outbound_queue_primitives::{
-	v2::{Command, Initializer, Message, SendMessage},
+	v2::{
+		AbortMessage, AbortMessageError, Command, EscrowedAsset, Initializer, Message, SendMessage,
+	},
 	OperatingMode, SendError,
 };
 use snowbridge_pallet_system::ForeignToNativeId;
@@ -63,6 +70,9 @@ where
 	O: OriginTrait,
 {
 	fn make_xcm_origin(location: Location) -> O;
+	/// Store an outbound message with the given `nonce`, sent from `message_origin`, whose
+	/// dispatch failed on Ethereum, such that it can be aborted.
+	fn initialize_failed_message(message_origin: H256, nonce: u64);
 }
 
 #[frame_support::pallet]
@@ -77,13 +87,20 @@ pub mod pallet {
 		#[allow(deprecated)]
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Send messages to Ethereum and add additional relayer rewards if deposited
-		type OutboundQueue: SendMessage + AddTip;
+		type OutboundQueue: SendMessage + AddTip + AbortMessage;
 		/// Add to the relayer reward for a specific message
 		type InboundQueue: AddTip;
 		/// Origin check for XCM locations that transact with this pallet
 		type FrontendOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Location>;
 		/// Origin for governance calls
 		type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Location>;
+		/// XCM message sender, to return the assets escrowed for aborted messages to AssetHub
+		type XcmSender: SendXcm;
+		/// Location of AssetHub
+		type AssetHubLocation: Get<Location>;
+		/// Location of the inbound queue on this chain, which AssetHub accepts assets from
+		/// Ethereum from
+		type InboundQueueLocation: Get<InteriorLocation>;
 		type WeightInfo: WeightInfo;
 		#[cfg(feature = "runtime-benchmarks")]
 		type Helper: BenchmarkHelper<Self::RuntimeOrigin>;
@@ -116,6 +133,16 @@ pub mod pallet {
 			/// that was already consumed, the tip will be added to LostTips.
 			success: bool,
 		},
+		/// An outbound message whose dispatch failed was aborted, and the assets escrowed for it
+		/// were sent back to AssetHub.
+		MessageReclaimed {
+			/// The outbound message nonce
+			nonce: u64,
+			/// The location on AssetHub which the assets were returned to.
+			beneficiary: VersionedLocation,
+			/// ID of the XCM returning the assets.
+			message_id: XcmHash,
+		},
 	}
 
 	#[pallet::error]
@@ -131,6 +158,10 @@ pub mod pallet {
 		/// The gateway contract upgrade message could not be sent due to invalid upgrade
 		/// parameters.
 		InvalidUpgradeParameters,
+		/// An outbound message could not be aborted, due to an `AbortMessageError`.
+		Abort(AbortMessageError),
+		/// The escrowed assets of an aborted message could not be sent back to AssetHub.
+		ReturnAssetsFailed,
 	}
 
 	/// Relayer reward tips that were paid by the user to incentivize the processing of their
@@ -279,6 +310,46 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Aborts an outbound message whose dispatch failed on Ethereum, as proven by its
+		/// delivery receipt, and returns the assets escrowed for it to the sender on AH.
+		///
+		/// The system frontend pallet on AH proxies this call to BH.
+		///
+		/// - `sender`: The original sender of the message on AH (relative to this chain)
+		/// - `nonce`: The nonce of the outbound message
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::reclaim_message())]
+		pub fn reclaim_message(
+			origin: OriginFor<T>,
+			sender: Box<VersionedLocation>,
+			nonce: u64,
+		) -> DispatchResult {
+			T::FrontendOrigin::ensure_origin(origin)?;
+
+			let sender_location: Location =
+				(*sender).try_into().map_err(|_| Error::<T>::UnsupportedLocationVersion)?;
+			let message_origin = Self::location_to_message_origin(sender_location.clone())?;
+
+			let assets = <T as pallet::Config>::OutboundQueue::abort(message_origin, nonce)
+				.map_err(|err| Error::<T>::Abort(err))?;
+
+			let beneficiary = sender_location
+				.reanchored(&T::AssetHubLocation::get(), &T::UniversalLocation::get())
+				.map_err(|_| Error::<T>::LocationReanchorFailed)?;
+			let xcm = Self::return_escrowed_assets_xcm(assets, beneficiary.clone())?;
+			let (message_id, _) =
+				send_xcm::<<T as pallet::Config>::XcmSender>(T::AssetHubLocation::get(), xcm)
+					.map_err(|_| Error::<T>::ReturnAssetsFailed)?;
+
+			Self::deposit_event(Event::<T>::MessageReclaimed {
+				nonce,
+				beneficiary: beneficiary.into(),
+				message_id,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -311,6 +382,67 @@ pub mod pallet {
 			LocationHashOf::convert_location(&reanchored_location)
 				.ok_or(Error::<T>::LocationConversionFailed)
 		}
+
+		/// Build the XCM returning the `assets` escrowed for an aborted message to `beneficiary`
+		/// on AH.
+		///
+		/// The assets are returned as if they were sent back from Ethereum, i.e. the
+		/// Ethereum-native tokens which were burned on AH are minted again, and the
+		/// Polkadot-native tokens are withdrawn from the sovereign account of Ethereum.
+		pub(crate) fn return_escrowed_assets_xcm(
+			assets: Vec<EscrowedAsset>,
+			beneficiary: Location,
+		) -> Result<Xcm<()>, Error<T>> {
+			let ethereum_location = T::EthereumLocation::get();
+			let ethereum_network =
+				ethereum_location.last().cloned().ok_or(Error::<T>::LocationConversionFailed)?;
+			let ethereum_universal_location: InteriorLocation = [ethereum_network.clone()].into();
+			let asset_hub_from_ethereum = Self::reanchor(T::AssetHubLocation::get())?;
+
+			let mut reserve_deposit_assets: Vec<Asset> = vec![];
+			let mut reserve_withdraw_assets: Vec<Asset> = vec![];
+			for asset in assets {
+				match asset {
+					EscrowedAsset::NativeToken { token, amount } => {
+						// The zero address stands for ether
+						let location = if token == H160::zero() {
+							ethereum_location.clone()
+						} else {
+							ethereum_location
+								.clone()
+								.appended_with(AccountKey20 { network: None, key: token.into() })
+								.map_err(|_| Error::<T>::LocationConversionFailed)?
+						};
+						reserve_deposit_assets.push((location, amount).into());
+					},
+					EscrowedAsset::ForeignToken { token_id, amount } => {
+						let location = Self::maybe_convert(token_id)
+							.ok_or(Error::<T>::LocationConversionFailed)?
+							.reanchored(&asset_hub_from_ethereum, &ethereum_universal_location)
+							.map_err(|_| Error::<T>::LocationReanchorFailed)?;
+						reserve_withdraw_assets.push((location, amount).into());
+					},
+				}
+			}
+			let count = (reserve_deposit_assets.len() + reserve_withdraw_assets.len()) as u32;
+
+			// AH executes messages from system parachains for free, and accepts assets from
+			// Ethereum through the inbound queue.
+			let mut instructions = vec![
+				UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+				DescendOrigin(T::InboundQueueLocation::get()),
+				UniversalOrigin(ethereum_network),
+			];
+			if !reserve_deposit_assets.is_empty() {
+				instructions.push(ReserveAssetDeposited(reserve_deposit_assets.into()));
+			}
+			if !reserve_withdraw_assets.is_empty() {
+				instructions.push(WithdrawAsset(reserve_withdraw_assets.into()));
+			}
+			instructions.push(DepositAsset { assets: Wild(AllCounted(count)), beneficiary });
+
+			Ok(instructions.into())
+		}
 	}
 
 	impl<T: Config> MaybeConvert<TokenId, Location> for Pallet<T> {
@@ -15,6 +15,7 @@ use snowbridge_core::{
 
 pub use snowbridge_test_utils::{
 	mock_inbound_queue::*, mock_origin::pallet_xcm_origin, mock_outbound_queue::*,
+	mock_xcm::MockXcmSender,
 };
 use sp_runtime::{
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
@@ -35,7 +36,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
-		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>, HoldReason},
 		XcmOrigin: pallet_xcm_origin::{Pallet, Origin},
 		EthereumSystem: snowbridge_pallet_system,
 		EthereumSystemV2: snowbridge_system_v2,
@@ -87,6 +88,8 @@ parameter_types! {
 	pub TestParaId: u32 = 2000;
 	pub RootLocation: Location = Location::parent();
 	pub FrontendLocation: Location = Location::new(1, [Parachain(1000), PalletInstance(36)]);
+	pub AssetHubLocation: Location = Location::new(1, [Parachain(1000)]);
+	pub InboundQueueLocation: InteriorLocation = [PalletInstance(91)].into();
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -94,6 +97,9 @@ impl BenchmarkHelper<RuntimeOrigin> for () {
 	fn make_xcm_origin(location: Location) -> RuntimeOrigin {
 		RuntimeOrigin::from(pallet_xcm_origin::Origin(location))
 	}
+
+	// not needed since `MockOkOutboundQueue` accepts every abort
+	fn initialize_failed_message(_: H256, _: u64) {}
 }
 
 pub struct AllowFromAssetHub;
@@ -109,6 +115,9 @@ impl crate::Config for Test {
 	type InboundQueue = MockOkInboundQueue;
 	type FrontendOrigin = pallet_xcm_origin::EnsureXcm<AllowFromAssetHub>;
 	type GovernanceOrigin = EnsureRootWithSuccess<AccountId, RootLocation>;
+	type XcmSender = MockXcmSender;
+	type AssetHubLocation = AssetHubLocation;
+	type InboundQueueLocation = InboundQueueLocation;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
@@ -277,3 +277,104 @@ fn add_tip_with_wrong_origin_fails() {
 		);
 	});
 }
+
+#[test]
+fn reclaim_message_succeeds() {
+	new_test_ext(true).execute_with(|| {
+		let origin = make_xcm_origin(FrontendLocation::get());
+		let account: AccountId = Keyring::Alice.into();
+		let sender = Location::new(
+			1,
+			[Parachain(1000), AccountId32 { network: None, id: account.clone().into() }],
+		);
+
+		assert_ok!(EthereumSystemV2::reclaim_message(
+			origin,
+			Box::new(VersionedLocation::from(sender)),
+			1
+		));
+
+		let beneficiary = Location::new(0, [AccountId32 { network: None, id: account.into() }]);
+		let xcm = EthereumSystemV2::return_escrowed_assets_xcm(
+			vec![EscrowedAsset::NativeToken { token: H160::repeat_byte(1), amount: 1_000 }],
+			beneficiary.clone(),
+		)
+		.unwrap();
+		let message_id = xcm.using_encoded(sp_io::hashing::blake2_256);
+		System::assert_last_event(RuntimeEvent::EthereumSystemV2(
+			Event::<Test>::MessageReclaimed { nonce: 1, beneficiary: beneficiary.into(), message_id },
+		));
+	});
+}
+
+#[test]
+fn return_escrowed_assets_xcm_releases_assets_from_ethereum() {
+	new_test_ext(true).execute_with(|| {
+		let beneficiary = Location::new(0, [AccountId32 { network: None, id: [1; 32] }]);
+		let ethereum = EthereumDestination::get();
+		let token = H160::repeat_byte(1);
+
+		let dot_location = EthereumSystemV2::reanchor(Location::parent()).unwrap();
+		let dot_id = TokenIdOf::convert_location(&dot_location).unwrap();
+		ForeignToNativeId::<Test>::insert(dot_id, dot_location);
+
+		let xcm = EthereumSystemV2::return_escrowed_assets_xcm(
+			vec![
+				EscrowedAsset::NativeToken { token: H160::zero(), amount: 1 },
+				EscrowedAsset::NativeToken { token, amount: 2 },
+				EscrowedAsset::ForeignToken { token_id: dot_id, amount: 3 },
+			],
+			beneficiary.clone(),
+		)
+		.unwrap();
+
+		let token_location = ethereum
+			.clone()
+			.appended_with(AccountKey20 { network: None, key: token.into() })
+			.unwrap();
+		let expected: Xcm<()> = vec![
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			DescendOrigin(InboundQueueLocation::get()),
+			UniversalOrigin(GlobalConsensus(EthereumNetwork::get())),
+			ReserveAssetDeposited(vec![(ethereum, 1).into(), (token_location, 2).into()].into()),
+			WithdrawAsset(vec![(Location::parent(), 3).into()].into()),
+			DepositAsset { assets: Wild(AllCounted(3)), beneficiary },
+		]
+		.into();
+		assert_eq!(xcm, expected);
+	});
+}
+
+#[test]
+fn reclaim_message_fails_when_message_cannot_be_aborted() {
+	new_test_ext(true).execute_with(|| {
+		let origin = make_xcm_origin(FrontendLocation::get());
+		let sender = Location::new(1, [Parachain(1000)]);
+
+		// In `MockOkOutboundQueue`, aborting fails when the nonce is equal to `FAILING_NONCE`.
+		assert_noop!(
+			EthereumSystemV2::reclaim_message(
+				origin,
+				Box::new(VersionedLocation::from(sender)),
+				FAILING_NONCE
+			),
+			Error::<Test>::Abort(AbortMessageError::UnknownMessage)
+		);
+	});
+}
+
+#[test]
+fn reclaim_message_with_wrong_origin_fails() {
+	new_test_ext(true).execute_with(|| {
+		let sender = Location::new(1, [Parachain(1000)]);
+
+		assert_noop!(
+			EthereumSystemV2::reclaim_message(
+				RuntimeOrigin::signed(Keyring::Alice.into()),
+				Box::new(VersionedLocation::from(sender)),
+				1
+			),
+			BadOrigin
+		);
+	});
+}
@@ -98,7 +98,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
-		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>, HoldReason},
 		XcmOrigin: pallet_xcm_origin::{Pallet, Origin},
 		OutboundQueue: snowbridge_pallet_outbound_queue::{Pallet, Call, Storage, Event<T>},
 		EthereumSystem: snowbridge_system,
@@ -150,6 +150,9 @@ impl pallet_message_queue::Config for Test {
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type MaxDeadLetters = ();
+	type DeadLetterDepositor = ();
+	type DeadLetterConsideration = ();
 	type QueuePausedQuery = ();
 }
 
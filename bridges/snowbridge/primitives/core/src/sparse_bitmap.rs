@@ -46,10 +46,18 @@ impl<BitMap> SparseBitmapImpl<BitMap>
 where
 	BitMap: StorageMap<u64, u128, Query = u128>,
 {
+	/// The number of indices tracked by each bucket.
+	pub const BUCKET_SIZE: u64 = 128;
+
 	/// Computes the bucket index and the bit mask for a given bit index.
 	/// Each bucket contains 128 bits.
 	fn compute_bucket_and_mask(index: u64) -> (u64, u128) {
-		(index >> 7, 1u128 << (index & 127))
+		(Self::bucket(index), 1u128 << (index & 127))
+	}
+
+	/// Computes the bucket index for a given bit index.
+	pub fn bucket(index: u64) -> u64 {
+		index >> 7
 	}
 }
 
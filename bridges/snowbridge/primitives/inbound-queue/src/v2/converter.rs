@@ -10,7 +10,10 @@ use frame_support::ensure;
 use snowbridge_core::TokenId;
 use sp_core::{Get, RuntimeDebug, H160};
 use sp_io::hashing::blake2_256;
-use sp_runtime::{traits::MaybeConvert, MultiAddress};
+use sp_runtime::{
+	traits::{Convert, MaybeConvert},
+	MultiAddress,
+};
 use sp_std::prelude::*;
 use xcm::{
 	prelude::{Junction::*, *},
@@ -38,6 +41,17 @@ pub struct PreparedMessage {
 	pub remote_xcm: Xcm<()>,
 	/// Fee in Ether to cover the xcm execution on AH.
 	pub execution_fee: Asset,
+	/// The native ERC-20s to create in the `ForeignAssets` pallet on AH before depositing them.
+	pub asset_registrations: Vec<AssetRegistration>,
+}
+
+/// A native ERC-20 to create in the `ForeignAssets` pallet on AH
+#[derive(Clone, RuntimeDebug, Encode)]
+pub struct AssetRegistration {
+	/// The location of the ERC-20, relative to AH
+	pub asset_id: Location,
+	/// The metadata of the ERC-20
+	pub metadata: TokenMetadata,
 }
 
 /// An asset transfer instruction
@@ -50,7 +64,9 @@ pub enum AssetTransfer {
 /// Concrete implementation of `ConvertMessage`
 pub struct MessageToXcm<
 	CreateAssetCall,
+	SetAssetMetadataCall,
 	CreateAssetDeposit,
+	AssetMetadataDeposit,
 	EthereumNetwork,
 	InboundQueueLocation,
 	ConvertAssetId,
@@ -62,7 +78,9 @@ pub struct MessageToXcm<
 > {
 	_phantom: PhantomData<(
 		CreateAssetCall,
+		SetAssetMetadataCall,
 		CreateAssetDeposit,
+		AssetMetadataDeposit,
 		EthereumNetwork,
 		InboundQueueLocation,
 		ConvertAssetId,
@@ -76,7 +94,9 @@ pub struct MessageToXcm<
 
 impl<
 		CreateAssetCall,
+		SetAssetMetadataCall,
 		CreateAssetDeposit,
+		AssetMetadataDeposit,
 		EthereumNetwork,
 		InboundQueueLocation,
 		ConvertAssetId,
@@ -88,7 +108,9 @@ impl<
 	>
 	MessageToXcm<
 		CreateAssetCall,
+		SetAssetMetadataCall,
 		CreateAssetDeposit,
+		AssetMetadataDeposit,
 		EthereumNetwork,
 		InboundQueueLocation,
 		ConvertAssetId,
@@ -100,7 +122,9 @@ impl<
 	>
 where
 	CreateAssetCall: Get<CallIndex>,
+	SetAssetMetadataCall: Get<CallIndex>,
 	CreateAssetDeposit: Get<u128>,
+	AssetMetadataDeposit: Convert<u32, u128>,
 	EthereumNetwork: Get<NetworkId>,
 	InboundQueueLocation: Get<InteriorLocation>,
 	ConvertAssetId: MaybeConvert<TokenId, Location>,
@@ -141,6 +165,7 @@ where
 		let execution_fee_asset: Asset = (ether_location.clone(), message.execution_fee).into();
 
 		let mut assets = vec![];
+		let mut asset_registrations = vec![];
 
 		if message.value > 0 {
 			// Asset for remaining ether
@@ -162,6 +187,22 @@ where
 					let asset: Asset = (token_location, *value).into();
 					assets.push(AssetTransfer::ReserveDeposit(asset));
 				},
+				EthereumAsset::NativeTokenERC20WithMetadata { token_id, value, metadata } => {
+					ensure!(*token_id != H160::zero(), ConvertMessageError::InvalidAsset);
+					let token_location: Location = Location::new(
+						2,
+						[
+							GlobalConsensus(EthereumNetwork::get()),
+							AccountKey20 { network: None, key: (*token_id).into() },
+						],
+					);
+					asset_registrations.push(AssetRegistration {
+						asset_id: token_location.clone(),
+						metadata: metadata.clone(),
+					});
+					let asset: Asset = (token_location, *value).into();
+					assets.push(AssetTransfer::ReserveDeposit(asset));
+				},
 				EthereumAsset::ForeignTokenERC20 { token_id, value } => {
 					let asset_loc = ConvertAssetId::maybe_convert(*token_id)
 						.ok_or(ConvertMessageError::InvalidAsset)?;
@@ -186,6 +227,7 @@ where
 			assets,
 			remote_xcm,
 			execution_fee: execution_fee_asset,
+			asset_registrations,
 		};
 
 		Ok(prepared_message)
@@ -282,6 +324,88 @@ where
 		.into()
 	}
 
+	/// The deposit in DOT reserved on AssetHub for creating a first-seen native ERC-20 and
+	/// setting its metadata.
+	fn registration_deposit(registration: &AssetRegistration) -> u128 {
+		let metadata_len =
+			registration.metadata.name.len().saturating_add(registration.metadata.symbol.len());
+		CreateAssetDeposit::get().saturating_add(AssetMetadataDeposit::convert(
+			metadata_len.try_into().unwrap_or(u32::MAX),
+		))
+	}
+
+	/// Construct the instructions needed to create first-seen native ERC-20s in the
+	/// `ForeignAssets` pallet on AssetHub and to set their metadata.
+	///
+	/// The instructions are executed with the Ethereum origin, so the assets are owned by, and
+	/// the deposits are reserved from, the Ethereum sovereign account on AH. The sender pays
+	/// for the deposits: ether from the message is exchanged for the DOT needed, which is
+	/// deposited into the sovereign account first. If there is not enough ether, the message
+	/// fails and the assets are trapped for the claimer.
+	///
+	/// If an asset exists already, its creation fails without aborting the message. The
+	/// metadata is read from the token contract by the Gateway, so setting it again for an
+	/// existing asset is harmless.
+	fn make_register_asset_instructions(
+		registrations: Vec<AssetRegistration>,
+		bridge_owner: AccountId,
+	) -> Vec<Instruction<()>> {
+		let bridge_owner_bytes: [u8; 32] = bridge_owner.into();
+		let deposit = registrations
+			.iter()
+			.map(Self::registration_deposit)
+			.fold(0u128, |total, deposit| total.saturating_add(deposit));
+		let dot_deposit_asset: Asset = (Location::new(1, Here), deposit).into();
+		let ether_id = AssetId(Location::new(2, [GlobalConsensus(EthereumNetwork::get())]));
+
+		let mut instructions = vec![
+			// Exchange the sender's eth for dot to pay the deposits.
+			ExchangeAsset {
+				give: Wild(AllOf { id: ether_id, fun: WildFungible }),
+				want: dot_deposit_asset.clone().into(),
+				maximal: false,
+			},
+			// Deposit the dot into the bridge sovereign account, which the deposits are
+			// reserved from.
+			DepositAsset {
+				assets: dot_deposit_asset.into(),
+				beneficiary: bridge_owner_bytes.into(),
+			},
+		];
+		for AssetRegistration { asset_id, metadata } in registrations {
+			instructions.extend([
+				// Call to create the asset.
+				Transact {
+					origin_kind: OriginKind::Xcm,
+					fallback_max_weight: None,
+					call: (
+						CreateAssetCall::get(),
+						asset_id.clone(),
+						MultiAddress::<[u8; 32], ()>::Id(bridge_owner_bytes.into()),
+						MINIMUM_DEPOSIT,
+					)
+						.encode()
+						.into(),
+				},
+				// Call to set the metadata of the asset, dispatched by the owner of the asset.
+				Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					fallback_max_weight: None,
+					call: (
+						SetAssetMetadataCall::get(),
+						asset_id,
+						metadata.name,
+						metadata.symbol,
+						metadata.decimals,
+					)
+						.encode()
+						.into(),
+				},
+			]);
+		}
+		instructions
+	}
+
 	/// Parse and (non-strictly) decode `raw` XCM bytes into a `Xcm<()>`.
 	/// If decoding fails, return an empty `Xcm<()>`—thus allowing the message
 	/// to proceed so assets can still be trapped on AH rather than the funds being locked on
@@ -302,7 +426,9 @@ where
 
 impl<
 		CreateAssetCall,
+		SetAssetMetadataCall,
 		CreateAssetDeposit,
+		AssetMetadataDeposit,
 		EthereumNetwork,
 		InboundQueueLocation,
 		ConvertAssetId,
@@ -314,7 +440,9 @@ impl<
 	> ConvertMessage
 	for MessageToXcm<
 		CreateAssetCall,
+		SetAssetMetadataCall,
 		CreateAssetDeposit,
+		AssetMetadataDeposit,
 		EthereumNetwork,
 		InboundQueueLocation,
 		ConvertAssetId,
@@ -326,7 +454,9 @@ impl<
 	>
 where
 	CreateAssetCall: Get<CallIndex>,
+	SetAssetMetadataCall: Get<CallIndex>,
 	CreateAssetDeposit: Get<u128>,
+	AssetMetadataDeposit: Convert<u32, u128>,
 	EthereumNetwork: Get<NetworkId>,
 	InboundQueueLocation: Get<InteriorLocation>,
 	ConvertAssetId: MaybeConvert<TokenId, Location>,
@@ -374,6 +504,15 @@ where
 			instructions.push(WithdrawAsset(reserve_withdraw_assets.into()));
 		}
 
+		// Create the first-seen native ERC-20s while the origin is still Ethereum, so that they
+		// exist before the assets are deposited by the XCM sent in the message.
+		if !message.asset_registrations.is_empty() {
+			instructions.extend(Self::make_register_asset_instructions(
+				message.asset_registrations,
+				Self::bridge_owner()?,
+			));
+		}
+
 		// If the message origin is not the gateway proxy contract, set the origin to
 		// the original sender on Ethereum. Important to be before the arbitrary XCM that is
 		// appended to the message on the next line.
@@ -413,11 +552,19 @@ mod tests {
 		pub AssetHubFromEthereum: Location = Location::new(1,[GlobalConsensus(Polkadot),Parachain(1000)]);
 		pub AssetHubUniversalLocation: InteriorLocation = [GlobalConsensus(Polkadot),Parachain(1000)].into();
 		pub const CreateAssetCall: [u8;2] = [53, 0];
+		pub const SetAssetMetadataCall: [u8;2] = [53, 17];
 		pub const CreateAssetDeposit: u128 = 10_000_000_000u128;
 		pub EthereumLocation: Location = Location::new(2,EthereumUniversalLocation::get());
 		pub BridgeHubContext: InteriorLocation = [GlobalConsensus(Polkadot),Parachain(1002)].into();
 	}
 
+	pub struct AssetMetadataDeposit;
+	impl Convert<u32, u128> for AssetMetadataDeposit {
+		fn convert(bytes: u32) -> u128 {
+			1_000_000_000 + 100_000 * bytes as u128
+		}
+	}
+
 	pub struct MockFailedTokenConvert;
 	impl MaybeConvert<TokenId, Location> for MockFailedTokenConvert {
 		fn maybe_convert(_id: TokenId) -> Option<Location> {
@@ -427,7 +574,9 @@ mod tests {
 
 	type Converter = MessageToXcm<
 		CreateAssetCall,
+		SetAssetMetadataCall,
 		CreateAssetDeposit,
+		AssetMetadataDeposit,
 		EthereumNetwork,
 		InboundQueueLocation,
 		LocationIdConvert,
@@ -440,7 +589,9 @@ mod tests {
 
 	type ConverterFailing = MessageToXcm<
 		CreateAssetCall,
+		SetAssetMetadataCall,
 		CreateAssetDeposit,
+		AssetMetadataDeposit,
 		EthereumNetwork,
 		InboundQueueLocation,
 		MockFailedTokenConvert,
@@ -597,6 +748,132 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn test_message_with_token_metadata_creates_asset_before_descending_origin() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let origin: H160 = hex!("29e3b139f4393adda86303fcdaa35f60bb7092bf").into();
+			let native_token_id: H160 = hex!("5615deb798bb3e4dfa0139dfa1b3d433cc23b72f").into();
+			let beneficiary: Location =
+				hex!("908783d8cd24c9e02cee1d26ab9c46d458621ad0150b626c536a40b9df3f09c6").into();
+			let token_value = 3_000_000_000_000u128;
+			let metadata = TokenMetadata {
+				name: b"Wrapped Token".to_vec(),
+				symbol: b"WTK".to_vec(),
+				decimals: 18,
+			};
+			let assets = vec![EthereumAsset::NativeTokenERC20WithMetadata {
+				token_id: native_token_id,
+				value: token_value,
+				metadata: metadata.clone(),
+			}];
+			let instructions =
+				vec![DepositAsset { assets: Wild(AllCounted(1).into()), beneficiary }];
+			let xcm: Xcm<()> = instructions.into();
+			let versioned_xcm = VersionedXcm::V5(xcm);
+
+			let message = Message {
+				gateway: H160::zero(),
+				nonce: 0,
+				origin,
+				assets,
+				xcm: XcmPayload::Raw(versioned_xcm.encode()),
+				claimer: None,
+				// Used to pay the asset creation deposits.
+				value: 1_000_000_000_000u128,
+				execution_fee: 1_000_000_000_000u128,
+				relayer_fee: 0,
+			};
+
+			let result = Converter::convert(message);
+			assert_ok!(result.clone());
+			let instructions: Vec<_> = result.unwrap().into_iter().collect();
+
+			let token_location = Location::new(
+				2,
+				[
+					GlobalConsensus(EthereumNetwork::get()),
+					AccountKey20 { network: None, key: native_token_id.into() },
+				],
+			);
+			let bridge_owner = ExternalConsensusLocationsConverterFor::<
+				AssetHubUniversalLocation,
+				[u8; 32],
+			>::convert_location(&Location::new(
+				2,
+				[GlobalConsensus(EthereumNetwork::get())],
+			))
+			.unwrap();
+			let expected_create_call = (
+				CreateAssetCall::get(),
+				token_location.clone(),
+				MultiAddress::<[u8; 32], ()>::Id(bridge_owner),
+				MINIMUM_DEPOSIT,
+			)
+				.encode();
+			let expected_metadata_call = (
+				SetAssetMetadataCall::get(),
+				token_location.clone(),
+				metadata.name,
+				metadata.symbol,
+				metadata.decimals,
+			)
+				.encode();
+
+			let transacts: Vec<_> = instructions
+				.iter()
+				.enumerate()
+				.filter_map(|(index, instruction)| match instruction {
+					Transact { origin_kind, call, .. } =>
+						Some((index, *origin_kind, call.clone().into_encoded())),
+					_ => None,
+				})
+				.collect();
+			assert_eq!(transacts.len(), 2);
+
+			// The sender pays for the deposits with ether, before the asset is created.
+			let deposit = CreateAssetDeposit::get() + AssetMetadataDeposit::convert(13 + 3);
+			let dot_deposit: Asset = (Location::parent(), deposit).into();
+			let exchange = instructions
+				.iter()
+				.position(|instruction| {
+					instruction ==
+						&ExchangeAsset {
+							give: Wild(AllOf {
+								id: AssetId(EthereumLocation::get()),
+								fun: WildFungible,
+							}),
+							want: dot_deposit.clone().into(),
+							maximal: false,
+						}
+				})
+				.expect("the deposits are paid by the sender; qed");
+			assert_eq!(
+				instructions[exchange + 1],
+				DepositAsset { assets: dot_deposit.into(), beneficiary: bridge_owner.into() }
+			);
+			assert!(exchange < transacts[0].0);
+
+			assert_eq!(transacts[0].1, OriginKind::Xcm);
+			assert_eq!(transacts[0].2, expected_create_call);
+			assert_eq!(transacts[1].1, OriginKind::SovereignAccount);
+			assert_eq!(transacts[1].2, expected_metadata_call);
+
+			// The asset is created with the Ethereum origin, before descending into the sender.
+			let descend_into_sender = instructions
+				.iter()
+				.position(|instruction| {
+					instruction ==
+						&DescendOrigin(AccountKey20 { key: origin.into(), network: None }.into())
+				})
+				.expect("origin is not the gateway; qed");
+			assert!(transacts[1].0 < descend_into_sender);
+
+			// The token is reserve deposited like any other native ERC-20.
+			let token: Asset = (token_location, token_value).into();
+			assert!(instructions.contains(&ReserveAssetDeposited(token.into())));
+		});
+	}
+
 	#[test]
 	fn test_message_with_gateway_origin_does_not_descend_origin_into_sender() {
 		let origin: H160 = GatewayAddress::get();
@@ -23,6 +23,13 @@ sol! {
 			bytes32 token_id;
 			uint128 value;
 		}
+		struct AsNativeTokenERC20WithMetadata {
+			address token_id;
+			uint128 value;
+			string name;
+			string symbol;
+			uint8 decimals;
+		}
 		struct EthereumAsset {
 			uint8 kind;
 			bytes data;
@@ -136,6 +143,27 @@ pub enum EthereumAsset {
 		/// The monetary value of the asset
 		value: u128,
 	},
+	/// A native ERC-20 which may not be registered on AH yet. The asset is created in the
+	/// `ForeignAssets` pallet with the given metadata before it is deposited.
+	NativeTokenERC20WithMetadata {
+		/// The native token ID
+		token_id: H160,
+		/// The monetary value of the asset
+		value: u128,
+		/// The metadata of the ERC-20
+		metadata: TokenMetadata,
+	},
+}
+
+/// The metadata of a native ERC-20, as read from the token contract by the Gateway.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo, PartialEq, Eq)]
+pub struct TokenMetadata {
+	/// The name of the token
+	pub name: Vec<u8>,
+	/// The symbol of the token
+	pub symbol: Vec<u8>,
+	/// The number of decimals of the token
+	pub decimals: u8,
 }
 
 #[derive(Copy, Clone, RuntimeDebug)]
@@ -236,6 +264,20 @@ impl TryFrom<&IGatewayV2::EthereumAsset> for EthereumAsset {
 					value: foreign_data.value,
 				}
 			},
+			2 => {
+				let native_data =
+					IGatewayV2::AsNativeTokenERC20WithMetadata::abi_decode_validate(&asset.data)
+						.map_err(|_| MessageDecodeError)?;
+				EthereumAsset::NativeTokenERC20WithMetadata {
+					token_id: H160::from(native_data.token_id.as_ref()),
+					value: native_data.value,
+					metadata: TokenMetadata {
+						name: native_data.name.into_bytes(),
+						symbol: native_data.symbol.into_bytes(),
+						decimals: native_data.decimals,
+					},
+				}
+			},
 			_ => return Err(MessageDecodeError),
 		};
 		Ok(asset)
@@ -2,8 +2,8 @@
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 //! # Outbound V2 primitives
 
-use codec::{Decode, DecodeWithMemTracking, Encode};
-use frame_support::{pallet_prelude::ConstU32, BoundedVec};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{pallet_prelude::ConstU32, BoundedVec, PalletError};
 use scale_info::TypeInfo;
 use sp_core::{RuntimeDebug, H160, H256};
 use sp_std::vec::Vec;
@@ -269,41 +269,79 @@ pub trait SendMessage {
 	fn deliver(ticket: Self::Ticket) -> Result<H256, SendError>;
 }
 
-pub trait GasMeter {
-	/// Measures the maximum amount of gas a command payload will require to *dispatch*, NOT
-	/// including validation & verification.
-	fn maximum_dispatch_gas_used_at_most(command: &Command) -> u64;
+/// An asset escrowed on Polkadot for an outbound message, which is released on Ethereum when the
+/// message is dispatched.
+#[derive(
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum EscrowedAsset {
+	/// An Ethereum-native token (or ether for the zero address), which is unlocked on Ethereum
+	NativeToken {
+		/// Address of the ERC20 token
+		token: H160,
+		/// The amount of tokens
+		amount: u128,
+	},
+	/// A Polkadot-native token, which is minted on Ethereum
+	ForeignToken {
+		/// ID for the token
+		token_id: H256,
+		/// The amount of tokens
+		amount: u128,
+	},
 }
 
-/// A meter that assigns a constant amount of gas for the execution of a command
-///
-/// The gas figures are extracted from this report:
-/// > forge test --match-path test/Gateway.t.sol --gas-report
-///
-/// A healthy buffer is added on top of these figures to account for:
-/// * The EIP-150 63/64 rule
-/// * Future EVM upgrades that may increase gas cost
-pub struct ConstantGasMeter;
-
-impl GasMeter for ConstantGasMeter {
-	fn maximum_dispatch_gas_used_at_most(command: &Command) -> u64 {
-		match command {
-			Command::SetOperatingMode { .. } => 40_000,
-			Command::Upgrade { initializer, .. } => {
-				// total maximum gas must also include the gas used for updating the proxy before
-				// the the initializer is called.
-				50_000 + initializer.maximum_required_gas
-			},
-			Command::UnlockNativeToken { .. } => 200_000,
-			Command::RegisterForeignToken { .. } => 1_200_000,
-			Command::MintForeignToken { .. } => 100_000,
-			Command::CallContract { gas: gas_limit, .. } => *gas_limit,
+impl Command {
+	/// The asset escrowed on Polkadot which is released on Ethereum by the command, if any.
+	pub fn escrowed_asset(&self) -> Option<EscrowedAsset> {
+		match self {
+			Command::UnlockNativeToken { token, amount, .. } =>
+				Some(EscrowedAsset::NativeToken { token: *token, amount: *amount }),
+			Command::MintForeignToken { token_id, amount, .. } =>
+				Some(EscrowedAsset::ForeignToken { token_id: *token_id, amount: *amount }),
+			_ => None,
 		}
 	}
 }
 
-impl GasMeter for () {
-	fn maximum_dispatch_gas_used_at_most(_: &Command) -> u64 {
-		1
-	}
+/// Reasons why an outbound message could not be aborted.
+#[derive(
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	PalletError,
+	TypeInfo,
+)]
+pub enum AbortMessageError {
+	/// There are no escrowed assets for the message, e.g. because it was already aborted.
+	UnknownMessage,
+	/// The message was not sent by the given origin.
+	InvalidOrigin,
+	/// No delivery receipt proving that the dispatch of the message failed on Ethereum was
+	/// submitted. The message may still be delivered.
+	NotFailed,
+}
+
+/// Abort an outbound message whose dispatch failed on Ethereum.
+pub trait AbortMessage {
+	/// Abort the message with the given `nonce` which was sent by `origin`, once a delivery
+	/// receipt proved that its dispatch failed on Ethereum. The Gateway consumed the nonce, so
+	/// the message can never be dispatched again.
+	///
+	/// Returns the assets escrowed for the message, to be returned to the origin.
+	fn abort(origin: H256, nonce: u64) -> Result<Vec<EscrowedAsset>, AbortMessageError>;
 }
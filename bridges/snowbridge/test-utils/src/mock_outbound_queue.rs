@@ -5,10 +5,10 @@ use crate::FAILING_NONCE;
 use snowbridge_core::reward::{AddTip, AddTipError};
 use snowbridge_outbound_queue_primitives::{
 	v1::{Fee, Message as MessageV1, SendMessage as SendMessageV1},
-	v2::{Message, SendMessage},
+	v2::{AbortMessage, AbortMessageError, EscrowedAsset, Message, SendMessage},
 	SendMessageFeeProvider,
 };
-use sp_core::H256;
+use sp_core::{H160, H256};
 
 pub struct MockOkOutboundQueue;
 impl SendMessage for MockOkOutboundQueue {
@@ -42,6 +42,15 @@ impl AddTip for MockOkOutboundQueue {
 	}
 }
 
+impl AbortMessage for MockOkOutboundQueue {
+	fn abort(_origin: H256, nonce: u64) -> Result<Vec<EscrowedAsset>, AbortMessageError> {
+		if nonce == FAILING_NONCE {
+			return Err(AbortMessageError::UnknownMessage)
+		}
+		Ok(vec![EscrowedAsset::NativeToken { token: H160::repeat_byte(1), amount: 1_000 }])
+	}
+}
+
 pub struct MockOkOutboundQueueV1;
 impl SendMessageV1 for MockOkOutboundQueueV1 {
 	type Ticket = ();
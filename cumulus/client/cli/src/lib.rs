@@ -294,6 +294,9 @@ pub struct RunCmd {
 	/// This node connects to the remote nodes following the order they were specified in. If the
 	/// connection fails, it attempts to connect to the next endpoint in the list.
 	///
+	/// The active endpoint is health checked periodically. If it repeatedly answers too slowly or
+	/// lags behind with finality, the node switches to the healthiest other endpoint.
+	///
 	/// Note: This option doesn't stop the node from connecting to the relay chain network but
 	/// reduces bandwidth use.
 	#[arg(
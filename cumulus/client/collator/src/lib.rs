@@ -38,6 +38,7 @@ use std::sync::Arc;
 
 use crate::service::CollatorService;
 
+mod metrics;
 pub mod service;
 
 /// The logging target.
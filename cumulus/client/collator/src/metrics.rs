@@ -0,0 +1,151 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus. If not, see <https://www.gnu.org/licenses/>.
+
+use cumulus_primitives_core::BlockUtilization;
+use prometheus_endpoint::{
+	linear_buckets, register, Gauge, GaugeVec, Histogram, HistogramOpts, Opts, PrometheusError,
+	Registry, U64,
+};
+
+/// Resource usage of a single collation, as observed by the collator.
+pub(crate) struct CollationUsage {
+	/// The relay parent number the block was built on, if known.
+	pub relay_parent_number: Option<u32>,
+	/// The size of the uncompressed PoV block data.
+	pub pov_size: usize,
+	/// The size of the storage proof included in the PoV.
+	pub proof_size: usize,
+	/// The utilization reported by the runtime, if the runtime deposits it.
+	pub utilization: Option<BlockUtilization>,
+	/// Number of upward messages sent by the block.
+	pub upward_messages: usize,
+	/// Number of horizontal messages sent by the block.
+	pub horizontal_messages: usize,
+	/// Number of downward messages processed by the block.
+	pub processed_downward_messages: u32,
+}
+
+/// Metrics about the collations built by the [`CollatorService`](crate::service::CollatorService).
+#[derive(Clone)]
+pub(crate) struct CollatorMetrics {
+	relay_parent_number: Gauge<U64>,
+	pov_size: Gauge<U64>,
+	proof_size: Gauge<U64>,
+	max_pov_size: Gauge<U64>,
+	pov_fill_rate: Histogram,
+	ref_time_used: GaugeVec<U64>,
+	messages: GaugeVec<U64>,
+}
+
+impl CollatorMetrics {
+	pub(crate) fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			relay_parent_number: register(
+				Gauge::new(
+					"cumulus_collator_relay_parent_number",
+					"Relay parent number of the last built collation.",
+				)?,
+				registry,
+			)?,
+			pov_size: register(
+				Gauge::new(
+					"cumulus_collator_pov_size_bytes",
+					"Uncompressed PoV size of the last built collation.",
+				)?,
+				registry,
+			)?,
+			proof_size: register(
+				Gauge::new(
+					"cumulus_collator_proof_size_bytes",
+					"Storage proof size of the last built collation.",
+				)?,
+				registry,
+			)?,
+			max_pov_size: register(
+				Gauge::new(
+					"cumulus_collator_max_pov_size_bytes",
+					"Maximum PoV size allowed by the relay chain for the last built collation.",
+				)?,
+				registry,
+			)?,
+			pov_fill_rate: register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"cumulus_collator_pov_fill_rate",
+						"Fraction of the maximum PoV size used by built collations.",
+					)
+					.buckets(linear_buckets(0.1, 0.1, 10)?),
+				)?,
+				registry,
+			)?,
+			ref_time_used: register(
+				GaugeVec::new(
+					Opts::new(
+						"cumulus_collator_ref_time_used",
+						"Ref time consumed by the last built collation, per dispatch class.",
+					),
+					&["class"],
+				)?,
+				registry,
+			)?,
+			messages: register(
+				GaugeVec::new(
+					Opts::new(
+						"cumulus_collator_messages",
+						"Number of messages serviced by the last built collation.",
+					),
+					&["kind"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	/// Record the resource usage of a freshly built collation.
+	pub(crate) fn on_collation_built(&self, usage: CollationUsage) {
+		if let Some(number) = usage.relay_parent_number {
+			self.relay_parent_number.set(number as u64);
+		}
+
+		self.pov_size.set(usage.pov_size as u64);
+		self.proof_size.set(usage.proof_size as u64);
+
+		if let Some(utilization) = usage.utilization {
+			self.max_pov_size.set(utilization.max_pov_size as u64);
+			if utilization.max_pov_size > 0 {
+				self.pov_fill_rate
+					.observe(usage.pov_size as f64 / utilization.max_pov_size as f64);
+			}
+
+			for (class, weight) in [
+				("normal", utilization.normal),
+				("operational", utilization.operational),
+				("mandatory", utilization.mandatory),
+			] {
+				self.ref_time_used.with_label_values(&[class]).set(weight.ref_time());
+			}
+		}
+
+		self.messages.with_label_values(&["upward"]).set(usage.upward_messages as u64);
+		self.messages
+			.with_label_values(&["horizontal"])
+			.set(usage.horizontal_messages as u64);
+		self.messages
+			.with_label_values(&["processed_downward"])
+			.set(usage.processed_downward_messages as u64);
+	}
+}
@@ -19,7 +19,10 @@
 //! operations used in parachain consensus/authoring.
 
 use cumulus_client_network::WaitToAnnounce;
-use cumulus_primitives_core::{CollationInfo, CollectCollationInfo, ParachainBlockData};
+use cumulus_primitives_core::{
+	rpsr_digest, CollationInfo, CollectCollationInfo, CumulusDigestItem, ParachainBlockData,
+};
+use prometheus_endpoint::Registry;
 
 use sc_client_api::BlockBackend;
 use sp_api::{ApiExt, ProvideRuntimeApi};
@@ -37,6 +40,8 @@ use futures::channel::oneshot;
 use parking_lot::Mutex;
 use std::sync::Arc;
 
+use crate::metrics::{CollationUsage, CollatorMetrics};
+
 /// The logging target.
 const LOG_TARGET: &str = "cumulus-collator";
 
@@ -83,6 +88,7 @@ pub struct CollatorService<Block: BlockT, BS, RA> {
 	wait_to_announce: Arc<Mutex<WaitToAnnounce<Block>>>,
 	announce_block: Arc<dyn Fn(Block::Hash, Option<Vec<u8>>) + Send + Sync>,
 	runtime_api: Arc<RA>,
+	metrics: Option<CollatorMetrics>,
 }
 
 impl<Block: BlockT, BS, RA> Clone for CollatorService<Block, BS, RA> {
@@ -92,6 +98,7 @@ impl<Block: BlockT, BS, RA> Clone for CollatorService<Block, BS, RA> {
 			wait_to_announce: self.wait_to_announce.clone(),
 			announce_block: self.announce_block.clone(),
 			runtime_api: self.runtime_api.clone(),
+			metrics: self.metrics.clone(),
 		}
 	}
 }
@@ -113,7 +120,25 @@ where
 		let wait_to_announce =
 			Arc::new(Mutex::new(WaitToAnnounce::new(spawner, announce_block.clone())));
 
-		Self { block_status, wait_to_announce, announce_block, runtime_api }
+		Self { block_status, wait_to_announce, announce_block, runtime_api, metrics: None }
+	}
+
+	/// Report metrics about the built collations to the given Prometheus `registry`.
+	///
+	/// Metrics are not collected if `registry` is `None` or registering them fails.
+	pub fn with_metrics(mut self, registry: Option<&Registry>) -> Self {
+		self.metrics = registry.and_then(|registry| {
+			CollatorMetrics::register(registry)
+				.map_err(|e| {
+					tracing::warn!(
+						target: LOG_TARGET,
+						error = ?e,
+						"Failed to register collator metrics.",
+					)
+				})
+				.ok()
+		});
+		self
 	}
 
 	/// Checks the status of the given block hash in the Parachain.
@@ -261,7 +286,14 @@ where
 			.ok()
 			.flatten()?;
 
+		let relay_parent_number =
+			rpsr_digest::extract_relay_parent_storage_root(block.header().digest())
+				.map(|(_, number)| number);
+		let utilization = CumulusDigestItem::find_block_utilization(block.header().digest());
+		let proof_size = compact_proof.encoded_size();
+
 		let block_data = ParachainBlockData::<Block>::new(vec![block], compact_proof);
+		let pov_size = block_data.encoded_size();
 
 		let pov = polkadot_node_primitives::maybe_compress_pov(PoV {
 			block_data: BlockData(if api_version >= 3 {
@@ -280,6 +312,18 @@ where
 			}),
 		});
 
+		if let Some(metrics) = &self.metrics {
+			metrics.on_collation_built(CollationUsage {
+				relay_parent_number,
+				pov_size,
+				proof_size,
+				utilization,
+				upward_messages: collation_info.upward_messages.len(),
+				horizontal_messages: collation_info.horizontal_messages.len(),
+				processed_downward_messages: collation_info.processed_downward_messages,
+			});
+		}
+
 		let upward_messages = collation_info
 			.upward_messages
 			.try_into()
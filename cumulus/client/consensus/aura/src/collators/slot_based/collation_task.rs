@@ -16,7 +16,10 @@
 // along with Cumulus. If not, see <https://www.gnu.org/licenses/>.
 
 use codec::Encode;
-use std::path::PathBuf;
+use std::{
+	collections::{HashMap, HashSet},
+	path::PathBuf,
+};
 
 use cumulus_client_collator::service::ServiceInterface as CollatorServiceInterface;
 use cumulus_relay_chain_interface::RelayChainInterface;
@@ -27,7 +30,7 @@ use polkadot_overseer::Handle as OverseerHandle;
 use polkadot_primitives::{CollatorPair, Id as ParaId};
 
 use cumulus_primitives_core::relay_chain::BlockId;
-use futures::prelude::*;
+use futures::{prelude::*, stream::FuturesUnordered};
 
 use crate::export_pov_to_path;
 use sc_utils::mpsc::TracingUnboundedReceiver;
@@ -63,6 +66,11 @@ pub struct Params<Block: BlockT, RClient, CS> {
 /// collations to the relay chain. It listens for new best relay chain block notifications and
 /// handles collator messages. If our parachain is scheduled on a core and we have a candidate,
 /// the task will build a collation and send it to the relay chain.
+///
+/// The collations are submitted concurrently, so that with elastic scaling the collation for one
+/// core does not have to wait for the submission of the collation for another core. A collation
+/// building on a block whose collation is still being submitted is only submitted after it, so
+/// that the collations of a chain of blocks reach the relay chain in order.
 pub async fn run_collation_task<Block, RClient, CS>(
 	Params {
 		relay_client,
@@ -92,6 +100,12 @@ pub async fn run_collation_task<Block, RClient, CS>(
 	)
 	.await;
 
+	let mut pending_submissions = FuturesUnordered::new();
+	// The blocks whose collations are being submitted.
+	let mut submitting = HashSet::new();
+	// Collations waiting for the submission of the collation of their parent, by parent hash.
+	let mut waiting_for_parent = HashMap::<_, Vec<CollatorMessage<Block>>>::new();
+
 	loop {
 		futures::select! {
 			collator_message = collator_receiver.next() => {
@@ -99,7 +113,33 @@ pub async fn run_collation_task<Block, RClient, CS>(
 					return;
 				};
 
-				handle_collation_message(message, &collator_service, &mut overseer_handle,relay_client.clone(),export_pov.clone()).await;
+				let parent_hash = message.parent_header.hash();
+				if submitting.contains(&parent_hash) {
+					waiting_for_parent.entry(parent_hash).or_default().push(message);
+					continue;
+				}
+
+				submitting.insert(message.parachain_candidate.block.header().hash());
+				pending_submissions.push(handle_collation_message(
+					message,
+					&collator_service,
+					overseer_handle.clone(),
+					relay_client.clone(),
+					export_pov.clone(),
+				));
+			},
+			hash = pending_submissions.select_next_some() => {
+				submitting.remove(&hash);
+				for message in waiting_for_parent.remove(&hash).unwrap_or_default() {
+					submitting.insert(message.parachain_candidate.block.header().hash());
+					pending_submissions.push(handle_collation_message(
+						message,
+						&collator_service,
+						overseer_handle.clone(),
+						relay_client.clone(),
+						export_pov.clone(),
+					));
+				}
 			},
 			block_import_msg = block_import_handle.next().fuse() => {
 				// TODO: Implement me.
@@ -113,13 +153,15 @@ pub async fn run_collation_task<Block, RClient, CS>(
 /// Handle an incoming collation message from the block builder task.
 /// This builds the collation from the [`CollatorMessage`] and submits it to
 /// the collation-generation subsystem of the relay chain.
+///
+/// Returns the hash of the block once the collation was submitted or could not be built.
 async fn handle_collation_message<Block: BlockT, RClient: RelayChainInterface + Clone + 'static>(
 	message: CollatorMessage<Block>,
 	collator_service: &impl CollatorServiceInterface<Block>,
-	overseer_handle: &mut OverseerHandle,
+	mut overseer_handle: OverseerHandle,
 	relay_client: RClient,
 	export_pov: Option<PathBuf>,
-) {
+) -> Block::Hash {
 	let CollatorMessage {
 		parent_header,
 		parachain_candidate,
@@ -136,7 +178,7 @@ async fn handle_collation_message<Block: BlockT, RClient: RelayChainInterface +
 			Some(collation) => collation,
 			None => {
 				tracing::warn!(target: LOG_TARGET, %hash, ?number, ?core_index, "Unable to build collation.");
-				return;
+				return hash;
 			},
 		};
 
@@ -185,4 +227,6 @@ async fn handle_collation_message<Block: BlockT, RClient: RelayChainInterface +
 			"SubmitCollation",
 		)
 		.await;
+
+	hash
 }
@@ -29,7 +29,10 @@ use polkadot_overseer::{ChainApiBackend, RuntimeApiSubsystemClient};
 use polkadot_primitives::{
 	async_backing::AsyncBackingParams,
 	slashing,
-	vstaging::async_backing::{BackingState, Constraints},
+	vstaging::{
+		async_backing::{BackingState, Constraints},
+		BackingGroups,
+	},
 	ApprovalVotingParams, CoreIndex, NodeFeatures,
 };
 use sc_authority_discovery::{AuthorityDiscovery, Error as AuthorityDiscoveryError};
@@ -477,6 +480,10 @@ impl RuntimeApiSubsystemClient for BlockChainRpcClient {
 	async fn para_ids(&self, at: Hash) -> Result<Vec<ParaId>, sp_api::ApiError> {
 		Ok(self.rpc_client.parachain_host_para_ids(at).await?)
 	}
+
+	async fn backing_groups(&self, at: Hash) -> Result<BackingGroups, sp_api::ApiError> {
+		Ok(self.rpc_client.parachain_host_backing_groups(at).await?)
+	}
 }
 
 #[async_trait::async_trait]
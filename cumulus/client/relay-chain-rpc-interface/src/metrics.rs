@@ -16,7 +16,7 @@
 // along with Cumulus. If not, see <https://www.gnu.org/licenses/>.
 
 use prometheus::{Error as PrometheusError, HistogramTimer, Registry};
-use prometheus_endpoint::{HistogramOpts, HistogramVec, Opts};
+use prometheus_endpoint::{CounterVec, Gauge, Histogram, HistogramOpts, HistogramVec, Opts, U64};
 
 /// Gathers metrics about the blockchain RPC client.
 #[derive(Clone)]
@@ -48,3 +48,60 @@ impl RelaychainRpcMetrics {
 		self.rpc_request.with_label_values(&[method]).start_timer()
 	}
 }
+
+/// Gathers metrics about the relay chain RPC endpoints the worker connects to.
+#[derive(Clone)]
+pub(crate) struct RelaychainRpcEndpointMetrics {
+	active_endpoint: Gauge<U64>,
+	endpoint_switches: CounterVec<U64>,
+	health_check_latency: Histogram,
+}
+
+impl RelaychainRpcEndpointMetrics {
+	pub(crate) fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			active_endpoint: prometheus_endpoint::register(
+				Gauge::new(
+					"relay_chain_rpc_interface_active_endpoint",
+					"Index of the relay chain RPC endpoint currently in use",
+				)?,
+				registry,
+			)?,
+			endpoint_switches: prometheus_endpoint::register(
+				CounterVec::new(
+					Opts::new(
+						"relay_chain_rpc_interface_endpoint_switches",
+						"Number of switches to another relay chain RPC endpoint",
+					),
+					&["reason"],
+				)?,
+				registry,
+			)?,
+			health_check_latency: prometheus_endpoint::register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"relay_chain_rpc_interface_health_check_latency",
+						"Latency of the health checks of the active relay chain RPC endpoint",
+					)
+					.buckets(
+						prometheus::exponential_buckets(0.01, 2.0, 10)
+							.expect("function parameters are constant and always valid; qed"),
+					),
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	pub(crate) fn on_connected(&self, index: usize) {
+		self.active_endpoint.set(index as u64);
+	}
+
+	pub(crate) fn on_switch(&self, reason: &str) {
+		self.endpoint_switches.with_label_values(&[reason]).inc();
+	}
+
+	pub(crate) fn on_health_check(&self, latency: std::time::Duration) {
+		self.health_check_latency.observe(latency.as_secs_f64());
+	}
+}
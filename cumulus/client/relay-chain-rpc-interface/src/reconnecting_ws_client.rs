@@ -32,27 +32,47 @@ use jsonrpsee::{
 	},
 	ws_client::WsClientBuilder,
 };
+use prometheus::Registry;
 use sc_rpc_api::chain::ChainApiClient;
 use schnellru::{ByLength, LruMap};
 use sp_runtime::generic::SignedBlock;
-use std::{sync::Arc, time::Duration};
-use tokio::sync::mpsc::{
-	channel as tokio_channel, Receiver as TokioReceiver, Sender as TokioSender,
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
+use tokio::{
+	sync::mpsc::{channel as tokio_channel, Receiver as TokioReceiver, Sender as TokioSender},
+	time::MissedTickBehavior,
 };
 use url::Url;
 
-use crate::rpc_client::{distribute_header, RpcDispatcherMessage};
+use crate::{
+	metrics::RelaychainRpcEndpointMetrics,
+	rpc_client::{distribute_header, RpcDispatcherMessage},
+};
 
 const LOG_TARGET: &str = "reconnecting-websocket-client";
 const DEFAULT_EXTERNAL_RPC_CONN_RETRIES: usize = 5;
 const DEFAULT_SLEEP_TIME_MS_BETWEEN_RETRIES: u64 = 1000;
 const DEFAULT_SLEEP_EXP_BACKOFF_BETWEEN_RETRIES: i32 = 2;
+/// How often the active RPC server is checked for its health.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(6);
+/// Health checks taking longer than this are considered failed.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+/// Maximum latency of a health check for the RPC server to be considered healthy.
+const MAX_HEALTHY_LATENCY: Duration = Duration::from_secs(2);
+/// Maximum number of blocks the finalized block of the RPC server may lag behind the highest
+/// finalized block seen so far for the RPC server to be considered healthy.
+const MAX_HEALTHY_FINALITY_LAG: RelayNumber = 8;
+/// Number of consecutive failed health checks after which we switch to another RPC server.
+const UNHEALTHY_CHECKS_BEFORE_SWITCH: u32 = 3;
 
 /// Worker that should be used in combination with [`RelayChainRpcClient`].
 ///
 /// Must be polled to distribute header notifications to listeners.
 pub struct ReconnectingWebsocketWorker {
 	ws_urls: Vec<String>,
+	metrics: Option<RelaychainRpcEndpointMetrics>,
 	/// Communication channel with the RPC client
 	client_receiver: TokioReceiver<RpcDispatcherMessage>,
 
@@ -84,11 +104,63 @@ fn url_to_string_with_port(url: Url) -> Option<String> {
 /// Manages the active websocket client.
 /// Responsible for creating request futures, subscription streams
 /// and reconnections.
-#[derive(Debug)]
 struct ClientManager {
 	urls: Vec<String>,
 	active_client: Arc<JsonRpcClient>,
 	active_index: usize,
+	/// Health score of every RPC server, lower is better. Increased on failed health checks and
+	/// lost connections, decreased on successful health checks.
+	penalties: Vec<u32>,
+	metrics: Option<RelaychainRpcEndpointMetrics>,
+}
+
+/// Why we switch to another RPC server.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SwitchReason {
+	/// The connection to the active RPC server was lost.
+	ConnectionLost,
+	/// The active RPC server failed too many consecutive health checks.
+	Unhealthy,
+}
+
+impl SwitchReason {
+	fn as_str(&self) -> &'static str {
+		match self {
+			Self::ConnectionLost => "connection_lost",
+			Self::Unhealthy => "unhealthy",
+		}
+	}
+}
+
+/// Result of a health check of an RPC server.
+struct HealthCheck {
+	/// The index of the checked RPC server.
+	index: usize,
+	/// The time it took to answer the health check.
+	latency: Duration,
+	/// The number of the finalized block reported by the RPC server, `None` if the check failed.
+	finalized_number: Option<RelayNumber>,
+}
+
+impl HealthCheck {
+	/// Whether the RPC server answered quickly enough and keeps up with finality.
+	fn is_healthy(&self, highest_finalized_number: RelayNumber) -> bool {
+		self.latency <= MAX_HEALTHY_LATENCY &&
+			self.finalized_number.map_or(false, |number| {
+				highest_finalized_number.saturating_sub(number) <= MAX_HEALTHY_FINALITY_LAG
+			})
+	}
+}
+
+/// The order in which to try the RPC servers when switching away from `active_index`.
+///
+/// RPC servers with lower penalties come first. Ties are broken by the order of the RPC servers
+/// in the list, starting after the active one, which comes last.
+fn failover_order(active_index: usize, penalties: &[u32]) -> Vec<usize> {
+	let len = penalties.len();
+	let mut order = (1..=len).map(|offset| (active_index + offset) % len).collect::<Vec<_>>();
+	order.sort_by_key(|index| penalties[*index]);
+	order
 }
 
 struct RelayChainSubscriptions {
@@ -99,26 +171,26 @@ struct RelayChainSubscriptions {
 
 /// Try to find a new RPC server to connect to. Uses a naive retry
 /// logic that does an exponential backoff in between iterations
-/// through all URLs from the list. It uses a constant to tell how
-/// many iterations of connection attempts to all URLs we allow. We
-/// return early when a connection is made.
+/// through all URLs from the list, in the given `order`. It uses a
+/// constant to tell how many iterations of connection attempts to all
+/// URLs we allow. We return early when a connection is made.
 async fn connect_next_available_rpc_server(
 	urls: &Vec<String>,
-	starting_position: usize,
+	order: Vec<usize>,
 ) -> Result<(usize, Arc<JsonRpcClient>), ()> {
-	tracing::debug!(target: LOG_TARGET, starting_position, "Connecting to RPC server.");
+	tracing::debug!(target: LOG_TARGET, ?order, "Connecting to RPC server.");
 
 	let mut prev_iteration: u32 = 0;
-	for (counter, url) in urls
+	for (counter, index) in order
 		.iter()
+		.copied()
 		.cycle()
-		.skip(starting_position)
-		.take(urls.len() * DEFAULT_EXTERNAL_RPC_CONN_RETRIES)
+		.take(order.len() * DEFAULT_EXTERNAL_RPC_CONN_RETRIES)
 		.enumerate()
 	{
 		// If we reached the end of the urls list, backoff before retrying
 		// connections to the entire list once more.
-		let Ok(current_iteration) = (counter / urls.len()).try_into() else {
+		let Ok(current_iteration) = (counter / order.len()).try_into() else {
 			tracing::error!(target: LOG_TARGET, "Too many connection attempts to the RPC servers, aborting...");
 			break;
 		};
@@ -132,7 +204,7 @@ async fn connect_next_available_rpc_server(
 			prev_iteration = current_iteration;
 		}
 
-		let index = (starting_position + counter) % urls.len();
+		let url = &urls[index];
 		tracing::info!(
 			target: LOG_TARGET,
 			attempt = current_iteration,
@@ -151,22 +223,110 @@ async fn connect_next_available_rpc_server(
 }
 
 impl ClientManager {
-	pub async fn new(urls: Vec<String>) -> Result<Self, ()> {
+	pub async fn new(
+		urls: Vec<String>,
+		metrics: Option<RelaychainRpcEndpointMetrics>,
+	) -> Result<Self, ()> {
 		if urls.is_empty() {
 			return Err(())
 		}
-		let active_client = connect_next_available_rpc_server(&urls, 0).await?;
-		Ok(Self { urls, active_client: active_client.1, active_index: active_client.0 })
+		let (active_index, active_client) =
+			connect_next_available_rpc_server(&urls, (0..urls.len()).collect()).await?;
+		if let Some(metrics) = &metrics {
+			metrics.on_connected(active_index);
+		}
+		let penalties = vec![0; urls.len()];
+		Ok(Self { urls, active_client, active_index, penalties, metrics })
 	}
 
-	pub async fn connect_to_new_rpc_server(&mut self) -> Result<(), ()> {
-		let new_active =
-			connect_next_available_rpc_server(&self.urls, self.active_index + 1).await?;
-		self.active_client = new_active.1;
-		self.active_index = new_active.0;
+	pub async fn connect_to_new_rpc_server(&mut self, reason: SwitchReason) -> Result<(), ()> {
+		if reason == SwitchReason::ConnectionLost {
+			self.penalties[self.active_index] = self.penalties[self.active_index].saturating_add(1);
+		}
+		let previous_index = self.active_index;
+		let (active_index, active_client) = connect_next_available_rpc_server(
+			&self.urls,
+			failover_order(self.active_index, &self.penalties),
+		)
+		.await?;
+		self.active_client = active_client;
+		self.active_index = active_index;
+
+		tracing::warn!(
+			target: LOG_TARGET,
+			from = %self.urls[previous_index],
+			to = %self.urls[active_index],
+			reason = reason.as_str(),
+			"Switched relay chain RPC server.",
+		);
+		if let Some(metrics) = &self.metrics {
+			metrics.on_switch(reason.as_str());
+			metrics.on_connected(active_index);
+		}
 		Ok(())
 	}
 
+	/// Create a future checking the latency and the finalized block of the active RPC server.
+	fn create_health_check(&self) -> BoxFuture<'static, HealthCheck> {
+		let client = self.active_client.clone();
+		let index = self.active_index;
+		async move {
+			let started = Instant::now();
+			let finalized_number = tokio::time::timeout(HEALTH_CHECK_TIMEOUT, async {
+				let client = &*client;
+				let hash = <JsonRpcClient as ChainApiClient<
+					RelayNumber,
+					RelayHash,
+					RelayHeader,
+					SignedBlock<RelayBlock>,
+				>>::finalized_head(client)
+				.await?;
+				let header = <JsonRpcClient as ChainApiClient<
+					RelayNumber,
+					RelayHash,
+					RelayHeader,
+					SignedBlock<RelayBlock>,
+				>>::header(client, Some(hash))
+				.await?;
+				Ok::<_, JsonRpseeError>(header.map(|header| header.number))
+			})
+			.await
+			.ok()
+			.and_then(|result| result.ok())
+			.flatten();
+			HealthCheck { index, latency: started.elapsed(), finalized_number }
+		}
+		.boxed()
+	}
+
+	/// Record the result of a health check of the active RPC server.
+	///
+	/// Returns whether the RPC server is healthy.
+	fn on_health_check(
+		&mut self,
+		check: &HealthCheck,
+		highest_finalized_number: RelayNumber,
+	) -> bool {
+		if let Some(metrics) = &self.metrics {
+			metrics.on_health_check(check.latency);
+		}
+
+		let healthy = check.is_healthy(highest_finalized_number);
+		let penalty = &mut self.penalties[check.index];
+		*penalty = if healthy { penalty.saturating_sub(1) } else { penalty.saturating_add(1) };
+		if !healthy {
+			tracing::warn!(
+				target: LOG_TARGET,
+				url = %self.urls[check.index],
+				latency = ?check.latency,
+				finalized_number = ?check.finalized_number,
+				highest_finalized_number,
+				"Relay chain RPC server failed health check.",
+			);
+		}
+		healthy
+	}
+
 	async fn get_subscriptions(&self) -> Result<RelayChainSubscriptions, JsonRpseeError> {
 		let import_subscription = <JsonRpcClient as ChainApiClient<
 			RelayNumber,
@@ -258,19 +418,32 @@ impl ClientManager {
 
 enum ConnectionStatus {
 	Connected,
-	ReconnectRequired(Option<RpcDispatcherMessage>),
+	ReconnectRequired(Option<RpcDispatcherMessage>, SwitchReason),
 }
 
 impl ReconnectingWebsocketWorker {
 	/// Create new worker. Returns the worker and a channel to register new listeners.
 	pub async fn new(
 		urls: Vec<Url>,
+		prometheus_registry: Option<&Registry>,
 	) -> (ReconnectingWebsocketWorker, TokioSender<RpcDispatcherMessage>) {
 		let urls = urls.into_iter().filter_map(url_to_string_with_port).collect();
+		let metrics = prometheus_registry.and_then(|registry| {
+			RelaychainRpcEndpointMetrics::register(registry)
+				.map_err(|err| {
+					tracing::warn!(
+						target: LOG_TARGET,
+						error = %err,
+						"Unable to instantiate the RPC endpoint metrics, continuing w/o metrics setup."
+					);
+				})
+				.ok()
+		});
 
 		let (tx, rx) = tokio_channel(100);
 		let worker = ReconnectingWebsocketWorker {
 			ws_urls: urls,
+			metrics,
 			client_receiver: rx,
 			imported_header_listeners: Vec::new(),
 			finalized_header_listeners: Vec::new(),
@@ -287,21 +460,23 @@ impl ReconnectingWebsocketWorker {
 			BoxFuture<'static, Result<(), RpcDispatcherMessage>>,
 		>,
 		first_failed_request: Option<RpcDispatcherMessage>,
+		reason: SwitchReason,
 	) -> Result<RelayChainSubscriptions, String> {
 		let mut requests_to_retry = Vec::new();
 		if let Some(req @ RpcDispatcherMessage::Request(_, _, _)) = first_failed_request {
 			requests_to_retry.push(req);
 		}
 
-		// At this point, all pending requests will return an error since the
-		// websocket connection is dead. So draining the pending requests should be fast.
+		// At this point, all pending requests will return an error if the websocket connection is
+		// dead, or complete on the unhealthy but alive connection. So draining the pending requests
+		// should be fast.
 		while !pending_requests.is_empty() {
 			if let Some(Err(req)) = pending_requests.next().await {
 				requests_to_retry.push(req);
 			}
 		}
 
-		if client_manager.connect_to_new_rpc_server().await.is_err() {
+		if client_manager.connect_to_new_rpc_server(reason).await.is_err() {
 			return Err("Unable to find valid external RPC server, shutting down.".to_string())
 		};
 
@@ -330,11 +505,17 @@ impl ReconnectingWebsocketWorker {
 	/// - Find a new valid RPC server to connect to in case the websocket connection is terminated.
 	///   If the worker is not able to connect to an RPC server from the list, the worker shuts
 	///   down.
+	/// - Periodically check the latency and finality lag of the active RPC server and switch to
+	///   another RPC server if it fails too many consecutive health checks.
 	pub async fn run(mut self) {
 		let mut pending_requests = FuturesUnordered::new();
+		let mut health_checks = FuturesUnordered::new();
+		let mut health_check_interval = tokio::time::interval(HEALTH_CHECK_INTERVAL);
+		health_check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+		let mut unhealthy_checks = 0;
 
 		let urls = std::mem::take(&mut self.ws_urls);
-		let Ok(mut client_manager) = ClientManager::new(urls).await else {
+		let Ok(mut client_manager) = ClientManager::new(urls, self.metrics.take()).await else {
 			tracing::error!(target: LOG_TARGET, "No valid RPC url found. Stopping RPC worker.");
 			return
 		};
@@ -348,17 +529,21 @@ impl ReconnectingWebsocketWorker {
 		let mut last_seen_finalized_num: RelayNumber = 0;
 		loop {
 			// This branch is taken if the websocket connection to the current RPC server is closed.
-			if let ConnectionStatus::ReconnectRequired(maybe_failed_request) = should_reconnect {
+			if let ConnectionStatus::ReconnectRequired(maybe_failed_request, reason) =
+				should_reconnect
+			{
 				match self
 					.handle_reconnect(
 						&mut client_manager,
 						&mut pending_requests,
 						maybe_failed_request,
+						reason,
 					)
 					.await
 				{
 					Ok(new_subscriptions) => {
 						subscriptions = new_subscriptions;
+						unhealthy_checks = 0;
 					},
 					Err(message) => {
 						tracing::error!(
@@ -393,7 +578,24 @@ impl ReconnectingWebsocketWorker {
 				},
 				should_retry = pending_requests.next(), if !pending_requests.is_empty() => {
 					if let Some(Err(req)) = should_retry {
-						should_reconnect = ConnectionStatus::ReconnectRequired(Some(req));
+						should_reconnect = ConnectionStatus::ReconnectRequired(Some(req), SwitchReason::ConnectionLost);
+					}
+				},
+				_ = health_check_interval.tick(), if health_checks.is_empty() => {
+					health_checks.push(client_manager.create_health_check());
+				},
+				Some(check) = health_checks.next(), if !health_checks.is_empty() => {
+					// Ignore checks of an RPC server we already switched away from.
+					if check.index != client_manager.active_index {
+						continue;
+					}
+					if client_manager.on_health_check(&check, last_seen_finalized_num) {
+						unhealthy_checks = 0;
+					} else {
+						unhealthy_checks += 1;
+					}
+					if unhealthy_checks >= UNHEALTHY_CHECKS_BEFORE_SWITCH && client_manager.urls.len() > 1 {
+						should_reconnect = ConnectionStatus::ReconnectRequired(None, SwitchReason::Unhealthy);
 					}
 				},
 				import_event = subscriptions.import_subscription.next() => {
@@ -414,11 +616,11 @@ impl ReconnectingWebsocketWorker {
 						},
 						None => {
 							tracing::error!(target: LOG_TARGET, "Subscription closed.");
-							should_reconnect = ConnectionStatus::ReconnectRequired(None);
+							should_reconnect = ConnectionStatus::ReconnectRequired(None, SwitchReason::ConnectionLost);
 						},
 						Some(Err(error)) => {
 							tracing::error!(target: LOG_TARGET, ?error, "Error in RPC subscription.");
-							should_reconnect = ConnectionStatus::ReconnectRequired(None);
+							should_reconnect = ConnectionStatus::ReconnectRequired(None, SwitchReason::ConnectionLost);
 						},
 					}
 				},
@@ -427,11 +629,11 @@ impl ReconnectingWebsocketWorker {
 						Some(Ok(header)) => distribute_header(header, &mut self.best_header_listeners),
 						None => {
 							tracing::error!(target: LOG_TARGET, "Subscription closed.");
-							should_reconnect = ConnectionStatus::ReconnectRequired(None);
+							should_reconnect = ConnectionStatus::ReconnectRequired(None, SwitchReason::ConnectionLost);
 						},
 						Some(Err(error)) => {
 							tracing::error!(target: LOG_TARGET, ?error, "Error in RPC subscription.");
-							should_reconnect = ConnectionStatus::ReconnectRequired(None);
+							should_reconnect = ConnectionStatus::ReconnectRequired(None, SwitchReason::ConnectionLost);
 						},
 					}
 				}
@@ -451,11 +653,11 @@ impl ReconnectingWebsocketWorker {
 						},
 						None => {
 							tracing::error!(target: LOG_TARGET, "Subscription closed.");
-							should_reconnect = ConnectionStatus::ReconnectRequired(None);
+							should_reconnect = ConnectionStatus::ReconnectRequired(None, SwitchReason::ConnectionLost);
 						},
 						Some(Err(error)) => {
 							tracing::error!(target: LOG_TARGET, ?error, "Error in RPC subscription.");
-							should_reconnect = ConnectionStatus::ReconnectRequired(None);
+							should_reconnect = ConnectionStatus::ReconnectRequired(None, SwitchReason::ConnectionLost);
 						},
 					}
 				}
@@ -468,7 +670,7 @@ impl ReconnectingWebsocketWorker {
 mod test {
 	use std::time::Duration;
 
-	use super::{url_to_string_with_port, ClientManager};
+	use super::{failover_order, url_to_string_with_port, ClientManager, HealthCheck};
 	use jsonrpsee::Methods;
 	use url::Url;
 
@@ -520,9 +722,35 @@ mod test {
 		// Start the client. Not exitting right away with an error means it
 		// is handling gracefully received connections refused while the server
 		// is starting.
-		let res = ClientManager::new(vec![format!("ws://127.0.0.1:{}", port)]).await;
+		let res = ClientManager::new(vec![format!("ws://127.0.0.1:{}", port)], None).await;
 		assert!(res.is_ok());
 
 		server.await.unwrap();
 	}
+
+	#[test]
+	fn failover_order_prefers_healthy_servers() {
+		// Without penalties, the servers after the active one are tried in order.
+		assert_eq!(failover_order(1, &[0, 0, 0, 0]), vec![2, 3, 0, 1]);
+		// Servers with fewer penalties are tried first.
+		assert_eq!(failover_order(1, &[0, 3, 2, 0]), vec![3, 0, 2, 1]);
+		assert_eq!(failover_order(0, &[0]), vec![0]);
+	}
+
+	#[test]
+	fn health_check_works() {
+		let check = |latency_ms, finalized_number| HealthCheck {
+			index: 0,
+			latency: Duration::from_millis(latency_ms),
+			finalized_number,
+		};
+		assert!(check(100, Some(100)).is_healthy(100));
+		assert!(check(100, Some(92)).is_healthy(100));
+		// Finalized block lagging behind.
+		assert!(!check(100, Some(91)).is_healthy(100));
+		// Too slow.
+		assert!(!check(2_001, Some(100)).is_healthy(100));
+		// Failed request.
+		assert!(!check(100, None).is_healthy(100));
+	}
 }
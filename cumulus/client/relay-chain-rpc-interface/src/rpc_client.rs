@@ -37,7 +37,8 @@ use cumulus_primitives_core::{
 		slashing,
 		vstaging::{
 			async_backing::{BackingState, Constraints},
-			CandidateEvent, CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState,
+			BackingGroups, CandidateEvent,
+			CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState,
 			ScrapedOnChainVotes,
 		},
 		ApprovalVotingParams, BlockNumber, CandidateCommitments, CandidateHash, CoreIndex,
@@ -97,7 +98,7 @@ pub async fn create_client_and_start_worker(
 	task_manager: &mut TaskManager,
 	prometheus_registry: Option<&Registry>,
 ) -> RelayChainResult<RelayChainRpcClient> {
-	let (worker, sender) = ReconnectingWebsocketWorker::new(urls).await;
+	let (worker, sender) = ReconnectingWebsocketWorker::new(urls, prometheus_registry).await;
 
 	task_manager
 		.spawn_essential_handle()
@@ -792,6 +793,16 @@ impl RelayChainRpcClient {
 		self.call_remote_runtime_function("ParachainHost_para_ids", at, None::<()>)
 			.await
 	}
+
+	/// Get the backing group assigned to each core and the number of blocks until the groups
+	/// rotate.
+	pub async fn parachain_host_backing_groups(
+		&self,
+		at: RelayHash,
+	) -> Result<BackingGroups, RelayChainError> {
+		self.call_remote_runtime_function("ParachainHost_backing_groups", at, None::<()>)
+			.await
+	}
 }
 
 /// Send `header` through all channels contained in `senders`.
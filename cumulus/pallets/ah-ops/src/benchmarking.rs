@@ -18,6 +18,24 @@ use crate::*;
 use frame_benchmarking::{account, v2::*};
 use frame_support::{dispatch::RawOrigin, traits::Currency};
 
+/// The Relay Chain sovereign child account of `para_id`.
+fn rc_sovereign_account(para_id: u32) -> AccountId32 {
+	let para_id = u16::try_from(para_id).expect("para IDs of sovereign accounts fit into u16");
+	let mut raw = [0u8; 32];
+	raw[0..4].copy_from_slice(b"para");
+	raw[4..6].copy_from_slice(&para_id.encode());
+	raw.into()
+}
+
+/// Gives `who` a free, a reserved and a locked balance to be moved by a claim.
+fn fund_sovereign_account<T: Config>(who: &AccountId32) -> u128 {
+	let ed = <T::Currency as Currency<_>>::minimum_balance();
+	let _ = T::Currency::deposit_creating(who, ed * 10);
+	let _ = T::Currency::reserve(who, ed);
+	T::Currency::set_lock(*b"ahopsben", who, ed, LockWithdrawReasons::all());
+	ed
+}
+
 #[benchmarks]
 pub mod benchmarks {
 	use super::*;
@@ -83,6 +101,46 @@ pub mod benchmarks {
 		assert_eq!(RcCrowdloanReserve::<T>::get((block, para_id, &sender)), None);
 	}
 
+	#[benchmark]
+	fn claim_parachain_sovereign_acc() -> Result<(), BenchmarkError> {
+		let origin = T::SovereignClaimOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let para_id = T::SovereignClaimOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let from = rc_sovereign_account(para_id);
+		let ed = fund_sovereign_account::<T>(&from);
+		let (to, _) = Pallet::<T>::try_translate_rc_sovereign_to_ah(&from)
+			.map_err(|_| "the sovereign account translates")?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, from.clone());
+
+		assert_eq!(<T::Currency as Currency<_>>::total_balance(&from), 0);
+		assert_eq!(T::Currency::reserved_balance(&to), ed);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_parachain_sovereign_derived_acc() -> Result<(), BenchmarkError> {
+		let origin = T::SovereignClaimOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let para_id = T::SovereignClaimOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let parent = rc_sovereign_account(para_id);
+		let index = 0;
+		let from = pallet_utility::derivative_account_id(parent.clone(), index);
+		let ed = fund_sovereign_account::<T>(&from);
+		let (to, _) = Pallet::<T>::try_rc_sovereign_derived_to_ah(&from, &parent, index)
+			.map_err(|_| "the derived account translates")?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, from.clone(), (parent, index));
+
+		assert_eq!(<T::Currency as Currency<_>>::total_balance(&from), 0);
+		assert_eq!(T::Currency::reserved_balance(&to), ed);
+		Ok(())
+	}
+
 	#[cfg(feature = "std")]
 	pub fn test_unreserve_lease_deposit<T: Config>() {
 		_unreserve_lease_deposit::<T>(true)
@@ -97,4 +155,14 @@ pub mod benchmarks {
 	pub fn test_unreserve_crowdloan_reserve<T: Config>() {
 		_unreserve_crowdloan_reserve::<T>(true)
 	}
+
+	#[cfg(feature = "std")]
+	pub fn test_claim_parachain_sovereign_acc<T: Config>() -> Result<(), BenchmarkError> {
+		_claim_parachain_sovereign_acc::<T>(true)
+	}
+
+	#[cfg(feature = "std")]
+	pub fn test_claim_parachain_sovereign_derived_acc<T: Config>() -> Result<(), BenchmarkError> {
+		_claim_parachain_sovereign_derived_acc::<T>(true)
+	}
 }
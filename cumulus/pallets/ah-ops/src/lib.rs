@@ -85,6 +85,11 @@ pub mod pallet {
 		/// Access the block number of the Relay Chain.
 		type RcBlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
 
+		/// Origin of a parachain claiming its sovereign accounts, returning the para ID.
+		///
+		/// Usually the origin of a sibling parachain sent through XCM.
+		type SovereignClaimOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = u32>;
+
 		/// The Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -363,6 +368,48 @@ pub mod pallet {
 
 			Self::do_force_unreserve(account, amount, reason).map_err(Into::into)
 		}
+
+		/// Claim the Relay Chain sovereign child account of a parachain as its sibling account.
+		///
+		/// Self-serve version of [`Self::migrate_parachain_sovereign_acc`]. Callable by
+		/// [`Config::SovereignClaimOrigin`] for the parachain that `from` belongs to. The account
+		/// is migrated to the sibling account of the parachain. An event of `SovereignMigrated`
+		/// will be emitted if the account was migrated successfully.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_parachain_sovereign_acc())]
+		pub fn claim_parachain_sovereign_acc(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+		) -> DispatchResult {
+			let claimer = T::SovereignClaimOrigin::ensure_origin(origin)?;
+			let (to, para_id) = Self::try_translate_rc_sovereign_to_ah(&from)?;
+			ensure!(u32::from(para_id) == claimer, Error::<T>::WrongSovereignTranslation);
+
+			Self::do_migrate_parachain_sovereign_derived_acc(&from, &to, None).map_err(Into::into)
+		}
+
+		/// Claim an account derived from a Relay Chain sovereign child account of a parachain.
+		///
+		/// Self-serve version of [`Self::migrate_parachain_sovereign_derived_acc`]. `derivation`
+		/// is the sovereign child account and index that `from` was derived from. Callable by
+		/// [`Config::SovereignClaimOrigin`] for the parachain of that sovereign child account. The
+		/// account is migrated to the account derived with the same index from the sibling
+		/// account of the parachain.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_parachain_sovereign_derived_acc())]
+		pub fn claim_parachain_sovereign_derived_acc(
+			origin: OriginFor<T>,
+			from: T::AccountId,
+			derivation: (T::AccountId, DerivationIndex),
+		) -> DispatchResult {
+			let claimer = T::SovereignClaimOrigin::ensure_origin(origin)?;
+			let (parent, index) = &derivation;
+			let (to, para_id) = Self::try_rc_sovereign_derived_to_ah(&from, parent, *index)?;
+			ensure!(u32::from(para_id) == claimer, Error::<T>::WrongSovereignTranslation);
+
+			Self::do_migrate_parachain_sovereign_derived_acc(&from, &to, Some(derivation))
+				.map_err(Into::into)
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
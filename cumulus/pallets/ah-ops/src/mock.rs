@@ -68,11 +68,16 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ();
+	type ExpectedMoment = ();
+	type AnomalyTolerance = frame_support::traits::ConstU64<0>;
 	type WeightInfo = ();
 }
 
 impl Config for Runtime {
 	type Currency = Balances;
-	type RcBlockNumberProvider = System; // Wrong but unused
+	// Wrong but unused.
+	type RcBlockNumberProvider = System;
+	// Root claims for Bifrost Polkadot.
+	type SovereignClaimOrigin = frame_system::EnsureRootWithSuccess<AccountId32, ConstU32<2030>>;
 	type WeightInfo = ();
 }
@@ -210,3 +210,64 @@ fn translate_sovereign_acc_good() {
 		});
 	});
 }
+
+#[test]
+fn claim_sovereign_acc_requires_para_origin() {
+	use crate::mock::RuntimeOrigin;
+	use frame_support::{assert_noop, assert_ok};
+	use sp_runtime::DispatchError;
+
+	sp_io::TestExternalities::new(Default::default()).execute_with(move || {
+		let balance = 1000000000000000000;
+
+		// Para 2030 index 0 (Bifrost Polkadot derived 0)
+		let from =
+			AccountId32::from_str("14vtfeKAVKh1Jzb3s7e43SqZ3zB5MLsdCxZPoKDxeoCFKLu5").unwrap();
+		let to = AccountId32::from_str("5ETehspFKFNpBbe5DsfuziN6BWq5Qwp1J8qcTQQoAxwa7BsS").unwrap();
+		let parent =
+			AccountId32::from_str("13YMK2eeopZtUNpeHnJ1Ws2HqMQG6Ts9PGCZYGyFbSYoZfcm").unwrap();
+		// Sibling account of para 2030.
+		let sibling =
+			AccountId32::from_str("13cKp89TtYknbyYnqnF6dWN75q5ZosvFSuqzoEVkUAaNR47A").unwrap();
+		// Para 2001 (Bifrost Kusama)
+		let other_para =
+			AccountId32::from_str("5Ec4AhPV91i9yNuiWuNunPf6AQCYDhFTTA4G5QCbtqYApH9E").unwrap();
+
+		<AssetHub as crate::Config>::Currency::mint_into(&from, balance).unwrap();
+		<AssetHub as crate::Config>::Currency::mint_into(&parent, balance).unwrap();
+		<AssetHub as crate::Config>::Currency::mint_into(&other_para, balance).unwrap();
+
+		// Signed origins cannot claim, not even the sibling account itself.
+		assert_noop!(
+			crate::Pallet::<AssetHub>::claim_parachain_sovereign_acc(
+				RuntimeOrigin::signed(sibling.clone()),
+				parent.clone(),
+			),
+			DispatchError::BadOrigin
+		);
+		// The origin of para 2030 cannot claim the accounts of another para.
+		assert_noop!(
+			crate::Pallet::<AssetHub>::claim_parachain_sovereign_acc(
+				RuntimeOrigin::root(),
+				other_para.clone(),
+			),
+			crate::Error::<AssetHub>::WrongSovereignTranslation
+		);
+
+		assert_ok!(crate::Pallet::<AssetHub>::claim_parachain_sovereign_derived_acc(
+			RuntimeOrigin::root(),
+			from.clone(),
+			(parent.clone(), 0),
+		));
+		assert_ok!(crate::Pallet::<AssetHub>::claim_parachain_sovereign_acc(
+			RuntimeOrigin::root(),
+			parent.clone(),
+		));
+
+		assert_eq!(<AssetHub as crate::Config>::Currency::free_balance(&from), 0);
+		assert_eq!(<AssetHub as crate::Config>::Currency::free_balance(&to), balance);
+		assert_eq!(<AssetHub as crate::Config>::Currency::free_balance(&parent), 0);
+		assert_eq!(<AssetHub as crate::Config>::Currency::free_balance(&sibling), balance);
+		assert_eq!(<AssetHub as crate::Config>::Currency::free_balance(&other_para), balance);
+	});
+}
@@ -45,6 +45,8 @@ use sp_consensus_aura::{Slot, SlotDuration};
 /// - 6 second relay chain slots
 /// - Maximum 2 blocks per slot
 /// - Maximum 8 blocks in unincluded segment
+///
+/// The velocity can be lowered at runtime with the [`pallet::BlockProductionThrottle`].
 pub struct FixedVelocityConsensusHook<
 	T,
 	const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
@@ -71,8 +73,7 @@ where
 	/// - When the number of authored blocks exceeds velocity limit
 	/// - When parachain slot is ahead of the calculated slot from relay chain
 	fn on_state_proof(state_proof: &RelayChainStateProof) -> (Weight, UnincludedSegmentCapacity) {
-		// Ensure velocity is non-zero.
-		let velocity = V.max(1);
+		let velocity = Self::velocity();
 		let relay_chain_slot = state_proof.read_slot().expect("failed to read relay chain slot");
 
 		let (relay_chain_slot, authored_in_relay) = match pallet::RelaySlotInfo::<T>::get() {
@@ -111,7 +112,7 @@ where
 			);
 		}
 
-		let weight = T::DbWeight::get().reads(1);
+		let weight = T::DbWeight::get().reads(2);
 
 		(
 			weight,
@@ -122,6 +123,22 @@ where
 	}
 }
 
+impl<
+		T: pallet::Config,
+		const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
+		const V: u32,
+		const C: u32,
+	> FixedVelocityConsensusHook<T, RELAY_CHAIN_SLOT_DURATION_MILLIS, V, C>
+{
+	/// The velocity `V`, lowered to the [`pallet::BlockProductionThrottle`] if any.
+	fn velocity() -> u32 {
+		// Ensure velocity is non-zero.
+		let velocity = V.max(1);
+		pallet::BlockProductionThrottle::<T>::get()
+			.map_or(velocity, |throttle| throttle.clamp(1, velocity))
+	}
+}
+
 impl<
 		T: pallet::Config + parachain_system::Config,
 		const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
@@ -140,7 +157,7 @@ impl<
 	/// whose state we are querying against, this must always return `true` as long as the slot
 	/// is more recent than the included block itself.
 	pub fn can_build_upon(included_hash: T::Hash, new_slot: Slot) -> bool {
-		let velocity = V.max(1);
+		let velocity = Self::velocity();
 		let (last_slot, authored_so_far) = match pallet::RelaySlotInfo::<T>::get() {
 			None => return true,
 			Some(x) => x,
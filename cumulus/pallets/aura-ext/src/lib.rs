@@ -22,6 +22,10 @@
 //! It is also required that the parachain runtime uses the provided [`BlockExecutor`] to properly
 //! check the constructed block on the relay chain.
 //!
+//! Root can temporarily slow down the block production of the [`FixedVelocityConsensusHook`], e.g.
+//! during a heavy migration, by setting a [`BlockProductionThrottle`] with
+//! [`Pallet::set_block_production_throttle`].
+//!
 //! ```
 //! # struct Runtime;
 //! # struct Executive;
@@ -42,8 +46,10 @@ use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
 pub mod consensus_hook;
 pub mod migration;
 mod test;
+pub mod timestamp;
 
 pub use consensus_hook::FixedVelocityConsensusHook;
+pub use timestamp::RelaySlotTimestampCheck;
 
 type Aura<T> = pallet_aura::Pallet<T>;
 
@@ -90,6 +96,14 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The maximum velocity of the [`FixedVelocityConsensusHook`], i.e. the number of blocks which
+	/// may be authored per relay chain slot.
+	///
+	/// It can only lower the velocity the hook is configured with. If `None`, the configured
+	/// velocity is used.
+	#[pallet::storage]
+	pub type BlockProductionThrottle<T: Config> = StorageValue<_, u32, OptionQuery>;
+
 	/// Current relay chain slot paired with a number of authored blocks.
 	///
 	/// This is updated in [`FixedVelocityConsensusHook::on_state_proof`] with the current relay
@@ -97,6 +111,34 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type RelaySlotInfo<T: Config> = StorageValue<_, (Slot, u32), OptionQuery>;
 
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The throttle would stop the block production.
+		ZeroThrottle,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the [`BlockProductionThrottle`], or remove it with `None`.
+		///
+		/// The throttle takes effect from the next block on. A throttle above the velocity of the
+		/// [`FixedVelocityConsensusHook`] has no effect.
+		///
+		/// The dispatch origin for this call must be Root.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_block_production_throttle(
+			origin: OriginFor<T>,
+			max_velocity: Option<u32>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(max_velocity != Some(0), Error::<T>::ZeroThrottle);
+
+			BlockProductionThrottle::<T>::set(max_velocity);
+			Ok(())
+		}
+	}
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
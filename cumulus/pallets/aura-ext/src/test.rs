@@ -43,6 +43,7 @@ frame_support::construct_runtime!(
 		ParachainSystem: cumulus_pallet_parachain_system,
 		Aura: pallet_aura,
 		AuraExt: crate,
+		Timestamp: pallet_timestamp,
 	}
 );
 
@@ -92,12 +93,18 @@ impl pallet_aura::Config for Test {
 	type DisabledValidators = ();
 	type AllowMultipleBlocksPerSlot = ConstBool<true>;
 	type SlotDuration = TestSlotDuration;
+	type EquivocationReportSystem = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ();
+	type ExpectedMoment = ();
+	type AnomalyTolerance = frame_support::traits::ConstU64<0>;
 	type WeightInfo = ();
 }
 
@@ -124,8 +131,10 @@ mod test {
 	use cumulus_pallet_parachain_system::{
 		Ancestor, ConsensusHook, RelayChainStateProof, UsedBandwidth,
 	};
+	use frame_support::{assert_noop, assert_ok, traits::OnTimestampSet};
 	use rstest::rstest;
 	use sp_core::H256;
+	use sp_runtime::traits::BadOrigin;
 
 	fn set_ancestors() {
 		let mut ancestors = Vec::new();
@@ -324,6 +333,33 @@ mod test {
 		});
 	}
 
+	#[rstest]
+	#[case::slot_start(10, 60_000)]
+	#[case::slot_end(10, 66_000)]
+	#[case::within_drift_before(10, 57_000)]
+	#[case::within_drift_after(10, 69_000)]
+	#[should_panic(expected = "Parachain timestamp is too far in the past: timestamp=56999")]
+	#[case::too_far_in_the_past(10, 56_999)]
+	#[should_panic(expected = "Parachain timestamp is too far in the future: timestamp=69001")]
+	#[case::too_far_in_the_future(10, 69_001)]
+	fn test_timestamp_drift_from_relay_slot(#[case] relay_slot: u64, #[case] timestamp: u64) {
+		type Check = RelaySlotTimestampCheck<Test, 6000, 3000, ()>;
+
+		new_test_ext(1).execute_with(|| {
+			set_relay_slot(relay_slot, 1);
+			Check::on_timestamp_set(timestamp);
+		});
+	}
+
+	#[test]
+	fn test_timestamp_drift_not_checked_without_relay_slot() {
+		type Check = RelaySlotTimestampCheck<Test, 6000, 3000, ()>;
+
+		new_test_ext(1).execute_with(|| {
+			Check::on_timestamp_set(0);
+		});
+	}
+
 	#[test]
 	fn test_can_build_upon_true_when_empty() {
 		type Hook = FixedVelocityConsensusHook<Test, 6000, DEFAULT_TEST_VELOCITY, 1>;
@@ -373,4 +409,64 @@ mod test {
 			assert!(Hook::can_build_upon(H256::repeat_byte(0x2), relay_slot));
 		});
 	}
+
+	#[test]
+	#[should_panic(expected = "authored blocks limit is reached for the slot")]
+	fn test_throttle_limits_velocity() {
+		type Hook = FixedVelocityConsensusHook<Test, 6000, DEFAULT_TEST_VELOCITY, 1>;
+
+		new_test_ext(1).execute_with(|| {
+			BlockProductionThrottle::<Test>::put(1);
+
+			let state_proof = relay_chain_state_proof(10);
+			for authored in 0..=1 {
+				Hook::on_state_proof(&state_proof);
+				assert_slot_info(10, authored + 1);
+			}
+			Hook::on_state_proof(&state_proof);
+		});
+	}
+
+	#[rstest]
+	#[case::no_throttle(None, DEFAULT_TEST_VELOCITY, true)]
+	#[case::throttled(Some(1), DEFAULT_TEST_VELOCITY, false)]
+	#[case::throttled_ok(Some(1), 1, true)]
+	#[case::throttle_above_velocity(Some(5), DEFAULT_TEST_VELOCITY + 1, false)]
+	fn test_can_build_upon_throttled(
+		#[case] throttle: Option<u32>,
+		#[case] authored_in_slot: u32,
+		#[case] expected_result: bool,
+	) {
+		type Hook = FixedVelocityConsensusHook<Test, 6000, DEFAULT_TEST_VELOCITY, 10>;
+
+		new_test_ext(1).execute_with(|| {
+			BlockProductionThrottle::<Test>::set(throttle);
+			set_relay_slot(10, authored_in_slot);
+
+			assert_eq!(
+				Hook::can_build_upon(H256::repeat_byte(0x1), Slot::from(10)),
+				expected_result
+			);
+		});
+	}
+
+	#[test]
+	fn test_set_block_production_throttle() {
+		new_test_ext(1).execute_with(|| {
+			assert_noop!(
+				AuraExt::set_block_production_throttle(RuntimeOrigin::signed(1), Some(1)),
+				BadOrigin
+			);
+			assert_noop!(
+				AuraExt::set_block_production_throttle(RuntimeOrigin::root(), Some(0)),
+				Error::<Test>::ZeroThrottle
+			);
+
+			assert_ok!(AuraExt::set_block_production_throttle(RuntimeOrigin::root(), Some(1)));
+			assert_eq!(BlockProductionThrottle::<Test>::get(), Some(1));
+
+			assert_ok!(AuraExt::set_block_production_throttle(RuntimeOrigin::root(), None));
+			assert_eq!(BlockProductionThrottle::<Test>::get(), None);
+		});
+	}
 }
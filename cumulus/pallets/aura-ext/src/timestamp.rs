@@ -0,0 +1,90 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The definition of a [`RelaySlotTimestampCheck`] that bounds the parachain timestamp by the
+//! relay chain slot.
+use super::{pallet, Aura};
+use core::marker::PhantomData;
+use frame_support::traits::OnTimestampSet;
+
+/// An [`OnTimestampSet`] handler that rejects blocks whose timestamp drifts too far from the time
+/// of the relay chain slot the block is built on.
+///
+/// The AuRa slot bounds alone allow a collator to pick any timestamp within its slot, which may be
+/// far away from the actual time when the parachain block is built on a relay parent. This check
+/// derives the time window of the relay chain slot from the slot stored by the
+/// [`FixedVelocityConsensusHook`](crate::FixedVelocityConsensusHook) and panics if the parachain
+/// timestamp lies more than `MAX_DRIFT_MILLIS` outside of it. After the check `Inner` is notified,
+/// so the handler usually wraps [`pallet_aura::Pallet`].
+///
+/// The check is skipped if no relay chain slot is known, e.g. because a different consensus hook
+/// is used. It requires the `set_validation_data` inherent to be applied before the timestamp
+/// inherent, which is the case when `ParachainSystem` comes before `Timestamp` in the runtime.
+///
+/// # Type Parameters
+/// - `T` - The runtime configuration trait
+/// - `RELAY_CHAIN_SLOT_DURATION_MILLIS` - Duration of relay chain slots in milliseconds
+/// - `MAX_DRIFT_MILLIS` - Maximum distance of the timestamp to the relay chain slot in milliseconds
+/// - `Inner` - The handler to notify after the check
+///
+/// # Example Configuration
+/// ```ignore
+/// type OnTimestampSet = RelaySlotTimestampCheck<Runtime, 6000, 12000>;
+/// ```
+/// This configures:
+/// - 6 second relay chain slots
+/// - Timestamps at most 12 seconds before the start or after the end of the relay chain slot
+pub struct RelaySlotTimestampCheck<
+	T,
+	const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
+	const MAX_DRIFT_MILLIS: u64,
+	Inner = Aura<T>,
+>(PhantomData<(T, Inner)>);
+
+impl<
+		T: pallet::Config,
+		const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
+		const MAX_DRIFT_MILLIS: u64,
+		Inner: OnTimestampSet<T::Moment>,
+	> OnTimestampSet<T::Moment>
+	for RelaySlotTimestampCheck<T, RELAY_CHAIN_SLOT_DURATION_MILLIS, MAX_DRIFT_MILLIS, Inner>
+where
+	<T as pallet_timestamp::Config>::Moment: Into<u64>,
+{
+	fn on_timestamp_set(moment: T::Moment) {
+		if let Some((relay_chain_slot, _)) = pallet::RelaySlotInfo::<T>::get() {
+			let timestamp: u64 = moment.into();
+			let relay_slot_start =
+				u64::from(RELAY_CHAIN_SLOT_DURATION_MILLIS).saturating_mul(*relay_chain_slot);
+			let relay_slot_end =
+				relay_slot_start.saturating_add(RELAY_CHAIN_SLOT_DURATION_MILLIS.into());
+
+			if timestamp.saturating_add(MAX_DRIFT_MILLIS) < relay_slot_start {
+				panic!(
+					"Parachain timestamp is too far in the past: timestamp={timestamp}, relay_slot_start={relay_slot_start}, relay_chain_slot={relay_chain_slot:?}, max_drift={MAX_DRIFT_MILLIS}"
+				);
+			}
+
+			if timestamp > relay_slot_end.saturating_add(MAX_DRIFT_MILLIS) {
+				panic!(
+					"Parachain timestamp is too far in the future: timestamp={timestamp}, relay_slot_end={relay_slot_end}, relay_chain_slot={relay_chain_slot:?}, max_drift={MAX_DRIFT_MILLIS}"
+				);
+			}
+		}
+
+		Inner::on_timestamp_set(moment);
+	}
+}
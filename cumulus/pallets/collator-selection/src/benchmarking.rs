@@ -224,6 +224,19 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_desired_candidates_ramp() -> Result<(), BenchmarkError> {
+		let target: u32 = T::MaxCandidates::get();
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target, 1);
+
+		assert_last_event::<T>(Event::DesiredCandidatesRampSet { target, step: 1 }.into());
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_candidacy_bond(
 		c: Linear<0, { T::MaxCandidates::get() }>,
@@ -62,7 +62,9 @@
 //! exist, i.e. someone is eligible to produce a block.
 //!
 //! When a new session starts, candidates with the highest deposits will be selected in order until
-//! the desired number of collators is reached. Candidates can increase or decrease their deposits
+//! the desired number of collators is reached. Instead of changing `DesiredCandidates` at once, the
+//! `UpdateOrigin` can set a [`DesiredCandidatesRamp`] which moves it towards a target by a fixed
+//! step at the start of every session. Candidates can increase or decrease their deposits
 //! between sessions in order to ensure they receive a slot in the collator list.
 //!
 //! ### Rewards
@@ -78,6 +80,14 @@
 //!
 //! Note: Eventually the Pot distribution may be modified as discussed in [this
 //! issue](https://github.com/paritytech/statemint/issues/21#issuecomment-810481073).
+//!
+//! ### Offences
+//!
+//! The pallet implements [`ReportOffence`](sp_staking::offence::ReportOffence), e.g. for the
+//! equivocations reported by `pallet_aura`. The slash fraction of an offence is slashed from the
+//! deposit of the offending candidate and the candidate is removed from the [`CandidateList`],
+//! unless this would leave fewer than `MinEligibleCollators` collators. Invulnerables are not
+//! punished.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -112,15 +122,19 @@ pub mod pallet {
 			Currency, EnsureOrigin, ExistenceRequirement::KeepAlive, ReservableCurrency,
 			ValidatorRegistration,
 		},
-		BoundedVec, DefaultNoBound, PalletId,
+		BoundedVec, DefaultNoBound, PalletId, StorageHasher,
 	};
 	use frame_system::{pallet_prelude::*, Config as SystemConfig};
+	use pallet_authorship::AuthorStats;
 	use pallet_session::SessionManager;
 	use sp_runtime::{
 		traits::{AccountIdConversion, CheckedSub, Convert, Saturating, Zero},
-		RuntimeDebug,
+		Perbill, RuntimeDebug,
+	};
+	use sp_staking::{
+		offence::{Offence, OffenceError, ReportOffence},
+		SessionIndex,
 	};
-	use sp_staking::SessionIndex;
 
 	/// The in-code storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
@@ -185,6 +199,18 @@ pub mod pallet {
 		/// Validate a user is registered
 		type ValidatorRegistration: ValidatorRegistration<Self::ValidatorId>;
 
+		/// The authored-block statistics of the collators, e.g. `pallet_authorship`.
+		type AuthorStats: AuthorStats<Self::AccountId>;
+
+		/// Minimum number of blocks a candidate must have authored within the window of
+		/// [`Config::AuthorStats`] to keep its candidacy.
+		///
+		/// Zero disables the check. It should be chosen well below the number of blocks a
+		/// collator is expected to author within the window, as candidates that joined the
+		/// collator set recently may not have reached it yet.
+		#[pallet::constant]
+		type MinAuthoredBlocks: Get<u32>;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -208,6 +234,17 @@ pub mod pallet {
 		pub deposit: Balance,
 	}
 
+	/// A gradual change of [`DesiredCandidates`].
+	#[derive(
+		PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+	)]
+	pub struct CandidatesRamp {
+		/// The number of desired candidates to reach.
+		pub target: u32,
+		/// By how much the number of desired candidates changes per session.
+		pub step: u32,
+	}
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -240,12 +277,25 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type DesiredCandidates<T> = StorageValue<_, u32, ValueQuery>;
 
+	/// The ongoing ramp of [`DesiredCandidates`], applied at the start of every session until the
+	/// target is reached.
+	#[pallet::storage]
+	pub type DesiredCandidatesRamp<T> = StorageValue<_, CandidatesRamp, OptionQuery>;
+
 	/// Fixed amount to deposit to become a collator.
 	///
 	/// When a collator calls `leave_intent` they immediately receive the deposit back.
 	#[pallet::storage]
 	pub type CandidacyBond<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// The offences which were reported, by the hash of their kind, time slot and offender, to the
+	/// session they were reported in.
+	///
+	/// Pruned at the start of each session, see [`Pallet::prune_reported_offences`].
+	#[pallet::storage]
+	pub type ReportedOffences<T: Config> =
+		StorageMap<_, Identity, [u8; 32], SessionIndex, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -293,6 +343,10 @@ pub mod pallet {
 		InvulnerableRemoved { account_id: T::AccountId },
 		/// The number of desired candidates was set.
 		NewDesiredCandidates { desired_candidates: u32 },
+		/// A ramp of the number of desired candidates was set.
+		DesiredCandidatesRampSet { target: u32, step: u32 },
+		/// The number of desired candidates was moved one step towards the target of the ramp.
+		DesiredCandidatesRampStepped { desired_candidates: u32, target: u32 },
 		/// The candidacy bond was set.
 		NewCandidacyBond { bond_amount: BalanceOf<T> },
 		/// A new candidate joined.
@@ -301,6 +355,8 @@ pub mod pallet {
 		CandidateBondUpdated { account_id: T::AccountId, deposit: BalanceOf<T> },
 		/// A candidate was removed.
 		CandidateRemoved { account_id: T::AccountId },
+		/// The deposit of a candidate was slashed for an offence.
+		CandidateSlashed { account_id: T::AccountId, amount: BalanceOf<T> },
 		/// An account was replaced in the candidate list by another one.
 		CandidateReplaced { old: T::AccountId, new: T::AccountId, deposit: BalanceOf<T> },
 		/// An account was unable to be added to the Invulnerables because they did not have keys
@@ -344,6 +400,8 @@ pub mod pallet {
 		IdenticalDeposit,
 		/// Cannot lower candidacy bond while occupying a future collator slot in the list.
 		InvalidUnreserve,
+		/// The step of a ramp of the desired candidates must not be zero.
+		ZeroRampStep,
 	}
 
 	#[pallet::hooks]
@@ -449,6 +507,8 @@ pub mod pallet {
 		/// number of running collators could be higher than this figure. Aside from that edge case,
 		/// there should be no other way to have more candidates than the desired number.
 		///
+		/// Cancels any ongoing [`DesiredCandidatesRamp`].
+		///
 		/// The origin for this call must be the `UpdateOrigin`.
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::set_desired_candidates())]
@@ -461,6 +521,7 @@ pub mod pallet {
 			if max > T::MaxCandidates::get() {
 				log::warn!("max > T::MaxCandidates; you might need to run benchmarks again");
 			}
+			DesiredCandidatesRamp::<T>::kill();
 			DesiredCandidates::<T>::put(max);
 			Self::deposit_event(Event::NewDesiredCandidates { desired_candidates: max });
 			Ok(().into())
@@ -809,6 +870,29 @@ pub mod pallet {
 			Self::deposit_event(Event::CandidateReplaced { old: target, new: who, deposit });
 			Ok(Some(T::WeightInfo::take_candidate_slot(length as u32)).into())
 		}
+
+		/// Move the number of desired candidates gradually towards `target`, changing it by `step`
+		/// at the start of every session. This avoids the rush of registrations and the churn of
+		/// the collator set caused by changing it at once.
+		///
+		/// Replaces any ongoing ramp. `target` must not exceed [`Config::MaxCandidates`].
+		///
+		/// The origin for this call must be the `UpdateOrigin`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_desired_candidates_ramp())]
+		pub fn set_desired_candidates_ramp(
+			origin: OriginFor<T>,
+			target: u32,
+			step: u32,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(step > 0, Error::<T>::ZeroRampStep);
+			ensure!(target <= T::MaxCandidates::get(), Error::<T>::TooManyCandidates);
+
+			DesiredCandidatesRamp::<T>::put(CandidatesRamp { target, step });
+			Self::deposit_event(Event::DesiredCandidatesRampSet { target, step });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -849,6 +933,100 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Slash `slash_fraction` of the deposit of the candidate `who` and remove it from the
+		/// candidates, refunding the rest of its deposit.
+		///
+		/// The candidate keeps its (reduced) deposit in the list if removing it would leave fewer
+		/// than [`Config::MinEligibleCollators`] eligible collators. Invulnerables are not
+		/// punished.
+		fn punish_offender(who: &T::AccountId, slash_fraction: Perbill) {
+			let Ok(info) = CandidateList::<T>::try_mutate(|candidates| {
+				candidates
+					.iter()
+					.position(|candidate_info| candidate_info.who == *who)
+					.map(|idx| candidates.remove(idx))
+					.ok_or(())
+			}) else {
+				return
+			};
+
+			let (_, unslashed) = T::Currency::slash_reserved(who, slash_fraction * info.deposit);
+			let amount = (slash_fraction * info.deposit).saturating_sub(unslashed);
+			let deposit = info.deposit.saturating_sub(amount);
+			Self::deposit_event(Event::CandidateSlashed { account_id: who.clone(), amount });
+
+			// The candidate was already removed from the list, keep it if it is needed.
+			let kept = Self::eligible_collators() < T::MinEligibleCollators::get() &&
+				CandidateList::<T>::try_mutate(|candidates| {
+					let pos = candidates
+						.iter()
+						.position(|candidate| candidate.deposit >= deposit)
+						.unwrap_or_else(|| candidates.len());
+					candidates.try_insert(pos, CandidateInfo { who: who.clone(), deposit })
+				})
+				.is_ok();
+			if !kept {
+				T::Currency::unreserve(who, deposit);
+				LastAuthoredBlock::<T>::remove(who);
+				Self::deposit_event(Event::CandidateRemoved { account_id: who.clone() });
+			}
+		}
+
+		/// Remove the [`ReportedOffences`] which were committed before the session preceding
+		/// `session`.
+		///
+		/// Offences can only be reported in the session they were committed in, so older reports
+		/// are not needed to reject duplicates. The reports of the previous session are kept in
+		/// case the session of an offence and of its report differ at the boundary.
+		///
+		/// Returns the weight consumed.
+		fn prune_reported_offences(session: SessionIndex) -> Weight {
+			let mut reads = 0u64;
+			let stale = ReportedOffences::<T>::iter()
+				.inspect(|_| reads.saturating_inc())
+				.filter(|(_, reported_in)| reported_in.saturating_add(1) < session)
+				.map(|(key, _)| key)
+				.collect::<Vec<_>>();
+			for key in &stale {
+				ReportedOffences::<T>::remove(key);
+			}
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), stale.len() as u64)
+		}
+
+		/// The key of an offence in [`ReportedOffences`].
+		fn offence_key<O: Offence<T::AccountId>>(
+			offender: &T::AccountId,
+			time_slot: &O::TimeSlot,
+		) -> [u8; 32] {
+			(O::ID, time_slot, offender).using_encoded(Blake2_256::hash)
+		}
+
+		/// Move [`DesiredCandidates`] one step along the [`DesiredCandidatesRamp`], if any, and
+		/// remove the ramp once its target is reached.
+		///
+		/// Returns the consumed weight.
+		fn step_desired_candidates_ramp() -> Weight {
+			let Some(ramp) = DesiredCandidatesRamp::<T>::get() else {
+				return T::DbWeight::get().reads(1)
+			};
+			let current = DesiredCandidates::<T>::get();
+			let desired_candidates = if current < ramp.target {
+				current.saturating_add(ramp.step).min(ramp.target)
+			} else {
+				current.saturating_sub(ramp.step).max(ramp.target)
+			};
+
+			DesiredCandidates::<T>::put(desired_candidates);
+			if desired_candidates == ramp.target {
+				DesiredCandidatesRamp::<T>::kill();
+			}
+			Self::deposit_event(Event::DesiredCandidatesRampStepped {
+				desired_candidates,
+				target: ramp.target,
+			});
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
 		/// Assemble the current set of candidates and invulnerables into the next collator set.
 		///
 		/// This is done on the fly, as frequent as we are told to do so, as the session manager.
@@ -867,22 +1045,29 @@ pub mod pallet {
 			collators
 		}
 
-		/// Kicks out candidates that did not produce a block in the kick threshold and refunds
-		/// their deposits.
+		/// Kicks out candidates that did not produce a block in the kick threshold, or fewer than
+		/// [`Config::MinAuthoredBlocks`] blocks within the window of [`Config::AuthorStats`], and
+		/// refunds their deposits.
 		///
 		/// Return value is the number of candidates left in the list.
 		pub fn kick_stale_candidates(candidates: impl IntoIterator<Item = T::AccountId>) -> u32 {
 			let now = frame_system::Pallet::<T>::block_number();
 			let kick_threshold = T::KickThreshold::get();
 			let min_collators = T::MinEligibleCollators::get();
+			let min_authored = T::MinAuthoredBlocks::get();
 			candidates
 				.into_iter()
 				.filter_map(|c| {
 					let last_block = LastAuthoredBlock::<T>::get(c.clone());
 					let since_last = now.saturating_sub(last_block);
+					// Candidates within their registration grace period have a future
+					// `LastAuthoredBlock` and are not judged by their statistics yet.
+					let underperforming = min_authored > 0 &&
+						last_block <= now &&
+						T::AuthorStats::authored_blocks(&c).is_some_and(|a| a < min_authored);
 
 					let is_invulnerable = Invulnerables::<T>::get().contains(&c);
-					let is_lazy = since_last >= kick_threshold;
+					let is_lazy = since_last >= kick_threshold || underperforming;
 
 					if is_invulnerable {
 						// They are invulnerable. No reason for them to be in `CandidateList` also.
@@ -918,8 +1103,21 @@ pub mod pallet {
 		/// * The current desired candidate count should not exceed the candidate list capacity.
 		/// * The number of selected candidates together with the invulnerables must be greater than
 		///   or equal to the minimum number of eligible collators.
+		///
+		/// ## `DesiredCandidatesRamp`
+		///
+		/// * The step of an ongoing ramp should not be zero.
+		/// * The target of an ongoing ramp should not exceed the candidate list capacity.
 		#[cfg(any(test, feature = "try-runtime"))]
 		pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+			if let Some(ramp) = DesiredCandidatesRamp::<T>::get() {
+				frame_support::ensure!(ramp.step > 0, "The desired candidates ramp should move.");
+				frame_support::ensure!(
+					ramp.target <= T::MaxCandidates::get(),
+					"Shouldn't ramp to more candidates than the pallet config allows."
+				);
+			}
+
 			let desired_candidates = DesiredCandidates::<T>::get();
 
 			frame_support::ensure!(
@@ -961,6 +1159,38 @@ pub mod pallet {
 		}
 	}
 
+	/// Punish the collators which committed an offence, e.g. an equivocation reported by
+	/// `pallet_aura`.
+	impl<T: Config, O: Offence<T::AccountId>> ReportOffence<T::AccountId, T::AccountId, O>
+		for Pallet<T>
+	{
+		fn report_offence(_reporters: Vec<T::AccountId>, offence: O) -> Result<(), OffenceError> {
+			let offenders = offence.offenders();
+			let time_slot = offence.time_slot();
+
+			if <Self as ReportOffence<_, _, O>>::is_known_offence(&offenders, &time_slot) {
+				return Err(OffenceError::DuplicateReport)
+			}
+
+			let slash_fraction = offence.slash_fraction(offenders.len() as u32);
+			for offender in offenders {
+				ReportedOffences::<T>::insert(
+					Self::offence_key::<O>(&offender, &time_slot),
+					offence.session_index(),
+				);
+				Self::punish_offender(&offender, slash_fraction);
+			}
+
+			Ok(())
+		}
+
+		fn is_known_offence(offenders: &[T::AccountId], time_slot: &O::TimeSlot) -> bool {
+			offenders.iter().all(|offender| {
+				ReportedOffences::<T>::contains_key(Self::offence_key::<O>(offender, time_slot))
+			})
+		}
+	}
+
 	/// Play the role of the session manager.
 	impl<T: Config> SessionManager<T::AccountId> for Pallet<T> {
 		fn new_session(index: SessionIndex) -> Option<Vec<T::AccountId>> {
@@ -983,16 +1213,25 @@ pub mod pallet {
 					.map(|candidate_info| candidate_info.who.clone()),
 			);
 			let removed = candidates_len_before.saturating_sub(active_candidates_count);
+			let ramp_weight = Self::step_desired_candidates_ramp();
 			let result = Self::assemble_collators();
 
+			let mut weight = T::WeightInfo::new_session(removed, candidates_len_before);
+			weight.saturating_accrue(ramp_weight);
+			if T::MinAuthoredBlocks::get() > 0 {
+				weight.saturating_accrue(T::DbWeight::get().reads(candidates_len_before.into()));
+			}
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::WeightInfo::new_session(removed, candidates_len_before),
+				weight,
 				DispatchClass::Mandatory,
 			);
 			Some(result)
 		}
-		fn start_session(_: SessionIndex) {
-			// we don't care.
+		fn start_session(index: SessionIndex) {
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				Self::prune_reported_offences(index),
+				DispatchClass::Mandatory,
+			);
 		}
 		fn end_session(_: SessionIndex) {
 			// we don't care.
@@ -74,12 +74,15 @@ impl FindAuthor<u64> for Author4 {
 impl pallet_authorship::Config for Test {
 	type FindAuthor = Author4;
 	type EventHandler = CollatorSelection;
+	type AuthorStatsWindow = AuthorStatsWindow;
 }
 
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<1>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = ();
 }
 
@@ -89,6 +92,10 @@ impl pallet_aura::Config for Test {
 	type DisabledValidators = ();
 	type AllowMultipleBlocksPerSlot = ConstBool<false>;
 	type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Self>;
+	type EquivocationReportSystem = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 sp_runtime::impl_opaque_keys! {
@@ -142,6 +149,8 @@ impl pallet_session::Config for Test {
 	type WeightInfo = ();
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 ord_parameter_types! {
@@ -150,6 +159,8 @@ ord_parameter_types! {
 
 parameter_types! {
 	pub const PotId: PalletId = PalletId(*b"PotStake");
+	pub static AuthorStatsWindow: u32 = 0;
+	pub static MinAuthoredBlocks: u32 = 0;
 }
 
 pub struct IsRegistered;
@@ -171,6 +182,8 @@ impl Config for Test {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = IdentityCollator;
 	type ValidatorRegistration = IsRegistered;
+	type AuthorStats = Authorship;
+	type MinAuthoredBlocks = MinAuthoredBlocks;
 	type WeightInfo = ();
 }
 
@@ -15,15 +15,17 @@
 
 use crate as collator_selection;
 use crate::{
-	mock::*, CandidacyBond, CandidateInfo, CandidateList, DesiredCandidates, Error, Invulnerables,
-	LastAuthoredBlock,
+	mock::*, CandidacyBond, CandidateInfo, CandidateList, CandidatesRamp, DesiredCandidates,
+	DesiredCandidatesRamp, Error, Invulnerables, LastAuthoredBlock, ReportedOffences,
 };
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::{Currency, OnInitialize},
 };
+use pallet_aura::EquivocationOffence;
 use pallet_balances::Error as BalancesError;
 use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin, BuildStorage};
+use sp_staking::offence::{OffenceError, ReportOffence};
 
 #[test]
 fn basic_setup_works() {
@@ -270,6 +272,110 @@ fn set_desired_candidates_works() {
 	});
 }
 
+#[test]
+fn set_desired_candidates_ramp_works() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_eq!(DesiredCandidates::<Test>::get(), 2);
+
+		// rejects bad origin, a zero step and too many candidates
+		assert_noop!(
+			CollatorSelection::set_desired_candidates_ramp(RuntimeOrigin::signed(1), 5, 2),
+			BadOrigin
+		);
+		assert_noop!(
+			CollatorSelection::set_desired_candidates_ramp(
+				RuntimeOrigin::signed(RootAccount::get()),
+				5,
+				0
+			),
+			Error::<Test>::ZeroRampStep
+		);
+		assert_noop!(
+			CollatorSelection::set_desired_candidates_ramp(
+				RuntimeOrigin::signed(RootAccount::get()),
+				21,
+				2
+			),
+			Error::<Test>::TooManyCandidates
+		);
+
+		assert_ok!(CollatorSelection::set_desired_candidates_ramp(
+			RuntimeOrigin::signed(RootAccount::get()),
+			5,
+			2
+		));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(
+			crate::Event::DesiredCandidatesRampSet { target: 5, step: 2 },
+		));
+		assert_eq!(
+			DesiredCandidatesRamp::<Test>::get(),
+			Some(CandidatesRamp { target: 5, step: 2 })
+		);
+		assert_eq!(DesiredCandidates::<Test>::get(), 2);
+
+		// every session moves one step towards the target
+		initialize_to_block(10);
+		System::assert_has_event(RuntimeEvent::CollatorSelection(
+			crate::Event::DesiredCandidatesRampStepped { desired_candidates: 4, target: 5 },
+		));
+		assert_eq!(DesiredCandidates::<Test>::get(), 4);
+
+		// the last step does not overshoot and ends the ramp
+		initialize_to_block(20);
+		System::assert_has_event(RuntimeEvent::CollatorSelection(
+			crate::Event::DesiredCandidatesRampStepped { desired_candidates: 5, target: 5 },
+		));
+		assert_eq!(DesiredCandidates::<Test>::get(), 5);
+		assert_eq!(DesiredCandidatesRamp::<Test>::get(), None);
+
+		initialize_to_block(30);
+		assert_eq!(DesiredCandidates::<Test>::get(), 5);
+	});
+}
+
+#[test]
+fn desired_candidates_ramp_can_decrease() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorSelection::set_desired_candidates(
+			RuntimeOrigin::signed(RootAccount::get()),
+			7
+		));
+		assert_ok!(CollatorSelection::set_desired_candidates_ramp(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3,
+			3
+		));
+
+		initialize_to_block(10);
+		assert_eq!(DesiredCandidates::<Test>::get(), 4);
+		initialize_to_block(20);
+		assert_eq!(DesiredCandidates::<Test>::get(), 3);
+		assert_eq!(DesiredCandidatesRamp::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn set_desired_candidates_cancels_ramp() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorSelection::set_desired_candidates_ramp(
+			RuntimeOrigin::signed(RootAccount::get()),
+			10,
+			1
+		));
+		assert_ok!(CollatorSelection::set_desired_candidates(
+			RuntimeOrigin::signed(RootAccount::get()),
+			3
+		));
+		assert_eq!(DesiredCandidatesRamp::<Test>::get(), None);
+
+		initialize_to_block(10);
+		assert_eq!(DesiredCandidates::<Test>::get(), 3);
+	});
+}
+
 #[test]
 fn set_candidacy_bond_empty_candidate_list() {
 	new_test_ext().execute_with(|| {
@@ -1497,6 +1603,24 @@ fn kick_mechanism() {
 	});
 }
 
+#[test]
+fn kick_mechanism_with_author_stats() {
+	new_test_ext().execute_with(|| {
+		AuthorStatsWindow::set(20);
+		MinAuthoredBlocks::set(5);
+
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		initialize_to_block(10);
+		assert_eq!(SessionChangeBlock::get(), 10);
+		// 4 authored every block and stays, 3 authored too few blocks once its grace period ended.
+		assert_eq!(CandidateList::<Test>::get().iter().map(|c| c.who).collect::<Vec<_>>(), vec![4]);
+		assert_eq!(pallet_authorship::AuthoredBlocks::<Test>::get(4), 10);
+		// kicked collator gets funds back
+		assert_eq!(Balances::free_balance(3), 100);
+	});
+}
+
 #[test]
 fn should_not_kick_mechanism_too_few() {
 	new_test_ext().execute_with(|| {
@@ -1575,6 +1699,101 @@ fn should_kick_invulnerables_from_candidates_on_session_change() {
 	});
 }
 
+#[test]
+fn report_offence_slashes_and_removes_candidate() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(3), 40));
+
+		// (3 / 6)^2 = 25% of the deposit is slashed.
+		let offence = |offender| EquivocationOffence {
+			slot: 7.into(),
+			session_index: 0,
+			validator_set_count: 6,
+			offender,
+		};
+		assert_ok!(CollatorSelection::report_offence(vec![5], offence(3)));
+		System::assert_has_event(RuntimeEvent::CollatorSelection(crate::Event::CandidateSlashed {
+			account_id: 3,
+			amount: 10,
+		}));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(
+			crate::Event::CandidateRemoved { account_id: 3 },
+		));
+
+		// the rest of the deposit is refunded
+		assert_eq!(Balances::free_balance(3), 90);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(CandidateList::<Test>::get().iter().map(|c| c.who).collect::<Vec<_>>(), vec![4]);
+
+		// the offence can only be reported once
+		assert!(
+			<CollatorSelection as ReportOffence<_, _, EquivocationOffence<u64>>>::is_known_offence(
+				&[3],
+				&7.into()
+			)
+		);
+		assert_noop!(
+			CollatorSelection::report_offence(vec![5], offence(3)),
+			OffenceError::DuplicateReport
+		);
+
+		// invulnerables are not punished
+		assert_ok!(CollatorSelection::report_offence(vec![5], offence(1)));
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Invulnerables::<Test>::get(), vec![1, 2]);
+	});
+}
+
+#[test]
+fn report_offence_keeps_candidate_if_too_few() {
+	new_test_ext().execute_with(|| {
+		Invulnerables::<Test>::kill();
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(3), 40));
+
+		let offence = EquivocationOffence {
+			slot: 7.into(),
+			session_index: 0,
+			validator_set_count: 6,
+			offender: 3,
+		};
+		assert_ok!(CollatorSelection::report_offence(vec![5], offence));
+
+		// 3 is slashed but stays, as it is the last eligible collator
+		assert_eq!(Balances::free_balance(3), 60);
+		assert_eq!(Balances::reserved_balance(3), 30);
+		assert_eq!(CandidateList::<Test>::get(), vec![CandidateInfo { who: 3, deposit: 30 }]);
+	});
+}
+
+#[test]
+fn reported_offences_are_pruned() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+
+		let offence = EquivocationOffence {
+			slot: 7.into(),
+			session_index: 0,
+			validator_set_count: 6,
+			offender: 3,
+		};
+		assert_ok!(CollatorSelection::report_offence(vec![5], offence));
+		assert_eq!(ReportedOffences::<Test>::iter().count(), 1);
+
+		// the reports of the previous session are kept
+		initialize_to_block(10);
+		assert_eq!(ReportedOffences::<Test>::iter().count(), 1);
+
+		// older reports are pruned
+		initialize_to_block(20);
+		assert_eq!(ReportedOffences::<Test>::iter().count(), 0);
+	});
+}
+
 #[test]
 #[should_panic = "duplicate invulnerables in genesis."]
 fn cannot_set_genesis_value_twice() {
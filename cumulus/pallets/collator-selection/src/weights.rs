@@ -30,6 +30,7 @@ pub trait WeightInfo {
 	fn add_invulnerable(_b: u32, _c: u32) -> Weight;
 	fn remove_invulnerable(_b: u32) -> Weight;
 	fn set_desired_candidates() -> Weight;
+	fn set_desired_candidates_ramp() -> Weight;
 	fn set_candidacy_bond(_c: u32, _k: u32) -> Weight;
 	fn register_as_candidate(_c: u32) -> Weight;
 	fn leave_intent(_c: u32) -> Weight;
@@ -49,7 +50,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	fn set_desired_candidates() -> Weight {
-		Weight::from_parts(16_363_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+		Weight::from_parts(16_363_000_u64, 0).saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_desired_candidates_ramp() -> Weight {
+		// Placeholder until the `set_desired_candidates_ramp` benchmark is run.
+		Weight::from_parts(16_512_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	fn set_candidacy_bond(_c: u32, _k: u32) -> Weight {
 		Weight::from_parts(16_840_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
@@ -150,7 +155,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	fn set_desired_candidates() -> Weight {
-		Weight::from_parts(16_363_000_u64, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+		Weight::from_parts(16_363_000_u64, 0).saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_desired_candidates_ramp() -> Weight {
+		// Placeholder until the `set_desired_candidates_ramp` benchmark is run.
+		Weight::from_parts(16_512_000_u64, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	fn set_candidacy_bond(_c: u32, _k: u32) -> Weight {
 		Weight::from_parts(16_840_000_u64, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
@@ -37,8 +37,9 @@ use cumulus_primitives_core::{
 		self,
 		vstaging::{ClaimQueueOffset, CoreSelector, DEFAULT_CLAIM_QUEUE_OFFSET},
 	},
-	AbridgedHostConfiguration, ChannelInfo, ChannelStatus, CollationInfo, GetChannelInfo,
-	ListChannelInfos, MessageSendError, OutboundHrmpMessage, ParaId, PersistedValidationData,
+	AbridgedHostConfiguration, BlockUtilization, ChannelBackpressure, ChannelInfo, ChannelStatus,
+	CollationInfo, CongestionLevel, CumulusDigestItem, GetChannelInfo, ListChannelInfos,
+	MessageSendError, OutboundHrmpMessage, ParaId, PersistedValidationData, QueueCapacity,
 	UpwardMessage, UpwardMessageSender, XcmpMessageHandler, XcmpMessageSource,
 };
 use cumulus_primitives_parachain_inherent::{v0, MessageQueueChain, ParachainInherentData};
@@ -46,7 +47,7 @@ use frame_support::{
 	dispatch::{DispatchClass, DispatchResult},
 	ensure,
 	inherent::{InherentData, InherentIdentifier, ProvideInherent},
-	traits::{Get, HandleMessage},
+	traits::{Get, HandleMessage, PostTransactions},
 	weights::Weight,
 };
 use frame_system::{ensure_none, ensure_root, pallet_prelude::HeaderFor};
@@ -233,6 +234,30 @@ impl<T: frame_system::Config> SelectCore for LookaheadCoreSelector<T> {
 	}
 }
 
+/// Deposits a [`CumulusDigestItem::BlockUtilization`] digest once all transactions were applied.
+///
+/// Collators use the digest to report how much of the block weight and PoV budget a block
+/// consumed. Meant to be configured as `frame_system::Config::PostTransactions`.
+pub struct DepositBlockUtilizationDigest<T>(PhantomData<T>);
+
+impl<T: Config> PostTransactions for DepositBlockUtilizationDigest<T> {
+	fn post_transactions() {
+		let weight = frame_system::Pallet::<T>::block_weight();
+		let max_pov_size = ValidationData::<T>::get().map_or(0, |vfp| vfp.max_pov_size);
+
+		let utilization = BlockUtilization {
+			normal: *weight.get(DispatchClass::Normal),
+			operational: *weight.get(DispatchClass::Operational),
+			mandatory: *weight.get(DispatchClass::Mandatory),
+			max_pov_size,
+		};
+
+		frame_system::Pallet::<T>::deposit_log(
+			CumulusDigestItem::BlockUtilization(utilization).to_digest_item(),
+		);
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -1060,6 +1085,54 @@ impl<T: Config> ListChannelInfos for Pallet<T> {
 	}
 }
 
+impl<T: Config> ChannelBackpressure for Pallet<T> {
+	fn upward_capacity() -> Option<QueueCapacity> {
+		let capacity = RelevantMessagingState::<T>::get()?.relay_dispatch_queue_remaining_capacity;
+		Some(QueueCapacity {
+			remaining_count: capacity.remaining_count,
+			remaining_size: capacity.remaining_size,
+		})
+	}
+
+	fn hrmp_capacity(recipient: ParaId) -> Option<QueueCapacity> {
+		let info = Self::get_channel_info(recipient)?;
+		Some(QueueCapacity {
+			remaining_count: info.max_capacity.saturating_sub(info.msg_count),
+			remaining_size: info.max_total_size.saturating_sub(info.total_size),
+		})
+	}
+
+	fn upward_congestion() -> CongestionLevel {
+		// Like for `get_channel_status`, the state is only missing in the very first block, before
+		// any message could have been sent.
+		let (Some(capacity), Some(host_config)) =
+			(Self::upward_capacity(), HostConfiguration::<T>::get())
+		else {
+			return CongestionLevel::Low
+		};
+		let (max_count, max_size) =
+			(host_config.max_upward_queue_count, host_config.max_upward_queue_size);
+		CongestionLevel::from_usage(
+			max_count.saturating_sub(capacity.remaining_count),
+			max_count,
+			max_size.saturating_sub(capacity.remaining_size),
+			max_size,
+		)
+	}
+
+	fn hrmp_congestion(recipient: ParaId) -> CongestionLevel {
+		match Self::get_channel_info(recipient) {
+			Some(info) => CongestionLevel::from_usage(
+				info.msg_count,
+				info.max_capacity,
+				info.total_size,
+				info.max_total_size,
+			),
+			None => CongestionLevel::Full,
+		}
+	}
+}
+
 impl<T: Config> GetChannelInfo for Pallet<T> {
 	fn get_channel_status(id: ParaId) -> ChannelStatus {
 		// Note, that we are using `relevant_messaging_state` which may be from the previous
@@ -131,6 +131,9 @@ impl pallet_message_queue::Config for Test {
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MaxWeight;
 	type IdleMaxServiceWeight = ();
+	type MaxDeadLetters = ();
+	type DeadLetterDepositor = ();
+	type DeadLetterConsideration = ();
 	type WeightInfo = ();
 }
 
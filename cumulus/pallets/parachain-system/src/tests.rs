@@ -807,6 +807,48 @@ fn hrmp_outbound_respects_used_bandwidth() {
 		);
 }
 
+#[test]
+fn channel_backpressure_reports_remaining_capacity() {
+	let recipient = ParaId::from(400);
+
+	BlockTests::new()
+		.with_relay_sproof_builder(move |_, _, sproof| {
+			sproof.host_config.max_upward_queue_count = 8;
+			sproof.host_config.max_upward_queue_size = 1024;
+			sproof.relay_dispatch_queue_remaining_capacity = Some((4, 1000));
+
+			let channel = sproof.upsert_outbound_channel(recipient);
+			channel.max_capacity = 10;
+			channel.max_total_size = 100;
+			channel.msg_count = 9;
+			channel.total_size = 10;
+		})
+		.add_with_post_test(
+			1,
+			|| {},
+			move || {
+				assert_eq!(
+					ParachainSystem::upward_capacity(),
+					Some(QueueCapacity { remaining_count: 4, remaining_size: 1000 })
+				);
+				assert_eq!(ParachainSystem::upward_congestion(), CongestionLevel::Elevated);
+
+				assert_eq!(
+					ParachainSystem::hrmp_capacity(recipient),
+					Some(QueueCapacity { remaining_count: 1, remaining_size: 90 })
+				);
+				assert_eq!(ParachainSystem::hrmp_congestion(recipient), CongestionLevel::High);
+
+				// There is no channel to other parachains.
+				assert_eq!(ParachainSystem::hrmp_capacity(ParaId::from(500)), None);
+				assert_eq!(
+					ParachainSystem::hrmp_congestion(ParaId::from(500)),
+					CongestionLevel::Full
+				);
+			},
+		);
+}
+
 #[test]
 fn runtime_upgrade_events() {
 	BlockTests::new()
@@ -1622,6 +1664,30 @@ fn deposits_relay_parent_storage_root() {
 	);
 }
 
+#[test]
+fn deposits_block_utilization_digest() {
+	BlockTests::new().add(123, || {
+		System::register_extra_weight_unchecked(
+			Weight::from_parts(1_000, 10),
+			DispatchClass::Operational,
+		);
+
+		DepositBlockUtilizationDigest::<Test>::post_transactions();
+
+		let utilization = CumulusDigestItem::find_block_utilization(&System::digest())
+			.expect("Utilization digest is deposited");
+		assert_eq!(
+			utilization.operational,
+			*System::block_weight().get(DispatchClass::Operational)
+		);
+		assert_eq!(utilization.normal, *System::block_weight().get(DispatchClass::Normal));
+		assert_eq!(
+			utilization.max_pov_size,
+			ValidationData::<Test>::get().expect("Validation data is set").max_pov_size
+		);
+	});
+}
+
 #[test]
 fn ump_fee_factor_increases_and_decreases() {
 	BlockTests::new()
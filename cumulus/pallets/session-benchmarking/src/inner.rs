@@ -59,4 +59,30 @@ mod benchmarks {
 
 		Ok(())
 	}
+
+	#[benchmark]
+	fn set_keys_at() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		frame_system::Pallet::<T>::inc_providers(&caller);
+		let keys = T::Keys::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap();
+		let proof: Vec<u8> = vec![0, 1, 2, 3];
+		pallet_session::Pallet::<T>::set_keys(
+			RawOrigin::Signed(caller.clone()).into(),
+			keys.clone(),
+			proof.clone(),
+		)?;
+		// Worst case: a previously scheduled rotation is replaced.
+		let session_index = CurrentIndex::<T>::get() + 2;
+		pallet_session::Pallet::<T>::set_keys_at(
+			RawOrigin::Signed(caller.clone()).into(),
+			session_index,
+			keys.clone(),
+			proof.clone(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), session_index + 1, keys, proof);
+
+		Ok(())
+	}
 }
@@ -40,6 +40,20 @@ mod benchmarks {
 		Pallet::<T>::update_resume_threshold(RawOrigin::Root, 1);
 	}
 
+	#[benchmark]
+	fn set_delivery_fee_override() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ControllerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let para = 123.into();
+		let fee_override = DeliveryFeeOverride { base_fee: 1, byte_fee: 1 };
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, para, Some(fee_override));
+
+		assert_eq!(DeliveryFeeOverrides::<T>::get(para), Some(fee_override));
+		Ok(())
+	}
+
 	/// Add a XCMP message of `n` bytes to the message queue.
 	///
 	/// The message will be added on a new page and also, the `BookState` will be added
@@ -57,7 +57,7 @@ extern crate alloc;
 
 use alloc::{collections::BTreeSet, vec, vec::Vec};
 use bounded_collections::BoundedBTreeSet;
-use codec::{Decode, DecodeLimit, Encode, MaxEncodedLen};
+use codec::{Decode, DecodeLimit, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use cumulus_primitives_core::{
 	relay_chain::BlockNumber as RelayBlockNumber, ChannelStatus, GetChannelInfo, MessageSendError,
 	ParaId, XcmpMessageFormat, XcmpMessageHandler, XcmpMessageSource,
@@ -77,7 +77,7 @@ use polkadot_runtime_common::xcm_sender::PriceForMessageDelivery;
 use polkadot_runtime_parachains::{FeeTracker, GetMinFeeFactor};
 use scale_info::TypeInfo;
 use sp_core::MAX_POSSIBLE_ALLOCATION;
-use sp_runtime::{FixedU128, RuntimeDebug, SaturatedConversion, WeakBoundedVec};
+use sp_runtime::{FixedPointNumber, FixedU128, RuntimeDebug, SaturatedConversion, WeakBoundedVec};
 use xcm::{latest::prelude::*, VersionedLocation, VersionedXcm, WrapVersion, MAX_XCM_DECODE_DEPTH};
 use xcm_builder::InspectMessageQueues;
 use xcm_executor::traits::ConvertOrigin;
@@ -164,6 +164,8 @@ pub mod pallet {
 		type ControllerOriginConverter: ConvertOrigin<Self::RuntimeOrigin>;
 
 		/// The price for delivering an XCM to a sibling parachain destination.
+		///
+		/// Use [`ExponentialPriceWithOverrides`] to honour the [`DeliveryFeeOverrides`].
 		type PriceForSiblingDelivery: PriceForMessageDelivery<Id = ParaId>;
 
 		/// The weight information of this pallet.
@@ -257,6 +259,28 @@ pub mod pallet {
 				data.validate::<T>()
 			})
 		}
+
+		/// Set or clear the delivery fee override of the channel to a sibling parachain.
+		///
+		/// The override replaces the base and per-byte fee of [`ExponentialPriceWithOverrides`]
+		/// for messages to `para`. The delivery fee factor of the channel still applies.
+		///
+		/// - `origin`: Must pass `ControllerOrigin`.
+		/// - `para`: The sibling parachain.
+		/// - `fee_override`: The new override, or `None` to use the default fees again.
+		#[pallet::call_index(6)]
+		#[pallet::weight((T::WeightInfo::set_delivery_fee_override(), DispatchClass::Operational,))]
+		pub fn set_delivery_fee_override(
+			origin: OriginFor<T>,
+			para: ParaId,
+			fee_override: Option<DeliveryFeeOverride>,
+		) -> DispatchResult {
+			T::ControllerOrigin::ensure_origin(origin)?;
+
+			DeliveryFeeOverrides::<T>::set(para, fee_override);
+			Self::deposit_event(Event::DeliveryFeeOverrideSet { para, fee_override });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -294,6 +318,8 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// An HRMP message was sent to a sibling parachain.
 		XcmpMessageSent { message_hash: XcmHash },
+		/// The delivery fee override of a channel was set or cleared.
+		DeliveryFeeOverrideSet { para: ParaId, fee_override: Option<DeliveryFeeOverride> },
 	}
 
 	#[pallet::error]
@@ -364,6 +390,34 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type DeliveryFeeFactor<T: Config> =
 		StorageMap<_, Twox64Concat, ParaId, FixedU128, ValueQuery, GetMinFeeFactor<Pallet<T>>>;
+
+	/// The delivery fees of channels that differ from the defaults of the price implementation.
+	///
+	/// Only honoured by [`ExponentialPriceWithOverrides`].
+	#[pallet::storage]
+	pub type DeliveryFeeOverrides<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, DeliveryFeeOverride, OptionQuery>;
+}
+
+/// The base and per-byte delivery fee of a channel, replacing the defaults of
+/// [`ExponentialPriceWithOverrides`].
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct DeliveryFeeOverride {
+	/// The fee for every message.
+	pub base_fee: u128,
+	/// The fee for every byte of a message.
+	pub byte_fee: u128,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -1157,3 +1211,24 @@ impl<T: Config> FeeTracker for Pallet<T> {
 		<DeliveryFeeFactor<T>>::set(id, val);
 	}
 }
+
+/// Like [`ExponentialPrice`](polkadot_runtime_common::xcm_sender::ExponentialPrice), but the base
+/// fee `B` and the per-byte fee `M` are replaced by the [`DeliveryFeeOverrides`] of the channel if
+/// there are any.
+///
+/// The price is `(base_fee + byte_fee * message_size) * delivery_fee_factor` in the asset `A`.
+pub struct ExponentialPriceWithOverrides<A, B, M, T>(core::marker::PhantomData<(A, B, M, T)>);
+impl<A: Get<AssetId>, B: Get<u128>, M: Get<u128>, T: Config> PriceForMessageDelivery
+	for ExponentialPriceWithOverrides<A, B, M, T>
+{
+	type Id = ParaId;
+
+	fn price_for_delivery(id: Self::Id, msg: &Xcm<()>) -> Assets {
+		let DeliveryFeeOverride { base_fee, byte_fee } = DeliveryFeeOverrides::<T>::get(id)
+			.unwrap_or(DeliveryFeeOverride { base_fee: B::get(), byte_fee: M::get() });
+		let msg_fee = (msg.encoded_size() as u128).saturating_mul(byte_fee);
+		let fee_sum = base_fee.saturating_add(msg_fee);
+		let amount = Pallet::<T>::get_fee_factor(id).saturating_mul_int(fee_sum);
+		(A::get(), amount).into()
+	}
+}
@@ -39,7 +39,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>, HoldReason},
 		ParachainSystem: cumulus_pallet_parachain_system::{
 			Pallet, Call, Config<T>, Storage, Inherent, Event<T>,
 		},
@@ -218,12 +218,8 @@ parameter_types! {
 	pub const ByteFee: Balance = 1_000_000;
 }
 
-pub type PriceForSiblingParachainDelivery = polkadot_runtime_common::xcm_sender::ExponentialPrice<
-	FeeAssetId,
-	BaseDeliveryFee,
-	ByteFee,
-	XcmpQueue,
->;
+pub type PriceForSiblingParachainDelivery =
+	ExponentialPriceWithOverrides<FeeAssetId, BaseDeliveryFee, ByteFee, Test>;
 
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -1007,6 +1007,59 @@ fn verify_fee_factor_increase_and_decrease() {
 	});
 }
 
+#[test]
+fn delivery_fee_override_works() {
+	let sibling_para_id = ParaId::from(12345);
+	let destination: Location = (Parent, Parachain(sibling_para_id.into())).into();
+	let xcm = Xcm(vec![ClearOrigin; 100]);
+	let fee_override = DeliveryFeeOverride { base_fee: 100_000_000, byte_fee: 2_000_000 };
+
+	new_test_ext().execute_with(|| {
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(sibling_para_id);
+		let delivery_fee = || {
+			let (_, delivery_fees) = validate_send::<XcmpQueue>(destination.clone(), xcm.clone())
+				.expect("message can be sent; qed");
+			let Fungible(delivery_fee_amount) = delivery_fees.inner()[0].fun else {
+				unreachable!("asset is fungible; qed");
+			};
+			delivery_fee_amount
+		};
+		assert_eq!(delivery_fee(), 402_000_000);
+
+		// Only the controller origin can set overrides.
+		assert_noop!(
+			XcmpQueue::set_delivery_fee_override(
+				Origin::signed(1),
+				sibling_para_id,
+				Some(fee_override)
+			),
+			BadOrigin
+		);
+		assert_ok!(XcmpQueue::set_delivery_fee_override(
+			Origin::root(),
+			sibling_para_id,
+			Some(fee_override)
+		));
+		assert_eq!(DeliveryFeeOverrides::<Test>::get(sibling_para_id), Some(fee_override));
+		assert_eq!(delivery_fee(), 304_000_000);
+
+		// The fee factor still applies.
+		DeliveryFeeFactor::<Test>::set(sibling_para_id, FixedU128::from(2));
+		assert_eq!(delivery_fee(), 608_000_000);
+
+		// Other channels are not affected.
+		let other: Location = (Parent, Parachain(2001)).into();
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(2001.into());
+		let (_, delivery_fees) = validate_send::<XcmpQueue>(other, xcm.clone()).unwrap();
+		assert_eq!(delivery_fees.inner()[0].fun, Fungible(402_000_000));
+
+		// Clearing the override restores the default fees.
+		assert_ok!(XcmpQueue::set_delivery_fee_override(Origin::root(), sibling_para_id, None));
+		assert!(!DeliveryFeeOverrides::<Test>::contains_key(sibling_para_id));
+		assert_eq!(delivery_fee(), 804_000_000);
+	});
+}
+
 #[test]
 fn get_messages_works() {
 	new_test_ext().execute_with(|| {
@@ -58,6 +58,7 @@ pub trait WeightInfo {
 	fn take_first_concatenated_xcm() -> Weight;
 	fn on_idle_good_msg() -> Weight;
 	fn on_idle_large_msg() -> Weight;
+	fn set_delivery_fee_override() -> Weight;
 }
 
 /// Weights for `cumulus_pallet_xcmp_queue` using the Substrate node and recommended hardware.
@@ -253,6 +254,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: `XcmpQueue::DeliveryFeeOverrides` (r:0 w:1)
+	/// Proof: `XcmpQueue::DeliveryFeeOverrides` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn set_delivery_fee_override() -> Weight {
+		// Placeholder until the `set_delivery_fee_override` benchmark is run.
+		Weight::from_parts(4_870_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -447,4 +455,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
+	/// Storage: `XcmpQueue::DeliveryFeeOverrides` (r:0 w:1)
+	/// Proof: `XcmpQueue::DeliveryFeeOverrides` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn set_delivery_fee_override() -> Weight {
+		// Placeholder until the `set_delivery_fee_override` benchmark is run.
+		Weight::from_parts(4_870_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -19,12 +19,13 @@
 use alloc::boxed::Box;
 use core::marker::PhantomData;
 use frame_support::traits::{
-	fungible, fungibles, tokens::imbalance::ResolveTo, Contains, ContainsPair, Currency, Defensive,
-	Get, Imbalance, OnUnbalanced, OriginTrait,
+	fungible, fungibles,
+	tokens::imbalance::{ResolveAssetTo, ResolveTo},
+	Contains, ContainsPair, Currency, Defensive, Get, Imbalance, OnUnbalanced, OriginTrait,
 };
 use pallet_asset_tx_payment::HandleCredit;
 use pallet_collator_selection::StakingPotAccountId;
-use sp_runtime::traits::Zero;
+use sp_runtime::{traits::Zero, Perbill, Permill};
 use xcm::latest::{
 	Asset, AssetId, Fungibility, Fungibility::Fungible, Junction, Junctions::Here, Location,
 	Parent, WeightLimit,
@@ -81,6 +82,113 @@ where
 	}
 }
 
+/// Observes how [`SplitFees`] distributed the fees of a transaction.
+pub trait OnFeesSplit<Balance> {
+	/// Called with the fees paid to the staking pot, burned and deposited into the treasury.
+	fn on_fees_split(to_staking_pot: Balance, burned: Balance, to_treasury: Balance);
+}
+
+impl<Balance> OnFeesSplit<Balance> for () {
+	fn on_fees_split(_: Balance, _: Balance, _: Balance) {}
+}
+
+/// Fungible implementation of `OnUnbalanced` that deals with the fees by combining tip and fee and
+/// splitting the result between the staking pot, burning and the `TreasuryAccount`.
+///
+/// An `AuthorShare` of the fees goes to the staking pot and a `BurnShare` is burned, capped at
+/// what is left after the author's share. The remainder is deposited into the `TreasuryAccount`.
+/// An `AuthorShare` of one behaves like [`DealWithFees`]. Reading the shares from dynamic
+/// parameters lets governance change the split without a runtime upgrade. The resulting amounts
+/// are reported to `OnSplit`.
+///
+/// It also handles `fungibles` credits, so the fees paid in other assets and swapped into the
+/// native asset can be split the same way.
+pub struct SplitFees<R, AuthorShare, BurnShare, TreasuryAccount, OnSplit = ()>(
+	PhantomData<(R, AuthorShare, BurnShare, TreasuryAccount, OnSplit)>,
+);
+impl<R, AuthorShare, BurnShare, TreasuryAccount, OnSplit>
+	OnUnbalanced<fungible::Credit<R::AccountId, pallet_balances::Pallet<R>>>
+	for SplitFees<R, AuthorShare, BurnShare, TreasuryAccount, OnSplit>
+where
+	R: pallet_balances::Config + pallet_collator_selection::Config,
+	AccountIdOf<R>: From<polkadot_primitives::AccountId> + Into<polkadot_primitives::AccountId>,
+	<R as frame_system::Config>::RuntimeEvent: From<pallet_balances::Event<R>>,
+	AuthorShare: Get<Permill>,
+	BurnShare: Get<Permill>,
+	TreasuryAccount: Get<AccountIdOf<R>>,
+	OnSplit: OnFeesSplit<R::Balance>,
+{
+	fn on_unbalanceds(
+		mut fees_then_tips: impl Iterator<
+			Item = fungible::Credit<R::AccountId, pallet_balances::Pallet<R>>,
+		>,
+	) {
+		if let Some(mut fees) = fees_then_tips.next() {
+			if let Some(tips) = fees_then_tips.next() {
+				tips.merge_into(&mut fees);
+			}
+			let total = fees.peek();
+			let (to_author, rest) = fees.split(AuthorShare::get() * total);
+			let (to_burn, to_treasury) = rest.split(BurnShare::get() * total);
+			OnSplit::on_fees_split(to_author.peek(), to_burn.peek(), to_treasury.peek());
+			ResolveTo::<StakingPotAccountId<R>, pallet_balances::Pallet<R>>::on_unbalanced(
+				to_author,
+			);
+			// Dropping the credit burns it.
+			drop(to_burn);
+			ResolveTo::<TreasuryAccount, pallet_balances::Pallet<R>>::on_unbalanced(to_treasury);
+		}
+	}
+}
+
+impl<R, F, AuthorShare, BurnShare, TreasuryAccount, OnSplit>
+	OnUnbalanced<fungibles::Credit<R::AccountId, F>>
+	for SplitFees<R, AuthorShare, BurnShare, TreasuryAccount, OnSplit>
+where
+	R: pallet_collator_selection::Config,
+	F: fungibles::Balanced<R::AccountId>,
+	AuthorShare: Get<Permill>,
+	BurnShare: Get<Permill>,
+	TreasuryAccount: Get<AccountIdOf<R>>,
+	OnSplit: OnFeesSplit<F::Balance>,
+{
+	fn on_nonzero_unbalanced(fees: fungibles::Credit<R::AccountId, F>) {
+		let total = fees.peek();
+		let (to_author, rest) = fees.split(AuthorShare::get() * total);
+		let (to_burn, to_treasury) = rest.split(BurnShare::get() * total);
+		OnSplit::on_fees_split(to_author.peek(), to_burn.peek(), to_treasury.peek());
+		ResolveAssetTo::<StakingPotAccountId<R>, F>::on_unbalanced(to_author);
+		// Dropping the credit burns it.
+		drop(to_burn);
+		ResolveAssetTo::<TreasuryAccount, F>::on_unbalanced(to_treasury);
+	}
+}
+
+/// Implementation of `OnUnbalanced` for `pallet_balances::Config::DustRemoval` that deposits a
+/// `TreasuryShare` of the dust into the `TreasuryAccount` and burns the rest.
+///
+/// A share of zero burns all dust, a share of one sends all of it to the treasury. Reading the
+/// share from a dynamic parameter lets governance change it without a runtime upgrade.
+pub struct DustToTreasury<R, TreasuryAccount, TreasuryShare>(
+	PhantomData<(R, TreasuryAccount, TreasuryShare)>,
+);
+impl<R, TreasuryAccount, TreasuryShare>
+	OnUnbalanced<fungible::Credit<R::AccountId, pallet_balances::Pallet<R>>>
+	for DustToTreasury<R, TreasuryAccount, TreasuryShare>
+where
+	R: pallet_balances::Config,
+	TreasuryAccount: Get<AccountIdOf<R>>,
+	TreasuryShare: Get<Perbill>,
+{
+	fn on_nonzero_unbalanced(dust: fungible::Credit<R::AccountId, pallet_balances::Pallet<R>>) {
+		let to_treasury = TreasuryShare::get() * dust.peek();
+		let (to_treasury, to_burn) = dust.split(to_treasury);
+		// Dropping the credit burns it.
+		drop(to_burn);
+		ResolveTo::<TreasuryAccount, pallet_balances::Pallet<R>>::on_unbalanced(to_treasury);
+	}
+}
+
 /// A `HandleCredit` implementation that naively transfers the fees to the block author.
 /// Will drop and burn the assets in case the transfer fails.
 pub struct AssetsToBlockAuthor<R, I>(PhantomData<(R, I)>);
@@ -206,7 +314,7 @@ mod tests {
 	use sp_core::H256;
 	use sp_runtime::{
 		traits::{BlakeTwo256, IdentityLookup},
-		BuildStorage, Perbill,
+		BuildStorage, Perbill, Permill,
 	};
 	use xcm::prelude::*;
 
@@ -217,7 +325,7 @@ mod tests {
 		pub enum Test
 		{
 			System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-			Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+			Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>, HoldReason},
 			CollatorSelection: pallet_collator_selection::{Pallet, Call, Storage, Event<T>},
 		}
 	);
@@ -290,6 +398,8 @@ mod tests {
 		type ValidatorId = <Self as frame_system::Config>::AccountId;
 		type ValidatorIdOf = IdentityCollator;
 		type ValidatorRegistration = IsRegistered;
+		type AuthorStats = ();
+		type MinAuthoredBlocks = ();
 		type KickThreshold = ();
 		type WeightInfo = ();
 	}
@@ -297,6 +407,7 @@ mod tests {
 	impl pallet_authorship::Config for Test {
 		type FindAuthor = OneAuthor;
 		type EventHandler = ();
+		type AuthorStatsWindow = ();
 	}
 
 	pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -329,6 +440,106 @@ mod tests {
 		});
 	}
 
+	parameter_types! {
+		pub const FeesTreasury: AccountId = AccountId::new([3; 32]);
+		pub static FeesAuthorShare: Permill = Permill::one();
+		pub static FeesBurnShare: Permill = Permill::zero();
+	}
+
+	#[test]
+	fn split_fees_splits_fees_and_tips() {
+		new_test_ext().execute_with(|| {
+			type Handler = SplitFees<Test, FeesAuthorShare, FeesBurnShare, FeesTreasury>;
+			let issue = |amount| {
+				<pallet_balances::Pallet<Test> as frame_support::traits::fungible::Balanced<
+					AccountId,
+				>>::issue(amount)
+			};
+			let pot = CollatorSelection::account_id();
+
+			// By default the author gets everything, like `DealWithFees`.
+			Handler::on_unbalanceds(vec![issue(10), issue(20)].into_iter());
+			assert_eq!(Balances::free_balance(&pot), 30);
+			assert_eq!(Balances::total_issuance(), 30);
+
+			// 50% to the author, 20% burned, the remaining 30% to the treasury.
+			FeesAuthorShare::set(Permill::from_percent(50));
+			FeesBurnShare::set(Permill::from_percent(20));
+			Handler::on_unbalanceds(vec![issue(60), issue(40)].into_iter());
+			assert_eq!(Balances::free_balance(&pot), 80);
+			assert_eq!(Balances::free_balance(FeesTreasury::get()), 30);
+			assert_eq!(Balances::total_issuance(), 110);
+
+			// The burned share is capped at what is left after the author's share.
+			FeesBurnShare::set(Permill::from_percent(80));
+			Handler::on_unbalanceds(vec![issue(100)].into_iter());
+			assert_eq!(Balances::free_balance(&pot), 130);
+			assert_eq!(Balances::free_balance(FeesTreasury::get()), 30);
+			assert_eq!(Balances::total_issuance(), 160);
+		});
+	}
+
+	parameter_types! {
+		pub static FeesSplit: Vec<(u64, u64, u64)> = vec![];
+	}
+
+	pub struct RecordSplit;
+	impl OnFeesSplit<u64> for RecordSplit {
+		fn on_fees_split(to_staking_pot: u64, burned: u64, to_treasury: u64) {
+			FeesSplit::mutate(|split| split.push((to_staking_pot, burned, to_treasury)));
+		}
+	}
+
+	#[test]
+	fn split_fees_reports_the_split() {
+		new_test_ext().execute_with(|| {
+			type Handler =
+				SplitFees<Test, FeesAuthorShare, FeesBurnShare, FeesTreasury, RecordSplit>;
+			let issue = |amount| {
+				<pallet_balances::Pallet<Test> as frame_support::traits::fungible::Balanced<
+					AccountId,
+				>>::issue(amount)
+			};
+
+			FeesAuthorShare::set(Permill::from_percent(50));
+			FeesBurnShare::set(Permill::from_percent(20));
+			Handler::on_unbalanceds(vec![issue(60), issue(40)].into_iter());
+			assert_eq!(FeesSplit::get(), vec![(50, 20, 30)]);
+		});
+	}
+
+	parameter_types! {
+		pub const DustTreasury: AccountId = AccountId::new([2; 32]);
+		pub static DustTreasuryShare: Perbill = Perbill::zero();
+	}
+
+	#[test]
+	fn dust_to_treasury_splits_dust() {
+		new_test_ext().execute_with(|| {
+			type Handler = DustToTreasury<Test, DustTreasury, DustTreasuryShare>;
+			let issue = |amount| {
+				<pallet_balances::Pallet<Test> as frame_support::traits::fungible::Balanced<
+					AccountId,
+				>>::issue(amount)
+			};
+
+			// All dust is burned by default.
+			Handler::on_unbalanced(issue(100));
+			assert_eq!(Balances::free_balance(DustTreasury::get()), 0);
+			assert_eq!(Balances::total_issuance(), 0);
+
+			DustTreasuryShare::set(Perbill::from_percent(30));
+			Handler::on_unbalanced(issue(100));
+			assert_eq!(Balances::free_balance(DustTreasury::get()), 30);
+			assert_eq!(Balances::total_issuance(), 30);
+
+			DustTreasuryShare::set(Perbill::one());
+			Handler::on_unbalanced(issue(100));
+			assert_eq!(Balances::free_balance(DustTreasury::get()), 130);
+			assert_eq!(Balances::total_issuance(), 130);
+		});
+	}
+
 	#[test]
 	fn assets_from_filters_correctly() {
 		parameter_types! {
@@ -20,6 +20,8 @@ use core::marker::PhantomData;
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
 use frame_support::traits::{QueueFootprint, QueuePausedQuery};
 use pallet_message_queue::OnQueueChanged;
+use sp_runtime::traits::Convert;
+use xcm_executor::traits::ConvertLocation;
 
 /// Narrow the scope of the `Inner` query from `AggregateMessageOrigin` to `ParaId`.
 ///
@@ -48,8 +50,28 @@ impl<Inner: OnQueueChanged<ParaId>> OnQueueChanged<AggregateMessageOrigin>
 
 /// Convert a sibling `ParaId` to an `AggregateMessageOrigin`.
 pub struct ParaIdToSibling;
-impl sp_runtime::traits::Convert<ParaId, AggregateMessageOrigin> for ParaIdToSibling {
+impl Convert<ParaId, AggregateMessageOrigin> for ParaIdToSibling {
 	fn convert(para_id: ParaId) -> AggregateMessageOrigin {
 		AggregateMessageOrigin::Sibling(para_id)
 	}
 }
+
+/// Convert a `Sibling` origin to the sovereign account of the sibling with `LocationToAccountId`,
+/// e.g. to let it pay for the dead letters of its queue.
+///
+/// All non-`Sibling` variants have no account.
+pub struct SiblingSovereignAccount<AccountId, LocationToAccountId>(
+	PhantomData<(AccountId, LocationToAccountId)>,
+);
+impl<AccountId, LocationToAccountId: ConvertLocation<AccountId>>
+	Convert<AggregateMessageOrigin, Option<AccountId>>
+	for SiblingSovereignAccount<AccountId, LocationToAccountId>
+{
+	fn convert(origin: AggregateMessageOrigin) -> Option<AccountId> {
+		match origin {
+			AggregateMessageOrigin::Sibling(_) =>
+				LocationToAccountId::convert_location(&origin.into()),
+			_ => None,
+		}
+	}
+}
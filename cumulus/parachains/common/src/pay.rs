@@ -14,11 +14,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use alloc::vec;
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
-use frame_support::traits::{
-	fungibles,
-	tokens::{PaymentStatus, Preservation},
+use frame_support::{
+	traits::{
+		fungibles,
+		tokens::{PaymentStatus, Preservation},
+		Get,
+	},
+	weights::Weight,
 };
+use pallet_asset_conversion::Swap;
 use polkadot_runtime_common::impls::VersionedLocatableAsset;
 use sp_runtime::{traits::TypedGet, DispatchError, RuntimeDebug};
 use xcm::latest::prelude::*;
@@ -65,7 +71,7 @@ where
 		amount: Self::Balance,
 	) -> Result<Self::Id, Self::Error> {
 		let who = Self::match_location(who).map_err(|_| DispatchError::Unavailable)?;
-		let asset = Self::match_asset(&asset).map_err(|_| DispatchError::Unavailable)?;
+		let asset = match_local_asset(&asset).map_err(|_| DispatchError::Unavailable)?;
 		<F as fungibles::Mutate<_>>::transfer(
 			asset,
 			&A::get(),
@@ -83,7 +89,7 @@ where
 	}
 	#[cfg(feature = "runtime-benchmarks")]
 	fn ensure_successful(_: &Self::Beneficiary, asset: Self::AssetKind, amount: Self::Balance) {
-		let asset = Self::match_asset(&asset).expect("invalid asset");
+		let asset = match_local_asset(&asset).expect("invalid asset");
 		<F as fungibles::Create<_>>::create(asset.clone(), A::get(), true, amount).unwrap();
 		<F as fungibles::Mutate<_>>::mint_into(asset, &A::get(), amount).unwrap();
 	}
@@ -109,14 +115,53 @@ where
 		};
 		C::convert_location(account_id).ok_or(())
 	}
-	fn match_asset(asset: &VersionedLocatableAsset) -> Result<xcm::v5::Location, ()> {
-		match asset {
-			VersionedLocatableAsset::V4 { location, asset_id } if location.is_here() =>
-				asset_id.clone().try_into().map(|a: xcm::v5::AssetId| a.0).map_err(|_| ()),
-			VersionedLocatableAsset::V5 { location, asset_id } if location.is_here() =>
-				Ok(asset_id.clone().0),
-			_ => Err(()),
-		}
+}
+
+/// Swap treasury funds on the local chain with the `Swap` implementation if both assets are local.
+///
+/// ### Parameters:
+/// - `S`: The swap implementation, e.g. `pallet_asset_conversion`.
+/// - `A`: The account holding the funds, which also receives the swapped funds.
+/// - `W`: The worst case weight of a swap.
+pub struct LocalSwap<S, A, W>(core::marker::PhantomData<(S, A, W)>);
+impl<S, A, W> pallet_treasury::SpendAssetSwap<VersionedLocatableAsset, S::Balance>
+	for LocalSwap<S, A, W>
+where
+	A: TypedGet,
+	S: Swap<A::Type, AssetKind = xcm::v5::Location>,
+	W: Get<Weight>,
+{
+	fn swap(
+		asset_kind: VersionedLocatableAsset,
+		requested_asset_kind: VersionedLocatableAsset,
+		amount_in: S::Balance,
+		min_amount_out: S::Balance,
+	) -> Result<S::Balance, DispatchError> {
+		let asset_in = match_local_asset(&asset_kind).map_err(|_| DispatchError::Unavailable)?;
+		let asset_out =
+			match_local_asset(&requested_asset_kind).map_err(|_| DispatchError::Unavailable)?;
+		S::swap_exact_tokens_for_tokens(
+			A::get(),
+			vec![asset_in, asset_out],
+			amount_in,
+			Some(min_amount_out),
+			A::get(),
+			true,
+		)
+	}
+	fn weight() -> Weight {
+		W::get()
+	}
+}
+
+/// Returns the location of the asset if it is local.
+fn match_local_asset(asset: &VersionedLocatableAsset) -> Result<xcm::v5::Location, ()> {
+	match asset {
+		VersionedLocatableAsset::V4 { location, asset_id } if location.is_here() =>
+			asset_id.clone().try_into().map(|a: xcm::v5::AssetId| a.0).map_err(|_| ()),
+		VersionedLocatableAsset::V5 { location, asset_id } if location.is_here() =>
+			Ok(asset_id.clone().0),
+		_ => Err(()),
 	}
 }
 
@@ -124,7 +169,6 @@ where
 pub mod benchmarks {
 	use super::*;
 	use core::marker::PhantomData;
-	use frame_support::traits::Get;
 	use pallet_treasury::ArgumentsFactory as TreasuryArgumentsFactory;
 	use sp_core::ConstU8;
 
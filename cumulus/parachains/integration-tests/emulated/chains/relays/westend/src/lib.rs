@@ -25,7 +25,7 @@ use emulated_integration_tests_common::{
 
 // Westend declaration
 decl_test_relay_chains! {
-	#[api_version(14)]
+	#[api_version(15)]
 	pub struct Westend {
 		genesis = genesis::genesis(),
 		on_init = (),
@@ -40,6 +40,7 @@ decl_test_relay_chains! {
 			Treasury: westend_runtime::Treasury,
 			AssetRate: westend_runtime::AssetRate,
 			Hrmp: westend_runtime::Hrmp,
+			OnDemand: westend_runtime::OnDemandAssignmentProvider,
 			Identity: westend_runtime::Identity,
 			IdentityMigrator: westend_runtime::IdentityMigrator,
 		}
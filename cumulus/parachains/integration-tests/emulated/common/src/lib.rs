@@ -15,6 +15,7 @@
 
 pub mod impls;
 pub mod macros;
+pub mod mock_ethereum;
 pub mod xcm_helpers;
 
 pub use xcm_emulator;
@@ -0,0 +1,388 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A simulated Ethereum endpoint for Snowbridge end-to-end tests.
+//!
+//! [`MockEthereum`] plays the role of the Snowbridge Gateway contract and of the relayers
+//! connecting it to an emulated BridgeHub:
+//! - [`MockEthereum::relay_outbound_messages`] consumes the messages committed by the outbound
+//!   queue of the BridgeHub and applies their commands to a simulated ledger of ERC20 balances;
+//! - [`MockEthereum::send_token`] and [`MockEthereum::register_token`] lock tokens in the Gateway
+//!   and inject the corresponding inbound messages into the BridgeHub.
+//!
+//! The simulated state lives in a thread local, so it is shared by all emulated chains of a test
+//! and can be inspected with [`MockEthereum::balance_of`] and friends.
+
+use crate::snowbridge::SEPOLIA_ID;
+
+use ethabi::{ParamKind, Token};
+use frame_support::{storage::unhashed, traits::PalletInfoAccess};
+use snowbridge_core::{ChannelId, ParaId};
+use snowbridge_inbound_queue_primitives::v1::{Command, Destination, MessageV1, VersionedMessage};
+use snowbridge_pallet_outbound_queue::CommittedMessage;
+use sp_core::{H160, H256, U256};
+use std::{cell::RefCell, collections::BTreeMap, marker::PhantomData};
+use xcm::latest::XcmHash;
+use xcm_emulator::{Chain, Parachain, ServiceQueues, TestExt, Weight};
+
+/// Index of `AgentExecute` in the outbound queue V1 command set.
+const AGENT_EXECUTE: u8 = 0;
+/// Index of `UnlockNativeToken` in the outbound queue V1 command set.
+const UNLOCK_NATIVE_TOKEN: u8 = 9;
+/// Index of `RegisterForeignToken` in the outbound queue V1 command set.
+const REGISTER_FOREIGN_TOKEN: u8 = 10;
+/// Index of `MintForeignToken` in the outbound queue V1 command set.
+const MINT_FOREIGN_TOKEN: u8 = 11;
+
+/// A Polkadot-native token registered with the Gateway.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ForeignToken {
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+}
+
+/// A Gateway command decoded from a committed outbound message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GatewayCommand {
+	/// Transfer Ethereum-native tokens out of the Gateway to `recipient`.
+	UnlockNativeToken { agent_id: H256, token: H160, recipient: H160, amount: u128 },
+	/// Register a Polkadot-native token.
+	RegisterForeignToken { token_id: H256, name: Vec<u8>, symbol: Vec<u8>, decimals: u8 },
+	/// Mint a Polkadot-native token to `recipient`.
+	MintForeignToken { token_id: H256, recipient: H160, amount: u128 },
+	/// A command the mock does not simulate, identified by its index.
+	Unsupported(u8),
+}
+
+/// An outbound message which has been dispatched by the mock Gateway.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DispatchedMessage {
+	pub channel_id: ChannelId,
+	pub nonce: u64,
+	pub id: H256,
+	pub command: GatewayCommand,
+	/// Whether the command was applied. Failed commands leave the ledger untouched.
+	pub success: bool,
+}
+
+/// The simulated state of the Ethereum network.
+#[derive(Clone, Debug, Default)]
+struct EthereumState {
+	/// Balances of Ethereum-native tokens, keyed by `(token, holder)`. Ether is `H160::zero()`.
+	balances: BTreeMap<(H160, H160), u128>,
+	/// Ethereum-native tokens locked in the Gateway.
+	locked: BTreeMap<H160, u128>,
+	/// Polkadot-native tokens registered with the Gateway.
+	foreign_tokens: BTreeMap<H256, ForeignToken>,
+	/// Balances of Polkadot-native tokens, keyed by `(token_id, holder)`.
+	foreign_balances: BTreeMap<(H256, H160), u128>,
+	/// Nonce of the last message dispatched on each outbound channel.
+	nonces: BTreeMap<[u8; 32], u64>,
+	/// Nonce of the last message sent through the Gateway.
+	inbound_nonce: u64,
+	/// All messages dispatched so far, in order.
+	dispatched: Vec<DispatchedMessage>,
+}
+
+thread_local! {
+	static ETHEREUM: RefCell<EthereumState> = RefCell::new(EthereumState::default());
+}
+
+/// A simulated Ethereum network running the Snowbridge Gateway contract.
+pub struct MockEthereum<BridgeHub>(PhantomData<BridgeHub>);
+
+impl<BridgeHub: Chain> MockEthereum<BridgeHub> {
+	/// Forget all simulated state.
+	pub fn reset() {
+		ETHEREUM.with(|s| *s.borrow_mut() = EthereumState::default());
+	}
+
+	/// Credit `amount` of the Ethereum-native `token` to `who`.
+	pub fn mint(token: H160, who: H160, amount: u128) {
+		ETHEREUM.with(|s| {
+			let mut state = s.borrow_mut();
+			let balance = state.balances.entry((token, who)).or_default();
+			*balance = balance.saturating_add(amount);
+		});
+	}
+
+	/// Balance of `who` in the Ethereum-native `token`.
+	pub fn balance_of(token: H160, who: H160) -> u128 {
+		ETHEREUM.with(|s| s.borrow().balances.get(&(token, who)).copied().unwrap_or_default())
+	}
+
+	/// Amount of the Ethereum-native `token` locked in the Gateway.
+	pub fn locked(token: H160) -> u128 {
+		ETHEREUM.with(|s| s.borrow().locked.get(&token).copied().unwrap_or_default())
+	}
+
+	/// The Polkadot-native token registered under `token_id`, if any.
+	pub fn foreign_token(token_id: H256) -> Option<ForeignToken> {
+		ETHEREUM.with(|s| s.borrow().foreign_tokens.get(&token_id).cloned())
+	}
+
+	/// Balance of `who` in the Polkadot-native token `token_id`.
+	pub fn foreign_balance_of(token_id: H256, who: H160) -> u128 {
+		ETHEREUM.with(|s| {
+			s.borrow().foreign_balances.get(&(token_id, who)).copied().unwrap_or_default()
+		})
+	}
+
+	/// All messages dispatched by the Gateway so far.
+	pub fn dispatched() -> Vec<DispatchedMessage> {
+		ETHEREUM.with(|s| s.borrow().dispatched.clone())
+	}
+}
+
+impl<BridgeHub> MockEthereum<BridgeHub>
+where
+	BridgeHub: Parachain,
+	BridgeHub::Runtime: snowbridge_pallet_outbound_queue::Config,
+{
+	/// Relay the messages committed by the outbound queue of the BridgeHub and dispatch them in
+	/// the Gateway, returning the newly dispatched messages.
+	///
+	/// The outbound queue clears its committed messages at the start of every block, so this
+	/// must be called before the BridgeHub builds another block. Messages which have already
+	/// been dispatched are skipped.
+	pub fn relay_outbound_messages() -> Vec<DispatchedMessage> {
+		let mut messages = BridgeHub::ext_wrapper(|| {
+			// Commit messages exported since the last block instead of waiting for the next one.
+			let _ = <BridgeHub as Parachain>::MessageProcessor::service_queues(Weight::MAX);
+			let key = frame_support::storage::storage_prefix(
+				<snowbridge_pallet_outbound_queue::Pallet<BridgeHub::Runtime>>::name().as_bytes(),
+				b"Messages",
+			);
+			unhashed::get_or_default::<Vec<CommittedMessage>>(&key)
+		});
+		messages.sort_by_key(|message| message.nonce);
+
+		ETHEREUM.with(|s| {
+			let mut state = s.borrow_mut();
+			messages
+				.into_iter()
+				.filter_map(|message| {
+					let channel: [u8; 32] = message.channel_id.into();
+					let last_nonce = state.nonces.get(&channel).copied().unwrap_or_default();
+					if message.nonce <= last_nonce {
+						return None
+					}
+					state.nonces.insert(channel, message.nonce);
+
+					let command = decode_command(message.command, &message.params);
+					let success = state.apply(&command);
+					let dispatched = DispatchedMessage {
+						channel_id: message.channel_id,
+						nonce: message.nonce,
+						id: message.id,
+						command,
+						success,
+					};
+					state.dispatched.push(dispatched.clone());
+					Some(dispatched)
+				})
+				.collect()
+		})
+	}
+}
+
+impl<BridgeHub> MockEthereum<BridgeHub>
+where
+	BridgeHub: Chain,
+	BridgeHub::Runtime: snowbridge_pallet_inbound_queue::Config,
+{
+	/// Register the Ethereum-native `token` on `para_id`, paying `fee` for XCM execution there.
+	pub fn register_token(token: H160, fee: u128, para_id: ParaId) -> XcmHash {
+		Self::send_message(Command::RegisterToken { token, fee }, para_id)
+	}
+
+	/// Lock `amount` of the Ethereum-native `token` held by `sender` in the Gateway and send it to
+	/// `destination` through `para_id`, paying `fee` for XCM execution there.
+	///
+	/// Panics if `sender` does not hold enough of `token`.
+	pub fn send_token(
+		sender: H160,
+		token: H160,
+		destination: Destination,
+		amount: u128,
+		fee: u128,
+		para_id: ParaId,
+	) -> XcmHash {
+		ETHEREUM.with(|s| {
+			let mut state = s.borrow_mut();
+			let balance = state.balances.entry((token, sender)).or_default();
+			*balance = balance.checked_sub(amount).expect("sender has enough balance; qed");
+			let locked = state.locked.entry(token).or_default();
+			*locked = locked.saturating_add(amount);
+		});
+		Self::send_message(Command::SendToken { token, destination, amount, fee }, para_id)
+	}
+
+	/// Inject an arbitrary Gateway `command` into the inbound queue of the BridgeHub and forward
+	/// the resulting XCM to `para_id`.
+	pub fn send_message(command: Command, para_id: ParaId) -> XcmHash {
+		let nonce = ETHEREUM.with(|s| {
+			let mut state = s.borrow_mut();
+			state.inbound_nonce += 1;
+			state.inbound_nonce
+		});
+		let message = VersionedMessage::V1(MessageV1 { chain_id: SEPOLIA_ID, command });
+
+		BridgeHub::execute_with(|| {
+			type InboundQueue<BridgeHub> =
+				snowbridge_pallet_inbound_queue::Pallet<<BridgeHub as Chain>::Runtime>;
+			let (xcm, _) =
+				InboundQueue::<BridgeHub>::do_convert(H256::from_low_u64_be(nonce), message)
+					.expect("inbound message can be converted to XCM");
+			InboundQueue::<BridgeHub>::send_xcm(xcm, para_id).expect("XCM can be sent")
+		})
+	}
+}
+
+impl EthereumState {
+	/// Apply `command` to the ledger, returning whether it succeeded.
+	fn apply(&mut self, command: &GatewayCommand) -> bool {
+		match command {
+			GatewayCommand::UnlockNativeToken { token, recipient, amount, .. } => {
+				let Some(locked) = self.locked.get(token).and_then(|l| l.checked_sub(*amount))
+				else {
+					return false
+				};
+				self.locked.insert(*token, locked);
+				let balance = self.balances.entry((*token, *recipient)).or_default();
+				*balance = balance.saturating_add(*amount);
+				true
+			},
+			GatewayCommand::RegisterForeignToken { token_id, name, symbol, decimals } => {
+				if self.foreign_tokens.contains_key(token_id) {
+					return false
+				}
+				self.foreign_tokens.insert(
+					*token_id,
+					ForeignToken {
+						name: name.clone(),
+						symbol: symbol.clone(),
+						decimals: *decimals,
+					},
+				);
+				true
+			},
+			GatewayCommand::MintForeignToken { token_id, recipient, amount } => {
+				if !self.foreign_tokens.contains_key(token_id) {
+					return false
+				}
+				let balance = self.foreign_balances.entry((*token_id, *recipient)).or_default();
+				*balance = balance.saturating_add(*amount);
+				true
+			},
+			GatewayCommand::Unsupported(_) => true,
+		}
+	}
+}
+
+/// Decode the ABI-encoded `params` of the outbound command with index `command`.
+fn decode_command(command: u8, params: &[u8]) -> GatewayCommand {
+	let decoded = match command {
+		AGENT_EXECUTE => decode_agent_execute(params),
+		UNLOCK_NATIVE_TOKEN => decode_unlock_native_token(params),
+		REGISTER_FOREIGN_TOKEN => decode_register_foreign_token(params),
+		MINT_FOREIGN_TOKEN => decode_mint_foreign_token(params),
+		_ => None,
+	};
+	decoded.unwrap_or(GatewayCommand::Unsupported(command))
+}
+
+fn decode_unlock_native_token(params: &[u8]) -> Option<GatewayCommand> {
+	let mut tokens = decode_tuple(
+		params,
+		vec![
+			ParamKind::FixedBytes(32),
+			ParamKind::Address,
+			ParamKind::Address,
+			ParamKind::Uint(256),
+		],
+	)?
+	.into_iter();
+	Some(GatewayCommand::UnlockNativeToken {
+		agent_id: H256::from_slice(&tokens.next()?.to_fixed_bytes()?),
+		token: tokens.next()?.to_address()?,
+		recipient: tokens.next()?.to_address()?,
+		amount: to_u128(tokens.next()?.to_uint()?)?,
+	})
+}
+
+fn decode_register_foreign_token(params: &[u8]) -> Option<GatewayCommand> {
+	let mut tokens = decode_tuple(
+		params,
+		vec![ParamKind::FixedBytes(32), ParamKind::String, ParamKind::String, ParamKind::Uint(256)],
+	)?
+	.into_iter();
+	Some(GatewayCommand::RegisterForeignToken {
+		token_id: H256::from_slice(&tokens.next()?.to_fixed_bytes()?),
+		name: tokens.next()?.to_string()?,
+		symbol: tokens.next()?.to_string()?,
+		decimals: u8::try_from(tokens.next()?.to_uint()?).ok()?,
+	})
+}
+
+fn decode_mint_foreign_token(params: &[u8]) -> Option<GatewayCommand> {
+	let mut tokens = decode_tuple(
+		params,
+		vec![ParamKind::FixedBytes(32), ParamKind::Address, ParamKind::Uint(256)],
+	)?
+	.into_iter();
+	Some(GatewayCommand::MintForeignToken {
+		token_id: H256::from_slice(&tokens.next()?.to_fixed_bytes()?),
+		recipient: tokens.next()?.to_address()?,
+		amount: to_u128(tokens.next()?.to_uint()?)?,
+	})
+}
+
+/// Decode an `AgentExecute` command. Its only sub-command is a token transfer out of the agent,
+/// which the mock treats as an unlock.
+fn decode_agent_execute(params: &[u8]) -> Option<GatewayCommand> {
+	let mut tokens =
+		decode_tuple(params, vec![ParamKind::FixedBytes(32), ParamKind::Bytes])?.into_iter();
+	let agent_id = H256::from_slice(&tokens.next()?.to_fixed_bytes()?);
+	let command = tokens.next()?.to_bytes()?;
+	let transfer = ethabi::decode(&[ParamKind::Uint(256), ParamKind::Bytes], &command)
+		.ok()?
+		.pop()?
+		.to_bytes()?;
+	let mut tokens =
+		ethabi::decode(&[ParamKind::Address, ParamKind::Address, ParamKind::Uint(256)], &transfer)
+			.ok()?
+			.into_iter();
+	Some(GatewayCommand::UnlockNativeToken {
+		agent_id,
+		token: tokens.next()?.to_address()?,
+		recipient: tokens.next()?.to_address()?,
+		amount: to_u128(tokens.next()?.to_uint()?)?,
+	})
+}
+
+/// Decode `params` as a single ABI tuple made of `kinds`.
+fn decode_tuple(params: &[u8], kinds: Vec<ParamKind>) -> Option<Vec<Token>> {
+	let kinds = kinds.into_iter().map(Box::new).collect();
+	match ethabi::decode(&[ParamKind::Tuple(kinds)], params).ok()?.pop()? {
+		Token::Tuple(tokens) => Some(tokens),
+		_ => None,
+	}
+}
+
+fn to_u128(value: U256) -> Option<u128> {
+	u128::try_from(value).ok()
+}
@@ -91,7 +91,7 @@ mod imports {
 				xcm_config::{
 					UniversalLocation as WestendUniversalLocation, XcmConfig as WestendXcmConfig,
 				},
-				Dmp,
+				Dmp, RuntimeCall as WestendRuntimeCall,
 			},
 			WestendRelayPallet as WestendPallet,
 		},
@@ -0,0 +1,136 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::imports::*;
+use emulated_integration_tests_common::impls::HrmpChannelId;
+use frame_support::traits::{fungible::Inspect as FungibleInspect, ReservableCurrency};
+use polkadot_runtime_parachains::{configuration, hrmp, paras, ParaLifecycle};
+
+type WestendRuntime = <Westend as Chain>::Runtime;
+
+/// A para funds an HRMP deposit from Asset Hub and gets it back there:
+///
+/// 1. An account on Asset Hub teleports WND into the sovereign account of the para on the relay
+///    chain.
+/// 2. The para chooses the account on Asset Hub as the beneficiary of its refunded deposits.
+/// 3. The para requests to open a channel, which reserves the deposit, and cancels the request.
+/// 4. The deposit is teleported back to the account on Asset Hub.
+#[test]
+fn hrmp_deposit_is_funded_from_and_refunded_to_asset_hub() {
+	let sender = AssetHubWestendSender::get();
+	let beneficiary = AssetHubWestendReceiver::get();
+	let para = PenpalA::para_id();
+	let recipient = PenpalB::para_id();
+	let deposit = WESTEND_ED * 1000;
+	let para_sovereign = Westend::sovereign_account_id_of(Westend::child_location_of(para));
+
+	Westend::execute_with(|| {
+		configuration::ActiveConfig::<WestendRuntime>::mutate(|config| {
+			config.hrmp_sender_deposit = deposit;
+			config.hrmp_recipient_deposit = deposit;
+		});
+		paras::ParaLifecycles::<WestendRuntime>::insert(recipient, ParaLifecycle::Parachain);
+		Dmp::make_parachain_reachable(AssetHubWestend::para_id());
+		Dmp::make_parachain_reachable(recipient);
+	});
+
+	// 1. Fund the deposit from Asset Hub.
+	AssetHubWestend::execute_with(|| {
+		assert_ok!(
+			<AssetHubWestend as AssetHubWestendPallet>::PolkadotXcm::limited_teleport_assets(
+				<AssetHubWestend as Chain>::RuntimeOrigin::signed(sender),
+				bx!(Parent.into()),
+				bx!(Location::new(0, [Parachain(para.into())]).into()),
+				bx!((Parent, deposit * 2).into()),
+				0,
+				Unlimited,
+			)
+		);
+		AssetHubWestend::assert_xcm_pallet_attempted_complete(None);
+	});
+
+	let beneficiary_balance_before = AssetHubWestend::execute_with(|| {
+		<<AssetHubWestend as AssetHubWestendPallet>::Balances as FungibleInspect<_>>::balance(
+			&beneficiary,
+		)
+	});
+
+	Westend::execute_with(|| {
+		type RuntimeEvent = <Westend as Chain>::RuntimeEvent;
+		type Balances = <Westend as WestendPallet>::Balances;
+		let para_origin = <Westend as Chain>::RuntimeOrigin::from(
+			polkadot_runtime_parachains::Origin::Parachain(para),
+		);
+		assert!(<Balances as FungibleInspect<_>>::balance(&para_sovereign) >= deposit);
+
+		// 2. Refund the deposits to the account on Asset Hub.
+		let refund_beneficiary = Location::new(
+			0,
+			[AccountId32Junction { network: None, id: beneficiary.clone().into() }],
+		);
+		assert_ok!(<Westend as WestendPallet>::Hrmp::set_deposit_refund_beneficiary(
+			para_origin.clone(),
+			Some(bx!(refund_beneficiary.clone().into())),
+		));
+
+		// 3. Reserve the deposit and get it back.
+		assert_ok!(<Westend as WestendPallet>::Hrmp::hrmp_init_open_channel(
+			para_origin.clone(),
+			recipient,
+			1,
+			1024,
+		));
+		assert_eq!(Balances::reserved_balance(&para_sovereign), deposit);
+		assert_ok!(<Westend as WestendPallet>::Hrmp::hrmp_cancel_open_request(
+			para_origin,
+			HrmpChannelId { sender: para, recipient },
+			1,
+		));
+
+		assert_expected_events!(
+			Westend,
+			vec![
+				RuntimeEvent::Hrmp(
+					hrmp::Event::DepositRefunded { para: refunded_para, beneficiary, amount }
+				) => {
+					refunded_para: *refunded_para == para,
+					beneficiary: *beneficiary == refund_beneficiary.clone().into(),
+					amount: *amount == deposit,
+				},
+			]
+		);
+		assert_eq!(Balances::reserved_balance(&para_sovereign), 0);
+	});
+
+	// 4. The deposit arrives on Asset Hub.
+	AssetHubWestend::execute_with(|| {
+		type RuntimeEvent = <AssetHubWestend as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![
+				RuntimeEvent::Balances(pallet_balances::Event::Minted { who, amount }) => {
+					who: *who == beneficiary,
+					amount: *amount == deposit,
+				},
+			]
+		);
+		assert_eq!(
+			<<AssetHubWestend as AssetHubWestendPallet>::Balances as FungibleInspect<_>>::balance(
+				&beneficiary,
+			),
+			beneficiary_balance_before + deposit
+		);
+	});
+}
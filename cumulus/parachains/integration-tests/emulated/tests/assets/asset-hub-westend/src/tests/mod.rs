@@ -17,7 +17,9 @@ mod aliases;
 mod claim_assets;
 mod exchange_asset;
 mod fellowship_treasury;
+mod hrmp_deposits;
 mod hybrid_transfers;
+mod on_demand;
 mod reserve_transfer;
 mod reward_pool;
 mod send;
@@ -0,0 +1,155 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{assets_balance_on, create_pool_with_wnd_on, imports::*};
+use frame_support::traits::fungible::Inspect as FungibleInspect;
+use polkadot_runtime_parachains::on_demand;
+
+/// An account on Asset Hub pays for an on-demand core with USDT:
+///
+/// 1. The USDT is swapped on Asset Hub into the WND needed for the on-demand `spot_price` quoted by
+///    the relay chain.
+/// 2. The WND is teleported to the relay chain, preserving the origin of the account.
+/// 3. The account derived for the Asset Hub account on the relay chain purchases on-demand credits
+///    with the WND and places the order for the target para with them.
+#[test]
+fn place_on_demand_order_from_asset_hub_paying_with_pool_asset() {
+	let sender = AssetHubWestendSender::get();
+	let target_para = PenpalA::para_id();
+	let usdt_location =
+		Location::new(0, [PalletInstance(ASSETS_PALLET_ID), GeneralIndex(USDT_ID.into())]);
+	let usdt_amount: Balance = 100_000_000_000;
+
+	create_pool_with_wnd_on!(
+		AssetHubWestend,
+		usdt_location.clone(),
+		false,
+		sender.clone(),
+		100_000_000_000_000,
+		2_000_000_000_000
+	);
+
+	// The account of the Asset Hub account on the relay chain, which places the order.
+	let sender_on_relay = Location::new(
+		0,
+		[
+			Parachain(AssetHubWestend::para_id().into()),
+			AccountId32Junction { network: None, id: sender.clone().into() },
+		],
+	);
+	let orderer = Westend::sovereign_account_id_of(sender_on_relay.clone());
+
+	// Quote the order on the relay chain.
+	let spot_price = Westend::execute_with(<Westend as WestendPallet>::OnDemand::spot_price);
+	let local_fees = WESTEND_ED * 100;
+	let relay_fees = WESTEND_ED * 100;
+	let order_funds = spot_price + WESTEND_ED * 10;
+	let wnd_wanted = local_fees + relay_fees + order_funds;
+
+	let purchase_credits =
+		WestendRuntimeCall::OnDemandAssignmentProvider(on_demand::Call::purchase_credits {
+			beneficiary: orderer.clone(),
+			amount: spot_price,
+		});
+	let order =
+		WestendRuntimeCall::OnDemandAssignmentProvider(on_demand::Call::place_order_with_credits {
+			max_amount: spot_price,
+			para_id: target_para,
+		});
+	let xcm_on_relay = Xcm(vec![
+		DepositAsset { assets: Wild(AllCounted(1)), beneficiary: sender_on_relay.clone() },
+		Transact {
+			origin_kind: OriginKind::SovereignAccount,
+			fallback_max_weight: None,
+			call: purchase_credits.encode().into(),
+		},
+		ExpectTransactStatus(MaybeErrorCode::Success),
+		Transact {
+			origin_kind: OriginKind::SovereignAccount,
+			fallback_max_weight: None,
+			call: order.encode().into(),
+		},
+		ExpectTransactStatus(MaybeErrorCode::Success),
+		RefundSurplus,
+		DepositAsset { assets: Wild(AllCounted(1)), beneficiary: sender_on_relay },
+	]);
+
+	let wnd: AssetId = Parent.into();
+	let usdt: Asset = (usdt_location.clone(), usdt_amount).into();
+	let xcm = Xcm::<()>(vec![
+		WithdrawAsset(usdt.clone().into()),
+		ExchangeAsset {
+			give: Definite(usdt.into()),
+			want: (wnd.clone(), wnd_wanted).into(),
+			maximal: false,
+		},
+		PayFees { asset: (wnd.clone(), local_fees).into() },
+		InitiateTransfer {
+			destination: Parent.into(),
+			remote_fees: Some(AssetTransferFilter::Teleport(Definite(
+				(wnd.clone(), relay_fees).into(),
+			))),
+			preserve_origin: true,
+			assets: BoundedVec::truncate_from(vec![AssetTransferFilter::Teleport(Wild(AllOf {
+				id: wnd,
+				fun: WildFungible,
+			}))]),
+			remote_xcm: xcm_on_relay,
+		},
+		RefundSurplus,
+		DepositAsset { assets: Wild(All), beneficiary: sender.clone().into() },
+	]);
+
+	let usdt_before = assets_balance_on!(AssetHubWestend, USDT_ID, &sender);
+	AssetHubWestend::execute_with(|| {
+		assert_ok!(<AssetHubWestend as AssetHubWestendPallet>::PolkadotXcm::execute(
+			<AssetHubWestend as Chain>::RuntimeOrigin::signed(sender.clone()),
+			bx!(xcm::VersionedXcm::from(xcm)),
+			Weight::MAX,
+		));
+		AssetHubWestend::assert_xcm_pallet_attempted_complete(None);
+	});
+	let usdt_after = assets_balance_on!(AssetHubWestend, USDT_ID, &sender);
+	assert!(usdt_after < usdt_before);
+	assert!(usdt_before - usdt_after <= usdt_amount);
+
+	Westend::execute_with(|| {
+		type RuntimeEvent = <Westend as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			Westend,
+			vec![
+				RuntimeEvent::OnDemandAssignmentProvider(
+					on_demand::Event::AccountCredited { who, amount }
+				) => {
+					who: *who == orderer,
+					amount: *amount == spot_price,
+				},
+				RuntimeEvent::OnDemandAssignmentProvider(
+					on_demand::Event::OnDemandOrderPlaced { para_id, spot_price: paid, ordered_by }
+				) => {
+					para_id: *para_id == target_para,
+					paid: *paid == spot_price,
+					ordered_by: *ordered_by == orderer,
+				},
+			]
+		);
+		// The credits are spent on the order and what is left over stays with the account.
+		assert_eq!(on_demand::Credits::<<Westend as Chain>::Runtime>::get(&orderer), 0);
+		assert!(
+			<<Westend as WestendPallet>::Balances as FungibleInspect<_>>::balance(&orderer) >=
+				order_funds - spot_price
+		);
+	});
+}
@@ -16,6 +16,7 @@
 use crate::tests::{snowbridge_common::snowbridge_sovereign, *};
 use emulated_integration_tests_common::{
 	macros::Dmp,
+	xcm_emulator::Network,
 	xcm_helpers::{find_mq_processed_id, find_xcm_sent_message_id},
 	xcm_simulator::helpers::TopicIdTracker,
 };
@@ -270,6 +271,50 @@ fn send_wnds_usdt_and_weth_from_asset_hub_westend_to_asset_hub_rococo() {
 	assert_eq!(receiver_weth_after, receiver_weth_before + amount);
 }
 
+#[test]
+/// Test that WNDs sent from AssetHub Westend wait on the Westend side while the bridged messages
+/// are held back, and reach AssetHub Rococo once they are released.
+fn send_wnds_from_asset_hub_westend_to_asset_hub_rococo_after_holding_bridged_messages() {
+	type WestendNetwork = <BridgeHubWestend as Chain>::Network;
+
+	let amount = ASSET_HUB_WESTEND_ED * 1_000;
+	let receiver = AssetHubRococoReceiver::get();
+	let bridged_wnd_at_asset_hub_rococo = bridged_wnd_at_ah_rococo();
+	create_foreign_on_ah_rococo(bridged_wnd_at_asset_hub_rococo.clone(), true);
+	let receiver_wnds_before =
+		foreign_balance_on_ah_rococo(bridged_wnd_at_asset_hub_rococo.clone(), &receiver);
+
+	// fund the AHW's SA on BHW for paying bridge delivery fees
+	BridgeHubWestend::fund_para_sovereign(AssetHubWestend::para_id(), 10_000_000_000_000u128);
+	AssetHubWestend::force_xcm_version(asset_hub_rococo_location(), XCM_VERSION);
+	BridgeHubWestend::force_xcm_version(bridge_hub_rococo_location(), XCM_VERSION);
+
+	WestendNetwork::hold_bridged_messages();
+	let assets: Assets = (wnd_at_ah_westend(), amount).into();
+	assert_ok!(send_assets_from_asset_hub_westend(asset_hub_rococo_location(), assets, 0));
+	assert_bridge_hub_westend_message_accepted(true);
+
+	// the message waits on the Westend side, also across further blocks of BridgeHub Westend
+	assert_eq!(WestendNetwork::pending_bridged_messages(), 1);
+	BridgeHubWestend::execute_with(|| {});
+	assert_eq!(WestendNetwork::pending_bridged_messages(), 1);
+	assert_eq!(
+		foreign_balance_on_ah_rococo(bridged_wnd_at_asset_hub_rococo.clone(), &receiver),
+		receiver_wnds_before
+	);
+
+	// releasing the message delivers it to AssetHub Rococo
+	WestendNetwork::release_bridged_messages();
+	assert_eq!(WestendNetwork::pending_bridged_messages(), 0);
+	assert_bridge_hub_rococo_message_received();
+	AssetHubRococo::execute_with(|| {
+		AssetHubRococo::assert_xcmp_queue_success(None);
+	});
+	let receiver_wnds_after =
+		foreign_balance_on_ah_rococo(bridged_wnd_at_asset_hub_rococo, &receiver);
+	assert!(receiver_wnds_after > receiver_wnds_before);
+}
+
 #[test]
 /// Send bridged ROCs "back" from AssetHub Westend to AssetHub Rococo.
 fn send_back_rocs_from_asset_hub_westend_to_asset_hub_rococo() {
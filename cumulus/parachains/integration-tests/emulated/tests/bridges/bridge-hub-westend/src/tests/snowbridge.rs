@@ -40,6 +40,7 @@ use bridge_hub_westend_runtime::{
 };
 use codec::{Decode, Encode};
 use emulated_integration_tests_common::{
+	mock_ethereum::{GatewayCommand, MockEthereum},
 	snowbridge::{SEPOLIA_ID, WETH},
 	PENPAL_B_ID, RESERVABLE_ASSET_ID,
 };
@@ -2266,3 +2267,105 @@ fn register_pna_in_v5_while_transfer_in_v4_should_work() {
 		);
 	});
 }
+
+/// Tests the full cycle of token transfers against the mock Ethereum network:
+/// - registering a token on AssetHub through the Gateway
+/// - locking the token in the Gateway and sending it to AssetHub
+/// - returning the token to Ethereum and unlocking it in the Gateway
+#[test]
+fn send_token_from_ethereum_to_asset_hub_and_back_through_mock_gateway() {
+	type Gateway = MockEthereum<BridgeHubWestend>;
+
+	// Fund AssetHub sovereign account so that it can pay execution fees.
+	BridgeHubWestend::fund_para_sovereign(AssetHubWestend::para_id().into(), INITIAL_FUND);
+	// Fund Snowbridge Sovereign to satisfy ED.
+	AssetHubWestend::fund_accounts(vec![
+		(snowbridge_sovereign(), INITIAL_FUND),
+		(AssetHubWestendReceiver::get(), INITIAL_FUND),
+	]);
+
+	let token = H160::random();
+	let sender = H160::random();
+	let recipient: H160 = ETHEREUM_DESTINATION_ADDRESS.into();
+	let token_location = Location::new(
+		2,
+		[
+			GlobalConsensus(Ethereum { chain_id: SEPOLIA_ID }),
+			AccountKey20 { network: None, key: token.into() },
+		],
+	);
+
+	Gateway::reset();
+	Gateway::mint(token, sender, TOKEN_AMOUNT);
+
+	Gateway::register_token(token, XCM_FEE, AssetHubWestend::para_id().into());
+	AssetHubWestend::execute_with(|| {
+		type RuntimeEvent = <AssetHubWestend as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![RuntimeEvent::ForeignAssets(pallet_assets::Event::Created { .. }) => {},]
+		);
+		assert!(<AssetHubWestend as AssetHubWestendPallet>::ForeignAssets::asset_exists(
+			token_location.clone()
+		));
+	});
+
+	Gateway::send_token(
+		sender,
+		token,
+		Destination::AccountId32 { id: AssetHubWestendReceiver::get().into() },
+		TOKEN_AMOUNT,
+		XCM_FEE,
+		AssetHubWestend::para_id().into(),
+	);
+	assert_eq!(Gateway::balance_of(token, sender), 0);
+	assert_eq!(Gateway::locked(token), TOKEN_AMOUNT);
+
+	AssetHubWestend::execute_with(|| {
+		type RuntimeOrigin = <AssetHubWestend as Chain>::RuntimeOrigin;
+
+		assert_eq!(
+			<AssetHubWestend as AssetHubWestendPallet>::ForeignAssets::balance(
+				token_location.clone(),
+				AssetHubWestendReceiver::get(),
+			),
+			TOKEN_AMOUNT
+		);
+
+		let assets =
+			vec![Asset { id: AssetId(token_location.clone()), fun: Fungible(TOKEN_AMOUNT) }];
+		let destination = VersionedLocation::from(Location::new(
+			2,
+			[GlobalConsensus(Ethereum { chain_id: SEPOLIA_ID })],
+		));
+		let beneficiary = VersionedLocation::from(Location::new(
+			0,
+			[AccountKey20 { network: None, key: recipient.into() }],
+		));
+
+		assert_ok!(<AssetHubWestend as AssetHubWestendPallet>::PolkadotXcm::limited_reserve_transfer_assets(
+			RuntimeOrigin::signed(AssetHubWestendReceiver::get()),
+			Box::new(destination),
+			Box::new(beneficiary),
+			Box::new(VersionedAssets::from(Assets::from(assets))),
+			0,
+			Unlimited,
+		));
+	});
+
+	// Relay the message committed by the outbound queue to the Gateway
+	let dispatched = Gateway::relay_outbound_messages();
+	assert_eq!(dispatched.len(), 1);
+	assert!(dispatched[0].success);
+	assert!(matches!(
+		dispatched[0].command,
+		GatewayCommand::UnlockNativeToken { token: t, recipient: r, amount, .. }
+			if t == token && r == recipient && amount == TOKEN_AMOUNT
+	));
+	assert_eq!(Gateway::balance_of(token, recipient), TOKEN_AMOUNT);
+	assert_eq!(Gateway::locked(token), 0);
+
+	// Messages are only dispatched once
+	assert!(Gateway::relay_outbound_messages().is_empty());
+}
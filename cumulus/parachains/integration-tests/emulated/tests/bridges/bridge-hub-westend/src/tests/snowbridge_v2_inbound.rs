@@ -35,8 +35,8 @@ use hex_literal::hex;
 use rococo_westend_system_emulated_network::penpal_emulated_chain::PARA_ID_B;
 use snowbridge_core::{reward::MessageId, AssetMetadata, TokenIdOf};
 use snowbridge_inbound_queue_primitives::v2::{
-	EthereumAsset::{ForeignTokenERC20, NativeTokenERC20},
-	Message, Network, XcmPayload,
+	EthereumAsset::{ForeignTokenERC20, NativeTokenERC20, NativeTokenERC20WithMetadata},
+	Message, Network, TokenMetadata, XcmPayload,
 };
 use sp_core::{H160, H256};
 use sp_io::hashing::blake2_256;
@@ -261,6 +261,121 @@ fn send_token_v2() {
 	});
 }
 
+#[test]
+fn send_token_with_metadata_v2_registers_asset_at_sender_cost() {
+	let relayer_account = BridgeHubWestendSender::get();
+	let relayer_reward = 1_500_000_000_000u128;
+	let bridge_owner = snowbridge_sovereign();
+
+	let token: H160 = TOKEN_ID.into();
+	let token_location = erc20_token_location(token);
+
+	let receiver = AssetHubWestendReceiver::get();
+	let claimer = Location::new(0, AccountId32 { network: None, id: receiver.clone().into() });
+	let claimer_bytes = claimer.encode();
+
+	let beneficiary_acc_bytes: [u8; 32] = H256::random().into();
+	let beneficiary = Location::new(0, AccountId32 { network: None, id: beneficiary_acc_bytes });
+
+	let token_transfer_value = 2_000_000_000_000u128;
+
+	set_up_eth_and_dot_pool();
+	let sovereign_balance_before = AssetHubWestend::execute_with(|| {
+		<AssetHubWestend as AssetHubWestendPallet>::Balances::free_balance(&bridge_owner)
+	});
+
+	BridgeHubWestend::execute_with(|| {
+		type RuntimeEvent = <BridgeHubWestend as Chain>::RuntimeEvent;
+		let instructions = vec![
+			RefundSurplus,
+			DepositAsset {
+				assets: Wild(AllOf {
+					id: AssetId(token_location.clone()),
+					fun: WildFungibility::Fungible,
+				}),
+				beneficiary,
+			},
+			DepositAsset {
+				assets: Wild(AllOf { id: AssetId(eth_location()), fun: WildFungibility::Fungible }),
+				beneficiary: claimer,
+			},
+		];
+		let xcm: Xcm<()> = instructions.into();
+
+		let message = Message {
+			gateway: EthereumGatewayAddress::get(),
+			nonce: 1,
+			origin: H160::random(),
+			assets: vec![NativeTokenERC20WithMetadata {
+				token_id: token,
+				value: token_transfer_value,
+				metadata: TokenMetadata {
+					name: b"Wrapped Ether".to_vec(),
+					symbol: b"WETH".to_vec(),
+					decimals: 18,
+				},
+			}],
+			xcm: XcmPayload::Raw(VersionedXcm::V5(xcm).encode()),
+			claimer: Some(claimer_bytes),
+			// Used to pay the asset creation and metadata deposits.
+			value: 9_000_000_000_000u128,
+			execution_fee: 1_500_000_000_000u128,
+			relayer_fee: relayer_reward,
+		};
+
+		EthereumInboundQueueV2::process_message(relayer_account.clone(), message).unwrap();
+
+		assert_expected_events!(
+			BridgeHubWestend,
+			vec![
+				RuntimeEvent::XcmpQueue(cumulus_pallet_xcmp_queue::Event::XcmpMessageSent { .. }) => {},
+			]
+		);
+	});
+
+	AssetHubWestend::execute_with(|| {
+		type RuntimeEvent = <AssetHubWestend as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![
+				RuntimeEvent::MessageQueue(
+					pallet_message_queue::Event::Processed { success: true, .. }
+				) => {},
+				// Check that the token was created as a foreign asset on AssetHub
+				RuntimeEvent::ForeignAssets(pallet_assets::Event::Created { asset_id, owner, .. }) => {
+					asset_id: *asset_id == token_location,
+					owner: *owner == bridge_owner,
+				},
+				RuntimeEvent::ForeignAssets(pallet_assets::Event::MetadataSet { asset_id, .. }) => {
+					asset_id: *asset_id == token_location,
+				},
+			]
+		);
+
+		// Beneficiary received the token transfer value
+		assert_eq!(
+			ForeignAssets::balance(token_location, AccountId::from(beneficiary_acc_bytes)),
+			token_transfer_value
+		);
+		// The deposits were paid by the sender, not by the Ethereum sovereign account.
+		assert_eq!(
+			<AssetHubWestend as AssetHubWestendPallet>::Balances::free_balance(&bridge_owner),
+			sovereign_balance_before
+		);
+
+		let events = AssetHubWestend::events();
+		// Check that no assets were trapped
+		assert!(
+			!events.iter().any(|event| matches!(
+				event,
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::AssetsTrapped { .. })
+			)),
+			"Assets were trapped, should not happen."
+		);
+	});
+}
+
 #[test]
 fn send_weth_v2() {
 	let relayer_account = BridgeHubWestendSender::get();
@@ -1047,7 +1162,9 @@ fn invalid_claimer_does_not_fail_the_message() {
 
 #[test]
 fn create_foreign_asset_deposit_is_equal_to_asset_hub_foreign_asset_pallet_deposit() {
-	let asset_hub_deposit = asset_hub_westend_runtime::ForeignAssetsAssetDeposit::get();
+	let asset_hub_deposit = AssetHubWestend::execute_with(|| {
+		asset_hub_westend_runtime::ForeignAssetsAssetDeposit::get()
+	});
 	let bridge_hub_deposit = bp_asset_hub_westend::CreateForeignAssetDeposit::get();
 	assert!(
 		bridge_hub_deposit >=
@@ -0,0 +1,43 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fee accounting pallet benchmarking.
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Hooks;
+use frame_system::pallet_prelude::BlockNumberFor;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn on_finalize() {
+		for flow in [FeeFlow::ToCollators, FeeFlow::ToTreasury, FeeFlow::Burned, FeeFlow::Swapped] {
+			Pallet::<T>::note(flow, 1_000u32.into());
+		}
+
+		#[block]
+		{
+			Pallet::<T>::on_finalize(BlockNumberFor::<T>::zero());
+		}
+
+		assert!(BlockFlows::<T>::get().is_zero());
+		assert!(!Totals::<T>::get().is_zero());
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,255 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Fee Accounting Pallet
+//!
+//! Aggregates where the transaction fees of a block flowed to, so that the fee revenue does not
+//! have to be reconstructed from the events of the individual transactions.
+//!
+//! The fee handlers of the runtime report the flows with [`Pallet::note`], or by wrapping the
+//! [`OnUnbalanced`] handler of a flow into [`RecordFees`]. Fees paid in other assets and swapped
+//! into the native asset are accounted with [`Pallet::account_swapped`]. At the end of every block
+//! in which fees were paid, the flows of the block are added to the [`Totals`] and reported with a
+//! single [`Event::FeesAccounted`].
+//!
+//! The flows of the current block are kept in whitelisted storage, so noting them does not add to
+//! the proof size of the transactions.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
+use frame_support::traits::{
+	fungible, fungibles, tokens::AssetId as AssetIdT, Get, Imbalance as _, OnUnbalanced,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	RuntimeDebug,
+};
+
+/// A destination of the fees.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum FeeFlow {
+	/// The fees were paid to the collators.
+	ToCollators,
+	/// The fees were paid to the treasury.
+	ToTreasury,
+	/// The fees were burned.
+	Burned,
+	/// The fees were paid in another asset and swapped into the native asset.
+	Swapped,
+}
+
+/// The amounts of the fees per [`FeeFlow`].
+#[derive(
+	Clone,
+	Default,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct FeeFlows<Balance> {
+	/// The fees paid to the collators.
+	pub to_collators: Balance,
+	/// The fees paid to the treasury.
+	pub to_treasury: Balance,
+	/// The fees burned.
+	pub burned: Balance,
+	/// The fees swapped into the native asset.
+	///
+	/// They are also part of the flow to their destination, e.g. [`FeeFlows::to_collators`].
+	pub swapped: Balance,
+}
+
+impl<Balance: Saturating + Zero + Copy> FeeFlows<Balance> {
+	/// Add `amount` to the given `flow`.
+	pub fn note(&mut self, flow: FeeFlow, amount: Balance) {
+		let total = match flow {
+			FeeFlow::ToCollators => &mut self.to_collators,
+			FeeFlow::ToTreasury => &mut self.to_treasury,
+			FeeFlow::Burned => &mut self.burned,
+			FeeFlow::Swapped => &mut self.swapped,
+		};
+		*total = total.saturating_add(amount);
+	}
+
+	/// Add all flows of `other`.
+	pub fn accrue(&mut self, other: &Self) {
+		self.note(FeeFlow::ToCollators, other.to_collators);
+		self.note(FeeFlow::ToTreasury, other.to_treasury);
+		self.note(FeeFlow::Burned, other.burned);
+		self.note(FeeFlow::Swapped, other.swapped);
+	}
+
+	/// The fees which flowed to their destinations, i.e. without [`FeeFlows::swapped`].
+	pub fn to_destinations(&self) -> Balance {
+		self.to_collators.saturating_add(self.to_treasury).saturating_add(self.burned)
+	}
+
+	/// Whether no fees flowed at all.
+	pub fn is_zero(&self) -> bool {
+		self.to_collators.is_zero() &&
+			self.to_treasury.is_zero() &&
+			self.burned.is_zero() &&
+			self.swapped.is_zero()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::tokens::Balance};
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The balance type of the native asset, in which the fees are accounted.
+		type Balance: Balance;
+
+		/// Weight information for the functions of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The fees which flowed in the current block.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub type BlockFlows<T: Config> = StorageValue<_, FeeFlows<T::Balance>, ValueQuery>;
+
+	/// The fees which flowed in all blocks so far.
+	#[pallet::storage]
+	pub type Totals<T: Config> = StorageValue<_, FeeFlows<T::Balance>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The fees of the block flowed to their destinations.
+		FeesAccounted { flows: FeeFlows<T::Balance> },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			T::WeightInfo::on_finalize()
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			let flows = BlockFlows::<T>::take();
+			if flows.is_zero() {
+				return
+			}
+			Totals::<T>::mutate(|totals| totals.accrue(&flows));
+			Self::deposit_event(Event::FeesAccounted { flows });
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Account `amount` of fees to the given `flow` of the current block.
+		pub fn note(flow: FeeFlow, amount: T::Balance) {
+			if amount.is_zero() {
+				return
+			}
+			BlockFlows::<T>::mutate(|flows| flows.note(flow, amount));
+		}
+
+		/// Run `f`, which pays swapped fees to their destinations, and account the fees it
+		/// accounted to their destinations also to [`FeeFlow::Swapped`].
+		///
+		/// Used by the fee handlers which only learn whether a fee was swapped before the fee is
+		/// handed to the [`RecordFees`] of its destination.
+		pub fn account_swapped<R>(f: impl FnOnce() -> R) -> R {
+			let before = BlockFlows::<T>::get().to_destinations();
+			let result = f();
+			let after = BlockFlows::<T>::get().to_destinations();
+			Self::note(FeeFlow::Swapped, after.saturating_sub(before));
+			result
+		}
+	}
+}
+
+/// Accounts the credit handled by `Inner` to the fee flow `Flow`, implementing [`OnUnbalanced`].
+///
+/// Works with the imbalances of both, `fungible` and `fungibles` implementations. The imbalances
+/// must be of the native asset.
+pub struct RecordFees<T, Flow, Inner>(PhantomData<(T, Flow, Inner)>);
+
+impl<T, Flow, Inner, OnDrop, OppositeOnDrop>
+	OnUnbalanced<fungible::Imbalance<T::Balance, OnDrop, OppositeOnDrop>>
+	for RecordFees<T, Flow, Inner>
+where
+	T: Config,
+	Flow: Get<FeeFlow>,
+	OnDrop: fungible::HandleImbalanceDrop<T::Balance>,
+	OppositeOnDrop: fungible::HandleImbalanceDrop<T::Balance>,
+	Inner: OnUnbalanced<fungible::Imbalance<T::Balance, OnDrop, OppositeOnDrop>>,
+{
+	fn on_nonzero_unbalanced(credit: fungible::Imbalance<T::Balance, OnDrop, OppositeOnDrop>) {
+		Pallet::<T>::note(Flow::get(), credit.peek());
+		Inner::on_unbalanced(credit);
+	}
+}
+
+impl<T, Flow, Inner, AssetId, OnDrop, OppositeOnDrop>
+	OnUnbalanced<fungibles::Imbalance<AssetId, T::Balance, OnDrop, OppositeOnDrop>>
+	for RecordFees<T, Flow, Inner>
+where
+	T: Config,
+	Flow: Get<FeeFlow>,
+	AssetId: AssetIdT,
+	OnDrop: fungibles::HandleImbalanceDrop<AssetId, T::Balance>,
+	OppositeOnDrop: fungibles::HandleImbalanceDrop<AssetId, T::Balance>,
+	Inner: OnUnbalanced<fungibles::Imbalance<AssetId, T::Balance, OnDrop, OppositeOnDrop>>,
+{
+	fn on_nonzero_unbalanced(
+		credit: fungibles::Imbalance<AssetId, T::Balance, OnDrop, OppositeOnDrop>,
+	) {
+		Pallet::<T>::note(Flow::get(), credit.peek());
+		Inner::on_unbalanced(credit);
+	}
+}
@@ -0,0 +1,124 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the fee accounting pallet.
+
+use crate::{mock::*, *};
+use frame_support::traits::{
+	fungible::{Balanced, Inspect},
+	tokens::imbalance::ResolveTo,
+	Hooks,
+};
+
+type ToCollator = RecordFees<Test, ToCollators, ResolveTo<Collator, Balances>>;
+type Burn = RecordFees<Test, Burned, ()>;
+
+fn fees_accounted_events() -> Vec<FeeFlows<u64>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::FeeAccounting(Event::FeesAccounted { flows }) => Some(flows),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn note_accumulates_flows() {
+	new_test_ext().execute_with(|| {
+		FeeAccounting::note(FeeFlow::ToCollators, 10);
+		FeeAccounting::note(FeeFlow::ToCollators, 5);
+		FeeAccounting::note(FeeFlow::ToTreasury, 3);
+		FeeAccounting::note(FeeFlow::Burned, 0);
+		FeeAccounting::note(FeeFlow::Swapped, 7);
+
+		assert_eq!(
+			BlockFlows::<Test>::get(),
+			FeeFlows { to_collators: 15, to_treasury: 3, burned: 0, swapped: 7 }
+		);
+	});
+}
+
+#[test]
+fn on_finalize_reports_and_totals_flows() {
+	new_test_ext().execute_with(|| {
+		FeeAccounting::note(FeeFlow::ToCollators, 10);
+		FeeAccounting::note(FeeFlow::Burned, 2);
+		FeeAccounting::on_finalize(1);
+
+		let flows = FeeFlows { to_collators: 10, to_treasury: 0, burned: 2, swapped: 0 };
+		assert_eq!(fees_accounted_events(), vec![flows.clone()]);
+		assert_eq!(Totals::<Test>::get(), flows);
+		assert!(BlockFlows::<Test>::get().is_zero());
+
+		// No event for a block without fees.
+		System::reset_events();
+		System::set_block_number(2);
+		FeeAccounting::on_finalize(2);
+		assert!(fees_accounted_events().is_empty());
+
+		System::set_block_number(3);
+		FeeAccounting::note(FeeFlow::ToCollators, 1);
+		FeeAccounting::note(FeeFlow::ToTreasury, 4);
+		FeeAccounting::on_finalize(3);
+		assert_eq!(
+			fees_accounted_events(),
+			vec![FeeFlows { to_collators: 1, to_treasury: 4, burned: 0, swapped: 0 }]
+		);
+		assert_eq!(
+			Totals::<Test>::get(),
+			FeeFlows { to_collators: 11, to_treasury: 4, burned: 2, swapped: 0 }
+		);
+	});
+}
+
+#[test]
+fn record_fees_works() {
+	new_test_ext().execute_with(|| {
+		let issuance = Balances::total_issuance();
+
+		ToCollator::on_unbalanceds([Balances::issue(100), Balances::issue(20)].into_iter());
+		Burn::on_unbalanced(Balances::issue(30));
+		Burn::on_unbalanced(Balances::issue(0));
+
+		assert_eq!(Balances::balance(&Collator::get()), 120);
+		assert_eq!(Balances::total_issuance(), issuance + 120);
+		assert_eq!(
+			BlockFlows::<Test>::get(),
+			FeeFlows { to_collators: 120, to_treasury: 0, burned: 30, swapped: 0 }
+		);
+	});
+}
+
+#[test]
+fn account_swapped_works() {
+	new_test_ext().execute_with(|| {
+		FeeAccounting::note(FeeFlow::ToCollators, 10);
+
+		// Only the fees accounted to their destinations within the closure are swapped.
+		let result = FeeAccounting::account_swapped(|| {
+			ToCollator::on_unbalanced(Balances::issue(100));
+			Burn::on_unbalanced(Balances::issue(5));
+			42
+		});
+		assert_eq!(result, 42);
+		FeeAccounting::account_swapped(|| ());
+
+		assert_eq!(
+			BlockFlows::<Test>::get(),
+			FeeFlows { to_collators: 110, to_treasury: 0, burned: 5, swapped: 105 }
+		);
+	});
+}
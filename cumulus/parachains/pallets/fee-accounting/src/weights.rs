@@ -0,0 +1,65 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_fee_accounting`.
+//!
+//! NOTE: These weights were written by hand and are placeholders until they are generated with the
+//! `pallet_fee_accounting` benchmarks, e.g. with:
+//! `frame-omni-bencher v1 benchmark pallet --pallet pallet_fee_accounting --extrinsic "*"
+//! --template substrate/.maintain/frame-weight-template.hbs
+//! --output cumulus/parachains/pallets/fee-accounting/src/weights.rs`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_fee_accounting`.
+pub trait WeightInfo {
+	fn on_finalize() -> Weight;
+}
+
+/// Weights for `pallet_fee_accounting` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `FeeAccounting::BlockFlows` (r:1 w:1)
+	/// Proof: `FeeAccounting::BlockFlows` (`max_values`: Some(1), `max_size`: Some(64), added: 559, mode: `MaxEncodedLen`)
+	/// Storage: `FeeAccounting::Totals` (r:1 w:1)
+	/// Proof: `FeeAccounting::Totals` (`max_values`: Some(1), `max_size`: Some(64), added: 559, mode: `MaxEncodedLen`)
+	fn on_finalize() -> Weight {
+		// Placeholder until the `on_finalize` benchmark is run.
+		Weight::from_parts(10_000_000, 1549)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `FeeAccounting::BlockFlows` (r:1 w:1)
+	/// Proof: `FeeAccounting::BlockFlows` (`max_values`: Some(1), `max_size`: Some(64), added: 559, mode: `MaxEncodedLen`)
+	/// Storage: `FeeAccounting::Totals` (r:1 w:1)
+	/// Proof: `FeeAccounting::Totals` (`max_values`: Some(1), `max_size`: Some(64), added: 559, mode: `MaxEncodedLen`)
+	fn on_finalize() -> Weight {
+		// Placeholder until the `on_finalize` benchmark is run.
+		Weight::from_parts(10_000_000, 1549)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
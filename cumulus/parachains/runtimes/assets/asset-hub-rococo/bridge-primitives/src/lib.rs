@@ -54,7 +54,10 @@ pub enum Call {
 frame_support::parameter_types! {
 	/// Some sane weight to execute `xcm::Transact(pallet-xcm-bridge-hub-router::Call::report_bridge_status)`.
 	pub const XcmBridgeHubRouterTransactCallMaxWeight: frame_support::weights::Weight = frame_support::weights::Weight::from_parts(200_000_000, 6144);
-	/// Should match the `AssetDeposit` of the `ForeignAssets` pallet on Asset Hub.
+	/// Default `AssetDeposit` of the `ForeignAssets` pallet on Asset Hub.
+	///
+	/// Asset Hub and the bridge hubs keep the actual deposit in a dynamic parameter, with this value
+	/// as the default, so it may be changed without a runtime upgrade.
 	pub const CreateForeignAssetDeposit: u128 = UNITS / 10;
 }
 
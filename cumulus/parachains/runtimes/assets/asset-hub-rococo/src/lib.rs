@@ -59,6 +59,7 @@ use cumulus_primitives_core::ParaId;
 use frame_support::{
 	construct_runtime, derive_impl,
 	dispatch::DispatchClass,
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
 	genesis_builder_helper::{build_state, get_preset},
 	ord_parameter_types, parameter_types,
 	traits::{
@@ -132,7 +133,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 1_019_002,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 16,
+	transaction_version: 17,
 	system_version: 1,
 };
 
@@ -196,12 +197,15 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<0>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 	type EventHandler = (CollatorSelection,);
+	type AuthorStatsWindow = ();
 }
 
 parameter_types! {
@@ -222,9 +226,12 @@ impl pallet_balances::Config for Runtime {
 	type ReserveIdentifier = [u8; 8];
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = ConstU32<50>;
 	type DoneSlashHandler = ();
+	type Scheduler = ();
+	type MaxScheduledTransferDelay = ConstU32<{ 30 * DAYS }>;
 }
 
 parameter_types! {
@@ -462,7 +469,7 @@ impl pallet_asset_conversion_ops::Config for Runtime {
 }
 
 parameter_types! {
-	pub const ForeignAssetsAssetDeposit: Balance = CreateForeignAssetDeposit::get();
+	pub ForeignAssetsAssetDeposit: Balance = dynamic_params::assets::ForeignAssetDeposit::get();
 	pub const ForeignAssetsAssetAccountDeposit: Balance = AssetAccountDeposit::get();
 	pub const ForeignAssetsApprovalDeposit: Balance = ApprovalDeposit::get();
 	pub const ForeignAssetsAssetsStringLimit: u32 = AssetsStringLimit::get();
@@ -532,6 +539,10 @@ impl pallet_multisig::Config for Runtime {
 	type MaxSignatories = MaxSignatories;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 impl pallet_utility::Config for Runtime {
@@ -541,6 +552,54 @@ impl pallet_utility::Config for Runtime {
 	type WeightInfo = weights::pallet_utility::WeightInfo<Runtime>;
 }
 
+/// Dynamic parameters that can be changed at runtime through
+/// `pallet_parameters::set_parameter`.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// Deposits taken for creating assets.
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod assets {
+		/// Deposit for creating a foreign asset.
+		///
+		/// Bridge hubs creating foreign assets on behalf of bridged tokens pay their own
+		/// `CreateForeignAssetDeposit` dynamic parameter, which defaults to
+		/// [`CreateForeignAssetDeposit`], so both values should be changed together.
+		#[codec(index = 0)]
+		pub static ForeignAssetDeposit: Balance = CreateForeignAssetDeposit::get();
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Assets(dynamic_params::assets::Parameters::ForeignAssetDeposit(
+			dynamic_params::assets::ForeignAssetDeposit,
+			Some(CreateForeignAssetDeposit::get()),
+		))
+	}
+}
+
+parameter_types! {
+	// GeneralAdmin pluralistic body.
+	pub const GeneralAdminBodyId: BodyId = BodyId::Administration;
+}
+
+/// Root or the `GeneralAdmin` of the governance location can modify the dynamic parameters.
+pub type DynamicParameterOrigin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	EnsureXcm<IsVoiceOfBody<GovernanceLocation, GeneralAdminBodyId>>,
+>;
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = AsEnsureOriginWithArg<DynamicParameterOrigin>;
+	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	// One storage item; key size 32, value size 8; .
 	pub const ProxyDepositBase: Balance = deposit(1, 40);
@@ -719,6 +778,8 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type TransferMeter = ();
+	type MaxAllowanceAssets = ConstU32<0>;
 }
 
 parameter_types! {
@@ -753,6 +814,14 @@ parameter_types! {
 	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(35) * RuntimeBlockWeights::get().max_block;
 }
 
+parameter_types! {
+	pub const MessageQueueMaxDeadLetters: u32 = 16;
+	pub const DeadLetterBaseDeposit: Balance = deposit(1, 0);
+	pub const DeadLetterByteDeposit: Balance = deposit(0, 1);
+	pub const DeadLetterHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::MessageQueue(pallet_message_queue::HoldReason::DeadLetter);
+}
+
 impl pallet_message_queue::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_message_queue::WeightInfo<Runtime>;
@@ -774,6 +843,22 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type MaxDeadLetters = MessageQueueMaxDeadLetters;
+	// Sovereign accounts pay for the dead letters of their queues.
+	type DeadLetterDepositor = parachains_common::message_queue::SiblingSovereignAccount<
+		AccountId,
+		xcm_config::LocationToAccountId,
+	>;
+	type DeadLetterConsideration = frame_support::traits::fungible::HoldConsideration<
+		AccountId,
+		Balances,
+		DeadLetterHoldReason,
+		frame_support::traits::LinearStoragePrice<
+			DeadLetterBaseDeposit,
+			DeadLetterByteDeposit,
+			Balance,
+		>,
+	>;
 }
 
 impl parachain_info::Config for Runtime {}
@@ -839,6 +924,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -847,6 +934,10 @@ impl pallet_aura::Config for Runtime {
 	type MaxAuthorities = ConstU32<100_000>;
 	type AllowMultipleBlocksPerSlot = ConstBool<true>;
 	type SlotDuration = ConstU64<SLOT_DURATION>;
+	type EquivocationReportSystem = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -875,6 +966,8 @@ impl pallet_collator_selection::Config for Runtime {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
 	type ValidatorRegistration = Session;
+	type AuthorStats = ();
+	type MinAuthoredBlocks = ();
 	type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -1099,6 +1192,7 @@ construct_runtime!(
 		Utility: pallet_utility = 40,
 		Multisig: pallet_multisig = 41,
 		Proxy: pallet_proxy = 42,
+		Parameters: pallet_parameters = 46,
 
 		// Bridge utilities.
 		ToWestendXcmRouter: pallet_xcm_bridge_hub_router::<Instance3> = 45,
@@ -1144,7 +1238,7 @@ pub type TxExtension = cumulus_pallet_weight_reclaim::StorageWeightReclaim<
 		frame_system::CheckEra<Runtime>,
 		frame_system::CheckNonce<Runtime>,
 		frame_system::CheckWeight<Runtime>,
-		pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
+		pallet_asset_conversion_tx_payment::ChargeAssetTxPaymentWithAssetTip<Runtime>,
 		frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	),
 >;
@@ -1169,6 +1263,9 @@ pub type Migrations = (
 		Runtime,
 		pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
 	>,
+	pallet_session::migrations::v2::MigrateV1ToV2<Runtime, sp_runtime::traits::ConvertInto>,
+	// unreleased
+	pallet_asset_conversion_ops::migration::InitUnmigratedPools<Runtime>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	cumulus_pallet_aura_ext::migration::MigrateV0ToV1<Runtime>,
@@ -1321,6 +1418,7 @@ mod benches {
 		[pallet_multisig, Multisig]
 		[pallet_nft_fractionalization, NftFractionalization]
 		[pallet_nfts, Nfts]
+		[pallet_parameters, Parameters]
 		[pallet_proxy, Proxy]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_uniques, Uniques]
@@ -1467,6 +1565,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_asset_conversion_ops::AssetConversionMigrationApi<Block, xcm::v5::Location> for Runtime {
+		fn unmigrated_pools() -> Vec<(xcm::v5::Location, xcm::v5::Location)> {
+			AssetConversionMigration::unmigrated_pools()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
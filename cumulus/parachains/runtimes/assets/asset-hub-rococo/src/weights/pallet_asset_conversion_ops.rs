@@ -80,4 +80,41 @@ impl<T: frame_system::Config> pallet_asset_conversion_ops::WeightInfo for Weight
 			.saturating_add(T::DbWeight::get().reads(13))
 			.saturating_add(T::DbWeight::get().writes(12))
 	}
+	/// Storage: `AssetConversionMigration::CounterForUnmigratedPools` (r:1 w:1)
+	/// Proof: `AssetConversionMigration::CounterForUnmigratedPools` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversionMigration::UnmigratedPools` (r:11 w:10)
+	/// Proof: `AssetConversionMigration::UnmigratedPools` (`max_values`: None, `max_size`: Some(1220), added: 3695, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::Pools` (r:10 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(1224), added: 3699, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:20 w:20)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:20 w:20)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:20 w:20)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:10 w:10)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:10 w:10)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssetsFreezer::FrozenBalances` (r:10 w:10)
+	/// Proof: `ForeignAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(682), added: 3157, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssetsFreezer::Freezes` (r:10 w:10)
+	/// Proof: `ForeignAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(685), added: 3160, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssetsFreezer::FrozenBalances` (r:10 w:10)
+	/// Proof: `PoolAssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssetsFreezer::Freezes` (r:10 w:10)
+	/// Proof: `PoolAssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn migrate_next(n: u32, ) -> Weight {
+		// Placeholder until the `migrate_next` benchmark is run.
+		Weight::from_parts(19_026_000, 0)
+			.saturating_add(Weight::from_parts(0, 8404))
+			// Standard Error: 6_930_875
+			.saturating_add(Weight::from_parts(277_235_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((14_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((13_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3695).saturating_mul(n.into()))
+	}
 }
@@ -174,4 +174,15 @@ impl<T: frame_system::Config> pallet_balances::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(22_108_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 	}
+	// The scheduled transfer calls are not benchmarked for this runtime yet, use the weights of
+	// the pallet until the next benchmark run.
+	fn schedule_transfer() -> Weight {
+		<pallet_balances::weights::SubstrateWeight<T> as pallet_balances::WeightInfo>::schedule_transfer()
+	}
+	fn cancel_scheduled_transfer() -> Weight {
+		<pallet_balances::weights::SubstrateWeight<T> as pallet_balances::WeightInfo>::cancel_scheduled_transfer()
+	}
+	fn execute_scheduled_transfer() -> Weight {
+		<pallet_balances::weights::SubstrateWeight<T> as pallet_balances::WeightInfo>::execute_scheduled_transfer()
+	}
 }
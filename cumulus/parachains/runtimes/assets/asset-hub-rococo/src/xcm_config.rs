@@ -476,6 +476,8 @@ impl pallet_xcm::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type MaxVersionNotificationsPerBlock = ();
+	type VersionDiscoveryPeriod = ();
 	// xcm_executor::Config::Aliasers also uses pallet_xcm::AuthorizedAliasers.
 	type AuthorizedAliasConsideration = HoldConsideration<
 		AccountId,
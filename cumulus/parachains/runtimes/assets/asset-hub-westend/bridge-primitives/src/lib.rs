@@ -31,7 +31,7 @@ use frame_support::{
 	sp_runtime::{MultiAddress, MultiSigner, RuntimeDebug, StateVersion},
 };
 use scale_info::TypeInfo;
-use testnet_parachains_constants::westend::currency::UNITS;
+use testnet_parachains_constants::westend::currency::{deposit, UNITS};
 use xcm::latest::prelude::*;
 
 /// `AssetHubWestend` Runtime `Call` enum.
@@ -54,8 +54,27 @@ frame_support::parameter_types! {
 	/// Some sane weight to execute `xcm::Transact(pallet-xcm-bridge-hub-router::Call::report_bridge_status)`.
 	pub const XcmBridgeHubRouterTransactCallMaxWeight: frame_support::weights::Weight = frame_support::weights::Weight::from_parts(200_000_000, 6144);
 
-	/// Should match the `AssetDeposit` of the `ForeignAssets` pallet on Asset Hub.
+	/// Default `AssetDeposit` of the `ForeignAssets` pallet on Asset Hub.
+	///
+	/// Asset Hub and the bridge hubs keep the actual deposit in a dynamic parameter, with this value
+	/// as the default, so it may be changed without a runtime upgrade.
 	pub const CreateForeignAssetDeposit: u128 = UNITS / 10;
+
+	/// `MetadataDepositBase` of the `ForeignAssets` pallet on Asset Hub.
+	pub const ForeignAssetMetadataDepositBase: u128 = deposit(1, 68);
+	/// `MetadataDepositPerByte` of the `ForeignAssets` pallet on Asset Hub.
+	pub const ForeignAssetMetadataDepositPerByte: u128 = deposit(0, 1);
+}
+
+/// Converts the length in bytes of the name and symbol of a foreign asset into the deposit
+/// reserved for its metadata on Asset Hub.
+pub struct ForeignAssetMetadataDeposit;
+impl frame_support::sp_runtime::traits::Convert<u32, u128> for ForeignAssetMetadataDeposit {
+	fn convert(bytes: u32) -> u128 {
+		ForeignAssetMetadataDepositPerByte::get()
+			.saturating_mul(bytes.into())
+			.saturating_add(ForeignAssetMetadataDepositBase::get())
+	}
 }
 
 /// Builds an (un)congestion XCM program with the `report_bridge_status` call for
@@ -21,11 +21,14 @@ use crate::{
 		AssetTransactors, LocationToAccountId, TrustBackedAssetsPalletLocation, UniversalLocation,
 		XcmConfig,
 	},
-	AccountId, AssetConversion, Assets, ForeignAssets, Runtime, RuntimeEvent,
+	AccountId, AssetConversion, Assets, BridgeAdmin, ForeignAssets, Runtime, RuntimeEvent,
 };
 use assets_common::{matching::FromSiblingParachain, AssetIdForTrustBackedAssetsConvert};
-use frame_support::{parameter_types, traits::EitherOf};
-use frame_system::EnsureRootWithSuccess;
+use frame_support::{
+	parameter_types,
+	traits::{EitherOf, EitherOfDiverse},
+};
+use frame_system::{EnsureRoot, EnsureRootWithSuccess};
 use parachains_common::AssetIdForTrustBackedAssets;
 use snowbridge_runtime_common::{ForeignAssetOwner, LocalAssetOwner};
 use testnet_parachains_constants::westend::snowbridge::{EthereumNetwork, FRONTEND_PALLET_INDEX};
@@ -175,6 +178,7 @@ impl snowbridge_pallet_system_frontend::Config for Runtime {
 		>,
 		EnsureRootWithSuccess<AccountId, RootLocation>,
 	>;
+	type AdminOrigin = EitherOfDiverse<EnsureRoot<AccountId>, BridgeAdmin>;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type XcmSender = XcmRouter;
 	#[cfg(feature = "runtime-benchmarks")]
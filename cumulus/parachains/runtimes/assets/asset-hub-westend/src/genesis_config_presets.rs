@@ -25,7 +25,7 @@ use frame_support::build_struct_json_patch;
 use hex_literal::hex;
 use parachains_common::{AccountId, AuraId};
 use sp_core::crypto::UncheckedInto;
-use sp_genesis_builder::PresetId;
+use sp_genesis_builder::{PresetId, PresetMetadata};
 use sp_keyring::Sr25519Keyring;
 use testnet_parachains_constants::westend::{
 	currency::UNITS as WND, xcm_version::SAFE_XCM_VERSION,
@@ -203,3 +203,19 @@ pub fn preset_names() -> Vec<PresetId> {
 		PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
 	]
 }
+
+/// Fields of the presets meant to be customized when spinning up a network.
+const PRESET_PARAMETERS: [&str; 4] = [
+	"balances.balances",
+	"collatorSelection.invulnerables",
+	"session.keys",
+	"parachainInfo.parachainId",
+];
+
+/// Metadata of the supported presets.
+pub fn presets_metadata() -> Vec<PresetMetadata> {
+	preset_names()
+		.into_iter()
+		.map(|id| PresetMetadata { id, parameters: PRESET_PARAMETERS.map(Into::into).to_vec() })
+		.collect()
+}
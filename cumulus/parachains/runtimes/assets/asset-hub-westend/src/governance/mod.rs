@@ -24,7 +24,7 @@ use frame_support::{
 };
 use frame_system::EnsureRootWithSuccess;
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
-use parachains_common::pay::{LocalPay, VersionedLocatableAccount};
+use parachains_common::pay::{LocalPay, LocalSwap, VersionedLocatableAccount};
 use polkadot_runtime_common::{
 	impls::{ContainsParts, VersionedLocatableAsset},
 	prod_or_fast,
@@ -34,8 +34,9 @@ use xcm::latest::BodyId;
 
 mod origins;
 pub use origins::{
-	pallet_custom_origins, AuctionAdmin, FellowshipAdmin, GeneralAdmin, LeaseAdmin,
-	ReferendumCanceller, ReferendumKiller, Spender, StakingAdmin, Treasurer, WhitelistedCaller,
+	pallet_custom_origins, AssetAdmin, AuctionAdmin, BridgeAdmin, FellowshipAdmin, GeneralAdmin,
+	LeaseAdmin, ReferendumCanceller, ReferendumKiller, Spender, StakingAdmin, Treasurer,
+	WhitelistedCaller,
 };
 mod tracks;
 pub use tracks::TracksInfo;
@@ -120,6 +121,9 @@ parameter_types! {
 	pub const MaxPeerInHeartbeats: u32 = 10_000;
 	pub const MaxBalance: Balance = Balance::max_value();
 	pub TreasuryAccount: AccountId = Treasury::account_id();
+	pub const MaxSpendSwapSlippage: Permill = Permill::from_percent(2);
+	pub SpendSwapWeight: Weight =
+		<weights::pallet_asset_conversion::WeightInfo<Runtime> as pallet_asset_conversion::WeightInfo>::swap_exact_tokens_for_tokens(2);
 }
 
 pub type TreasurySpender = EitherOf<EnsureRootWithSuccess<AccountId, MaxBalance>, Spender>;
@@ -134,7 +138,7 @@ impl pallet_treasury::Config for Runtime {
 	type BurnDestination = ();
 	type MaxApprovals = MaxApprovals;
 	type WeightInfo = weights::pallet_treasury::WeightInfo<Runtime>;
-	type SpendFunds = ();
+	type SpendFunds = Bounties;
 	type SpendOrigin = TreasurySpender;
 	type AssetKind = VersionedLocatableAsset;
 	type Beneficiary = VersionedLocatableAccount;
@@ -150,6 +154,9 @@ impl pallet_treasury::Config for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type MaxRecurringSpends = ConstU32<50>;
+	type AssetSwap = LocalSwap<AssetConversion, TreasuryAccount, SpendSwapWeight>;
+	type MaxSwapSlippage = MaxSpendSwapSlippage;
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = parachains_common::pay::benchmarks::LocalPayArguments<
@@ -157,6 +164,52 @@ impl pallet_treasury::Config for Runtime {
 	>;
 }
 
+parameter_types! {
+	pub const BountyDepositBase: Balance = 100 * CENTS;
+	pub const BountyDepositPayoutDelay: BlockNumber = 4 * RC_DAYS;
+	pub const BountyUpdatePeriod: BlockNumber = 90 * RC_DAYS;
+	pub const MaximumReasonLength: u32 = 16384;
+	pub const CuratorDepositMultiplier: Permill = Permill::from_percent(50);
+	pub const CuratorDepositMin: Balance = 10 * CENTS;
+	pub const CuratorDepositMax: Balance = 500 * CENTS;
+	pub const BountyValueMinimum: Balance = 200 * CENTS;
+}
+
+impl pallet_bounties::Config for Runtime {
+	type BountyDepositBase = BountyDepositBase;
+	type BountyDepositPayoutDelay = BountyDepositPayoutDelay;
+	type BountyUpdatePeriod = BountyUpdatePeriod;
+	type CuratorDepositMultiplier = CuratorDepositMultiplier;
+	type CuratorDepositMin = CuratorDepositMin;
+	type CuratorDepositMax = CuratorDepositMax;
+	type BountyValueMinimum = BountyValueMinimum;
+	type ChildBountyManager = ChildBounties;
+	type DataDepositPerByte = DataDepositPerByte;
+	type RuntimeEvent = RuntimeEvent;
+	type MaximumReasonLength = MaximumReasonLength;
+	type WeightInfo = weights::pallet_bounties::WeightInfo<Runtime>;
+	type OnSlash = Treasury;
+}
+
+parameter_types! {
+	pub const MaxActiveChildBountyCount: u32 = 100;
+	pub ChildBountyValueMinimum: Balance = BountyValueMinimum::get() / 10;
+}
+
+impl pallet_child_bounties::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
+	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type WeightInfo = weights::pallet_child_bounties::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const AssetRateFeedPeriod: BlockNumber = 1 * HOURS;
+	pub const AssetRateMaxFeedDeviation: Perbill = Perbill::from_percent(10);
+	pub const AssetRateOffchainRepeat: BlockNumber = 5 * MINUTES;
+	pub const AssetRateUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+}
+
 impl pallet_asset_rate::Config for Runtime {
 	type WeightInfo = weights::pallet_asset_rate::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
@@ -165,6 +218,12 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type AssetKind = <Runtime as pallet_treasury::Config>::AssetKind;
+	type FeederId = pallet_asset_rate::sr25519::FeederId;
+	type MaxFeeders = ConstU32<16>;
+	type FeedPeriod = AssetRateFeedPeriod;
+	type MaxFeedDeviation = AssetRateMaxFeedDeviation;
+	type OffchainRepeat = AssetRateOffchainRepeat;
+	type UnsignedPriority = AssetRateUnsignedPriority;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::AssetRateArguments;
 }
@@ -72,6 +72,12 @@ pub mod pallet_custom_origins {
 		BigSpender,
 		/// Origin able to dispatch a whitelisted call.
 		WhitelistedCaller,
+		/// Origin for force operations on assets, e.g. freezing or transferring the ownership of
+		/// an asset.
+		AssetAdmin,
+		/// Origin for managing the bridges, e.g. the bridge hub routers and the Snowbridge
+		/// operating mode.
+		BridgeAdmin,
 	}
 
 	macro_rules! decl_unit_ensures {
@@ -114,6 +120,8 @@ pub mod pallet_custom_origins {
 		ReferendumCanceller,
 		ReferendumKiller,
 		WhitelistedCaller,
+		AssetAdmin,
+		BridgeAdmin,
 	);
 
 	macro_rules! decl_ensure {
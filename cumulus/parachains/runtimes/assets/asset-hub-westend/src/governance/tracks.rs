@@ -43,6 +43,12 @@ const APP_AUCTION_ADMIN: Curve =
 	Curve::make_reciprocal(4, 28, percent(80), percent(50), percent(100));
 const SUP_AUCTION_ADMIN: Curve =
 	Curve::make_reciprocal(7, 28, percent(10), percent(0), percent(50));
+const APP_ASSET_ADMIN: Curve =
+	Curve::make_reciprocal(4, 28, percent(80), percent(50), percent(100));
+const SUP_ASSET_ADMIN: Curve = Curve::make_reciprocal(7, 28, percent(10), percent(0), percent(50));
+const APP_BRIDGE_ADMIN: Curve =
+	Curve::make_reciprocal(4, 28, percent(80), percent(50), percent(100));
+const SUP_BRIDGE_ADMIN: Curve = Curve::make_reciprocal(7, 28, percent(10), percent(0), percent(50));
 const APP_LEASE_ADMIN: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
 const SUP_LEASE_ADMIN: Curve = Curve::make_reciprocal(12, 28, percent(1), percent(0), percent(50));
 const APP_REFERENDUM_CANCELLER: Curve = Curve::make_linear(17, 28, percent(50), percent(100));
@@ -68,7 +74,7 @@ const APP_WHITELISTED_CALLER: Curve =
 const SUP_WHITELISTED_CALLER: Curve =
 	Curve::make_reciprocal(1, 28, percent(20), percent(5), percent(50));
 
-const TRACKS_DATA: [pallet_referenda::Track<u16, Balance, BlockNumber>; 15] = [
+const TRACKS_DATA: [pallet_referenda::Track<u16, Balance, BlockNumber>; 17] = [
 	pallet_referenda::Track {
 		id: 0,
 		info: pallet_referenda::TrackInfo {
@@ -181,6 +187,34 @@ const TRACKS_DATA: [pallet_referenda::Track<u16, Balance, BlockNumber>; 15] = [
 			min_support: SUP_AUCTION_ADMIN,
 		},
 	},
+	pallet_referenda::Track {
+		id: 16,
+		info: pallet_referenda::TrackInfo {
+			name: s("asset_admin"),
+			max_deciding: 10,
+			decision_deposit: 5 * GRAND,
+			prepare_period: 8 * MINUTES,
+			decision_period: 20 * MINUTES,
+			confirm_period: 8 * MINUTES,
+			min_enactment_period: 3 * MINUTES,
+			min_approval: APP_ASSET_ADMIN,
+			min_support: SUP_ASSET_ADMIN,
+		},
+	},
+	pallet_referenda::Track {
+		id: 17,
+		info: pallet_referenda::TrackInfo {
+			name: s("bridge_admin"),
+			max_deciding: 10,
+			decision_deposit: 5 * GRAND,
+			prepare_period: 8 * MINUTES,
+			decision_period: 20 * MINUTES,
+			confirm_period: 8 * MINUTES,
+			min_enactment_period: 3 * MINUTES,
+			min_approval: APP_BRIDGE_ADMIN,
+			min_support: SUP_BRIDGE_ADMIN,
+		},
+	},
 	pallet_referenda::Track {
 		id: 20,
 		info: pallet_referenda::TrackInfo {
@@ -307,6 +341,8 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 				origins::Origin::FellowshipAdmin => Ok(13),
 				origins::Origin::GeneralAdmin => Ok(14),
 				origins::Origin::AuctionAdmin => Ok(15),
+				origins::Origin::AssetAdmin => Ok(16),
+				origins::Origin::BridgeAdmin => Ok(17),
 				// Referendum admins
 				origins::Origin::ReferendumCanceller => Ok(20),
 				origins::Origin::ReferendumKiller => Ok(21),
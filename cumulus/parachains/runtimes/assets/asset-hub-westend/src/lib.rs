@@ -33,13 +33,17 @@ pub mod xcm_config;
 mod bag_thresholds;
 pub mod governance;
 mod staking;
-use governance::{pallet_custom_origins, FellowshipAdmin, GeneralAdmin, StakingAdmin, Treasurer};
+pub mod sudo_seal;
+use governance::{
+	pallet_custom_origins, AssetAdmin, BridgeAdmin, FellowshipAdmin, GeneralAdmin, StakingAdmin,
+	Treasurer, TreasuryAccount,
+};
 
 extern crate alloc;
 
 use alloc::{vec, vec::Vec};
 use assets_common::{
-	local_and_foreign_assets::{LocalFromLeft, TargetFromLeft},
+	local_and_foreign_assets::{LocalFromLeft, NativeLocalForeignOrPool, TargetFromLeft},
 	AssetIdForPoolAssets, AssetIdForPoolAssetsConvert, AssetIdForTrustBackedAssetsConvert,
 };
 use bp_asset_hub_westend::CreateForeignAssetDeposit;
@@ -51,40 +55,48 @@ use cumulus_primitives_core::{
 use frame_support::{
 	construct_runtime, derive_impl,
 	dispatch::DispatchClass,
-	genesis_builder_helper::{build_state, get_preset},
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
+	genesis_builder_helper::{build_state, get_preset, get_preset_with_patch},
 	ord_parameter_types, parameter_types,
 	traits::{
 		fungible::{self, HoldConsideration},
 		fungibles,
 		tokens::{imbalance::ResolveAssetTo, nonfungibles_v2::Inspect},
 		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8,
-		ConstantStoragePrice, EitherOfDiverse, Equals, InstanceFilter, LinearStoragePrice, Nothing,
-		TransformOrigin, WithdrawReasons,
+		ConstantStoragePrice, Contains, EitherOf, EitherOfDiverse, EnsureOrigin,
+		EnsureOriginWithArg, Equals, EverythingBut, Get, InsideBoth, MapSuccess, Nothing,
+		TieredStoragePrice, TransformOrigin, WithdrawReasons,
 	},
 	weights::{ConstantMultiplier, Weight},
 	BoundedVec, PalletId,
 };
 use frame_system::{
 	limits::{BlockLength, BlockWeights},
-	EnsureRoot, EnsureSigned, EnsureSignedBy,
+	EnsureRoot, EnsureRootWithSuccess, EnsureSigned, EnsureSignedBy,
 };
 use pallet_asset_conversion_tx_payment::SwapAssetAdapter;
 use pallet_assets::precompiles::{InlineIdConfig, ERC20};
+use pallet_fee_accounting::FeeFlow;
 use pallet_nfts::{DestroyWitness, PalletFeatures};
 use pallet_nomination_pools::PoolId;
 use pallet_revive::evm::runtime::EthExtra;
+use pallet_tx_pause::RuntimeCallNameOf;
 use pallet_xcm::{precompiles::XcmPrecompile, EnsureXcm};
 use parachains_common::{
-	impls::DealWithFees, message_queue::*, AccountId, AssetIdForTrustBackedAssets, AuraId, Balance,
-	BlockNumber, CollectionId, Hash, Header, ItemId, Nonce, Signature, AVERAGE_ON_INITIALIZE_RATIO,
-	NORMAL_DISPATCH_RATIO,
+	impls::{DustToTreasury, OnFeesSplit, SplitFees},
+	message_queue::*,
+	AccountId, AssetIdForTrustBackedAssets, AuraId, Balance, BlockNumber, CollectionId, Hash,
+	Header, ItemId, Nonce, Signature, AVERAGE_ON_INITIALIZE_RATIO, NORMAL_DISPATCH_RATIO,
 };
 use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	generic, impl_opaque_keys,
-	traits::{AccountIdConversion, BlakeTwo256, Block as BlockT, ConvertInto, Saturating, Verify},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	traits::{
+		AccountIdConversion, BlakeTwo256, Block as BlockT, ConvertInto, Replace, Saturating,
+		TransactionExtension, Verify, Zero,
+	},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, Perbill, Permill, RuntimeDebug,
 };
 #[cfg(feature = "std")]
@@ -148,7 +160,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 1_019_002,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 16,
+	transaction_version: 17,
 	system_version: 1,
 };
 
@@ -186,6 +198,8 @@ parameter_types! {
 // Configure FRAME pallets to include in runtime.
 #[derive_impl(frame_system::config_preludes::ParaChainDefaultConfig)]
 impl frame_system::Config for Runtime {
+	type BaseCallFilter =
+		InsideBoth<InsideBoth<SafeMode, TxPause>, EverythingBut<ScheduledTransferCalls>>;
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type AccountId = AccountId;
@@ -202,6 +216,8 @@ impl frame_system::Config for Runtime {
 	type OnSetCode = cumulus_pallet_parachain_system::ParachainSetCode<Self>;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 	type MultiBlockMigrator = MultiBlockMigrations;
+	type PostTransactions = cumulus_pallet_parachain_system::DepositBlockUtilizationDigest<Runtime>;
+	type FindBlockAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 }
 
 impl cumulus_pallet_weight_reclaim::Config for Runtime {
@@ -213,12 +229,15 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<0>;
+	type ExpectedMoment = pallet_aura::SlotStartMoment<Runtime>;
+	type AnomalyTolerance = ConstU64<{ SLOT_DURATION / 2 }>;
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 	type EventHandler = (CollatorSelection,);
+	type AuthorStatsWindow = ConstU32<{ 6 * HOURS }>;
 }
 
 parameter_types! {
@@ -231,7 +250,8 @@ impl pallet_balances::Config for Runtime {
 	type Balance = Balance;
 	/// The ubiquitous event type.
 	type RuntimeEvent = RuntimeEvent;
-	type DustRemoval = ();
+	type DustRemoval =
+		DustToTreasury<Runtime, TreasuryAccount, dynamic_params::dust::TreasuryShare>;
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = System;
 	type WeightInfo = weights::pallet_balances::WeightInfo<Runtime>;
@@ -239,9 +259,30 @@ impl pallet_balances::Config for Runtime {
 	type ReserveIdentifier = [u8; 8];
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = frame_support::traits::VariantCountOf<RuntimeFreezeReason>;
 	type DoneSlashHandler = ();
+	// `schedule_transfer` takes parachain block numbers, but the scheduler of this runtime counts
+	// relay chain blocks and cannot execute the transfers. The calls are filtered out by
+	// `ScheduledTransferCalls` until a scheduler counting parachain blocks is wired.
+	type Scheduler = ();
+	type MaxScheduledTransferDelay = ConstU32<{ 30 * DAYS }>;
+}
+
+/// The scheduled transfer calls of `Balances`, which are filtered out by the `BaseCallFilter`
+/// since `Balances` has no scheduler to execute the transfers.
+pub struct ScheduledTransferCalls;
+impl Contains<RuntimeCall> for ScheduledTransferCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Balances(
+				pallet_balances::Call::schedule_transfer { .. } |
+					pallet_balances::Call::cancel_scheduled_transfer { .. }
+			)
+		)
+	}
 }
 
 parameter_types! {
@@ -251,8 +292,16 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction =
-		pallet_transaction_payment::FungibleAdapter<Balances, DealWithFees<Runtime>>;
+	type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<
+		Balances,
+		SplitFees<
+			Runtime,
+			dynamic_params::fees::AuthorShare,
+			dynamic_params::fees::BurnShare,
+			TreasuryAccount,
+			AccountSplitFees,
+		>,
+	>;
 	type WeightToFee = WeightToFee;
 	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
 	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
@@ -260,8 +309,92 @@ impl pallet_transaction_payment::Config for Runtime {
 	type WeightInfo = weights::pallet_transaction_payment::WeightInfo<Runtime>;
 }
 
+impl pallet_fee_accounting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type WeightInfo = weights::pallet_fee_accounting::WeightInfo<Runtime>;
+}
+
+/// Accounts the native fees split by [`SplitFees`] in the [`FeeAccounting`] pallet.
+pub struct AccountSplitFees;
+impl OnFeesSplit<Balance> for AccountSplitFees {
+	fn on_fees_split(to_staking_pot: Balance, burned: Balance, to_treasury: Balance) {
+		FeeAccounting::note(FeeFlow::ToCollators, to_staking_pot);
+		FeeAccounting::note(FeeFlow::Burned, burned);
+		FeeAccounting::note(FeeFlow::ToTreasury, to_treasury);
+	}
+}
+
+/// Accounts the fees which `Inner` swapped from other assets than [`WestendLocation`] into the
+/// native asset as [`FeeFlow::Swapped`] in the [`FeeAccounting`] pallet.
+pub struct AccountSwappedFees<Inner>(core::marker::PhantomData<Inner>);
+impl<Inner> pallet_asset_conversion_tx_payment::OnChargeAssetTransaction<Runtime>
+	for AccountSwappedFees<Inner>
+where
+	Inner: pallet_asset_conversion_tx_payment::OnChargeAssetTransaction<
+		Runtime,
+		AssetId = xcm::v5::Location,
+		Balance = Balance,
+	>,
+{
+	type Balance = Balance;
+	type AssetId = xcm::v5::Location;
+	type LiquidityInfo = Inner::LiquidityInfo;
+
+	fn withdraw_fee(
+		who: &AccountId,
+		call: &RuntimeCall,
+		dispatch_info: &sp_runtime::traits::DispatchInfoOf<RuntimeCall>,
+		asset_id: Self::AssetId,
+		fee: Balance,
+		tip: Balance,
+	) -> Result<Self::LiquidityInfo, sp_runtime::transaction_validity::TransactionValidityError> {
+		Inner::withdraw_fee(who, call, dispatch_info, asset_id, fee, tip)
+	}
+
+	fn can_withdraw_fee(
+		who: &AccountId,
+		asset_id: Self::AssetId,
+		fee: Balance,
+	) -> Result<(), sp_runtime::transaction_validity::TransactionValidityError> {
+		Inner::can_withdraw_fee(who, asset_id, fee)
+	}
+
+	fn correct_and_deposit_fee(
+		who: &AccountId,
+		dispatch_info: &sp_runtime::traits::DispatchInfoOf<RuntimeCall>,
+		post_info: &sp_runtime::traits::PostDispatchInfoOf<RuntimeCall>,
+		corrected_fee: Balance,
+		tip: Balance,
+		asset_id: Self::AssetId,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Result<Balance, sp_runtime::transaction_validity::TransactionValidityError> {
+		let swapped = asset_id != WestendLocation::get();
+		let deposit_fee = || {
+			Inner::correct_and_deposit_fee(
+				who,
+				dispatch_info,
+				post_info,
+				corrected_fee,
+				tip,
+				asset_id,
+				already_withdrawn,
+			)
+		};
+		if swapped {
+			FeeAccounting::account_swapped(deposit_fee)
+		} else {
+			deposit_fee()
+		}
+	}
+
+	fn tip_to_native(asset_id: Self::AssetId, tip: Balance) -> Option<Balance> {
+		Inner::tip_to_native(asset_id, tip)
+	}
+}
+
 parameter_types! {
-	pub const AssetDeposit: Balance = UNITS / 10; // 1 / 10 WND deposit to create asset
+	pub AssetDeposit: Balance = dynamic_params::assets::AssetDeposit::get();
 	pub const AssetAccountDeposit: Balance = deposit(1, 16);
 	pub const ApprovalDeposit: Balance = EXISTENTIAL_DEPOSIT;
 	pub const AssetsStringLimit: u32 = 50;
@@ -271,7 +404,7 @@ parameter_types! {
 	pub const MetadataDepositPerByte: Balance = deposit(0, 1);
 }
 
-pub type AssetsForceOrigin = EnsureRoot<AccountId>;
+pub type AssetsForceOrigin = EitherOfDiverse<EnsureRoot<AccountId>, AssetAdmin>;
 
 // Called "Trust Backed" assets because these are generally registered by some account, and users of
 // the asset assume it has some claimed backing. The pallet is called `Assets` in
@@ -396,32 +529,39 @@ pub type NativeAndNonPoolAssetsFreezer = fungible::UnionOf<
 	AccountId,
 >;
 
-/// Union fungibles implementation for [`PoolAssets`] and [`NativeAndNonPoolAssets`].
+/// Criterion of [`NativeAndAllAssets`] and [`NativeAndAllAssetsFreezer`].
+pub type NativeLocalForeignOrPoolAsset = NativeLocalForeignOrPool<
+	WestendLocation,
+	AssetIdForTrustBackedAssetsConvert<TrustBackedAssetsPalletLocation, xcm::v5::Location>,
+	AssetIdForTrustBackedAssets,
+	AssetIdForPoolAssetsConvert<PoolAssetsPalletLocation, xcm::v5::Location>,
+	AssetIdForPoolAssets,
+	xcm::v5::Location,
+>;
+
+/// Union fungibles implementation for `Balances`, `Assets`, `ForeignAssets` and [`PoolAssets`].
 ///
 /// NOTE: Should be kept updated to include ALL balances and assets in the runtime.
-pub type NativeAndAllAssets = fungibles::UnionOf<
+pub type NativeAndAllAssets = fungible::UnionOf4<
+	Balances,
+	Assets,
+	ForeignAssets,
 	PoolAssets,
-	NativeAndNonPoolAssets,
-	LocalFromLeft<
-		AssetIdForPoolAssetsConvert<PoolAssetsPalletLocation, xcm::v5::Location>,
-		AssetIdForPoolAssets,
-		xcm::v5::Location,
-	>,
+	NativeLocalForeignOrPoolAsset,
 	xcm::v5::Location,
 	AccountId,
 >;
 
-/// Union fungibles implementation for [`PoolAssetsFreezer`] and [`NativeAndNonPoolAssetsFreezer`].
+/// Union fungibles implementation for `Balances`, `AssetsFreezer`, `ForeignAssetsFreezer` and
+/// [`PoolAssetsFreezer`].
 ///
 /// NOTE: Should be kept updated to include ALL balances and assets in the runtime.
-pub type NativeAndAllAssetsFreezer = fungibles::UnionOf<
+pub type NativeAndAllAssetsFreezer = fungible::UnionOf4<
+	Balances,
+	AssetsFreezer,
+	ForeignAssetsFreezer,
 	PoolAssetsFreezer,
-	NativeAndNonPoolAssetsFreezer,
-	LocalFromLeft<
-		AssetIdForPoolAssetsConvert<PoolAssetsPalletLocation, xcm::v5::Location>,
-		AssetIdForPoolAssets,
-		xcm::v5::Location,
-	>,
+	NativeLocalForeignOrPoolAsset,
 	xcm::v5::Location,
 	AccountId,
 >;
@@ -500,6 +640,20 @@ impl pallet_vesting::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_vesting::WeightInfo<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type SplitOrigin = EnsureSigned<AccountId>;
+}
+
+impl pallet_assets_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Assets = Assets;
+	type Freezer = AssetsFreezer;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type BlockNumberToBalance = ConvertInto;
+	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
+	type MaxVestingSchedules = ConstU32<28>;
+	type WeightInfo = weights::pallet_assets_vesting::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -543,12 +697,14 @@ impl pallet_asset_conversion_ops::Config for Runtime {
 }
 
 parameter_types! {
-	pub const ForeignAssetsAssetDeposit: Balance = CreateForeignAssetDeposit::get();
+	pub ForeignAssetsAssetDeposit: Balance = dynamic_params::assets::ForeignAssetDeposit::get();
 	pub const ForeignAssetsAssetAccountDeposit: Balance = AssetAccountDeposit::get();
 	pub const ForeignAssetsApprovalDeposit: Balance = ApprovalDeposit::get();
 	pub const ForeignAssetsAssetsStringLimit: u32 = AssetsStringLimit::get();
-	pub const ForeignAssetsMetadataDepositBase: Balance = MetadataDepositBase::get();
-	pub const ForeignAssetsMetadataDepositPerByte: Balance = MetadataDepositPerByte::get();
+	pub const ForeignAssetsMetadataDepositBase: Balance =
+		bp_asset_hub_westend::ForeignAssetMetadataDepositBase::get();
+	pub const ForeignAssetsMetadataDepositPerByte: Balance =
+		bp_asset_hub_westend::ForeignAssetMetadataDepositPerByte::get();
 }
 
 /// Assets managed by some foreign location. Note: we do not declare a `ForeignAssetsCall` type, as
@@ -613,6 +769,10 @@ impl pallet_multisig::Config for Runtime {
 	type MaxSignatories = MaxSignatories;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = System;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 impl pallet_utility::Config for Runtime {
@@ -622,19 +782,276 @@ impl pallet_utility::Config for Runtime {
 	type WeightInfo = weights::pallet_utility::WeightInfo<Runtime>;
 }
 
+/// Dynamic parameters that can be changed at runtime through
+/// `pallet_parameters::set_parameter`.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// Deposits taken for creating assets.
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod assets {
+		/// Deposit for creating a trust backed asset or fractionalizing an NFT.
+		#[codec(index = 0)]
+		pub static AssetDeposit: Balance = UNITS / 10;
+
+		/// Deposit for creating a foreign asset.
+		///
+		/// Bridge hubs creating foreign assets on behalf of bridged tokens pay their own
+		/// `CreateForeignAssetDeposit` dynamic parameter, which defaults to
+		/// [`CreateForeignAssetDeposit`], so both values should be changed together.
+		#[codec(index = 1)]
+		pub static ForeignAssetDeposit: Balance = CreateForeignAssetDeposit::get();
+	}
+
+	/// Deposits taken by the proxy pallet.
+	#[dynamic_pallet_params]
+	#[codec(index = 1)]
+	pub mod proxy {
+		/// Base deposit for a proxy. One storage item; key size 32, value size 8.
+		#[codec(index = 0)]
+		pub static ProxyDepositBase: Balance = deposit(1, 40);
+
+		/// Deposit per proxy. Additional storage item size of 33 bytes.
+		#[codec(index = 1)]
+		pub static ProxyDepositFactor: Balance = deposit(0, 33);
+
+		/// Base deposit for announcements. One storage item; key size 32, value size 16.
+		#[codec(index = 2)]
+		pub static AnnouncementDepositBase: Balance = deposit(1, 48);
+
+		/// Deposit per announcement.
+		#[codec(index = 3)]
+		pub static AnnouncementDepositFactor: Balance = deposit(0, 66);
+	}
+
+	/// Fees for delivering XCM messages to sibling parachains and the relay chain.
+	#[dynamic_pallet_params]
+	#[codec(index = 2)]
+	pub mod delivery {
+		/// The base fee for the message delivery fees.
+		#[codec(index = 0)]
+		pub static BaseDeliveryFee: Balance = CENTS.saturating_mul(3);
+
+		/// The fee per byte of a delivered message.
+		#[codec(index = 1)]
+		pub static ByteFee: Balance = TransactionByteFee::get();
+	}
+
+	/// Handling of dust removed by the balances pallet.
+	#[dynamic_pallet_params]
+	#[codec(index = 3)]
+	pub mod dust {
+		/// The share of dust deposited into the treasury. The rest is burned.
+		#[codec(index = 0)]
+		pub static TreasuryShare: Perbill = Perbill::zero();
+	}
+
+	/// Split of the transaction fees and tips, including those paid in other assets and swapped
+	/// into the native token.
+	#[dynamic_pallet_params]
+	#[codec(index = 4)]
+	pub mod fees {
+		/// The share of fees and tips deposited into the collators' staking pot.
+		#[codec(index = 0)]
+		pub static AuthorShare: Permill = Permill::one();
+
+		/// The share of fees and tips burned. The remainder after the author's and the burned
+		/// shares is deposited into the treasury.
+		#[codec(index = 1)]
+		pub static BurnShare: Permill = Permill::zero();
+	}
+
+	/// Deposits taken for noting preimages.
+	#[dynamic_pallet_params]
+	#[codec(index = 5)]
+	pub mod preimage {
+		/// Base deposit for a preimage.
+		#[codec(index = 0)]
+		pub static BaseDeposit: Balance = deposit(2, 64);
+
+		/// Deposit per byte of a preimage up to [`TierThreshold`].
+		#[codec(index = 1)]
+		pub static ByteDeposit: Balance = deposit(0, 1);
+
+		/// The size in bytes above which [`SteepByteDeposit`] is charged.
+		#[codec(index = 2)]
+		pub static TierThreshold: u64 = 64 * 1024;
+
+		/// Deposit per byte of a preimage above [`TierThreshold`].
+		#[codec(index = 3)]
+		pub static SteepByteDeposit: Balance = deposit(0, 4);
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Assets(dynamic_params::assets::Parameters::AssetDeposit(
+			dynamic_params::assets::AssetDeposit,
+			Some(UNITS / 10),
+		))
+	}
+}
+
+/// Defines what origin can modify which dynamic parameters.
+pub struct DynamicParameterOrigin;
+impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParameterOrigin {
+	type Success = ();
+
+	fn try_origin(
+		origin: RuntimeOrigin,
+		key: &RuntimeParametersKey,
+	) -> Result<Self::Success, RuntimeOrigin> {
+		use crate::RuntimeParametersKey::*;
+
+		match key {
+			Assets(_) => AssetsForceOrigin::try_origin(origin).map(|_| ()),
+			Proxy(_) => EitherOfDiverse::<EnsureRoot<AccountId>, GeneralAdmin>::try_origin(origin)
+				.map(|_| ()),
+			Dust(_) => EitherOfDiverse::<EnsureRoot<AccountId>, GeneralAdmin>::try_origin(origin)
+				.map(|_| ()),
+			Fees(_) => EitherOfDiverse::<EnsureRoot<AccountId>, GeneralAdmin>::try_origin(origin)
+				.map(|_| ()),
+			Preimage(_) =>
+				EitherOfDiverse::<EnsureRoot<AccountId>, GeneralAdmin>::try_origin(origin)
+					.map(|_| ()),
+			Delivery(_) => EitherOfDiverse::<
+				EnsureRoot<AccountId>,
+				EitherOfDiverse<GeneralAdmin, BridgeAdmin>,
+			>::try_origin(origin)
+			.map(|_| ()),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(_key: &RuntimeParametersKey) -> Result<RuntimeOrigin, ()> {
+		// Provide the origin for the parameter returned by `Default`:
+		Ok(RuntimeOrigin::root())
+	}
+}
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = DynamicParameterOrigin;
+	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
+/// Calls that can bypass the safe-mode pallet.
+///
+/// Besides the pallets needed to leave safe mode again, this keeps block production, XCM,
+/// governance and the staking/session flow with the relay chain running. Of `PolkadotXcm` only
+/// the privileged calls managing XCM versions and suspension are allowed, so that no assets can be
+/// moved off the chain while in safe mode.
+pub struct SafeModeWhitelistedCalls;
+impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::System(..) |
+				RuntimeCall::ParachainSystem(..) |
+				RuntimeCall::Timestamp(..) |
+				RuntimeCall::SafeMode(..) |
+				RuntimeCall::TxPause(..) |
+				RuntimeCall::Session(..) |
+				RuntimeCall::Staking(..) |
+				RuntimeCall::StakingRcClient(..) |
+				RuntimeCall::MultiBlockElection(..) |
+				RuntimeCall::MultiBlockElectionVerifier(..) |
+				RuntimeCall::MultiBlockElectionUnsigned(..) |
+				RuntimeCall::MultiBlockElectionSigned(..) |
+				RuntimeCall::XcmpQueue(..) |
+				RuntimeCall::PolkadotXcm(
+					pallet_xcm::Call::force_xcm_version { .. } |
+						pallet_xcm::Call::force_default_xcm_version { .. } |
+						pallet_xcm::Call::force_subscribe_version_notify { .. } |
+						pallet_xcm::Call::force_unsubscribe_version_notify { .. } |
+						pallet_xcm::Call::force_suspension { .. }
+				) | RuntimeCall::MessageQueue(..) |
+				RuntimeCall::Preimage(..) |
+				RuntimeCall::ConvictionVoting(..) |
+				RuntimeCall::Referenda(..) |
+				RuntimeCall::Whitelist(..)
+		)
+	}
+}
+
+/// Calls that cannot be paused by the tx-pause pallet.
+pub struct TxPauseWhitelistedCalls;
+impl Contains<RuntimeCallNameOf<Runtime>> for TxPauseWhitelistedCalls {
+	fn contains(full_name: &RuntimeCallNameOf<Runtime>) -> bool {
+		matches!(
+			full_name.0.as_slice(),
+			b"System" |
+				b"ParachainSystem" |
+				b"Timestamp" | b"SafeMode" |
+				b"TxPause" | b"Session" |
+				b"StakingRcClient" |
+				b"XcmpQueue" | b"MessageQueue"
+		)
+	}
+}
+
 parameter_types! {
-	// One storage item; key size 32, value size 8; .
-	pub const ProxyDepositBase: Balance = deposit(1, 40);
-	// Additional storage item size of 33 bytes.
-	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	pub const SafeModeForceEnterDuration: BlockNumber = 1 * DAYS;
+	pub const SafeModeForceExtendDuration: BlockNumber = 12 * HOURS;
+	pub const SafeModeEnterDuration: BlockNumber = 4 * HOURS;
+	pub const SafeModeExtendDuration: BlockNumber = 2 * HOURS;
+	/// Permissionless entering and extending of safe mode is disabled.
+	pub const SafeModeDepositAmount: Option<Balance> = None;
+	pub const SafeModeReleaseDelay: Option<BlockNumber> = None;
+}
+
+/// Origin that can pause and unpause calls and force safe mode: Root or the `GeneralAdmin` track.
+pub type EmergencyOrigin = EitherOfDiverse<EnsureRoot<AccountId>, GeneralAdmin>;
+
+impl pallet_tx_pause::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PauseOrigin = EmergencyOrigin;
+	type UnpauseOrigin = EmergencyOrigin;
+	type WhitelistedCalls = TxPauseWhitelistedCalls;
+	type MaxNameLen = ConstU32<256>;
+	type WeightInfo = weights::pallet_tx_pause::WeightInfo<Runtime>;
+}
+
+impl pallet_safe_mode::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type WhitelistedCalls = SafeModeWhitelistedCalls;
+	type EnterDuration = SafeModeEnterDuration;
+	type EnterDepositAmount = SafeModeDepositAmount;
+	type ExtendDuration = SafeModeExtendDuration;
+	type ExtendDepositAmount = SafeModeDepositAmount;
+	type ForceEnterOrigin = EitherOf<
+		EnsureRootWithSuccess<AccountId, SafeModeForceEnterDuration>,
+		MapSuccess<GeneralAdmin, Replace<SafeModeForceEnterDuration>>,
+	>;
+	type ForceExtendOrigin = EitherOf<
+		EnsureRootWithSuccess<AccountId, SafeModeForceExtendDuration>,
+		MapSuccess<GeneralAdmin, Replace<SafeModeForceExtendDuration>>,
+	>;
+	type ForceExitOrigin = EmergencyOrigin;
+	type ForceDepositOrigin = EnsureRoot<AccountId>;
+	type ReleaseDelay = SafeModeReleaseDelay;
+	type Notify = ();
+	type WeightInfo = weights::pallet_safe_mode::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub ProxyDepositBase: Balance = dynamic_params::proxy::ProxyDepositBase::get();
+	pub ProxyDepositFactor: Balance = dynamic_params::proxy::ProxyDepositFactor::get();
 	pub const MaxProxies: u16 = 32;
-	// One storage item; key size 32, value size 16
-	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
-	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub AnnouncementDepositBase: Balance = dynamic_params::proxy::AnnouncementDepositBase::get();
+	pub AnnouncementDepositFactor: Balance = dynamic_params::proxy::AnnouncementDepositFactor::get();
 	pub const MaxPending: u16 = 32;
 }
 
 /// The type used to represent the kinds of proxying allowed.
+#[frame_support::instance_filter(RuntimeCall, Runtime)]
 #[derive(
 	Copy,
 	Clone,
@@ -651,43 +1068,177 @@ parameter_types! {
 )]
 pub enum ProxyType {
 	/// Fully permissioned proxy. Can execute any call on behalf of _proxied_.
+	#[filter(any)]
 	Any,
 	/// Can execute any call that does not transfer funds or assets.
+	///
+	/// Acting as a recovered account gives access to all of its funds, so `as_recovered` is
+	/// excluded. Setting up a recovery configuration or initiating a recovery would let the proxy
+	/// take over an account, so `create_recovery` and `initiate_recovery` are excluded too.
+	/// Vesting and merging vesting schedules is allowed, but vested transfers are not.
+	#[filter(
+		deny(
+			Balances,
+			Assets,
+			NftFractionalization,
+			Nfts,
+			ForeignNfts,
+			XcmNftRegistry,
+			Uniques,
+			Scheduler,
+			Treasury,
+			Bounties,
+			ChildBounties,
+			Recovery::as_recovered,
+			Recovery::create_recovery,
+			Recovery::initiate_recovery,
+			Vesting::vested_transfer,
+			AssetsVesting::vested_transfer,
+			ConvictionVoting,
+			Referenda,
+			Whitelist,
+		),
+		superset_of(Collator, Governance, Staking, NominationPools)
+	)]
 	NonTransfer,
 	/// Proxy with the ability to reject time-delay proxy announcements.
+	#[filter(allow(Proxy::reject_announcement, Utility, Multisig))]
 	CancelProxy,
 	/// Assets proxy. Can execute any call from `assets`, **including asset transfers**.
+	#[filter(
+		allow(Assets, Utility, Multisig, NftFractionalization, Nfts, ForeignNfts, Uniques),
+		superset_of(AssetOwner, AssetManager)
+	)]
 	Assets,
 	/// Owner proxy. Can execute calls related to asset ownership.
+	#[filter(allow(
+		Assets::create,
+		Assets::start_destroy,
+		Assets::destroy_accounts,
+		Assets::destroy_approvals,
+		Assets::finish_destroy,
+		Assets::transfer_ownership,
+		Assets::set_team,
+		Assets::set_metadata,
+		Assets::clear_metadata,
+		Assets::set_min_balance,
+		Nfts::create,
+		Nfts::destroy,
+		Nfts::redeposit,
+		Nfts::transfer_ownership,
+		Nfts::set_team,
+		Nfts::set_collection_max_supply,
+		Nfts::lock_collection,
+		Uniques::create,
+		Uniques::destroy,
+		Uniques::transfer_ownership,
+		Uniques::set_team,
+		Uniques::set_metadata,
+		Uniques::set_attribute,
+		Uniques::set_collection_metadata,
+		Uniques::clear_metadata,
+		Uniques::clear_attribute,
+		Uniques::clear_collection_metadata,
+		Uniques::set_collection_max_supply,
+		Utility,
+		Multisig,
+	))]
 	AssetOwner,
 	/// Asset manager. Can execute calls related to asset management.
+	#[filter(allow(
+		Assets::mint,
+		Assets::burn,
+		Assets::freeze,
+		Assets::block,
+		Assets::thaw,
+		Assets::freeze_asset,
+		Assets::thaw_asset,
+		Assets::touch_other,
+		Assets::refund_other,
+		Nfts::force_mint,
+		Nfts::update_mint_settings,
+		Nfts::mint_pre_signed,
+		Nfts::set_attributes_pre_signed,
+		Nfts::lock_item_transfer,
+		Nfts::unlock_item_transfer,
+		Nfts::lock_item_properties,
+		Nfts::set_metadata,
+		Nfts::clear_metadata,
+		Nfts::set_collection_metadata,
+		Nfts::clear_collection_metadata,
+		Uniques::mint,
+		Uniques::burn,
+		Uniques::freeze,
+		Uniques::thaw,
+		Uniques::freeze_collection,
+		Uniques::thaw_collection,
+		Utility,
+		Multisig,
+	))]
 	AssetManager,
 	/// Collator selection proxy. Can execute calls related to collator selection mechanism.
+	#[filter(allow(CollatorSelection, Utility, Multisig))]
 	Collator,
 	// New variants introduced by the Asset Hub Migration from the Relay Chain.
 	/// Allow to do governance.
-	///
-	/// Contains pallets `Treasury`, `Bounties`, `Utility`, `ChildBounties`, `ConvictionVoting`,
-	/// `Referenda` and `Whitelist`.
+	#[filter(allow(
+		Treasury,
+		Bounties,
+		Utility,
+		ChildBounties,
+		ConvictionVoting,
+		Referenda,
+		Whitelist,
+	))]
 	Governance,
 	/// Allows access to staking related calls.
-	///
-	/// Contains the `Staking`, `Session`, `Utility`, `FastUnstake`, `VoterList`, `NominationPools`
-	/// pallets.
+	#[filter(allow(Staking, Session, Utility, NominationPools, FastUnstake, VoterList))]
 	Staking,
 	/// Allows access to nomination pools related calls.
-	///
-	/// Contains the `NominationPools` and `Utility` pallets.
+	#[filter(allow(NominationPools, Utility))]
 	NominationPools,
 
 	/// Placeholder variant to track the state before the Asset Hub Migration.
+	#[filter(nothing)]
 	OldSudoBalances,
 	/// Placeholder variant to track the state before the Asset Hub Migration.
+	#[filter(nothing)]
 	OldIdentityJudgement,
 	/// Placeholder variant to track the state before the Asset Hub Migration.
+	#[filter(nothing)]
 	OldAuction,
 	/// Placeholder variant to track the state before the Asset Hub Migration.
+	#[filter(nothing)]
 	OldParaRegistration,
+	/// Liquidity provider proxy. Can create and touch asset conversion pools, add and remove
+	/// liquidity, swap, stake in `AssetRewards` pools and touch or refund `PoolAssets` accounts.
+	///
+	/// Only the variants of the calls paying out to the proxied account are allowed, so that the
+	/// proxy cannot move funds to other accounts. Neither `Assets` nor `NonTransfer` are a
+	/// superset of it, since it moves funds into and out of pools.
+	#[filter(allow(
+		AssetConversion::create_pool,
+		AssetConversion::touch,
+		AssetConversion::add_liquidity_to_sender,
+		AssetConversion::remove_liquidity_to_sender,
+		AssetConversion::swap_exact_tokens_for_tokens_to_sender,
+		AssetConversion::swap_tokens_for_exact_tokens_to_sender,
+		AssetRewards::stake,
+		AssetRewards::unstake,
+		AssetRewards::harvest_rewards,
+		PoolAssets::touch,
+		PoolAssets::refund,
+		Utility,
+		Multisig,
+	))]
+	AssetLiquidity,
+	/// Smart contract operator proxy. Can upload and remove code, instantiate and call
+	/// contracts and map accounts in `Revive`, as long as no value is transferred and no
+	/// pre-compile is called.
+	///
+	/// `dispatch_as_fallback_account` is excluded, since it can dispatch arbitrary calls.
+	#[filter(with = smart_contracts_proxy_filter)]
+	SmartContracts,
 }
 impl Default for ProxyType {
 	fn default() -> Self {
@@ -695,160 +1246,99 @@ impl Default for ProxyType {
 	}
 }
 
-impl InstanceFilter<RuntimeCall> for ProxyType {
-	fn filter(&self, c: &RuntimeCall) -> bool {
-		match self {
-			ProxyType::Any => true,
-			ProxyType::OldSudoBalances |
-			ProxyType::OldIdentityJudgement |
-			ProxyType::OldAuction |
-			ProxyType::OldParaRegistration => false,
-			ProxyType::NonTransfer => !matches!(
-				c,
-				RuntimeCall::Balances { .. } |
-					RuntimeCall::Assets { .. } |
-					RuntimeCall::NftFractionalization { .. } |
-					RuntimeCall::Nfts { .. } |
-					RuntimeCall::Uniques { .. } |
-					RuntimeCall::Scheduler(..) |
-					RuntimeCall::Treasury(..) |
-					// We allow calling `vest` and merging vesting schedules, but obviously not
-					// vested transfers.
-					RuntimeCall::Vesting(pallet_vesting::Call::vested_transfer { .. }) |
-					RuntimeCall::ConvictionVoting(..) |
-					RuntimeCall::Referenda(..) |
-					RuntimeCall::Whitelist(..)
-			),
-			ProxyType::CancelProxy => matches!(
-				c,
-				RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. }) |
-					RuntimeCall::Utility { .. } |
-					RuntimeCall::Multisig { .. }
-			),
-			ProxyType::Assets => {
-				matches!(
-					c,
-					RuntimeCall::Assets { .. } |
-						RuntimeCall::Utility { .. } |
-						RuntimeCall::Multisig { .. } |
-						RuntimeCall::NftFractionalization { .. } |
-						RuntimeCall::Nfts { .. } |
-						RuntimeCall::Uniques { .. }
-				)
-			},
-			ProxyType::AssetOwner => matches!(
-				c,
-				RuntimeCall::Assets(TrustBackedAssetsCall::create { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::start_destroy { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::destroy_accounts { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::destroy_approvals { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::finish_destroy { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::transfer_ownership { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::set_team { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::set_metadata { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::clear_metadata { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::set_min_balance { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::create { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::destroy { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::redeposit { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::transfer_ownership { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::set_team { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::set_collection_max_supply { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::lock_collection { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::create { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::destroy { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::transfer_ownership { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::set_team { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::set_metadata { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::set_attribute { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::set_collection_metadata { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::clear_metadata { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::clear_attribute { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::clear_collection_metadata { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::set_collection_max_supply { .. }) |
-					RuntimeCall::Utility { .. } |
-					RuntimeCall::Multisig { .. }
-			),
-			ProxyType::AssetManager => matches!(
-				c,
-				RuntimeCall::Assets(TrustBackedAssetsCall::mint { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::burn { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::freeze { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::block { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::thaw { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::freeze_asset { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::thaw_asset { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::touch_other { .. }) |
-					RuntimeCall::Assets(TrustBackedAssetsCall::refund_other { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::force_mint { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::update_mint_settings { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::mint_pre_signed { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::set_attributes_pre_signed { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::lock_item_transfer { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::unlock_item_transfer { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::lock_item_properties { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::set_metadata { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::clear_metadata { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::set_collection_metadata { .. }) |
-					RuntimeCall::Nfts(pallet_nfts::Call::clear_collection_metadata { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::mint { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::burn { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::freeze { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::thaw { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::freeze_collection { .. }) |
-					RuntimeCall::Uniques(pallet_uniques::Call::thaw_collection { .. }) |
-					RuntimeCall::Utility { .. } |
-					RuntimeCall::Multisig { .. }
-			),
-			ProxyType::Collator => matches!(
-				c,
-				RuntimeCall::CollatorSelection { .. } |
-					RuntimeCall::Utility { .. } |
-					RuntimeCall::Multisig { .. }
-			),
-			// New variants introduced by the Asset Hub Migration from the Relay Chain.
-			ProxyType::Governance => matches!(
-				c,
-				RuntimeCall::Treasury(..) |
-					RuntimeCall::Utility(..) |
-					RuntimeCall::ConvictionVoting(..) |
-					RuntimeCall::Referenda(..) |
-					RuntimeCall::Whitelist(..)
-			),
-			ProxyType::Staking => {
-				matches!(
-					c,
-					RuntimeCall::Staking(..) |
-						RuntimeCall::Session(..) |
-						RuntimeCall::Utility(..) |
-						RuntimeCall::NominationPools(..) |
-						RuntimeCall::FastUnstake(..) |
-						RuntimeCall::VoterList(..)
-				)
-			},
-			ProxyType::NominationPools => {
-				matches!(c, RuntimeCall::NominationPools(..) | RuntimeCall::Utility(..))
-			},
-		}
+/// Whether `address` lies in the address range of the `Revive` pre-compiles.
+///
+/// Pre-compiles only ever use the first four and the last four bytes of their addresses, see
+/// [`pallet_revive::precompiles::AddressMatcher`].
+fn is_precompile_address(address: &sp_core::H160) -> bool {
+	address.as_bytes()[4..16].iter().all(|byte| *byte == 0)
+}
+
+/// The filter of [`ProxyType::SmartContracts`], which depends on the value and the callee of the
+/// calls.
+///
+/// Calling a pre-compile directly could move the assets of the proxied account, e.g. through the
+/// ERC20 or the XCM pre-compile, so pre-compiles cannot be called even without value.
+fn smart_contracts_proxy_filter(c: &RuntimeCall) -> bool {
+	match c {
+		RuntimeCall::Revive(pallet_revive::Call::call { dest, value, .. }) =>
+			value.is_zero() && !is_precompile_address(dest),
+		RuntimeCall::Revive(
+			pallet_revive::Call::instantiate { value, .. } |
+			pallet_revive::Call::instantiate_with_code { value, .. },
+		) => value.is_zero(),
+		RuntimeCall::Revive(pallet_revive::Call::upload_code { .. }) |
+		RuntimeCall::Revive(pallet_revive::Call::remove_code { .. }) |
+		RuntimeCall::Revive(pallet_revive::Call::map_account { .. }) |
+		RuntimeCall::Revive(pallet_revive::Call::unmap_account { .. }) |
+		RuntimeCall::Utility { .. } |
+		RuntimeCall::Multisig { .. } => true,
+		_ => false,
 	}
+}
+
+/// Reports the native, trust-backed and foreign asset transfers of calls, keyed by the location of
+/// the asset, to meter proxy allowances.
+///
+/// All other ways to move funds, e.g. XCM transfers, swaps, vested transfers or contract calls, are
+/// unknown to the meter, so proxies with an allowance cannot use them.
+pub struct ProxyTransferMeter;
+impl pallet_proxy::TransferMeter<RuntimeCall, Balance> for ProxyTransferMeter {
+	type Asset = xcm::v5::Location;
+
+	fn transferred(call: &RuntimeCall) -> pallet_proxy::Transferred<xcm::v5::Location, Balance> {
+		use pallet_proxy::Transferred;
+		use sp_runtime::traits::MaybeEquivalence;
+		type ForeignAssetsCall = pallet_assets::Call<Runtime, ForeignAssetsInstance>;
+		type TrustBackedAssetsLocation =
+			AssetIdForTrustBackedAssetsConvert<TrustBackedAssetsPalletLocation, xcm::v5::Location>;
+
+		let trust_backed = |id: &codec::Compact<AssetIdForTrustBackedAssets>, amount: Balance| {
+			TrustBackedAssetsLocation::convert_back(&id.0)
+				.map_or(Transferred::Unknown, |location| Transferred::Amount(location, amount))
+		};
 
-	fn is_superset(&self, o: &Self) -> bool {
-		match (self, o) {
-			(x, y) if x == y => true,
-			(ProxyType::Any, _) => true,
-			(_, ProxyType::Any) => false,
-			(ProxyType::Assets, ProxyType::AssetOwner) => true,
-			(ProxyType::Assets, ProxyType::AssetManager) => true,
-			(
-				ProxyType::NonTransfer,
-				ProxyType::Collator |
-				ProxyType::Governance |
-				ProxyType::Staking |
-				ProxyType::NominationPools,
-			) => true,
-			_ => false,
+		match call {
+			RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+				value, ..
+			}) |
+			RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { value, .. }) =>
+				Transferred::Amount(WestendLocation::get(), *value),
+			RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) =>
+				Transferred::Amount(WestendLocation::get(), Balance::MAX),
+			RuntimeCall::Assets(TrustBackedAssetsCall::transfer { id, amount, .. }) |
+			RuntimeCall::Assets(TrustBackedAssetsCall::transfer_keep_alive {
+				id, amount, ..
+			}) |
+			RuntimeCall::Assets(TrustBackedAssetsCall::approve_transfer { id, amount, .. }) =>
+				trust_backed(id, *amount),
+			RuntimeCall::Assets(TrustBackedAssetsCall::transfer_all { id, .. }) =>
+				trust_backed(id, Balance::MAX),
+			RuntimeCall::ForeignAssets(ForeignAssetsCall::transfer { id, amount, .. }) |
+			RuntimeCall::ForeignAssets(ForeignAssetsCall::transfer_keep_alive {
+				id,
+				amount,
+				..
+			}) |
+			RuntimeCall::ForeignAssets(ForeignAssetsCall::approve_transfer {
+				id, amount, ..
+			}) => Transferred::Amount(id.clone(), *amount),
+			RuntimeCall::ForeignAssets(ForeignAssetsCall::transfer_all { id, .. }) =>
+				Transferred::Amount(id.clone(), Balance::MAX),
+			// The calls dispatched by a batch are metered on their own.
+			RuntimeCall::System(frame_system::Call::remark { .. }) |
+			RuntimeCall::System(frame_system::Call::remark_with_event { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) => Transferred::Nothing,
+			_ => Transferred::Unknown,
 		}
 	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn benchmark_asset(i: u32) -> xcm::v5::Location {
+		xcm::v5::Location::new(0, [xcm::v5::Junction::GeneralIndex(i.into())])
+	}
 }
 
 impl pallet_proxy::Config for Runtime {
@@ -865,6 +1355,35 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
+	type TransferMeter = ProxyTransferMeter;
+	type MaxAllowanceAssets = ConstU32<8>;
+}
+
+parameter_types! {
+	// 1/100 of the Westend relay chain deposits.
+	pub const RecoveryConfigDepositBase: Balance = 5 * CENTS;
+	pub const RecoveryFriendDepositFactor: Balance = 500 * MILLICENTS;
+	pub const RecoveryMaxFriends: u16 = 9;
+	pub const RecoveryDeposit: Balance = 5 * CENTS;
+}
+
+impl pallet_recovery::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_recovery::WeightInfo<Runtime>;
+	type RuntimeCall = RuntimeCall;
+	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
+	type Currency = Balances;
+	type ConfigDepositBase = RecoveryConfigDepositBase;
+	type FriendDepositFactor = RecoveryFriendDepositFactor;
+	type MaxFriends = RecoveryMaxFriends;
+	type RecoveryDeposit = RecoveryDeposit;
+}
+
+/// Stored remarks are paid for through the transaction length fee (`TransactionByteFee`) and
+/// the per-byte component of the `store` weight.
+impl pallet_remark::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_remark::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -901,6 +1420,14 @@ parameter_types! {
 	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(35) * RuntimeBlockWeights::get().max_block;
 }
 
+parameter_types! {
+	pub const MessageQueueMaxDeadLetters: u32 = 16;
+	pub const DeadLetterBaseDeposit: Balance = deposit(1, 0);
+	pub const DeadLetterByteDeposit: Balance = deposit(0, 1);
+	pub const DeadLetterHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::MessageQueue(pallet_message_queue::HoldReason::DeadLetter);
+}
+
 impl pallet_message_queue::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_message_queue::WeightInfo<Runtime>;
@@ -922,6 +1449,22 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type MaxDeadLetters = MessageQueueMaxDeadLetters;
+	// Sovereign accounts pay for the dead letters of their queues.
+	type DeadLetterDepositor = parachains_common::message_queue::SiblingSovereignAccount<
+		AccountId,
+		xcm_config::LocationToAccountId,
+	>;
+	type DeadLetterConsideration = frame_support::traits::fungible::HoldConsideration<
+		AccountId,
+		Balances,
+		DeadLetterHoldReason,
+		frame_support::traits::LinearStoragePrice<
+			DeadLetterBaseDeposit,
+			DeadLetterByteDeposit,
+			Balance,
+		>,
+	>;
 }
 
 impl cumulus_pallet_aura_ext::Config for Runtime {}
@@ -930,15 +1473,18 @@ parameter_types! {
 	/// The asset ID for the asset that we use to pay for message delivery fees.
 	pub FeeAssetId: AssetId = AssetId(xcm_config::WestendLocation::get());
 	/// The base fee for the message delivery fees.
-	pub const BaseDeliveryFee: u128 = CENTS.saturating_mul(3);
+	pub BaseDeliveryFee: u128 = dynamic_params::delivery::BaseDeliveryFee::get();
+	/// The fee per byte for the message delivery fees.
+	pub DeliveryByteFee: u128 = dynamic_params::delivery::ByteFee::get();
 }
 
-pub type PriceForSiblingParachainDelivery = polkadot_runtime_common::xcm_sender::ExponentialPrice<
-	FeeAssetId,
-	BaseDeliveryFee,
-	TransactionByteFee,
-	XcmpQueue,
->;
+pub type PriceForSiblingParachainDelivery =
+	cumulus_pallet_xcmp_queue::ExponentialPriceWithOverrides<
+		FeeAssetId,
+		BaseDeliveryFee,
+		DeliveryByteFee,
+		Runtime,
+	>;
 
 impl cumulus_pallet_xcmp_queue::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -986,6 +1532,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -994,6 +1542,37 @@ impl pallet_aura::Config for Runtime {
 	type MaxAuthorities = ConstU32<100_000>;
 	type AllowMultipleBlocksPerSlot = ConstBool<true>;
 	type SlotDuration = ConstU64<SLOT_DURATION>;
+	type EquivocationReportSystem = pallet_aura::EquivocationReportSystem<Self, CollatorSelection>;
+	type WeightInfo = weights::pallet_aura::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AuraBenchmarkHelper;
+}
+
+/// Registers the offender of the `pallet_aura` benchmarks as a collator candidate, so that the
+/// reported offence removes it from the candidates.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AuraBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_aura::BenchmarkHelper<AuraId> for AuraBenchmarkHelper {
+	fn setup_offender(authority: &AuraId) {
+		use frame_support::traits::Currency;
+
+		let offender: AccountId = frame_benchmarking::account("offender", 0, 0);
+		let bond = pallet_collator_selection::CandidacyBond::<Runtime>::get();
+		Balances::make_free_balance_be(&offender, bond.saturating_add(UNITS));
+		Session::set_keys(
+			RuntimeOrigin::signed(offender.clone()),
+			SessionKeys { aura: authority.clone() },
+			Vec::new(),
+		)
+		.expect("the offender has no keys yet; qed");
+		pallet_collator_selection::DesiredCandidates::<Runtime>::put(
+			<Runtime as pallet_collator_selection::Config>::MaxCandidates::get(),
+		);
+		CollatorSelection::register_as_candidate(RuntimeOrigin::signed(offender))
+			.expect("the offender has keys and funds to register; qed");
+	}
 }
 
 parameter_types! {
@@ -1016,21 +1595,29 @@ impl pallet_collator_selection::Config for Runtime {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
 	type ValidatorRegistration = Session;
+	type AuthorStats = Authorship;
+	type MinAuthoredBlocks = ();
 	type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
 }
 
-parameter_types! {
-	pub StakingPot: AccountId = CollatorSelection::account_id();
-}
-
 impl pallet_asset_conversion_tx_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = xcm::v5::Location;
-	type OnChargeAssetTransaction = SwapAssetAdapter<
-		WestendLocation,
-		NativeAndNonPoolAssets,
-		AssetConversion,
-		ResolveAssetTo<StakingPot, NativeAndNonPoolAssets>,
+	type OnChargeAssetTransaction = AccountSwappedFees<
+		SwapAssetAdapter<
+			WestendLocation,
+			NativeAndNonPoolAssets,
+			AssetConversion,
+			// The fees paid in other assets are swapped into the native asset and split like the
+			// native fees.
+			SplitFees<
+				Runtime,
+				dynamic_params::fees::AuthorShare,
+				dynamic_params::fees::BurnShare,
+				TreasuryAccount,
+				AccountSplitFees,
+			>,
+		>,
 	>;
 	type WeightInfo = weights::pallet_asset_conversion_tx_payment::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1133,6 +1720,63 @@ impl pallet_nfts::Config for Runtime {
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
 }
 
+/// NFTs managed by some foreign location, e.g. reserve transferred from sibling parachains.
+///
+/// The owner of a foreign collection, e.g. the sovereign account of a sibling parachain, creates
+/// a local collection and maps the location of the foreign collection to it with
+/// `XcmNftRegistry`.
+pub type ForeignNftsInstance = pallet_nfts::Instance1;
+impl pallet_nfts::Config<ForeignNftsInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = CollectionId;
+	type ItemId = ItemId;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = AssetsForceOrigin;
+	type Locker = ();
+	type CollectionDeposit = NftsCollectionDeposit;
+	type ItemDeposit = NftsItemDeposit;
+	type MetadataDepositBase = NftsMetadataDepositBase;
+	type AttributeDepositBase = NftsAttributeDepositBase;
+	type DepositPerByte = NftsDepositPerByte;
+	type StringLimit = ConstU32<256>;
+	type KeyLimit = ConstU32<64>;
+	type ValueLimit = ConstU32<256>;
+	type ApprovalsLimit = ConstU32<20>;
+	type ItemAttributesApprovalsLimit = ConstU32<30>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = NftsMaxDeadlineDuration;
+	type MaxAttributesPerCall = ConstU32<10>;
+	type Features = NftsPalletFeatures;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	// Same pallet and storage layout as `Nfts`, so re-use its weights.
+	type WeightInfo = weights::pallet_nfts::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
+}
+
+impl pallet_xcm_nft_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = CollectionId;
+	type Collections = ForeignNfts;
+	type RegisterOrigin = ForeignCreators<
+		(
+			FromSiblingParachain<parachain_info::Pallet<Runtime>, xcm::v5::Location>,
+			FromNetwork<xcm_config::UniversalLocation, EthereumNetwork, xcm::v5::Location>,
+			xcm_config::bridging::to_rococo::RococoAssetFromAssetHubRococo,
+		),
+		LocationToAccountId,
+		AccountId,
+		xcm::v5::Location,
+	>;
+	type ForceOrigin = AssetsForceOrigin;
+	type WeightInfo = pallet_xcm_nft_registry::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = xcm_config::XcmBenchmarkHelper;
+}
+
 /// XCM router instance to BridgeHub with bridging capabilities for `Rococo` global
 /// consensus with dynamic fees and back-pressure.
 pub type ToRococoXcmRouterInstance = pallet_xcm_bridge_hub_router::Instance1;
@@ -1147,7 +1791,7 @@ impl pallet_xcm_bridge_hub_router::Config<ToRococoXcmRouterInstance> for Runtime
 	type DestinationVersion = PolkadotXcm;
 
 	type BridgeHubOrigin = frame_support::traits::EitherOfDiverse<
-		EnsureRoot<AccountId>,
+		EitherOfDiverse<EnsureRoot<AccountId>, BridgeAdmin>,
 		EnsureXcm<Equals<Self::SiblingBridgeHubLocation>>,
 	>;
 	type ToBridgeHubSender = XcmpQueue;
@@ -1208,12 +1852,17 @@ impl pallet_migrations::Config for Runtime {
 	type MigrationStatusHandler = ();
 	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
 	type MaxServiceWeight = MbmServiceWeight;
+	type PauseOrigin = EnsureRoot<AccountId>;
+	type ProgressEventInterval = ConstU32<50>;
 	type WeightInfo = weights::pallet_migrations::WeightInfo<Runtime>;
 }
 
 parameter_types! {
 	pub MaximumSchedulerWeight: frame_support::weights::Weight = Perbill::from_percent(80) *
 		RuntimeBlockWeights::get().max_block;
+	pub const SchedulerNormalShare: Perbill = Perbill::from_percent(80);
+	pub const SchedulerBulkShare: Perbill = Perbill::from_percent(50);
+	pub const SchedulerMaxPostponement: BlockNumber = 1 * RC_DAYS;
 }
 
 impl pallet_scheduler::Config for Runtime {
@@ -1228,15 +1877,23 @@ impl pallet_scheduler::Config for Runtime {
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type MaxScheduledPerBlock = ConstU32<50>;
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
+	type ClassLimits = pallet_scheduler::WeightShares<
+		SchedulerNormalShare,
+		SchedulerBulkShare,
+		SchedulerMaxPostponement,
+	>;
 	type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = RelaychainDataProvider<Runtime>;
 }
 
 parameter_types! {
-	pub const PreimageBaseDeposit: Balance = deposit(2, 64);
-	pub const PreimageByteDeposit: Balance = deposit(0, 1);
+	pub PreimageBaseDeposit: Balance = dynamic_params::preimage::BaseDeposit::get();
+	pub PreimageByteDeposit: Balance = dynamic_params::preimage::ByteDeposit::get();
+	pub PreimageTierThreshold: u64 = dynamic_params::preimage::TierThreshold::get();
+	pub PreimageSteepByteDeposit: Balance = dynamic_params::preimage::SteepByteDeposit::get();
 	pub const PreimageHoldReason: RuntimeHoldReason = RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+	pub const PreimageUploadLifetime: BlockNumber = 1 * DAYS;
 }
 
 impl pallet_preimage::Config for Runtime {
@@ -1248,8 +1905,15 @@ impl pallet_preimage::Config for Runtime {
 		AccountId,
 		Balances,
 		PreimageHoldReason,
-		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+		TieredStoragePrice<
+			PreimageBaseDeposit,
+			PreimageByteDeposit,
+			PreimageTierThreshold,
+			PreimageSteepByteDeposit,
+			Balance,
+		>,
 	>;
+	type UploadLifetime = PreimageUploadLifetime;
 }
 
 parameter_types! {
@@ -1264,13 +1928,34 @@ impl pallet_indices::Config for Runtime {
 	type AccountIndex = AccountIndex;
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
+	type ExpiryPeriod = ();
+	type GracePeriod = ();
+	type RenewalFee = ();
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_indices::WeightInfo<Runtime>;
 }
 
+/// Sibling parachains claiming their migrated Relay Chain sovereign accounts through XCM.
+pub struct EnsureSiblingParaId;
+impl EnsureOrigin<RuntimeOrigin> for EnsureSiblingParaId {
+	type Success = u32;
+
+	fn try_origin(origin: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		cumulus_pallet_xcm::ensure_sibling_para(origin.clone())
+			.map(Into::into)
+			.map_err(|_| origin)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(cumulus_pallet_xcm::Origin::SiblingParachain(1000.into()).into())
+	}
+}
+
 impl pallet_ah_ops::Config for Runtime {
 	type Currency = Balances;
 	type RcBlockNumberProvider = RelaychainDataProvider<Runtime>;
+	type SovereignClaimOrigin = EnsureSiblingParaId;
 	type WeightInfo = weights::pallet_ah_ops::WeightInfo<Runtime>;
 }
 
@@ -1287,7 +1972,6 @@ construct_runtime!(
 		// System support stuff.
 		System: frame_system = 0,
 		ParachainSystem: cumulus_pallet_parachain_system = 1,
-		// RandomnessCollectiveFlip = 2 removed
 		Timestamp: pallet_timestamp = 3,
 		ParachainInfo: parachain_info = 4,
 		WeightReclaim: cumulus_pallet_weight_reclaim = 5,
@@ -1302,6 +1986,7 @@ construct_runtime!(
 		// AssetTxPayment: pallet_asset_tx_payment = 12,
 		AssetTxPayment: pallet_asset_conversion_tx_payment = 13,
 		Vesting: pallet_vesting = 14,
+		FeeAccounting: pallet_fee_accounting = 15,
 
 		// Collator support. the order of these 5 are important and shall not change.
 		Authorship: pallet_authorship = 20,
@@ -1325,6 +2010,11 @@ construct_runtime!(
 		Multisig: pallet_multisig = 41,
 		Proxy: pallet_proxy = 42,
 		Indices: pallet_indices = 43,
+		SafeMode: pallet_safe_mode = 44,
+		TxPause: pallet_tx_pause = 45,
+		Parameters: pallet_parameters = 46,
+		Recovery: pallet_recovery = 47,
+		Remark: pallet_remark = 48,
 
 		// The main stage.
 		Assets: pallet_assets::<Instance1> = 50,
@@ -1341,6 +2031,9 @@ construct_runtime!(
 		Revive: pallet_revive = 60,
 
 		AssetRewards: pallet_asset_rewards = 61,
+		AssetsVesting: pallet_assets_vesting = 62,
+		ForeignNfts: pallet_nfts::<Instance1> = 63,
+		XcmNftRegistry: pallet_xcm_nft_registry = 64,
 
 		StateTrieMigration: pallet_state_trie_migration = 70,
 
@@ -1365,6 +2058,8 @@ construct_runtime!(
 		Whitelist: pallet_whitelist = 93,
 		Treasury: pallet_treasury = 94,
 		AssetRate: pallet_asset_rate = 95,
+		Bounties: pallet_bounties = 96,
+		ChildBounties: pallet_child_bounties = 97,
 
 		// TODO: the pallet instance should be removed once all pools have migrated
 		// to the new account IDs.
@@ -1372,6 +2067,10 @@ construct_runtime!(
 
 		AhOps: pallet_ah_ops = 254,
 	}
+	// Indices of removed pallets, which must not be reused.
+	reserved_indices {
+		RandomnessCollectiveFlip = 2,
+	}
 );
 
 /// The address format for describing accounts.
@@ -1394,7 +2093,7 @@ pub type TxExtension = cumulus_pallet_weight_reclaim::StorageWeightReclaim<
 		frame_system::CheckEra<Runtime>,
 		frame_system::CheckNonce<Runtime>,
 		frame_system::CheckWeight<Runtime>,
-		pallet_asset_conversion_tx_payment::ChargeAssetTxPayment<Runtime>,
+		pallet_asset_conversion_tx_payment::ChargeAssetTxPaymentWithAssetTip<Runtime>,
 		frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	),
 >;
@@ -1417,7 +2116,9 @@ impl EthExtra for EthExtraImpl {
 			frame_system::CheckMortality::from(generic::Era::Immortal),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
-			pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::<Runtime>::from(tip, None),
+			pallet_asset_conversion_tx_payment::ChargeAssetTxPaymentWithAssetTip::<Runtime>::from(
+				tip, None,
+			),
 			frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
 		)
 			.into()
@@ -1453,6 +2154,9 @@ pub type Migrations = (
 		Runtime,
 		pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
 	>,
+	pallet_session::migrations::v2::MigrateV1ToV2<Runtime, sp_runtime::traits::ConvertInto>,
+	// unreleased
+	pallet_asset_conversion_ops::migration::InitUnmigratedPools<Runtime>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	cumulus_pallet_aura_ext::migration::MigrateV0ToV1<Runtime>,
@@ -1650,24 +2354,32 @@ mod benches {
 		[pallet_assets, Local]
 		[pallet_assets, Foreign]
 		[pallet_assets, Pool]
+		[pallet_assets_vesting, AssetsVesting]
 		[pallet_asset_conversion, AssetConversion]
 		[pallet_asset_rewards, AssetRewards]
 		[pallet_asset_conversion_tx_payment, AssetTxPayment]
+		[pallet_aura, Aura]
 		[pallet_bags_list, VoterList]
 		[pallet_balances, Balances]
 		[pallet_conviction_voting, ConvictionVoting]
-		// Temporarily disabled due to https://github.com/paritytech/polkadot-sdk/issues/7714
-		// [pallet_election_provider_multi_block, MultiBlockElection]
-		// [pallet_election_provider_multi_block_verifier, MultiBlockElectionVerifier]
+		[pallet_election_provider_multi_block, MultiBlockElection]
+		[pallet_election_provider_multi_block_verifier, MultiBlockElectionVerifier]
 		[pallet_election_provider_multi_block_unsigned, MultiBlockElectionUnsigned]
 		[pallet_election_provider_multi_block_signed, MultiBlockElectionSigned]
 		[pallet_fast_unstake, FastUnstake]
+		[pallet_fee_accounting, FeeAccounting]
 		[pallet_message_queue, MessageQueue]
 		[pallet_migrations, MultiBlockMigrations]
 		[pallet_multisig, Multisig]
 		[pallet_nft_fractionalization, NftFractionalization]
 		[pallet_nfts, Nfts]
+		[pallet_nfts, ForeignNfts]
+		[pallet_parameters, Parameters]
 		[pallet_proxy, Proxy]
+		[pallet_recovery, Recovery]
+		[pallet_remark, Remark]
+		[pallet_safe_mode, SafeMode]
+		[pallet_tx_pause, TxPause]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_staking_async, Staking]
 		[pallet_uniques, Uniques]
@@ -1678,9 +2390,12 @@ mod benches {
 		[cumulus_pallet_parachain_system, ParachainSystem]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_treasury, Treasury]
+		[pallet_bounties, Bounties]
+		[pallet_child_bounties, ChildBounties]
 		[pallet_vesting, Vesting]
 		[pallet_whitelist, Whitelist]
 		[pallet_xcm_bridge_hub_router, ToRococo]
+		[pallet_xcm_nft_registry, XcmNftRegistry]
 		[pallet_asset_conversion_ops, AssetConversionMigration]
 		[pallet_revive, Revive]
 		// XCM
@@ -1698,6 +2413,7 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 	Executive,
 	EthExtraImpl,
 
+	#[api_version(2)]
 	impl sp_consensus_aura::AuraApi<Block, AuraId> for Runtime {
 		fn slot_duration() -> sp_consensus_aura::SlotDuration {
 			sp_consensus_aura::SlotDuration::from_millis(SLOT_DURATION)
@@ -1706,6 +2422,30 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		fn authorities() -> Vec<AuraId> {
 			pallet_aura::Authorities::<Runtime>::get().into_inner()
 		}
+
+		fn current_slot() -> sp_consensus_aura::Slot {
+			pallet_aura::CurrentSlot::<Runtime>::get()
+		}
+
+		fn authority_index_of(authority: AuraId) -> Option<sp_consensus_aura::AuthorityIndex> {
+			Aura::authority_index_of(&authority)
+		}
+	}
+
+	impl sp_consensus_aura::AuraScheduleApi<Block, AuraId, AccountId> for Runtime {
+		fn authorship_schedule(
+			count: u32,
+		) -> Vec<sp_consensus_aura::SlotAuthor<AuraId, AccountId>> {
+			use sp_runtime::RuntimeAppPublic;
+
+			Aura::authorship_schedule(count)
+				.into_iter()
+				.map(|(slot, authority_index, authority)| {
+					let account = Session::key_owner(AuraId::ID, authority.as_ref());
+					sp_consensus_aura::SlotAuthor { slot, authority_index, authority, account }
+				})
+				.collect()
+		}
 	}
 
 	impl cumulus_primitives_core::RelayParentOffsetApi<Block> for Runtime {
@@ -1757,6 +2497,12 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl frame_support::view_functions::runtime_api::RuntimeViewFunction<Block> for Runtime {
+		fn execute_view_function(id: frame_support::view_functions::ViewFunctionId, input: Vec<u8>) -> Result<Vec<u8>, frame_support::view_functions::ViewFunctionDispatchError> {
+			Runtime::execute_view_function(id, input)
+		}
+	}
+
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
 		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
 			Executive::apply_extrinsic(extrinsic)
@@ -1812,6 +2558,12 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::BlockAuthorApi<Block, AccountId> for Runtime {
+		fn block_author() -> Option<AccountId> {
+			System::block_author()
+		}
+	}
+
 	impl pallet_nfts_runtime_api::NftsApi<Block, AccountId, u32, u32> for Runtime {
 		fn owner(collection: u32, item: u32) -> Option<AccountId> {
 			<Nfts as Inspect<AccountId>>::owner(&collection, &item)
@@ -1875,6 +2627,12 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl pallet_asset_conversion_ops::AssetConversionMigrationApi<Block, xcm::v5::Location> for Runtime {
+		fn unmigrated_pools() -> Vec<(xcm::v5::Location, xcm::v5::Location)> {
+			AssetConversionMigration::unmigrated_pools()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
@@ -1896,6 +2654,51 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentFeeBreakdownApi<Block, Balance, VersionedLocation>
+		for Runtime
+	{
+		fn query_fee_breakdown(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			tip: Balance,
+		) -> pallet_transaction_payment::FeeBreakdown<Balance> {
+			TransactionPayment::query_fee_breakdown(uxt, len, tip)
+				.charged_by(pallet_asset_conversion_tx_payment::ChargeAssetTxPaymentWithAssetTip::<Runtime>::IDENTIFIER.as_bytes())
+		}
+		fn query_fee_breakdown_in_asset(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			tip: Balance,
+			asset: VersionedLocation,
+		) -> Option<pallet_transaction_payment::FeeBreakdown<Balance>> {
+			let asset: xcm::v5::Location = asset.try_into().ok()?;
+			let native = xcm_config::WestendLocation::get();
+			let extension = pallet_asset_conversion_tx_payment::ChargeAssetTxPaymentWithAssetTip::<Runtime>::IDENTIFIER.as_bytes();
+			let breakdown = TransactionPayment::query_fee_breakdown(uxt, len, tip).charged_by(extension);
+			// Nothing is swapped for a free transaction, so there is no premium either.
+			if asset == native || breakdown.items.is_empty() {
+				return Some(breakdown);
+			}
+			// The amount of `asset` swapped for the fee, including the pool's swap fee.
+			let charged = AssetConversion::quote_price_tokens_for_exact_tokens(
+				asset.clone(),
+				native.clone(),
+				breakdown.total(),
+				true,
+			)?;
+			breakdown.in_asset(
+				extension,
+				|amount| AssetConversion::quote_price_tokens_for_exact_tokens(
+					asset.clone(),
+					native.clone(),
+					amount,
+					false,
+				),
+				charged,
+			)
+		}
+	}
+
 	impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
 			let native_token = xcm_config::WestendLocation::get();
@@ -1957,6 +2760,20 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl xcm_runtime_apis::versions::XcmVersionsApi<Block> for Runtime {
+		fn version_migration_progress() -> xcm_runtime_apis::versions::VersionMigrationProgress {
+			PolkadotXcm::version_migration_progress()
+		}
+
+		fn destination_xcm_version(destination: VersionedLocation) -> Option<XcmVersion> {
+			PolkadotXcm::destination_xcm_version(destination)
+		}
+
+		fn outdated_destinations() -> Vec<(VersionedLocation, XcmVersion)> {
+			PolkadotXcm::outdated_destinations()
+		}
+	}
+
 	impl xcm_runtime_apis::authorized_aliases::AuthorizedAliasersApi<Block> for Runtime {
 		fn authorized_aliasers(target: VersionedLocation) -> Result<
 			Vec<xcm_runtime_apis::authorized_aliases::OriginAliaser>,
@@ -2047,6 +2864,40 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	impl pallet_authorship::runtime_api::AuthorshipApi<Block, AccountId> for Runtime {
+		fn author_stats_window() -> u32 {
+			<Runtime as pallet_authorship::Config>::AuthorStatsWindow::get()
+		}
+
+		fn author_stats() -> Vec<(AccountId, u32)> {
+			Authorship::author_stats()
+		}
+	}
+
+	impl pallet_proxy::runtime_api::ProxyApi<Block, ProxyType, RuntimeCall> for Runtime {
+		fn proxy_allows(proxy_type: ProxyType, call: RuntimeCall) -> bool {
+			Proxy::check_permissions(call, proxy_type)
+		}
+
+		fn proxy_capabilities(proxy_type: ProxyType) -> pallet_proxy::ProxyCapabilities {
+			let pallets = frame_support::traits::PalletCalls::from_metadata(&Runtime::metadata_ir());
+			Proxy::proxy_capabilities(proxy_type, &pallets)
+		}
+	}
+
+	impl pallet_migrations::runtime_api::MigrationsApi<Block> for Runtime {
+		fn migrations_status() -> Vec<pallet_migrations::MigrationStatus> {
+			MultiBlockMigrations::migrations_status()
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	impl pallet_migrations::runtime_api::MigrationsDryRunApi<Block> for Runtime {
+		fn dry_run_migrations(max_steps: u32) -> pallet_migrations::DryRunReport {
+			MultiBlockMigrations::dry_run_migrations(max_steps)
+		}
+	}
+
 	impl cumulus_primitives_core::GetCoreSelectorApi<Block> for Runtime {
 		fn core_selector() -> (CoreSelector, ClaimQueueOffset) {
 			ParachainSystem::core_selector()
@@ -2566,6 +3417,7 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
@@ -2578,6 +3430,21 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
 			genesis_config_presets::preset_names()
 		}
+
+		fn get_preset_with_patch(
+			id: &sp_genesis_builder::PresetId,
+			patch: Vec<u8>,
+		) -> Result<Vec<u8>, alloc::string::String> {
+			get_preset_with_patch::<RuntimeGenesisConfig>(
+				id,
+				patch,
+				&genesis_config_presets::get_preset,
+			)
+		}
+
+		fn presets_metadata() -> Vec<sp_genesis_builder::PresetMetadata> {
+			genesis_config_presets::presets_metadata()
+		}
 	}
 );
 
@@ -474,7 +474,9 @@ where
 			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
-			pallet_asset_conversion_tx_payment::ChargeAssetTxPayment::<Runtime>::from(tip, None),
+			pallet_asset_conversion_tx_payment::ChargeAssetTxPaymentWithAssetTip::<Runtime>::from(
+				tip, None,
+			),
 			frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(true),
 		));
 		let raw_payload = SignedPayload::new(call, tx_ext)
@@ -0,0 +1,226 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Staged removal of `pallet_sudo`.
+//!
+//! The removal happens in two phases:
+//!
+//! 1. The sudo key calls [`pallet_sudo_seal::Call::seal`]. From then on the runtime's
+//!    `BaseCallFilter` rejects every `Sudo` call except `remove_key`, and `Sudo::remove_key` is
+//!    scheduled with the key as origin after [`pallet_sudo_seal::Config::RemovalDelay`].
+//! 2. Once the key is gone, `Sudo` and `SudoSeal` are removed from `construct_runtime!` and
+//!    [`RemoveSudo`] is added to the runtime's migrations to clear their storage. The index of
+//!    `Sudo` ([`SUDO_PALLET_INDEX`]) is added to the `reserved_indices` of `construct_runtime!`, so
+//!    that reusing it fails to compile. Until then, the integrity test of the runtime checks that
+//!    `Sudo` keeps this index.
+//!
+//! `SudoSeal` is not part of `construct_runtime!` yet. It is added, starting the first phase, once
+//! weights generated by the benchmark of [`pallet_sudo_seal::Call::seal`] are available.
+
+pub use pallet_sudo_seal::*;
+
+use frame_support::{migrations::RemovePallet, parameter_types};
+
+/// The index of `Sudo` in `construct_runtime!`, reserved after its removal.
+pub const SUDO_PALLET_INDEX: u8 = 9;
+
+parameter_types! {
+	pub const SudoPalletName: &'static str = "Sudo";
+	pub const SudoSealPalletName: &'static str = "SudoSeal";
+}
+
+/// Clears the storage of `Sudo` and `SudoSeal`, to be applied in the second phase.
+pub type RemoveSudo<DbWeight> =
+	(RemovePallet<SudoPalletName, DbWeight>, RemovePallet<SudoSealPalletName, DbWeight>);
+
+#[frame_support::pallet]
+pub mod pallet_sudo_seal {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{
+			schedule::{v3::Anon as ScheduleAnon, DispatchTime, LOWEST_PRIORITY},
+			Contains, IsSubType, PalletInfoAccess, StorePreimage,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{BlockNumberProvider, Saturating};
+
+	/// Block number type used by the scheduler.
+	pub type SchedulerBlockNumberFor<T> = pallet_scheduler::BlockNumberFor<T>;
+
+	/// Weight functions needed for `pallet_sudo_seal`.
+	pub trait WeightInfo {
+		fn seal() -> Weight;
+	}
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_sudo::Config
+		+ pallet_scheduler::Config<
+			RuntimeCall: From<pallet_sudo::Call<Self>>,
+			PalletsOrigin: From<frame_system::RawOrigin<Self::AccountId>>,
+		>
+	{
+		/// The overarching event type.
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Delay, in scheduler blocks, between sealing sudo and removing its key.
+		#[pallet::constant]
+		type RemovalDelay: Get<SchedulerBlockNumberFor<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn integrity_test() {
+			assert_eq!(
+				<pallet_sudo::Pallet<T> as PalletInfoAccess>::index(),
+				SUDO_PALLET_INDEX as usize,
+				"`Sudo` must have the index which is reserved after its removal",
+			);
+		}
+	}
+
+	/// The scheduler block at which the sudo key will be removed, if sudo is sealed.
+	#[pallet::storage]
+	pub type SealedUntil<T: Config> = StorageValue<_, SchedulerBlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Sudo was sealed and its key will be removed at `removal_at`.
+		Sealed { key: T::AccountId, removal_at: SchedulerBlockNumberFor<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Sender must be the sudo key.
+		RequireSudo,
+		/// Sudo is already sealed.
+		AlreadySealed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Seal sudo and schedule the removal of its key.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the sudo key.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::seal())]
+		pub fn seal(origin: OriginFor<T>) -> DispatchResult {
+			let key = ensure_signed(origin)?;
+			ensure!(pallet_sudo::Key::<T>::get() == Some(key.clone()), Error::<T>::RequireSudo);
+			ensure!(!SealedUntil::<T>::exists(), Error::<T>::AlreadySealed);
+
+			let removal_at =
+				<T as pallet_scheduler::Config>::BlockNumberProvider::current_block_number()
+					.saturating_add(T::RemovalDelay::get());
+			let call = <T as pallet_scheduler::Config>::Preimages::bound(
+				<T as pallet_scheduler::Config>::RuntimeCall::from(
+					pallet_sudo::Call::<T>::remove_key {},
+				),
+			)?;
+			<pallet_scheduler::Pallet<T> as ScheduleAnon<_, _, _>>::schedule(
+				DispatchTime::At(removal_at),
+				None,
+				LOWEST_PRIORITY,
+				frame_system::RawOrigin::Signed(key.clone()).into(),
+				call,
+			)?;
+
+			SealedUntil::<T>::put(removal_at);
+			Self::deposit_event(Event::Sealed { key, removal_at });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether sudo has been sealed.
+		pub fn is_sealed() -> bool {
+			SealedUntil::<T>::exists()
+		}
+	}
+
+	/// Call filter rejecting all `Sudo` calls but `remove_key` once sudo is sealed.
+	pub struct SudoNotSealed<T>(PhantomData<T>);
+	impl<T: Config> Contains<<T as frame_system::Config>::RuntimeCall> for SudoNotSealed<T>
+	where
+		<T as frame_system::Config>::RuntimeCall: IsSubType<pallet_sudo::Call<T>>,
+	{
+		fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+			match call.is_sub_type() {
+				Some(pallet_sudo::Call::remove_key {}) | None => true,
+				Some(_) => !Pallet::<T>::is_sealed(),
+			}
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking {
+	use super::pallet_sudo_seal::*;
+	use frame_benchmarking::v2::*;
+	use frame_support::traits::{
+		schedule::{v3::Anon as ScheduleAnon, DispatchTime, LOWEST_PRIORITY},
+		Get, StorePreimage,
+	};
+	use frame_system::RawOrigin;
+	use sp_runtime::traits::{BlockNumberProvider, Saturating};
+
+	#[benchmarks]
+	mod benchmarks {
+		use super::*;
+
+		#[benchmark]
+		fn seal() -> Result<(), BenchmarkError> {
+			let key: T::AccountId = whitelisted_caller();
+			pallet_sudo::Key::<T>::put(&key);
+
+			// Worst case: the agenda of the block removing the key has room for one more task.
+			let removal_at =
+				<T as pallet_scheduler::Config>::BlockNumberProvider::current_block_number()
+					.saturating_add(T::RemovalDelay::get());
+			let call = <T as pallet_scheduler::Config>::Preimages::bound(
+				<T as pallet_scheduler::Config>::RuntimeCall::from(
+					pallet_sudo::Call::<T>::remove_key {},
+				),
+			)?;
+			for _ in 1..<T as pallet_scheduler::Config>::MaxScheduledPerBlock::get() {
+				<pallet_scheduler::Pallet<T> as ScheduleAnon<_, _, _>>::schedule(
+					DispatchTime::At(removal_at),
+					None,
+					LOWEST_PRIORITY,
+					RawOrigin::Root.into(),
+					call.clone(),
+				)?;
+			}
+
+			#[extrinsic_call]
+			_(RawOrigin::Signed(key));
+
+			assert!(Pallet::<T>::is_sealed());
+			Ok(())
+		}
+	}
+}
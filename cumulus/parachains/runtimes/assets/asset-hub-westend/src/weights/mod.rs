@@ -30,11 +30,16 @@ pub mod pallet_asset_rewards;
 pub mod pallet_assets_foreign;
 pub mod pallet_assets_local;
 pub mod pallet_assets_pool;
+pub mod pallet_assets_vesting;
+pub mod pallet_aura;
 pub mod pallet_bags_list;
 pub mod pallet_balances;
+pub mod pallet_bounties;
+pub mod pallet_child_bounties;
 pub mod pallet_collator_selection;
 pub mod pallet_conviction_voting;
 pub mod pallet_fast_unstake;
+pub mod pallet_fee_accounting;
 pub mod pallet_indices;
 pub mod pallet_message_queue;
 pub mod pallet_migrations;
@@ -42,9 +47,12 @@ pub mod pallet_multisig;
 pub mod pallet_nft_fractionalization;
 pub mod pallet_nfts;
 pub mod pallet_nomination_pools;
+pub mod pallet_parameters;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
+pub mod pallet_recovery;
 pub mod pallet_referenda;
+pub mod pallet_remark;
 pub mod pallet_scheduler;
 pub mod pallet_session;
 pub mod pallet_staking_async;
@@ -83,4 +83,32 @@ impl<T: frame_system::Config> pallet_asset_rate::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `AssetRate::Feeders` (r:0 w:1)
+	/// Proof: `AssetRate::Feeders` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 10]`.
+	fn set_feeders(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_005_000 picoseconds.
+		Weight::from_parts(5_214_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			// Standard Error: 1_200
+			.saturating_add(Weight::from_parts(48_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:1)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRate::FeedStates` (r:1 w:1)
+	/// Proof: `AssetRate::FeedStates` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn feed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `92`
+		//  Estimated: `3509`
+		// Minimum execution time: 11_455_000 picoseconds.
+		Weight::from_parts(11_932_000, 0)
+			.saturating_add(Weight::from_parts(0, 3509))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -0,0 +1,159 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for `pallet_assets_vesting`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2025-05-18, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `c47a012f15ca`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
+
+// Executed Command:
+// frame-omni-bencher
+// v1
+// benchmark
+// pallet
+// --extrinsic=*
+// --runtime=target/production/wbuild/asset-hub-westend-runtime/asset_hub_westend_runtime.wasm
+// --pallet=pallet_assets_vesting
+// --header=/__w/polkadot-sdk/polkadot-sdk/cumulus/file_header.txt
+// --output=./cumulus/parachains/runtimes/assets/asset-hub-westend/src/weights
+// --wasm-execution=compiled
+// --steps=50
+// --repeat=20
+// --heap-pages=4096
+// --no-storage-info
+// --no-min-squares
+// --no-median-slopes
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+/// Weight functions for `pallet_assets_vesting`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_assets_vesting::WeightInfo for WeightInfo<T> {
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1077), added: 3552, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `436 + s * (36 ±0)`
+		//  Estimated: `4542`
+		// Minimum execution time: 26_316_000 picoseconds.
+		Weight::from_parts(27_413_000, 0)
+			.saturating_add(Weight::from_parts(0, 4542))
+			// Standard Error: 2_960
+			.saturating_add(Weight::from_parts(118_412, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1077), added: 3552, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vest_other(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `436 + s * (36 ±0)`
+		//  Estimated: `4542`
+		// Minimum execution time: 26_972_000 picoseconds.
+		Weight::from_parts(28_096_000, 0)
+			.saturating_add(Weight::from_parts(0, 4542))
+			// Standard Error: 3_034
+			.saturating_add(Weight::from_parts(121_367, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1077), added: 3552, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `618 + s * (36 ±0)`
+		//  Estimated: `6208`
+		// Minimum execution time: 68_360_000 picoseconds.
+		Weight::from_parts(71_208_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			// Standard Error: 3_372
+			.saturating_add(Weight::from_parts(134_905, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1077), added: 3552, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn force_vested_transfer(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `618 + s * (36 ±0)`
+		//  Estimated: `6208`
+		// Minimum execution time: 69_661_000 picoseconds.
+		Weight::from_parts(72_564_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			// Standard Error: 3_430
+			.saturating_add(Weight::from_parts(137_220, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `AssetsVesting::Vesting` (r:1 w:1)
+	/// Proof: `AssetsVesting::Vesting` (`max_values`: None, `max_size`: Some(1077), added: 3552, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::Freezes` (r:1 w:1)
+	/// Proof: `AssetsFreezer::Freezes` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `AssetsFreezer::FrozenBalances` (r:1 w:1)
+	/// Proof: `AssetsFreezer::FrozenBalances` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 28]`.
+	fn force_remove_vesting_schedule(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `436 + s * (36 ±0)`
+		//  Estimated: `4542`
+		// Minimum execution time: 28_676_000 picoseconds.
+		Weight::from_parts(29_871_000, 0)
+			.saturating_add(Weight::from_parts(0, 4542))
+			// Standard Error: 3_142
+			.saturating_add(Weight::from_parts(125_689, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
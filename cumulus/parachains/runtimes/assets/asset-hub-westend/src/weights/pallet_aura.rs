@@ -0,0 +1,45 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_aura`
+//!
+//! THIS FILE WAS NOT AUTO-GENERATED. The values are placeholders until the `pallet_aura`
+//! benchmarks are run against this runtime, which regenerates this file:
+//!
+//! `frame-omni-bencher v1 benchmark pallet --extrinsic=* --pallet=pallet_aura
+//! --runtime=target/production/wbuild/asset-hub-westend-runtime/asset_hub_westend_runtime.wasm
+//! --header=./cumulus/file_header.txt
+//! --output=./cumulus/parachains/runtimes/assets/asset-hub-westend/src/weights`
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_aura`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_aura::WeightInfo for WeightInfo<T> {
+	fn report_equivocation(x: u32, ) -> Weight {
+		// The authorities, the session keys and the collator candidates are read.
+		Weight::from_parts(220_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 64 * 1024))
+			.saturating_add(Weight::from_parts(20_000, 32).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
@@ -197,4 +197,37 @@ impl<T: frame_system::Config> pallet_message_queue::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: `MessageQueue::DeadLetterBookFor` (r:1 w:1)
+	/// Proof: `MessageQueue::DeadLetterBookFor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `MessageQueue::DeadLetters` (r:0 w:1)
+	/// Proof: `MessageQueue::DeadLetters` (`max_values`: None, `max_size`: Some(105552), added: 108027, mode: `MaxEncodedLen`)
+	fn note_dead_letter() -> Weight {
+		// Placeholder until the `note_dead_letter` benchmark is run.
+		Weight::from_parts(16_416_000, 0)
+			.saturating_add(Weight::from_parts(0, 3514))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `MessageQueue::DeadLetters` (r:1 w:1)
+	/// Proof: `MessageQueue::DeadLetters` (`max_values`: None, `max_size`: Some(105552), added: 108027, mode: `MaxEncodedLen`)
+	/// Storage: `MessageQueue::DeadLetterBookFor` (r:1 w:1)
+	/// Proof: `MessageQueue::DeadLetterBookFor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn reprocess_dead_letter() -> Weight {
+		// Placeholder until the `reprocess_dead_letter` benchmark is run.
+		Weight::from_parts(64_278_000, 0)
+			.saturating_add(Weight::from_parts(0, 108030))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `MessageQueue::DeadLetters` (r:1 w:1)
+	/// Proof: `MessageQueue::DeadLetters` (`max_values`: None, `max_size`: Some(105552), added: 108027, mode: `MaxEncodedLen`)
+	/// Storage: `MessageQueue::DeadLetterBookFor` (r:1 w:1)
+	/// Proof: `MessageQueue::DeadLetterBookFor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	fn purge_dead_letter() -> Weight {
+		// Placeholder until the `purge_dead_letter` benchmark is run.
+		Weight::from_parts(26_883_000, 0)
+			.saturating_add(Weight::from_parts(0, 108030))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
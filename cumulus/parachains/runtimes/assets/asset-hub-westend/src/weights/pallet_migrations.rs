@@ -221,4 +221,39 @@ impl<T: frame_system::Config> pallet_migrations::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 39).saturating_mul(n.into()))
 	}
+	/// Storage: `MultiBlockMigrations::Cursor` (r:1 w:0)
+	/// Proof: `MultiBlockMigrations::Cursor` (`max_values`: Some(1), `max_size`: Some(65550), added: 66045, mode: `MaxEncodedLen`)
+	/// Storage: `MultiBlockMigrations::PausedAt` (r:1 w:1)
+	/// Proof: `MultiBlockMigrations::PausedAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// Placeholder until the `pause` benchmark is run.
+		Weight::from_parts(6_842_000, 0)
+			.saturating_add(Weight::from_parts(0, 67035))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `MultiBlockMigrations::Cursor` (r:1 w:1)
+	/// Proof: `MultiBlockMigrations::Cursor` (`max_values`: Some(1), `max_size`: Some(65550), added: 66045, mode: `MaxEncodedLen`)
+	/// Storage: `MultiBlockMigrations::PausedAt` (r:1 w:1)
+	/// Proof: `MultiBlockMigrations::PausedAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn resume() -> Weight {
+		// Placeholder until the `resume` benchmark is run.
+		Weight::from_parts(7_913_000, 0)
+			.saturating_add(Weight::from_parts(0, 67035))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `MultiBlockMigrations::Cursor` (r:1 w:1)
+	/// Proof: `MultiBlockMigrations::Cursor` (`max_values`: Some(1), `max_size`: Some(65550), added: 66045, mode: `MaxEncodedLen`)
+	/// Storage: `MultiBlockMigrations::PausedAt` (r:1 w:0)
+	/// Proof: `MultiBlockMigrations::PausedAt` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MultiBlockMigrations::ActiveSteps` (r:0 w:1)
+	/// Proof: `MultiBlockMigrations::ActiveSteps` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn force_skip_current() -> Weight {
+		// Placeholder until the `force_skip_current` benchmark is run.
+		Weight::from_parts(7_388_000, 0)
+			.saturating_add(Weight::from_parts(0, 67035))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -20,6 +20,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `99fc4dfa9c86`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
+//!
+//! NOTE: `set_proxy_allowance`, `remove_proxy_allowance` and the `Proxy::ProxyAllowances` accesses
+//! of the other extrinsics were added by hand. They are placeholders until this file is regenerated
+//! with the command below.
 
 // Executed Command:
 // frame-omni-bencher
@@ -52,17 +56,17 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyExpiries` (r:1 w:0)
+	/// Proof: `Proxy::ProxyExpiries` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyAllowances` (r:1 w:0)
+	/// Proof: `Proxy::ProxyAllowances` (`max_values`: None, `max_size`: Some(5113), added: 7588, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy(p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 14_242_000 picoseconds.
+		// Placeholder until the `proxy` benchmark is run.
 		Weight::from_parts(14_960_578, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_137
+			.saturating_add(Weight::from_parts(0, 11166))
 			.saturating_add(Weight::from_parts(35_649, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(3))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:0)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
@@ -70,20 +74,19 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyExpiries` (r:1 w:0)
+	/// Proof: `Proxy::ProxyExpiries` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyAllowances` (r:1 w:0)
+	/// Proof: `Proxy::ProxyAllowances` (`max_values`: None, `max_size`: Some(5113), added: 7588, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 31]`.
 	/// The range of component `p` is `[1, 31]`.
 	fn proxy_announced(a: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `488 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
-		// Minimum execution time: 41_637_000 picoseconds.
+		// Placeholder until the `proxy_announced` benchmark is run.
 		Weight::from_parts(41_169_266, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
-			// Standard Error: 2_978
+			.saturating_add(Weight::from_parts(0, 11166))
 			.saturating_add(Weight::from_parts(172_008, 0).saturating_mul(a.into()))
-			// Standard Error: 3_077
 			.saturating_add(Weight::from_parts(68_505, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Announcements` (r:1 w:1)
@@ -132,20 +135,17 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	/// Proof: `Proxy::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyExpiries` (r:1 w:0)
+	/// Proof: `Proxy::ProxyExpiries` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
 	/// The range of component `a` is `[0, 31]`.
 	/// The range of component `p` is `[1, 31]`.
 	fn announce(a: u32, p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `420 + a * (68 ±0) + p * (37 ±0)`
-		//  Estimated: `5698`
-		// Minimum execution time: 37_884_000 picoseconds.
+		// Placeholder until the `announce` benchmark is run.
 		Weight::from_parts(38_379_964, 0)
-			.saturating_add(Weight::from_parts(0, 5698))
-			// Standard Error: 1_965
+			.saturating_add(Weight::from_parts(0, 8286))
 			.saturating_add(Weight::from_parts(151_226, 0).saturating_mul(a.into()))
-			// Standard Error: 2_030
 			.saturating_add(Weight::from_parts(51_251, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
@@ -165,33 +165,38 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyExpiries` (r:1 w:1)
+	/// Proof: `Proxy::ProxyExpiries` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyAllowances` (r:1 w:1)
+	/// Proof: `Proxy::ProxyAllowances` (`max_values`: None, `max_size`: Some(5113), added: 7588, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxy(p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 23_865_000 picoseconds.
+		// Placeholder until the `remove_proxy` benchmark is run.
 		Weight::from_parts(25_111_800, 0)
-			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_501
+			.saturating_add(Weight::from_parts(0, 11166))
 			.saturating_add(Weight::from_parts(45_002, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyExpiries` (r:31 w:31)
+	/// Proof: `Proxy::ProxyExpiries` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyAllowances` (r:31 w:31)
+	/// Proof: `Proxy::ProxyAllowances` (`max_values`: None, `max_size`: Some(5113), added: 7588, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[1, 31]`.
 	fn remove_proxies(p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `161 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 21_721_000 picoseconds.
+		// Placeholder until the `remove_proxies` benchmark is run.
 		Weight::from_parts(22_626_669, 0)
 			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_314
 			.saturating_add(Weight::from_parts(27_650, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 7588).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2588).saturating_mul(p.into()))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
@@ -210,18 +215,23 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyExpiries` (r:30 w:30)
+	/// Proof: `Proxy::ProxyExpiries` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyAllowances` (r:30 w:30)
+	/// Proof: `Proxy::ProxyAllowances` (`max_values`: None, `max_size`: Some(5113), added: 7588, mode: `MaxEncodedLen`)
 	/// The range of component `p` is `[0, 30]`.
 	fn kill_pure(p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `198 + p * (37 ±0)`
-		//  Estimated: `4706`
-		// Minimum execution time: 22_817_000 picoseconds.
+		// Placeholder until the `kill_pure` benchmark is run.
 		Weight::from_parts(23_755_496, 0)
 			.saturating_add(Weight::from_parts(0, 4706))
-			// Standard Error: 1_854
 			.saturating_add(Weight::from_parts(33_383, 0).saturating_mul(p.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 7588).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 2588).saturating_mul(p.into()))
 	}
 	/// Storage: `Proxy::Proxies` (r:1 w:1)
 	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
@@ -239,4 +249,61 @@ impl<T: frame_system::Config> pallet_proxy::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyExpiries` (r:0 w:1)
+	/// Proof: `Proxy::ProxyExpiries` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn add_proxy_with_expiry(p: u32, ) -> Weight {
+		// Placeholder until the `add_proxy_with_expiry` benchmark is run.
+		Weight::from_parts(28_333_919, 0)
+			.saturating_add(Weight::from_parts(0, 4706))
+			.saturating_add(Weight::from_parts(47_145, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Proxy::ProxyExpiries` (r:1 w:1)
+	/// Proof: `Proxy::ProxyExpiries` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::Proxies` (r:1 w:1)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyAllowances` (r:1 w:1)
+	/// Proof: `Proxy::ProxyAllowances` (`max_values`: None, `max_size`: Some(5113), added: 7588, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 31]`.
+	fn reap_expired_proxy(p: u32, ) -> Weight {
+		// Placeholder until the `reap_expired_proxy` benchmark is run.
+		Weight::from_parts(30_525_786, 0)
+			.saturating_add(Weight::from_parts(0, 11166))
+			.saturating_add(Weight::from_parts(49_591, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Proxy::Proxies` (r:1 w:0)
+	/// Proof: `Proxy::Proxies` (`max_values`: None, `max_size`: Some(1241), added: 3716, mode: `MaxEncodedLen`)
+	/// Storage: `Proxy::ProxyAllowances` (r:1 w:1)
+	/// Proof: `Proxy::ProxyAllowances` (`max_values`: None, `max_size`: Some(5113), added: 7588, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 8]`.
+	fn set_proxy_allowance(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1383`
+		//  Estimated: `8578`
+		// Minimum execution time: 26_022_000 picoseconds.
+		Weight::from_parts(27_106_597, 0)
+			.saturating_add(Weight::from_parts(0, 8578))
+			// Standard Error: 9_290
+			.saturating_add(Weight::from_parts(371_615, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Proxy::ProxyAllowances` (r:1 w:1)
+	/// Proof: `Proxy::ProxyAllowances` (`max_values`: None, `max_size`: Some(5113), added: 7588, mode: `MaxEncodedLen`)
+	fn remove_proxy_allowance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1871`
+		//  Estimated: `8578`
+		// Minimum execution time: 23_881_000 picoseconds.
+		Weight::from_parts(24_876_000, 0)
+			.saturating_add(Weight::from_parts(0, 8578))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
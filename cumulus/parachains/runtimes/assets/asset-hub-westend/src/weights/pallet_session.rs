@@ -78,4 +78,23 @@ impl<T: frame_system::Config> pallet_session::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::KeyOwner` (r:1 w:0)
+	/// Proof: `Session::KeyOwner` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::ScheduledKeysSession` (r:1 w:1)
+	/// Proof: `Session::ScheduledKeysSession` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::ScheduledKeys` (r:0 w:2)
+	/// Proof: `Session::ScheduledKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_keys_at() -> Weight {
+		// Placeholder until the `set_keys_at` benchmark is run.
+		Weight::from_parts(21_063_000, 0)
+			.saturating_add(Weight::from_parts(0, 3739))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+			// `Session::ScheduledKeysCount` of the old and the new session.
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
 }
@@ -91,4 +91,28 @@ impl<T: frame_system::Config> pallet_xcm_bridge_hub_router::WeightInfo for Weigh
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `ToRococoXcmRouter::IsSuspended` (r:1 w:1)
+	/// Proof: `ToRococoXcmRouter::IsSuspended` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn suspend_bridge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `1486`
+		// Minimum execution time: 8_971_000 picoseconds.
+		Weight::from_parts(9_342_000, 0)
+			.saturating_add(Weight::from_parts(0, 1486))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ToRococoXcmRouter::IsSuspended` (r:1 w:1)
+	/// Proof: `ToRococoXcmRouter::IsSuspended` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn resume_bridge() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `143`
+		//  Estimated: `1486`
+		// Minimum execution time: 9_127_000 picoseconds.
+		Weight::from_parts(9_518_000, 0)
+			.saturating_add(Weight::from_parts(0, 1486))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
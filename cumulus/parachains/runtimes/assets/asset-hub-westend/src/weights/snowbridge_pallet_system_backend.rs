@@ -38,6 +38,12 @@ impl<T: frame_system::Config> snowbridge_pallet_system_frontend::BackendWeightIn
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 
+	fn transact_reclaim_message() -> Weight {
+		Weight::from_parts(22_000_000, 3539)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
 	fn do_process_message() -> Weight {
 		Weight::from_parts(19_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 1527))
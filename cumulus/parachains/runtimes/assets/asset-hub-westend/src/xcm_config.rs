@@ -15,14 +15,17 @@
 
 use super::{
 	governance::TreasuryAccount, AccountId, AllPalletsWithSystem, Assets, Balance, Balances,
-	BaseDeliveryFee, CollatorSelection, DepositPerByte, DepositPerItem, FeeAssetId,
-	FellowshipAdmin, ForeignAssets, GeneralAdmin, ParachainInfo, ParachainSystem, PolkadotXcm,
-	PoolAssets, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin, StakingAdmin,
-	ToRococoXcmRouter, TransactionByteFee, Treasurer, Uniques, WeightToFee, XcmpQueue,
+	BaseDeliveryFee, CollatorSelection, DeliveryByteFee, DepositPerByte, DepositPerItem,
+	FeeAssetId, FellowshipAdmin, ForeignAssets, ForeignNfts, GeneralAdmin, ParachainInfo,
+	ParachainSystem, PolkadotXcm, PoolAssets, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeHoldReason, RuntimeOrigin, StakingAdmin, ToRococoXcmRouter, TransactionByteFee,
+	Treasurer, Uniques, WeightToFee, XcmNftRegistry, XcmpQueue,
 };
 use assets_common::{
-	matching::{FromSiblingParachain, IsForeignConcreteAsset, ParentLocation},
-	TrustBackedAssetsAsLocation,
+	matching::{
+		FromSiblingParachain, IsForeignConcreteAsset, IsForeignNonFungibleAsset, ParentLocation,
+	},
+	NonFungiblesV2Compat, TrustBackedAssetsAsLocation,
 };
 use frame_support::{
 	parameter_types,
@@ -35,8 +38,11 @@ use frame_support::{
 };
 use frame_system::EnsureRoot;
 use pallet_xcm::{AuthorizedAliasers, XcmPassthrough};
-use parachains_common::xcm_config::{
-	AllSiblingSystemParachains, ConcreteAssetFromSystem, RelayOrOtherSystemParachains,
+use parachains_common::{
+	xcm_config::{
+		AllSiblingSystemParachains, ConcreteAssetFromSystem, RelayOrOtherSystemParachains,
+	},
+	CollectionId, ItemId,
 };
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_common::xcm_sender::ExponentialPrice;
@@ -190,6 +196,33 @@ pub type ForeignFungiblesTransactor = FungiblesAdapter<
 	CheckingAccount,
 >;
 
+/// `CollectionId`/`ItemId` converter for `ForeignNfts`.
+pub type ForeignNftsConvertedConcreteId = assets_common::ForeignNftsConvertedConcreteId<
+	// Ignore NFTs which start explicitly with our `GlobalConsensus(NetworkId)`, see
+	// `ForeignAssetsConvertedConcreteId`.
+	StartsWithExplicitGlobalConsensus<UniversalLocationNetworkId>,
+	ItemId,
+	CollectionId,
+	// The locations of foreign collections are mapped to local collections by the registry.
+	XcmNftRegistry,
+>;
+
+/// Means for transacting foreign NFTs, e.g. the ones reserve transferred from sibling parachains.
+pub type ForeignNonFungiblesTransactor = NonFungiblesAdapter<
+	// Use this non-fungibles implementation:
+	NonFungiblesV2Compat<ForeignNfts, pallet_nfts::ItemConfig>,
+	// This adapter will handle any non-fungible asset of a foreign collection:
+	ForeignNftsConvertedConcreteId,
+	// Convert an XCM Location into a local account id:
+	LocationToAccountId,
+	// Our chain's account ID type (we can't get away without mentioning it explicitly):
+	AccountId,
+	// We don't need to check teleports here.
+	NoChecking,
+	// The account to use for tracking teleports.
+	CheckingAccount,
+>;
+
 /// `AssetId`/`Balance` converter for `PoolAssets`.
 pub type PoolAssetsConvertedConcreteId =
 	assets_common::PoolAssetsConvertedConcreteId<PoolAssetsPalletLocation, Balance>;
@@ -243,6 +276,7 @@ pub type AssetTransactors = (
 	ForeignFungiblesTransactor,
 	PoolFungiblesTransactor,
 	UniquesTransactor,
+	ForeignNonFungiblesTransactor,
 	ERC20Transactor,
 );
 
@@ -414,6 +448,8 @@ impl xcm_executor::Config for XcmConfig {
 	type IsReserve = (
 		bridging::to_rococo::RococoAssetFromAssetHubRococo,
 		bridging::to_ethereum::EthereumAssetFromEthereum,
+		// Sibling parachains are the reserves of their own NFTs.
+		IsForeignNonFungibleAsset<FromSiblingParachain<AssetHubParaId>>,
 	);
 	type IsTeleporter = TrustedTeleporters;
 	type UniversalLocation = UniversalLocation;
@@ -517,7 +553,7 @@ pub type LocalPalletOriginToLocation = (
 );
 
 pub type PriceForParentDelivery =
-	ExponentialPrice<FeeAssetId, BaseDeliveryFee, TransactionByteFee, ParachainSystem>;
+	ExponentialPrice<FeeAssetId, BaseDeliveryFee, DeliveryByteFee, ParachainSystem>;
 
 /// For routing XCM messages which do not cross local consensus boundary.
 type LocalXcmRouter = (
@@ -586,6 +622,8 @@ impl pallet_xcm::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type MaxVersionNotificationsPerBlock = ConstU32<16>;
+	type VersionDiscoveryPeriod = ();
 	// xcm_executor::Config::Aliasers also uses pallet_xcm::AuthorizedAliasers.
 	type AuthorizedAliasConsideration = HoldConsideration<
 		AccountId,
@@ -608,6 +646,15 @@ impl pallet_assets::BenchmarkHelper<xcm::v5::Location> for XcmBenchmarkHelper {
 		xcm::v5::Location::new(1, [xcm::v5::Junction::Parachain(id)])
 	}
 }
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_xcm_nft_registry::BenchmarkHelper<CollectionId, AccountId> for XcmBenchmarkHelper {
+	fn create_collection(owner: &AccountId) -> CollectionId {
+		use frame_support::traits::{fungible::Mutate, tokens::nonfungibles_v2::Create};
+		Balances::set_balance(owner, 100 * crate::UNITS);
+		ForeignNfts::create_collection(owner, owner, &Default::default())
+			.expect("the owner can pay the collection deposit; qed")
+	}
+}
 
 /// All configuration related to bridging
 pub mod bridging {
@@ -34,8 +34,8 @@ use asset_hub_westend_runtime::{
 };
 pub use asset_hub_westend_runtime::{AssetConversion, AssetDeposit, CollatorSelection, System};
 use asset_test_utils::{
-	test_cases_over_bridge::TestBridgingConfig, CollatorSessionKey, CollatorSessionKeys,
-	ExtBuilder, GovernanceOrigin, SlotDurations,
+	pool_helpers, test_cases_over_bridge::TestBridgingConfig, CollatorSessionKey,
+	CollatorSessionKeys, ExtBuilder, GovernanceOrigin, SlotDurations,
 };
 use codec::{Decode, Encode};
 use frame_support::{
@@ -45,7 +45,7 @@ use frame_support::{
 		fungibles::{
 			self, Create, Inspect as FungiblesInspect, InspectEnumerable, Mutate as FungiblesMutate,
 		},
-		ContainsPair,
+		Contains, ContainsPair, Get,
 	},
 	weights::{Weight, WeightToFee as WeightToFeeT},
 };
@@ -199,32 +199,11 @@ fn test_buy_and_refund_weight_with_swap_local_asset_xcm_trader() {
 			assert_ok!(Balances::mint_into(&bob, initial_balance));
 			assert_ok!(Balances::mint_into(&staking_pot, initial_balance));
 
-			assert_ok!(AssetConversion::create_pool(
-				RuntimeHelper::origin_of(bob.clone()),
-				Box::new(
-					xcm::v5::Location::try_from(native_location.clone()).expect("conversion works")
-				),
-				Box::new(
-					xcm::v5::Location::try_from(asset_1_location.clone())
-						.expect("conversion works")
-				)
-			));
-
-			assert_ok!(AssetConversion::add_liquidity(
-				RuntimeHelper::origin_of(bob.clone()),
-				Box::new(
-					xcm::v5::Location::try_from(native_location.clone()).expect("conversion works")
-				),
-				Box::new(
-					xcm::v5::Location::try_from(asset_1_location.clone())
-						.expect("conversion works")
-				),
-				pool_liquidity,
-				pool_liquidity,
-				1,
-				1,
+			pool_helpers::create_pool_with_liquidity::<Runtime>(
 				bob,
-			));
+				(native_location.clone(), pool_liquidity),
+				(asset_1_location.clone(), pool_liquidity),
+			);
 
 			// keep initial total issuance to assert later.
 			let asset_total_issuance = Assets::total_issuance(asset_1);
@@ -253,11 +232,8 @@ fn test_buy_and_refund_weight_with_swap_local_asset_xcm_trader() {
 			// prepare input to refund weight.
 			let refund_weight = Weight::from_parts(1_000_000_000, 0);
 			let refund = WeightToFee::weight_to_fee(&refund_weight);
-			let (reserve1, reserve2) = AssetConversion::get_reserves(
-				xcm::v5::Location::try_from(native_location).expect("conversion works"),
-				xcm::v5::Location::try_from(asset_1_location.clone()).expect("conversion works"),
-			)
-			.unwrap();
+			let (reserve1, reserve2) =
+				pool_helpers::pool_reserves::<Runtime>(native_location, asset_1_location.clone());
 			let asset_refund =
 				AssetConversion::get_amount_out(&refund, &reserve1, &reserve2).unwrap();
 
@@ -279,6 +255,107 @@ fn test_buy_and_refund_weight_with_swap_local_asset_xcm_trader() {
 		})
 }
 
+#[test]
+fn swap_through_trust_backed_and_foreign_asset_pools_works() {
+	ExtBuilder::<Runtime>::default()
+		.with_tracing()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			let bob: AccountId = SOME_ASSET_ADMIN.into();
+			let native_location = WestendLocation::get();
+			let local_asset: u32 = 1;
+			let local_asset_location =
+				AssetIdForTrustBackedAssetsConvert::convert_back(&local_asset).unwrap();
+			let foreign_asset_location = Location::new(1, [Parachain(1234), GeneralIndex(12)]);
+			let initial_balance = 300 * UNITS;
+			let pool_liquidity = 100 * UNITS;
+
+			// init assets, balances and the (native, local) and (native, foreign) pools.
+			assert_ok!(<Assets as Create<_>>::create(local_asset, bob.clone(), true, 10));
+			assert_ok!(<ForeignAssets as Create<_>>::create(
+				foreign_asset_location.clone(),
+				bob.clone(),
+				true,
+				10
+			));
+			pool_helpers::mint_native::<Runtime>(&bob, initial_balance);
+			pool_helpers::mint_assets::<Runtime, TrustBackedAssetsInstance>(
+				local_asset,
+				&bob,
+				initial_balance,
+			);
+			pool_helpers::mint_assets::<Runtime, ForeignAssetsInstance>(
+				foreign_asset_location.clone(),
+				&bob,
+				initial_balance,
+			);
+			pool_helpers::create_pool_with_liquidity::<Runtime>(
+				bob.clone(),
+				(native_location.clone(), pool_liquidity),
+				(local_asset_location.clone(), pool_liquidity),
+			);
+			pool_helpers::create_pool_with_liquidity::<Runtime>(
+				bob.clone(),
+				(native_location.clone(), pool_liquidity),
+				(foreign_asset_location.clone(), pool_liquidity),
+			);
+			let lp_tokens = pool_helpers::lp_token_balance::<Runtime>(
+				&native_location,
+				&local_asset_location,
+				&bob,
+			);
+			assert!(lp_tokens > 0);
+
+			// swap local assets for foreign assets through the native token.
+			let amount_in = UNITS;
+			let native_out =
+				AssetConversion::get_amount_out(&amount_in, &pool_liquidity, &pool_liquidity)
+					.unwrap();
+			let expected_out =
+				AssetConversion::get_amount_out(&native_out, &pool_liquidity, &pool_liquidity)
+					.unwrap();
+			let amount_out = pool_helpers::swap_exact_tokens_for_tokens::<Runtime>(
+				bob.clone(),
+				vec![
+					local_asset_location.clone(),
+					native_location.clone(),
+					foreign_asset_location.clone(),
+				],
+				amount_in,
+				1,
+			);
+			assert_eq!(amount_out, expected_out);
+
+			// both pools were rebalanced, LP tokens are untouched.
+			pool_helpers::assert_pool_reserves::<Runtime>(
+				native_location.clone(),
+				local_asset_location.clone(),
+				(pool_liquidity - native_out, pool_liquidity + amount_in),
+			);
+			pool_helpers::assert_pool_reserves::<Runtime>(
+				native_location.clone(),
+				foreign_asset_location.clone(),
+				(pool_liquidity + native_out, pool_liquidity - amount_out),
+			);
+			pool_helpers::assert_lp_token_balance::<Runtime>(
+				&native_location,
+				&local_asset_location,
+				&bob,
+				lp_tokens,
+			);
+			assert_eq!(
+				ForeignAssets::balance(foreign_asset_location, &bob),
+				initial_balance - pool_liquidity + amount_out
+			);
+		})
+}
+
 #[test]
 fn test_buy_and_refund_weight_with_swap_foreign_asset_xcm_trader() {
 	ExtBuilder::<Runtime>::default()
@@ -818,7 +895,7 @@ asset_test_utils::include_create_and_manage_foreign_assets_for_local_consensus_p
 	WithLatestLocationConverter<xcm::v5::Location>,
 	collator_session_keys(),
 	ExistentialDeposit::get(),
-	AssetDeposit::get(),
+	bp_asset_hub_westend::CreateForeignAssetDeposit::get(),
 	MetadataDepositBase::get(),
 	MetadataDepositPerByte::get(),
 	Box::new(|pallet_asset_call| RuntimeCall::ForeignAssets(pallet_asset_call).encode()),
@@ -1416,6 +1493,499 @@ fn xcm_payment_api_works() {
 	>(ExistentialDeposit::get(), ROCOCO_GENESIS_HASH);
 }
 
+#[test]
+fn non_transfer_proxy_cannot_take_over_accounts() {
+	use asset_hub_westend_runtime::ProxyType;
+	use frame_support::traits::InstanceFilter;
+
+	let create_recovery = RuntimeCall::Recovery(pallet_recovery::Call::create_recovery {
+		friends: vec![AccountId::from(ALICE)],
+		threshold: 1,
+		delay_period: 0,
+	});
+	let initiate_recovery = RuntimeCall::Recovery(pallet_recovery::Call::initiate_recovery {
+		account: AccountId::from(ALICE).into(),
+	});
+	let remove_recovery = RuntimeCall::Recovery(pallet_recovery::Call::remove_recovery {});
+
+	assert!(!ProxyType::NonTransfer.filter(&create_recovery));
+	assert!(!ProxyType::NonTransfer.filter(&initiate_recovery));
+	assert!(ProxyType::NonTransfer.filter(&remove_recovery));
+	assert!(ProxyType::Any.filter(&create_recovery));
+}
+
+#[test]
+fn recovery_deposits_are_a_hundredth_of_the_relay_chain_deposits() {
+	use asset_hub_westend_runtime::{
+		RecoveryConfigDepositBase, RecoveryDeposit, RecoveryFriendDepositFactor,
+	};
+	use testnet_parachains_constants::westend::currency::{CENTS, MILLICENTS};
+
+	// The relay chain takes `500 * CENTS` per config and recovery, and `50 * CENTS` per friend.
+	assert_eq!(RecoveryConfigDepositBase::get(), 500 * CENTS / 100);
+	assert_eq!(RecoveryFriendDepositFactor::get(), 50 * CENTS / 100);
+	assert_eq!(RecoveryFriendDepositFactor::get(), 500 * MILLICENTS);
+	assert_eq!(RecoveryDeposit::get(), 500 * CENTS / 100);
+}
+
+#[test]
+fn asset_liquidity_proxy_filter_works() {
+	use asset_hub_westend_runtime::ProxyType;
+	use frame_support::traits::InstanceFilter;
+
+	let native = Box::new(Location::parent());
+	let add_liquidity =
+		RuntimeCall::AssetConversion(pallet_asset_conversion::Call::add_liquidity_to_sender {
+			asset1: native.clone(),
+			asset2: native.clone(),
+			amount1_desired: UNITS,
+			amount2_desired: UNITS,
+			amount1_min: 0,
+			amount2_min: 0,
+		});
+	let add_liquidity_to_other =
+		RuntimeCall::AssetConversion(pallet_asset_conversion::Call::add_liquidity {
+			asset1: native.clone(),
+			asset2: native.clone(),
+			amount1_desired: UNITS,
+			amount2_desired: UNITS,
+			amount1_min: 0,
+			amount2_min: 0,
+			mint_to: AccountId::from(ALICE),
+		});
+	let swap_to_other =
+		RuntimeCall::AssetConversion(pallet_asset_conversion::Call::swap_exact_tokens_for_tokens {
+			path: vec![native.clone(), native.clone()],
+			amount_in: UNITS,
+			amount_out_min: 1,
+			send_to: AccountId::from(ALICE),
+			keep_alive: true,
+		});
+	let touch_pool_asset = RuntimeCall::PoolAssets(pallet_assets::Call::touch { id: 1 });
+	let transfer_pool_asset = RuntimeCall::PoolAssets(pallet_assets::Call::transfer {
+		id: 1,
+		target: AccountId::from(ALICE).into(),
+		amount: UNITS,
+	});
+	let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+		dest: AccountId::from(ALICE).into(),
+		value: UNITS,
+	});
+
+	assert!(ProxyType::AssetLiquidity.filter(&add_liquidity));
+	assert!(!ProxyType::AssetLiquidity.filter(&add_liquidity_to_other));
+	assert!(!ProxyType::AssetLiquidity.filter(&swap_to_other));
+	assert!(ProxyType::AssetLiquidity.filter(&touch_pool_asset));
+	assert!(!ProxyType::AssetLiquidity.filter(&transfer_pool_asset));
+	assert!(!ProxyType::AssetLiquidity.filter(&transfer));
+	assert!(!ProxyType::Assets.filter(&add_liquidity));
+
+	assert!(ProxyType::Any.is_superset(&ProxyType::AssetLiquidity));
+	assert!(!ProxyType::Assets.is_superset(&ProxyType::AssetLiquidity));
+	assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::AssetLiquidity));
+	assert!(!ProxyType::AssetLiquidity.is_superset(&ProxyType::Assets));
+}
+
+#[test]
+fn smart_contracts_proxy_filter_works() {
+	use asset_hub_westend_runtime::ProxyType;
+	use frame_support::traits::InstanceFilter;
+
+	let call = |dest, value| {
+		RuntimeCall::Revive(pallet_revive::Call::call {
+			dest,
+			value,
+			gas_limit: Weight::MAX,
+			storage_deposit_limit: UNITS,
+			data: vec![],
+		})
+	};
+	let dispatch_as_fallback =
+		RuntimeCall::Revive(pallet_revive::Call::dispatch_as_fallback_account {
+			call: Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] })),
+		});
+
+	let call_contract = |value| call(sp_core::H160::repeat_byte(1), value);
+	// The ERC20 pre-compile of the trust backed asset `1`.
+	let erc20_precompile = sp_core::H160::from(hex!("0000000100000000000000000000000001200000"));
+
+	assert!(ProxyType::SmartContracts.filter(&call_contract(0)));
+	assert!(!ProxyType::SmartContracts.filter(&call_contract(UNITS)));
+	assert!(!ProxyType::SmartContracts.filter(&call(erc20_precompile, 0)));
+	assert!(!ProxyType::SmartContracts.filter(&call(sp_core::H160::from_low_u64_be(1), 0)));
+	assert!(
+		ProxyType::SmartContracts.filter(&RuntimeCall::Revive(pallet_revive::Call::map_account {}))
+	);
+	assert!(!ProxyType::SmartContracts.filter(&dispatch_as_fallback));
+	assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::SmartContracts));
+	assert!(ProxyType::Any.is_superset(&ProxyType::SmartContracts));
+}
+
+#[test]
+fn proxy_filter_matrix_matches_runtime_calls() {
+	use asset_hub_westend_runtime::ProxyType;
+	use frame_support::traits::{CallFilter, InstanceFilterMatrix};
+
+	assert_eq!(ProxyType::ensure_matrix_matches::<RuntimeCall>(), Ok(()));
+
+	let matrix = ProxyType::filter_matrix();
+	assert_eq!(matrix.len(), 16);
+	let smart_contracts =
+		matrix.iter().find(|entry| entry.instance == ProxyType::SmartContracts).unwrap();
+	assert_eq!(smart_contracts.filter, CallFilter::Custom);
+	let assets = matrix.iter().find(|entry| entry.instance == ProxyType::Assets).unwrap();
+	assert_eq!(assets.superset_of, vec![ProxyType::AssetOwner, ProxyType::AssetManager]);
+}
+
+#[test]
+fn proxy_api_reports_capabilities() {
+	use asset_hub_westend_runtime::{Proxy, ProxyType};
+	use frame_support::traits::PalletCalls;
+	use pallet_proxy::ProxyCapabilities;
+
+	let pallets = PalletCalls::from_metadata(&Runtime::metadata_ir());
+	let capabilities = |proxy_type| match Proxy::proxy_capabilities(proxy_type, &pallets) {
+		ProxyCapabilities::Calls(calls) => calls,
+		ProxyCapabilities::Custom => panic!("{proxy_type:?} has a static filter"),
+	};
+	// The pallet and call index of a call.
+	let indices = |call: RuntimeCall| {
+		let encoded = call.encode();
+		(encoded[0], encoded[1])
+	};
+
+	let (proxy, reject_announcement) =
+		indices(RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement {
+			delegate: AccountId::from(ALICE).into(),
+			call_hash: Default::default(),
+		}));
+	let (utility, batch) =
+		indices(RuntimeCall::Utility(pallet_utility::Call::batch { calls: vec![] }));
+	let cancel_proxy = capabilities(ProxyType::CancelProxy);
+	assert!(cancel_proxy.contains(&(proxy, vec![reject_announcement])));
+	assert!(cancel_proxy
+		.iter()
+		.any(|(pallet, calls)| *pallet == utility && calls.contains(&batch)));
+	assert_eq!(cancel_proxy.len(), 3);
+
+	assert!(capabilities(ProxyType::OldAuction).is_empty());
+	assert_eq!(
+		Proxy::proxy_capabilities(ProxyType::SmartContracts, &pallets),
+		ProxyCapabilities::Custom
+	);
+	let (balances, _) = indices(RuntimeCall::Balances(pallet_balances::Call::transfer_all {
+		dest: AccountId::from(ALICE).into(),
+		keep_alive: false,
+	}));
+	let has_balances = |capabilities: Vec<(u8, Vec<u8>)>| {
+		capabilities.iter().any(|(pallet, _)| *pallet == balances)
+	};
+	assert!(has_balances(capabilities(ProxyType::Any)));
+	assert!(!has_balances(capabilities(ProxyType::NonTransfer)));
+}
+
+#[test]
+fn scheduled_transfers_are_filtered_out() {
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		type BaseCallFilter = <Runtime as frame_system::Config>::BaseCallFilter;
+
+		let dest = AccountId::from(BOB);
+		let schedule = RuntimeCall::Balances(pallet_balances::Call::schedule_transfer {
+			dest: dest.clone().into(),
+			value: UNITS,
+			at: System::block_number() + 1,
+		});
+		let cancel =
+			RuntimeCall::Balances(pallet_balances::Call::cancel_scheduled_transfer { index: 0 });
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: dest.into(),
+			value: UNITS,
+		});
+		assert!(!BaseCallFilter::contains(&schedule));
+		assert!(!BaseCallFilter::contains(&cancel));
+		assert!(BaseCallFilter::contains(&transfer));
+	})
+}
+
+#[test]
+fn dynamic_parameters_update_deposits() {
+	use asset_hub_westend_runtime::{dynamic_params, Parameters, RuntimeParameters};
+
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		assert_eq!(AssetDeposit::get(), UNITS / 10);
+
+		let new_deposit =
+			RuntimeParameters::Assets(dynamic_params::assets::Parameters::AssetDeposit(
+				dynamic_params::assets::AssetDeposit,
+				Some(UNITS),
+			));
+		assert_noop!(
+			Parameters::set_parameter(
+				RuntimeOrigin::signed(AccountId::from(ALICE)),
+				new_deposit.clone()
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Parameters::set_parameter(RuntimeOrigin::root(), new_deposit));
+		assert_eq!(AssetDeposit::get(), UNITS);
+		assert_eq!(
+			<Runtime as pallet_assets::Config<TrustBackedAssetsInstance>>::AssetDeposit::get(),
+			UNITS
+		);
+	})
+}
+
+#[test]
+fn dynamic_parameters_update_delivery_fees() {
+	use asset_hub_westend_runtime::{
+		dynamic_params::delivery, xcm_config::PriceForParentDelivery, BaseDeliveryFee,
+		DeliveryByteFee, Parameters, RuntimeParameters, RuntimeParametersKey,
+		RuntimeParametersValue, TransactionByteFee,
+	};
+	use polkadot_runtime_common::xcm_sender::PriceForMessageDelivery;
+	use testnet_parachains_constants::westend::currency::CENTS;
+
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		let xcm = Xcm::<()>(vec![ClearOrigin; 10]);
+		let price = || match PriceForParentDelivery::price_for_delivery((), &xcm).inner()[0].fun {
+			Fungible(amount) => amount,
+			_ => unreachable!("delivery fees are fungible"),
+		};
+		let size = xcm.encoded_size() as u128;
+		assert_eq!(DeliveryByteFee::get(), TransactionByteFee::get());
+		assert_eq!(price(), BaseDeliveryFee::get() + size * TransactionByteFee::get());
+
+		assert_ok!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			RuntimeParameters::Delivery(delivery::Parameters::BaseDeliveryFee(
+				delivery::BaseDeliveryFee,
+				Some(UNITS),
+			)),
+		));
+		assert_ok!(Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			RuntimeParameters::Delivery(delivery::Parameters::ByteFee(
+				delivery::ByteFee,
+				Some(CENTS),
+			)),
+		));
+		System::assert_last_event(RuntimeEvent::Parameters(pallet_parameters::Event::Updated {
+			key: RuntimeParametersKey::Delivery(delivery::ParametersKey::ByteFee(
+				delivery::ByteFee,
+			)),
+			old_value: None,
+			new_value: Some(RuntimeParametersValue::Delivery(delivery::ParametersValue::ByteFee(
+				CENTS,
+			))),
+		}));
+		assert_eq!(price(), UNITS + size * CENTS);
+	})
+}
+
+#[test]
+fn foreign_nfts_can_be_reserve_transferred_from_sibling() {
+	use asset_hub_westend_runtime::{ForeignNfts, XcmNftRegistry};
+	use xcm_executor::traits::TransactAsset;
+
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		let sibling = Location::new(1, [Parachain(2000)]);
+		let sibling_account = LocationToAccountId::convert_location(&sibling).unwrap();
+		let location = Location::new(1, [Parachain(2000), PalletInstance(52), GeneralIndex(1)]);
+		let nft: Asset = (location.clone(), Index(1)).into();
+		let fungible: Asset = (location.clone(), 1u128).into();
+		let beneficiary: Location = AccountId32 { network: None, id: BOB }.into();
+		assert_ok!(Balances::mint_into(&sibling_account, 100 * UNITS));
+
+		// The sibling creates a local collection for its foreign collection.
+		let collection = 0;
+		assert_ok!(ForeignNfts::create(
+			RuntimeOrigin::signed(sibling_account.clone()),
+			sibling_account.clone().into(),
+			Default::default(),
+		));
+		assert_eq!(ForeignNfts::collection_owner(collection), Some(sibling_account.clone()));
+
+		// NFTs of unregistered collections are not handled.
+		type AssetTransactor = <XcmConfig as xcm_executor::Config>::AssetTransactor;
+		assert!(AssetTransactor::deposit_asset(&nft, &beneficiary, None).is_err());
+
+		// Only the sibling may map its collections.
+		let other_sibling = Location::new(1, [Parachain(2001)]);
+		assert_noop!(
+			XcmNftRegistry::register_collection(
+				pallet_xcm::Origin::Xcm(other_sibling).into(),
+				Box::new(location.clone().into()),
+				collection,
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(XcmNftRegistry::register_collection(
+			pallet_xcm::Origin::Xcm(sibling.clone()).into(),
+			Box::new(location.clone().into()),
+			collection,
+		));
+
+		// The sibling is the reserve of its NFTs, but not of its fungible assets.
+		type IsReserve = <XcmConfig as xcm_executor::Config>::IsReserve;
+		assert!(IsReserve::contains(&nft, &sibling));
+		assert!(!IsReserve::contains(&fungible, &sibling));
+		assert!(!IsReserve::contains(&nft, &Location::new(1, [Parachain(2001)])));
+
+		// Reserve deposited NFTs are minted to the beneficiary and burnt when withdrawn.
+		assert_ok!(AssetTransactor::deposit_asset(&nft, &beneficiary, None));
+		assert_eq!(ForeignNfts::owner(collection, 1), Some(AccountId::from(BOB)));
+		assert_ok!(AssetTransactor::withdraw_asset(&nft, &beneficiary, None));
+		assert_eq!(ForeignNfts::owner(collection, 1), None);
+	})
+}
+
+#[test]
+fn non_transfer_proxy_cannot_transfer_foreign_nfts() {
+	use asset_hub_westend_runtime::{ForeignNfts, Proxy, ProxyType};
+	use frame_support::traits::{tokens::nonfungibles_v2, InstanceFilter};
+
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		let alice = AccountId::from(ALICE);
+		let bob = AccountId::from(BOB);
+		assert_ok!(Balances::mint_into(&alice, 100 * UNITS));
+		assert_ok!(Balances::mint_into(&bob, 100 * UNITS));
+
+		let collection = 0;
+		let item = 1;
+		assert_ok!(ForeignNfts::create(
+			RuntimeOrigin::signed(alice.clone()),
+			alice.clone().into(),
+			Default::default(),
+		));
+		assert_ok!(<ForeignNfts as nonfungibles_v2::Mutate<_, _>>::mint_into(
+			&collection,
+			&item,
+			&alice,
+			&Default::default(),
+			true,
+		));
+		assert_ok!(Proxy::add_proxy(
+			RuntimeOrigin::signed(alice.clone()),
+			bob.clone().into(),
+			ProxyType::NonTransfer,
+			0,
+		));
+
+		let transfer = RuntimeCall::ForeignNfts(pallet_nfts::Call::transfer {
+			collection,
+			item,
+			dest: bob.clone().into(),
+		});
+		assert!(!ProxyType::NonTransfer.filter(&transfer));
+		assert!(ProxyType::Assets.filter(&transfer));
+
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(bob.clone()),
+			alice.clone().into(),
+			None,
+			Box::new(transfer),
+		));
+		System::assert_last_event(RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted {
+			result: Err(frame_system::Error::<Runtime>::CallFiltered.into()),
+		}));
+		assert_eq!(ForeignNfts::owner(collection, item), Some(alice));
+	})
+}
+
+#[test]
+fn native_fees_paid_through_asset_tx_payment_are_not_accounted_as_swapped() {
+	use pallet_asset_conversion_tx_payment::OnChargeAssetTransaction;
+	type OnChargeAsset =
+		<Runtime as pallet_asset_conversion_tx_payment::Config>::OnChargeAssetTransaction;
+
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		let who = AccountId::from(ALICE);
+		let fee = UNITS;
+		assert_ok!(Balances::mint_into(&who, 10 * UNITS));
+
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let info = Default::default();
+		let paid = OnChargeAsset::withdraw_fee(&who, &call, &info, WestendLocation::get(), fee, 0)
+			.unwrap();
+		assert_ok!(OnChargeAsset::correct_and_deposit_fee(
+			&who,
+			&info,
+			&Default::default(),
+			fee,
+			0,
+			WestendLocation::get(),
+			paid,
+		));
+
+		let flows = pallet_fee_accounting::BlockFlows::<Runtime>::get();
+		assert_eq!(flows.to_destinations(), fee);
+		assert_eq!(flows.swapped, 0);
+	})
+}
+
+#[test]
+fn safe_mode_and_tx_pause_filter_calls() {
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		type BaseCallFilter = <Runtime as frame_system::Config>::BaseCallFilter;
+
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: AccountId::from(ALICE).into(),
+			value: UNITS,
+		});
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let vote = RuntimeCall::ConvictionVoting(pallet_conviction_voting::Call::remove_vote {
+			class: None,
+			index: 0,
+		});
+		let xcm_execute = RuntimeCall::PolkadotXcm(pallet_xcm::Call::execute {
+			message: Box::new(VersionedXcm::V5(Xcm::new())),
+			max_weight: Weight::zero(),
+		});
+		let xcm_suspend =
+			RuntimeCall::PolkadotXcm(pallet_xcm::Call::force_suspension { suspended: true });
+		assert!(BaseCallFilter::contains(&transfer));
+
+		// pausing a single call only filters that call.
+		let transfer_name = (
+			b"Balances".to_vec().try_into().unwrap(),
+			b"transfer_keep_alive".to_vec().try_into().unwrap(),
+		);
+		assert_ok!(asset_hub_westend_runtime::TxPause::pause(
+			RuntimeOrigin::root(),
+			transfer_name.clone()
+		));
+		assert!(!BaseCallFilter::contains(&transfer));
+		assert!(BaseCallFilter::contains(&vote));
+		assert_ok!(asset_hub_westend_runtime::TxPause::unpause(
+			RuntimeOrigin::root(),
+			transfer_name
+		));
+
+		// calls needed to keep the chain running cannot be paused.
+		assert_noop!(
+			asset_hub_westend_runtime::TxPause::pause(
+				RuntimeOrigin::root(),
+				(b"System".to_vec().try_into().unwrap(), b"remark".to_vec().try_into().unwrap())
+			),
+			pallet_tx_pause::Error::<Runtime>::Unpausable
+		);
+
+		// safe mode filters everything but the whitelisted calls.
+		assert_noop!(
+			asset_hub_westend_runtime::SafeMode::enter(RuntimeOrigin::signed(ALICE.into())),
+			pallet_safe_mode::Error::<Runtime>::NotConfigured
+		);
+		assert_ok!(asset_hub_westend_runtime::SafeMode::force_enter(RuntimeOrigin::root()));
+		assert!(!BaseCallFilter::contains(&transfer));
+		assert!(BaseCallFilter::contains(&remark));
+		assert!(BaseCallFilter::contains(&vote));
+		assert!(!BaseCallFilter::contains(&xcm_execute));
+		assert!(BaseCallFilter::contains(&xcm_suspend));
+		assert_ok!(asset_hub_westend_runtime::SafeMode::force_exit(RuntimeOrigin::root()));
+		assert!(BaseCallFilter::contains(&transfer));
+	})
+}
+
 #[test]
 fn governance_authorize_upgrade_works() {
 	use westend_runtime_constants::system_parachain::COLLECTIVES_ID;
@@ -1752,3 +2322,139 @@ fn expensive_erc20_runs_out_of_gas() {
 		.is_err());
 	});
 }
+
+#[test]
+fn aura_schedule_api_maps_slots_to_collators() {
+	use sp_consensus_aura::runtime_decl_for_aura_schedule_api::AuraScheduleApiV1;
+
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(collator_session_keys().session_keys())
+		.build()
+		.execute_with(|| {
+			let alice_aura = AuraId::from(sp_core::sr25519::Public::from_raw(ALICE));
+			let schedule = Runtime::authorship_schedule(3);
+			assert_eq!(schedule.len(), 3);
+			for (offset, slot_author) in schedule.into_iter().enumerate() {
+				assert_eq!(
+					slot_author.slot,
+					pallet_aura::CurrentSlot::<Runtime>::get() + offset as u64 + 1
+				);
+				assert_eq!(slot_author.authority_index, 0);
+				assert_eq!(slot_author.authority, alice_aura);
+				assert_eq!(slot_author.account, Some(AccountId::from(ALICE)));
+			}
+		})
+}
+
+#[test]
+fn aura_api_reports_current_slot_and_authority_index() {
+	use sp_consensus_aura::runtime_decl_for_aura_api::AuraApiV2;
+
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(collator_session_keys().session_keys())
+		.build()
+		.execute_with(|| {
+			pallet_aura::CurrentSlot::<Runtime>::put(sp_consensus_aura::Slot::from(42));
+			assert_eq!(Runtime::current_slot(), sp_consensus_aura::Slot::from(42));
+
+			let alice_aura = AuraId::from(sp_core::sr25519::Public::from_raw(ALICE));
+			let bob_aura = AuraId::from(sp_core::sr25519::Public::from_raw(BOB));
+			assert_eq!(Runtime::authority_index_of(alice_aura), Some(0));
+			assert_eq!(Runtime::authority_index_of(bob_aura), None);
+		})
+}
+
+#[test]
+fn genesis_presets_can_be_patched() {
+	use sp_genesis_builder::runtime_decl_for_genesis_builder::GenesisBuilderV2;
+
+	let patch = serde_json::json!({
+		"balances": { "balances": [[AccountId::from(ALICE), 42 * UNITS]] },
+		"parachainInfo": { "parachainId": 2000 },
+	});
+	let patched = Runtime::get_preset_with_patch(
+		&sp_genesis_builder::DEV_RUNTIME_PRESET.into(),
+		patch.to_string().into_bytes(),
+	)
+	.unwrap();
+	let patched = serde_json::from_slice::<serde_json::Value>(&patched).unwrap();
+	assert_eq!(patched["parachainInfo"]["parachainId"], 2000);
+	assert_eq!(patched["balances"]["balances"], patch["balances"]["balances"]);
+	// The rest of the preset is kept.
+	assert_eq!(patched["collatorSelection"]["invulnerables"].as_array().unwrap().len(), 1);
+
+	let invalid = serde_json::json!({ "parachainInfo": { "unknownField": 1 } });
+	assert!(Runtime::get_preset_with_patch(
+		&sp_genesis_builder::DEV_RUNTIME_PRESET.into(),
+		invalid.to_string().into_bytes(),
+	)
+	.is_err());
+
+	let metadata = Runtime::presets_metadata();
+	assert_eq!(
+		metadata.iter().map(|preset| preset.id.clone()).collect::<Vec<_>>(),
+		Runtime::preset_names()
+	);
+	assert!(metadata.iter().all(|preset| preset.parameters.contains(&"session.keys".into())));
+}
+
+#[test]
+fn multi_block_migrations_run_to_completion() {
+	use frame_support::migrations::MultiStepMigrator;
+
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			let outcome = asset_test_utils::run_mbms_to_completion::<Runtime>();
+
+			assert!(!asset_hub_westend_runtime::MultiBlockMigrations::ongoing());
+			// Each step stays within the service weight reserved for migrations.
+			let max_weight = asset_hub_westend_runtime::MbmServiceWeight::get()
+				.saturating_mul(outcome.blocks.into());
+			assert!(outcome.weight.all_lte(max_weight));
+		})
+}
+
+#[test]
+fn paused_multi_block_migrations_keep_transactions_blocked() {
+	use asset_hub_westend_runtime::MultiBlockMigrations;
+	use frame_support::{migrations::MultiStepMigrator, traits::OnRuntimeUpgrade};
+
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			<MultiBlockMigrations as OnRuntimeUpgrade>::on_runtime_upgrade();
+			assert!(MultiBlockMigrations::ongoing());
+
+			// While paused, no step makes progress and the chain stays in migration mode, so only
+			// inherents are included.
+			assert_ok!(MultiBlockMigrations::pause(RuntimeOrigin::root()));
+			for _ in 0..3 {
+				MultiBlockMigrations::step();
+				assert!(pallet_migrations::PausedAt::<Runtime>::exists());
+				assert!(MultiBlockMigrations::ongoing());
+			}
+
+			assert_ok!(MultiBlockMigrations::resume(RuntimeOrigin::root()));
+			let mut blocks = 0;
+			while MultiBlockMigrations::ongoing() {
+				assert!(blocks < asset_test_utils::MAX_MBM_BLOCKS);
+				MultiBlockMigrations::step();
+				blocks += 1;
+			}
+		})
+}
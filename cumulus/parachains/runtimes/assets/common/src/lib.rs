@@ -22,8 +22,11 @@ pub mod foreign_creators;
 pub mod fungible_conversion;
 pub mod local_and_foreign_assets;
 pub mod matching;
+pub mod nonfungibles_compat;
 pub mod runtime_api;
+pub mod versioned_location;
 pub use erc20_transactor::ERC20Transactor;
+pub use nonfungibles_compat::NonFungiblesV2Compat;
 
 extern crate alloc;
 extern crate core;
@@ -128,6 +131,47 @@ pub type ForeignAssetsConvertedConcreteId<
 	BalanceConverter,
 >;
 
+/// [`MatchedConvertedConcreteId`] converter dedicated for storing foreign NFTs in local
+/// collections.
+///
+/// `LocationToCollectionIdConverter` maps the location of a foreign collection to the local
+/// `CollectionId`, e.g. `pallet-xcm-nft-registry`.
+///
+/// Excludes the same Locations as [`ForeignAssetsConvertedConcreteId`]:
+/// - parent as relay chain
+/// - all local Locations
+///
+/// `AdditionalLocationExclusionFilter` can customize additional excluded Locations
+pub type ForeignNftsConvertedConcreteId<
+	AdditionalLocationExclusionFilter,
+	ItemId,
+	CollectionId,
+	LocationToCollectionIdConverter,
+	ItemIdConverter = TryConvertInto,
+> = MatchedConvertedConcreteId<
+	CollectionId,
+	ItemId,
+	EverythingBut<(
+		Equals<ParentLocation>,
+		StartsWith<LocalLocationPattern>,
+		AdditionalLocationExclusionFilter,
+	)>,
+	LocationToCollectionIdConverter,
+	ItemIdConverter,
+>;
+
+/// [`ForeignAssetsConvertedConcreteId`] for `ForeignAssets` with `AssetId` as
+/// [`VersionedLocation`], which does not change with every XCM version bump.
+///
+/// Asset ids are converted to their canonical form, see [`versioned_location`].
+pub type ForeignAssetsVersionedConvertedConcreteId<AdditionalLocationExclusionFilter, Balance> =
+	ForeignAssetsConvertedConcreteId<
+		AdditionalLocationExclusionFilter,
+		Balance,
+		VersionedLocation,
+		versioned_location::VersionedLocationConverter,
+	>;
+
 /// `Contains<Location>` implementation that matches locations with no parents,
 /// a `PalletInstance` and an `AccountKey20` junction.
 pub struct IsLocalAccountKey20;
@@ -14,7 +14,7 @@
 // limitations under the License.
 
 use core::marker::PhantomData;
-use frame_support::traits::Get;
+use frame_support::traits::{fungibles::OneOf4, Get};
 use sp_runtime::{
 	traits::{Convert, MaybeEquivalence},
 	Either,
@@ -52,3 +52,47 @@ where
 		}
 	}
 }
+
+/// Classifies a [`Location`] as the native asset, a local asset, a foreign asset or a pool asset.
+///
+/// Local and pool assets are recognized through their `Equivalence` criteria, the native asset
+/// is equal to `Native`, and everything else is a foreign asset.
+///
+/// Suitable for use as a `Criterion` with [`frame_support::traits::tokens::fungible::UnionOf4`],
+/// with the native, local, foreign and pool sets in this order.
+pub struct NativeLocalForeignOrPool<
+	Native,
+	LocalEquivalence,
+	LocalAssetId,
+	PoolEquivalence,
+	PoolAssetId,
+	L = Location,
+>(PhantomData<(Native, LocalEquivalence, LocalAssetId, PoolEquivalence, PoolAssetId, L)>);
+impl<Native, LocalEquivalence, LocalAssetId, PoolEquivalence, PoolAssetId, L>
+	Convert<L, OneOf4<(), LocalAssetId, L, PoolAssetId>>
+	for NativeLocalForeignOrPool<
+		Native,
+		LocalEquivalence,
+		LocalAssetId,
+		PoolEquivalence,
+		PoolAssetId,
+		L,
+	>
+where
+	Native: Get<L>,
+	LocalEquivalence: MaybeEquivalence<L, LocalAssetId>,
+	PoolEquivalence: MaybeEquivalence<L, PoolAssetId>,
+	L: PartialEq + Eq,
+{
+	fn convert(l: L) -> OneOf4<(), LocalAssetId, L, PoolAssetId> {
+		if let Some(id) = PoolEquivalence::convert(&l) {
+			OneOf4::Fourth(id)
+		} else if Native::get() == l {
+			OneOf4::First(())
+		} else if let Some(id) = LocalEquivalence::convert(&l) {
+			OneOf4::Second(id)
+		} else {
+			OneOf4::Third(l)
+		}
+	}
+}
@@ -40,6 +40,19 @@ impl<IsForeign: ContainsPair<Location, Location>> ContainsPair<Asset, Location>
 	}
 }
 
+/// Accepts a non-fungible asset if it is from the origin.
+pub struct IsForeignNonFungibleAsset<IsForeign>(core::marker::PhantomData<IsForeign>);
+impl<IsForeign: ContainsPair<Location, Location>> ContainsPair<Asset, Location>
+	for IsForeignNonFungibleAsset<IsForeign>
+{
+	fn contains(asset: &Asset, origin: &Location) -> bool {
+		let result = matches!(asset.fun, NonFungible(_)) &&
+			IsForeignConcreteAsset::<IsForeign>::contains(asset, origin);
+		tracing::trace!(target: "xcm::contains", ?asset, ?origin, ?result, "IsForeignNonFungibleAsset");
+		result
+	}
+}
+
 /// Checks if `a` is from sibling location `b`. Checks that `Location-a` starts with
 /// `Location-b`, and that the `ParaId` of `b` is not equal to `a`.
 pub struct FromSiblingParachain<SelfParaId, L = Location>(
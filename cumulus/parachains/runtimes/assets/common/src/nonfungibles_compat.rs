@@ -0,0 +1,79 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exposes a [`nonfungibles_v2`] implementation, e.g. `pallet-nfts`, through the [`nonfungibles`]
+//! traits used by the `NonFungiblesAdapter` of `xcm-builder`.
+
+use core::marker::PhantomData;
+use frame_support::traits::tokens::{nonfungibles, nonfungibles_v2};
+use sp_runtime::DispatchResult;
+
+/// Implements [`nonfungibles::Inspect`], [`nonfungibles::Mutate`] and [`nonfungibles::Transfer`]
+/// on top of the [`nonfungibles_v2`] implementation `Assets`.
+///
+/// Items are minted with the default `ItemConfig`, and the item deposit is taken from the owner of
+/// the collection, e.g. the sovereign account of the chain the collection was bridged from.
+pub struct NonFungiblesV2Compat<Assets, ItemConfig>(PhantomData<(Assets, ItemConfig)>);
+
+impl<AccountId, Assets: nonfungibles_v2::Inspect<AccountId>, ItemConfig>
+	nonfungibles::Inspect<AccountId> for NonFungiblesV2Compat<Assets, ItemConfig>
+{
+	type ItemId = Assets::ItemId;
+	type CollectionId = Assets::CollectionId;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		Assets::owner(collection, item)
+	}
+
+	fn collection_owner(collection: &Self::CollectionId) -> Option<AccountId> {
+		Assets::collection_owner(collection)
+	}
+
+	fn can_transfer(collection: &Self::CollectionId, item: &Self::ItemId) -> bool {
+		Assets::can_transfer(collection, item)
+	}
+}
+
+impl<AccountId, Assets: nonfungibles_v2::Mutate<AccountId, ItemConfig>, ItemConfig: Default>
+	nonfungibles::Mutate<AccountId> for NonFungiblesV2Compat<Assets, ItemConfig>
+{
+	fn mint_into(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		who: &AccountId,
+	) -> DispatchResult {
+		Assets::mint_into(collection, item, who, &ItemConfig::default(), true)
+	}
+
+	fn burn(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		Assets::burn(collection, item, maybe_check_owner)
+	}
+}
+
+impl<AccountId, Assets: nonfungibles_v2::Transfer<AccountId>, ItemConfig>
+	nonfungibles::Transfer<AccountId> for NonFungiblesV2Compat<Assets, ItemConfig>
+{
+	fn transfer(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		destination: &AccountId,
+	) -> DispatchResult {
+		Assets::transfer(collection, item, destination)
+	}
+}
@@ -0,0 +1,456 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Version-agnostic conversion between [`Location`]s and asset ids.
+//!
+//! Asset ids pinned to a concrete `xcm::v*::Location` have to change their type, and with it the
+//! whole runtime, on every XCM version bump. Asset ids of type [`VersionedLocation`] keep their
+//! type instead. To keep lookups working, such asset ids are always stored in their canonical
+//! form, i.e. in the latest XCM version, which is what [`VersionedLocationConverter`] produces.
+//! When the latest XCM version advances, the stored keys are migrated to the new canonical form
+//! with the [`MigrateVersionedLocationKeys`] multi-block migration.
+
+use alloc::{boxed::Box, vec::Vec};
+use codec::{Encode, FullCodec, MaxEncodedLen};
+use core::marker::PhantomData;
+use frame_support::{
+	migrations::{count_metered, steps_for_weight, SteppedMigration, SteppedMigrationError},
+	storage::{
+		unhashed, IterableStorageDoubleMap, IterableStorageMap, StorageDoubleMap, StorageMap,
+		StoragePrefixedMap,
+	},
+	traits::Get,
+	weights::{RuntimeDbWeight, Weight, WeightMeter},
+};
+use sp_core::hashing::blake2_256;
+use sp_runtime::traits::MaybeEquivalence;
+use xcm::{latest::Location, IdentifyVersion, IntoVersion, VersionedLocation};
+
+/// Converts `location` to its canonical form, i.e. to the latest XCM version.
+///
+/// Returns `None` if `location` cannot be represented in the latest XCM version.
+pub fn canonicalize(location: VersionedLocation) -> Option<VersionedLocation> {
+	location.into_version(xcm::latest::VERSION).ok()
+}
+
+/// Returns `true` if `location` is in its canonical form, i.e. in the latest XCM version.
+pub fn is_canonical(location: &VersionedLocation) -> bool {
+	location.identify_version() == xcm::latest::VERSION
+}
+
+/// An adapter implementation of `MaybeEquivalence` which converts between the latest [`Location`]
+/// and [`VersionedLocation`].
+///
+/// The conversion to [`VersionedLocation`] always yields the canonical form, so it can be used
+/// as the `ConvertAssetId` of asset ids stored as [`VersionedLocation`]. The conversion back
+/// accepts any XCM version.
+pub struct VersionedLocationConverter;
+impl MaybeEquivalence<Location, VersionedLocation> for VersionedLocationConverter {
+	fn convert(location: &Location) -> Option<VersionedLocation> {
+		Some(VersionedLocation::from(location.clone()))
+	}
+
+	fn convert_back(location: &VersionedLocation) -> Option<Location> {
+		location.clone().try_into().ok()
+	}
+}
+
+/// A storage map with a [`VersionedLocation`] in its key, whose entries can be moved to the
+/// canonical form of the location by [`MigrateVersionedLocationKeys`].
+///
+/// Implemented by [`MapKey`], [`DoubleMapFirstKey`] and [`DoubleMapSecondKey`].
+pub trait VersionedLocationKeyedMap {
+	/// The full key of an entry.
+	type Key: FullCodec + MaxEncodedLen + Clone;
+
+	/// The prefix of all entries of the map.
+	fn final_prefix() -> [u8; 32];
+
+	/// Iterates over the keys of the map, starting after `previous` or at the beginning.
+	fn iter_keys_after(previous: Option<&Self::Key>) -> Box<dyn Iterator<Item = Self::Key>>;
+
+	/// The raw storage key of the entry under `key`.
+	fn hashed_key(key: &Self::Key) -> Vec<u8>;
+
+	/// The location in `key`.
+	fn location(key: &Self::Key) -> &VersionedLocation;
+
+	/// `key` with its location replaced by `location`.
+	fn with_location(key: Self::Key, location: VersionedLocation) -> Self::Key;
+}
+
+/// A [`VersionedLocationKeyedMap`] of a storage map keyed by a [`VersionedLocation`].
+pub struct MapKey<Map, Value>(PhantomData<(Map, Value)>);
+impl<Map, Value> VersionedLocationKeyedMap for MapKey<Map, Value>
+where
+	Map: IterableStorageMap<VersionedLocation, Value>
+		+ StorageMap<VersionedLocation, Value>
+		+ StoragePrefixedMap<Value>,
+	Map::KeyIterator: 'static,
+	Value: FullCodec,
+{
+	type Key = VersionedLocation;
+
+	fn final_prefix() -> [u8; 32] {
+		Map::final_prefix()
+	}
+
+	fn iter_keys_after(previous: Option<&Self::Key>) -> Box<dyn Iterator<Item = Self::Key>> {
+		match previous {
+			Some(previous) => Box::new(Map::iter_keys_from(Map::hashed_key_for(previous))),
+			None => Box::new(Map::iter_keys()),
+		}
+	}
+
+	fn hashed_key(key: &Self::Key) -> Vec<u8> {
+		Map::hashed_key_for(key)
+	}
+
+	fn location(key: &Self::Key) -> &VersionedLocation {
+		key
+	}
+
+	fn with_location(_key: Self::Key, location: VersionedLocation) -> Self::Key {
+		location
+	}
+}
+
+/// A [`VersionedLocationKeyedMap`] of a storage double map whose first key is a
+/// [`VersionedLocation`], e.g. the asset accounts of `pallet-assets`.
+pub struct DoubleMapFirstKey<Map, Key2, Value>(PhantomData<(Map, Key2, Value)>);
+impl<Map, Key2, Value> VersionedLocationKeyedMap for DoubleMapFirstKey<Map, Key2, Value>
+where
+	Map: IterableStorageDoubleMap<VersionedLocation, Key2, Value>
+		+ StorageDoubleMap<VersionedLocation, Key2, Value>
+		+ StoragePrefixedMap<Value>,
+	Map::FullKeyIterator: 'static,
+	Key2: FullCodec + MaxEncodedLen + Clone,
+	Value: FullCodec,
+{
+	type Key = (VersionedLocation, Key2);
+
+	fn final_prefix() -> [u8; 32] {
+		Map::final_prefix()
+	}
+
+	fn iter_keys_after(previous: Option<&Self::Key>) -> Box<dyn Iterator<Item = Self::Key>> {
+		match previous {
+			Some(previous) => Box::new(Map::iter_keys_from(Self::hashed_key(previous))),
+			None => Box::new(Map::iter_keys()),
+		}
+	}
+
+	fn hashed_key((key1, key2): &Self::Key) -> Vec<u8> {
+		Map::hashed_key_for(key1, key2)
+	}
+
+	fn location((key1, _): &Self::Key) -> &VersionedLocation {
+		key1
+	}
+
+	fn with_location((_, key2): Self::Key, location: VersionedLocation) -> Self::Key {
+		(location, key2)
+	}
+}
+
+/// A [`VersionedLocationKeyedMap`] of a storage double map whose second key is a
+/// [`VersionedLocation`].
+pub struct DoubleMapSecondKey<Map, Key1, Value>(PhantomData<(Map, Key1, Value)>);
+impl<Map, Key1, Value> VersionedLocationKeyedMap for DoubleMapSecondKey<Map, Key1, Value>
+where
+	Map: IterableStorageDoubleMap<Key1, VersionedLocation, Value>
+		+ StorageDoubleMap<Key1, VersionedLocation, Value>
+		+ StoragePrefixedMap<Value>,
+	Map::FullKeyIterator: 'static,
+	Key1: FullCodec + MaxEncodedLen + Clone,
+	Value: FullCodec,
+{
+	type Key = (Key1, VersionedLocation);
+
+	fn final_prefix() -> [u8; 32] {
+		Map::final_prefix()
+	}
+
+	fn iter_keys_after(previous: Option<&Self::Key>) -> Box<dyn Iterator<Item = Self::Key>> {
+		match previous {
+			Some(previous) => Box::new(Map::iter_keys_from(Self::hashed_key(previous))),
+			None => Box::new(Map::iter_keys()),
+		}
+	}
+
+	fn hashed_key((key1, key2): &Self::Key) -> Vec<u8> {
+		Map::hashed_key_for(key1, key2)
+	}
+
+	fn location((_, key2): &Self::Key) -> &VersionedLocation {
+		key2
+	}
+
+	fn with_location((key1, _): Self::Key, location: VersionedLocation) -> Self::Key {
+		(key1, location)
+	}
+}
+
+/// Moves the value stored under `key` to the key with the canonical form of its location.
+///
+/// Returns `false` if the location of `key` cannot be represented in the latest XCM version, or
+/// if the canonical key is taken already. The value is left untouched then.
+fn migrate_key<Map: VersionedLocationKeyedMap>(key: &Map::Key) -> bool {
+	let location = Map::location(key);
+	if is_canonical(location) {
+		return true
+	}
+	let Some(canonical) = canonicalize(location.clone()) else {
+		tracing::warn!(
+			target: "runtime::assets-common",
+			?location,
+			"Cannot migrate location key to the latest XCM version",
+		);
+		return false
+	};
+	let from = Map::hashed_key(key);
+	let to = Map::hashed_key(&Map::with_location(key.clone(), canonical));
+	if unhashed::exists(&to) {
+		tracing::warn!(
+			target: "runtime::assets-common",
+			?location,
+			"Cannot migrate location key, its canonical form is taken already",
+		);
+		return false
+	}
+	if let Some(value) = unhashed::get_raw(&from) {
+		unhashed::kill(&from);
+		unhashed::put_raw(&to, &value);
+	}
+	true
+}
+
+/// Multi-block migration moving all values of `Map` stored under non-canonical
+/// [`VersionedLocation`] keys to their canonical keys.
+///
+/// Every key costs at most three reads and two writes of `DbWeight`, usually
+/// `<Runtime as frame_system::Config>::DbWeight`. The cursor is the last key visited, so every
+/// step continues where the previous one stopped. Keys which cannot be migrated are logged and
+/// left untouched.
+///
+/// `StepWeight` is the weight that every step gets, usually the `MaxServiceWeight` of
+/// `pallet-migrations`. It is only used to estimate the number of steps.
+///
+/// The identifier is derived from the prefix of `Map` and the latest XCM version, so the
+/// migration has to be scheduled again once the latest XCM version advances.
+pub struct MigrateVersionedLocationKeys<Map, DbWeight, StepWeight>(
+	PhantomData<(Map, DbWeight, StepWeight)>,
+);
+impl<Map, DbWeight, StepWeight> MigrateVersionedLocationKeys<Map, DbWeight, StepWeight>
+where
+	DbWeight: Get<RuntimeDbWeight>,
+{
+	/// The weight of migrating a single key.
+	pub fn weight_per_key() -> Weight {
+		DbWeight::get().reads_writes(3, 2)
+	}
+}
+
+impl<Map, DbWeight, StepWeight> SteppedMigration
+	for MigrateVersionedLocationKeys<Map, DbWeight, StepWeight>
+where
+	Map: VersionedLocationKeyedMap,
+	DbWeight: Get<RuntimeDbWeight>,
+	StepWeight: Get<Weight>,
+{
+	type Cursor = Map::Key;
+	type Identifier = [u8; 32];
+
+	fn id() -> Self::Identifier {
+		(b"assets-common::MigrateVersionedLocationKeys", Map::final_prefix(), xcm::latest::VERSION)
+			.using_encoded(blake2_256)
+	}
+
+	fn estimated_steps(meter: &mut WeightMeter) -> Option<u32> {
+		let keys = count_metered(Map::iter_keys_after(None), DbWeight::get().reads(1), meter)?;
+		steps_for_weight(Self::weight_per_key().saturating_mul(keys.into()), StepWeight::get())
+	}
+
+	fn step(
+		mut cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		let required = Self::weight_per_key();
+		if meter.remaining().any_lt(required) {
+			return Err(SteppedMigrationError::InsufficientWeight { required })
+		}
+
+		let mut keys = Map::iter_keys_after(cursor.as_ref());
+		while meter.try_consume(required).is_ok() {
+			let Some(key) = keys.next() else { return Ok(None) };
+			migrate_key::<Map>(&key);
+			cursor = Some(key);
+		}
+		Ok(cursor)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		for key in Map::iter_keys_after(None) {
+			let location = Map::location(&key);
+			let migratable = canonicalize(location.clone()).map_or(false, |canonical| {
+				!unhashed::exists(&Map::hashed_key(&Map::with_location(key.clone(), canonical)))
+			});
+			if !is_canonical(location) && migratable {
+				return Err("A location key was not migrated to the latest XCM version".into())
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::{
+		parameter_types, storage_alias, weights::constants::RocksDbWeight, Blake2_128Concat,
+	};
+
+	parameter_types! {
+		pub TwoKeysPerStep: Weight = RocksDbWeight::get().reads_writes(3, 2) * 2;
+	}
+
+	type Migration<Map> = MigrateVersionedLocationKeys<Map, RocksDbWeight, TwoKeysPerStep>;
+
+	#[storage_alias]
+	type Balances = StorageMap<Test, Blake2_128Concat, VersionedLocation, u128>;
+
+	#[storage_alias]
+	type Accounts =
+		StorageDoubleMap<Test, Blake2_128Concat, VersionedLocation, Blake2_128Concat, u32, u128>;
+
+	#[storage_alias]
+	type Approvals =
+		StorageDoubleMap<Test, Blake2_128Concat, u32, Blake2_128Concat, VersionedLocation, u128>;
+
+	fn v4_location(para_id: u32) -> VersionedLocation {
+		VersionedLocation::V4(xcm::v4::Location::new(1, [xcm::v4::Junction::Parachain(para_id)]))
+	}
+
+	fn v5_location(para_id: u32) -> VersionedLocation {
+		VersionedLocation::V5(Location::new(1, [xcm::latest::Junction::Parachain(para_id)]))
+	}
+
+	#[test]
+	fn versioned_location_converter_canonicalizes() {
+		let location = Location::new(1, [xcm::latest::Junction::Parachain(1000)]);
+		assert_eq!(VersionedLocationConverter::convert(&location), Some(v5_location(1000)));
+		assert_eq!(
+			VersionedLocationConverter::convert_back(&v4_location(1000)),
+			Some(location.clone())
+		);
+		assert_eq!(VersionedLocationConverter::convert_back(&v5_location(1000)), Some(location));
+		assert_eq!(canonicalize(v4_location(1000)), Some(v5_location(1000)));
+		assert!(is_canonical(&v5_location(1000)));
+		assert!(!is_canonical(&v4_location(1000)));
+	}
+
+	/// Runs the stepped migration of `Map` to completion with room for `keys_per_step` keys per
+	/// step, and returns the number of steps.
+	fn migrate<Map: VersionedLocationKeyedMap>(keys_per_step: u64) -> u32 {
+		let mut cursor = None;
+		let mut steps = 0;
+		loop {
+			let mut meter =
+				WeightMeter::with_limit(Migration::<Map>::weight_per_key() * keys_per_step);
+			steps += 1;
+			cursor = Migration::<Map>::step(cursor, &mut meter).unwrap();
+			if cursor.is_none() {
+				return steps
+			}
+		}
+	}
+
+	#[test]
+	fn migrate_versioned_location_keys_works() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			Balances::insert(v4_location(1000), 1);
+			Balances::insert(v4_location(2000), 2);
+			Balances::insert(v5_location(3000), 3);
+			// The canonical key is taken already, so the old one is kept.
+			Balances::insert(v4_location(4000), 4);
+			Balances::insert(v5_location(4000), 40);
+			assert_eq!(
+				Migration::<MapKey<Balances, u128>>::estimated_steps(&mut WeightMeter::new()),
+				Some(3)
+			);
+
+			// Five keys, plus the migrated keys which may be visited again, at most two per step.
+			let steps = migrate::<MapKey<Balances, u128>>(2);
+			assert!(steps >= 3 && steps <= 5, "{steps}");
+
+			assert_eq!(Balances::get(v5_location(1000)), Some(1));
+			assert_eq!(Balances::get(v5_location(2000)), Some(2));
+			assert_eq!(Balances::get(v5_location(3000)), Some(3));
+			assert_eq!(Balances::get(v5_location(4000)), Some(40));
+			assert_eq!(Balances::get(v4_location(4000)), Some(4));
+			assert!(Balances::get(v4_location(1000)).is_none());
+			assert!(Balances::get(v4_location(2000)).is_none());
+
+			// Running it again leaves everything as it is.
+			migrate::<MapKey<Balances, u128>>(2);
+			assert_eq!(Balances::iter().count(), 5);
+		});
+	}
+
+	#[test]
+	fn migrate_versioned_location_keys_of_double_maps_works() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			Accounts::insert(v4_location(1000), 1, 10);
+			Accounts::insert(v4_location(1000), 2, 20);
+			Accounts::insert(v5_location(2000), 1, 30);
+			Approvals::insert(1, v4_location(1000), 40);
+			Approvals::insert(2, v5_location(2000), 50);
+
+			migrate::<DoubleMapFirstKey<Accounts, u32, u128>>(1);
+			migrate::<DoubleMapSecondKey<Approvals, u32, u128>>(1);
+
+			assert_eq!(Accounts::get(v5_location(1000), 1), Some(10));
+			assert_eq!(Accounts::get(v5_location(1000), 2), Some(20));
+			assert_eq!(Accounts::get(v5_location(2000), 1), Some(30));
+			assert_eq!(Accounts::iter().count(), 3);
+			assert_eq!(Approvals::get(1, v5_location(1000)), Some(40));
+			assert_eq!(Approvals::get(2, v5_location(2000)), Some(50));
+			assert_eq!(Approvals::iter().count(), 2);
+		});
+	}
+
+	#[test]
+	fn migrate_versioned_location_keys_needs_weight_for_one_key() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			Balances::insert(v4_location(1000), 1);
+
+			let mut meter = WeightMeter::with_limit(Weight::zero());
+			assert!(matches!(
+				Migration::<MapKey<Balances, u128>>::step(None, &mut meter),
+				Err(SteppedMigrationError::InsufficientWeight { .. })
+			));
+			assert_eq!(Balances::get(v4_location(1000)), Some(1));
+		});
+	}
+
+	#[test]
+	fn migration_ids_differ_per_map() {
+		assert_ne!(
+			Migration::<MapKey<Balances, u128>>::id(),
+			Migration::<DoubleMapFirstKey<Accounts, u32, u128>>::id(),
+		);
+	}
+}
@@ -0,0 +1,198 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for setting up `pallet-asset-conversion` pools and asserting their state in runtime
+//! tests.
+//!
+//! Pools are identified by the `Location`s of their assets, so the same helpers work for pools of
+//! the native token with trust-backed assets, foreign assets or both. Use [`mint_assets`] with the
+//! matching `pallet-assets` instance to fund liquidity providers beforehand.
+
+use frame_support::{
+	assert_ok,
+	traits::{
+		fungible::Mutate as FungibleMutate,
+		fungibles::{Inspect, Mutate},
+	},
+};
+use frame_system::RawOrigin;
+use pallet_asset_conversion::{PoolLocator, Pools, Swap};
+use parachains_runtimes_test_utils::{AccountIdOf, BalanceOf};
+use xcm::latest::Location;
+
+/// Balance type of the pools of `Runtime`.
+pub type PoolBalanceOf<Runtime> = <Runtime as pallet_asset_conversion::Config>::Balance;
+
+/// Mint `amount` of the native token to `who`.
+pub fn mint_native<Runtime>(who: &AccountIdOf<Runtime>, amount: BalanceOf<Runtime>)
+where
+	Runtime: pallet_balances::Config,
+{
+	assert_ok!(<pallet_balances::Pallet<Runtime> as FungibleMutate<_>>::mint_into(who, amount));
+}
+
+/// Mint `amount` of `asset_id` of the `pallet-assets` instance `I` to `who`.
+///
+/// The asset must exist already.
+pub fn mint_assets<Runtime, I>(
+	asset_id: <Runtime as pallet_assets::Config<I>>::AssetId,
+	who: &AccountIdOf<Runtime>,
+	amount: <Runtime as pallet_assets::Config<I>>::Balance,
+) where
+	Runtime: pallet_assets::Config<I>,
+	I: 'static,
+{
+	assert_ok!(<pallet_assets::Pallet<Runtime, I> as Mutate<_>>::mint_into(asset_id, who, amount));
+}
+
+/// Create the pool of `asset1` and `asset2`, paying the pool setup fee from `creator`.
+pub fn create_pool<Runtime>(creator: AccountIdOf<Runtime>, asset1: Location, asset2: Location)
+where
+	Runtime: pallet_asset_conversion::Config<AssetKind = Location>,
+{
+	assert_ok!(pallet_asset_conversion::Pallet::<Runtime>::create_pool(
+		RawOrigin::Signed(creator).into(),
+		Box::new(asset1),
+		Box::new(asset2),
+	));
+}
+
+/// Add `amount1` of `asset1` and `amount2` of `asset2` to their pool from `provider`, who also
+/// receives the minted LP tokens.
+pub fn add_liquidity<Runtime>(
+	provider: AccountIdOf<Runtime>,
+	asset1: Location,
+	asset2: Location,
+	amount1: PoolBalanceOf<Runtime>,
+	amount2: PoolBalanceOf<Runtime>,
+) where
+	Runtime: pallet_asset_conversion::Config<AssetKind = Location>,
+{
+	assert_ok!(pallet_asset_conversion::Pallet::<Runtime>::add_liquidity(
+		RawOrigin::Signed(provider.clone()).into(),
+		Box::new(asset1),
+		Box::new(asset2),
+		amount1,
+		amount2,
+		1u32.into(),
+		1u32.into(),
+		provider,
+	));
+}
+
+/// Create the pool of `asset1` and `asset2` and add the initial liquidity from `provider`.
+pub fn create_pool_with_liquidity<Runtime>(
+	provider: AccountIdOf<Runtime>,
+	(asset1, amount1): (Location, PoolBalanceOf<Runtime>),
+	(asset2, amount2): (Location, PoolBalanceOf<Runtime>),
+) where
+	Runtime: pallet_asset_conversion::Config<AssetKind = Location>,
+{
+	create_pool::<Runtime>(provider.clone(), asset1.clone(), asset2.clone());
+	add_liquidity::<Runtime>(provider, asset1, asset2, amount1, amount2);
+}
+
+/// Swap exactly `amount_in` of the first asset of `path` for at least `amount_out_min` of the last
+/// one, returning the amount received by `who`.
+pub fn swap_exact_tokens_for_tokens<Runtime>(
+	who: AccountIdOf<Runtime>,
+	path: Vec<Location>,
+	amount_in: PoolBalanceOf<Runtime>,
+	amount_out_min: PoolBalanceOf<Runtime>,
+) -> PoolBalanceOf<Runtime>
+where
+	Runtime: pallet_asset_conversion::Config<AssetKind = Location>,
+{
+	<pallet_asset_conversion::Pallet<Runtime> as Swap<_>>::swap_exact_tokens_for_tokens(
+		who.clone(),
+		path,
+		amount_in,
+		Some(amount_out_min),
+		who,
+		true,
+	)
+	.expect("swap succeeds")
+}
+
+/// Swap at most `amount_in_max` of the first asset of `path` for exactly `amount_out` of the last
+/// one, returning the amount paid by `who`.
+pub fn swap_tokens_for_exact_tokens<Runtime>(
+	who: AccountIdOf<Runtime>,
+	path: Vec<Location>,
+	amount_out: PoolBalanceOf<Runtime>,
+	amount_in_max: PoolBalanceOf<Runtime>,
+) -> PoolBalanceOf<Runtime>
+where
+	Runtime: pallet_asset_conversion::Config<AssetKind = Location>,
+{
+	<pallet_asset_conversion::Pallet<Runtime> as Swap<_>>::swap_tokens_for_exact_tokens(
+		who.clone(),
+		path,
+		amount_out,
+		Some(amount_in_max),
+		who,
+		true,
+	)
+	.expect("swap succeeds")
+}
+
+/// Reserves of `asset1` and `asset2` in their pool, in that order.
+pub fn pool_reserves<Runtime>(
+	asset1: Location,
+	asset2: Location,
+) -> (PoolBalanceOf<Runtime>, PoolBalanceOf<Runtime>)
+where
+	Runtime: pallet_asset_conversion::Config<AssetKind = Location>,
+{
+	pallet_asset_conversion::Pallet::<Runtime>::get_reserves(asset1, asset2)
+		.expect("pool exists and has liquidity")
+}
+
+/// Assert the reserves of `asset1` and `asset2` in their pool, in that order.
+pub fn assert_pool_reserves<Runtime>(
+	asset1: Location,
+	asset2: Location,
+	expected: (PoolBalanceOf<Runtime>, PoolBalanceOf<Runtime>),
+) where
+	Runtime: pallet_asset_conversion::Config<AssetKind = Location>,
+{
+	assert_eq!(pool_reserves::<Runtime>(asset1, asset2), expected);
+}
+
+/// Balance of `who` in the LP token of the pool of `asset1` and `asset2`.
+pub fn lp_token_balance<Runtime>(
+	asset1: &Location,
+	asset2: &Location,
+	who: &AccountIdOf<Runtime>,
+) -> PoolBalanceOf<Runtime>
+where
+	Runtime: pallet_asset_conversion::Config<AssetKind = Location>,
+{
+	let pool_id = Runtime::PoolLocator::pool_id(asset1, asset2).expect("valid pool assets");
+	let pool = Pools::<Runtime>::get(pool_id).expect("pool exists");
+	<Runtime::PoolAssets as Inspect<_>>::balance(pool.lp_token, who)
+}
+
+/// Assert the balance of `who` in the LP token of the pool of `asset1` and `asset2`.
+pub fn assert_lp_token_balance<Runtime>(
+	asset1: &Location,
+	asset2: &Location,
+	who: &AccountIdOf<Runtime>,
+	expected: PoolBalanceOf<Runtime>,
+) where
+	Runtime: pallet_asset_conversion::Config<AssetKind = Location>,
+{
+	assert_eq!(lp_token_balance::<Runtime>(asset1, asset2, who), expected);
+}
@@ -15,7 +15,7 @@
 
 //! Module contains predefined test-case scenarios for `Runtime` with various assets.
 
-use super::xcm_helpers;
+use super::{pool_helpers, xcm_helpers};
 use crate::{assert_matches_reserve_asset_deposited_instructions, get_fungible_delivery_fees};
 use codec::Encode;
 use core::ops::Mul;
@@ -1783,22 +1783,11 @@ pub fn setup_pool_for_paying_fees_with_foreign_assets<Runtime, RuntimeOrigin>(
 		(foreign_asset_id_minimum_balance + pool_liquidity).mul(2).into(),
 	));
 
-	assert_ok!(pallet_asset_conversion::Pallet::<Runtime>::create_pool(
-		RuntimeOrigin::signed(pool_owner.clone()),
-		Box::new(native_asset.clone().into()),
-		Box::new(foreign_asset_id_location.clone().into())
-	));
-
-	assert_ok!(pallet_asset_conversion::Pallet::<Runtime>::add_liquidity(
-		RuntimeOrigin::signed(pool_owner.clone()),
-		Box::new(native_asset.into()),
-		Box::new(foreign_asset_id_location.into()),
-		pool_liquidity,
-		pool_liquidity,
-		1,
-		1,
+	pool_helpers::create_pool_with_liquidity::<Runtime>(
 		pool_owner,
-	));
+		(native_asset, pool_liquidity),
+		(foreign_asset_id_location, pool_liquidity),
+	);
 }
 
 pub fn xcm_payment_api_foreign_asset_pool_works<
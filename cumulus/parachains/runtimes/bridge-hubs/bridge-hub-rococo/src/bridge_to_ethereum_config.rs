@@ -37,7 +37,6 @@ use testnet_parachains_constants::rococo::{
 use crate::xcm_config::RelayNetwork;
 #[cfg(feature = "runtime-benchmarks")]
 use benchmark_helpers::DoNothingRouter;
-use bp_asset_hub_rococo::CreateForeignAssetDeposit;
 use frame_support::{parameter_types, weights::ConstantMultiplier};
 use hex_literal::hex;
 use pallet_xcm::EnsureXcm;
@@ -59,6 +58,7 @@ pub type SnowbridgeExporter = EthereumBlobExporter<
 // Ethereum Bridge
 parameter_types! {
 	pub storage EthereumGatewayAddress: H160 = H160(hex!("EDa338E4dC46038493b885327842fD3E301CaB39"));
+	pub CreateForeignAssetDeposit: u128 = crate::dynamic_params::snowbridge::CreateForeignAssetDeposit::get();
 }
 
 parameter_types! {
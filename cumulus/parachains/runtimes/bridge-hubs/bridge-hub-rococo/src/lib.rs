@@ -61,9 +61,13 @@ use cumulus_primitives_core::{ClaimQueueOffset, CoreSelector, ParaId};
 use frame_support::{
 	construct_runtime, derive_impl,
 	dispatch::DispatchClass,
+	dynamic_params::{dynamic_pallet_params, dynamic_params},
 	genesis_builder_helper::{build_state, get_preset},
 	parameter_types,
-	traits::{ConstBool, ConstU32, ConstU64, ConstU8, Get, TransformOrigin},
+	traits::{
+		AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, ConstU8, EitherOfDiverse, Get,
+		TransformOrigin,
+	},
 	weights::{ConstantMultiplier, Weight},
 	PalletId,
 };
@@ -192,6 +196,7 @@ pub type Migrations = (
 		Runtime,
 		pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
 	>,
+	pallet_session::migrations::v2::MigrateV1ToV2<Runtime, sp_runtime::traits::ConvertInto>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	cumulus_pallet_aura_ext::migration::MigrateV0ToV1<Runtime>,
@@ -335,12 +340,15 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<0>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 	type EventHandler = (CollatorSelection,);
+	type AuthorStatsWindow = ();
 }
 
 parameter_types! {
@@ -361,9 +369,12 @@ impl pallet_balances::Config for Runtime {
 	type ReserveIdentifier = [u8; 8];
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ConstU32<0>;
 	type DoneSlashHandler = ();
+	type Scheduler = ();
+	type MaxScheduledTransferDelay = ConstU32<{ 30 * DAYS }>;
 }
 
 parameter_types! {
@@ -420,6 +431,14 @@ parameter_types! {
 	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(60) * RuntimeBlockWeights::get().max_block;
 }
 
+parameter_types! {
+	pub const MessageQueueMaxDeadLetters: u32 = 16;
+	pub const DeadLetterBaseDeposit: Balance = deposit(1, 0);
+	pub const DeadLetterByteDeposit: Balance = deposit(0, 1);
+	pub const DeadLetterHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::MessageQueue(pallet_message_queue::HoldReason::DeadLetter);
+}
+
 impl pallet_message_queue::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_message_queue::WeightInfo<Runtime>;
@@ -447,6 +466,22 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type MaxDeadLetters = MessageQueueMaxDeadLetters;
+	// Sovereign accounts pay for the dead letters of their queues.
+	type DeadLetterDepositor = parachains_common::message_queue::SiblingSovereignAccount<
+		AccountId,
+		xcm_config::LocationToAccountId,
+	>;
+	type DeadLetterConsideration = frame_support::traits::fungible::HoldConsideration<
+		AccountId,
+		Balances,
+		DeadLetterHoldReason,
+		frame_support::traits::LinearStoragePrice<
+			DeadLetterBaseDeposit,
+			DeadLetterByteDeposit,
+			Balance,
+		>,
+	>;
 }
 
 impl cumulus_pallet_aura_ext::Config for Runtime {}
@@ -509,6 +544,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -517,6 +554,10 @@ impl pallet_aura::Config for Runtime {
 	type MaxAuthorities = ConstU32<100_000>;
 	type AllowMultipleBlocksPerSlot = ConstBool<true>;
 	type SlotDuration = ConstU64<SLOT_DURATION>;
+	type EquivocationReportSystem = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -539,6 +580,8 @@ impl pallet_collator_selection::Config for Runtime {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
 	type ValidatorRegistration = Session;
+	type AuthorStats = ();
+	type MinAuthoredBlocks = ();
 	type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -558,6 +601,10 @@ impl pallet_multisig::Config for Runtime {
 	type MaxSignatories = ConstU32<100>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as sp_runtime::traits::Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 impl pallet_utility::Config for Runtime {
@@ -567,6 +614,58 @@ impl pallet_utility::Config for Runtime {
 	type WeightInfo = weights::pallet_utility::WeightInfo<Runtime>;
 }
 
+/// Dynamic parameters that can be changed at runtime through
+/// `pallet_parameters::set_parameter`.
+#[dynamic_params(RuntimeParameters, pallet_parameters::Parameters::<Runtime>)]
+pub mod dynamic_params {
+	use super::*;
+
+	/// Parameters of the bridge to Ethereum.
+	#[dynamic_pallet_params]
+	#[codec(index = 0)]
+	pub mod snowbridge {
+		/// Deposit paid for creating a foreign asset for a bridged token on Asset Hub.
+		///
+		/// Should match the foreign asset deposit of Asset Hub, which defaults to the same
+		/// [`bp_asset_hub_rococo::CreateForeignAssetDeposit`].
+		#[codec(index = 0)]
+		pub static CreateForeignAssetDeposit: u128 =
+			bp_asset_hub_rococo::CreateForeignAssetDeposit::get();
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl Default for RuntimeParameters {
+	fn default() -> Self {
+		RuntimeParameters::Snowbridge(
+			dynamic_params::snowbridge::Parameters::CreateForeignAssetDeposit(
+				dynamic_params::snowbridge::CreateForeignAssetDeposit,
+				Some(bp_asset_hub_rococo::CreateForeignAssetDeposit::get()),
+			),
+		)
+	}
+}
+
+parameter_types! {
+	// GeneralAdmin pluralistic body.
+	pub const GeneralAdminBodyId: BodyId = BodyId::Administration;
+}
+
+/// Root or the `GeneralAdmin` of the governance location can modify the dynamic parameters.
+pub type DynamicParameterOrigin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_xcm::EnsureXcm<
+		pallet_xcm::IsVoiceOfBody<xcm_config::GovernanceLocation, GeneralAdminBodyId>,
+	>,
+>;
+
+impl pallet_parameters::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeParameters = RuntimeParameters;
+	type AdminOrigin = AsEnsureOriginWithArg<DynamicParameterOrigin>;
+	type WeightInfo = weights::pallet_parameters::WeightInfo<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime
@@ -597,6 +696,7 @@ construct_runtime!(
 		// Handy utilities.
 		Utility: pallet_utility = 40,
 		Multisig: pallet_multisig = 36,
+		Parameters: pallet_parameters = 37,
 
 		// Bridge relayers pallet, used by several bridges here.
 		BridgeRelayers: pallet_bridge_relayers = 47,
@@ -682,6 +782,7 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_message_queue, MessageQueue]
 		[pallet_multisig, Multisig]
+		[pallet_parameters, Parameters]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_utility, Utility]
 		[pallet_timestamp, Timestamp]
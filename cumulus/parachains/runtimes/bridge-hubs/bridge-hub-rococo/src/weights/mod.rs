@@ -39,6 +39,7 @@ pub mod pallet_bridge_relayers_permissionless_lanes;
 pub mod pallet_collator_selection;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
+pub mod pallet_parameters;
 pub mod pallet_session;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
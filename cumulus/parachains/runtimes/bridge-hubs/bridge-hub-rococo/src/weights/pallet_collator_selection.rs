@@ -111,6 +111,8 @@ impl<T: frame_system::Config> pallet_collator_selection::WeightInfo for WeightIn
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `CollatorSelection::DesiredCandidatesRamp` (r:0 w:1)
+	/// Proof: `CollatorSelection::DesiredCandidatesRamp` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::DesiredCandidates` (r:0 w:1)
 	/// Proof: `CollatorSelection::DesiredCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	fn set_desired_candidates() -> Weight {
@@ -119,6 +121,14 @@ impl<T: frame_system::Config> pallet_collator_selection::WeightInfo for WeightIn
 		//  Estimated: `0`
 		// Minimum execution time: 5_392_000 picoseconds.
 		Weight::from_parts(5_704_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CollatorSelection::DesiredCandidatesRamp` (r:0 w:1)
+	/// Proof: `CollatorSelection::DesiredCandidatesRamp` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn set_desired_candidates_ramp() -> Weight {
+		// Placeholder until the `set_desired_candidates_ramp` benchmark is run.
+		Weight::from_parts(5_902_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -64,6 +64,7 @@ use xcm_executor::{
 parameter_types! {
 	pub const RootLocation: Location = Location::here();
 	pub const TokenLocation: Location = Location::parent();
+	pub const GovernanceLocation: Location = Location::parent();
 	pub RelayChainOrigin: RuntimeOrigin = cumulus_pallet_xcm::Origin::Relay.into();
 	pub RelayNetwork: NetworkId = NetworkId::ByGenesis(ROCOCO_GENESIS_HASH);
 	pub UniversalLocation: InteriorLocation =
@@ -294,6 +295,8 @@ impl pallet_xcm::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type MaxVersionNotificationsPerBlock = ();
+	type VersionDiscoveryPeriod = ();
 	// xcm_executor::Config::Aliasers also uses pallet_xcm::AuthorizedAliasers.
 	type AuthorizedAliasConsideration = HoldConsideration<
 		AccountId,
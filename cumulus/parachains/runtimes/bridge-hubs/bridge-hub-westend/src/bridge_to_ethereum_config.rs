@@ -22,7 +22,6 @@ use crate::{
 	EthereumOutboundQueueV2, EthereumSystem, EthereumSystemV2, MessageQueue, Runtime, RuntimeEvent,
 	TransactionByteFee,
 };
-use bp_asset_hub_westend::CreateForeignAssetDeposit;
 use frame_support::{parameter_types, traits::Contains, weights::ConstantMultiplier};
 use frame_system::EnsureRootWithSuccess;
 use pallet_xcm::EnsureXcm;
@@ -35,7 +34,7 @@ use snowbridge_outbound_queue_primitives::{
 };
 use sp_core::H160;
 use sp_runtime::{
-	traits::{ConstU32, ConstU8, Keccak256},
+	traits::{ConstU32, ConstU64, ConstU8, Keccak256},
 	FixedU128,
 };
 use testnet_parachains_constants::westend::{
@@ -73,10 +72,12 @@ pub type SnowbridgeExporterV2 = EthereumBlobExporterV2<
 // Ethereum Bridge
 parameter_types! {
 	pub storage EthereumGatewayAddress: H160 = H160(hex!("b1185ede04202fe62d38f5db72f71e38ff3e8305"));
+	pub CreateForeignAssetDeposit: u128 = crate::dynamic_params::snowbridge::CreateForeignAssetDeposit::get();
 }
 
 parameter_types! {
 	pub const CreateAssetCall: [u8;2] = [53, 0];
+	pub const SetAssetMetadataCall: [u8;2] = [53, 17];
 	pub Parameters: PricingParameters<u128> = PricingParameters {
 		exchange_rate: FixedU128::from_rational(1, 400),
 		fee_per_gas: gwei(20),
@@ -138,7 +139,9 @@ impl snowbridge_pallet_inbound_queue_v2::Config for Runtime {
 	type XcmExecutor = XcmExecutor<XcmConfig>;
 	type MessageConverter = snowbridge_inbound_queue_primitives::v2::MessageToXcm<
 		CreateAssetCall,
+		SetAssetMetadataCall,
 		CreateForeignAssetDeposit,
+		bp_asset_hub_westend::ForeignAssetMetadataDeposit,
 		EthereumNetwork,
 		InboundQueueV2Location,
 		EthereumSystem,
@@ -155,6 +158,7 @@ impl snowbridge_pallet_inbound_queue_v2::Config for Runtime {
 	type RewardKind = BridgeReward;
 	type DefaultRewardKind = SnowbridgeReward;
 	type RewardPayment = BridgeRelayers;
+	type NonceReplayWindow = ConstU64<{ 1 << 20 }>;
 }
 
 impl snowbridge_pallet_outbound_queue::Config for Runtime {
@@ -299,6 +303,9 @@ impl snowbridge_pallet_system_v2::Config for Runtime {
 	type FrontendOrigin = EnsureXcm<AllowFromEthereumFrontend>;
 	type WeightInfo = crate::weights::snowbridge_pallet_system_v2::WeightInfo<Runtime>;
 	type GovernanceOrigin = EnsureRootWithSuccess<crate::AccountId, RootLocation>;
+	type XcmSender = crate::XcmRouter;
+	type AssetHubLocation = AssetHubLocation;
+	type InboundQueueLocation = InboundQueueV2Location;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
 }
@@ -315,7 +322,7 @@ pub mod benchmark_helpers {
 	use snowbridge_pallet_inbound_queue::BenchmarkHelper;
 	use snowbridge_pallet_inbound_queue_v2::BenchmarkHelper as InboundQueueBenchmarkHelperV2;
 	use snowbridge_pallet_outbound_queue_v2::BenchmarkHelper as OutboundQueueBenchmarkHelperV2;
-	use sp_core::H256;
+	use sp_core::{H160, H256};
 	use xcm::latest::{Assets, Location, SendError, SendResult, SendXcm, Xcm, XcmHash};
 
 	impl<T: snowbridge_pallet_ethereum_client::Config> BenchmarkHelper<T> for Runtime {
@@ -370,6 +377,25 @@ pub mod benchmark_helpers {
 		fn make_xcm_origin(location: Location) -> RuntimeOrigin {
 			RuntimeOrigin::from(pallet_xcm::Origin::Xcm(location))
 		}
+
+		fn initialize_failed_message(message_origin: H256, nonce: u64) {
+			use frame_support::BoundedVec;
+			use snowbridge_outbound_queue_primitives::v2::{EscrowedAsset, MAX_COMMANDS};
+			use snowbridge_pallet_outbound_queue_v2::{Escrow, Escrows};
+			// The worst case, where every command of the message released an asset.
+			let assets = (0..MAX_COMMANDS)
+				.map(|i| EscrowedAsset::NativeToken {
+					token: H160::repeat_byte(i as u8 + 1),
+					amount: 1_000_000_000,
+				})
+				.collect::<alloc::vec::Vec<_>>();
+			let escrow = Escrow {
+				origin: message_origin,
+				assets: BoundedVec::truncate_from(assets),
+				failed: true,
+			};
+			Escrows::<Runtime>::insert(nonce, escrow);
+		}
 	}
 }
 
@@ -55,6 +55,8 @@ impl<T: frame_system::Config> snowbridge_pallet_outbound_queue_v2::WeightInfo fo
 	/// Proof: `EthereumOutboundQueueV2::Messages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `EthereumOutboundQueueV2::PendingOrders` (r:0 w:1)
 	/// Proof: `EthereumOutboundQueueV2::PendingOrders` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumOutboundQueueV2::Escrows` (r:0 w:1)
+	/// Proof: `EthereumOutboundQueueV2::Escrows` (`max_values`: None, `max_size`: Some(426), added: 2901, mode: `MaxEncodedLen`)
 	fn do_process_message() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
@@ -63,7 +65,7 @@ impl<T: frame_system::Config> snowbridge_pallet_outbound_queue_v2::WeightInfo fo
 		Weight::from_parts(19_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 1527))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `EthereumOutboundQueueV2::MessageLeaves` (r:1 w:0)
 	/// Proof: `EthereumOutboundQueueV2::MessageLeaves` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -104,6 +106,8 @@ impl<T: frame_system::Config> snowbridge_pallet_outbound_queue_v2::WeightInfo fo
 	/// Proof: `EthereumOutboundQueueV2::Nonce` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `EthereumOutboundQueueV2::PendingOrders` (r:0 w:32)
 	/// Proof: `EthereumOutboundQueueV2::PendingOrders` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumOutboundQueueV2::Escrows` (r:0 w:32)
+	/// Proof: `EthereumOutboundQueueV2::Escrows` (`max_values`: None, `max_size`: Some(426), added: 2901, mode: `MaxEncodedLen`)
 	/// Storage: `EthereumOutboundQueueV2::MessageLeaves` (r:0 w:1)
 	/// Proof: `EthereumOutboundQueueV2::MessageLeaves` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `EthereumOutboundQueueV2::Messages` (r:0 w:1)
@@ -116,7 +120,7 @@ impl<T: frame_system::Config> snowbridge_pallet_outbound_queue_v2::WeightInfo fo
 		Weight::from_parts(521_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 1493))
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(35))
+			.saturating_add(T::DbWeight::get().writes(67))
 	}
 	/// Storage: `EthereumBeaconClient::LatestFinalizedBlockRoot` (r:1 w:0)
 	/// Proof: `EthereumBeaconClient::LatestFinalizedBlockRoot` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
@@ -126,6 +130,8 @@ impl<T: frame_system::Config> snowbridge_pallet_outbound_queue_v2::WeightInfo fo
 	/// Proof: UNKNOWN KEY `0xaed97c7854d601808b98ae43079dafb3` (r:1 w:0)
 	/// Storage: `EthereumOutboundQueueV2::PendingOrders` (r:1 w:1)
 	/// Proof: `EthereumOutboundQueueV2::PendingOrders` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumOutboundQueueV2::Escrows` (r:1 w:1)
+	/// Proof: `EthereumOutboundQueueV2::Escrows` (`max_values`: None, `max_size`: Some(426), added: 2901, mode: `MaxEncodedLen`)
 	fn submit_delivery_receipt() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `320`
@@ -133,7 +139,7 @@ impl<T: frame_system::Config> snowbridge_pallet_outbound_queue_v2::WeightInfo fo
 		// Minimum execution time: 67_000_000 picoseconds.
 		Weight::from_parts(68_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3785))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 }
@@ -107,4 +107,16 @@ impl<T: frame_system::Config> snowbridge_pallet_system_v2::WeightInfo for Weight
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `EthereumOutboundQueueV2::Escrows` (r:1 w:1)
+	/// Proof: `EthereumOutboundQueueV2::Escrows` (`max_values`: None, `max_size`: Some(426), added: 2901, mode: `MaxEncodedLen`)
+	/// Storage: `EthereumSystem::ForeignToNativeId` (r:8 w:0)
+	/// Proof: `EthereumSystem::ForeignToNativeId` (`max_values`: None, `max_size`: Some(650), added: 3125, mode: `MaxEncodedLen`)
+	fn reclaim_message() -> Weight {
+		// Placeholder until the `reclaim_message` benchmark is run, accounting for the storage
+		// above and for sending the XCM to AssetHub.
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3125))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 }
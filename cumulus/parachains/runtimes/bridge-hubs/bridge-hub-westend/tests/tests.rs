@@ -244,6 +244,61 @@ fn change_required_stake_by_governance_works() {
 	)
 }
 
+#[test]
+fn change_create_foreign_asset_deposit_by_governance_works() {
+	use bridge_hub_westend_runtime::{
+		bridge_to_ethereum_config::CreateForeignAssetDeposit, dynamic_params, RuntimeParameters,
+	};
+	type RuntimeHelper =
+		parachains_runtimes_test_utils::RuntimeHelper<Runtime, AllPalletsWithoutSystem>;
+
+	let set_deposit = |deposit| {
+		RuntimeCall::Parameters(pallet_parameters::Call::set_parameter {
+			key_value: RuntimeParameters::Snowbridge(
+				dynamic_params::snowbridge::Parameters::CreateForeignAssetDeposit(
+					dynamic_params::snowbridge::CreateForeignAssetDeposit,
+					Some(deposit),
+				),
+			),
+		})
+	};
+
+	run_test::<Runtime, _>(
+		collator_session_keys(),
+		bp_bridge_hub_westend::BRIDGE_HUB_WESTEND_PARACHAIN_ID,
+		vec![],
+		|| {
+			let default_deposit = bp_asset_hub_westend::CreateForeignAssetDeposit::get();
+			assert_eq!(CreateForeignAssetDeposit::get(), default_deposit);
+
+			// Signed origins cannot change the deposit.
+			assert!(RuntimeHelper::execute_as_governance_call(
+				set_deposit(default_deposit * 2),
+				GovernanceOrigin::Origin(RuntimeOrigin::signed(Alice.to_account_id())),
+			)
+			.is_err());
+			assert_eq!(CreateForeignAssetDeposit::get(), default_deposit);
+
+			// The governance location can.
+			assert_ok!(RuntimeHelper::execute_as_governance_call(
+				set_deposit(default_deposit * 2),
+				Governance::get(),
+			));
+			assert_eq!(CreateForeignAssetDeposit::get(), default_deposit * 2);
+
+			// And so can its `GeneralAdmin`.
+			let general_admin = GovernanceLocation::get()
+				.appended_with(Plurality { id: BodyId::Administration, part: BodyPart::Voice })
+				.unwrap();
+			assert_ok!(RuntimeHelper::execute_as_governance_call(
+				set_deposit(default_deposit * 3),
+				GovernanceOrigin::Origin(pallet_xcm::Origin::Xcm(general_admin).into()),
+			));
+			assert_eq!(CreateForeignAssetDeposit::get(), default_deposit * 3);
+		},
+	)
+}
+
 #[test]
 fn handle_export_message_from_system_parachain_add_to_outbound_queue_works() {
 	bridge_hub_test_utils::test_cases::handle_export_message_from_system_parachain_to_outbound_queue_works::<
@@ -61,7 +61,7 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	generic, impl_opaque_keys,
 	traits::{AccountIdConversion, BlakeTwo256, Block as BlockT},
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{TransactionPriority, TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, Perbill,
 };
 
@@ -203,12 +203,15 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<0>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 	type EventHandler = (CollatorSelection,);
+	type AuthorStatsWindow = ();
 }
 
 parameter_types! {
@@ -229,9 +232,12 @@ impl pallet_balances::Config for Runtime {
 	type ReserveIdentifier = [u8; 8];
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ConstU32<0>;
 	type DoneSlashHandler = ();
+	type Scheduler = Scheduler;
+	type MaxScheduledTransferDelay = ConstU32<{ 30 * DAYS }>;
 }
 
 parameter_types! {
@@ -266,6 +272,10 @@ impl pallet_multisig::Config for Runtime {
 	type MaxSignatories = ConstU32<100>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as sp_runtime::traits::Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 impl pallet_utility::Config for Runtime {
@@ -401,6 +411,8 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type TransferMeter = ();
+	type MaxAllowanceAssets = ConstU32<0>;
 }
 
 parameter_types! {
@@ -437,6 +449,14 @@ parameter_types! {
 	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(35) * RuntimeBlockWeights::get().max_block;
 }
 
+parameter_types! {
+	pub const MessageQueueMaxDeadLetters: u32 = 16;
+	pub const DeadLetterBaseDeposit: Balance = deposit(1, 0);
+	pub const DeadLetterByteDeposit: Balance = deposit(0, 1);
+	pub const DeadLetterHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::MessageQueue(pallet_message_queue::HoldReason::DeadLetter);
+}
+
 impl pallet_message_queue::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_message_queue::WeightInfo<Runtime>;
@@ -458,6 +478,22 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type MaxDeadLetters = MessageQueueMaxDeadLetters;
+	// Sovereign accounts pay for the dead letters of their queues.
+	type DeadLetterDepositor = parachains_common::message_queue::SiblingSovereignAccount<
+		AccountId,
+		xcm_config::LocationToAccountId,
+	>;
+	type DeadLetterConsideration = frame_support::traits::fungible::HoldConsideration<
+		AccountId,
+		Balances,
+		DeadLetterHoldReason,
+		frame_support::traits::LinearStoragePrice<
+			DeadLetterBaseDeposit,
+			DeadLetterByteDeposit,
+			Balance,
+		>,
+	>;
 }
 
 impl cumulus_pallet_aura_ext::Config for Runtime {}
@@ -520,6 +556,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -528,6 +566,10 @@ impl pallet_aura::Config for Runtime {
 	type MaxAuthorities = ConstU32<100_000>;
 	type AllowMultipleBlocksPerSlot = ConstBool<true>;
 	type SlotDuration = ConstU64<SLOT_DURATION>;
+	type EquivocationReportSystem = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -556,6 +598,8 @@ impl pallet_collator_selection::Config for Runtime {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
 	type ValidatorRegistration = Session;
+	type AuthorStats = ();
+	type MinAuthoredBlocks = ();
 	type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -622,6 +666,9 @@ impl pallet_alliance::Config for Runtime {
 
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+	pub const SchedulerNormalShare: Perbill = Perbill::from_percent(80);
+	pub const SchedulerBulkShare: Perbill = Perbill::from_percent(50);
+	pub const SchedulerMaxPostponement: BlockNumber = 1 * DAYS;
 }
 
 #[cfg(not(feature = "runtime-benchmarks"))]
@@ -643,6 +690,11 @@ impl pallet_scheduler::Config for Runtime {
 	type ScheduleOrigin = EnsureRoot<AccountId>;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
+	type ClassLimits = pallet_scheduler::WeightShares<
+		SchedulerNormalShare,
+		SchedulerBulkShare,
+		SchedulerMaxPostponement,
+	>;
 	type OriginPrivilegeCmp = EqualOrGreatestRootCmp;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
@@ -652,6 +704,7 @@ parameter_types! {
 	pub const PreimageBaseDeposit: Balance = deposit(2, 64);
 	pub const PreimageByteDeposit: Balance = deposit(0, 1);
 	pub const PreimageHoldReason: RuntimeHoldReason = RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+	pub const PreimageUploadLifetime: BlockNumber = 1 * DAYS;
 }
 
 impl pallet_preimage::Config for Runtime {
@@ -665,6 +718,14 @@ impl pallet_preimage::Config for Runtime {
 		PreimageHoldReason,
 		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
 	>;
+	type UploadLifetime = PreimageUploadLifetime;
+}
+
+parameter_types! {
+	pub const AssetRateFeedPeriod: BlockNumber = 1 * HOURS;
+	pub const AssetRateMaxFeedDeviation: Perbill = Perbill::from_percent(10);
+	pub const AssetRateOffchainRepeat: BlockNumber = 5 * MINUTES;
+	pub const AssetRateUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
 }
 
 impl pallet_asset_rate::Config for Runtime {
@@ -678,10 +739,33 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = Self::CreateOrigin;
 	type Currency = Balances;
 	type AssetKind = VersionedLocatableAsset;
+	type FeederId = pallet_asset_rate::sr25519::FeederId;
+	type MaxFeeders = ConstU32<16>;
+	type FeedPeriod = AssetRateFeedPeriod;
+	type MaxFeedDeviation = AssetRateMaxFeedDeviation;
+	type OffchainRepeat = AssetRateOffchainRepeat;
+	type UnsignedPriority = AssetRateUnsignedPriority;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::AssetRateArguments;
 }
 
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type RuntimeCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
+}
+
+impl<C> frame_system::offchain::CreateBare<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	fn create_bare(call: RuntimeCall) -> UncheckedExtrinsic {
+		UncheckedExtrinsic::new_bare(call)
+	}
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime
@@ -800,6 +884,7 @@ type Migrations = (
 		Runtime,
 		pallet_session::migrations::v1::InitOffenceSeverity<Runtime>,
 	>,
+	pallet_session::migrations::v2::MigrateV1ToV2<Runtime, sp_runtime::traits::ConvertInto>,
 );
 
 /// Executive: handles dispatch to the various modules.
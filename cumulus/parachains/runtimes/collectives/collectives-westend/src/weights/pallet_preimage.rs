@@ -265,4 +265,18 @@ impl<T: frame_system::Config> pallet_preimage::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
 	}
+	// The chunked upload calls are not benchmarked for this runtime yet, use the weights of
+	// the pallet until the next benchmark run.
+	fn start_preimage() -> Weight {
+		<pallet_preimage::weights::SubstrateWeight<T> as pallet_preimage::WeightInfo>::start_preimage()
+	}
+	fn append_chunk(s: u32, ) -> Weight {
+		<pallet_preimage::weights::SubstrateWeight<T> as pallet_preimage::WeightInfo>::append_chunk(s)
+	}
+	fn finalize_preimage(c: u32, s: u32, ) -> Weight {
+		<pallet_preimage::weights::SubstrateWeight<T> as pallet_preimage::WeightInfo>::finalize_preimage(c, s)
+	}
+	fn clear_upload(c: u32, ) -> Weight {
+		<pallet_preimage::weights::SubstrateWeight<T> as pallet_preimage::WeightInfo>::clear_upload(c)
+	}
 }
@@ -177,4 +177,42 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::SignatureNonces` (r:1 w:1)
+	/// Proof: `Multisig::SignatureNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3517`
+		// Minimum execution time: 25_578_000 picoseconds.
+		Weight::from_parts(26_644_065, 0)
+			.saturating_add(Weight::from_parts(0, 3517))
+			// Standard Error: 1_087_018
+			.saturating_add(Weight::from_parts(43_480_733, 0).saturating_mul(s.into()))
+			// Standard Error: 9
+			.saturating_add(Weight::from_parts(370, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancelApprovals` (r:1 w:1)
+	/// Proof: `Multisig::CancelApprovals` (`max_values`: None, `max_size`: Some(4862), added: 7337, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:99 w:99)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_cancel_as_multi(s: u32, ) -> Weight {
+		// Placeholder until the `approve_cancel_as_multi` benchmark is run.
+		Weight::from_parts(32_593_394, 0)
+			.saturating_add(Weight::from_parts(0, 8411))
+			// Standard Error: 3_856
+			.saturating_add(Weight::from_parts(154_265, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(s.into()))
+	}
 }
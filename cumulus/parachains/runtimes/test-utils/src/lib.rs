@@ -27,8 +27,9 @@ use cumulus_test_relay_sproof_builder::RelayStateSproofBuilder;
 use frame_support::{
 	dispatch::{DispatchResult, GetDispatchInfo, RawOrigin},
 	inherent::{InherentData, ProvideInherent},
+	migrations::MultiStepMigrator,
 	pallet_prelude::Get,
-	traits::{OnFinalize, OnInitialize, OriginTrait, UnfilteredDispatchable},
+	traits::{OnFinalize, OnInitialize, OnRuntimeUpgrade, OriginTrait, UnfilteredDispatchable},
 	weights::Weight,
 };
 use frame_system::pallet_prelude::{BlockNumberFor, HeaderFor};
@@ -763,3 +764,73 @@ impl<HrmpChannelSource: cumulus_primitives_core::XcmpMessageSource, AllPalletsWi
 		}
 	}
 }
+
+/// Maximum number of blocks [`run_mbms_to_completion`] waits for the migrations to complete.
+pub const MAX_MBM_BLOCKS: u32 = 10_000;
+
+/// Resources consumed by the multi-block migrations run by [`run_mbms_to_completion`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MbmsOutcome {
+	/// Number of blocks it took to complete all migrations.
+	pub blocks: u32,
+	/// Total weight consumed by the migration steps.
+	pub weight: Weight,
+}
+
+/// Helper function which starts the multi-block migrations of `Runtime`, as a runtime upgrade
+/// would, and advances blocks until all of them completed.
+///
+/// Panics if any migration fails, if the migrations are paused, or if they do not complete within
+/// [`MAX_MBM_BLOCKS`] blocks. Paused migrations make no progress, but keep the chain in migration
+/// mode, so transactions stay blocked until they are resumed. No migration must be ongoing when
+/// calling this function.
+pub fn run_mbms_to_completion<Runtime>() -> MbmsOutcome
+where
+	Runtime: pallet_migrations::Config,
+	<Runtime as frame_system::Config>::RuntimeEvent: TryInto<pallet_migrations::Event<Runtime>>,
+{
+	assert!(
+		!<Runtime as frame_system::Config>::MultiBlockMigrator::ongoing(),
+		"multi-block migrations are already ongoing"
+	);
+	<pallet_migrations::Pallet<Runtime> as OnRuntimeUpgrade>::on_runtime_upgrade();
+	assert_no_mbm_failure::<Runtime>();
+
+	let mut outcome = MbmsOutcome::default();
+	while <Runtime as frame_system::Config>::MultiBlockMigrator::ongoing() {
+		assert!(
+			outcome.blocks < MAX_MBM_BLOCKS,
+			"multi-block migrations did not complete within {MAX_MBM_BLOCKS} blocks"
+		);
+		let next_block_number = frame_system::Pallet::<Runtime>::block_number() + 1u32.into();
+		frame_system::Pallet::<Runtime>::reset_events();
+		frame_system::Pallet::<Runtime>::set_block_number(next_block_number);
+
+		outcome
+			.weight
+			.saturating_accrue(<Runtime as frame_system::Config>::MultiBlockMigrator::step());
+		outcome.blocks += 1;
+		assert_no_mbm_failure::<Runtime>();
+		assert!(
+			!pallet_migrations::PausedAt::<Runtime>::exists(),
+			"multi-block migrations were paused after {} blocks and keep transactions blocked",
+			outcome.blocks
+		);
+	}
+	outcome
+}
+
+fn assert_no_mbm_failure<Runtime>()
+where
+	Runtime: pallet_migrations::Config,
+	<Runtime as frame_system::Config>::RuntimeEvent: TryInto<pallet_migrations::Event<Runtime>>,
+{
+	for record in frame_system::Pallet::<Runtime>::events() {
+		match record.event.try_into() {
+			Ok(pallet_migrations::Event::MigrationFailed { index, .. }) =>
+				panic!("multi-block migration {index} failed"),
+			Ok(pallet_migrations::Event::UpgradeFailed) => panic!("multi-block upgrade failed"),
+			_ => {},
+		}
+	}
+}
@@ -163,9 +163,19 @@ pub struct Cli<Config: CliConfig> {
 	///
 	/// The `--dev` flag sets the `dev_block_time` to a default value of 3000ms unless explicitly
 	/// provided.
-	#[arg(long)]
+	#[arg(long, conflicts_with = "dev_block_mode")]
 	pub dev_block_time: Option<u64>,
 
+	/// Start a dev node that produces blocks on demand rather than each `dev_block_time` ms.
+	///
+	/// Like `--dev-block-time`, this enables manual sealing: the node works on its own, without
+	/// connecting to a relay chain, and mocks the parachain inherents of the blocks it produces.
+	/// With `manual`, blocks are only produced when requested through the `engine_createBlock`
+	/// RPC. With `instant`, a block is produced as soon as a transaction enters the transaction
+	/// pool.
+	#[arg(long)]
+	pub dev_block_mode: Option<DevBlockMode>,
+
 	/// DEPRECATED: This feature has been stabilized, pLease use `--authoring slot-based` instead.
 	///
 	/// Use slot-based collator which can handle elastic scaling.
@@ -230,6 +240,15 @@ impl Display for AuthoringPolicy {
 	}
 }
 
+/// How a dev node produces blocks on demand.
+#[derive(PartialEq, Debug, ValueEnum, Clone, Copy)]
+pub enum DevBlockMode {
+	/// Produce a block only when requested through the `engine_createBlock` RPC.
+	Manual,
+	/// Produce a block as soon as a transaction enters the transaction pool.
+	Instant,
+}
+
 impl<Config: CliConfig> Cli<Config> {
 	pub(crate) fn node_extra_args(&self) -> NodeExtraArgs {
 		NodeExtraArgs {
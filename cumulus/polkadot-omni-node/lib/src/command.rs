@@ -15,7 +15,7 @@
 // limitations under the License.
 
 use crate::{
-	cli::{Cli, RelayChainCli, Subcommand},
+	cli::{Cli, DevBlockMode, RelayChainCli, Subcommand},
 	common::{
 		chain_spec::LoadSpec,
 		runtime::{
@@ -27,7 +27,7 @@ use crate::{
 	},
 	extra_subcommand::DefaultExtraSubcommands,
 	fake_runtime_api,
-	nodes::DynNodeSpecExt,
+	nodes::{DynNodeSpecExt, ManualSealMode},
 	runtime::BlockNumber,
 };
 use clap::{CommandFactory, FromArgMatches};
@@ -251,6 +251,19 @@ where
 						node.run_benchmark_block_cmd(config, cmd)
 					})
 				},
+				BenchmarkCmd::Pov(cmd) => {
+					// The command needs the full node configuration because it uses the node
+					// client to execute extrinsics on the state of its database.
+					let runner = cli.create_runner(cmd)?;
+					runner.sync_run(|config| {
+						let node = new_node_spec(
+							&config,
+							&cmd_config.runtime_resolver,
+							&cli.node_extra_args(),
+						)?;
+						node.run_benchmark_pov_cmd(config, cmd)
+					})
+				},
 				#[cfg(feature = "runtime-benchmarks")]
 				BenchmarkCmd::Storage(cmd) => {
 					// The command needs the full node configuration because it uses the node
@@ -303,19 +316,19 @@ where
 				let node_spec =
 					new_node_spec(&config, &cmd_config.runtime_resolver, &cli.node_extra_args())?;
 
-				if cli.run.base.is_dev()? {
+				let is_dev = cli.run.base.is_dev()?;
+				let manual_seal_mode = match (cli.dev_block_mode, cli.dev_block_time) {
+					(Some(DevBlockMode::Manual), _) => Some(ManualSealMode::Manual),
+					(Some(DevBlockMode::Instant), _) => Some(ManualSealMode::Instant),
+					(None, Some(dev_block_time)) => Some(ManualSealMode::Interval(dev_block_time)),
 					// Set default dev block time to 3000ms if not set.
 					// TODO: take block time from AURA config if set.
-					let dev_block_time = cli.dev_block_time.unwrap_or(DEFAULT_DEV_BLOCK_TIME_MS);
-					return node_spec
-						.start_manual_seal_node(config, dev_block_time)
-						.map_err(Into::into);
-				}
-
-				if let Some(dev_block_time) = cli.dev_block_time {
-					return node_spec
-						.start_manual_seal_node(config, dev_block_time)
-						.map_err(Into::into);
+					(None, None) if is_dev =>
+						Some(ManualSealMode::Interval(DEFAULT_DEV_BLOCK_TIME_MS)),
+					(None, None) => None,
+				};
+				if let Some(mode) = manual_seal_mode {
+					return node_spec.start_manual_seal_node(config, mode).map_err(Into::into);
 				}
 
 				// If Statemint (Statemine, Westmint, Rockmine) DB exists and we're using the
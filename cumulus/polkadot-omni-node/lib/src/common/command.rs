@@ -16,9 +16,9 @@
 
 use crate::common::spec::BaseNodeSpec;
 use cumulus_client_cli::ExportGenesisHeadCommand;
-use frame_benchmarking_cli::BlockCmd;
 #[cfg(any(feature = "runtime-benchmarks"))]
 use frame_benchmarking_cli::StorageCmd;
+use frame_benchmarking_cli::{BlockCmd, PovCmd};
 use sc_cli::{CheckBlockCmd, ExportBlocksCmd, ExportStateCmd, ImportBlocksCmd, RevertCmd};
 use sc_service::{Configuration, TaskManager};
 use std::{future::Future, pin::Pin};
@@ -71,6 +71,9 @@ pub trait NodeCommandRunner {
 		cmd: &BlockCmd,
 	) -> SyncCmdResult;
 
+	fn run_benchmark_pov_cmd(self: Box<Self>, config: Configuration, cmd: &PovCmd)
+		-> SyncCmdResult;
+
 	#[cfg(any(feature = "runtime-benchmarks"))]
 	fn run_benchmark_storage_cmd(
 		self: Box<Self>,
@@ -146,6 +149,15 @@ where
 		cmd.run(partial.client)
 	}
 
+	fn run_benchmark_pov_cmd(
+		self: Box<Self>,
+		config: Configuration,
+		cmd: &PovCmd,
+	) -> SyncCmdResult {
+		let partial = T::new_partial(&config).map_err(sc_cli::Error::Service)?;
+		cmd.run(partial.client)
+	}
+
 	#[cfg(any(feature = "runtime-benchmarks"))]
 	fn run_benchmark_storage_cmd(
 		self: Box<Self>,
@@ -14,16 +14,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::common::{
-	rpc::BuildRpcExtensions as BuildRpcExtensionsT,
-	spec::{BaseNodeSpec, BuildImportQueue, ClientBlockImport, NodeSpec as NodeSpecT},
-	types::{Hash, ParachainBlockImport, ParachainClient},
+use crate::{
+	common::{
+		rpc::BuildRpcExtensions as BuildRpcExtensionsT,
+		spec::{BaseNodeSpec, BuildImportQueue, ClientBlockImport, NodeSpec as NodeSpecT},
+		types::{Hash, ParachainBlockImport, ParachainClient},
+	},
+	nodes::ManualSealMode,
 };
 use codec::Encode;
 use cumulus_client_parachain_inherent::{MockValidationDataInherentDataProvider, MockXcmConfig};
 use cumulus_primitives_aura::AuraUnincludedSegmentApi;
 use cumulus_primitives_core::CollectCollationInfo;
-use futures::FutureExt;
+use futures::{FutureExt, StreamExt};
 use polkadot_primitives::UpgradeGoAhead;
 use sc_client_api::Backend;
 use sc_consensus::{DefaultImportQueue, LongestChain};
@@ -31,7 +34,7 @@ use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
 use sc_network::NetworkBackend;
 use sc_service::{Configuration, PartialComponents, TaskManager};
 use sc_telemetry::TelemetryHandle;
-use sc_transaction_pool_api::OffchainTransactionPoolFactory;
+use sc_transaction_pool_api::{OffchainTransactionPoolFactory, TransactionPool};
 use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_runtime::traits::Header;
 use std::{marker::PhantomData, sync::Arc};
@@ -78,7 +81,7 @@ impl<NodeSpec: NodeSpecT> ManualSealNode<NodeSpec> {
 	pub fn start_node<Net>(
 		&self,
 		mut config: Configuration,
-		block_time: u64,
+		mode: ManualSealMode,
 	) -> sc_service::error::Result<TaskManager>
 	where
 		Net: NetworkBackend<NodeSpec::Block, Hash>,
@@ -154,21 +157,47 @@ impl<NodeSpec: NodeSpecT> ManualSealNode<NodeSpec> {
 
 		let (manual_seal_sink, manual_seal_stream) = futures::channel::mpsc::channel(1024);
 		let mut manual_seal_sink_clone = manual_seal_sink.clone();
-		task_manager
-			.spawn_essential_handle()
-			.spawn("block_authoring", None, async move {
-				loop {
-					futures_timer::Delay::new(std::time::Duration::from_millis(block_time)).await;
-					manual_seal_sink_clone
-						.try_send(sc_consensus_manual_seal::EngineCommand::SealNewBlock {
-							create_empty: true,
-							finalize: true,
-							parent_hash: None,
-							sender: None,
-						})
-						.unwrap();
-				}
-			});
+		match mode {
+			ManualSealMode::Interval(block_time) => {
+				task_manager
+					.spawn_essential_handle()
+					.spawn("block_authoring", None, async move {
+						loop {
+							futures_timer::Delay::new(std::time::Duration::from_millis(block_time))
+								.await;
+							manual_seal_sink_clone
+								.try_send(sc_consensus_manual_seal::EngineCommand::SealNewBlock {
+									create_empty: true,
+									finalize: true,
+									parent_hash: None,
+									sender: None,
+								})
+								.unwrap();
+						}
+					});
+			},
+			ManualSealMode::Instant => {
+				let mut imported_transactions = transaction_pool.import_notification_stream();
+				task_manager
+					.spawn_essential_handle()
+					.spawn("block_authoring", None, async move {
+						while imported_transactions.next().await.is_some() {
+							if let Err(e) = manual_seal_sink_clone.try_send(
+								sc_consensus_manual_seal::EngineCommand::SealNewBlock {
+									create_empty: false,
+									finalize: true,
+									parent_hash: None,
+									sender: None,
+								},
+							) {
+								log::warn!("Failed to request a new block: {e}");
+							}
+						}
+					});
+			},
+			// Blocks are only requested through the `engine_createBlock` RPC.
+			ManualSealMode::Manual => {},
+		}
 
 		let client_for_cidp = client.clone();
 		let params = sc_consensus_manual_seal::ManualSealParams {
@@ -26,6 +26,17 @@ use sc_service::{Configuration, TaskManager};
 /// `polkadot` version of a stable release.
 pub const NODE_VERSION: &'static str = "1.19.0";
 
+/// How a manual seal node produces blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ManualSealMode {
+	/// Produce a block every given number of milliseconds.
+	Interval(u64),
+	/// Produce a block only when requested through the `engine_createBlock` RPC.
+	Manual,
+	/// Produce a block as soon as a transaction is imported into the transaction pool.
+	Instant,
+}
+
 /// Trait that extends the `DynNodeSpec` trait with manual seal related logic.
 ///
 /// We need it in order to be able to access both the `DynNodeSpec` and the manual seal logic
@@ -34,7 +45,7 @@ pub trait DynNodeSpecExt: DynNodeSpec {
 	fn start_manual_seal_node(
 		&self,
 		config: Configuration,
-		block_time: u64,
+		mode: ManualSealMode,
 	) -> sc_service::error::Result<TaskManager>;
 }
 
@@ -46,14 +57,14 @@ where
 	fn start_manual_seal_node(
 		&self,
 		config: Configuration,
-		block_time: u64,
+		mode: ManualSealMode,
 	) -> sc_service::error::Result<TaskManager> {
 		let node = ManualSealNode::<T>::new();
 		match config.network.network_backend {
 			sc_network::config::NetworkBackendType::Libp2p =>
-				node.start_node::<sc_network::NetworkWorker<_, _>>(config, block_time),
+				node.start_node::<sc_network::NetworkWorker<_, _>>(config, mode),
 			sc_network::config::NetworkBackendType::Litep2p =>
-				node.start_node::<sc_network::Litep2pNetworkBackend>(config, block_time),
+				node.start_node::<sc_network::Litep2pNetworkBackend>(config, mode),
 		}
 	}
 }
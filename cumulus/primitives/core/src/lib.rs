@@ -24,7 +24,7 @@ use alloc::vec::Vec;
 use codec::{Decode, DecodeAll, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use polkadot_parachain_primitives::primitives::HeadData;
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{Perbill, RuntimeDebug};
 
 pub mod parachain_block_data;
 
@@ -149,6 +149,92 @@ pub trait ListChannelInfos {
 	fn outgoing_channels() -> Vec<ParaId>;
 }
 
+/// The remaining capacity of an outgoing message queue.
+#[derive(Clone, Copy, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct QueueCapacity {
+	/// The number of messages that can still be enqueued.
+	pub remaining_count: u32,
+	/// The total size in bytes of the messages that can still be enqueued.
+	pub remaining_size: u32,
+}
+
+/// An advisory level of congestion of an outgoing message queue.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug)]
+pub enum CongestionLevel {
+	/// Less than half of the queue is used.
+	Low,
+	/// At least half of the queue is used.
+	Elevated,
+	/// At least 90% of the queue is used.
+	High,
+	/// No more messages fit into the queue.
+	Full,
+}
+
+impl CongestionLevel {
+	/// The congestion level of a queue holding `count` messages of `size` bytes in total, which
+	/// fits at most `max_count` messages of `max_size` bytes in total.
+	///
+	/// The more used of both dimensions determines the level.
+	pub fn from_usage(count: u32, max_count: u32, size: u32, max_size: u32) -> Self {
+		if count >= max_count || size >= max_size {
+			return Self::Full
+		}
+		let used =
+			Perbill::from_rational(count, max_count).max(Perbill::from_rational(size, max_size));
+		if used >= Perbill::from_percent(90) {
+			Self::High
+		} else if used >= Perbill::from_percent(50) {
+			Self::Elevated
+		} else {
+			Self::Low
+		}
+	}
+}
+
+/// Backpressure of the outgoing message queues of a parachain.
+///
+/// Allows the senders of upward and HRMP messages to learn how much capacity is left, so that
+/// they can defer messages which are not urgent while the queues are congested. The values are
+/// advisory, they do not account for messages that are still buffered in the runtime.
+pub trait ChannelBackpressure {
+	/// The remaining capacity of the relay chain dispatch queue for upward messages.
+	///
+	/// Returns `None` if it is not known.
+	fn upward_capacity() -> Option<QueueCapacity>;
+
+	/// The remaining capacity of the outgoing HRMP channel to `recipient`.
+	///
+	/// Returns `None` if there is no such channel.
+	fn hrmp_capacity(recipient: ParaId) -> Option<QueueCapacity>;
+
+	/// The congestion level of the relay chain dispatch queue for upward messages.
+	fn upward_congestion() -> CongestionLevel;
+
+	/// The congestion level of the outgoing HRMP channel to `recipient`.
+	///
+	/// A channel that does not exist is [`CongestionLevel::Full`].
+	fn hrmp_congestion(recipient: ParaId) -> CongestionLevel;
+}
+
+impl ChannelBackpressure for () {
+	fn upward_capacity() -> Option<QueueCapacity> {
+		None
+	}
+
+	fn hrmp_capacity(_: ParaId) -> Option<QueueCapacity> {
+		None
+	}
+
+	fn upward_congestion() -> CongestionLevel {
+		CongestionLevel::Low
+	}
+
+	fn hrmp_congestion(_: ParaId) -> CongestionLevel {
+		CongestionLevel::Low
+	}
+}
+
 /// Something that should be called when sending an upward message.
 pub trait UpwardMessageSender {
 	/// Send the given UMP message; return the expected number of blocks before the message will
@@ -228,6 +314,22 @@ pub enum CumulusDigestItem {
 		/// The claim queue offset that determines how far "into the future" the core is selected.
 		claim_queue_offset: ClaimQueueOffset,
 	},
+	/// A digest item reporting the resources consumed by a parachain block.
+	#[codec(index = 2)]
+	BlockUtilization(BlockUtilization),
+}
+
+/// The resources consumed by a parachain block, as reported by the runtime.
+#[derive(Clone, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo)]
+pub struct BlockUtilization {
+	/// The weight consumed by `Normal` dispatches.
+	pub normal: sp_runtime::Weight,
+	/// The weight consumed by `Operational` dispatches.
+	pub operational: sp_runtime::Weight,
+	/// The weight consumed by `Mandatory` dispatches.
+	pub mandatory: sp_runtime::Weight,
+	/// The maximum PoV size the relay chain accepts for the block.
+	pub max_pov_size: u32,
 }
 
 impl CumulusDigestItem {
@@ -254,6 +356,25 @@ impl CumulusDigestItem {
 			_ => None,
 		})
 	}
+
+	/// Find [`CumulusDigestItem::BlockUtilization`] in the given `digest`.
+	///
+	/// If there are multiple valid digests, this returns the value of the first one, although
+	/// well-behaving runtimes should not produce headers with more than one.
+	pub fn find_block_utilization(digest: &Digest) -> Option<BlockUtilization> {
+		digest.convert_first(|d| match d {
+			DigestItem::Consensus(id, val) if id == &CUMULUS_CONSENSUS_ID => {
+				let Ok(CumulusDigestItem::BlockUtilization(utilization)) =
+					CumulusDigestItem::decode_all(&mut &val[..])
+				else {
+					return None
+				};
+
+				Some(utilization)
+			},
+			_ => None,
+		})
+	}
 }
 
 /// Extract the relay-parent from the provided header digest. Returns `None` if none were found.
@@ -107,6 +107,8 @@ thread_local! {
 	pub static UPWARD_MESSAGES: RefCell<HashMap<String, VecDeque<(u32, Vec<u8>)>>> = RefCell::new(HashMap::new());
 	/// Bridged messages, each message is: `BridgeMessage`
 	pub static BRIDGED_MESSAGES: RefCell<HashMap<String, VecDeque<BridgeMessage>>> = RefCell::new(HashMap::new());
+	/// Flag indicating if the bridged messages of a certain Network are held back instead of relayed
+	pub static HELD_BRIDGED_MESSAGES: RefCell<HashMap<String, bool>> = RefCell::new(HashMap::new());
 	/// Parachains Ids a the Network
 	pub static PARA_IDS: RefCell<HashMap<String, Vec<u32>>> = RefCell::new(HashMap::new());
 	/// Flag indicating if global variables have been initialized for a certain Network
@@ -232,7 +234,38 @@ pub trait Network {
 	}
 
 	fn send_bridged_messages(msg: BridgeMessage) {
-		BRIDGED_MESSAGES.with(|b| b.borrow_mut().get_mut(Self::name()).unwrap().push_back(msg));
+		BRIDGED_MESSAGES.with(|b| {
+			let mut b = b.borrow_mut();
+			let messages = b.get_mut(Self::name()).unwrap();
+			// Messages are collected from the source until their delivery is confirmed, so a
+			// message which is held back is collected again with every block of the source.
+			if !messages.iter().any(|m| m.lane_id == msg.lane_id && m.nonce == msg.nonce) {
+				messages.push_back(msg);
+			}
+		});
+	}
+
+	/// Hold back the bridged messages of the network until [`Network::release_bridged_messages`]
+	/// is called, e.g. to inspect both sides of the bridge while the messages are in flight.
+	fn hold_bridged_messages() {
+		HELD_BRIDGED_MESSAGES.with(|b| b.borrow_mut().insert(Self::name().to_string(), true));
+	}
+
+	/// Relay the bridged messages held back by [`Network::hold_bridged_messages`], and any
+	/// messages that are sent after them, to the target network.
+	fn release_bridged_messages() {
+		HELD_BRIDGED_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
+		Self::process_messages();
+	}
+
+	/// Whether the bridged messages of the network are held back.
+	fn are_bridged_messages_held() -> bool {
+		HELD_BRIDGED_MESSAGES.with(|b| b.borrow().get(Self::name()).is_some())
+	}
+
+	/// The number of bridged messages waiting to be relayed to the target network.
+	fn pending_bridged_messages() -> usize {
+		BRIDGED_MESSAGES.with(|b| b.borrow().get(Self::name()).map_or(0, |messages| messages.len()))
 	}
 }
 
@@ -1014,6 +1047,7 @@ macro_rules! decl_test_networks {
 					$crate::UPWARD_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
 					$crate::HORIZONTAL_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
 					$crate::BRIDGED_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
+					$crate::HELD_BRIDGED_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
 					$crate::LAST_HEAD.with(|b| b.borrow_mut().remove(Self::name()));
 
 					<$relay_chain<Self>>::reset_ext();
@@ -1068,7 +1102,7 @@ macro_rules! decl_test_networks {
 					$crate::DOWNWARD_MESSAGES.with(|b| !b.borrow_mut().get_mut(Self::name()).unwrap().is_empty())
 					|| $crate::HORIZONTAL_MESSAGES.with(|b| !b.borrow_mut().get_mut(Self::name()).unwrap().is_empty())
 					|| $crate::UPWARD_MESSAGES.with(|b| !b.borrow_mut().get_mut(Self::name()).unwrap().is_empty())
-					|| $crate::BRIDGED_MESSAGES.with(|b| !b.borrow_mut().get_mut(Self::name()).unwrap().is_empty())
+					|| (!Self::are_bridged_messages_held() && $crate::BRIDGED_MESSAGES.with(|b| !b.borrow_mut().get_mut(Self::name()).unwrap().is_empty()))
 				}
 
 				fn process_downward_messages() {
@@ -1159,6 +1193,9 @@ macro_rules! decl_test_networks {
 
 				fn process_bridged_messages() {
 					use $crate::{Bridge, BridgeMessageHandler, TestExt};
+					if Self::are_bridged_messages_held() {
+						return;
+					}
 					// Make sure both, including the target `Network` are initialized
 					<Self::Bridge as Bridge>::init();
 
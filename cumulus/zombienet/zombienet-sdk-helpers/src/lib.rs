@@ -100,7 +100,7 @@ pub async fn assert_para_throughput(
 		let receipts = find_event_and_decode_fields::<CandidateReceiptV2<H256>>(
 			&events,
 			"ParaInclusion",
-			"CandidateBacked",
+			"CandidateBackedV2",
 		)?;
 
 		for receipt in receipts {
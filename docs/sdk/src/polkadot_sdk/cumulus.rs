@@ -113,6 +113,10 @@ mod tests {
 				type MaxAuthorities = ConstU32<100_000>;
 				type AllowMultipleBlocksPerSlot = ConstBool<false>;
 				type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Self>;
+				type EquivocationReportSystem = ();
+				type WeightInfo = ();
+				#[cfg(feature = "runtime-benchmarks")]
+				type BenchmarkHelper = ();
 			}
 
 			#[docify::export(timestamp)]
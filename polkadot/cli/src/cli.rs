@@ -159,6 +159,23 @@ pub struct RunCmd {
 	/// networks.
 	#[arg(long)]
 	pub keep_finalized_for: Option<u32>,
+
+	/// The order in which the availability recovery strategies are tried by validators.
+	///
+	/// Fetching the full data from backers needs the least CPU. Fetching systematic chunks
+	/// spreads the requests over more validators. Regular chunks are always the last resort. If
+	/// not specified, backers are tried first.
+	#[arg(long, value_enum)]
+	pub availability_recovery_strategy: Option<AvailabilityRecoveryStrategy>,
+}
+
+/// The availability recovery strategy to try first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AvailabilityRecoveryStrategy {
+	/// Fetch the full data from the backing group first, then systematic chunks.
+	BackersFirst,
+	/// Fetch systematic chunks first, then the full data from the backing group.
+	SystematicChunksFirst,
 }
 
 #[allow(missing_docs)]
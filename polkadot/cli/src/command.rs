@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::cli::{Cli, Subcommand, NODE_VERSION};
+use crate::cli::{AvailabilityRecoveryStrategy, Cli, Subcommand, NODE_VERSION};
 use frame_benchmarking_cli::{
 	BenchmarkCmd, ExtrinsicFactory, SubstrateRemarkBuilder, SUBSTRATE_REFERENCE_HARDWARE,
 };
@@ -23,6 +23,7 @@ use log::info;
 use polkadot_service::{
 	self,
 	benchmarking::{benchmark_inherent_data, TransferKeepAliveBuilder},
+	overseer::RecoveryStrategyPreference,
 	HeaderBackend, IdentifyVariant,
 };
 #[cfg(feature = "pyroscope")]
@@ -235,6 +236,14 @@ where
 				prepare_workers_hard_max_num: cli.run.prepare_workers_hard_max_num,
 				prepare_workers_soft_max_num: cli.run.prepare_workers_soft_max_num,
 				keep_finalized_for: cli.run.keep_finalized_for,
+				recovery_strategy_preference: cli.run.availability_recovery_strategy.map(
+					|strategy| match strategy {
+						AvailabilityRecoveryStrategy::BackersFirst =>
+							RecoveryStrategyPreference::BackersFirst,
+						AvailabilityRecoveryStrategy::SystematicChunksFirst =>
+							RecoveryStrategyPreference::SystematicChunksFirst,
+					},
+				),
 			},
 		)
 		.map(|full| full.task_manager)?;
@@ -354,6 +354,14 @@ async fn run_main_loop<Context>(
 								)
 							).await;
 						},
+						ApprovalVotingParallelMessage::GetBlockApprovalStatus(_, _) => {
+							to_approval_voting_worker.send_message(
+								msg.try_into().expect(
+									"Message is GetBlockApprovalStatus and that can be safely converted
+									 to ApprovalVotingMessage; qed"
+								)
+							).await;
+						},
 						// Now the message the approval distribution subsystem would've handled and need to
 						// be forwarded to the workers.
 						ApprovalVotingParallelMessage::NewBlocks(msg) => {
@@ -74,6 +74,18 @@ pub enum RequiredTranches {
 	},
 }
 
+impl RequiredTranches {
+	/// The highest delay tranche needed to approve, or considered so far if more assignments are
+	/// needed. `None` if all validators are needed.
+	pub fn depth(&self) -> Option<DelayTranche> {
+		match self {
+			RequiredTranches::All => None,
+			RequiredTranches::Pending { considered, .. } => Some(*considered),
+			RequiredTranches::Exact { needed, .. } => Some(*needed),
+		}
+	}
+}
+
 /// The result of a check.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Check {
@@ -37,7 +37,8 @@ use polkadot_node_subsystem::{
 	messages::{
 		ApprovalCheckError, ApprovalCheckResult, ApprovalDistributionMessage,
 		ApprovalVotingMessage, AssignmentCheckError, AssignmentCheckResult,
-		AvailabilityRecoveryMessage, BlockDescription, CandidateValidationMessage, ChainApiMessage,
+		AvailabilityRecoveryMessage, BlockApprovalStatus, BlockDescription,
+		CandidateApprovalStatus, CandidateValidationMessage, ChainApiMessage,
 		ChainSelectionMessage, CheckedIndirectAssignment, CheckedIndirectSignedApprovalVote,
 		DisputeCoordinatorMessage, HighestApprovedAncestorBlock, PvfExecKind, RuntimeApiMessage,
 		RuntimeApiRequest,
@@ -55,8 +56,8 @@ use polkadot_node_subsystem_util::{
 use polkadot_primitives::{
 	vstaging::CandidateReceiptV2 as CandidateReceipt, ApprovalVoteMultipleCandidates,
 	ApprovalVotingParams, BlockNumber, CandidateHash, CandidateIndex, CoreIndex, ExecutorParams,
-	GroupIndex, Hash, SessionIndex, SessionInfo, ValidatorId, ValidatorIndex, ValidatorPair,
-	ValidatorSignature,
+	GroupIndex, Hash, Id as ParaId, SessionIndex, SessionInfo, ValidatorId, ValidatorIndex,
+	ValidatorPair, ValidatorSignature,
 };
 use sc_keystore::LocalKeystore;
 use sp_application_crypto::Pair;
@@ -213,6 +214,10 @@ struct MetricsInner {
 	// - Stage 1: We wait for enough tranches to cover all no-shows in stage 0.
 	// - Stage 2: We wait for enough tranches to cover all no-shows  of stage 1.
 	assignments_gathering_time_by_stage: prometheus::HistogramVec,
+	// The following metrics are split by para, to find out which paras hold up approvals.
+	no_shows_by_para_total: prometheus::CounterVec<prometheus::U64>,
+	candidate_approval_time_ticks_by_para: prometheus::HistogramVec,
+	approval_tranche_depth_by_para: prometheus::HistogramVec,
 }
 
 /// Approval Voting metrics.
@@ -278,9 +283,13 @@ impl Metrics {
 		}
 	}
 
-	fn on_no_shows(&self, n: usize) {
+	fn on_no_shows(&self, para_id: ParaId, n: usize) {
 		if let Some(metrics) = &self.0 {
 			metrics.no_shows_total.inc_by(n as u64);
+			metrics
+				.no_shows_by_para_total
+				.with_label_values(&[&para_id.to_string()])
+				.inc_by(n as u64);
 		}
 	}
 
@@ -302,9 +311,25 @@ impl Metrics {
 		}
 	}
 
-	fn on_candidate_approved(&self, ticks: Tick) {
+	fn on_candidate_approved(
+		&self,
+		para_id: ParaId,
+		ticks: Tick,
+		tranche_depth: Option<DelayTranche>,
+	) {
 		if let Some(metrics) = &self.0 {
+			let para_id = para_id.to_string();
 			metrics.candidate_approval_time_ticks.observe(ticks as f64);
+			metrics
+				.candidate_approval_time_ticks_by_para
+				.with_label_values(&[&para_id])
+				.observe(ticks as f64);
+			if let Some(tranche_depth) = tranche_depth {
+				metrics
+					.approval_tranche_depth_by_para
+					.with_label_values(&[&para_id])
+					.observe(tranche_depth as f64);
+			}
 		}
 	}
 
@@ -487,6 +512,38 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			no_shows_by_para_total: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_approvals_no_shows_by_para_total",
+						"Number of assignments which became no-shows, by para",
+					),
+					&["para_id"],
+				)?,
+				registry,
+			)?,
+			candidate_approval_time_ticks_by_para: prometheus::register(
+				prometheus::HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_approvals_candidate_approval_time_ticks_by_para",
+						"Number of ticks (500ms) to approve candidates, by para.",
+					)
+					.buckets(vec![6.0, 12.0, 18.0, 24.0, 30.0, 36.0, 72.0, 100.0, 144.0]),
+					&["para_id"],
+				)?,
+				registry,
+			)?,
+			approval_tranche_depth_by_para: prometheus::register(
+				prometheus::HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_approvals_tranche_depth_by_para",
+						"The highest delay tranche needed to approve candidates, by para.",
+					)
+					.buckets(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 10.0, 15.0, 25.0, 40.0, 70.0]),
+					&["para_id"],
+				)?,
+				registry,
+			)?,
 		};
 
 		Ok(Metrics(Some(metrics)))
@@ -2163,12 +2220,78 @@ async fn handle_from_overseer<
 				.await?;
 				Vec::new()
 			},
+			ApprovalVotingMessage::GetBlockApprovalStatus(block_hash, tx) => {
+				let status =
+					get_block_approval_status(sender, state, db, session_info_provider, block_hash)
+						.await?;
+				let _ = tx.send(status);
+				Vec::new()
+			},
 		},
 	};
 
 	Ok(actions)
 }
 
+/// Retrieve the approval status of all the candidates included in a block.
+async fn get_block_approval_status<Sender: SubsystemSender<RuntimeApiMessage>>(
+	sender: &mut Sender,
+	state: &State,
+	db: &OverlayedBackend<'_, impl Backend>,
+	session_info_provider: &mut RuntimeInfo,
+	block_hash: Hash,
+) -> SubsystemResult<Option<BlockApprovalStatus>> {
+	let Some(block_entry) = db.load_block_entry(&block_hash)? else { return Ok(None) };
+
+	let mut candidates = Vec::with_capacity(block_entry.candidates().len());
+	for (_, candidate_hash) in block_entry.candidates() {
+		let Some(candidate_entry) = db.load_candidate_entry(candidate_hash)? else {
+			gum::warn!(
+				target: LOG_TARGET,
+				?candidate_hash,
+				?block_hash,
+				"Missing expected candidate in DB",
+			);
+			continue
+		};
+		let Some((approval_entry, status)) = state
+			.approval_status(sender, session_info_provider, &block_entry, &candidate_entry)
+			.await
+		else {
+			continue
+		};
+
+		// Only count the approvals of the validators assigned under this block.
+		let approvals = candidate_entry
+			.approvals()
+			.iter()
+			.by_vals()
+			.enumerate()
+			.filter(|(i, approved)| {
+				*approved && approval_entry.is_assigned(ValidatorIndex(*i as _))
+			})
+			.count();
+
+		candidates.push(CandidateApprovalStatus {
+			candidate_hash: *candidate_hash,
+			para_id: candidate_entry.candidate_receipt().descriptor().para_id(),
+			approved: block_entry.is_candidate_approved(candidate_hash),
+			assignments: approval_entry.n_assignments() as u32,
+			approvals: approvals as u32,
+			no_shows: status.last_no_shows as u32,
+			tranche_depth: status.required_tranches.depth(),
+		});
+	}
+
+	let block_tick = slot_number_to_tick(state.slot_duration_millis, block_entry.slot());
+	Ok(Some(BlockApprovalStatus {
+		block_number: block_entry.block_number(),
+		approved: block_entry.is_fully_approved(),
+		ticks_since_slot_start: state.clock.tick_now().saturating_sub(block_tick),
+		candidates,
+	}))
+}
+
 /// Retrieve approval signatures.
 ///
 /// This involves an unbounded message send to approval-distribution, the caller has to ensure that
@@ -3114,7 +3237,7 @@ where
 			let is_block_approved = block_entry.is_fully_approved();
 
 			if no_shows != 0 {
-				metrics.on_no_shows(no_shows);
+				metrics.on_no_shows(para_id, no_shows);
 			}
 			if check == Check::ApprovedOneThird {
 				// No-shows are not counted when more than one third of validators approve a
@@ -3123,7 +3246,11 @@ where
 				metrics.on_approved_by_one_third()
 			}
 
-			metrics.on_candidate_approved(status.tranche_now as _);
+			metrics.on_candidate_approved(
+				para_id,
+				status.tranche_now as _,
+				status.required_tranches.depth(),
+			);
 
 			if is_block_approved && !was_block_approved {
 				metrics.on_block_approved(status.tranche_now as _);
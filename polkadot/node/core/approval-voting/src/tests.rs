@@ -1632,6 +1632,98 @@ fn subsystem_accepts_and_imports_approval_after_assignment() {
 	});
 }
 
+#[test]
+fn subsystem_reports_block_approval_status() {
+	test_harness(HarnessConfig::default(), |test_harness| async move {
+		let TestHarness { mut virtual_overseer, sync_oracle_handle: _sync_oracle_handle, .. } =
+			test_harness;
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::ChainApi(ChainApiMessage::FinalizedBlockNumber(rx)) => {
+				rx.send(Ok(0)).unwrap();
+			}
+		);
+
+		let block_hash = Hash::repeat_byte(0x01);
+
+		let candidate_hash = {
+			let mut candidate_receipt =
+				dummy_candidate_receipt_v2_bad_sig(block_hash, Some(Default::default()));
+			candidate_receipt.descriptor.set_para_id(ParaId::from(0_u32));
+			candidate_receipt.descriptor.set_relay_parent(block_hash);
+			candidate_receipt.hash()
+		};
+
+		let candidate_index = 0;
+		let validator = ValidatorIndex(0);
+		let session_index = 1;
+
+		ChainBuilder::new()
+			.add_block(
+				block_hash,
+				ChainBuilder::GENESIS_HASH,
+				1,
+				BlockConfig {
+					slot: Slot::from(1),
+					candidates: None,
+					session_info: None,
+					end_syncing: false,
+				},
+			)
+			.build(&mut virtual_overseer)
+			.await;
+
+		// Unknown blocks aren't reported.
+		let (tx, rx) = oneshot::channel();
+		overseer_send(
+			&mut virtual_overseer,
+			FromOrchestra::Communication {
+				msg: ApprovalVotingMessage::GetBlockApprovalStatus(Hash::repeat_byte(0x02), tx),
+			},
+		)
+		.await;
+		assert_eq!(rx.await, Ok(None));
+
+		let rx =
+			import_assignment(&mut virtual_overseer, block_hash, candidate_index, validator, 0)
+				.await;
+		assert_eq!(rx.await, Ok(AssignmentCheckResult::Accepted));
+
+		let rx = import_approval(
+			&mut virtual_overseer,
+			block_hash,
+			candidate_index,
+			validator,
+			candidate_hash,
+			session_index,
+			true,
+			None,
+		)
+		.await;
+		assert_eq!(rx.await, Ok(ApprovalCheckResult::Accepted));
+
+		let (tx, rx) = oneshot::channel();
+		overseer_send(
+			&mut virtual_overseer,
+			FromOrchestra::Communication {
+				msg: ApprovalVotingMessage::GetBlockApprovalStatus(block_hash, tx),
+			},
+		)
+		.await;
+		let status = rx.await.unwrap().expect("block is tracked by approval-voting");
+		assert_eq!(status.block_number, 1);
+		assert_eq!(status.candidates.len(), 1);
+
+		let candidate = &status.candidates[0];
+		assert_eq!(candidate.candidate_hash, candidate_hash);
+		assert_eq!(candidate.para_id, ParaId::from(0_u32));
+		assert_eq!(candidate.assignments, 1);
+		assert_eq!(candidate.approvals, 1);
+
+		virtual_overseer
+	});
+}
+
 #[test]
 fn subsystem_second_approval_import_only_schedules_wakeups() {
 	test_harness(HarnessConfig::default(), |test_harness| async move {
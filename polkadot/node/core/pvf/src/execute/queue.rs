@@ -225,6 +225,16 @@ impl Queue {
 			}
 
 			purge_dead(&self.metrics, &mut self.workers).await;
+			self.observe_queue_depth();
+		}
+	}
+
+	fn observe_queue_depth(&self) {
+		for priority in Priority::iter() {
+			self.metrics.observe_execute_queue_depth(
+				priority.as_str(),
+				self.unscheduled.unscheduled.get(&priority).map_or(0, |queue| queue.len()),
+			);
 		}
 	}
 
@@ -696,10 +706,11 @@ fn assign(queue: &mut Queue, worker: Worker, job: ExecuteJob) {
 			thus claim_idle cannot return None;
 			qed.",
 	);
-	queue
-		.metrics
-		.observe_execution_queued_time(job.waiting_since.elapsed().as_millis() as u32);
-	let execution_timer = queue.metrics.time_execution();
+	queue.metrics.observe_execution_queued_time(
+		job.exec_kind,
+		job.waiting_since.elapsed().as_millis() as u32,
+	);
+	let execution_timer = queue.metrics.time_execution(job.exec_kind);
 	queue.mux.push(
 		async move {
 			let _timer = execution_timer;
@@ -756,6 +767,17 @@ enum Priority {
 	Backing,
 }
 
+impl Priority {
+	fn as_str(&self) -> &'static str {
+		match *self {
+			Priority::Dispute => "dispute",
+			Priority::Approval => "approval",
+			Priority::BackingSystemParas => "backing_system_paras",
+			Priority::Backing => "backing",
+		}
+	}
+}
+
 impl From<PvfExecKind> for Priority {
 	fn from(kind: PvfExecKind) -> Self {
 		match kind {
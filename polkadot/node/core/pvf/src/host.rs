@@ -23,7 +23,7 @@
 use crate::{
 	artifacts::{ArtifactId, ArtifactPathId, ArtifactState, Artifacts, ArtifactsCleanupConfig},
 	execute::{self, PendingExecutionRequest},
-	metrics::Metrics,
+	metrics::{ArtifactCacheLookup, Metrics},
 	prepare, Priority, SecurityStatus, ValidationError, LOG_TARGET,
 };
 use always_assert::never;
@@ -334,7 +334,7 @@ pub async fn start(
 	);
 
 	let (to_execute_queue_tx, from_execute_queue_rx, run_execute_queue) = execute::start(
-		metrics,
+		metrics.clone(),
 		config.execute_worker_program_path.to_owned(),
 		config.cache_path.clone(),
 		config.execute_workers_max_num,
@@ -358,6 +358,7 @@ pub async fn start(
 			from_execute_queue_rx,
 			to_sweeper_tx,
 			awaiting_prepare: AwaitingPrepare::default(),
+			metrics,
 		})
 		.await
 	};
@@ -407,6 +408,8 @@ struct Inner {
 	to_sweeper_tx: mpsc::Sender<PathBuf>,
 
 	awaiting_prepare: AwaitingPrepare,
+
+	metrics: Metrics,
 }
 
 #[derive(Debug)]
@@ -424,6 +427,7 @@ async fn run(
 		mut to_execute_queue_tx,
 		mut to_sweeper_tx,
 		mut awaiting_prepare,
+		metrics,
 	}: Inner,
 ) {
 	macro_rules! break_if_fatal {
@@ -491,6 +495,7 @@ async fn run(
 					&mut to_prepare_queue_tx,
 					&mut to_execute_queue_tx,
 					&mut awaiting_prepare,
+					&metrics,
 					to_host,
 				)
 				.await);
@@ -523,6 +528,7 @@ async fn handle_to_host(
 	prepare_queue: &mut mpsc::Sender<prepare::ToQueue>,
 	execute_queue: &mut mpsc::Sender<execute::ToQueue>,
 	awaiting_prepare: &mut AwaitingPrepare,
+	metrics: &Metrics,
 	to_host: ToHost,
 ) -> Result<(), Fatal> {
 	match to_host {
@@ -530,8 +536,15 @@ async fn handle_to_host(
 			handle_precheck_pvf(artifacts, prepare_queue, pvf, result_tx).await?;
 		},
 		ToHost::ExecutePvf(inputs) => {
-			handle_execute_pvf(artifacts, prepare_queue, execute_queue, awaiting_prepare, inputs)
-				.await?;
+			handle_execute_pvf(
+				artifacts,
+				prepare_queue,
+				execute_queue,
+				awaiting_prepare,
+				metrics,
+				inputs,
+			)
+			.await?;
 		},
 		ToHost::HeadsUp { active_pvfs } =>
 			handle_heads_up(artifacts, prepare_queue, active_pvfs).await?,
@@ -599,6 +612,7 @@ async fn handle_execute_pvf(
 	prepare_queue: &mut mpsc::Sender<prepare::ToQueue>,
 	execute_queue: &mut mpsc::Sender<execute::ToQueue>,
 	awaiting_prepare: &mut AwaitingPrepare,
+	metrics: &Metrics,
 	inputs: ExecutePvfInputs,
 ) -> Result<(), Fatal> {
 	let ExecutePvfInputs { pvf, exec_timeout, pvd, pov, priority, exec_kind, result_tx } = inputs;
@@ -612,6 +626,7 @@ async fn handle_execute_pvf(
 
 				if file_metadata.is_ok() {
 					*last_time_needed = SystemTime::now();
+					metrics.on_artifact_cache_lookup(ArtifactCacheLookup::Hit);
 
 					// This artifact has already been prepared, send it to the execute queue.
 					send_execute(
@@ -639,6 +654,7 @@ async fn handle_execute_pvf(
 
 					// The artifact has been prepared previously but the file is missing, prepare it
 					// again.
+					metrics.on_artifact_cache_lookup(ArtifactCacheLookup::Miss);
 					*state = ArtifactState::Preparing {
 						waiting_for_response: Vec::new(),
 						num_failures: 0,
@@ -662,6 +678,7 @@ async fn handle_execute_pvf(
 				}
 			},
 			ArtifactState::Preparing { .. } => {
+				metrics.on_artifact_cache_lookup(ArtifactCacheLookup::Preparing);
 				awaiting_prepare.add(
 					artifact_id,
 					PendingExecutionRequest {
@@ -675,6 +692,7 @@ async fn handle_execute_pvf(
 				);
 			},
 			ArtifactState::FailedToProcess { last_time_failed, num_failures, error } => {
+				metrics.on_artifact_cache_lookup(ArtifactCacheLookup::Failed);
 				if can_retry_prepare_after_failure(*last_time_failed, *num_failures, error) {
 					gum::warn!(
 						target: LOG_TARGET,
@@ -716,6 +734,7 @@ async fn handle_execute_pvf(
 	} else {
 		// Artifact is unknown: register it and enqueue a job with the corresponding priority and
 		// PVF.
+		metrics.on_artifact_cache_lookup(ArtifactCacheLookup::Miss);
 		artifacts.insert_preparing(artifact_id.clone(), Vec::new());
 		enqueue_prepare_for_execute(
 			prepare_queue,
@@ -1128,6 +1147,7 @@ pub(crate) mod tests {
 				from_execute_queue_rx,
 				to_sweeper_tx,
 				awaiting_prepare: AwaitingPrepare::default(),
+				metrics: Metrics::default(),
 			})
 			.boxed();
 
@@ -71,14 +71,57 @@ impl Metrics {
 	}
 
 	/// Time between sending execution request to a worker to having the response.
-	pub(crate) fn time_execution(&self) -> Option<metrics::prometheus::prometheus::HistogramTimer> {
-		self.0.as_ref().map(|metrics| metrics.execution_time.start_timer())
+	///
+	/// The time is reported both in total and for the priority of the given execution `kind`.
+	pub(crate) fn time_execution(
+		&self,
+		kind: PvfExecKind,
+	) -> Option<(
+		metrics::prometheus::prometheus::HistogramTimer,
+		metrics::prometheus::prometheus::HistogramTimer,
+	)> {
+		self.0.as_ref().map(|metrics| {
+			(
+				metrics.execution_time.start_timer(),
+				metrics
+					.execution_time_by_priority
+					.with_label_values(&[kind.as_str()])
+					.start_timer(),
+			)
+		})
 	}
 
-	pub(crate) fn observe_execution_queued_time(&self, queued_for_millis: u32) {
-		self.0.as_ref().map(|metrics| {
-			metrics.execution_queued_time.observe(queued_for_millis as f64 / 1000 as f64)
-		});
+	/// Observe the time an execution job of the given `kind` spent waiting in the queue.
+	pub(crate) fn observe_execution_queued_time(&self, kind: PvfExecKind, queued_for_millis: u32) {
+		if let Some(metrics) = &self.0 {
+			let queued_for_secs = queued_for_millis as f64 / 1000 as f64;
+			metrics.execution_queued_time.observe(queued_for_secs);
+			metrics
+				.execution_queued_time_by_priority
+				.with_label_values(&[kind.as_str()])
+				.observe(queued_for_secs);
+		}
+	}
+
+	/// Observe the number of jobs of the given `priority` waiting in the execution queue.
+	pub(crate) fn observe_execute_queue_depth(&self, priority: &str, depth: usize) {
+		if let Some(metrics) = &self.0 {
+			metrics.execute_queue_depth.with_label_values(&[priority]).set(depth as u64);
+		}
+	}
+
+	/// Observe the number of jobs waiting for a worker in the preparation queue.
+	pub(crate) fn observe_prepare_queue_depth(&self, depth: usize) {
+		if let Some(metrics) = &self.0 {
+			metrics.prepare_queue_depth.set(depth as u64);
+		}
+	}
+
+	/// When the host looked up an artifact to execute a PVF.
+	pub(crate) fn on_artifact_cache_lookup(&self, lookup: ArtifactCacheLookup) {
+		if let Some(metrics) = &self.0 {
+			metrics.artifact_cache_lookups.with_label_values(&[lookup.as_label()]).inc();
+		}
 	}
 
 	/// Observe memory stats for preparation.
@@ -142,6 +185,11 @@ struct MetricsInner {
 	preparation_time: prometheus::Histogram,
 	execution_time: prometheus::Histogram,
 	execution_queued_time: prometheus::Histogram,
+	execution_time_by_priority: prometheus::HistogramVec,
+	execution_queued_time_by_priority: prometheus::HistogramVec,
+	execute_queue_depth: prometheus::GaugeVec<prometheus::U64>,
+	prepare_queue_depth: prometheus::Gauge<prometheus::U64>,
+	artifact_cache_lookups: prometheus::CounterVec<prometheus::U64>,
 	#[cfg(target_os = "linux")]
 	preparation_max_rss: prometheus::Histogram,
 	// Max. allocated memory, tracked by Jemallocator, polling-based
@@ -298,6 +346,85 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			execution_time_by_priority: prometheus::register(
+				prometheus::HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_pvf_execution_time_by_priority",
+						"Time spent in executing PVFs, per execution priority",
+					).buckets(vec![
+						0.01,
+						0.025,
+						0.05,
+						0.1,
+						0.25,
+						0.5,
+						1.0,
+						2.0,
+						3.0,
+						4.0,
+						5.0,
+						6.0,
+						8.0,
+						10.0,
+						12.0,
+					]),
+					&["priority"],
+				)?,
+				registry,
+			)?,
+			execution_queued_time_by_priority: prometheus::register(
+				prometheus::HistogramVec::new(
+					prometheus::HistogramOpts::new(
+						"polkadot_pvf_execution_queued_time_by_priority",
+						"Time spent in queue waiting for PVFs execution job to be assigned, per execution priority",
+					).buckets(vec![
+						0.01,
+						0.025,
+						0.05,
+						0.1,
+						0.25,
+						0.5,
+						1.0,
+						2.0,
+						3.0,
+						4.0,
+						5.0,
+						6.0,
+						12.0,
+						24.0,
+						48.0,
+					]),
+					&["priority"],
+				)?,
+				registry,
+			)?,
+			execute_queue_depth: prometheus::register(
+				prometheus::GaugeVec::new(
+					prometheus::Opts::new(
+						"polkadot_pvf_execute_queue_depth",
+						"The number of jobs waiting in the execution queue, per execution priority",
+					),
+					&["priority"],
+				)?,
+				registry,
+			)?,
+			prepare_queue_depth: prometheus::register(
+				prometheus::Gauge::new(
+					"polkadot_pvf_prepare_queue_depth",
+					"The number of jobs waiting for a worker in the preparation queue",
+				)?,
+				registry,
+			)?,
+			artifact_cache_lookups: prometheus::register(
+				prometheus::CounterVec::new(
+					prometheus::Opts::new(
+						"polkadot_pvf_artifact_cache_lookups",
+						"The total number of artifact lookups for PVF executions, by outcome",
+					),
+					&["outcome"],
+				)?,
+				registry,
+			)?,
 			#[cfg(target_os = "linux")]
 			preparation_max_rss: prometheus::register(
 				prometheus::Histogram::with_opts(
@@ -393,6 +520,29 @@ impl metrics::Metrics for Metrics {
 	}
 }
 
+/// The outcome of looking up the artifact of a PVF to execute.
+pub(crate) enum ArtifactCacheLookup {
+	/// The artifact was prepared and is available on disk.
+	Hit,
+	/// The artifact is unknown or its file is missing and must be prepared.
+	Miss,
+	/// The artifact is already being prepared.
+	Preparing,
+	/// The preparation of the artifact failed before.
+	Failed,
+}
+
+impl ArtifactCacheLookup {
+	fn as_label(&self) -> &'static str {
+		match *self {
+			ArtifactCacheLookup::Hit => "hit",
+			ArtifactCacheLookup::Miss => "miss",
+			ArtifactCacheLookup::Preparing => "preparing",
+			ArtifactCacheLookup::Failed => "failed",
+		}
+	}
+}
+
 enum WorkerFlavor {
 	Prepare,
 	Execute,
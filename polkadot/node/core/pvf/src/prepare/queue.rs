@@ -125,6 +125,10 @@ impl Unscheduled {
 		self.normal.is_empty() && self.critical.is_empty()
 	}
 
+	fn len(&self) -> usize {
+		self.normal.len() + self.critical.len()
+	}
+
 	fn next(&mut self) -> Option<Job> {
 		let mut check = |prio: Priority| self.queue_mut(prio).pop_front();
 		check(Priority::Critical).or_else(|| check(Priority::Normal))
@@ -204,6 +208,8 @@ impl Queue {
 				from_pool = self.from_pool_rx.select_next_some() =>
 					break_if_fatal!(handle_from_pool(&mut self, from_pool).await),
 			}
+
+			self.metrics.observe_prepare_queue_depth(self.unscheduled.len());
 		}
 	}
 }
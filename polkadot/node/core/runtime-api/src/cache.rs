@@ -22,7 +22,7 @@ use sp_consensus_babe::Epoch;
 use polkadot_primitives::{
 	async_backing, slashing,
 	vstaging::{
-		self, async_backing::Constraints, CandidateEvent,
+		self, async_backing::Constraints, BackingGroups, CandidateEvent,
 		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState, ScrapedOnChainVotes,
 	},
 	ApprovalVotingParams, AuthorityDiscoveryId, BlockNumber, CandidateCommitments, CandidateHash,
@@ -79,6 +79,7 @@ pub(crate) struct RequestResultCache {
 	scheduling_lookahead: LruMap<SessionIndex, u32>,
 	validation_code_bomb_limits: LruMap<SessionIndex, u32>,
 	para_ids: LruMap<SessionIndex, Vec<ParaId>>,
+	backing_groups: LruMap<Hash, BackingGroups>,
 }
 
 impl Default for RequestResultCache {
@@ -120,6 +121,7 @@ impl Default for RequestResultCache {
 			scheduling_lookahead: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			validation_code_bomb_limits: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 			para_ids: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
+			backing_groups: LruMap::new(ByLength::new(DEFAULT_CACHE_CAP)),
 		}
 	}
 }
@@ -612,6 +614,14 @@ impl RequestResultCache {
 	pub(crate) fn cache_para_ids(&mut self, session_index: SessionIndex, value: Vec<ParaId>) {
 		self.para_ids.insert(session_index, value);
 	}
+
+	pub(crate) fn backing_groups(&mut self, relay_parent: &Hash) -> Option<&BackingGroups> {
+		self.backing_groups.get(relay_parent).map(|v| &*v)
+	}
+
+	pub(crate) fn cache_backing_groups(&mut self, relay_parent: Hash, value: BackingGroups) {
+		self.backing_groups.insert(relay_parent, value);
+	}
 }
 
 pub(crate) enum RequestResult {
@@ -666,4 +676,5 @@ pub(crate) enum RequestResult {
 	SchedulingLookahead(SessionIndex, u32),
 	ValidationCodeBombLimit(SessionIndex, u32),
 	ParaIds(SessionIndex, Vec<ParaId>),
+	BackingGroups(Hash, BackingGroups),
 }
@@ -194,6 +194,8 @@ where
 			ParaIds(session_index, para_ids) => {
 				self.requests_cache.cache_para_ids(session_index, para_ids);
 			},
+			BackingGroups(relay_parent, backing_groups) =>
+				self.requests_cache.cache_backing_groups(relay_parent, backing_groups),
 		}
 	}
 
@@ -380,6 +382,8 @@ where
 					Some(Request::ParaIds(index, sender))
 				}
 			},
+			Request::BackingGroups(sender) =>
+				query!(backing_groups(), sender).map(|sender| Request::BackingGroups(sender)),
 		}
 	}
 
@@ -721,5 +725,11 @@ where
 			sender,
 			result = (index)
 		),
+		Request::BackingGroups(sender) => query!(
+			BackingGroups,
+			backing_groups(),
+			ver = Request::BACKING_GROUPS_RUNTIME_REQUIREMENT,
+			sender
+		),
 	}
 }
@@ -22,7 +22,7 @@ use polkadot_node_subsystem_test_helpers::make_subsystem_context;
 use polkadot_primitives::{
 	async_backing, slashing, vstaging,
 	vstaging::{
-		async_backing::Constraints, CandidateEvent,
+		async_backing::Constraints, BackingGroups, CandidateEvent,
 		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState, ScrapedOnChainVotes,
 	},
 	ApprovalVotingParams, AuthorityDiscoveryId, BlockNumber, CandidateCommitments, CandidateHash,
@@ -327,6 +327,10 @@ impl RuntimeApiSubsystemClient for MockSubsystemClient {
 	async fn para_ids(&self, _: Hash) -> Result<Vec<ParaId>, ApiError> {
 		todo!("Not required for tests")
 	}
+
+	async fn backing_groups(&self, _: Hash) -> Result<BackingGroups, ApiError> {
+		todo!("Not required for tests")
+	}
 }
 
 #[test]
@@ -119,10 +119,31 @@ pub enum RecoveryStrategyKind {
 	SystematicChunks,
 }
 
+/// The order in which the recovery strategies are tried, when the [`RecoveryStrategyKind`] allows
+/// both fetching the full data from backers and fetching systematic chunks.
+///
+/// Regular chunk recovery is always the last resort.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RecoveryStrategyPreference {
+	/// Fetch the full data from the backing group first, then systematic chunks.
+	///
+	/// Needs the least CPU, as there is nothing to reconstruct, but all the data comes from the
+	/// few validators of the backing group.
+	#[default]
+	BackersFirst,
+	/// Fetch systematic chunks first, then the full data from the backing group.
+	///
+	/// Spreads the requests over many validators, at the cost of some CPU to put the chunks
+	/// back together.
+	SystematicChunksFirst,
+}
+
 /// The Availability Recovery Subsystem.
 pub struct AvailabilityRecoverySubsystem {
 	/// PoV recovery strategy to use.
 	recovery_strategy_kind: RecoveryStrategyKind,
+	/// The order in which the recovery strategies are tried.
+	recovery_strategy_preference: RecoveryStrategyPreference,
 	// If this is true, do not request data from the availability store.
 	/// This is the useful for nodes where the
 	/// availability-store subsystem is not expected to run,
@@ -378,6 +399,7 @@ async fn handle_recover<Context>(
 	metrics: &Metrics,
 	erasure_task_tx: futures::channel::mpsc::Sender<ErasureTask>,
 	recovery_strategy_kind: RecoveryStrategyKind,
+	recovery_strategy_preference: RecoveryStrategyPreference,
 	bypass_availability_store: bool,
 	post_recovery_check: PostRecoveryCheck,
 	maybe_core_index: Option<CoreIndex>,
@@ -412,6 +434,12 @@ async fn handle_recover<Context>(
 			let mut recovery_strategies: VecDeque<
 				Box<dyn RecoveryStrategy<<Context as SubsystemContext>::Sender>>,
 			> = VecDeque::with_capacity(3);
+			let mut fetch_full: Option<
+				Box<dyn RecoveryStrategy<<Context as SubsystemContext>::Sender>>,
+			> = None;
+			let mut fetch_systematic_chunks: Option<
+				Box<dyn RecoveryStrategy<<Context as SubsystemContext>::Sender>>,
+			> = None;
 
 			if let Some(backing_group) = backing_group {
 				if let Some(backing_validators) = session_info.validator_groups.get(backing_group) {
@@ -455,9 +483,9 @@ async fn handle_recover<Context>(
 							true,
 						) |
 						(RecoveryStrategyKind::BackersThenSystematicChunks, _) =>
-							recovery_strategies.push_back(Box::new(FetchFull::new(
-								FetchFullParams { validators: backing_validators.to_vec() },
-							))),
+							fetch_full = Some(Box::new(FetchFull::new(FetchFullParams {
+								validators: backing_validators.to_vec(),
+							}))),
 						_ => {},
 					};
 
@@ -512,15 +540,25 @@ async fn handle_recover<Context>(
 						})
 						.collect();
 
-					recovery_strategies.push_back(Box::new(FetchSystematicChunks::new(
-						FetchSystematicChunksParams {
+					fetch_systematic_chunks =
+						Some(Box::new(FetchSystematicChunks::new(FetchSystematicChunksParams {
 							validators,
 							backers: backer_group.map(|v| v.to_vec()).unwrap_or_else(|| vec![]),
-						},
-					)));
+						})));
 				}
 			}
 
+			match recovery_strategy_preference {
+				RecoveryStrategyPreference::BackersFirst => {
+					recovery_strategies.extend(fetch_full);
+					recovery_strategies.extend(fetch_systematic_chunks);
+				},
+				RecoveryStrategyPreference::SystematicChunksFirst => {
+					recovery_strategies.extend(fetch_systematic_chunks);
+					recovery_strategies.extend(fetch_full);
+				},
+			}
+
 			recovery_strategies.push_back(Box::new(FetchChunks::new(FetchChunksParams {
 				n_validators: session_info.validators.len(),
 			})));
@@ -604,6 +642,7 @@ impl AvailabilityRecoverySubsystem {
 			recovery_strategy_kind: RecoveryStrategyKind::BackersFirstIfSizeLower(
 				fetch_chunks_threshold.unwrap_or(CONSERVATIVE_FETCH_CHUNKS_THRESHOLD),
 			),
+			recovery_strategy_preference: RecoveryStrategyPreference::BackersFirst,
 			bypass_availability_store: true,
 			post_recovery_check: PostRecoveryCheck::PovHash,
 			req_receiver,
@@ -634,6 +673,7 @@ impl AvailabilityRecoverySubsystem {
 				RecoveryStrategyKind::BackersFirstIfSizeLowerThenSystematicChunks(
 					fetch_chunks_threshold.unwrap_or(CONSERVATIVE_FETCH_CHUNKS_THRESHOLD),
 				),
+			recovery_strategy_preference: RecoveryStrategyPreference::BackersFirst,
 			bypass_availability_store: false,
 			post_recovery_check: PostRecoveryCheck::Reencode,
 			req_receiver,
@@ -656,6 +696,7 @@ impl AvailabilityRecoverySubsystem {
 	) -> Self {
 		Self {
 			recovery_strategy_kind,
+			recovery_strategy_preference: RecoveryStrategyPreference::BackersFirst,
 			bypass_availability_store: false,
 			post_recovery_check: PostRecoveryCheck::Reencode,
 			req_receiver,
@@ -667,6 +708,15 @@ impl AvailabilityRecoverySubsystem {
 		}
 	}
 
+	/// Set the order in which the recovery strategies are tried.
+	pub fn with_recovery_strategy_preference(
+		mut self,
+		recovery_strategy_preference: RecoveryStrategyPreference,
+	) -> Self {
+		self.recovery_strategy_preference = recovery_strategy_preference;
+		self
+	}
+
 	/// Starts the inner subsystem loop.
 	pub async fn run<Context>(self, mut ctx: Context) -> std::result::Result<(), FatalError> {
 		let mut state = State::default();
@@ -674,6 +724,7 @@ impl AvailabilityRecoverySubsystem {
 			mut req_receiver,
 			metrics,
 			recovery_strategy_kind,
+			recovery_strategy_preference,
 			bypass_availability_store,
 			post_recovery_check,
 			req_v1_protocol_name,
@@ -760,6 +811,7 @@ impl AvailabilityRecoverySubsystem {
 										&metrics,
 										erasure_task_tx.clone(),
 										recovery_strategy_kind.clone(),
+										recovery_strategy_preference,
 										bypass_availability_store,
 										post_recovery_check.clone(),
 										maybe_core_index,
@@ -43,6 +43,11 @@ struct MetricsInner {
 	/// Gets incremented on each successful recovery
 	recovered_bytes_total: Counter<U64>,
 
+	/// Total number of bytes fetched from the network, including invalid responses.
+	///
+	/// Split by recovery type (`regular_chunks`, `systematic_chunks` or `full_from_backers`).
+	fetched_bytes_total: CounterVec<U64>,
+
 	/// A counter for finished chunk requests.
 	///
 	/// Split by the chunk type (`regular_chunks` or `systematic_chunks`)
@@ -226,6 +231,16 @@ impl Metrics {
 		}
 	}
 
+	/// Data of `bytes` length was fetched from the network by the given recovery strategy.
+	pub fn on_bytes_fetched(&self, strategy_type: &str, bytes: usize) {
+		if let Some(metrics) = &self.0 {
+			metrics
+				.fetched_bytes_total
+				.with_label_values(&[strategy_type])
+				.inc_by(bytes as u64)
+		}
+	}
+
 	/// Get a timer to time request/response duration.
 	pub fn time_chunk_request(&self, chunk_type: &str) -> Option<HistogramTimer> {
 		self.0.as_ref().map(|metrics| {
@@ -321,6 +336,16 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			fetched_bytes_total: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"polkadot_parachain_availability_recovery_fetched_bytes_total",
+						"Total number of bytes fetched from the network, per recovery strategy.",
+					),
+					&["type"],
+				)?,
+				registry,
+			)?,
 			chunk_requests_finished: prometheus::register(
 				CounterVec::new(
 					Opts::new(
@@ -107,10 +107,9 @@ impl FetchChunks {
 		state: &mut State,
 		common_params: &RecoveryParams,
 	) -> Result<AvailableData, RecoveryError> {
-		let recovery_duration =
-			common_params
-				.metrics
-				.time_erasure_recovery(RecoveryStrategy::<Sender>::strategy_type(self));
+		let strategy_type = RecoveryStrategy::<Sender>::strategy_type(self);
+		let recovery_duration = common_params.metrics.time_erasure_recovery(strategy_type);
+		let reconstruct_duration = common_params.metrics.time_erasure_reconstruct(strategy_type);
 
 		// Send request to reconstruct available data from chunks.
 		let (avilable_data_tx, available_data_rx) = oneshot::channel();
@@ -135,20 +134,25 @@ impl FetchChunks {
 
 		match available_data_response {
 			// Attempt post-recovery check.
-			Ok(data) => do_post_recovery_check(common_params, data)
-				.await
-				.inspect_err(|_| {
-					recovery_duration.map(|rd| rd.stop_and_discard());
-				})
-				.inspect(|_| {
-					gum::trace!(
-						target: LOG_TARGET,
-						candidate_hash = ?common_params.candidate_hash,
-						erasure_root = ?common_params.erasure_root,
-						"Data recovery from chunks complete",
-					);
-				}),
+			Ok(data) => {
+				drop(reconstruct_duration);
+
+				do_post_recovery_check(common_params, data)
+					.await
+					.inspect_err(|_| {
+						recovery_duration.map(|rd| rd.stop_and_discard());
+					})
+					.inspect(|_| {
+						gum::trace!(
+							target: LOG_TARGET,
+							candidate_hash = ?common_params.candidate_hash,
+							erasure_root = ?common_params.erasure_root,
+							"Data recovery from chunks complete",
+						);
+					})
+			},
 			Err(err) => {
+				reconstruct_duration.map(|rd| rd.stop_and_discard());
 				recovery_duration.map(|rd| rd.stop_and_discard());
 				gum::debug!(
 					target: LOG_TARGET,
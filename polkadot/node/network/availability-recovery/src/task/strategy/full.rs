@@ -27,6 +27,7 @@ use polkadot_node_subsystem::{messages::NetworkBridgeTxMessage, overseer, Recove
 use polkadot_primitives::ValidatorIndex;
 use sc_network::{IfDisconnected, OutboundFailure, RequestFailure};
 
+use codec::Encode;
 use futures::{channel::oneshot, SinkExt};
 use rand::seq::SliceRandom;
 
@@ -94,6 +95,7 @@ impl<Sender: overseer::AvailabilityRecoverySenderTrait> RecoveryStrategy<Sender>
 
 			match response.await {
 				Ok(req_res::v1::AvailableDataFetchingResponse::AvailableData(data)) => {
+					common_params.metrics.on_bytes_fetched(strategy_type, data.encoded_size());
 					let recovery_duration =
 						common_params.metrics.time_erasure_recovery(strategy_type);
 					let maybe_data = match common_params.post_recovery_check {
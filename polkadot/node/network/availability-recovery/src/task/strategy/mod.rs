@@ -491,6 +491,10 @@ impl State {
 						_ => {},
 					}
 
+					if let Some(chunk) = &maybe_chunk {
+						metrics.on_bytes_fetched(strategy_type, chunk.chunk.len());
+					}
+
 					match maybe_chunk {
 						Some(chunk) =>
 							if is_chunk_valid(params, &chunk) {
@@ -26,7 +26,8 @@ use polkadot_node_network_protocol::{
 };
 use polkadot_node_primitives::PoV;
 use polkadot_primitives::{
-	vstaging::CandidateReceiptV2 as CandidateReceipt, CandidateHash, Hash, HeadData, Id as ParaId,
+	vstaging::CandidateReceiptV2 as CandidateReceipt, CandidateHash, CoreIndex, Hash, HeadData,
+	Id as ParaId,
 };
 
 /// The status of a collation as seen from the collator.
@@ -77,12 +78,13 @@ pub struct Collation {
 	pub status: CollationStatus,
 }
 
-/// Stores the state for waiting collation fetches per relay parent.
+/// Stores the state for waiting collation fetches per relay parent and core.
 #[derive(Default)]
 pub struct WaitingCollationFetches {
 	/// A flag indicating that we have an ongoing request.
 	/// This limits the number of collations being sent at any moment
-	/// of time to 1 for each relay parent.
+	/// of time to 1 for each core of a relay parent, so that the collations
+	/// for the different cores of a para are sent in parallel.
 	///
 	/// If set to `true`, any new request will be queued.
 	pub collation_fetch_active: bool,
@@ -154,6 +156,8 @@ impl VersionedCollationRequest {
 pub struct CollationSendResult {
 	/// Candidate's relay parent.
 	pub relay_parent: Hash,
+	/// The core the candidate was built for.
+	pub core_index: CoreIndex,
 	/// Candidate hash.
 	pub candidate_hash: CandidateHash,
 	/// Peer id.
@@ -21,7 +21,9 @@ use std::{
 
 use polkadot_node_subsystem::prometheus::prometheus::HistogramTimer;
 use polkadot_node_subsystem_util::metrics::{self, prometheus};
-use polkadot_primitives::{vstaging::CandidateReceiptV2 as CandidateReceipt, BlockNumber, Hash};
+use polkadot_primitives::{
+	vstaging::CandidateReceiptV2 as CandidateReceipt, BlockNumber, CoreIndex, Hash,
+};
 use sp_core::H256;
 
 use super::collation::CollationStatus;
@@ -62,6 +64,26 @@ impl Metrics {
 		}
 	}
 
+	/// Note that we started to upload a collation for the given core.
+	pub fn on_collation_upload_started(&self, core_index: CoreIndex) {
+		if let Some(metrics) = &self.0 {
+			metrics
+				.collation_uploads_in_flight
+				.with_label_values(&[&core_index.0.to_string()])
+				.inc();
+		}
+	}
+
+	/// Note that an upload of a collation for the given core finished or timed out.
+	pub fn on_collation_upload_finished(&self, core_index: CoreIndex) {
+		if let Some(metrics) = &self.0 {
+			metrics
+				.collation_uploads_in_flight
+				.with_label_values(&[&core_index.0.to_string()])
+				.dec();
+		}
+	}
+
 	/// Provide a timer for `process_msg` which observes on drop.
 	pub fn time_process_msg(&self) -> Option<prometheus::prometheus::HistogramTimer> {
 		self.0.as_ref().map(|metrics| metrics.process_msg.start_timer())
@@ -103,6 +125,7 @@ struct MetricsInner {
 	advertisements_made: prometheus::Counter<prometheus::U64>,
 	collations_sent: prometheus::Counter<prometheus::U64>,
 	collations_send_requested: prometheus::Counter<prometheus::U64>,
+	collation_uploads_in_flight: prometheus::GaugeVec<prometheus::U64>,
 	process_msg: prometheus::Histogram,
 	collation_distribution_time: prometheus::HistogramVec,
 	collation_fetch_latency: prometheus::Histogram,
@@ -138,6 +161,16 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			collation_uploads_in_flight: prometheus::register(
+				prometheus::GaugeVec::new(
+					prometheus::Opts::new(
+						"polkadot_parachain_collation_uploads_in_flight",
+						"A number of collations being uploaded to validators, per core.",
+					),
+					&["core"],
+				)?,
+				registry,
+			)?,
 			process_msg: prometheus::register(
 				prometheus::Histogram::with_opts(
 					prometheus::HistogramOpts::new(
@@ -315,9 +315,9 @@ struct State {
 
 	/// All collation fetching requests that are still waiting to be answered.
 	///
-	/// They are stored per relay parent, when our view changes and the relay parent moves out, we
-	/// will cancel the fetch request.
-	waiting_collation_fetches: HashMap<Hash, WaitingCollationFetches>,
+	/// They are stored per relay parent and core, when our view changes and the relay parent moves
+	/// out, we will cancel the fetch request.
+	waiting_collation_fetches: HashMap<(Hash, CoreIndex), WaitingCollationFetches>,
 
 	/// Active collation fetches.
 	///
@@ -713,7 +713,7 @@ async fn advertise_collation<Context>(
 				?core_index,
 				"Skipping advertising to validator, validator group for core not found",
 			);
-			return
+			continue
 		};
 
 		let should_advertise = validator_group.should_advertise_to(candidate_hash, peer_ids, &peer);
@@ -853,6 +853,7 @@ async fn process_msg<Context>(
 async fn send_collation(
 	state: &mut State,
 	request: VersionedCollationRequest,
+	core_index: CoreIndex,
 	receipt: CandidateReceipt,
 	pov: PoV,
 	parent_head_data: HeadData,
@@ -881,12 +882,13 @@ async fn send_collation(
 			let r = rx.timeout(MAX_UNSHARED_UPLOAD_TIME).await;
 			let timed_out = r.is_none();
 
-			CollationSendResult { relay_parent, candidate_hash, peer_id, timed_out }
+			CollationSendResult { relay_parent, core_index, candidate_hash, peer_id, timed_out }
 		}
 		.boxed(),
 	);
 
 	state.metrics.on_collation_sent();
+	state.metrics.on_collation_upload_started(core_index);
 }
 
 /// A networking messages switch.
@@ -1044,11 +1046,13 @@ async fn handle_incoming_request<Context>(
 				VersionedCollationRequest::V2(req) =>
 					per_relay_parent.collations.get_mut(&req.payload.candidate_hash),
 			};
-			let (receipt, pov, parent_head_data) =
+			let (core_index, receipt, pov, parent_head_data) =
 				if let Some(collation_with_core) = collation_with_core {
+					let core_index = *collation_with_core.core_index();
 					let collation = collation_with_core.collation_mut();
 					collation.status.advance_to_requested();
 					(
+						core_index,
 						collation.receipt.clone(),
 						collation.pov.clone(),
 						collation.parent_head_data.clone(),
@@ -1065,7 +1069,8 @@ async fn handle_incoming_request<Context>(
 
 			state.metrics.on_collation_sent_requested();
 
-			let waiting = state.waiting_collation_fetches.entry(relay_parent).or_default();
+			let waiting =
+				state.waiting_collation_fetches.entry((relay_parent, core_index)).or_default();
 			let candidate_hash = receipt.hash();
 
 			if !waiting.waiting_peers.insert((peer_id, candidate_hash)) {
@@ -1090,7 +1095,7 @@ async fn handle_incoming_request<Context>(
 				// Obtain a timer for sending collation
 				let _ = state.metrics.time_collation_distribution("send");
 
-				send_collation(state, req, receipt, pov, parent_head_data).await;
+				send_collation(state, req, core_index, receipt, pov, parent_head_data).await;
 			}
 		},
 		Some(our_para_id) => {
@@ -1472,7 +1477,9 @@ async fn handle_our_view_change<Context>(
 				);
 			}
 
-			state.waiting_collation_fetches.remove(removed);
+			state
+				.waiting_collation_fetches
+				.retain(|(relay_parent, _), _| relay_parent != removed);
 		}
 	}
 	Ok(())
@@ -1631,10 +1638,11 @@ async fn run_inner<Context>(
 				FromOrchestra::Signal(BlockFinalized(..)) => {}
 				FromOrchestra::Signal(Conclude) => return Ok(()),
 			},
-			CollationSendResult { relay_parent, candidate_hash, peer_id, timed_out } =
+			CollationSendResult { relay_parent, core_index, candidate_hash, peer_id, timed_out } =
 				state.active_collation_fetches.select_next_some() => {
+				metrics.on_collation_upload_finished(core_index);
 
-				let next = if let Some(waiting) = state.waiting_collation_fetches.get_mut(&relay_parent) {
+				let next = if let Some(waiting) = state.waiting_collation_fetches.get_mut(&(relay_parent, core_index)) {
 					if timed_out {
 						gum::debug!(
 							target: LOG_TARGET_STATS,
@@ -1712,7 +1720,7 @@ async fn run_inner<Context>(
 					let pov = collation.pov.clone();
 					let parent_head_data = collation.parent_head_data.clone();
 
-					send_collation(&mut state, next, receipt, pov, parent_head_data).await;
+					send_collation(&mut state, next, core_index, receipt, pov, parent_head_data).await;
 				}
 			},
 			(candidate_hash, peer_id) = state.advertisement_timeouts.select_next_some() => {
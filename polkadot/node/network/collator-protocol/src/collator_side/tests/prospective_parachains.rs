@@ -660,6 +660,114 @@ fn send_parent_head_data_for_elastic_scaling() {
 	)
 }
 
+/// Tests that the collations for different cores of the same relay parent are sent in parallel.
+#[test]
+fn send_collations_for_different_cores_in_parallel() {
+	let test_state = TestState::with_elastic_scaling();
+
+	let local_peer_id = test_state.local_peer_id;
+	let collator_pair = test_state.collator_pair.clone();
+
+	test_harness(
+		local_peer_id,
+		collator_pair,
+		ReputationAggregator::new(|_| true),
+		|test_harness| async move {
+			let mut virtual_overseer = test_harness.virtual_overseer;
+			let mut req_v2_cfg = test_harness.req_v2_cfg;
+
+			let head_b = Hash::from_low_u64_be(129);
+			let head_b_num: u32 = 63;
+
+			// Set collating para id.
+			overseer_send(
+				&mut virtual_overseer,
+				CollatorProtocolMessage::CollateOn(test_state.para_id),
+			)
+			.await;
+			update_view(&test_state, &mut virtual_overseer, vec![(head_b, head_b_num)], 1).await;
+
+			// Both cores are backed by the same group, so a single validator fetches both.
+			let mut candidates = Vec::new();
+			for core_index in [CoreIndex(0), CoreIndex(2)] {
+				let pov = PoV { block_data: BlockData(vec![core_index.0 as u8]) };
+				let candidate = TestCandidateBuilder {
+					para_id: test_state.para_id,
+					relay_parent: head_b,
+					pov_hash: pov.hash(),
+					core_index,
+					..Default::default()
+				}
+				.build();
+				distribute_collation_with_receipt(
+					&mut virtual_overseer,
+					&test_state,
+					head_b,
+					true,
+					candidate.clone(),
+					pov.clone(),
+					Hash::zero(),
+				)
+				.await;
+				candidates.push((candidate, pov));
+			}
+
+			let peer = test_state.current_group_validator_peer_ids()[0];
+			let validator_id = test_state.current_group_validator_authority_ids()[0].clone();
+			connect_peer(&mut virtual_overseer, peer, CollationVersion::V2, Some(validator_id))
+				.await;
+			expect_declare_msg(&mut virtual_overseer, &test_state, &peer).await;
+
+			send_peer_view_change(&mut virtual_overseer, &peer, vec![head_b]).await;
+			let hashes = candidates.iter().map(|(candidate, _)| candidate.hash()).collect();
+			expect_advertise_collation_msg(&mut virtual_overseer, &[peer], head_b, hashes).await;
+
+			// Request the collations one after the other. The upload of the first collation is not
+			// finished, so the second one is only sent right away if it does not wait for it.
+			let mut feedback_senders = Vec::new();
+			for (candidate, pov) in candidates {
+				let (pending_response, rx) = oneshot::channel();
+				req_v2_cfg
+					.inbound_queue
+					.as_mut()
+					.unwrap()
+					.send(RawIncomingRequest {
+						peer,
+						payload: CollationFetchingRequest {
+							relay_parent: head_b,
+							para_id: test_state.para_id,
+							candidate_hash: candidate.hash(),
+						}
+						.encode(),
+						pending_response,
+					})
+					.await
+					.unwrap();
+
+				let full_response = rx.await.expect("Collations are sent");
+				let (receipt, sent_pov) = decode_collation_response(
+					full_response.result.expect("We should have a proper answer").as_ref(),
+				);
+				assert_eq!(receipt, candidate);
+				assert_eq!(sent_pov, pov);
+
+				// Keep the uploads alive until the end of the test.
+				feedback_senders
+					.push(full_response.sent_feedback.expect("Feedback channel is always set"));
+			}
+
+			// Had the second collation waited for the first upload, it would only have been sent
+			// once that upload timed out and dropped its feedback receiver.
+			assert!(
+				!feedback_senders[0].is_canceled(),
+				"Collations for different cores are sent in parallel"
+			);
+
+			TestHarness { virtual_overseer, req_v2_cfg }
+		},
+	)
+}
+
 /// Tests that collator correctly handles peer V2 requests.
 #[test]
 fn advertise_and_send_collation_by_hash() {
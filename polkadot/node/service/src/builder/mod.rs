@@ -33,7 +33,7 @@ use crate::{
 use frame_benchmarking_cli::SUBSTRATE_REFERENCE_HARDWARE;
 use gum::info;
 use mmr_gadget::MmrGadget;
-use polkadot_availability_recovery::FETCH_CHUNKS_THRESHOLD;
+use polkadot_availability_recovery::{RecoveryStrategyPreference, FETCH_CHUNKS_THRESHOLD};
 use polkadot_node_core_approval_voting::Config as ApprovalVotingConfig;
 use polkadot_node_core_av_store::Config as AvailabilityConfig;
 use polkadot_node_core_candidate_validation::Config as CandidateValidationConfig;
@@ -85,6 +85,9 @@ pub struct NewFullParams<OverseerGenerator: OverseerGen> {
 	pub prepare_workers_hard_max_num: Option<usize>,
 	/// How long finalized data should be kept in the availability store (in hours)
 	pub keep_finalized_for: Option<u32>,
+	/// The order in which the availability recovery strategies are tried. Backers first if not
+	/// specified.
+	pub recovery_strategy_preference: Option<RecoveryStrategyPreference>,
 	pub overseer_gen: OverseerGenerator,
 	pub overseer_message_channel_capacity_override: Option<usize>,
 	#[allow(dead_code)]
@@ -147,8 +150,12 @@ where
 			SelectRelayChain::new_longest_chain(basics.backend.clone())
 		};
 
-		let partial_components =
-			new_partial::<SelectRelayChain<_>>(&mut config, basics, select_chain)?;
+		let partial_components = new_partial::<SelectRelayChain<_>>(
+			&mut config,
+			basics,
+			select_chain,
+			auth_or_collator.then(|| overseer_handle.clone()),
+		)?;
 
 		let net_config = sc_network::config::FullNetworkConfiguration::<_, _, Network>::new(
 			&config.network,
@@ -199,6 +206,7 @@ where
 					prepare_workers_soft_max_num,
 					prepare_workers_hard_max_num,
 					keep_finalized_for,
+					recovery_strategy_preference,
 				},
 			overseer_connector,
 			partial_components:
@@ -440,6 +448,7 @@ where
 				dispute_coordinator_config,
 				chain_selection_config,
 				fetch_chunks_threshold,
+				recovery_strategy_preference: recovery_strategy_preference.unwrap_or_default(),
 			})
 		};
 
@@ -22,6 +22,7 @@ use crate::{
 	fake_runtime_api::RuntimeApi, grandpa_support, relay_chain_selection, Error, FullBackend,
 	FullClient, IdentifyVariant, GRANDPA_JUSTIFICATION_PERIOD,
 };
+use polkadot_overseer::Handle;
 use polkadot_primitives::Block;
 use sc_consensus_grandpa::FinalityProofProvider as GrandpaFinalityProofProvider;
 use sc_executor::{HeapAllocStrategy, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY};
@@ -146,6 +147,7 @@ pub(crate) fn new_partial<ChainSelection>(
 	config: &mut Configuration,
 	Basics { task_manager, backend, client, keystore_container, telemetry }: Basics,
 	select_chain: ChainSelection,
+	overseer_handle: Option<Handle>,
 ) -> Result<PolkadotPartialComponents<ChainSelection>, Error>
 where
 	ChainSelection: 'static + SelectChain<Block>,
@@ -259,6 +261,7 @@ where
 					subscription_executor,
 				},
 				backend: backend.clone(),
+				overseer_handle: overseer_handle.clone(),
 			};
 
 			polkadot_rpc::create_full(deps).map_err(Into::into)
@@ -292,6 +292,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(3)]
 	impl sp_mmr_primitives::MmrApi<Block, Hash, BlockNumber> for Runtime {
 		fn mmr_root() -> Result<Hash, sp_mmr_primitives::Error> {
 			unimplemented!()
@@ -308,6 +309,13 @@ sp_api::impl_runtime_apis! {
 			unimplemented!()
 		}
 
+		fn generate_batch_proof_for_range(
+			_: BlockNumber,
+			_: BlockNumber,
+		) -> Result<(Vec<sp_mmr_primitives::EncodableOpaqueLeaf>, sp_mmr_primitives::LeafProof<Hash>), sp_mmr_primitives::Error> {
+			unimplemented!()
+		}
+
 		fn verify_proof(_: Vec<sp_mmr_primitives::EncodableOpaqueLeaf>, _: sp_mmr_primitives::LeafProof<Hash>)
 			-> Result<(), sp_mmr_primitives::Error>
 		{
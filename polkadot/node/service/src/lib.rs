@@ -400,7 +400,7 @@ macro_rules! chain_ops {
 		let chain_selection = LongestChain::new(basics.backend.clone());
 
 		let sc_service::PartialComponents { client, backend, import_queue, task_manager, .. } =
-			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection)?;
+			new_partial::<LongestChain<_, Block>>(&mut config, basics, chain_selection, None)?;
 		Ok((client, backend, import_queue, task_manager))
 	}};
 }
@@ -48,7 +48,9 @@ use std::{collections::HashMap, sync::Arc};
 pub use polkadot_approval_distribution::ApprovalDistribution as ApprovalDistributionSubsystem;
 pub use polkadot_availability_bitfield_distribution::BitfieldDistribution as BitfieldDistributionSubsystem;
 pub use polkadot_availability_distribution::AvailabilityDistributionSubsystem;
-pub use polkadot_availability_recovery::AvailabilityRecoverySubsystem;
+pub use polkadot_availability_recovery::{
+	AvailabilityRecoverySubsystem, RecoveryStrategyPreference,
+};
 pub use polkadot_collator_protocol::{CollatorProtocolSubsystem, ProtocolSide};
 pub use polkadot_dispute_distribution::DisputeDistributionSubsystem;
 pub use polkadot_gossip_support::GossipSupport as GossipSupportSubsystem;
@@ -139,6 +141,8 @@ pub struct ExtendedOverseerGenArgs {
 	/// than the value put in here we always try to recovery availability from backers.
 	/// The presence of this parameter here is needed to have different values per chain.
 	pub fetch_chunks_threshold: Option<usize>,
+	/// The order in which the availability recovery strategies are tried.
+	pub recovery_strategy_preference: RecoveryStrategyPreference,
 }
 
 /// Obtain a prepared validator `Overseer`, that is initialized with all default values.
@@ -173,6 +177,7 @@ pub fn validator_overseer_builder<Spawner, RuntimeClient>(
 		dispute_coordinator_config,
 		chain_selection_config,
 		fetch_chunks_threshold,
+		recovery_strategy_preference,
 	}: ExtendedOverseerGenArgs,
 ) -> Result<
 	InitializedOverseerBuilder<
@@ -253,12 +258,15 @@ where
 			req_protocol_names.clone(),
 			Metrics::register(registry)?,
 		))
-		.availability_recovery(AvailabilityRecoverySubsystem::for_validator(
-			fetch_chunks_threshold,
-			available_data_req_receiver,
-			&req_protocol_names,
-			Metrics::register(registry)?,
-		))
+		.availability_recovery(
+			AvailabilityRecoverySubsystem::for_validator(
+				fetch_chunks_threshold,
+				available_data_req_receiver,
+				&req_protocol_names,
+				Metrics::register(registry)?,
+			)
+			.with_recovery_strategy_preference(recovery_strategy_preference),
+		)
 		.availability_store(AvailabilityStoreSubsystem::new(
 			parachains_db.clone(),
 			availability_config,
@@ -44,7 +44,8 @@ use polkadot_node_primitives::{
 use polkadot_primitives::{
 	async_backing, slashing,
 	vstaging::{
-		self, async_backing::Constraints, BackedCandidate, CandidateReceiptV2 as CandidateReceipt,
+		self, async_backing::Constraints, BackedCandidate, BackingGroups,
+		CandidateReceiptV2 as CandidateReceipt,
 		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState,
 	},
 	ApprovalVotingParams, AuthorityDiscoveryId, BlockNumber, CandidateCommitments, CandidateHash,
@@ -784,6 +785,10 @@ pub enum RuntimeApiRequest {
 	/// Get the paraids at the relay parent.
 	/// `V14`
 	ParaIds(SessionIndex, RuntimeApiSender<Vec<ParaId>>),
+	/// Get the backing group assigned to each core and the number of blocks until the groups
+	/// rotate.
+	/// `V15`
+	BackingGroups(RuntimeApiSender<BackingGroups>),
 }
 
 impl RuntimeApiRequest {
@@ -836,6 +841,9 @@ impl RuntimeApiRequest {
 
 	/// `ParaIds`
 	pub const PARAIDS_RUNTIME_REQUIREMENT: u32 = 14;
+
+	/// `BackingGroups`
+	pub const BACKING_GROUPS_RUNTIME_REQUIREMENT: u32 = 15;
 }
 
 /// A message to the Runtime API subsystem.
@@ -1014,6 +1022,8 @@ pub enum ApprovalVotingParallelMessage {
 		CandidateHash,
 		oneshot::Sender<HashMap<ValidatorIndex, (Vec<CandidateHash>, ValidatorSignature)>>,
 	),
+	/// Gets mapped into `ApprovalVotingMessage::GetBlockApprovalStatus`
+	GetBlockApprovalStatus(Hash, oneshot::Sender<Option<BlockApprovalStatus>>),
 	/// Gets mapped into `ApprovalDistributionMessage::NewBlocks`
 	NewBlocks(Vec<BlockApprovalMeta>),
 	/// Gets mapped into `ApprovalDistributionMessage::DistributeAssignment`
@@ -1043,6 +1053,8 @@ impl TryFrom<ApprovalVotingParallelMessage> for ApprovalVotingMessage {
 				Ok(ApprovalVotingMessage::ApprovedAncestor(hash, number, tx)),
 			ApprovalVotingParallelMessage::GetApprovalSignaturesForCandidate(candidate, tx) =>
 				Ok(ApprovalVotingMessage::GetApprovalSignaturesForCandidate(candidate, tx)),
+			ApprovalVotingParallelMessage::GetBlockApprovalStatus(hash, tx) =>
+				Ok(ApprovalVotingMessage::GetBlockApprovalStatus(hash, tx)),
 			_ => Err(()),
 		}
 	}
@@ -1103,6 +1115,39 @@ pub struct HighestApprovedAncestorBlock {
 	pub descriptions: Vec<BlockDescription>,
 }
 
+/// Response type to `ApprovalVotingMessage::GetBlockApprovalStatus`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockApprovalStatus {
+	/// The number of the block.
+	pub block_number: BlockNumber,
+	/// Whether all the candidates included in the block are approved.
+	pub approved: bool,
+	/// The number of ticks elapsed since the start of the slot of the block.
+	pub ticks_since_slot_start: u64,
+	/// The approval status of the candidates included in the block.
+	pub candidates: Vec<CandidateApprovalStatus>,
+}
+
+/// The approval status of a candidate under a relay chain block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CandidateApprovalStatus {
+	/// The hash of the candidate.
+	pub candidate_hash: CandidateHash,
+	/// The para the candidate belongs to.
+	pub para_id: ParaId,
+	/// Whether the candidate is approved under the block.
+	pub approved: bool,
+	/// The number of validators assigned to check the candidate.
+	pub assignments: u32,
+	/// The number of assigned validators who approved the candidate.
+	pub approvals: u32,
+	/// The number of assigned validators who are currently no-shows.
+	pub no_shows: u32,
+	/// The highest delay tranche which is needed to approve the candidate, or which has been
+	/// considered so far if more assignments are needed. `None` if all validators are needed.
+	pub tranche_depth: Option<DelayTranche>,
+}
+
 /// A checked indirect assignment, the crypto for the cert has been validated
 /// and the `candidate_bitfield` is correctly claimed at `delay_tranche`.
 #[derive(Debug)]
@@ -1182,6 +1227,12 @@ pub enum ApprovalVotingMessage {
 		CandidateHash,
 		oneshot::Sender<HashMap<ValidatorIndex, (Vec<CandidateHash>, ValidatorSignature)>>,
 	),
+
+	/// Retrieve the approval status of all the candidates included in a relay chain block.
+	///
+	/// Returns `None` if the block is unknown to approval-voting, e.g. because it is finalized.
+	/// Meant for introspection only: calls should be infrequent.
+	GetBlockApprovalStatus(Hash, oneshot::Sender<Option<BlockApprovalStatus>>),
 }
 
 /// Message to the Approval Distribution subsystem.
@@ -20,7 +20,7 @@ use polkadot_primitives::{
 	runtime_api::ParachainHost,
 	slashing,
 	vstaging::{
-		self, async_backing::Constraints, CandidateEvent,
+		self, async_backing::Constraints, BackingGroups, CandidateEvent,
 		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState, ScrapedOnChainVotes,
 	},
 	ApprovalVotingParams, Block, BlockNumber, CandidateCommitments, CandidateHash, CoreIndex,
@@ -361,6 +361,11 @@ pub trait RuntimeApiSubsystemClient {
 	// == v14 ==
 	/// Fetch the list of all parachain IDs registered in the relay chain.
 	async fn para_ids(&self, at: Hash) -> Result<Vec<Id>, ApiError>;
+
+	// == v15 ==
+	/// Fetch the backing group assigned to each core and the number of blocks until the groups
+	/// rotate.
+	async fn backing_groups(&self, at: Hash) -> Result<BackingGroups, ApiError>;
 }
 
 /// Default implementation of [`RuntimeApiSubsystemClient`] using the client.
@@ -658,6 +663,10 @@ where
 	async fn para_ids(&self, at: Hash) -> Result<Vec<Id>, ApiError> {
 		self.client.runtime_api().para_ids(at)
 	}
+
+	async fn backing_groups(&self, at: Hash) -> Result<BackingGroups, ApiError> {
+		self.client.runtime_api().backing_groups(at)
+	}
 }
 
 impl<Client, Block> HeaderBackend<Block> for DefaultSubsystemClient<Client>
@@ -44,8 +44,8 @@ use polkadot_primitives::{
 	slashing,
 	vstaging::{
 		async_backing::{BackingState, Constraints},
-		CandidateEvent, CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState,
-		ScrapedOnChainVotes,
+		BackingGroups, CandidateEvent, CommittedCandidateReceiptV2 as CommittedCandidateReceipt,
+		CoreState, ScrapedOnChainVotes,
 	},
 	AsyncBackingParams, AuthorityDiscoveryId, CandidateHash, CoreIndex, EncodeAs, ExecutorParams,
 	GroupIndex, GroupRotationInfo, Hash, Id as ParaId, NodeFeatures, OccupiedCoreAssumption,
@@ -320,6 +320,7 @@ specialize_requests! {
 	fn request_min_backing_votes(session_index: SessionIndex) -> u32; MinimumBackingVotes;
 	fn request_node_features(session_index: SessionIndex) -> NodeFeatures; NodeFeatures;
 	fn request_para_ids(session_index: SessionIndex) -> Vec<ParaId>; ParaIds;
+	fn request_backing_groups() -> BackingGroups; BackingGroups;
 
 }
 
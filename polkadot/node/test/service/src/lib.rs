@@ -100,6 +100,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 		prepare_workers_hard_max_num: None,
 		prepare_workers_soft_max_num: None,
 		keep_finalized_for: None,
+		recovery_strategy_preference: None,
 	};
 
 	match config.network.network_backend {
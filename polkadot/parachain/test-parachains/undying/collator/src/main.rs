@@ -110,6 +110,7 @@ fn main() -> Result<()> {
 						prepare_workers_hard_max_num: None,
 						prepare_workers_soft_max_num: None,
 						keep_finalized_for: None,
+						recovery_strategy_preference: None,
 					},
 				)
 				.map_err(|e| e.to_string())?;
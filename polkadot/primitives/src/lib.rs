@@ -42,21 +42,21 @@ pub use v8::{
 	AccountId, AccountIndex, AccountPublic, ApprovalVote, ApprovalVoteMultipleCandidates,
 	ApprovalVotingParams, AssignmentId, AsyncBackingParams, AuthorityDiscoveryId,
 	AvailabilityBitfield, BackedCandidate, Balance, BlakeTwo256, Block, BlockId, BlockNumber,
-	CandidateCommitments, CandidateDescriptor, CandidateEvent, CandidateHash, CandidateIndex,
-	CandidateReceipt, CheckedDisputeStatementSet, CheckedMultiDisputeStatementSet, ChunkIndex,
-	CollatorId, CollatorSignature, CommittedCandidateReceipt, CompactStatement, ConsensusLog,
-	CoreIndex, CoreState, DisputeState, DisputeStatement, DisputeStatementSet, DownwardMessage,
-	EncodeAs, ExecutorParam, ExecutorParamError, ExecutorParams, ExecutorParamsHash,
-	ExecutorParamsPrepHash, ExplicitDisputeStatement, GroupIndex, GroupRotationInfo, Hash, HashT,
-	HeadData, Header, HorizontalMessages, HrmpChannelId, Id, InboundDownwardMessage,
-	InboundHrmpMessage, IndexedVec, InherentData, InvalidDisputeStatementKind, Moment,
-	MultiDisputeStatementSet, NodeFeatures, Nonce, OccupiedCore, OccupiedCoreAssumption,
-	OutboundHrmpMessage, ParathreadClaim, ParathreadEntry, PersistedValidationData,
-	PvfCheckStatement, PvfExecKind, PvfPrepKind, RuntimeMetricLabel, RuntimeMetricLabelValue,
-	RuntimeMetricLabelValues, RuntimeMetricLabels, RuntimeMetricOp, RuntimeMetricUpdate,
-	ScheduledCore, SchedulerParams, ScrapedOnChainVotes, SessionIndex, SessionInfo, Signature,
-	Signed, SignedAvailabilityBitfield, SignedAvailabilityBitfields, SignedStatement,
-	SigningContext, Slot, UncheckedSigned, UncheckedSignedAvailabilityBitfield,
+	CandidateCommitments, CandidateDescriptor, CandidateEvent, CandidateEventsExt, CandidateHash,
+	CandidateIndex, CandidateReceipt, CheckedDisputeStatementSet, CheckedMultiDisputeStatementSet,
+	ChunkIndex, CollatorId, CollatorSignature, CommittedCandidateReceipt, CompactStatement,
+	ConsensusLog, CoreIndex, CoreState, DisputeState, DisputeStatement, DisputeStatementSet,
+	DownwardMessage, EncodeAs, ExecutorParam, ExecutorParamError, ExecutorParams,
+	ExecutorParamsHash, ExecutorParamsPrepHash, ExplicitDisputeStatement, GroupIndex,
+	GroupRotationInfo, Hash, HashT, HeadData, Header, HorizontalMessages, HrmpChannelId, Id,
+	InboundDownwardMessage, InboundHrmpMessage, IndexedVec, InherentData,
+	InvalidDisputeStatementKind, Moment, MultiDisputeStatementSet, NodeFeatures, Nonce,
+	OccupiedCore, OccupiedCoreAssumption, OutboundHrmpMessage, ParathreadClaim, ParathreadEntry,
+	PersistedValidationData, PvfCheckStatement, PvfExecKind, PvfPrepKind, RuntimeMetricLabel,
+	RuntimeMetricLabelValue, RuntimeMetricLabelValues, RuntimeMetricLabels, RuntimeMetricOp,
+	RuntimeMetricUpdate, ScheduledCore, SchedulerParams, ScrapedOnChainVotes, SessionIndex,
+	SessionInfo, Signature, Signed, SignedAvailabilityBitfield, SignedAvailabilityBitfields,
+	SignedStatement, SigningContext, Slot, UncheckedSigned, UncheckedSignedAvailabilityBitfield,
 	UncheckedSignedAvailabilityBitfields, UncheckedSignedStatement, UpgradeGoAhead,
 	UpgradeRestriction, UpwardMessage, ValidDisputeStatementKind, ValidationCode,
 	ValidationCodeHash, ValidatorId, ValidatorIndex, ValidatorSignature, ValidityAttestation,
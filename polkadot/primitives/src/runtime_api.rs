@@ -319,5 +319,11 @@ sp_api::decl_runtime_apis! {
 		#[api_version(14)]
 		fn para_ids() -> Vec<ppp::Id>;
 
+		/***** Added in v15 *****/
+		/// Retrieve the backing group assigned to each core and the number of blocks until the
+		/// groups rotate, for the block built on top of the relay parent.
+		#[api_version(15)]
+		fn backing_groups() -> vstaging::BackingGroups<BlockNumber>;
+
 	}
 }
@@ -1218,6 +1218,123 @@ pub enum CandidateEvent<H = Hash> {
 	CandidateTimedOut(CandidateReceipt<H>, HeadData, CoreIndex),
 }
 
+impl<H> CandidateEvent<H> {
+	/// The receipt of the candidate.
+	pub fn receipt(&self) -> &CandidateReceipt<H> {
+		match self {
+			Self::CandidateBacked(receipt, ..) |
+			Self::CandidateIncluded(receipt, ..) |
+			Self::CandidateTimedOut(receipt, ..) => receipt,
+		}
+	}
+
+	/// Consumes the event and returns the receipt of the candidate.
+	pub fn into_receipt(self) -> CandidateReceipt<H> {
+		match self {
+			Self::CandidateBacked(receipt, ..) |
+			Self::CandidateIncluded(receipt, ..) |
+			Self::CandidateTimedOut(receipt, ..) => receipt,
+		}
+	}
+
+	/// The head data of the candidate.
+	pub fn head_data(&self) -> &HeadData {
+		match self {
+			Self::CandidateBacked(_, head_data, ..) |
+			Self::CandidateIncluded(_, head_data, ..) |
+			Self::CandidateTimedOut(_, head_data, ..) => head_data,
+		}
+	}
+
+	/// The core the candidate is or was occupying.
+	pub fn core_index(&self) -> CoreIndex {
+		match self {
+			Self::CandidateBacked(_, _, core_index, ..) |
+			Self::CandidateIncluded(_, _, core_index, ..) |
+			Self::CandidateTimedOut(_, _, core_index) => *core_index,
+		}
+	}
+
+	/// The group responsible for backing the candidate.
+	///
+	/// Timed out candidates don't carry the group, so `None` is returned for them.
+	pub fn group_index(&self) -> Option<GroupIndex> {
+		match self {
+			Self::CandidateBacked(.., group_index) | Self::CandidateIncluded(.., group_index) =>
+				Some(*group_index),
+			Self::CandidateTimedOut(..) => None,
+		}
+	}
+
+	/// Whether the candidate was backed.
+	pub fn is_backed(&self) -> bool {
+		matches!(self, Self::CandidateBacked(..))
+	}
+
+	/// Whether the candidate was included.
+	pub fn is_included(&self) -> bool {
+		matches!(self, Self::CandidateIncluded(..))
+	}
+
+	/// Whether the candidate timed out.
+	pub fn is_timed_out(&self) -> bool {
+		matches!(self, Self::CandidateTimedOut(..))
+	}
+
+	/// The hash of the candidate.
+	pub fn candidate_hash(&self) -> CandidateHash
+	where
+		H: Encode,
+	{
+		self.receipt().hash()
+	}
+
+	/// The para the candidate is for.
+	pub fn para_id(&self) -> Id {
+		self.receipt().descriptor.para_id
+	}
+}
+
+/// Iterator adapters for [`CandidateEvent`]s.
+///
+/// The same adapters are available for the candidate events of all primitives versions.
+pub trait CandidateEventsExt<H>: Iterator<Item = CandidateEvent<H>> + Sized {
+	/// Only yields the events of candidates for the given para.
+	fn for_para(self, para_id: Id) -> impl Iterator<Item = CandidateEvent<H>>
+	where
+		H: Copy,
+	{
+		self.filter(move |event| event.para_id() == para_id)
+	}
+
+	/// Only yields the events of candidates which are or were occupying the given core.
+	fn for_core(self, core_index: CoreIndex) -> impl Iterator<Item = CandidateEvent<H>> {
+		self.filter(move |event| event.core_index() == core_index)
+	}
+
+	/// Only yields the events of backed candidates.
+	fn backed(self) -> impl Iterator<Item = CandidateEvent<H>> {
+		self.filter(CandidateEvent::is_backed)
+	}
+
+	/// Only yields the events of included candidates.
+	fn included(self) -> impl Iterator<Item = CandidateEvent<H>> {
+		self.filter(CandidateEvent::is_included)
+	}
+
+	/// Only yields the events of timed out candidates.
+	fn timed_out(self) -> impl Iterator<Item = CandidateEvent<H>> {
+		self.filter(CandidateEvent::is_timed_out)
+	}
+
+	/// Yields the receipts of the candidates.
+	fn receipts(self) -> impl Iterator<Item = CandidateReceipt<H>> {
+		self.map(CandidateEvent::into_receipt)
+	}
+}
+
+impl<H, I: Iterator<Item = CandidateEvent<H>>> CandidateEventsExt<H> for I {}
+
 /// Scraped runtime backing votes and resolved disputes.
 #[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(PartialEq))]
@@ -2234,6 +2351,54 @@ pub mod tests {
 		}
 	}
 
+	#[test]
+	fn candidate_event_helpers_work() {
+		let receipt = |para_id: u32| {
+			let mut receipt = dummy_committed_candidate_receipt().to_plain();
+			receipt.descriptor.para_id = para_id.into();
+			receipt
+		};
+		let head_data = HeadData(vec![1, 2, 3]);
+		let events = vec![
+			CandidateEvent::CandidateBacked(
+				receipt(1),
+				head_data.clone(),
+				CoreIndex(0),
+				GroupIndex(2),
+			),
+			CandidateEvent::CandidateIncluded(
+				receipt(2),
+				head_data.clone(),
+				CoreIndex(1),
+				GroupIndex(3),
+			),
+			CandidateEvent::CandidateTimedOut(receipt(1), head_data.clone(), CoreIndex(1)),
+		];
+
+		assert_eq!(events[0].para_id(), 1.into());
+		assert_eq!(events[1].head_data(), &head_data);
+		assert_eq!(events[1].core_index(), CoreIndex(1));
+		assert_eq!(events[1].group_index(), Some(GroupIndex(3)));
+		assert_eq!(events[2].group_index(), None);
+		assert_eq!(events[2].candidate_hash(), receipt(1).hash());
+		assert!(events[0].is_backed() && events[1].is_included() && events[2].is_timed_out());
+
+		assert_eq!(events.clone().into_iter().for_para(1.into()).count(), 2);
+		assert_eq!(events.clone().into_iter().for_core(CoreIndex(1)).count(), 2);
+		assert_eq!(
+			events
+				.clone()
+				.into_iter()
+				.for_para(1.into())
+				.timed_out()
+				.receipts()
+				.collect::<Vec<_>>(),
+			vec![receipt(1)],
+		);
+		assert_eq!(events.clone().into_iter().backed().count(), 1);
+		assert_eq!(events.into_iter().included().receipts().collect::<Vec<_>>(), vec![receipt(2)]);
+	}
+
 	#[test]
 	fn group_rotation_info_calculations() {
 		let info =
@@ -360,6 +360,125 @@ pub enum CandidateEvent<H = Hash> {
 	CandidateTimedOut(CandidateReceiptV2<H>, HeadData, CoreIndex),
 }
 
+impl<H> CandidateEvent<H> {
+	/// The receipt of the candidate.
+	pub fn receipt(&self) -> &CandidateReceiptV2<H> {
+		match self {
+			Self::CandidateBacked(receipt, ..) |
+			Self::CandidateIncluded(receipt, ..) |
+			Self::CandidateTimedOut(receipt, ..) => receipt,
+		}
+	}
+
+	/// Consumes the event and returns the receipt of the candidate.
+	pub fn into_receipt(self) -> CandidateReceiptV2<H> {
+		match self {
+			Self::CandidateBacked(receipt, ..) |
+			Self::CandidateIncluded(receipt, ..) |
+			Self::CandidateTimedOut(receipt, ..) => receipt,
+		}
+	}
+
+	/// The head data of the candidate.
+	pub fn head_data(&self) -> &HeadData {
+		match self {
+			Self::CandidateBacked(_, head_data, ..) |
+			Self::CandidateIncluded(_, head_data, ..) |
+			Self::CandidateTimedOut(_, head_data, ..) => head_data,
+		}
+	}
+
+	/// The core the candidate is or was occupying.
+	pub fn core_index(&self) -> CoreIndex {
+		match self {
+			Self::CandidateBacked(_, _, core_index, ..) |
+			Self::CandidateIncluded(_, _, core_index, ..) |
+			Self::CandidateTimedOut(_, _, core_index) => *core_index,
+		}
+	}
+
+	/// The group responsible for backing the candidate.
+	///
+	/// Timed out candidates don't carry the group, so `None` is returned for them.
+	pub fn group_index(&self) -> Option<GroupIndex> {
+		match self {
+			Self::CandidateBacked(.., group_index) | Self::CandidateIncluded(.., group_index) =>
+				Some(*group_index),
+			Self::CandidateTimedOut(..) => None,
+		}
+	}
+
+	/// Whether the candidate was backed.
+	pub fn is_backed(&self) -> bool {
+		matches!(self, Self::CandidateBacked(..))
+	}
+
+	/// Whether the candidate was included.
+	pub fn is_included(&self) -> bool {
+		matches!(self, Self::CandidateIncluded(..))
+	}
+
+	/// Whether the candidate timed out.
+	pub fn is_timed_out(&self) -> bool {
+		matches!(self, Self::CandidateTimedOut(..))
+	}
+
+	/// The hash of the candidate.
+	pub fn candidate_hash(&self) -> CandidateHash
+	where
+		H: Encode,
+	{
+		self.receipt().hash()
+	}
+}
+
+impl<H: Copy> CandidateEvent<H> {
+	/// The para the candidate is for.
+	pub fn para_id(&self) -> Id {
+		self.receipt().descriptor.para_id()
+	}
+}
+
+/// Iterator adapters for [`CandidateEvent`]s.
+///
+/// The same adapters are available for the candidate events of all primitives versions.
+pub trait CandidateEventsExt<H>: Iterator<Item = CandidateEvent<H>> + Sized {
+	/// Only yields the events of candidates for the given para.
+	fn for_para(self, para_id: Id) -> impl Iterator<Item = CandidateEvent<H>>
+	where
+		H: Copy,
+	{
+		self.filter(move |event| event.para_id() == para_id)
+	}
+
+	/// Only yields the events of candidates which are or were occupying the given core.
+	fn for_core(self, core_index: CoreIndex) -> impl Iterator<Item = CandidateEvent<H>> {
+		self.filter(move |event| event.core_index() == core_index)
+	}
+
+	/// Only yields the events of backed candidates.
+	fn backed(self) -> impl Iterator<Item = CandidateEvent<H>> {
+		self.filter(CandidateEvent::is_backed)
+	}
+
+	/// Only yields the events of included candidates.
+	fn included(self) -> impl Iterator<Item = CandidateEvent<H>> {
+		self.filter(CandidateEvent::is_included)
+	}
+
+	/// Only yields the events of timed out candidates.
+	fn timed_out(self) -> impl Iterator<Item = CandidateEvent<H>> {
+		self.filter(CandidateEvent::is_timed_out)
+	}
+
+	/// Yields the receipts of the candidates.
+	fn receipts(self) -> impl Iterator<Item = CandidateReceiptV2<H>> {
+		self.map(CandidateEvent::into_receipt)
+	}
+}
+
+impl<H, I: Iterator<Item = CandidateEvent<H>>> CandidateEventsExt<H> for I {}
+
 impl<H: Encode + Copy> From<CandidateEvent<H>> for super::v8::CandidateEvent<H> {
 	fn from(value: CandidateEvent<H>) -> Self {
 		match value {
@@ -1059,6 +1178,29 @@ impl<H: Copy> From<CoreState<H>> for super::v8::CoreState<H> {
 	}
 }
 
+/// The backing groups assigned to the availability cores at some relay parent.
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(PartialEq))]
+pub struct BackingGroups<N = BlockNumber> {
+	/// The backing group assigned to each core.
+	pub core_groups: BTreeMap<CoreIndex, GroupIndex>,
+	/// The validators of each backing group, indexed by [`GroupIndex`].
+	pub validator_groups: Vec<Vec<ValidatorIndex>>,
+	/// The number of blocks until the groups rotate to the next core.
+	///
+	/// Counted from the block built on top of the relay parent, which is the block the groups of
+	/// `core_groups` are backing candidates in.
+	pub blocks_until_rotation: N,
+}
+
+impl<N> BackingGroups<N> {
+	/// Returns the validators of the backing group assigned to the given core.
+	pub fn validators_for_core(&self, core_index: CoreIndex) -> Option<&[ValidatorIndex]> {
+		let group_index = self.core_groups.get(&core_index)?;
+		self.validator_groups.get(group_index.0 as usize).map(|group| group.as_slice())
+	}
+}
+
 /// The claim queue mapped by parachain id.
 pub type TransposedClaimQueue = BTreeMap<ParaId, BTreeMap<u8, BTreeSet<CoreIndex>>>;
 
@@ -1133,6 +1275,49 @@ mod candidate_receipt_tests {
 		}
 	}
 
+	#[test]
+	fn candidate_event_helpers_work() {
+		let receipt = |para_id: u32| {
+			let mut receipt = dummy_committed_candidate_receipt_v2().to_plain();
+			receipt.descriptor.para_id = para_id.into();
+			receipt
+		};
+		let events = vec![
+			CandidateEvent::CandidateBacked(
+				receipt(1),
+				HeadData(vec![]),
+				CoreIndex(0),
+				GroupIndex(2),
+			),
+			CandidateEvent::CandidateIncluded(
+				receipt(2),
+				HeadData(vec![]),
+				CoreIndex(1),
+				GroupIndex(3),
+			),
+			CandidateEvent::CandidateTimedOut(receipt(1), HeadData(vec![]), CoreIndex(1)),
+		];
+
+		assert_eq!(events[1].para_id(), 2.into());
+		assert_eq!(events[1].group_index(), Some(GroupIndex(3)));
+		assert_eq!(events[2].group_index(), None);
+
+		// the helpers behave the same for all versions of the candidate events
+		for (event, old_event) in events
+			.iter()
+			.cloned()
+			.zip(events.iter().cloned().map(crate::v8::CandidateEvent::from))
+		{
+			assert_eq!(event.para_id(), old_event.para_id());
+			assert_eq!(event.core_index(), old_event.core_index());
+			assert_eq!(event.group_index(), old_event.group_index());
+			assert_eq!(event.candidate_hash(), old_event.candidate_hash());
+		}
+
+		assert_eq!(events.clone().into_iter().for_para(1.into()).for_core(CoreIndex(1)).count(), 1);
+		assert_eq!(events.into_iter().included().receipts().collect::<Vec<_>>(), vec![receipt(2)]);
+	}
+
 	#[test]
 	fn is_binary_compatibile() {
 		let old_ccr = dummy_old_committed_candidate_receipt();
@@ -0,0 +1,162 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC to introspect the state of the approval-voting subsystem.
+//!
+//! All methods are unsafe, as they query the subsystems of the node.
+
+use futures::channel::oneshot;
+use jsonrpsee::{
+	core::async_trait,
+	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
+	Extensions,
+};
+use polkadot_node_subsystem_types::messages::{
+	ApprovalVotingParallelMessage, BlockApprovalStatus, CandidateApprovalStatus,
+};
+use polkadot_overseer::Handle;
+use polkadot_primitives::{BlockNumber, CandidateHash, Hash};
+use sc_rpc_api::{check_if_safe, UnsafeRpcError};
+use serde::{Deserialize, Serialize};
+
+const APPROVAL_VOTING_ERROR: i32 = 9100;
+
+/// Provides RPC methods to introspect approval-voting.
+#[rpc(server)]
+pub trait ApprovalVotingApi {
+	/// Returns the approval status of the candidates included in the given relay chain block.
+	///
+	/// Returns `None` if the block isn't tracked by approval-voting, e.g. because it is
+	/// finalized already.
+	#[method(name = "parachain_approvalStatus", with_extensions)]
+	async fn approval_status(&self, block_hash: Hash) -> Result<Option<BlockApproval>, Error>;
+}
+
+/// Implements the [`ApprovalVotingApiServer`] on top of the overseer.
+pub struct ApprovalVoting {
+	overseer_handle: Handle,
+}
+
+impl ApprovalVoting {
+	/// Creates a new instance of the approval-voting RPC handler.
+	pub fn new(overseer_handle: Handle) -> Self {
+		Self { overseer_handle }
+	}
+}
+
+#[async_trait]
+impl ApprovalVotingApiServer for ApprovalVoting {
+	async fn approval_status(
+		&self,
+		ext: &Extensions,
+		block_hash: Hash,
+	) -> Result<Option<BlockApproval>, Error> {
+		check_if_safe(ext)?;
+
+		let (tx, rx) = oneshot::channel();
+		self.overseer_handle
+			.clone()
+			.send_msg(
+				ApprovalVotingParallelMessage::GetBlockApprovalStatus(block_hash, tx),
+				"approval-voting-rpc",
+			)
+			.await;
+
+		Ok(rx.await.map_err(|_| Error::Unavailable)?.map(Into::into))
+	}
+}
+
+/// The approval status of a relay chain block.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockApproval {
+	/// The number of the block.
+	pub block_number: BlockNumber,
+	/// Whether all the candidates included in the block are approved.
+	pub approved: bool,
+	/// The number of ticks (500ms) elapsed since the start of the slot of the block.
+	pub ticks_since_slot_start: u64,
+	/// The approval status of the candidates included in the block.
+	pub candidates: Vec<CandidateApproval>,
+}
+
+/// The approval status of a candidate under a relay chain block.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateApproval {
+	/// The hash of the candidate.
+	pub candidate_hash: CandidateHash,
+	/// The para the candidate belongs to.
+	pub para_id: u32,
+	/// Whether the candidate is approved under the block.
+	pub approved: bool,
+	/// The number of validators assigned to check the candidate.
+	pub assignments: u32,
+	/// The number of assigned validators who approved the candidate.
+	pub approvals: u32,
+	/// The number of assigned validators who are currently no-shows.
+	pub no_shows: u32,
+	/// The highest delay tranche needed to approve the candidate. `None` if all validators are
+	/// needed.
+	pub tranche_depth: Option<u32>,
+}
+
+impl From<BlockApprovalStatus> for BlockApproval {
+	fn from(status: BlockApprovalStatus) -> Self {
+		Self {
+			block_number: status.block_number,
+			approved: status.approved,
+			ticks_since_slot_start: status.ticks_since_slot_start,
+			candidates: status.candidates.into_iter().map(Into::into).collect(),
+		}
+	}
+}
+
+impl From<CandidateApprovalStatus> for CandidateApproval {
+	fn from(status: CandidateApprovalStatus) -> Self {
+		Self {
+			candidate_hash: status.candidate_hash,
+			para_id: status.para_id.into(),
+			approved: status.approved,
+			assignments: status.assignments,
+			approvals: status.approvals,
+			no_shows: status.no_shows,
+			tranche_depth: status.tranche_depth,
+		}
+	}
+}
+
+/// Top-level error type for the RPC handler.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	/// Approval-voting didn't answer, e.g. because it doesn't run on this node.
+	#[error("Approval voting is unavailable")]
+	Unavailable,
+	/// Call to an unsafe RPC was denied.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] UnsafeRpcError),
+}
+
+impl From<Error> for ErrorObjectOwned {
+	fn from(error: Error) -> Self {
+		match error {
+			Error::Unavailable =>
+				ErrorObject::owned(APPROVAL_VOTING_ERROR + 1, error.to_string(), None::<()>),
+			Error::UnsafeRpcCalled(e) => e.into(),
+		}
+	}
+}
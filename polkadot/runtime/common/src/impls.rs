@@ -395,6 +395,9 @@ mod tests {
 		type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
 		type BalanceConverter = UnityAssetBalanceConversion;
 		type PayoutPeriod = ConstU64<0>;
+		type MaxRecurringSpends = frame_support::traits::ConstU32<0>;
+		type AssetSwap = ();
+		type MaxSwapSlippage = ();
 		type BlockNumberProvider = System;
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper = ();
@@ -412,6 +415,7 @@ mod tests {
 	impl pallet_authorship::Config for Test {
 		type FindAuthor = OneAuthor;
 		type EventHandler = ();
+		type AuthorStatsWindow = ();
 	}
 
 	pub fn new_test_ext() -> sp_io::TestExternalities {
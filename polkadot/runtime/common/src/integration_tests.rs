@@ -78,6 +78,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		Babe: pallet_babe,
+		Timestamp: pallet_timestamp,
 
 		// Parachains Runtime
 		Configuration: configuration,
@@ -177,6 +178,8 @@ impl pallet_timestamp::Config for Test {
 	type Moment = Moment;
 	type OnTimestampSet = ();
 	type MinimumPeriod = MinimumPeriod;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU32<0>;
 	type WeightInfo = ();
 }
 
@@ -222,6 +225,8 @@ impl paras::Config for Test {
 parameter_types! {
 	pub const ParaDeposit: Balance = 500;
 	pub const DataDepositPerByte: Balance = 1;
+	pub const TransferOfferDeposit: Balance = 10;
+	pub const TransferOfferDuration: BlockNumber = 100;
 }
 
 impl paras_registrar::Config for Test {
@@ -229,6 +234,8 @@ impl paras_registrar::Config for Test {
 	type OnSwap = (Crowdloan, Slots);
 	type ParaDeposit = ParaDeposit;
 	type DataDepositPerByte = DataDepositPerByte;
+	type TransferOfferDeposit = TransferOfferDeposit;
+	type TransferOfferDuration = TransferOfferDuration;
 	type Currency = Balances;
 	type RuntimeOrigin = RuntimeOrigin;
 	type WeightInfo = crate::paras_registrar::TestWeightInfo;
@@ -384,9 +391,11 @@ fn add_blocks(n: u32) {
 fn run_to_block(n: u32) {
 	System::run_to_block_with::<AllPalletsWithSystem>(
 		n,
-		frame_system::RunToBlockHooks::default().before_initialize(|bn| {
-			maybe_new_session(bn);
-		}),
+		frame_system::RunToBlockHooks::default()
+			.before_initialize(|bn| {
+				maybe_new_session(bn);
+			})
+			.after_initialize(|bn| Timestamp::set_timestamp(bn as Moment * 6_000)),
 	);
 }
 
@@ -205,6 +205,72 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn offer_para_transfer() -> Result<(), BenchmarkError> {
+		let para = register_para::<T>(LOWEST_PUBLIC_ID.into());
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, 0);
+		let price = T::Currency::minimum_balance();
+		// Worst case: a previous offer is replaced.
+		assert_ok!(Registrar::<T>::offer_para_transfer(
+			RawOrigin::Signed(caller.clone()).into(),
+			para,
+			caller.clone(),
+			price,
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), para, to.clone(), price);
+
+		assert_last_event::<T>(Event::<T>::TransferOffered { para_id: para, to, price }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_para_transfer() -> Result<(), BenchmarkError> {
+		let para = register_para::<T>(LOWEST_PUBLIC_ID.into());
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, 0);
+		// Leave room for the price to be paid to the current manager.
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		T::Currency::make_free_balance_be(&to, BalanceOf::<T>::max_value() / 2u32.into());
+		let price = T::Currency::minimum_balance();
+		assert_ok!(Registrar::<T>::offer_para_transfer(
+			RawOrigin::Signed(caller.clone()).into(),
+			para,
+			to.clone(),
+			price,
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(to.clone()), para, price);
+
+		assert_last_event::<T>(
+			Event::<T>::Transferred { para_id: para, from: caller, to, price }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_para_transfer() -> Result<(), BenchmarkError> {
+		let para = register_para::<T>(LOWEST_PUBLIC_ID.into());
+		let caller: T::AccountId = whitelisted_caller();
+		let to: T::AccountId = account("to", 0, 0);
+		let price = T::Currency::minimum_balance();
+		assert_ok!(Registrar::<T>::offer_para_transfer(
+			RawOrigin::Signed(caller.clone()).into(),
+			para,
+			to,
+			price,
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), para);
+
+		assert_last_event::<T>(Event::<T>::TransferOfferCancelled { para_id: para }.into());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Registrar,
 		crate::integration_tests::new_test_ext(),
@@ -141,6 +141,8 @@ impl configuration::Config for Test {
 parameter_types! {
 	pub const ParaDeposit: Balance = 10;
 	pub const DataDepositPerByte: Balance = 1;
+	pub const TransferOfferDeposit: Balance = 5;
+	pub const TransferOfferDuration: BlockNumber = 10;
 	pub const MaxRetries: u32 = 3;
 }
 
@@ -151,6 +153,8 @@ impl Config for Test {
 	type OnSwap = MockSwap;
 	type ParaDeposit = ParaDeposit;
 	type DataDepositPerByte = DataDepositPerByte;
+	type TransferOfferDeposit = TransferOfferDeposit;
+	type TransferOfferDuration = TransferOfferDuration;
 	type WeightInfo = TestWeightInfo;
 }
 
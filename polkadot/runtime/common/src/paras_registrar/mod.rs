@@ -25,7 +25,7 @@ use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	pallet_prelude::Weight,
-	traits::{Currency, Get, ReservableCurrency},
+	traits::{Currency, ExistenceRequirement, Get, ReservableCurrency},
 };
 use frame_system::{self, ensure_root, ensure_signed};
 use polkadot_primitives::{
@@ -76,6 +76,31 @@ impl<Account, Balance> ParaInfo<Account, Balance> {
 	}
 }
 
+/// An offer to transfer the management of a para to another account.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	DecodeWithMemTracking,
+)]
+pub struct ParaTransferOffer<Account, Balance, BlockNumber> {
+	/// The manager who made the offer and holds `deposit` for it.
+	pub from: Account,
+	/// The account which may accept the offer and become the new manager.
+	pub to: Account,
+	/// The amount `to` pays to `from` when accepting the offer.
+	pub price: Balance,
+	/// The amount reserved by `from` for the offer.
+	pub deposit: Balance,
+	/// The block after which the offer can no longer be accepted.
+	pub expires_at: BlockNumber,
+}
+
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -87,6 +112,9 @@ pub trait WeightInfo {
 	fn swap() -> Weight;
 	fn schedule_code_upgrade(b: u32) -> Weight;
 	fn set_current_head(b: u32) -> Weight;
+	fn offer_para_transfer() -> Weight;
+	fn accept_para_transfer() -> Weight;
+	fn cancel_para_transfer() -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -112,6 +140,15 @@ impl WeightInfo for TestWeightInfo {
 	fn set_current_head(_b: u32) -> Weight {
 		Weight::zero()
 	}
+	fn offer_para_transfer() -> Weight {
+		Weight::zero()
+	}
+	fn accept_para_transfer() -> Weight {
+		Weight::zero()
+	}
+	fn cancel_para_transfer() -> Weight {
+		Weight::zero()
+	}
 }
 
 #[frame_support::pallet]
@@ -157,6 +194,15 @@ pub mod pallet {
 		#[pallet::constant]
 		type DataDepositPerByte: Get<BalanceOf<Self>>;
 
+		/// The deposit to be paid for an offer to transfer the management of a para.
+		#[pallet::constant]
+		type TransferOfferDeposit: Get<BalanceOf<Self>>;
+
+		/// The number of blocks for which an offer to transfer the management of a para can be
+		/// accepted.
+		#[pallet::constant]
+		type TransferOfferDuration: Get<BlockNumberFor<Self>>;
+
 		/// Weight Information for the Extrinsics in the Pallet
 		type WeightInfo: WeightInfo;
 	}
@@ -168,6 +214,9 @@ pub mod pallet {
 		Deregistered { para_id: ParaId },
 		Reserved { para_id: ParaId, who: T::AccountId },
 		Swapped { para_id: ParaId, other_id: ParaId },
+		TransferOffered { para_id: ParaId, to: T::AccountId, price: BalanceOf<T> },
+		Transferred { para_id: ParaId, from: T::AccountId, to: T::AccountId, price: BalanceOf<T> },
+		TransferOfferCancelled { para_id: ParaId },
 	}
 
 	#[pallet::error]
@@ -202,6 +251,17 @@ pub mod pallet {
 		/// Cannot perform a parachain slot / lifecycle swap. Check that the state of both paras
 		/// are correct for the swap to work.
 		CannotSwap,
+		/// There is no offer to transfer the management of this para.
+		NoTransferOffer,
+		/// The offer to transfer the management of this para has expired.
+		TransferOfferExpired,
+		/// The offer to transfer the management of this para has not expired yet and may only be
+		/// cancelled by the account which made it.
+		TransferOfferNotExpired,
+		/// The caller is not the recipient of the offer to transfer the management of this para.
+		NotTransferRecipient,
+		/// The price of the offer to transfer the management of this para does not match.
+		TransferPriceMismatch,
 	}
 
 	/// Pending swap operations.
@@ -220,6 +280,15 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextFreeParaId<T> = StorageValue<_, ParaId, ValueQuery>;
 
+	/// Pending offers to transfer the management of a para to another account.
+	#[pallet::storage]
+	pub type TransferOffers<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		ParaId,
+		ParaTransferOffer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		#[serde(skip)]
@@ -463,6 +532,130 @@ pub mod pallet {
 			polkadot_runtime_parachains::set_current_head::<T>(para, new_head);
 			Ok(())
 		}
+
+		/// Offer to transfer the management of a para to another account.
+		///
+		/// ## Arguments
+		/// - `origin`: Must be called by a `Signed` origin. Must be the manager of the unlocked
+		///   `para`.
+		/// - `para`: The para whose management should be transferred.
+		/// - `to`: The account which may accept the offer and become the new manager.
+		/// - `price`: The amount `to` has to pay to the current manager when accepting the offer.
+		///
+		/// Any previous offer for the `para` is replaced. The offer can be accepted for
+		/// `TransferOfferDuration` blocks.
+		///
+		/// ## Deposits/Fees
+		/// The origin must reserve a deposit of `TransferOfferDeposit` for the offer. It is
+		/// returned once the offer is accepted or cancelled.
+		///
+		/// ## Events
+		/// The `TransferOffered` event is emitted in case of success.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::offer_para_transfer())]
+		pub fn offer_para_transfer(
+			origin: OriginFor<T>,
+			para: ParaId,
+			to: T::AccountId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let info = Paras::<T>::get(para).ok_or(Error::<T>::NotRegistered)?;
+			ensure!(info.manager == who, Error::<T>::NotOwner);
+			ensure!(!info.is_locked(), Error::<T>::ParaLocked);
+
+			if let Some(previous) = TransferOffers::<T>::take(para) {
+				<T as Config>::Currency::unreserve(&previous.from, previous.deposit);
+			}
+			let deposit = T::TransferOfferDeposit::get();
+			<T as Config>::Currency::reserve(&who, deposit)?;
+
+			let expires_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::TransferOfferDuration::get());
+			let offer = ParaTransferOffer { from: who, to: to.clone(), price, deposit, expires_at };
+			TransferOffers::<T>::insert(para, offer);
+
+			Self::deposit_event(Event::<T>::TransferOffered { para_id: para, to, price });
+			Ok(())
+		}
+
+		/// Accept an offer to transfer the management of a para.
+		///
+		/// ## Arguments
+		/// - `origin`: Must be called by a `Signed` origin. Must be the recipient of the offer.
+		/// - `para`: The para whose management is offered.
+		/// - `price`: The price of the offer, guarding against it being changed in the meantime.
+		///
+		/// ## Deposits/Fees
+		/// The origin pays `price` to the current manager and reserves the registration deposit
+		/// of the para, which is returned to the current manager along with the offer deposit.
+		///
+		/// ## Events
+		/// The `Transferred` event is emitted in case of success.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_para_transfer())]
+		pub fn accept_para_transfer(
+			origin: OriginFor<T>,
+			para: ParaId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = TransferOffers::<T>::get(para).ok_or(Error::<T>::NoTransferOffer)?;
+			ensure!(offer.to == who, Error::<T>::NotTransferRecipient);
+			ensure!(offer.price == price, Error::<T>::TransferPriceMismatch);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= offer.expires_at,
+				Error::<T>::TransferOfferExpired
+			);
+			let mut info = Paras::<T>::get(para).ok_or(Error::<T>::NotRegistered)?;
+			// The para may have changed hands or been locked since the offer was made.
+			ensure!(info.manager == offer.from, Error::<T>::NotOwner);
+			ensure!(!info.is_locked(), Error::<T>::ParaLocked);
+
+			<T as Config>::Currency::transfer(
+				&who,
+				&offer.from,
+				price,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			<T as Config>::Currency::reserve(&who, info.deposit)?;
+			<T as Config>::Currency::unreserve(&offer.from, info.deposit);
+			<T as Config>::Currency::unreserve(&offer.from, offer.deposit);
+
+			info.manager = who.clone();
+			Paras::<T>::insert(para, info);
+			TransferOffers::<T>::remove(para);
+			PendingSwap::<T>::remove(para);
+
+			Self::deposit_event(Event::<T>::Transferred {
+				para_id: para,
+				from: offer.from,
+				to: who,
+				price,
+			});
+			Ok(())
+		}
+
+		/// Cancel an offer to transfer the management of a para and return its deposit.
+		///
+		/// Can be called by the account which made the offer at any time, or by any `Signed`
+		/// origin once the offer has expired.
+		///
+		/// ## Events
+		/// The `TransferOfferCancelled` event is emitted in case of success.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_para_transfer())]
+		pub fn cancel_para_transfer(origin: OriginFor<T>, para: ParaId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let offer = TransferOffers::<T>::get(para).ok_or(Error::<T>::NoTransferOffer)?;
+			ensure!(
+				offer.from == who || frame_system::Pallet::<T>::block_number() > offer.expires_at,
+				Error::<T>::TransferOfferNotExpired
+			);
+
+			Self::remove_transfer_offer(para);
+			Ok(())
+		}
 	}
 }
 
@@ -671,10 +864,19 @@ impl<T: Config> Pallet<T> {
 		}
 
 		PendingSwap::<T>::remove(id);
+		Self::remove_transfer_offer(id);
 		Self::deposit_event(Event::<T>::Deregistered { para_id: id });
 		Ok(())
 	}
 
+	/// Remove the offer to transfer the management of `id`, if any, returning its deposit.
+	fn remove_transfer_offer(id: ParaId) {
+		if let Some(offer) = TransferOffers::<T>::take(id) {
+			<T as Config>::Currency::unreserve(&offer.from, offer.deposit);
+			Self::deposit_event(Event::<T>::TransferOfferCancelled { para_id: id });
+		}
+	}
+
 	/// Verifies the onboarding data is valid for a para.
 	///
 	/// Returns `ParaGenesisArgs` and the deposit needed for the data.
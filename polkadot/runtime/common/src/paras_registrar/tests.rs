@@ -586,3 +586,91 @@ fn swap_handles_bad_states() {
 		assert!(Parachains::is_parathread(para_2));
 	});
 }
+
+#[test]
+fn para_transfer_works() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(mock::Registrar::reserve(RuntimeOrigin::signed(1)));
+		let para_id = LOWEST_PUBLIC_ID;
+		assert_ok!(mock::Registrar::register(
+			RuntimeOrigin::signed(1),
+			para_id,
+			vec![1; 3].into(),
+			test_validation_code(32)
+		));
+		let deposit = Paras::<Test>::get(para_id).unwrap().deposit;
+		let offer_deposit = <Test as Config>::TransferOfferDeposit::get();
+
+		assert_ok!(mock::Registrar::offer_para_transfer(RuntimeOrigin::signed(1), para_id, 2, 100));
+		assert_eq!(Balances::reserved_balance(&1), deposit + offer_deposit);
+		assert_noop!(
+			mock::Registrar::accept_para_transfer(RuntimeOrigin::signed(3), para_id, 100),
+			Error::<Test>::NotTransferRecipient
+		);
+		assert_noop!(
+			mock::Registrar::accept_para_transfer(RuntimeOrigin::signed(2), para_id, 50),
+			Error::<Test>::TransferPriceMismatch
+		);
+
+		let (free_1, free_2) = (Balances::free_balance(&1), Balances::free_balance(&2));
+		assert_ok!(mock::Registrar::accept_para_transfer(RuntimeOrigin::signed(2), para_id, 100));
+		System::assert_last_event(
+			Event::<Test>::Transferred { para_id, from: 1, to: 2, price: 100 }.into(),
+		);
+
+		// The registration deposit is now held by the new manager, who paid the price.
+		assert_eq!(Paras::<Test>::get(para_id).unwrap().manager, 2);
+		assert!(TransferOffers::<Test>::get(para_id).is_none());
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(Balances::free_balance(&1), free_1 + deposit + offer_deposit + 100);
+		assert_eq!(Balances::reserved_balance(&2), deposit);
+		assert_eq!(Balances::free_balance(&2), free_2 - deposit - 100);
+	});
+}
+
+#[test]
+fn para_transfer_handles_basic_errors() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let para_id = LOWEST_PUBLIC_ID;
+		assert_noop!(
+			mock::Registrar::offer_para_transfer(RuntimeOrigin::signed(1), para_id, 2, 100),
+			Error::<Test>::NotRegistered
+		);
+		assert_ok!(mock::Registrar::reserve(RuntimeOrigin::signed(1)));
+		assert_noop!(
+			mock::Registrar::offer_para_transfer(RuntimeOrigin::signed(2), para_id, 3, 100),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			mock::Registrar::accept_para_transfer(RuntimeOrigin::signed(2), para_id, 100),
+			Error::<Test>::NoTransferOffer
+		);
+
+		assert_ok!(mock::Registrar::offer_para_transfer(RuntimeOrigin::signed(1), para_id, 2, 100));
+		// Only the account which made the offer may cancel it before it expires.
+		assert_noop!(
+			mock::Registrar::cancel_para_transfer(RuntimeOrigin::signed(3), para_id),
+			Error::<Test>::TransferOfferNotExpired
+		);
+
+		run_to_block(1 + <Test as Config>::TransferOfferDuration::get() + 1);
+		assert_noop!(
+			mock::Registrar::accept_para_transfer(RuntimeOrigin::signed(2), para_id, 100),
+			Error::<Test>::TransferOfferExpired
+		);
+		assert_ok!(mock::Registrar::cancel_para_transfer(RuntimeOrigin::signed(3), para_id));
+		System::assert_last_event(Event::<Test>::TransferOfferCancelled { para_id }.into());
+		assert_eq!(Balances::reserved_balance(&1), <Test as Config>::ParaDeposit::get());
+
+		// The manager cannot offer a locked para.
+		mock::Registrar::apply_lock(para_id);
+		assert_noop!(
+			mock::Registrar::offer_para_transfer(RuntimeOrigin::signed(1), para_id, 2, 100),
+			Error::<Test>::ParaLocked
+		);
+	});
+}
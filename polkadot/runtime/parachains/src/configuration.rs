@@ -20,7 +20,7 @@
 
 use crate::shared;
 use alloc::vec::Vec;
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeWithMemTracking, Encode};
 use frame_support::{pallet_prelude::*, DefaultNoBound};
 use frame_system::pallet_prelude::*;
 use polkadot_parachain_primitives::primitives::{
@@ -50,6 +50,9 @@ const LOG_TARGET: &str = "runtime::configuration";
 // `polkadot_node_network_protocol::POV_RESPONSE_SIZE`.
 const POV_SIZE_HARD_LIMIT: u32 = 16 * 1024 * 1024;
 
+/// The maximum number of sessions for which configuration changes can be staged.
+pub const MAX_STAGED_CONFIG_CHANGES: u32 = 16;
+
 // The maximum compression ratio that we use to compute the maximum uncompressed code size.
 pub(crate) const MAX_VALIDATION_CODE_COMPRESSION_RATIO: u32 = 10;
 
@@ -304,6 +307,96 @@ impl<BlockNumber: Default + From<u32>> HostConfiguration<BlockNumber> {
 	}
 }
 
+/// Defines [`HostConfigurationDiff`] with an optional field for each field of
+/// [`HostConfiguration`].
+macro_rules! host_configuration_diff {
+	($($field:ident: $ty:ty,)*) => {
+		/// A change of some fields of a [`HostConfiguration`].
+		///
+		/// Fields which are `None` are left as they are.
+		#[derive(
+			Clone,
+			Encode,
+			Decode,
+			DecodeWithMemTracking,
+			PartialEq,
+			DefaultNoBound,
+			sp_core::RuntimeDebug,
+			scale_info::TypeInfo,
+		)]
+		pub struct HostConfigurationDiff<BlockNumber> {
+			$(pub $field: Option<$ty>,)*
+		}
+
+		impl<BlockNumber: Clone + PartialEq> HostConfigurationDiff<BlockNumber> {
+			/// The fields of `new` which differ from `old`.
+			pub fn between(
+				old: &HostConfiguration<BlockNumber>,
+				new: &HostConfiguration<BlockNumber>,
+			) -> Self {
+				Self { $($field: (old.$field != new.$field).then(|| new.$field.clone()),)* }
+			}
+
+			/// Whether no field is changed.
+			pub fn is_empty(&self) -> bool {
+				true $(&& self.$field.is_none())*
+			}
+
+			/// Add the changes of `other`, which take precedence over the changes of `self`.
+			pub fn merge(&mut self, other: Self) {
+				$(if other.$field.is_some() {
+					self.$field = other.$field;
+				})*
+			}
+
+			/// Apply the changes to `config`.
+			pub fn apply_to(self, config: &mut HostConfiguration<BlockNumber>) {
+				$(if let Some(value) = self.$field {
+					config.$field = value;
+				})*
+			}
+		}
+	};
+}
+
+host_configuration_diff! {
+	max_code_size: u32,
+	max_head_data_size: u32,
+	max_upward_queue_count: u32,
+	max_upward_queue_size: u32,
+	max_upward_message_size: u32,
+	max_upward_message_num_per_candidate: u32,
+	hrmp_max_message_num_per_candidate: u32,
+	validation_upgrade_cooldown: BlockNumber,
+	validation_upgrade_delay: BlockNumber,
+	async_backing_params: AsyncBackingParams,
+	max_pov_size: u32,
+	max_downward_message_size: u32,
+	hrmp_max_parachain_outbound_channels: u32,
+	hrmp_sender_deposit: Balance,
+	hrmp_recipient_deposit: Balance,
+	hrmp_channel_max_capacity: u32,
+	hrmp_channel_max_total_size: u32,
+	hrmp_max_parachain_inbound_channels: u32,
+	hrmp_channel_max_message_size: u32,
+	executor_params: ExecutorParams,
+	code_retention_period: BlockNumber,
+	max_validators: Option<u32>,
+	dispute_period: SessionIndex,
+	dispute_post_conclusion_acceptance_period: BlockNumber,
+	no_show_slots: u32,
+	n_delay_tranches: u32,
+	zeroth_delay_tranche_width: u32,
+	needed_approvals: u32,
+	relay_vrf_modulo_samples: u32,
+	pvf_voting_ttl: SessionIndex,
+	minimum_validation_upgrade_delay: BlockNumber,
+	minimum_backing_votes: u32,
+	node_features: NodeFeatures,
+	approval_voting_params: ApprovalVotingParams,
+	scheduler_params: SchedulerParams<BlockNumber>,
+}
+
 /// Enumerates the possible inconsistencies of `HostConfiguration`.
 #[derive(Debug)]
 pub enum InconsistentError<BlockNumber> {
@@ -544,6 +637,18 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// The new value for a configuration parameter is invalid.
 		InvalidNewValue,
+		/// The activation session of staged configuration changes is before the scheduled
+		/// session.
+		ActivationSessionTooEarly,
+		/// There are too many sessions with staged configuration changes.
+		TooManyStagedConfigChanges,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The configuration changes staged for `session` were rejected when they became due.
+		StagedConfigChangesDiscarded { session: SessionIndex },
 	}
 
 	/// The active configuration for the current session.
@@ -568,6 +673,19 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type BypassConsistencyCheck<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// Configuration changes staged for a session further in the future than the scheduled
+	/// session.
+	///
+	/// The list is sorted ascending by session index and holds at most
+	/// [`MAX_STAGED_CONFIG_CHANGES`] entries. The changes for a session are scheduled as a whole
+	/// once it becomes the scheduled session.
+	#[pallet::storage]
+	pub type StagedConfigChanges<T: Config> = StorageValue<
+		_,
+		Vec<(SessionIndex, HostConfigurationDiff<BlockNumberFor<T>>)>,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -1245,6 +1363,47 @@ pub mod pallet {
 				config.scheduler_params = new;
 			})
 		}
+
+		/// Stage configuration changes to be applied together at `activation_session`.
+		///
+		/// The `changes` are applied on top of whatever configuration is pending once
+		/// `activation_session` becomes the scheduled session. If the resulting configuration is
+		/// inconsistent at that point, all of them are discarded and
+		/// [`Event::StagedConfigChangesDiscarded`] is emitted. Changes staged for the same session
+		/// are merged, with later ones taking precedence.
+		#[pallet::call_index(56)]
+		#[pallet::weight((
+			T::WeightInfo::set_config_with_executor_params()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+			DispatchClass::Operational,
+		))]
+		pub fn stage_config_changes(
+			origin: OriginFor<T>,
+			activation_session: SessionIndex,
+			changes: HostConfigurationDiff<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let scheduled_session = Self::scheduled_session();
+			ensure!(activation_session >= scheduled_session, Error::<T>::ActivationSessionTooEarly);
+
+			if activation_session == scheduled_session {
+				return Self::schedule_config_update(|config| changes.apply_to(config))
+			}
+
+			StagedConfigChanges::<T>::try_mutate(|staged| {
+				match staged.binary_search_by_key(&activation_session, |(session, _)| *session) {
+					Ok(i) => staged[i].1.merge(changes),
+					Err(i) => {
+						ensure!(
+							staged.len() < MAX_STAGED_CONFIG_CHANGES as usize,
+							Error::<T>::TooManyStagedConfigChanges
+						);
+						staged.insert(i, (activation_session, changes));
+					},
+				}
+				Ok(())
+			})
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -1258,6 +1417,33 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// Returns the changes of the pending configurations together with the sessions at which
+		/// they will become active.
+		///
+		/// Each change is relative to the configuration that is active right before it.
+		pub fn pending_config_changes(
+		) -> Vec<(SessionIndex, HostConfigurationDiff<BlockNumberFor<T>>)> {
+			let mut previous = ActiveConfig::<T>::get();
+			PendingConfigs::<T>::get()
+				.into_iter()
+				.map(|(session, config)| {
+					let changes = HostConfigurationDiff::between(&previous, &config);
+					previous = config;
+					(session, changes)
+				})
+				.collect()
+		}
+
+		/// Returns the staged configuration changes together with the sessions at which they
+		/// will become active.
+		pub fn staged_config_changes(
+		) -> Vec<(SessionIndex, HostConfigurationDiff<BlockNumberFor<T>>)> {
+			StagedConfigChanges::<T>::get()
+		}
+	}
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn integrity_test() {
@@ -1302,6 +1488,7 @@ impl<T: Config> Pallet<T> {
 
 		// No pending configuration changes, so we're done.
 		if pending_configs.is_empty() {
+			Self::schedule_staged_config_changes(session_index);
 			return SessionChangeOutcome { prev_config, new_config: None }
 		}
 
@@ -1325,10 +1512,59 @@ impl<T: Config> Pallet<T> {
 		}
 
 		PendingConfigs::<T>::put(future);
+		Self::schedule_staged_config_changes(session_index);
 
 		SessionChangeOutcome { prev_config, new_config }
 	}
 
+	/// Schedule the staged configuration changes whose activation session becomes the scheduled
+	/// session once `session_index` has started.
+	///
+	/// The weight is registered as mandatory, since this is called on session change.
+	fn schedule_staged_config_changes(session_index: &SessionIndex) {
+		let scheduled_session = session_index.saturating_add(shared::SESSION_DELAY);
+		let mut staged = StagedConfigChanges::<T>::get();
+		let due = staged.iter().take_while(|&&(session, _)| session <= scheduled_session).count();
+		let mut weight = T::DbWeight::get().reads(1);
+
+		for (activation_session, changes) in staged.drain(..due) {
+			if activation_session < scheduled_session {
+				// This should never happen since staged changes are scheduled when their session
+				// becomes the scheduled session and this handler is called for each session change.
+				log::error!(
+					target: LOG_TARGET,
+					"Skipping staged configuration changes for past session {}",
+					activation_session,
+				);
+				continue
+			}
+			weight.saturating_accrue(T::WeightInfo::set_config_with_executor_params());
+			let result = Self::schedule_config_update_at(scheduled_session, |config| {
+				changes.apply_to(config)
+			});
+			if let Err(e) = result {
+				log::warn!(
+					target: LOG_TARGET,
+					"Discarding staged configuration changes for session {}: {:?}",
+					activation_session,
+					e,
+				);
+				Self::deposit_event(Event::StagedConfigChangesDiscarded {
+					session: activation_session,
+				});
+			}
+		}
+
+		if due > 0 {
+			StagedConfigChanges::<T>::put(staged);
+			weight.saturating_accrue(T::DbWeight::get().writes(1));
+		}
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			weight,
+			DispatchClass::Mandatory,
+		);
+	}
+
 	/// Return the session index that should be used for any future scheduled changes.
 	fn scheduled_session() -> SessionIndex {
 		shared::Pallet::<T>::scheduled_session()
@@ -1354,12 +1590,23 @@ impl<T: Config> Pallet<T> {
 	/// In other words, all the configuration changes made in the same session will be folded
 	/// together in the order they were made, and only once the scheduled session is reached will
 	/// the final pending configuration be applied.
+	pub(crate) fn schedule_config_update(
+		updater: impl FnOnce(&mut HostConfiguration<BlockNumberFor<T>>),
+	) -> DispatchResult {
+		Self::schedule_config_update_at(Self::scheduled_session(), updater)
+	}
+
+	/// Like [`Self::schedule_config_update`], but for an explicitly given `scheduled_session`.
+	///
+	/// This is used on session change, where the scheduled session is derived from the session
+	/// that is about to start.
 	// NOTE: Explicitly tell rustc not to inline this, because otherwise heuristics note the
 	// incoming closure make it attractive to inline. However, in that case, we will end up with
 	// lots of duplicated code (making this function show up on top of the heaviest functions) only
 	// for the sake of essentially avoiding an indirect call. It is not worth it.
 	#[inline(never)]
-	pub(crate) fn schedule_config_update(
+	fn schedule_config_update_at(
+		scheduled_session: SessionIndex,
 		updater: impl FnOnce(&mut HostConfiguration<BlockNumberFor<T>>),
 	) -> DispatchResult {
 		let mut pending_configs = PendingConfigs::<T>::get();
@@ -1433,8 +1680,6 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
-		let scheduled_session = Self::scheduled_session();
-
 		if let Some(&mut (_, ref mut config)) = pending_configs
 			.iter_mut()
 			.find(|&&mut (apply_at_session, _)| apply_at_session >= scheduled_session)
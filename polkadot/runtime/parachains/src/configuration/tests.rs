@@ -490,6 +490,148 @@ fn setting_pending_config_members() {
 	})
 }
 
+#[test]
+fn staged_config_changes_are_applied_together_at_activation_session() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let old_config = configuration::ActiveConfig::<Test>::get();
+
+		assert_ok!(Configuration::stage_config_changes(
+			RuntimeOrigin::root(),
+			5,
+			HostConfigurationDiff { validation_upgrade_delay: Some(100), ..Default::default() },
+		));
+		// Changes staged for the same session are merged.
+		assert_ok!(Configuration::stage_config_changes(
+			RuntimeOrigin::root(),
+			5,
+			HostConfigurationDiff { minimum_backing_votes: Some(5), ..Default::default() },
+		));
+		assert_ok!(Configuration::set_max_validators(RuntimeOrigin::root(), Some(10)));
+		assert_eq!(
+			Configuration::staged_config_changes(),
+			vec![(
+				5,
+				HostConfigurationDiff {
+					validation_upgrade_delay: Some(100),
+					minimum_backing_votes: Some(5),
+					..Default::default()
+				}
+			)],
+		);
+
+		// The regular change becomes active at the scheduled session, the staged ones don't.
+		on_new_session(1);
+		let (_, new_config) = on_new_session(2);
+		let expected = HostConfiguration { max_validators: Some(10), ..old_config };
+		assert_eq!(new_config, expected);
+		assert!(PendingConfigs::<Test>::get().is_empty());
+
+		// The staged changes are scheduled once session 5 becomes the scheduled session.
+		on_new_session(3);
+		assert_eq!(
+			Configuration::pending_config_changes(),
+			vec![(
+				5,
+				HostConfigurationDiff {
+					validation_upgrade_delay: Some(100),
+					minimum_backing_votes: Some(5),
+					..Default::default()
+				}
+			)],
+		);
+		assert!(StagedConfigChanges::<Test>::get().is_empty());
+
+		let (_, new_config) = on_new_session(4);
+		assert_eq!(new_config.validation_upgrade_delay, 2);
+		let (_, new_config) = on_new_session(5);
+		assert_eq!(
+			new_config,
+			HostConfiguration { validation_upgrade_delay: 100, minimum_backing_votes: 5, ..expected }
+		);
+	});
+}
+
+#[test]
+fn staged_config_changes_are_discarded_together() {
+	new_test_ext(Default::default()).execute_with(|| {
+		frame_system::Pallet::<Test>::set_block_number(1);
+		let invalid_changes = HostConfigurationDiff {
+			minimum_backing_votes: Some(5),
+			max_code_size: Some(MAX_CODE_SIZE + 1),
+			..Default::default()
+		};
+		assert_ok!(Configuration::stage_config_changes(
+			RuntimeOrigin::root(),
+			3,
+			invalid_changes.clone(),
+		));
+
+		on_new_session(1);
+		assert!(PendingConfigs::<Test>::get().is_empty());
+		assert!(StagedConfigChanges::<Test>::get().is_empty());
+		frame_system::Pallet::<Test>::assert_last_event(
+			Event::<Test>::StagedConfigChangesDiscarded { session: 3 }.into(),
+		);
+
+		// Staging for the scheduled session applies the changes right away.
+		assert_err!(
+			Configuration::stage_config_changes(RuntimeOrigin::root(), 3, invalid_changes),
+			Error::<Test>::InvalidNewValue
+		);
+		assert!(PendingConfigs::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn cannot_stage_invalid_config_changes() {
+	new_test_ext(Default::default()).execute_with(|| {
+		let changes =
+			HostConfigurationDiff { minimum_backing_votes: Some(5), ..Default::default() };
+		on_new_session(1);
+		assert_err!(
+			Configuration::stage_config_changes(RuntimeOrigin::root(), 2, changes.clone()),
+			Error::<Test>::ActivationSessionTooEarly
+		);
+		for session in 0..MAX_STAGED_CONFIG_CHANGES {
+			assert_ok!(Configuration::stage_config_changes(
+				RuntimeOrigin::root(),
+				4 + session,
+				changes.clone(),
+			));
+		}
+		assert_err!(
+			Configuration::stage_config_changes(
+				RuntimeOrigin::root(),
+				4 + MAX_STAGED_CONFIG_CHANGES,
+				changes.clone(),
+			),
+			Error::<Test>::TooManyStagedConfigChanges
+		);
+		assert!(Configuration::stage_config_changes(RuntimeOrigin::signed(1), 5, changes).is_err());
+	});
+}
+
+#[test]
+fn host_configuration_diff_round_trips() {
+	let old = HostConfiguration::<u32>::default();
+	let new = HostConfiguration { max_code_size: 42, max_validators: Some(3), ..old.clone() };
+
+	let diff = HostConfigurationDiff::between(&old, &new);
+	assert_eq!(
+		diff,
+		HostConfigurationDiff {
+			max_code_size: Some(42),
+			max_validators: Some(Some(3)),
+			..Default::default()
+		}
+	);
+	assert!(HostConfigurationDiff::between(&old, &old).is_empty());
+
+	let mut config = old;
+	diff.apply_to(&mut config);
+	assert_eq!(config, new);
+}
+
 #[test]
 fn non_root_cannot_set_config() {
 	new_test_ext(Default::default()).execute_with(|| {
@@ -48,8 +48,17 @@
 //! chain, without `FullIdentification` of the offenders. Later on, a block
 //! producer can submit an unsigned transaction with `KeyOwnershipProof` of an
 //! offender and submit it to the runtime to produce an offence.
+//!
+//! Exoneration:
+//!
+//! If the outcome of a dispute turns out to be wrong, for instance because of a bug in candidate
+//! validation, `ExonerateOrigin` can exonerate its losers. Their pending slashes are dropped, so
+//! they can no longer be reported. The validators whose offences have already been reported are
+//! compensated through `SlashCompensation`. Reported offences are remembered for
+//! `ExonerationPeriod` sessions.
 
 use crate::{disputes, initializer::ValidatorSetCount, session_info::IdentificationTuple};
+use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::Pays,
 	traits::{Defensive, Get, KeyOwnerProofSystem, ValidatorSet, ValidatorSetWithIdentification},
@@ -96,6 +105,9 @@ const DEFENSIVE_PROOF: &'static str = "disputes module should bail on old sessio
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 
+#[cfg(test)]
+mod tests;
+
 /// The benchmarking configuration.
 pub trait BenchmarkingConfiguration {
 	const MAX_VALIDATORS: u32;
@@ -163,15 +175,59 @@ impl<KeyOwnerIdentification> SlashingOffence<KeyOwnerIdentification> {
 		kind: DisputeOffenceKind,
 	) -> Self {
 		let time_slot = DisputesTimeSlot::new(session_index, candidate_hash);
-		let slash_fraction = match kind {
-			DisputeOffenceKind::ForInvalidBacked => SLASH_FOR_INVALID_BACKED,
-			DisputeOffenceKind::ForInvalidApproved => SLASH_FOR_INVALID_APPROVED,
-			DisputeOffenceKind::AgainstValid => SLASH_AGAINST_VALID,
-		};
+		let slash_fraction = slash_fraction(kind);
 		Self { time_slot, validator_set_count, offenders, slash_fraction, kind }
 	}
 }
 
+/// The fraction of the total exposure that is slashed for an offence of the given kind.
+fn slash_fraction(kind: DisputeOffenceKind) -> Perbill {
+	match kind {
+		DisputeOffenceKind::ForInvalidBacked => SLASH_FOR_INVALID_BACKED,
+		DisputeOffenceKind::ForInvalidApproved => SLASH_FOR_INVALID_APPROVED,
+		DisputeOffenceKind::AgainstValid => SLASH_AGAINST_VALID,
+	}
+}
+
+/// A validator whose offence for losing a dispute has been reported to the staking system.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct ReportedOffender<KeyOwnerIdentification> {
+	/// The index of the validator in the session of the dispute.
+	pub validator_index: ValidatorIndex,
+	/// Staking information about the validator, as it was reported.
+	pub offender: KeyOwnerIdentification,
+	/// The type of the reported offence.
+	pub kind: DisputeOffenceKind,
+}
+
+/// Compensates validators for the slashes they received for a dispute they have been exonerated
+/// of.
+pub trait CompensateSlashes<KeyOwnerIdentification> {
+	/// Compensate the `offenders`, which have been reported for slashing by `slash_fraction` of
+	/// their exposure in `session_index`.
+	fn compensate(
+		session_index: SessionIndex,
+		offenders: &[KeyOwnerIdentification],
+		slash_fraction: Perbill,
+	);
+
+	/// The weight of compensating `offenders` validators.
+	fn weight(offenders: u32) -> Weight;
+}
+
+impl<KeyOwnerIdentification> CompensateSlashes<KeyOwnerIdentification> for () {
+	fn compensate(
+		_session_index: SessionIndex,
+		_offenders: &[KeyOwnerIdentification],
+		_slash_fraction: Perbill,
+	) {
+	}
+
+	fn weight(_offenders: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
 /// This type implements `SlashingHandler`.
 pub struct SlashValidatorsForDisputes<C> {
 	_phantom: core::marker::PhantomData<C>,
@@ -192,7 +248,7 @@ where
 	fn maybe_identify_validators(
 		session_index: SessionIndex,
 		validators: impl IntoIterator<Item = ValidatorIndex>,
-	) -> Option<Vec<IdentificationTuple<T>>> {
+	) -> Option<Vec<(ValidatorIndex, IdentificationTuple<T>)>> {
 		// We use `ValidatorSet::session_index` and not
 		// `shared::CurrentSessionIndex::<T>::get()` because at the first block of a new era,
 		// the `IdentificationOf` of a validator in the previous session might be
@@ -205,13 +261,13 @@ where
 
 			let fully_identified = validators
 				.into_iter()
-				.flat_map(|i| account_ids.get(i.0 as usize).cloned())
-				.filter_map(|id| {
+				.flat_map(|i| account_ids.get(i.0 as usize).cloned().map(|id| (i, id)))
+				.filter_map(|(i, id)| {
 					<T::ValidatorSet as ValidatorSetWithIdentification<T::AccountId>>::IdentificationOf::convert(
 						id.clone()
-					).map(|full_id| (id, full_id))
+					).map(|full_id| (i, (id, full_id)))
 				})
-				.collect::<Vec<IdentificationTuple<T>>>();
+				.collect::<Vec<(ValidatorIndex, IdentificationTuple<T>)>>();
 			return Some(fully_identified)
 		}
 		None
@@ -241,12 +297,14 @@ where
 				session_index,
 				candidate_hash,
 				validator_set_count,
-				offenders,
+				offenders.iter().map(|(_, offender)| offender.clone()).collect(),
 				kind,
 			);
 			// This is the first time we report an offence for this dispute,
 			// so it is not a duplicate.
-			let _ = T::HandleReports::report_offence(offence);
+			if T::HandleReports::report_offence(offence).is_ok() {
+				Pallet::<T>::note_reported(session_index, candidate_hash, kind, offenders);
+			}
 			return
 		}
 
@@ -383,6 +441,7 @@ impl<T: Config> HandleReports<T> for () {
 
 pub trait WeightInfo {
 	fn report_dispute_lost_unsigned(validator_count: ValidatorSetCount) -> Weight;
+	fn exonerate(validator_count: ValidatorSetCount) -> Weight;
 }
 
 pub struct TestWeightInfo;
@@ -390,6 +449,9 @@ impl WeightInfo for TestWeightInfo {
 	fn report_dispute_lost_unsigned(_validator_count: ValidatorSetCount) -> Weight {
 		Weight::zero()
 	}
+	fn exonerate(_validator_count: ValidatorSetCount) -> Weight {
+		Weight::zero()
+	}
 }
 
 pub use pallet::*;
@@ -430,6 +492,36 @@ pub mod pallet {
 
 		/// Benchmarking configuration.
 		type BenchmarkingConfig: BenchmarkingConfiguration;
+
+		/// The origin which can exonerate the losers of a dispute.
+		type ExonerateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Compensates exonerated validators whose offences have already been reported.
+		type SlashCompensation: CompensateSlashes<Self::KeyOwnerIdentification>;
+
+		/// The number of sessions for which reported offences are remembered, so that their
+		/// offenders can be compensated if they are exonerated.
+		///
+		/// When using the staking pallet this should cover the slash deferral duration.
+		#[pallet::constant]
+		type ExonerationPeriod: Get<SessionIndex>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The losers of a dispute have been exonerated. Their pending slashes have been dropped
+		/// and their reported slashes compensated.
+		Exonerated {
+			/// The session of the dispute.
+			session_index: SessionIndex,
+			/// The candidate of the dispute.
+			candidate_hash: CandidateHash,
+			/// The validators whose pending slashes have been dropped.
+			validators: Vec<ValidatorIndex>,
+			/// The validators whose reported slashes have been compensated.
+			compensated: Vec<ValidatorIndex>,
+		},
 	}
 
 	#[pallet::pallet]
@@ -447,6 +539,19 @@ pub mod pallet {
 		PendingSlashesV2,
 	>;
 
+	/// Validators whose offences for losing a dispute have been reported, kept for
+	/// `ExonerationPeriod` sessions.
+	#[pallet::storage]
+	pub(crate) type ReportedSlashes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Blake2_128Concat,
+		CandidateHash,
+		Vec<ReportedOffender<T::KeyOwnerIdentification>>,
+		ValueQuery,
+	>;
+
 	/// `ValidatorSetCount` per session.
 	#[pallet::storage]
 	pub(super) type ValidatorSetCounts<T> =
@@ -467,6 +572,8 @@ pub mod pallet {
 		ValidatorIndexIdMismatch,
 		/// The given slashing report is valid but already previously reported.
 		DuplicateSlashingReport,
+		/// There are no pending or reported slashes for the given dispute.
+		NoSlashes,
 	}
 
 	#[pallet::call]
@@ -488,6 +595,8 @@ pub mod pallet {
 				(polkadot_primitives::PARACHAIN_KEY_TYPE_ID, dispute_proof.validator_id.clone());
 			let offender = T::KeyOwnerProofSystem::check_proof(key, key_owner_proof)
 				.ok_or(Error::<T>::InvalidKeyOwnershipProof)?;
+			let validator_index = dispute_proof.validator_index;
+			let kind = dispute_proof.kind;
 
 			let session_index = dispute_proof.time_slot.session_index;
 
@@ -524,15 +633,74 @@ pub mod pallet {
 				session_index,
 				candidate_hash,
 				validator_set_count,
-				vec![offender],
-				dispute_proof.kind,
+				vec![offender.clone()],
+				kind,
 			);
 
 			<T::HandleReports as HandleReports<T>>::report_offence(offence)
 				.map_err(|_| Error::<T>::DuplicateSlashingReport)?;
+			Self::note_reported(session_index, candidate_hash, kind, [(validator_index, offender)]);
 
 			Ok(Pays::No.into())
 		}
+
+		/// Exonerate the validators who lost the dispute about `candidate_hash` in
+		/// `session_index`.
+		///
+		/// Drops all slashes of the dispute which have not been reported yet, and compensates the
+		/// validators whose offences have been reported within the last `ExonerationPeriod`
+		/// sessions through `SlashCompensation`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::exonerate(
+			T::BenchmarkingConfig::MAX_VALIDATORS
+		).saturating_add(T::SlashCompensation::weight(T::BenchmarkingConfig::MAX_VALIDATORS)))]
+		pub fn exonerate(
+			origin: OriginFor<T>,
+			session_index: SessionIndex,
+			candidate_hash: CandidateHash,
+		) -> DispatchResultWithPostInfo {
+			T::ExonerateOrigin::ensure_origin(origin)?;
+
+			let pending = <UnappliedSlashes<T>>::take(session_index, candidate_hash);
+			let reported = <ReportedSlashes<T>>::take(session_index, candidate_hash);
+			ensure!(pending.is_some() || !reported.is_empty(), Error::<T>::NoSlashes);
+
+			let validators: Vec<_> =
+				pending.map(|pending| pending.keys.into_keys().collect()).unwrap_or_default();
+			for kind in [
+				DisputeOffenceKind::ForInvalidBacked,
+				DisputeOffenceKind::ForInvalidApproved,
+				DisputeOffenceKind::AgainstValid,
+			] {
+				let offenders: Vec<_> = reported
+					.iter()
+					.filter(|reported| reported.kind == kind)
+					.map(|reported| reported.offender.clone())
+					.collect();
+				if !offenders.is_empty() {
+					T::SlashCompensation::compensate(
+						session_index,
+						&offenders,
+						slash_fraction(kind),
+					);
+				}
+			}
+			let compensated: Vec<_> =
+				reported.iter().map(|reported| reported.validator_index).collect();
+
+			let weight = <T as Config>::WeightInfo::exonerate(
+				(validators.len() + compensated.len()) as ValidatorSetCount,
+			)
+			.saturating_add(T::SlashCompensation::weight(compensated.len() as u32));
+			Self::deposit_event(Event::Exonerated {
+				session_index,
+				candidate_hash,
+				validators,
+				compensated,
+			});
+
+			Ok(Some(weight).into())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -564,6 +732,12 @@ impl<T: Config> Pallet<T> {
 		// fine.
 		const REMOVE_LIMIT: u32 = u32::MAX;
 
+		if let Some(old_session) =
+			session_index.checked_sub(T::ExonerationPeriod::get().saturating_add(1))
+		{
+			let _ = <ReportedSlashes<T>>::clear_prefix(old_session, REMOVE_LIMIT, None);
+		}
+
 		let config = crate::configuration::ActiveConfig::<T>::get();
 		if session_index <= config.dispute_period + 1 {
 			return
@@ -573,6 +747,21 @@ impl<T: Config> Pallet<T> {
 		let _ = <UnappliedSlashes<T>>::clear_prefix(old_session, REMOVE_LIMIT, None);
 	}
 
+	/// Remember the `offenders` reported for the dispute about `candidate_hash` in
+	/// `session_index`, so that they can be compensated if they are exonerated.
+	fn note_reported(
+		session_index: SessionIndex,
+		candidate_hash: CandidateHash,
+		kind: DisputeOffenceKind,
+		offenders: impl IntoIterator<Item = (ValidatorIndex, T::KeyOwnerIdentification)>,
+	) {
+		<ReportedSlashes<T>>::mutate(session_index, candidate_hash, |reported| {
+			reported.extend(offenders.into_iter().map(|(validator_index, offender)| {
+				ReportedOffender { validator_index, offender, kind }
+			}))
+		});
+	}
+
 	pub(crate) fn unapplied_slashes() -> Vec<(SessionIndex, CandidateHash, PendingSlashesV1)> {
 		// Converting UnappliedSlashes to use the old SlashingOffence enum
 		// instead of the new DisputeOffenceKind enum to maintain the same
@@ -19,7 +19,7 @@ use super::*;
 use crate::{disputes::SlashingHandler, initializer, shared};
 use codec::Decode;
 use frame_benchmarking::v2::*;
-use frame_support::traits::{OnFinalize, OnInitialize};
+use frame_support::traits::{EnsureOrigin, OnFinalize, OnInitialize};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use pallet_staking::testing_utils::create_validators;
 use polkadot_primitives::{Hash, PARACHAIN_KEY_TYPE_ID};
@@ -165,4 +165,25 @@ mod benchmarks {
 		let unapplied = <UnappliedSlashes<T>>::get(session_index, CANDIDATE_HASH);
 		assert!(unapplied.is_none());
 	}
+
+	#[benchmark]
+	fn exonerate(n: Linear<1, { max_validators_for::<T>() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::ExonerateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let session_index = 1;
+		let validator_id = ValidatorId::decode(&mut &[0u8; 32][..]).expect("32 bytes are a key");
+		let keys = (0..n).map(|i| (ValidatorIndex(i), validator_id.clone())).collect();
+		let kind = DisputeOffenceKind::ForInvalidBacked;
+		<UnappliedSlashes<T>>::insert(
+			session_index,
+			CANDIDATE_HASH,
+			PendingSlashesV2 { keys, kind },
+		);
+
+		#[extrinsic_call]
+		_(origin as <T as frame_system::Config>::RuntimeOrigin, session_index, CANDIDATE_HASH);
+
+		assert!(<UnappliedSlashes<T>>::get(session_index, CANDIDATE_HASH).is_none());
+		Ok(())
+	}
 }
@@ -0,0 +1,159 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::{
+	compensated_slashes, new_test_ext, MockGenesisConfig, ParasSlashing, RuntimeEvent,
+	RuntimeOrigin, System, Test, REPORTED_OFFENCES,
+};
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResultWithPostInfo};
+use polkadot_primitives::Hash;
+use sp_core::sr25519;
+use sp_runtime::traits::BadOrigin;
+
+const SESSION: SessionIndex = 1;
+const CANDIDATE_HASH: CandidateHash = CandidateHash(Hash::repeat_byte(1));
+
+/// The key of the validator with the account `account`, see `TestKeyOwnerProofSystem`.
+fn validator_id(account: u8) -> ValidatorId {
+	sr25519::Public::from_raw([account; 32]).into()
+}
+
+/// Note a pending slash of `kind` for each of the `validators`.
+fn note_pending(kind: DisputeOffenceKind, validators: &[u8]) {
+	let keys = validators
+		.iter()
+		.map(|&v| (ValidatorIndex(v.into()), validator_id(v)))
+		.collect();
+	<UnappliedSlashes<Test>>::insert(SESSION, CANDIDATE_HASH, PendingSlashesV2 { keys, kind });
+}
+
+fn report(kind: DisputeOffenceKind, validator: u8) -> DispatchResultWithPostInfo {
+	ParasSlashing::report_dispute_lost_unsigned(
+		RuntimeOrigin::none(),
+		Box::new(DisputeProofV2 {
+			time_slot: DisputesTimeSlot::new(SESSION, CANDIDATE_HASH),
+			kind,
+			validator_index: ValidatorIndex(validator.into()),
+			validator_id: validator_id(validator),
+		}),
+		sp_session::MembershipProof { session: SESSION, trie_nodes: vec![], validator_count: 10 },
+	)
+}
+
+#[test]
+fn reported_slashes_are_remembered() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		note_pending(DisputeOffenceKind::ForInvalidBacked, &[1, 2]);
+
+		assert_ok!(report(DisputeOffenceKind::ForInvalidBacked, 1));
+
+		assert_eq!(REPORTED_OFFENCES.with(|r| r.borrow().clone()), vec![(SESSION, vec![1])]);
+		assert_eq!(
+			<ReportedSlashes<Test>>::get(SESSION, CANDIDATE_HASH),
+			vec![ReportedOffender {
+				validator_index: ValidatorIndex(1),
+				offender: 1,
+				kind: DisputeOffenceKind::ForInvalidBacked,
+			}]
+		);
+		let pending = <UnappliedSlashes<Test>>::get(SESSION, CANDIDATE_HASH).unwrap();
+		assert_eq!(pending.keys.into_keys().collect::<Vec<_>>(), vec![ValidatorIndex(2)]);
+	});
+}
+
+#[test]
+fn exonerate_drops_pending_and_compensates_reported_slashes() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		System::set_block_number(1);
+		note_pending(DisputeOffenceKind::ForInvalidBacked, &[1, 2, 3]);
+		assert_ok!(report(DisputeOffenceKind::ForInvalidBacked, 1));
+		assert_ok!(report(DisputeOffenceKind::ForInvalidBacked, 3));
+
+		assert_ok!(ParasSlashing::exonerate(RuntimeOrigin::root(), SESSION, CANDIDATE_HASH));
+
+		assert!(<UnappliedSlashes<Test>>::get(SESSION, CANDIDATE_HASH).is_none());
+		assert!(<ReportedSlashes<Test>>::get(SESSION, CANDIDATE_HASH).is_empty());
+		assert_eq!(compensated_slashes(), vec![(SESSION, vec![1, 3], SLASH_FOR_INVALID_BACKED)]);
+		System::assert_last_event(RuntimeEvent::ParasSlashing(Event::Exonerated {
+			session_index: SESSION,
+			candidate_hash: CANDIDATE_HASH,
+			validators: vec![ValidatorIndex(2)],
+			compensated: vec![ValidatorIndex(1), ValidatorIndex(3)],
+		}));
+
+		// The slashes can neither be reported nor compensated again.
+		assert_noop!(
+			report(DisputeOffenceKind::ForInvalidBacked, 2),
+			Error::<Test>::InvalidCandidateHash
+		);
+		assert_noop!(
+			ParasSlashing::exonerate(RuntimeOrigin::root(), SESSION, CANDIDATE_HASH),
+			Error::<Test>::NoSlashes
+		);
+	});
+}
+
+#[test]
+fn exonerate_compensates_reported_slashes_per_kind() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		note_pending(DisputeOffenceKind::ForInvalidBacked, &[1]);
+		assert_ok!(report(DisputeOffenceKind::ForInvalidBacked, 1));
+		note_pending(DisputeOffenceKind::ForInvalidApproved, &[2]);
+		assert_ok!(report(DisputeOffenceKind::ForInvalidApproved, 2));
+
+		assert_ok!(ParasSlashing::exonerate(RuntimeOrigin::root(), SESSION, CANDIDATE_HASH));
+
+		assert_eq!(
+			compensated_slashes(),
+			vec![
+				(SESSION, vec![1], SLASH_FOR_INVALID_BACKED),
+				(SESSION, vec![2], SLASH_FOR_INVALID_APPROVED),
+			]
+		);
+	});
+}
+
+#[test]
+fn exonerate_requires_exonerate_origin() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		note_pending(DisputeOffenceKind::ForInvalidBacked, &[1]);
+
+		assert_noop!(
+			ParasSlashing::exonerate(RuntimeOrigin::signed(1), SESSION, CANDIDATE_HASH),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn reported_slashes_are_pruned_after_exoneration_period() {
+	new_test_ext(MockGenesisConfig::default()).execute_with(|| {
+		note_pending(DisputeOffenceKind::ForInvalidBacked, &[1]);
+		assert_ok!(report(DisputeOffenceKind::ForInvalidBacked, 1));
+
+		// `ExonerationPeriod` is 2 sessions in the mock.
+		Pallet::<Test>::initializer_on_new_session(SESSION + 2);
+		assert!(!<ReportedSlashes<Test>>::get(SESSION, CANDIDATE_HASH).is_empty());
+
+		Pallet::<Test>::initializer_on_new_session(SESSION + 3);
+		assert!(<ReportedSlashes<Test>>::get(SESSION, CANDIDATE_HASH).is_empty());
+		assert_noop!(
+			ParasSlashing::exonerate(RuntimeOrigin::root(), SESSION, CANDIDATE_HASH),
+			Error::<Test>::NoSlashes
+		);
+	});
+}
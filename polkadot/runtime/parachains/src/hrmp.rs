@@ -19,13 +19,18 @@ use crate::{
 	dmp, ensure_parachain, initializer, paras,
 };
 use alloc::{
+	boxed::Box,
 	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
 	vec,
 	vec::Vec,
 };
 use codec::{Decode, Encode};
 use core::{fmt, mem};
-use frame_support::{pallet_prelude::*, traits::ReservableCurrency, DefaultNoBound};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Contains, ReservableCurrency},
+	DefaultNoBound,
+};
 use frame_system::pallet_prelude::*;
 use polkadot_parachain_primitives::primitives::{HorizontalMessages, IsSystem};
 use polkadot_primitives::{
@@ -34,9 +39,16 @@ use polkadot_primitives::{
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AccountIdConversion, BlakeTwo256, Hash as HashT, UniqueSaturatedInto, Zero},
+	traits::{
+		AccountIdConversion, BlakeTwo256, Hash as HashT, TryConvert, UniqueSaturatedInto, Zero,
+	},
 	ArithmeticError,
 };
+use xcm::{
+	latest::{Error as XcmError, Location},
+	VersionedLocation,
+};
+use xcm_executor::traits::TransactAsset;
 
 pub use pallet::*;
 
@@ -67,6 +79,8 @@ pub trait WeightInfo {
 	fn establish_system_channel() -> Weight;
 	fn poke_channel_deposits() -> Weight;
 	fn establish_channel_with_system() -> Weight;
+	fn set_deposit_refund_beneficiary() -> Weight;
+	fn refund_deposit() -> Weight;
 }
 
 /// A weight info that is only suitable for testing.
@@ -109,6 +123,92 @@ impl WeightInfo for TestWeightInfo {
 	fn establish_channel_with_system() -> Weight {
 		Weight::MAX
 	}
+	fn set_deposit_refund_beneficiary() -> Weight {
+		Weight::MAX
+	}
+	fn refund_deposit() -> Weight {
+		Weight::MAX
+	}
+}
+
+/// Matches all system parachains, see [`IsSystem`].
+///
+/// Can be used as [`Config::AutoAcceptChannelRequestsFrom`] to auto-accept open channel requests
+/// sent by system parachains.
+pub struct SystemParachains;
+impl Contains<ParaId> for SystemParachains {
+	fn contains(para: &ParaId) -> bool {
+		para.is_system()
+	}
+}
+
+/// Moves the refunded HRMP deposits of a para out of its sovereign account to the beneficiary
+/// chosen with [`Pallet::set_deposit_refund_beneficiary`].
+pub trait DepositRefunder<AccountId> {
+	/// Move `amount` from `who`, the sovereign account of a para, to `beneficiary`.
+	fn refund(who: &AccountId, beneficiary: Location, amount: Balance) -> Result<(), XcmError>;
+
+	/// Set up the environment so that a refund succeeds in benchmarks.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful() {}
+}
+
+impl<AccountId> DepositRefunder<AccountId> for () {
+	fn refund(_: &AccountId, _: Location, _: Balance) -> Result<(), XcmError> {
+		Err(XcmError::Unimplemented)
+	}
+}
+
+/// A [`DepositRefunder`] which teleports the refunds to `Destination`, e.g. Asset Hub, and deposits
+/// them there into the beneficiary, which is expressed relative to `Destination`.
+///
+/// No delivery fees are charged, so `Destination` must accept unpaid execution from this chain.
+pub struct TeleportDepositRefund<Destination, Router, AssetTransactor, AccountToLocation>(
+	core::marker::PhantomData<(Destination, Router, AssetTransactor, AccountToLocation)>,
+);
+
+impl<AccountId, Destination, Router, AssetTransactor, AccountToLocation> DepositRefunder<AccountId>
+	for TeleportDepositRefund<Destination, Router, AssetTransactor, AccountToLocation>
+where
+	Destination: Get<Location>,
+	Router: xcm::latest::SendXcm,
+	AssetTransactor: TransactAsset,
+	AccountToLocation: for<'a> TryConvert<&'a AccountId, Location>,
+{
+	fn refund(who: &AccountId, beneficiary: Location, amount: Balance) -> Result<(), XcmError> {
+		use xcm::latest::prelude::*;
+
+		let dest = Destination::get();
+		let who = AccountToLocation::try_convert(who).map_err(|_| XcmError::InvalidLocation)?;
+		let asset = Asset { id: Location::here().into(), fun: Fungible(amount) };
+		let context = XcmContext { origin: None, message_id: [0; 32], topic: None };
+
+		AssetTransactor::can_check_out(&dest, &asset, &context)?;
+		let asset_reanchored =
+			asset.clone().reanchored(&dest, &Here).map_err(|_| XcmError::ReanchorFailed)?;
+		let message = Xcm(vec![
+			UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+			ReceiveTeleportedAsset(asset_reanchored.into()),
+			ClearOrigin,
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary },
+		]);
+		let (ticket, _) = Router::validate(&mut Some(dest.clone()), &mut Some(message))?;
+
+		AssetTransactor::withdraw_asset(&asset, &who, None)?;
+		if let Err(err) = Router::deliver(ticket) {
+			// Put the funds back, so that they are not lost.
+			let _ = AssetTransactor::deposit_asset(&asset, &who, None);
+			return Err(err.into())
+		}
+		AssetTransactor::check_out(&dest, &asset, &context);
+
+		Ok(())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful() {
+		Router::ensure_successful_delivery(Some(Destination::get()));
+	}
 }
 
 /// A description of a request to open an HRMP channel.
@@ -288,6 +388,29 @@ pub mod pallet {
 		/// the default `()` implementation uses the latest XCM version for all parachains.
 		type VersionWrapper: xcm::WrapVersion;
 
+		/// Senders whose open channel requests are accepted automatically on behalf of the
+		/// recipient at the next session change.
+		///
+		/// The recipient deposit follows the usual rules, i.e. it is waived for channels with or
+		/// amongst the system. Requests which can't be accepted, e.g. because the recipient can't
+		/// afford the deposit, are left pending. Use [`SystemParachains`] to auto-accept requests
+		/// from all system parachains or `()` to disable auto-acceptance.
+		type AutoAcceptChannelRequestsFrom: Contains<ParaId>;
+
+		/// The maximal number of open channel requests from
+		/// [`Config::AutoAcceptChannelRequestsFrom`] that are processed at a session change.
+		///
+		/// Bounds the weight of auto-acceptance. Requests over the limit stay pending until the
+		/// next session change.
+		#[pallet::constant]
+		type MaxAutoAcceptedChannelRequests: Get<u32>;
+
+		/// Moves the refunded deposits of paras to the beneficiaries they have chosen.
+		///
+		/// Use [`TeleportDepositRefund`] to send refunds back to Asset Hub, where parachain teams
+		/// usually hold their funds, or `()` to always keep them in the sovereign accounts.
+		type DepositRefunder: DepositRefunder<Self::AccountId>;
+
 		/// Something that provides the weight of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -324,6 +447,12 @@ pub mod pallet {
 		},
 		/// An HRMP channel's deposits were updated.
 		OpenChannelDepositsUpdated { sender: ParaId, recipient: ParaId },
+		/// Open HRMP channel request was accepted automatically on behalf of the recipient.
+		OpenChannelAutoAccepted { sender: ParaId, recipient: ParaId },
+		/// The beneficiary of the refunded deposits of a para was changed.
+		DepositRefundBeneficiarySet { para: ParaId, beneficiary: Option<VersionedLocation> },
+		/// A refunded deposit of a para was moved to its beneficiary.
+		DepositRefunded { para: ParaId, beneficiary: VersionedLocation, amount: Balance },
 	}
 
 	#[pallet::error]
@@ -368,6 +497,8 @@ pub mod pallet {
 		WrongWitness,
 		/// The channel between these two chains cannot be authorized.
 		ChannelCreationNotAuthorized,
+		/// The deposit refund beneficiary can't be converted into the latest XCM version.
+		BadDepositRefundBeneficiary,
 	}
 
 	/// The set of pending HRMP open channel requests.
@@ -472,6 +603,13 @@ pub mod pallet {
 	pub type HrmpChannelDigests<T: Config> =
 		StorageMap<_, Twox64Concat, ParaId, Vec<(BlockNumberFor<T>, Vec<ParaId>)>, ValueQuery>;
 
+	/// The beneficiary of the deposits refunded to a para, set by the para itself.
+	///
+	/// Refunds of paras without a beneficiary stay in their sovereign accounts.
+	#[pallet::storage]
+	pub type HrmpDepositRefundBeneficiary<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, VersionedLocation>;
+
 	/// Preopen the given HRMP channels.
 	///
 	/// The values in the tuple corresponds to
@@ -572,7 +710,10 @@ pub mod pallet {
 		///
 		/// Origin must be the `ChannelManager`.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::force_clean_hrmp(*num_inbound, *num_outbound))]
+		#[pallet::weight(<T as Config>::WeightInfo::force_clean_hrmp(*num_inbound, *num_outbound)
+			.saturating_add(Pallet::<T>::refund_deposits_weight(
+				num_inbound.saturating_add(*num_outbound).saturating_mul(2),
+			)))]
 		pub fn force_clean_hrmp(
 			origin: OriginFor<T>,
 			para: ParaId,
@@ -629,7 +770,8 @@ pub mod pallet {
 		///
 		/// Origin must be the `ChannelManager`.
 		#[pallet::call_index(5)]
-		#[pallet::weight(<T as Config>::WeightInfo::force_process_hrmp_close(*channels))]
+		#[pallet::weight(<T as Config>::WeightInfo::force_process_hrmp_close(*channels)
+			.saturating_add(Pallet::<T>::refund_deposits_weight(channels.saturating_mul(2))))]
 		pub fn force_process_hrmp_close(origin: OriginFor<T>, channels: u32) -> DispatchResult {
 			T::ChannelManager::ensure_origin(origin)?;
 
@@ -652,7 +794,8 @@ pub mod pallet {
 		/// Total number of open requests (i.e. `HrmpOpenChannelRequestsList`) must be provided as
 		/// witness data.
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as Config>::WeightInfo::hrmp_cancel_open_request(*open_requests))]
+		#[pallet::weight(<T as Config>::WeightInfo::hrmp_cancel_open_request(*open_requests)
+			.saturating_add(Pallet::<T>::refund_deposits_weight(1)))]
 		pub fn hrmp_cancel_open_request(
 			origin: OriginFor<T>,
 			channel_id: HrmpChannelId,
@@ -678,7 +821,8 @@ pub mod pallet {
 		///
 		/// Origin must be the `ChannelManager`.
 		#[pallet::call_index(7)]
-		#[pallet::weight(<T as Config>::WeightInfo::force_open_hrmp_channel(1))]
+		#[pallet::weight(<T as Config>::WeightInfo::force_open_hrmp_channel(1)
+			.saturating_add(Pallet::<T>::refund_deposits_weight(1)))]
 		pub fn force_open_hrmp_channel(
 			origin: OriginFor<T>,
 			sender: ParaId,
@@ -769,7 +913,8 @@ pub mod pallet {
 		///
 		/// Any signed origin can call this function.
 		#[pallet::call_index(9)]
-		#[pallet::weight(<T as Config>::WeightInfo::poke_channel_deposits())]
+		#[pallet::weight(<T as Config>::WeightInfo::poke_channel_deposits()
+			.saturating_add(Pallet::<T>::refund_deposits_weight(2)))]
 		pub fn poke_channel_deposits(
 			origin: OriginFor<T>,
 			sender: ParaId,
@@ -806,12 +951,7 @@ pub mod pallet {
 						let amount = current_sender_deposit
 							.checked_sub(new_sender_deposit)
 							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::unreserve(
-							&channel_id.sender.into_account_truncating(),
-							// The difference should always be convertible into `Balance`, but be
-							// paranoid and do nothing in case.
-							amount.try_into().unwrap_or(Zero::zero()),
-						);
+						Self::refund_deposit(channel_id.sender, amount);
 					} else if current_sender_deposit < new_sender_deposit {
 						let amount = new_sender_deposit
 							.checked_sub(current_sender_deposit)
@@ -827,10 +967,7 @@ pub mod pallet {
 						let amount = current_recipient_deposit
 							.checked_sub(new_recipient_deposit)
 							.ok_or(ArithmeticError::Underflow)?;
-						T::Currency::unreserve(
-							&channel_id.recipient.into_account_truncating(),
-							amount.try_into().unwrap_or(Zero::zero()),
-						);
+						Self::refund_deposit(channel_id.recipient, amount);
 					} else if current_recipient_deposit < new_recipient_deposit {
 						let amount = new_recipient_deposit
 							.checked_sub(current_recipient_deposit)
@@ -898,6 +1035,40 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
+
+		/// Set the beneficiary of the HRMP deposits refunded to the origin, or clear it with
+		/// `None`.
+		///
+		/// Whenever a deposit of the para is returned, e.g. on closing a channel or canceling an
+		/// open channel request, it is moved from the sovereign account to the beneficiary by the
+		/// configured [`Config::DepositRefunder`]. If that fails, the deposit stays in the
+		/// sovereign account.
+		///
+		/// Together with teleporting the deposit from Asset Hub to the sovereign account before
+		/// opening a channel, this allows paras to pay HRMP deposits with the funds they hold on
+		/// Asset Hub.
+		///
+		/// The origin needs to be the parachain origin.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_deposit_refund_beneficiary())]
+		pub fn set_deposit_refund_beneficiary(
+			origin: OriginFor<T>,
+			beneficiary: Option<Box<VersionedLocation>>,
+		) -> DispatchResult {
+			let para = ensure_parachain(<T as Config>::RuntimeOrigin::from(origin))?;
+
+			let beneficiary = beneficiary
+				.map(|beneficiary| {
+					let location = Location::try_from(*beneficiary)
+						.map_err(|_| Error::<T>::BadDepositRefundBeneficiary)?;
+					Ok::<_, Error<T>>(VersionedLocation::from(location))
+				})
+				.transpose()?;
+			HrmpDepositRefundBeneficiary::<T>::set(para, beneficiary.clone());
+
+			Self::deposit_event(Event::DepositRefundBeneficiarySet { para, beneficiary });
+			Ok(())
+		}
 	}
 }
 
@@ -940,14 +1111,16 @@ impl<T: Config> Pallet<T> {
 		outgoing_paras: &[ParaId],
 	) -> Weight {
 		let w1 = Self::perform_outgoing_para_cleanup(&notification.prev_config, outgoing_paras);
-		Self::process_hrmp_open_channel_requests(&notification.prev_config);
-		Self::process_hrmp_close_channel_requests();
-		w1.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_open(
-			outgoing_paras.len() as u32
-		))
-		.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_close(
-			outgoing_paras.len() as u32,
-		))
+		let w2 = Self::process_hrmp_open_channel_requests(&notification.prev_config);
+		let w3 = Self::process_hrmp_close_channel_requests();
+		w1.saturating_add(w2)
+			.saturating_add(w3)
+			.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_open(
+				outgoing_paras.len() as u32,
+			))
+			.saturating_add(<T as Config>::WeightInfo::force_process_hrmp_close(
+				outgoing_paras.len() as u32,
+			))
 	}
 
 	/// Iterate over all paras that were noted for offboarding and remove all the data
@@ -958,7 +1131,7 @@ impl<T: Config> Pallet<T> {
 	) -> Weight {
 		let mut w = Self::clean_open_channel_requests(config, outgoing);
 		for outgoing_para in outgoing {
-			Self::clean_hrmp_after_outgoing(outgoing_para);
+			w = w.saturating_add(Self::clean_hrmp_after_outgoing(outgoing_para));
 
 			// we need a few extra bits of data to weigh this -- all of this is read internally
 			// anyways, so no overhead.
@@ -994,6 +1167,7 @@ impl<T: Config> Pallet<T> {
 
 		// Then iterate over all open requests to be removed, pull them out of the set and perform
 		// the refunds if applicable.
+		let mut refunds_weight = Weight::zero();
 		for req_id in go {
 			let req_data = match HrmpOpenChannelRequests::<T>::take(&req_id) {
 				Some(req_data) => req_data,
@@ -1005,10 +1179,10 @@ impl<T: Config> Pallet<T> {
 
 			// Return the deposit of the sender, but only if it is not the para being offboarded.
 			if !outgoing.contains(&req_id.sender) {
-				T::Currency::unreserve(
-					&req_id.sender.into_account_truncating(),
-					req_data.sender_deposit.unique_saturated_into(),
-				);
+				refunds_weight.saturating_accrue(Self::refund_deposit(
+					req_id.sender,
+					req_data.sender_deposit,
+				));
 			}
 
 			// If the request was confirmed, then it means it was confirmed in the finished session.
@@ -1018,20 +1192,23 @@ impl<T: Config> Pallet<T> {
 			// We still want to refund the deposit only if the para is not being offboarded.
 			if req_data.confirmed {
 				if !outgoing.contains(&req_id.recipient) {
-					T::Currency::unreserve(
-						&req_id.recipient.into_account_truncating(),
-						config.hrmp_recipient_deposit.unique_saturated_into(),
-					);
+					refunds_weight.saturating_accrue(Self::refund_deposit(
+						req_id.recipient,
+						config.hrmp_recipient_deposit,
+					));
 				}
 				Self::decrease_accepted_channel_request_count(req_id.recipient);
 			}
 		}
 
 		<T as Config>::WeightInfo::clean_open_channel_requests(outgoing.len() as u32)
+			.saturating_add(refunds_weight)
 	}
 
 	/// Remove all storage entries associated with the given para.
-	fn clean_hrmp_after_outgoing(outgoing_para: &ParaId) {
+	///
+	/// Returns the weight of refunding the deposits of the closed channels.
+	fn clean_hrmp_after_outgoing(outgoing_para: &ParaId) -> Weight {
 		HrmpOpenChannelRequestCount::<T>::remove(outgoing_para);
 		HrmpAcceptedChannelRequestCount::<T>::remove(outgoing_para);
 
@@ -1045,20 +1222,29 @@ impl<T: Config> Pallet<T> {
 		to_close.sort();
 		to_close.dedup();
 
+		let mut refunds_weight = Weight::zero();
 		for channel in to_close {
-			Self::close_hrmp_channel(&channel);
+			refunds_weight.saturating_accrue(Self::close_hrmp_channel(&channel));
 		}
+
+		HrmpDepositRefundBeneficiary::<T>::remove(outgoing_para);
+		refunds_weight
 	}
 
 	/// Iterate over all open channel requests and:
 	///
 	/// - prune the stale requests
+	/// - auto-accept the requests from [`Config::AutoAcceptChannelRequestsFrom`]
 	/// - enact the confirmed requests
-	fn process_hrmp_open_channel_requests(config: &HostConfiguration<BlockNumberFor<T>>) {
+	///
+	/// Returns the weight of auto-accepting the requests.
+	fn process_hrmp_open_channel_requests(config: &HostConfiguration<BlockNumberFor<T>>) -> Weight {
 		let mut open_req_channels = HrmpOpenChannelRequestsList::<T>::get();
 		if open_req_channels.is_empty() {
-			return
+			return Weight::zero()
 		}
+		let mut auto_accept_budget = T::MaxAutoAcceptedChannelRequests::get();
+		let mut auto_accept_attempts = 0u32;
 
 		// iterate the vector starting from the end making our way to the beginning. This way we
 		// can leverage `swap_remove` to efficiently remove an item during iteration.
@@ -1071,10 +1257,20 @@ impl<T: Config> Pallet<T> {
 
 			idx -= 1;
 			let channel_id = open_req_channels[idx].clone();
-			let request = HrmpOpenChannelRequests::<T>::get(&channel_id).expect(
+			let mut request = HrmpOpenChannelRequests::<T>::get(&channel_id).expect(
 				"can't be `None` due to the invariant that the list contains the same items as the set; qed",
 			);
 
+			if !request.confirmed &&
+				auto_accept_budget > 0 &&
+				T::AutoAcceptChannelRequestsFrom::contains(&channel_id.sender)
+			{
+				// Failed attempts are weighed like accepted requests, so they count as well.
+				auto_accept_budget -= 1;
+				auto_accept_attempts += 1;
+				request.confirmed = Self::auto_accept_open_channel(config, &channel_id);
+			}
+
 			let system_channel = channel_id.sender.is_system() || channel_id.recipient.is_system();
 			let sender_deposit = request.sender_deposit;
 			let recipient_deposit = if system_channel { 0 } else { config.hrmp_recipient_deposit };
@@ -1118,15 +1314,110 @@ impl<T: Config> Pallet<T> {
 		}
 
 		HrmpOpenChannelRequestsList::<T>::put(open_req_channels);
+
+		// Auto-accepting a request does the same work as `hrmp_accept_open_channel`.
+		<T as Config>::WeightInfo::hrmp_accept_open_channel()
+			.saturating_mul(auto_accept_attempts.into())
+	}
+
+	/// Accept the pending open channel request `channel_id` on behalf of the recipient. The
+	/// sender must be allowed by [`Config::AutoAcceptChannelRequestsFrom`].
+	///
+	/// Returns `true` if the request was accepted. Otherwise the request is left untouched, e.g.
+	/// if the recipient would exceed its inbound channel limit or can't afford the deposit.
+	fn auto_accept_open_channel(
+		config: &HostConfiguration<BlockNumberFor<T>>,
+		channel_id: &HrmpChannelId,
+	) -> bool {
+		let (sender, recipient) = (channel_id.sender, channel_id.recipient);
+		if !paras::Pallet::<T>::is_valid_para(recipient) {
+			return false
+		}
+
+		let ingress_cnt = HrmpIngressChannelsIndex::<T>::decode_len(&recipient).unwrap_or(0) as u32;
+		let accepted_cnt = HrmpAcceptedChannelRequestCount::<T>::get(&recipient);
+		if ingress_cnt + accepted_cnt >= config.hrmp_max_parachain_inbound_channels {
+			return false
+		}
+
+		// Do not require deposits for channels with or amongst the system.
+		let is_system = sender.is_system() || recipient.is_system();
+		let deposit = if is_system { 0 } else { config.hrmp_recipient_deposit };
+		if !deposit.is_zero() &&
+			T::Currency::reserve(
+				&recipient.into_account_truncating(),
+				deposit.unique_saturated_into(),
+			)
+			.is_err()
+		{
+			return false
+		}
+
+		HrmpAcceptedChannelRequestCount::<T>::insert(&recipient, accepted_cnt + 1);
+
+		Self::send_to_para(
+			"auto_accept_open_channel",
+			config,
+			sender,
+			Self::wrap_notification(|| {
+				use xcm::opaque::latest::{prelude::*, Xcm};
+				Xcm(vec![HrmpChannelAccepted { recipient: recipient.into() }])
+			}),
+		);
+
+		Self::deposit_event(Event::OpenChannelAutoAccepted { sender, recipient });
+		true
+	}
+
+	/// The worst-case weight of refunding `n` deposits with [`Self::refund_deposit`], on top of
+	/// unreserving them.
+	pub(crate) fn refund_deposits_weight(n: u32) -> Weight {
+		<T as Config>::WeightInfo::refund_deposit().saturating_mul(n.into())
+	}
+
+	/// Unreserve the `deposit` of `para` and move it to the deposit refund beneficiary of the
+	/// para, if any.
+	///
+	/// The deposit stays in the sovereign account of the para if the beneficiary is not set or the
+	/// refund fails.
+	///
+	/// Returns the weight of moving the deposit, which is at most
+	/// `Self::refund_deposits_weight(1)`.
+	pub(crate) fn refund_deposit(para: ParaId, deposit: Balance) -> Weight {
+		let who = para.into_account_truncating();
+		T::Currency::unreserve(&who, deposit.unique_saturated_into());
+
+		if deposit.is_zero() {
+			return Weight::zero()
+		}
+		let Some(beneficiary) = HrmpDepositRefundBeneficiary::<T>::get(para) else {
+			return T::DbWeight::get().reads(1)
+		};
+		let Ok(location) = Location::try_from(beneficiary.clone()) else {
+			return T::DbWeight::get().reads(1)
+		};
+		match T::DepositRefunder::refund(&who, location, deposit) {
+			Ok(()) =>
+				Self::deposit_event(Event::DepositRefunded { para, beneficiary, amount: deposit }),
+			Err(err) => log::warn!(
+				target: "runtime::hrmp",
+				"Failed to refund the deposit of {para:?} to {beneficiary:?}: {err:?}",
+			),
+		}
+		Self::refund_deposits_weight(1)
 	}
 
 	/// Iterate over all close channel requests unconditionally closing the channels.
-	fn process_hrmp_close_channel_requests() {
+	///
+	/// Returns the weight of refunding the deposits of the closed channels.
+	fn process_hrmp_close_channel_requests() -> Weight {
 		let close_reqs = HrmpCloseChannelRequestsList::<T>::take();
+		let mut refunds_weight = Weight::zero();
 		for condemned_ch_id in close_reqs {
 			HrmpCloseChannelRequests::<T>::remove(&condemned_ch_id);
-			Self::close_hrmp_channel(&condemned_ch_id);
+			refunds_weight.saturating_accrue(Self::close_hrmp_channel(&condemned_ch_id));
 		}
+		refunds_weight
 	}
 
 	/// Close and remove the designated HRMP channel.
@@ -1135,18 +1426,17 @@ impl<T: Config> Pallet<T> {
 	///
 	/// This function is idempotent, meaning that after the first application it should have no
 	/// effect (i.e. it won't return the deposits twice).
-	fn close_hrmp_channel(channel_id: &HrmpChannelId) {
+	///
+	/// Returns the weight of refunding the deposits.
+	fn close_hrmp_channel(channel_id: &HrmpChannelId) -> Weight {
+		let mut refunds_weight = Weight::zero();
 		if let Some(HrmpChannel { sender_deposit, recipient_deposit, .. }) =
 			HrmpChannels::<T>::take(channel_id)
 		{
-			T::Currency::unreserve(
-				&channel_id.sender.into_account_truncating(),
-				sender_deposit.unique_saturated_into(),
-			);
-			T::Currency::unreserve(
-				&channel_id.recipient.into_account_truncating(),
-				recipient_deposit.unique_saturated_into(),
-			);
+			refunds_weight
+				.saturating_accrue(Self::refund_deposit(channel_id.sender, sender_deposit));
+			refunds_weight
+				.saturating_accrue(Self::refund_deposit(channel_id.recipient, recipient_deposit));
 		}
 
 		HrmpChannelContents::<T>::remove(channel_id);
@@ -1161,6 +1451,8 @@ impl<T: Config> Pallet<T> {
 				v.remove(i);
 			}
 		});
+
+		refunds_weight
 	}
 
 	/// Check that the candidate of the given recipient controls the HRMP watermark properly.
@@ -1596,10 +1888,7 @@ impl<T: Config> Pallet<T> {
 
 		// Unreserve the sender's deposit. The recipient could not have left their deposit because
 		// we ensured that the request is not confirmed.
-		T::Currency::unreserve(
-			&channel_id.sender.into_account_truncating(),
-			open_channel_req.sender_deposit.unique_saturated_into(),
-		);
+		Self::refund_deposit(channel_id.sender, open_channel_req.sender_deposit);
 
 		Ok(())
 	}
@@ -561,6 +561,45 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn set_deposit_refund_beneficiary() {
+		let para: ParaId = 2000u32.into();
+		let para_origin: crate::Origin = 2000u32.into();
+		let beneficiary = VersionedLocation::from(Location::new(
+			0,
+			[xcm::latest::Junction::AccountId32 { network: None, id: [1; 32] }],
+		));
+
+		#[extrinsic_call]
+		_(para_origin, Some(Box::new(beneficiary.clone())));
+
+		assert_eq!(HrmpDepositRefundBeneficiary::<T>::get(para), Some(beneficiary));
+	}
+
+	#[benchmark]
+	fn refund_deposit() {
+		let para: ParaId = 2000u32.into();
+		let deposit = configuration::ActiveConfig::<T>::get().hrmp_sender_deposit;
+		let sovereign: T::AccountId = para.into_account_truncating();
+		T::Currency::make_free_balance_be(&sovereign, (deposit * 2).unique_saturated_into());
+		assert_ok!(T::Currency::reserve(&sovereign, deposit.unique_saturated_into()));
+		let beneficiary = VersionedLocation::from(Location::new(
+			0,
+			[xcm::latest::Junction::AccountId32 { network: None, id: [1; 32] }],
+		));
+		HrmpDepositRefundBeneficiary::<T>::insert(para, beneficiary.clone());
+		T::DepositRefunder::ensure_successful();
+
+		#[block]
+		{
+			Hrmp::<T>::refund_deposit(para, deposit);
+		}
+
+		assert_has_event::<T>(
+			Event::<T>::DepositRefunded { para, beneficiary, amount: deposit }.into(),
+		);
+	}
+
 	impl_benchmark_test_suite!(
 		Hrmp,
 		crate::mock::new_test_ext(crate::hrmp::tests::GenesisConfigBuilder::default().build()),
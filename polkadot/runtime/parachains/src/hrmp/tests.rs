@@ -23,7 +23,8 @@ use crate::{
 	mock::{
 		deregister_parachain, new_test_ext, register_parachain, register_parachain_with_balance,
 		Dmp, Hrmp, MockGenesisConfig, Paras, ParasShared, RuntimeEvent as MockEvent, RuntimeOrigin,
-		System, Test, TestUsesOnlyStoredVersionWrapper,
+		System, Test, TestAutoAcceptChannelRequestsFrom, TestDepositRefunder,
+		TestUsesOnlyStoredVersionWrapper,
 	},
 	shared,
 };
@@ -368,6 +369,152 @@ fn open_system_channel_works() {
 	});
 }
 
+#[test]
+fn open_channel_from_auto_accepted_sender_works() {
+	let para_a = 1.into();
+	let para_a_origin: crate::Origin = 1.into();
+	let para_b = 2001.into();
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		TestAutoAcceptChannelRequestsFrom::set(vec![para_a]);
+
+		// We need both A & B to be registered and live parachains.
+		register_parachain(para_a);
+		register_parachain_with_balance(para_b, 0);
+
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::hrmp_init_open_channel(para_a_origin.into(), para_b, 2, 8).unwrap();
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// Advance to a block 6, but without session change. The request is still pending.
+		run_to_block(6, None);
+		assert!(!channel_exists(para_a, para_b));
+		assert!(!System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::OpenChannelAutoAccepted {
+				sender: para_a,
+				recipient: para_b
+			})));
+
+		// Now let the session change happen. The request is accepted on behalf of B, without
+		// a deposit because A is a system chain, and the channel is opened.
+		run_to_block(8, Some(vec![8]));
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::OpenChannelAutoAccepted {
+				sender: para_a,
+				recipient: para_b
+			})));
+		assert!(channel_exists(para_a, para_b));
+		let channel =
+			HrmpChannels::<Test>::get(&HrmpChannelId { sender: para_a, recipient: para_b })
+				.unwrap();
+		assert_eq!(channel.sender_deposit, 0);
+		assert_eq!(channel.recipient_deposit, 0);
+		assert!(HrmpOpenChannelRequestsList::<Test>::get().is_empty());
+		Hrmp::assert_storage_consistency_exhaustive();
+	});
+}
+
+#[test]
+fn open_channel_from_auto_accepted_sender_requires_recipient_deposit() {
+	let para_a = 2001.into();
+	let para_a_origin: crate::Origin = 2001.into();
+	let para_b = 2003.into();
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		TestAutoAcceptChannelRequestsFrom::set(vec![para_a]);
+
+		register_parachain(para_a);
+		register_parachain_with_balance(para_b, 0);
+
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::hrmp_init_open_channel(para_a_origin.into(), para_b, 2, 8).unwrap();
+
+		// B can't afford the deposit, so the request is left pending.
+		run_to_block(8, Some(vec![8]));
+		assert!(!channel_exists(para_a, para_b));
+		let request = HrmpOpenChannelRequests::<Test>::get(&HrmpChannelId {
+			sender: para_a,
+			recipient: para_b,
+		})
+		.unwrap();
+		assert!(!request.confirmed);
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// Once B is funded the request is accepted at the next session change.
+		<Test as Config>::Currency::make_free_balance_be(&para_b.into_account_truncating(), 1_000);
+		run_to_block(10, Some(vec![10]));
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::OpenChannelAutoAccepted {
+				sender: para_a,
+				recipient: para_b
+			})));
+		assert!(channel_exists(para_a, para_b));
+		assert_eq!(
+			<Test as Config>::Currency::reserved_balance(&para_b.into_account_truncating()),
+			100
+		);
+		Hrmp::assert_storage_consistency_exhaustive();
+	});
+}
+
+#[test]
+fn auto_accepted_open_channel_requests_are_bounded_per_session() {
+	let para_a = 1.into();
+	let para_a_origin: crate::Origin = 1.into();
+	let para_b = 2001.into();
+	let para_c = 2002.into();
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		TestAutoAcceptChannelRequestsFrom::set(vec![para_a]);
+
+		register_parachain(para_a);
+		register_parachain_with_balance(para_b, 0);
+		register_parachain_with_balance(para_c, 0);
+
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::hrmp_init_open_channel(para_a_origin.clone().into(), para_b, 2, 8).unwrap();
+		Hrmp::hrmp_init_open_channel(para_a_origin.into(), para_c, 2, 8).unwrap();
+
+		// Only `MaxAutoAcceptedChannelRequests` requests are accepted at a session change.
+		run_to_block(8, Some(vec![8]));
+		assert_eq!(
+			channel_exists(para_a, para_b) as u32 + channel_exists(para_a, para_c) as u32,
+			1
+		);
+		assert_eq!(HrmpOpenChannelRequestsList::<Test>::get().len(), 1);
+		Hrmp::assert_storage_consistency_exhaustive();
+
+		// The other one is accepted at the next session change.
+		run_to_block(10, Some(vec![10]));
+		assert!(channel_exists(para_a, para_b));
+		assert!(channel_exists(para_a, para_c));
+		assert!(HrmpOpenChannelRequestsList::<Test>::get().is_empty());
+		Hrmp::assert_storage_consistency_exhaustive();
+	});
+}
+
+#[test]
+fn open_channel_from_other_senders_is_not_auto_accepted() {
+	let para_a = 2001.into();
+	let para_a_origin: crate::Origin = 2001.into();
+	let para_b = 2003.into();
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		TestAutoAcceptChannelRequestsFrom::set(vec![para_b]);
+
+		register_parachain(para_a);
+		register_parachain(para_b);
+
+		run_to_block(5, Some(vec![4, 5]));
+		Hrmp::hrmp_init_open_channel(para_a_origin.into(), para_b, 2, 8).unwrap();
+
+		run_to_block(8, Some(vec![8]));
+		assert!(!channel_exists(para_a, para_b));
+		assert_eq!(HrmpOpenChannelRequestsList::<Test>::get().len(), 1);
+		Hrmp::assert_storage_consistency_exhaustive();
+	});
+}
+
 #[test]
 fn open_system_channel_does_not_work_for_non_system_chains() {
 	let para_a = 2001.into();
@@ -456,6 +603,53 @@ fn poke_deposits_works() {
 	});
 }
 
+#[test]
+fn poke_deposits_refunds_excess_to_beneficiary() {
+	let para_a = 1.into();
+	let para_a_origin: crate::Origin = 1.into();
+	let para_b = 2001.into();
+	let beneficiary =
+		Location::new(0, [xcm::latest::Junction::AccountId32 { network: None, id: [1; 32] }]);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		register_parachain_with_balance(para_a, 200);
+		register_parachain_with_balance(para_b, 200);
+
+		// A channel with a system chain which still holds the deposit of the sender.
+		let config = configuration::ActiveConfig::<Test>::get();
+		let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+		HrmpChannels::<Test>::insert(
+			&channel_id,
+			HrmpChannel {
+				sender_deposit: 20,
+				recipient_deposit: 0,
+				max_capacity: config.hrmp_channel_max_capacity,
+				max_total_size: config.hrmp_channel_max_total_size,
+				max_message_size: config.hrmp_channel_max_message_size,
+				msg_count: 0,
+				total_size: 0,
+				mqc_head: None,
+			},
+		);
+		assert_ok!(<Test as Config>::Currency::reserve(&para_a.into_account_truncating(), 20));
+		assert_ok!(Hrmp::set_deposit_refund_beneficiary(
+			para_a_origin.into(),
+			Some(Box::new(beneficiary.clone().into())),
+		));
+
+		assert_ok!(Hrmp::poke_channel_deposits(RuntimeOrigin::signed(1), para_a, para_b));
+
+		assert_eq!(
+			TestDepositRefunder::refunds(),
+			vec![(para_a.into_account_truncating(), beneficiary, 20)]
+		);
+		assert_eq!(
+			<Test as Config>::Currency::reserved_balance(&para_a.into_account_truncating()),
+			0
+		);
+	});
+}
+
 #[test]
 fn close_channel_works() {
 	let para_a = 2005.into();
@@ -782,6 +976,115 @@ fn refund_deposit_on_normal_closure() {
 	});
 }
 
+#[test]
+fn refund_deposit_to_beneficiary_on_closure() {
+	let para_a = 2032.into();
+	let para_a_origin: crate::Origin = 2032.into();
+	let para_b = 2064.into();
+	let beneficiary =
+		Location::new(0, [xcm::latest::Junction::AccountId32 { network: None, id: [1; 32] }]);
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	genesis.hrmp_recipient_deposit = 15;
+	new_test_ext(genesis.build()).execute_with(|| {
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		run_to_block(5, Some(vec![4, 5]));
+
+		assert_ok!(Hrmp::set_deposit_refund_beneficiary(
+			para_a_origin.into(),
+			Some(Box::new(beneficiary.clone().into())),
+		));
+		assert_eq!(
+			HrmpDepositRefundBeneficiary::<Test>::get(para_a),
+			Some(beneficiary.clone().into())
+		);
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::DepositRefundBeneficiarySet {
+				para: para_a,
+				beneficiary: Some(beneficiary.clone().into()),
+			})));
+
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+		run_to_block(8, Some(vec![8]));
+
+		// Close the channel. Only the deposit of A is moved to its beneficiary.
+		Hrmp::close_channel(para_b, HrmpChannelId { sender: para_a, recipient: para_b }).unwrap();
+		run_to_block(10, Some(vec![10]));
+		assert_eq!(
+			TestDepositRefunder::refunds(),
+			vec![(para_a.into_account_truncating(), beneficiary.clone(), 20)]
+		);
+		assert!(System::events().iter().any(|record| record.event ==
+			MockEvent::Hrmp(Event::DepositRefunded {
+				para: para_a,
+				beneficiary: beneficiary.clone().into(),
+				amount: 20,
+			})));
+		assert_eq!(
+			<Test as Config>::Currency::reserved_balance(&para_a.into_account_truncating()),
+			0
+		);
+		assert_eq!(
+			<Test as Config>::Currency::reserved_balance(&para_b.into_account_truncating()),
+			0
+		);
+	});
+}
+
+#[test]
+fn refund_deposit_to_beneficiary_on_cancel() {
+	let para_a = 2032.into();
+	let para_a_origin: crate::Origin = 2032.into();
+	let para_b = 2064.into();
+	let beneficiary =
+		Location::new(0, [xcm::latest::Junction::AccountId32 { network: None, id: [1; 32] }]);
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_sender_deposit = 20;
+	new_test_ext(genesis.build()).execute_with(|| {
+		register_parachain_with_balance(para_a, 100);
+		register_parachain_with_balance(para_b, 110);
+		run_to_block(5, Some(vec![4, 5]));
+
+		assert_ok!(Hrmp::set_deposit_refund_beneficiary(
+			para_a_origin.into(),
+			Some(Box::new(beneficiary.clone().into())),
+		));
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::cancel_open_request(para_a, HrmpChannelId { sender: para_a, recipient: para_b })
+			.unwrap();
+		assert_eq!(
+			TestDepositRefunder::refunds(),
+			vec![(para_a.into_account_truncating(), beneficiary, 20)]
+		);
+
+		// Clearing the beneficiary keeps further refunds in the sovereign account.
+		assert_ok!(Hrmp::set_deposit_refund_beneficiary(para_a_origin.into(), None));
+		assert_eq!(HrmpDepositRefundBeneficiary::<Test>::get(para_a), None);
+		Hrmp::init_open_channel(para_a, para_b, 2, 8).unwrap();
+		Hrmp::cancel_open_request(para_a, HrmpChannelId { sender: para_a, recipient: para_b })
+			.unwrap();
+		assert_eq!(TestDepositRefunder::refunds().len(), 1);
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(&para_a.into_account_truncating()),
+			100
+		);
+	});
+}
+
+#[test]
+fn set_deposit_refund_beneficiary_requires_para_origin() {
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		assert_noop!(
+			Hrmp::set_deposit_refund_beneficiary(RuntimeOrigin::signed(1), None),
+			BadOrigin
+		);
+	});
+}
+
 #[test]
 fn refund_deposit_on_offboarding() {
 	let para_a = 2032.into();
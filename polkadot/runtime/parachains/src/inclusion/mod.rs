@@ -48,14 +48,17 @@ use polkadot_primitives::{
 	vstaging::{
 		skip_ump_signals, BackedCandidate, CandidateDescriptorV2 as CandidateDescriptor,
 		CandidateReceiptV2 as CandidateReceipt,
-		CommittedCandidateReceiptV2 as CommittedCandidateReceipt,
+		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, DEFAULT_CLAIM_QUEUE_OFFSET,
 	},
 	well_known_keys, CandidateCommitments, CandidateHash, CoreIndex, GroupIndex, HeadData,
-	Id as ParaId, SignedAvailabilityBitfields, SigningContext, UpwardMessage, ValidatorId,
-	ValidatorIndex, ValidityAttestation,
+	Id as ParaId, SessionIndex, SignedAvailabilityBitfields, SigningContext, UpwardMessage,
+	ValidatorId, ValidatorIndex, ValidityAttestation,
 };
 use scale_info::TypeInfo;
-use sp_runtime::{traits::One, DispatchError, SaturatedConversion, Saturating};
+use sp_runtime::{
+	traits::{AccountIdConversion, Convert, One},
+	DispatchError, SaturatedConversion, Saturating,
+};
 
 pub use pallet::*;
 
@@ -267,6 +270,20 @@ impl From<u32> for AggregateMessageOrigin {
 	}
 }
 
+/// Converts the origin of a UMP queue to the sovereign account of its parachain, e.g. to let it
+/// pay for the dead letters of its queue.
+pub struct ParaSovereignAccount<AccountId>(core::marker::PhantomData<AccountId>);
+impl<AccountId: Encode + Decode> Convert<AggregateMessageOrigin, Option<AccountId>>
+	for ParaSovereignAccount<AccountId>
+{
+	fn convert(origin: AggregateMessageOrigin) -> Option<AccountId> {
+		match origin {
+			AggregateMessageOrigin::Ump(UmpQueueId::Para(para)) =>
+				Some(para.into_account_truncating()),
+		}
+	}
+}
+
 /// The maximal length of a UMP message.
 pub type MaxUmpMessageLenOf<T> =
 	<<T as Config>::MessageQueue as EnqueueMessage<AggregateMessageOrigin>>::MaxMessageLen;
@@ -312,13 +329,53 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A candidate was backed. `[candidate, head_data]`
+		///
+		/// No longer emitted, superseded by [`Event::CandidateBackedV2`]. Kept to decode the
+		/// events of past blocks.
 		CandidateBacked(CandidateReceipt<T::Hash>, HeadData, CoreIndex, GroupIndex),
 		/// A candidate was included. `[candidate, head_data]`
+		///
+		/// No longer emitted, superseded by [`Event::CandidateIncludedV2`]. Kept to decode the
+		/// events of past blocks.
 		CandidateIncluded(CandidateReceipt<T::Hash>, HeadData, CoreIndex, GroupIndex),
 		/// A candidate timed out. `[candidate, head_data]`
 		CandidateTimedOut(CandidateReceipt<T::Hash>, HeadData, CoreIndex),
 		/// Some upward messages have been received and will be processed.
 		UpwardMessagesReceived { from: ParaId, count: u32 },
+		/// A candidate was backed.
+		CandidateBackedV2 {
+			/// The receipt of the candidate.
+			candidate: CandidateReceipt<T::Hash>,
+			/// The head data of the candidate.
+			head_data: HeadData,
+			/// The core the candidate is occupying.
+			core_index: CoreIndex,
+			/// The group that backed the candidate.
+			group_index: GroupIndex,
+			/// The session in which the candidate was backed.
+			session_index: SessionIndex,
+			/// The block number of the relay parent of the candidate.
+			relay_parent_number: BlockNumberFor<T>,
+			/// The position in the claim queue of the relay parent that the candidate claimed.
+			claim_queue_offset: u8,
+		},
+		/// A candidate was included.
+		CandidateIncludedV2 {
+			/// The receipt of the candidate.
+			candidate: CandidateReceipt<T::Hash>,
+			/// The head data of the candidate.
+			head_data: HeadData,
+			/// The core the candidate was occupying.
+			core_index: CoreIndex,
+			/// The group that backed the candidate.
+			group_index: GroupIndex,
+			/// The session in which the candidate was included.
+			session_index: SessionIndex,
+			/// The block number of the relay parent of the candidate.
+			relay_parent_number: BlockNumberFor<T>,
+			/// The position in the claim queue of the relay parent that the candidate claimed.
+			claim_queue_offset: u8,
+		},
 	}
 
 	#[pallet::error]
@@ -730,18 +787,35 @@ impl<T: Config> Pallet<T> {
 				});
 
 				// Deposit backed event.
-				Self::deposit_event(Event::<T>::CandidateBacked(
-					candidate.candidate().to_plain(),
-					candidate.candidate().commitments.head_data.clone(),
-					*core,
-					group_idx,
-				));
+				Self::deposit_event(Event::<T>::CandidateBackedV2 {
+					candidate: candidate.candidate().to_plain(),
+					head_data: candidate.candidate().commitments.head_data.clone(),
+					core_index: *core,
+					group_index: group_idx,
+					session_index: shared::CurrentSessionIndex::<T>::get(),
+					relay_parent_number,
+					claim_queue_offset: Self::claim_queue_offset(
+						&candidate.candidate().commitments,
+					),
+				});
 			}
 		}
 
 		Ok(candidate_receipt_with_backing_validator_indices)
 	}
 
+	/// The claim queue offset committed to by the `SelectCore` UMP signal of a candidate, or the
+	/// default offset if it sends none.
+	///
+	/// The signals of backed candidates were already checked when sanitizing the inherent.
+	fn claim_queue_offset(commitments: &CandidateCommitments) -> u8 {
+		commitments
+			.ump_signals()
+			.ok()
+			.and_then(|signals| signals.core_selector())
+			.map_or(DEFAULT_CLAIM_QUEUE_OFFSET, |(_, offset)| offset.0)
+	}
+
 	// Get the latest backed output head data of this para (including pending availability).
 	pub(crate) fn para_latest_head_data(para_id: &ParaId) -> Option<HeadData> {
 		match PendingAvailability::<T>::get(para_id).and_then(|pending_candidates| {
@@ -862,6 +936,7 @@ impl<T: Config> Pallet<T> {
 		backing_group: GroupIndex,
 	) {
 		let plain = receipt.to_plain();
+		let claim_queue_offset = Self::claim_queue_offset(&receipt.commitments);
 		let commitments = receipt.commitments;
 		let config = configuration::ActiveConfig::<T>::get();
 
@@ -912,12 +987,15 @@ impl<T: Config> Pallet<T> {
 			commitments.horizontal_messages,
 		);
 
-		Self::deposit_event(Event::<T>::CandidateIncluded(
-			plain,
-			commitments.head_data.clone(),
+		Self::deposit_event(Event::<T>::CandidateIncludedV2 {
+			candidate: plain,
+			head_data: commitments.head_data.clone(),
 			core_index,
-			backing_group,
-		));
+			group_index: backing_group,
+			session_index: shared::CurrentSessionIndex::<T>::get(),
+			relay_parent_number,
+			claim_queue_offset,
+		});
 
 		paras::Pallet::<T>::note_new_head(
 			receipt.descriptor.para_id(),
@@ -19,7 +19,8 @@ use crate::{
 	configuration::HostConfiguration,
 	initializer::SessionChangeNotification,
 	mock::{
-		new_test_ext, MockGenesisConfig, ParaInclusion, Paras, ParasShared, Scheduler, System, Test,
+		new_test_ext, MockGenesisConfig, ParaInclusion, Paras, ParasShared, RuntimeEvent,
+		Scheduler, System, Test,
 	},
 	paras::{ParaGenesisArgs, ParaKind},
 	paras_inherent::DisputedBitfield,
@@ -2813,6 +2814,17 @@ fn para_upgrade_delay_scheduled_from_inclusion() {
 			)
 			.expect("candidates scheduled, in order, and backed");
 
+		assert!(System::events().iter().any(|r| r.event ==
+			RuntimeEvent::ParaInclusion(Event::CandidateBackedV2 {
+				candidate: candidate_a.to_plain(),
+				head_data: candidate_a.commitments.head_data.clone(),
+				core_index: CoreIndex::from(0),
+				group_index: GroupIndex::from(0),
+				session_index: 5,
+				relay_parent_number: RELAY_PARENT_NUM,
+				claim_queue_offset: 0,
+			})));
+
 		// Run a couple of blocks before the inclusion.
 		run_to_block(7, |_| None);
 
@@ -2843,6 +2855,17 @@ fn para_upgrade_delay_scheduled_from_inclusion() {
 		let v = process_bitfields(checked_bitfields);
 		assert_eq!(vec![(CoreIndex(0), candidate_a.hash())], v);
 
+		assert!(System::events().iter().any(|r| r.event ==
+			RuntimeEvent::ParaInclusion(Event::CandidateIncludedV2 {
+				candidate: candidate_a.to_plain(),
+				head_data: candidate_a.commitments.head_data.clone(),
+				core_index: CoreIndex::from(0),
+				group_index: GroupIndex::from(0),
+				session_index: 5,
+				relay_parent_number: RELAY_PARENT_NUM,
+				claim_queue_offset: 0,
+			})));
+
 		assert!(PendingAvailability::<Test>::get(&chain_a).unwrap().is_empty());
 
 		let active_vote_state = paras::Pallet::<Test>::active_vote_state(&new_validation_code_hash)
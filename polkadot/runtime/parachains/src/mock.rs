@@ -34,7 +34,8 @@ use frame_support::{
 	dispatch::GetDispatchInfo,
 	parameter_types,
 	traits::{
-		Currency, ProcessMessage, ProcessMessageError, ValidatorSet, ValidatorSetWithIdentification,
+		Contains, Currency, ProcessMessage, ProcessMessageError, ValidatorSet,
+		ValidatorSetWithIdentification,
 	},
 	weights::{Weight, WeightMeter},
 	PalletId,
@@ -43,9 +44,9 @@ use frame_support_test::TestRandomness;
 use frame_system::{limits, EnsureRoot};
 use polkadot_primitives::{
 	AuthorityDiscoveryId, Balance, BlockNumber, CandidateHash, Moment, SessionIndex, UpwardMessage,
-	ValidationCode, ValidatorIndex,
+	ValidationCode, ValidatorId, ValidatorIndex,
 };
-use sp_core::{ConstU32, H256};
+use sp_core::{ConstU32, ConstU64, H256};
 use sp_io::TestExternalities;
 use sp_runtime::{
 	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
@@ -87,7 +88,9 @@ frame_support::construct_runtime!(
 		ParachainsOrigin: origin,
 		SessionInfo: session_info,
 		Disputes: disputes,
+		ParasSlashing: disputes::slashing,
 		Babe: pallet_babe,
+		Timestamp: pallet_timestamp,
 	}
 );
 
@@ -184,6 +187,8 @@ impl pallet_timestamp::Config for Test {
 	type Moment = Moment;
 	type OnTimestampSet = ();
 	type MinimumPeriod = MinimumPeriod;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = frame_support::traits::ConstU64<0>;
 	type WeightInfo = ();
 }
 
@@ -283,6 +288,45 @@ impl TestUsesOnlyStoredVersionWrapper {
 	}
 }
 
+thread_local! {
+	pub static AUTO_ACCEPT_CHANNEL_REQUESTS_FROM: RefCell<Vec<ParaId>> = RefCell::new(Vec::new());
+}
+/// Mock implementation of the auto-accepted senders of open channel requests, which are stored
+/// in `AUTO_ACCEPT_CHANNEL_REQUESTS_FROM`.
+pub struct TestAutoAcceptChannelRequestsFrom;
+impl Contains<ParaId> for TestAutoAcceptChannelRequestsFrom {
+	fn contains(para: &ParaId) -> bool {
+		AUTO_ACCEPT_CHANNEL_REQUESTS_FROM.with(|r| r.borrow().contains(para))
+	}
+}
+impl TestAutoAcceptChannelRequestsFrom {
+	pub fn set(paras: Vec<ParaId>) {
+		AUTO_ACCEPT_CHANNEL_REQUESTS_FROM.with(|r| *r.borrow_mut() = paras);
+	}
+}
+
+thread_local! {
+	pub static DEPOSIT_REFUNDS: RefCell<Vec<(AccountId, Location, Balance)>> = RefCell::new(Vec::new());
+}
+/// Mock implementation of the [`hrmp::DepositRefunder`] which only records the refunds in
+/// `DEPOSIT_REFUNDS`.
+pub struct TestDepositRefunder;
+impl hrmp::DepositRefunder<AccountId> for TestDepositRefunder {
+	fn refund(
+		who: &AccountId,
+		beneficiary: Location,
+		amount: Balance,
+	) -> Result<(), xcm::latest::Error> {
+		DEPOSIT_REFUNDS.with(|r| r.borrow_mut().push((*who, beneficiary, amount)));
+		Ok(())
+	}
+}
+impl TestDepositRefunder {
+	pub fn refunds() -> Vec<(AccountId, Location, Balance)> {
+		DEPOSIT_REFUNDS.with(|r| r.borrow().clone())
+	}
+}
+
 impl crate::hrmp::Config for Test {
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeEvent = RuntimeEvent;
@@ -290,6 +334,9 @@ impl crate::hrmp::Config for Test {
 	type Currency = pallet_balances::Pallet<Test>;
 	type DefaultChannelSizeAndCapacityWithSystem = DefaultChannelSizeAndCapacityWithSystem;
 	type VersionWrapper = TestUsesOnlyStoredVersionWrapper;
+	type AutoAcceptChannelRequestsFrom = TestAutoAcceptChannelRequestsFrom;
+	type MaxAutoAcceptedChannelRequests = ConstU32<1>;
+	type DepositRefunder = TestDepositRefunder;
 	type WeightInfo = crate::hrmp::TestWeightInfo;
 }
 
@@ -347,6 +394,88 @@ impl crate::disputes::SlashingHandler<BlockNumber> for Test {
 	fn initializer_on_new_session(_: SessionIndex) {}
 }
 
+pub struct TestKeyOwnerProofSystem;
+impl frame_support::traits::KeyOwnerProofSystem<(sp_core::crypto::KeyTypeId, ValidatorId)>
+	for TestKeyOwnerProofSystem
+{
+	type Proof = sp_session::MembershipProof;
+	type IdentificationTuple = AccountId;
+
+	fn prove(_key: (sp_core::crypto::KeyTypeId, ValidatorId)) -> Option<Self::Proof> {
+		None
+	}
+
+	/// The account of a validator is the first byte of its key.
+	fn check_proof(
+		key: (sp_core::crypto::KeyTypeId, ValidatorId),
+		_proof: Self::Proof,
+	) -> Option<Self::IdentificationTuple> {
+		Some(AsRef::<[u8]>::as_ref(&key.1)[0].into())
+	}
+}
+
+thread_local! {
+	pub static REPORTED_OFFENCES: RefCell<Vec<(SessionIndex, Vec<AccountId>)>> = RefCell::new(Vec::new());
+	pub static COMPENSATED_SLASHES: RefCell<Vec<(SessionIndex, Vec<AccountId>, Perbill)>> = RefCell::new(Vec::new());
+}
+
+pub struct TestSlashingReports;
+impl disputes::slashing::HandleReports<Test> for TestSlashingReports {
+	type ReportLongevity = ConstU64<100>;
+
+	fn report_offence(
+		offence: disputes::slashing::SlashingOffence<AccountId>,
+	) -> Result<(), sp_staking::offence::OffenceError> {
+		use sp_staking::offence::Offence;
+		REPORTED_OFFENCES
+			.with(|r| r.borrow_mut().push((offence.session_index(), offence.offenders())));
+		Ok(())
+	}
+
+	fn is_known_offence(
+		_offenders: &[AccountId],
+		_time_slot: &polkadot_primitives::slashing::DisputesTimeSlot,
+	) -> bool {
+		false
+	}
+
+	fn submit_unsigned_slashing_report(
+		_dispute_proof: polkadot_primitives::vstaging::DisputeProof,
+		_key_owner_proof: sp_session::MembershipProof,
+	) -> Result<(), sp_runtime::TryRuntimeError> {
+		Ok(())
+	}
+}
+
+pub struct TestSlashCompensation;
+impl disputes::slashing::CompensateSlashes<AccountId> for TestSlashCompensation {
+	fn compensate(session_index: SessionIndex, offenders: &[AccountId], slash_fraction: Perbill) {
+		COMPENSATED_SLASHES
+			.with(|c| c.borrow_mut().push((session_index, offenders.to_vec(), slash_fraction)))
+	}
+
+	fn weight(_offenders: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The slashes of the exonerated validators that have been compensated.
+pub fn compensated_slashes() -> Vec<(SessionIndex, Vec<AccountId>, Perbill)> {
+	COMPENSATED_SLASHES.with(|c| c.borrow().clone())
+}
+
+impl disputes::slashing::Config for Test {
+	type KeyOwnerProof = sp_session::MembershipProof;
+	type KeyOwnerIdentification = AccountId;
+	type KeyOwnerProofSystem = TestKeyOwnerProofSystem;
+	type HandleReports = TestSlashingReports;
+	type WeightInfo = disputes::slashing::TestWeightInfo;
+	type BenchmarkingConfig = disputes::slashing::BenchConfig<1000>;
+	type ExonerateOrigin = EnsureRoot<AccountId>;
+	type SlashCompensation = TestSlashCompensation;
+	type ExonerationPeriod = ConstU32<2>;
+}
+
 impl crate::scheduler::Config for Test {
 	type AssignmentProvider = MockAssigner;
 }
@@ -386,6 +515,15 @@ impl pallet_message_queue::WeightInfo for TestMessageQueueWeight {
 	fn execute_overweight_page_updated() -> Weight {
 		Weight::zero()
 	}
+	fn note_dead_letter() -> Weight {
+		Weight::zero()
+	}
+	fn reprocess_dead_letter() -> Weight {
+		Weight::zero()
+	}
+	fn purge_dead_letter() -> Weight {
+		Weight::zero()
+	}
 }
 parameter_types! {
 	pub const MessageQueueServiceWeight: Weight = Weight::from_all(500);
@@ -404,6 +542,9 @@ impl pallet_message_queue::Config for Test {
 	type MaxStale = ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type MaxDeadLetters = ();
+	type DeadLetterDepositor = ();
+	type DeadLetterConsideration = ();
 }
 
 parameter_types! {
@@ -105,6 +105,20 @@ mod benchmarks {
 		_(RawOrigin::Signed(caller.into()), BalanceOf::<T>::max_value(), para_id)
 	}
 
+	#[benchmark]
+	fn purchase_credits() {
+		// Setup
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		let amount = T::Currency::minimum_balance().saturating_mul(100u32.into());
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.into()), beneficiary.clone(), amount);
+
+		assert_eq!(Credits::<T>::get(&beneficiary), amount);
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(
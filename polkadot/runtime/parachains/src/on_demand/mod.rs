@@ -62,8 +62,8 @@ use sp_runtime::{
 	FixedPointNumber, FixedPointOperand, FixedU128, Perbill, Saturating,
 };
 use types::{
-	BalanceOf, CoreAffinityCount, EnqueuedOrder, QueuePushDirection, QueueStatusType,
-	SpotTrafficCalculationErr,
+	BalanceOf, CoreAffinityCount, EnqueuedOrder, NegativeImbalanceOf, QueuePushDirection,
+	QueueStatusType, SpotTrafficCalculationErr,
 };
 
 const LOG_TARGET: &str = "runtime::parachains::on-demand";
@@ -74,6 +74,7 @@ pub trait WeightInfo {
 	fn place_order_allow_death(s: u32) -> Weight;
 	fn place_order_keep_alive(s: u32) -> Weight;
 	fn place_order_with_credits(s: u32) -> Weight;
+	fn purchase_credits() -> Weight;
 }
 
 /// A weight info that is only suitable for testing.
@@ -91,6 +92,10 @@ impl WeightInfo for TestWeightInfo {
 	fn place_order_with_credits(_: u32) -> Weight {
 		Weight::MAX
 	}
+
+	fn purchase_credits() -> Weight {
+		Weight::MAX
+	}
 }
 
 /// Defines how the account wants to pay for on-demand.
@@ -343,6 +348,59 @@ pub mod pallet {
 				PaymentType::Credits,
 			)
 		}
+
+		/// Purchase on-demand credits for `beneficiary` with the free balance of the origin.
+		///
+		/// This allows paying for on-demand coretime with funds that were transferred to the
+		/// relay chain, e.g. teleported from Asset Hub, and placing orders with
+		/// [`place_order_with_credits`](Self::place_order_with_credits). The funds are kept in the
+		/// pallet account and accounted as revenue when the credits are spent.
+		///
+		/// Parameters:
+		/// - `origin`: The sender of the call, funds will be withdrawn from this account.
+		/// - `beneficiary`: The account which receives the credits.
+		/// - `amount`: The amount of credits to purchase, one credit per unit of balance.
+		///
+		/// Errors:
+		/// - `InsufficientBalance`: from the Currency implementation
+		///
+		/// Events:
+		/// - `AccountCredited`
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::purchase_credits())]
+		pub fn purchase_credits(
+			origin: OriginFor<T>,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let payment = T::Currency::withdraw(&sender, amount, WithdrawReasons::FEE, AllowDeath)?;
+			Self::deposit_into_pot(payment);
+			Self::credit_account(beneficiary, amount);
+			Ok(())
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// The price of an on-demand order placed now.
+		///
+		/// Used to quote orders which are paid from another chain, e.g. with assets on Asset Hub
+		/// that are swapped into the native token and teleported to pay for the order.
+		pub fn spot_price() -> BalanceOf<T> {
+			let config = configuration::ActiveConfig::<T>::get();
+			let queue_status = QueueStatus::<T>::get();
+			// The traffic as it will be updated by the next order, without writing it.
+			let traffic = Self::calculate_spot_traffic(
+				queue_status.traffic,
+				config.scheduler_params.on_demand_queue_max_size,
+				queue_status.size(),
+				config.scheduler_params.on_demand_target_queue_utilization,
+				config.scheduler_params.on_demand_fee_variability,
+			)
+			.unwrap_or(queue_status.traffic);
+			Self::spot_price_for(&config, traffic)
+		}
 	}
 }
 
@@ -464,12 +522,7 @@ where
 
 		QueueStatus::<T>::mutate(|queue_status| {
 			Self::update_spot_traffic(&config, queue_status);
-			let traffic = queue_status.traffic;
-
-			// Calculate spot price
-			let spot_price: BalanceOf<T> = traffic.saturating_mul_int(
-				config.scheduler_params.on_demand_base_fee.saturated_into::<BalanceOf<T>>(),
-			);
+			let spot_price = Self::spot_price_for(&config, queue_status.traffic);
 
 			// Is the current price higher than `max_amount`
 			ensure!(spot_price.le(&max_amount), Error::<T>::SpotPriceHigherThanMaxAmount);
@@ -490,13 +543,7 @@ where
 						existence_requirement,
 					)?;
 
-					// Consume the negative imbalance and deposit it into the pallet account. Make
-					// sure the account preserves even without the existential deposit.
-					let pot = Self::account_id();
-					if !System::<T>::account_exists(&pot) {
-						System::<T>::inc_providers(&pot);
-					}
-					T::Currency::resolve_creating(&pot, amt);
+					Self::deposit_into_pot(amt);
 				},
 				PaymentType::Credits => {
 					let credits = Credits::<T>::get(&sender);
@@ -536,6 +583,26 @@ where
 		})
 	}
 
+	/// Consume the negative imbalance and deposit it into the pallet account. Make sure the
+	/// account preserves even without the existential deposit.
+	fn deposit_into_pot(amount: NegativeImbalanceOf<T>) {
+		let pot = Self::account_id();
+		if !System::<T>::account_exists(&pot) {
+			System::<T>::inc_providers(&pot);
+		}
+		T::Currency::resolve_creating(&pot, amount);
+	}
+
+	/// The spot price for the given spot traffic.
+	fn spot_price_for(
+		config: &configuration::HostConfiguration<BlockNumberFor<T>>,
+		traffic: FixedU128,
+	) -> BalanceOf<T> {
+		traffic.saturating_mul_int(
+			config.scheduler_params.on_demand_base_fee.saturated_into::<BalanceOf<T>>(),
+		)
+	}
+
 	/// Calculate and update spot traffic.
 	fn update_spot_traffic(
 		config: &configuration::HostConfiguration<BlockNumberFor<T>>,
@@ -555,9 +622,7 @@ where
 					queue_status.traffic = new_traffic;
 
 					// calculate the new spot price
-					let spot_price: BalanceOf<T> = new_traffic.saturating_mul_int(
-						config.scheduler_params.on_demand_base_fee.saturated_into::<BalanceOf<T>>(),
-					);
+					let spot_price = Self::spot_price_for(config, new_traffic);
 
 					// emit the event for updated new price
 					Pallet::<T>::deposit_event(Event::<T>::SpotPriceSet { spot_price });
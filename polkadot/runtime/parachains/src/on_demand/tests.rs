@@ -400,6 +400,70 @@ fn place_order_with_credits() {
 	});
 }
 
+#[test]
+fn purchase_credits_works() {
+	let alice = 100u64;
+	let bob = 101u64;
+	let amount = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+		Balances::make_free_balance_be(&alice, amount);
+		let pot = OnDemand::account_id();
+
+		// The whole balance can be spent on credits for another account.
+		assert_ok!(OnDemand::purchase_credits(RuntimeOrigin::signed(alice), bob, amount));
+		assert_eq!(Balances::free_balance(&alice), 0);
+		assert_eq!(Balances::free_balance(&pot), amount);
+		assert_eq!(Credits::<Test>::get(bob), amount);
+		System::assert_last_event(Event::<Test>::AccountCredited { who: bob, amount }.into());
+
+		// The credits are accounted as revenue when they are spent.
+		let revenue = Revenue::<Test>::get().first().copied().unwrap_or_default();
+		let spot_price = OnDemand::spot_price();
+		assert_ok!(OnDemand::place_order_with_credits(
+			RuntimeOrigin::signed(bob),
+			spot_price,
+			para_id
+		));
+		assert_eq!(Credits::<Test>::get(bob), amount - spot_price);
+		assert_eq!(Revenue::<Test>::get().first(), Some(&(revenue + spot_price)));
+
+		assert_noop!(
+			OnDemand::purchase_credits(RuntimeOrigin::signed(alice), bob, 1),
+			BalancesError::<Test, _>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn spot_price_quotes_the_price_of_the_next_order() {
+	let alice = 1u64;
+	let initial_credit = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		OnDemand::credit_account(alice, initial_credit);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+
+		// The quote stays accurate while the queue fills up.
+		for _ in 0..3 {
+			let quote = OnDemand::spot_price();
+			let credits = Credits::<Test>::get(alice);
+			assert_ok!(OnDemand::place_order_with_credits(
+				RuntimeOrigin::signed(alice),
+				quote,
+				para_id
+			));
+			assert_eq!(Credits::<Test>::get(alice), credits - quote);
+			run_to_block(System::block_number() + 1, |_| None);
+		}
+	});
+}
+
 #[test]
 fn pop_assignment_for_core_works() {
 	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
@@ -30,6 +30,11 @@ use sp_runtime::FixedU128;
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// Shorthand for the negative imbalance of the Currency the runtime is using.
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+
 /// Meta data for full queue.
 ///
 /// This includes elements with affinity and free entries.
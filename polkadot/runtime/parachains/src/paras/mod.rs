@@ -148,6 +148,10 @@ pub use pallet::*;
 
 const LOG_TARGET: &str = "runtime::paras";
 
+/// The number of sessions for which the outcome of a concluded PVF pre-checking vote is kept in
+/// `RecentPvfCheckOutcomes`.
+pub const PVF_CHECK_OUTCOME_RETENTION: SessionIndex = 6;
+
 // the two key times necessary to track for every code replacement.
 #[derive(Default, Encode, Decode, TypeInfo)]
 #[cfg_attr(test, derive(Debug, Clone, PartialEq))]
@@ -439,12 +443,31 @@ impl<BlockNumber> PvfCheckCause<BlockNumber> {
 }
 
 /// Specifies what was the outcome of a PVF pre-checking vote.
-#[derive(Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
-enum PvfCheckOutcome {
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum PvfCheckOutcome {
 	Accepted,
 	Rejected,
 }
 
+/// The progress of a PVF pre-checking vote, as returned by [`Pallet::pvf_check_status`].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PvfCheckStatus {
+	/// The number of validators who voted to accept the PVF in the current session, or in the
+	/// session in which the vote concluded.
+	pub votes_accept: u32,
+	/// The number of validators who voted to reject the PVF in the current session, or in the
+	/// session in which the vote concluded.
+	pub votes_reject: u32,
+	/// The number of validators who can vote in the current session, or in the session in which
+	/// the vote concluded.
+	pub n_validators: u32,
+	/// The session at the start of which the PVF is rejected if the vote hasn't concluded by
+	/// then.
+	pub deadline_session: SessionIndex,
+	/// The outcome of the vote, once it has concluded.
+	pub outcome: Option<PvfCheckOutcome>,
+}
+
 /// This struct describes the current state of an in-progress PVF pre-checking vote.
 #[derive(Encode, Decode, TypeInfo)]
 pub(crate) struct PvfCheckActiveVoteState<BlockNumber> {
@@ -514,6 +537,22 @@ impl<BlockNumber> PvfCheckActiveVoteState<BlockNumber> {
 		}
 	}
 
+	/// Returns the progress of this vote during `session`, given the PVF voting TTL.
+	fn status(
+		&self,
+		session: SessionIndex,
+		pvf_voting_ttl: SessionIndex,
+		outcome: Option<PvfCheckOutcome>,
+	) -> PvfCheckStatus {
+		PvfCheckStatus {
+			votes_accept: self.votes_accept.count_ones() as u32,
+			votes_reject: self.votes_reject.count_ones() as u32,
+			n_validators: self.votes_accept.len() as u32,
+			deadline_session: session.saturating_add(pvf_voting_ttl.saturating_sub(self.age)),
+			outcome,
+		}
+	}
+
 	#[cfg(test)]
 	pub(crate) fn causes(&self) -> &[PvfCheckCause<BlockNumber>] {
 		self.causes.as_slice()
@@ -803,6 +842,14 @@ pub mod pallet {
 	pub(super) type PvfActiveVoteList<T: Config> =
 		StorageValue<_, Vec<ValidationCodeHash>, ValueQuery>;
 
+	/// The PVF pre-checking votes which concluded recently, with the session in which they
+	/// concluded.
+	///
+	/// Entries are kept for `PVF_CHECK_OUTCOME_RETENTION` sessions. Ordered by the session.
+	#[pallet::storage]
+	pub(super) type RecentPvfCheckOutcomes<T: Config> =
+		StorageValue<_, Vec<(ValidationCodeHash, SessionIndex, PvfCheckStatus)>, ValueQuery>;
+
 	/// All lease holding parachains. Ordered ascending by `ParaId`. On demand parachains are not
 	/// included.
 	///
@@ -1093,6 +1140,7 @@ pub mod pallet {
 				});
 
 				let cfg = configuration::ActiveConfig::<T>::get();
+				Self::note_pvf_check_outcome(code_hash, &vote, &cfg, PvfCheckOutcome::Accepted);
 				Self::enact_pvf_accepted(
 					frame_system::Pallet::<T>::block_number(),
 					&code_hash,
@@ -1200,9 +1248,10 @@ pub mod pallet {
 						l.remove(i);
 					}
 				});
+				let cfg = configuration::ActiveConfig::<T>::get();
+				Self::note_pvf_check_outcome(stmt.subject, &active_vote, &cfg, outcome);
 				match outcome {
 					PvfCheckOutcome::Accepted => {
-						let cfg = configuration::ActiveConfig::<T>::get();
 						Self::enact_pvf_accepted(
 							frame_system::Pallet::<T>::block_number(),
 							&stmt.subject,
@@ -1361,6 +1410,37 @@ pub mod pallet {
 				.map(|(_, c)| Self::calculate_remove_upgrade_cooldown_cost(*c))
 				.unwrap_or_default()
 		}
+
+		/// Returns the progress of the PVF pre-checking vote on `code_hash`.
+		///
+		/// Votes which concluded within the last `PVF_CHECK_OUTCOME_RETENTION` sessions are
+		/// reported with their outcome. Returns `None` if there is no such vote.
+		pub fn pvf_check_status(code_hash: ValidationCodeHash) -> Option<PvfCheckStatus> {
+			Self::pvf_check_statuses()
+				.into_iter()
+				.find(|(hash, _)| *hash == code_hash)
+				.map(|(_, status)| status)
+		}
+
+		/// Returns the progress of all ongoing PVF pre-checking votes, followed by the ones which
+		/// concluded within the last `PVF_CHECK_OUTCOME_RETENTION` sessions.
+		pub fn pvf_check_statuses() -> Vec<(ValidationCodeHash, PvfCheckStatus)> {
+			let session = shared::CurrentSessionIndex::<T>::get();
+			let ttl = configuration::ActiveConfig::<T>::get().pvf_voting_ttl;
+			PvfActiveVoteList::<T>::get()
+				.into_iter()
+				.filter_map(|code_hash| {
+					let vote = PvfActiveVoteMap::<T>::get(&code_hash)?;
+					Some((code_hash, vote.status(session, ttl, None)))
+				})
+				.chain(
+					RecentPvfCheckOutcomes::<T>::get()
+						.into_iter()
+						.rev()
+						.map(|(code_hash, _, status)| (code_hash, status)),
+				)
+				.collect()
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -1825,6 +1905,14 @@ impl<T: Config> Pallet<T> {
 	) -> Weight {
 		let mut weight = T::DbWeight::get().reads(1);
 
+		weight += T::DbWeight::get().reads_writes(1, 1);
+		let session = shared::CurrentSessionIndex::<T>::get();
+		RecentPvfCheckOutcomes::<T>::mutate(|outcomes| {
+			outcomes.retain(|(_, concluded_at, _)| {
+				session.saturating_sub(*concluded_at) < PVF_CHECK_OUTCOME_RETENTION
+			})
+		});
+
 		let potentially_active_votes = PvfActiveVoteList::<T>::get();
 
 		// Initially empty list which contains all the PVF active votes that made it through this
@@ -1859,6 +1947,13 @@ impl<T: Config> Pallet<T> {
 				actually_active_votes.push(vote_subject);
 			} else {
 				// TTL is reached. Reject.
+				weight += T::DbWeight::get().reads_writes(1, 1);
+				Self::note_pvf_check_outcome(
+					vote_subject,
+					&vote_state,
+					cfg,
+					PvfCheckOutcome::Rejected,
+				);
 				weight += Self::enact_pvf_rejected(&vote_subject, vote_state.causes);
 			}
 		}
@@ -1869,6 +1964,19 @@ impl<T: Config> Pallet<T> {
 		weight
 	}
 
+	/// Records the outcome of a concluded PVF pre-checking vote, so that it can be queried for a
+	/// while afterwards.
+	fn note_pvf_check_outcome(
+		code_hash: ValidationCodeHash,
+		vote: &PvfCheckActiveVoteState<BlockNumberFor<T>>,
+		cfg: &configuration::HostConfiguration<BlockNumberFor<T>>,
+		outcome: PvfCheckOutcome,
+	) {
+		let session = shared::CurrentSessionIndex::<T>::get();
+		let status = vote.status(session, cfg.pvf_voting_ttl, Some(outcome));
+		RecentPvfCheckOutcomes::<T>::append((code_hash, session, status));
+	}
+
 	fn enact_pvf_accepted(
 		now: BlockNumberFor<T>,
 		code_hash: &ValidationCodeHash,
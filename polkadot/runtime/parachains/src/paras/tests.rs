@@ -1362,6 +1362,93 @@ fn pvf_check_onboarding_reject_on_expiry() {
 	});
 }
 
+#[test]
+fn pvf_check_status_works() {
+	let pvf_voting_ttl = 2;
+	let (a, b) = (ParaId::from(111), ParaId::from(222));
+	let (code_a, code_b) = (test_validation_code_1(), test_validation_code_2());
+
+	let genesis_config = MockGenesisConfig {
+		configuration: crate::configuration::GenesisConfig {
+			config: HostConfiguration { pvf_voting_ttl, ..Default::default() },
+		},
+		..Default::default()
+	};
+
+	new_test_ext(genesis_config).execute_with(|| {
+		run_to_block(1, Some(vec![1]));
+
+		for (id, validation_code) in [(a, code_a.clone()), (b, code_b.clone())] {
+			assert_ok!(Paras::schedule_para_initialize(
+				id,
+				ParaGenesisArgs {
+					para_kind: ParaKind::Parathread,
+					genesis_head: vec![2].into(),
+					validation_code,
+				},
+			));
+		}
+		assert_eq!(Paras::pvf_check_statuses().len(), 2);
+
+		[(true, 0), (false, 1)]
+			.into_iter()
+			.map(|(accept, i)| PvfCheckStatement {
+				accept,
+				subject: code_a.hash(),
+				session_index: 1,
+				validator_index: i.into(),
+			})
+			.for_each(sign_and_include_pvf_check_statement);
+		assert_eq!(
+			Paras::pvf_check_status(code_a.hash()),
+			Some(PvfCheckStatus {
+				votes_accept: 1,
+				votes_reject: 1,
+				n_validators: 5,
+				deadline_session: 3,
+				outcome: None,
+			})
+		);
+
+		// The outcome is reported once the vote concludes.
+		submit_super_majority_pvf_votes(&code_b, 1, true);
+		assert_eq!(
+			Paras::pvf_check_status(code_b.hash()),
+			Some(PvfCheckStatus {
+				votes_accept: 4,
+				votes_reject: 0,
+				n_validators: 5,
+				deadline_session: 3,
+				outcome: Some(PvfCheckOutcome::Accepted),
+			})
+		);
+
+		// The ballots are reset at each session and the vote is rejected once the deadline is
+		// reached.
+		run_to_block(2, Some(vec![2]));
+		assert_eq!(Paras::pvf_check_status(code_a.hash()).unwrap().votes_accept, 0);
+		run_to_block(3, Some(vec![3]));
+		assert_eq!(
+			Paras::pvf_check_status(code_a.hash()),
+			Some(PvfCheckStatus {
+				votes_accept: 0,
+				votes_reject: 0,
+				n_validators: 5,
+				deadline_session: 3,
+				outcome: Some(PvfCheckOutcome::Rejected),
+			})
+		);
+
+		// Outcomes are forgotten after a while.
+		let last = 1 + PVF_CHECK_OUTCOME_RETENTION;
+		run_to_block(last, Some((4..=last).collect()));
+		assert!(Paras::pvf_check_status(code_a.hash()).is_some());
+		assert!(Paras::pvf_check_status(code_b.hash()).is_none());
+		run_to_block(last + 2, Some(vec![last + 1, last + 2]));
+		assert!(Paras::pvf_check_statuses().is_empty());
+	});
+}
+
 #[test]
 fn pvf_check_upgrade_reject() {
 	let a = ParaId::from(111);
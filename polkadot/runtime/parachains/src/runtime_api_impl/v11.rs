@@ -298,6 +298,21 @@ where
 					CandidateEvent::CandidateBacked(c, h, core, group),
 				RawEvent::<T>::CandidateIncluded(c, h, core, group) =>
 					CandidateEvent::CandidateIncluded(c, h, core, group),
+				RawEvent::<T>::CandidateBackedV2 {
+					candidate,
+					head_data,
+					core_index,
+					group_index,
+					..
+				} => CandidateEvent::CandidateBacked(candidate, head_data, core_index, group_index),
+				RawEvent::<T>::CandidateIncludedV2 {
+					candidate,
+					head_data,
+					core_index,
+					group_index,
+					..
+				} =>
+					CandidateEvent::CandidateIncluded(candidate, head_data, core_index, group_index),
 				RawEvent::<T>::CandidateTimedOut(c, h, core) =>
 					CandidateEvent::CandidateTimedOut(c, h, core),
 				// Not needed for candidate events.
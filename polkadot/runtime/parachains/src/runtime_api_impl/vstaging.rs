@@ -16,11 +16,15 @@
 
 //! Put implementations of functions from staging APIs here.
 
-use crate::{configuration, initializer, paras};
+use crate::{configuration, initializer, paras, scheduler};
 use alloc::vec::Vec;
 
 use frame_system::pallet_prelude::*;
-use polkadot_primitives::{vstaging::async_backing::Constraints, Id as ParaId};
+use polkadot_primitives::{
+	vstaging::{async_backing::Constraints, BackingGroups},
+	CoreIndex, Id as ParaId,
+};
+use sp_runtime::traits::One;
 
 /// Implementation for `constraints` function from the runtime API
 pub fn backing_constraints<T: initializer::Config>(
@@ -62,3 +66,23 @@ pub fn validation_code_bomb_limit<T: initializer::Config>() -> u32 {
 pub fn para_ids<T: initializer::Config>() -> Vec<ParaId> {
 	paras::Heads::<T>::iter_keys().collect()
 }
+
+/// Implementation for `backing_groups` function from the runtime API
+pub fn backing_groups<T: initializer::Config>() -> BackingGroups<BlockNumberFor<T>> {
+	// Same as in `validator_groups`, the groups are the ones of the block built on top of the relay
+	// parent.
+	let now = frame_system::Pallet::<T>::block_number() + One::one();
+
+	let validator_groups = scheduler::ValidatorGroups::<T>::get();
+	let core_groups = (0..validator_groups.len() as u32)
+		.map(CoreIndex)
+		.filter_map(|core_index| {
+			scheduler::Pallet::<T>::group_assigned_to_core(core_index, now)
+				.map(|group_index| (core_index, group_index))
+		})
+		.collect();
+	let blocks_until_rotation =
+		scheduler::Pallet::<T>::group_rotation_info(now).next_rotation_at() - now;
+
+	BackingGroups { core_groups, validator_groups, blocks_until_rotation }
+}
@@ -479,6 +479,59 @@ fn schedule_rotates_groups() {
 	});
 }
 
+#[test]
+fn backing_groups_rotate_at_the_rotation_boundary() {
+	let config = {
+		let mut config = default_config();
+		config.scheduler_params.lookahead = 1;
+		config.scheduler_params.num_cores = 2;
+		config
+	};
+
+	let rotation_frequency = config.scheduler_params.group_rotation_frequency;
+
+	let genesis_config = genesis_config(&config);
+
+	new_test_ext(genesis_config).execute_with(|| {
+		register_para(ParaId::from(1_u32));
+		register_para(ParaId::from(2_u32));
+
+		// start a new session to activate, 2 validators for 2 cores.
+		run_to_block(1, |number| match number {
+			1 => Some(SessionChangeNotification {
+				new_config: config.clone(),
+				validators: vec![
+					ValidatorId::from(Sr25519Keyring::Alice.public()),
+					ValidatorId::from(Sr25519Keyring::Eve.public()),
+				],
+				..Default::default()
+			}),
+			_ => None,
+		});
+		assert_eq!(scheduler::SessionStartBlock::<Test>::get(), 1);
+
+		// The groups are the ones of the block built on top of the current one, which is the last
+		// block before the first rotation.
+		run_to_block(rotation_frequency - 1, |_| None);
+		let backing_groups = crate::runtime_api_impl::vstaging::backing_groups::<Test>();
+		assert_eq!(backing_groups.validator_groups, scheduler::ValidatorGroups::<Test>::get());
+		assert_eq!(
+			backing_groups.core_groups,
+			BTreeMap::from([(CoreIndex(0), GroupIndex(0)), (CoreIndex(1), GroupIndex(1))])
+		);
+		assert_eq!(backing_groups.blocks_until_rotation, 1);
+
+		// The block built on top of the current one is the first block after the rotation.
+		run_to_block(rotation_frequency, |_| None);
+		let backing_groups = crate::runtime_api_impl::vstaging::backing_groups::<Test>();
+		assert_eq!(
+			backing_groups.core_groups,
+			BTreeMap::from([(CoreIndex(0), GroupIndex(1)), (CoreIndex(1), GroupIndex(0))])
+		);
+		assert_eq!(backing_groups.blocks_until_rotation, rotation_frequency);
+	});
+}
+
 #[test]
 fn availability_predicate_works() {
 	let genesis_config = genesis_config(&default_config());
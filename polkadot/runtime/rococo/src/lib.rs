@@ -49,7 +49,7 @@ use pallet_nis::WithMaximumOf;
 use polkadot_primitives::{
 	slashing,
 	vstaging::{
-		async_backing::Constraints, CandidateEvent,
+		async_backing::Constraints, BackingGroups, CandidateEvent,
 		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState, ScrapedOnChainVotes,
 	},
 	AccountId, AccountIndex, ApprovalVotingParams, Balance, BlockNumber, CandidateHash, CoreIndex,
@@ -245,6 +245,9 @@ parameter_types! {
 		BlockWeights::get().max_block;
 	pub const MaxScheduledPerBlock: u32 = 50;
 	pub const NoPreimagePostponement: Option<u32> = Some(10);
+	pub const SchedulerNormalShare: Perbill = Perbill::from_percent(80);
+	pub const SchedulerBulkShare: Perbill = Perbill::from_percent(50);
+	pub const SchedulerMaxPostponement: BlockNumber = 1 * DAYS;
 }
 
 /// Used the compare the privilege of an origin inside the scheduler.
@@ -342,6 +345,11 @@ impl pallet_scheduler::Config for Runtime {
 	type ScheduleOrigin = EitherOf<EnsureRoot<AccountId>, AuctionAdmin>;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
+	type ClassLimits = pallet_scheduler::WeightShares<
+		SchedulerNormalShare,
+		SchedulerBulkShare,
+		SchedulerMaxPostponement,
+	>;
 	type OriginPrivilegeCmp = OriginPrivilegeCmp;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
@@ -349,6 +357,7 @@ impl pallet_scheduler::Config for Runtime {
 
 parameter_types! {
 	pub const PreimageHoldReason: RuntimeHoldReason = RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+	pub const PreimageUploadLifetime: BlockNumber = 1 * DAYS;
 }
 
 impl pallet_preimage::Config for Runtime {
@@ -366,6 +375,7 @@ impl pallet_preimage::Config for Runtime {
 			Balance,
 		>,
 	>;
+	type UploadLifetime = PreimageUploadLifetime;
 }
 
 parameter_types! {
@@ -395,6 +405,9 @@ impl pallet_indices::Config for Runtime {
 	type AccountIndex = AccountIndex;
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
+	type ExpiryPeriod = ();
+	type GracePeriod = ();
+	type RenewalFee = ();
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_indices::WeightInfo<Runtime>;
 }
@@ -418,8 +431,11 @@ impl pallet_balances::Config for Runtime {
 	type FreezeIdentifier = ();
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type MaxFreezes = ConstU32<1>;
 	type DoneSlashHandler = ();
+	type Scheduler = Scheduler;
+	type MaxScheduledTransferDelay = ConstU32<{ 30 * DAYS }>;
 }
 
 parameter_types! {
@@ -446,12 +462,15 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Babe;
 	type MinimumPeriod = MinimumPeriod;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = frame_support::traits::ConstU64<0>;
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Babe>;
 	type EventHandler = ();
+	type AuthorStatsWindow = ();
 }
 
 impl_opaque_keys! {
@@ -486,6 +505,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 pub struct FullIdentificationOf;
@@ -561,6 +582,9 @@ impl pallet_treasury::Config for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type MaxRecurringSpends = ConstU32<50>;
+	type AssetSwap = ();
+	type MaxSwapSlippage = ();
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
@@ -811,6 +835,10 @@ impl pallet_multisig::Config for Runtime {
 	type MaxSignatories = MaxSignatories;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -867,6 +895,7 @@ impl pallet_vesting::Config for Runtime {
 	type MinVestedTransfer = MinVestedTransfer;
 	type WeightInfo = weights::pallet_vesting::WeightInfo<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type SplitOrigin = EnsureSigned<AccountId>;
 	type BlockNumberProvider = System;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
@@ -1019,6 +1048,8 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type TransferMeter = ();
+	type MaxAllowanceAssets = ConstU32<0>;
 }
 
 impl parachains_origin::Config for Runtime {}
@@ -1101,6 +1132,14 @@ impl ProcessMessage for MessageProcessor {
 	}
 }
 
+parameter_types! {
+	pub const MessageQueueMaxDeadLetters: u32 = 16;
+	pub const DeadLetterBaseDeposit: Balance = deposit(1, 0);
+	pub const DeadLetterByteDeposit: Balance = deposit(0, 1);
+	pub const DeadLetterHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::MessageQueue(pallet_message_queue::HoldReason::DeadLetter);
+}
+
 impl pallet_message_queue::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Size = u32;
@@ -1108,6 +1147,19 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = MessageQueueMaxStale;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type MaxDeadLetters = MessageQueueMaxDeadLetters;
+	// Sovereign accounts pay for the dead letters of their queues.
+	type DeadLetterDepositor = parachains_inclusion::ParaSovereignAccount<AccountId>;
+	type DeadLetterConsideration = frame_support::traits::fungible::HoldConsideration<
+		AccountId,
+		Balances,
+		DeadLetterHoldReason,
+		frame_support::traits::LinearStoragePrice<
+			DeadLetterBaseDeposit,
+			DeadLetterByteDeposit,
+			Balance,
+		>,
+	>;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type MessageProcessor = MessageProcessor;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1134,6 +1186,17 @@ impl parachains_hrmp::Config for Runtime {
 		HrmpChannelSizeAndCapacityWithSystemRatio,
 	>;
 	type VersionWrapper = crate::XcmPallet;
+	type AutoAcceptChannelRequestsFrom = parachains_hrmp::SystemParachains;
+	type MaxAutoAcceptedChannelRequests = ConstU32<16>;
+	type DepositRefunder = parachains_hrmp::TeleportDepositRefund<
+		xcm_config::AssetHub,
+		xcm_config::XcmRouter,
+		xcm_config::LocalAssetTransactor,
+		xcm_builder::AliasesIntoAccountId32<
+			xcm_config::ThisNetwork,
+			<Runtime as frame_system::Config>::AccountId,
+		>,
+	>;
 	type WeightInfo = weights::polkadot_runtime_parachains_hrmp::WeightInfo<Runtime>;
 }
 
@@ -1208,6 +1271,10 @@ impl parachains_disputes::Config for Runtime {
 	type WeightInfo = weights::polkadot_runtime_parachains_disputes::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub ExonerationPeriod: SessionIndex = BondingDuration::get() * SessionsPerEra::get();
+}
+
 impl parachains_slashing::Config for Runtime {
 	type KeyOwnerProofSystem = Historical;
 	type KeyOwnerProof =
@@ -1223,10 +1290,15 @@ impl parachains_slashing::Config for Runtime {
 	>;
 	type WeightInfo = parachains_slashing::TestWeightInfo;
 	type BenchmarkingConfig = parachains_slashing::BenchConfig<200>;
+	type ExonerateOrigin = EnsureRoot<AccountId>;
+	type SlashCompensation = ();
+	type ExonerationPeriod = ExonerationPeriod;
 }
 
 parameter_types! {
 	pub const ParaDeposit: Balance = 40 * UNITS;
+	pub const TransferOfferDeposit: Balance = deposit(1, 100);
+	pub const TransferOfferDuration: BlockNumber = 7 * DAYS;
 }
 
 impl paras_registrar::Config for Runtime {
@@ -1236,6 +1308,8 @@ impl paras_registrar::Config for Runtime {
 	type OnSwap = (Crowdloan, Slots, SwapLeases);
 	type ParaDeposit = ParaDeposit;
 	type DataDepositPerByte = DataDepositPerByte;
+	type TransferOfferDeposit = TransferOfferDeposit;
+	type TransferOfferDuration = TransferOfferDuration;
 	type WeightInfo = weights::polkadot_runtime_common_paras_registrar::WeightInfo<Runtime>;
 }
 
@@ -1317,9 +1391,12 @@ impl pallet_balances::Config<NisCounterpartInstance> for Runtime {
 	type WeightInfo = weights::pallet_balances_nis_counterpart_balances::WeightInfo<Runtime>;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ConstU32<1>;
 	type DoneSlashHandler = ();
+	type Scheduler = ();
+	type MaxScheduledTransferDelay = ConstU32<{ 30 * DAYS }>;
 }
 
 parameter_types! {
@@ -1465,6 +1542,8 @@ impl pallet_migrations::Config for Runtime {
 	type MigrationStatusHandler = ();
 	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
 	type MaxServiceWeight = MbmServiceWeight;
+	type PauseOrigin = EnsureRoot<AccountId>;
+	type ProgressEventInterval = ConstU32<50>;
 	type WeightInfo = weights::pallet_migrations::WeightInfo<Runtime>;
 }
 
@@ -1478,6 +1557,13 @@ impl pallet_root_testing::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+parameter_types! {
+	pub const AssetRateFeedPeriod: BlockNumber = 1 * HOURS;
+	pub const AssetRateMaxFeedDeviation: Perbill = Perbill::from_percent(10);
+	pub const AssetRateOffchainRepeat: BlockNumber = 5 * MINUTES;
+	pub const AssetRateUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+}
+
 impl pallet_asset_rate::Config for Runtime {
 	type WeightInfo = weights::pallet_asset_rate::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
@@ -1486,6 +1572,12 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type AssetKind = <Runtime as pallet_treasury::Config>::AssetKind;
+	type FeederId = pallet_asset_rate::sr25519::FeederId;
+	type MaxFeeders = ConstU32<16>;
+	type FeedPeriod = AssetRateFeedPeriod;
+	type MaxFeedDeviation = AssetRateMaxFeedDeviation;
+	type OffchainRepeat = AssetRateOffchainRepeat;
+	type UnsignedPriority = AssetRateUnsignedPriority;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::AssetRateArguments;
 }
@@ -1794,6 +1886,7 @@ pub mod migrations {
 
 		// migrates session storage item
 		pallet_session::migrations::v1::MigrateV0ToV1<Runtime, pallet_session::migrations::v1::InitOffenceSeverity<Runtime>>,
+		pallet_session::migrations::v2::MigrateV1ToV2<Runtime, sp_runtime::traits::ConvertInto>,
 
         // permanent
         pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
@@ -2014,7 +2107,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(14)]
+	#[api_version(15)]
 	impl polkadot_primitives::runtime_api::ParachainHost<Block> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			parachains_runtime_api_impl::validators::<Runtime>()
@@ -2196,6 +2289,10 @@ sp_api::impl_runtime_apis! {
 		fn para_ids() -> Vec<ParaId> {
 			parachains_staging_runtime_api_impl::para_ids::<Runtime>()
 		}
+
+		fn backing_groups() -> BackingGroups<BlockNumber> {
+			parachains_staging_runtime_api_impl::backing_groups::<Runtime>()
+		}
 	}
 
 	#[api_version(5)]
@@ -2272,7 +2369,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(2)]
+	#[api_version(3)]
 	impl mmr::MmrApi<Block, mmr::Hash, BlockNumber> for Runtime {
 		fn mmr_root() -> Result<mmr::Hash, mmr::Error> {
 			Ok(pallet_mmr::RootHash::<Runtime>::get())
@@ -2299,6 +2396,21 @@ sp_api::impl_runtime_apis! {
 			)
 		}
 
+		fn generate_batch_proof_for_range(
+			start_block: BlockNumber,
+			end_block: BlockNumber,
+		) -> Result<(Vec<mmr::EncodableOpaqueLeaf>, mmr::LeafProof<mmr::Hash>), mmr::Error> {
+			Mmr::generate_proof_for_range(start_block, end_block).map(|(leaves, proof)| {
+				(
+					leaves
+						.into_iter()
+						.map(|leaf| mmr::EncodableOpaqueLeaf::from_leaf(&leaf))
+						.collect(),
+					proof,
+				)
+			})
+		}
+
 		fn verify_proof(leaves: Vec<mmr::EncodableOpaqueLeaf>, proof: mmr::LeafProof<mmr::Hash>)
 			-> Result<(), mmr::Error>
 		{
@@ -59,4 +59,18 @@ impl<T: frame_system::Config> pallet_session::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
+	// Storage: Session CurrentIndex (r:1 w:0)
+	// Storage: Staking Ledger (r:1 w:0)
+	// Storage: Session NextKeys (r:1 w:0)
+	// Storage: Session KeyOwner (r:6 w:0)
+	// Storage: Session ScheduledKeysSession (r:1 w:1)
+	// Storage: Session ScheduledKeys (r:0 w:2)
+	fn set_keys_at() -> Weight {
+		// Placeholder until the `set_keys_at` benchmark is run.
+		Weight::from_parts(38_249_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			// `Session::ScheduledKeysCount` of the old and the new session.
+			.saturating_add(T::DbWeight::get().reads_writes(2 as u64, 2 as u64))
+	}
 }
@@ -164,4 +164,72 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::CounterForRecurringSpends` (r:1 w:1)
+	/// Proof: `Treasury::CounterForRecurringSpends` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::RecurringSpendCount` (r:1 w:1)
+	/// Proof: `Treasury::RecurringSpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::NextRecurringPayout` (r:1 w:1)
+	/// Proof: `Treasury::NextRecurringPayout` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::RecurringSpends` (r:0 w:1)
+	/// Proof: `Treasury::RecurringSpends` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	fn spend_recurring() -> Weight {
+		// Placeholder until the `spend_recurring` benchmark is run.
+		Weight::from_parts(14_212_000, 0)
+			.saturating_add(Weight::from_parts(0, 3502))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Treasury::RecurringSpends` (r:1 w:1)
+	/// Proof: `Treasury::RecurringSpends` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::CounterForRecurringSpends` (r:1 w:1)
+	/// Proof: `Treasury::CounterForRecurringSpends` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn cancel_recurring_spend() -> Weight {
+		// Placeholder until the `cancel_recurring_spend` benchmark is run.
+		Weight::from_parts(13_518_000, 0)
+			.saturating_add(Weight::from_parts(0, 3551))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Treasury::NextRecurringPayout` (r:1 w:1)
+	/// Proof: `Treasury::NextRecurringPayout` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::RecurringSpends` (r:51 w:50)
+	/// Proof: `Treasury::RecurringSpends` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCount` (r:1 w:1)
+	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Spends` (r:0 w:50)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 50]`.
+	fn on_initialize_recurring_spends(r: u32, ) -> Weight {
+		// Placeholder until the `on_initialize_recurring_spends` benchmark is run.
+		Weight::from_parts(6_893_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(Weight::from_parts(21_947_000, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2561).saturating_mul(r.into()))
+	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:2 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCount` (r:1 w:1)
+	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendConversions` (r:0 w:1)
+	/// Proof: `Treasury::SpendConversions` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Spends` (r:0 w:1)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
+	fn spend_with_conversion() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `140`
+		//  Estimated: `6024`
+		// Minimum execution time: 16_787_000 picoseconds.
+		Weight::from_parts(17_486_000, 0)
+			.saturating_add(Weight::from_parts(0, 6024))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
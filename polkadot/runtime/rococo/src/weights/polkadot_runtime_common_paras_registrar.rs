@@ -224,4 +224,43 @@ impl<T: frame_system::Config> polkadot_runtime_common::paras_registrar::WeightIn
 			.saturating_add(Weight::from_parts(3_656, 0).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Registrar::Paras` (r:1 w:0)
+	/// Proof: `Registrar::Paras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::TransferOffers` (r:1 w:1)
+	/// Proof: `Registrar::TransferOffers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn offer_para_transfer() -> Weight {
+		// Placeholder until the `offer_para_transfer` benchmark is run.
+		Weight::from_parts(41_318_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Registrar::TransferOffers` (r:1 w:1)
+	/// Proof: `Registrar::TransferOffers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Registrar::Paras` (r:1 w:1)
+	/// Proof: `Registrar::Paras` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Registrar::PendingSwap` (r:0 w:1)
+	/// Proof: `Registrar::PendingSwap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn accept_para_transfer() -> Weight {
+		// Placeholder until the `accept_para_transfer` benchmark is run.
+		Weight::from_parts(79_552_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Registrar::TransferOffers` (r:1 w:1)
+	/// Proof: `Registrar::TransferOffers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_para_transfer() -> Weight {
+		// Placeholder until the `cancel_para_transfer` benchmark is run.
+		Weight::from_parts(34_071_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
@@ -62,7 +62,7 @@ use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use polkadot_primitives::{
 	slashing,
 	vstaging::{
-		async_backing::Constraints, CandidateEvent,
+		async_backing::Constraints, BackingGroups, CandidateEvent,
 		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState, ScrapedOnChainVotes,
 	},
 	AccountId, AccountIndex, Balance, BlockNumber, CandidateHash, CoreIndex, DisputeState,
@@ -253,6 +253,9 @@ impl pallet_indices::Config for Runtime {
 	type AccountIndex = AccountIndex;
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
+	type ExpiryPeriod = ();
+	type GracePeriod = ();
+	type RenewalFee = ();
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 }
@@ -275,9 +278,12 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = ();
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type FreezeIdentifier = ();
 	type MaxFreezes = ConstU32<0>;
 	type DoneSlashHandler = ();
+	type Scheduler = ();
+	type MaxScheduledTransferDelay = ConstU32<{ 30 * DAYS }>;
 }
 
 parameter_types! {
@@ -305,12 +311,15 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Babe;
 	type MinimumPeriod = MinimumPeriod;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = frame_support::traits::ConstU64<0>;
 	type WeightInfo = ();
 }
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Babe>;
 	type EventHandler = Staking;
+	type AuthorStatsWindow = ();
 }
 
 parameter_types! {
@@ -341,6 +350,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = ();
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 impl pallet_session::historical::Config for Runtime {
@@ -535,6 +546,7 @@ impl pallet_vesting::Config for Runtime {
 	type MinVestedTransfer = MinVestedTransfer;
 	type WeightInfo = ();
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type SplitOrigin = frame_system::EnsureSigned<AccountId>;
 	type BlockNumberProvider = System;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
@@ -568,6 +580,10 @@ impl parachains_disputes::Config for Runtime {
 	type WeightInfo = parachains_disputes::TestWeightInfo;
 }
 
+parameter_types! {
+	pub ExonerationPeriod: SessionIndex = BondingDuration::get() * SessionsPerEra::get();
+}
+
 impl parachains_slashing::Config for Runtime {
 	type KeyOwnerProofSystem = Historical;
 	type KeyOwnerProof =
@@ -583,6 +599,9 @@ impl parachains_slashing::Config for Runtime {
 	>;
 	type WeightInfo = parachains_disputes::slashing::TestWeightInfo;
 	type BenchmarkingConfig = parachains_slashing::BenchConfig<1000>;
+	type ExonerateOrigin = EnsureRoot<AccountId>;
+	type SlashCompensation = ();
+	type ExonerationPeriod = ExonerationPeriod;
 }
 
 impl parachains_paras_inherent::Config for Runtime {
@@ -652,6 +671,9 @@ impl parachains_hrmp::Config for Runtime {
 		HrmpChannelSizeAndCapacityWithSystemRatio,
 	>;
 	type VersionWrapper = crate::Xcm;
+	type AutoAcceptChannelRequestsFrom = ();
+	type MaxAutoAcceptedChannelRequests = ConstU32<0>;
+	type DepositRefunder = ();
 	type WeightInfo = parachains_hrmp::TestWeightInfo;
 }
 
@@ -965,7 +987,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(14)]
+	#[api_version(15)]
 	impl polkadot_primitives::runtime_api::ParachainHost<Block> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			runtime_impl::validators::<Runtime>()
@@ -1144,6 +1166,10 @@ sp_api::impl_runtime_apis! {
 		fn para_ids() -> Vec<ParaId> {
 			staging_runtime_impl::para_ids::<Runtime>()
 		}
+
+		fn backing_groups() -> BackingGroups<BlockNumber> {
+			staging_runtime_impl::backing_groups::<Runtime>()
+		}
 	}
 
 	impl sp_consensus_beefy::BeefyApi<Block, BeefyId> for Runtime {
@@ -1202,6 +1228,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(3)]
 	impl mmr::MmrApi<Block, Hash, BlockNumber> for Runtime {
 		fn mmr_root() -> Result<Hash, mmr::Error> {
 			Err(mmr::Error::PalletNotIncluded)
@@ -1218,6 +1245,13 @@ sp_api::impl_runtime_apis! {
 			Err(mmr::Error::PalletNotIncluded)
 		}
 
+		fn generate_batch_proof_for_range(
+			_start_block: BlockNumber,
+			_end_block: BlockNumber,
+		) -> Result<(Vec<mmr::EncodableOpaqueLeaf>, mmr::LeafProof<Hash>), mmr::Error> {
+			Err(mmr::Error::PalletNotIncluded)
+		}
+
 		fn verify_proof(_leaves: Vec<mmr::EncodableOpaqueLeaf>, _proof: mmr::LeafProof<Hash>)
 			-> Result<(), mmr::Error>
 		{
@@ -56,7 +56,7 @@ use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
 use polkadot_primitives::{
 	slashing,
 	vstaging::{
-		async_backing::Constraints, CandidateEvent,
+		async_backing::Constraints, BackingGroups, CandidateEvent,
 		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, CoreState, ScrapedOnChainVotes,
 	},
 	AccountId, AccountIndex, ApprovalVotingParams, Balance, BlockNumber, CandidateHash, CoreIndex,
@@ -234,6 +234,9 @@ parameter_types! {
 		BlockWeights::get().max_block;
 	pub const MaxScheduledPerBlock: u32 = 50;
 	pub const NoPreimagePostponement: Option<u32> = Some(10);
+	pub const SchedulerNormalShare: Perbill = Perbill::from_percent(80);
+	pub const SchedulerBulkShare: Perbill = Perbill::from_percent(50);
+	pub const SchedulerMaxPostponement: BlockNumber = 1 * DAYS;
 }
 
 impl pallet_scheduler::Config for Runtime {
@@ -247,6 +250,11 @@ impl pallet_scheduler::Config for Runtime {
 	type ScheduleOrigin = EitherOf<EnsureRoot<AccountId>, AuctionAdmin>;
 	type MaxScheduledPerBlock = MaxScheduledPerBlock;
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
+	type ClassLimits = pallet_scheduler::WeightShares<
+		SchedulerNormalShare,
+		SchedulerBulkShare,
+		SchedulerMaxPostponement,
+	>;
 	type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = System;
@@ -332,6 +340,10 @@ impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParamet
 	}
 }
 
+parameter_types! {
+	pub const PreimageUploadLifetime: BlockNumber = 1 * DAYS;
+}
+
 impl pallet_preimage::Config for Runtime {
 	type WeightInfo = weights::pallet_preimage::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
@@ -343,6 +355,7 @@ impl pallet_preimage::Config for Runtime {
 		PreimageHoldReason,
 		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
 	>;
+	type UploadLifetime = PreimageUploadLifetime;
 }
 
 parameter_types! {
@@ -383,6 +396,9 @@ impl pallet_indices::Config for Runtime {
 	type AccountIndex = AccountIndex;
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
+	type ExpiryPeriod = ();
+	type GracePeriod = ();
+	type RenewalFee = ();
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_indices::WeightInfo<Runtime>;
 }
@@ -405,9 +421,12 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = weights::pallet_balances::WeightInfo<Runtime>;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
 	type DoneSlashHandler = ();
+	type Scheduler = Scheduler;
+	type MaxScheduledTransferDelay = ConstU32<{ 30 * DAYS }>;
 }
 
 parameter_types! {
@@ -498,12 +517,15 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Babe;
 	type MinimumPeriod = MinimumPeriod;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = frame_support::traits::ConstU64<0>;
 	type WeightInfo = weights::pallet_timestamp::WeightInfo<Runtime>;
 }
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Babe>;
 	type EventHandler = StakingAhClient;
+	type AuthorStatsWindow = ();
 }
 
 parameter_types! {
@@ -535,6 +557,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = weights::pallet_session::WeightInfo<Runtime>;
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 impl pallet_session::historical::Config for Runtime {
@@ -963,6 +987,9 @@ impl pallet_treasury::Config for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type MaxRecurringSpends = ConstU32<50>;
+	type AssetSwap = ();
+	type MaxSwapSlippage = ();
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
@@ -1169,6 +1196,10 @@ impl pallet_multisig::Config for Runtime {
 	type MaxSignatories = MaxSignatories;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -1203,6 +1234,7 @@ impl pallet_vesting::Config for Runtime {
 	type MinVestedTransfer = MinVestedTransfer;
 	type WeightInfo = weights::pallet_vesting::WeightInfo<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type SplitOrigin = EnsureSigned<AccountId>;
 	type BlockNumberProvider = System;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
@@ -1382,6 +1414,8 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type TransferMeter = ();
+	type MaxAllowanceAssets = ConstU32<0>;
 }
 
 impl parachains_origin::Config for Runtime {}
@@ -1464,6 +1498,14 @@ impl ProcessMessage for MessageProcessor {
 	}
 }
 
+parameter_types! {
+	pub const MessageQueueMaxDeadLetters: u32 = 16;
+	pub const DeadLetterBaseDeposit: Balance = deposit(1, 0);
+	pub const DeadLetterByteDeposit: Balance = deposit(0, 1);
+	pub const DeadLetterHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::MessageQueue(pallet_message_queue::HoldReason::DeadLetter);
+}
+
 impl pallet_message_queue::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Size = u32;
@@ -1471,6 +1513,19 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = MessageQueueMaxStale;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type MaxDeadLetters = MessageQueueMaxDeadLetters;
+	// Sovereign accounts pay for the dead letters of their queues.
+	type DeadLetterDepositor = parachains_inclusion::ParaSovereignAccount<AccountId>;
+	type DeadLetterConsideration = frame_support::traits::fungible::HoldConsideration<
+		AccountId,
+		Balances,
+		DeadLetterHoldReason,
+		frame_support::traits::LinearStoragePrice<
+			DeadLetterBaseDeposit,
+			DeadLetterByteDeposit,
+			Balance,
+		>,
+	>;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type MessageProcessor = MessageProcessor;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1497,6 +1552,17 @@ impl parachains_hrmp::Config for Runtime {
 		HrmpChannelSizeAndCapacityWithSystemRatio,
 	>;
 	type VersionWrapper = crate::XcmPallet;
+	type AutoAcceptChannelRequestsFrom = parachains_hrmp::SystemParachains;
+	type MaxAutoAcceptedChannelRequests = ConstU32<16>;
+	type DepositRefunder = parachains_hrmp::TeleportDepositRefund<
+		xcm_config::AssetHub,
+		xcm_config::XcmRouter,
+		xcm_config::LocalAssetTransactor,
+		xcm_builder::AliasesIntoAccountId32<
+			xcm_config::ThisNetwork,
+			<Runtime as frame_system::Config>::AccountId,
+		>,
+	>;
 	type WeightInfo = weights::polkadot_runtime_parachains_hrmp::WeightInfo<Self>;
 }
 
@@ -1590,6 +1656,10 @@ impl parachains_disputes::Config for Runtime {
 	type WeightInfo = weights::polkadot_runtime_parachains_disputes::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	pub ExonerationPeriod: SessionIndex = BondingDuration::get() * SessionsPerEra::get();
+}
+
 impl parachains_slashing::Config for Runtime {
 	type KeyOwnerProofSystem = Historical;
 	type KeyOwnerProof =
@@ -1605,11 +1675,18 @@ impl parachains_slashing::Config for Runtime {
 	>;
 	type WeightInfo = weights::polkadot_runtime_parachains_disputes_slashing::WeightInfo<Runtime>;
 	type BenchmarkingConfig = parachains_slashing::BenchConfig<300>;
+	type ExonerateOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
+	// Reported offences are slashed on Asset Hub, where the deferred slashes of exonerated
+	// validators are cancelled with `cancel_deferred_slash`.
+	type SlashCompensation = ();
+	type ExonerationPeriod = ExonerationPeriod;
 }
 
 parameter_types! {
 	pub const ParaDeposit: Balance = 2000 * CENTS;
 	pub const RegistrarDataDepositPerByte: Balance = deposit(0, 1);
+	pub const TransferOfferDeposit: Balance = deposit(1, 100);
+	pub const TransferOfferDuration: BlockNumber = 7 * DAYS;
 }
 
 impl paras_registrar::Config for Runtime {
@@ -1619,6 +1696,8 @@ impl paras_registrar::Config for Runtime {
 	type OnSwap = (Crowdloan, Slots, SwapLeases);
 	type ParaDeposit = ParaDeposit;
 	type DataDepositPerByte = RegistrarDataDepositPerByte;
+	type TransferOfferDeposit = TransferOfferDeposit;
+	type TransferOfferDuration = TransferOfferDuration;
 	type WeightInfo = weights::polkadot_runtime_common_paras_registrar::WeightInfo<Runtime>;
 }
 
@@ -1744,6 +1823,8 @@ impl pallet_migrations::Config for Runtime {
 	type MigrationStatusHandler = ();
 	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
 	type MaxServiceWeight = MbmServiceWeight;
+	type PauseOrigin = EnsureRoot<AccountId>;
+	type ProgressEventInterval = ConstU32<50>;
 	type WeightInfo = weights::pallet_migrations::WeightInfo<Runtime>;
 }
 
@@ -1754,6 +1835,13 @@ parameter_types! {
 	pub const MigrationMaxKeyLen: u32 = 512;
 }
 
+parameter_types! {
+	pub const AssetRateFeedPeriod: BlockNumber = 1 * HOURS;
+	pub const AssetRateMaxFeedDeviation: Perbill = Perbill::from_percent(10);
+	pub const AssetRateOffchainRepeat: BlockNumber = 5 * MINUTES;
+	pub const AssetRateUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+}
+
 impl pallet_asset_rate::Config for Runtime {
 	type WeightInfo = weights::pallet_asset_rate::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
@@ -1762,6 +1850,12 @@ impl pallet_asset_rate::Config for Runtime {
 	type UpdateOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type AssetKind = <Runtime as pallet_treasury::Config>::AssetKind;
+	type FeederId = pallet_asset_rate::sr25519::FeederId;
+	type MaxFeeders = ConstU32<16>;
+	type FeedPeriod = AssetRateFeedPeriod;
+	type MaxFeedDeviation = AssetRateMaxFeedDeviation;
+	type OffchainRepeat = AssetRateOffchainRepeat;
+	type UnsignedPriority = AssetRateUnsignedPriority;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::AssetRateArguments;
 }
@@ -2074,6 +2168,7 @@ pub mod migrations {
 			Runtime,
 			pallet_staking::migrations::v17::MigrateDisabledToSession<Runtime>,
 		>,
+		pallet_session::migrations::v2::MigrateV1ToV2<Runtime, sp_runtime::traits::ConvertInto>,
 		// permanent
 		pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	);
@@ -2237,7 +2332,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(14)]
+	#[api_version(15)]
 	impl polkadot_primitives::runtime_api::ParachainHost<Block> for Runtime {
 		fn validators() -> Vec<ValidatorId> {
 			parachains_runtime_api_impl::validators::<Runtime>()
@@ -2419,6 +2514,10 @@ sp_api::impl_runtime_apis! {
 		fn para_ids() -> Vec<ParaId> {
 			parachains_staging_runtime_api_impl::para_ids::<Runtime>()
 		}
+
+		fn backing_groups() -> BackingGroups<BlockNumber> {
+			parachains_staging_runtime_api_impl::backing_groups::<Runtime>()
+		}
 	}
 
 	#[api_version(5)]
@@ -2495,6 +2594,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(3)]
 	impl mmr::MmrApi<Block, Hash, BlockNumber> for Runtime {
 		fn mmr_root() -> Result<mmr::Hash, mmr::Error> {
 			Ok(pallet_mmr::RootHash::<Runtime>::get())
@@ -2521,6 +2621,21 @@ sp_api::impl_runtime_apis! {
 			)
 		}
 
+		fn generate_batch_proof_for_range(
+			start_block: BlockNumber,
+			end_block: BlockNumber,
+		) -> Result<(Vec<mmr::EncodableOpaqueLeaf>, mmr::LeafProof<mmr::Hash>), mmr::Error> {
+			Mmr::generate_proof_for_range(start_block, end_block).map(|(leaves, proof)| {
+				(
+					leaves
+						.into_iter()
+						.map(|leaf| mmr::EncodableOpaqueLeaf::from_leaf(&leaf))
+						.collect(),
+					proof,
+				)
+			})
+		}
+
 		fn verify_proof(leaves: Vec<mmr::EncodableOpaqueLeaf>, proof: mmr::LeafProof<mmr::Hash>)
 			-> Result<(), mmr::Error>
 		{
@@ -259,4 +259,46 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Vesting::Vesting` (r:2 w:2)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:2)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn split_schedule(l: u32, s: u32, ) -> Weight {
+		// Placeholder until the `split_schedule` benchmark is run.
+		Weight::from_parts(71_840_000, 0)
+			.saturating_add(Weight::from_parts(0, 8538))
+			.saturating_add(Weight::from_parts(74_212, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(163_457, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(Weight::from_parts(0, 50).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 72).saturating_mul(s.into()))
+	}
+	/// Storage: `Vesting::Vesting` (r:2 w:2)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:2)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn reassign_schedule(l: u32, s: u32, ) -> Weight {
+		// Placeholder until the `reassign_schedule` benchmark is run.
+		Weight::from_parts(70_392_000, 0)
+			.saturating_add(Weight::from_parts(0, 8538))
+			.saturating_add(Weight::from_parts(73_906, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(160_118, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(Weight::from_parts(0, 50).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 72).saturating_mul(s.into()))
+	}
 }
@@ -179,6 +179,8 @@ impl pallet_xcm::Config for Runtime {
 	type MaxLockers = frame::traits::ConstU32<0>;
 	type MaxRemoteLockConsumers = frame::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type MaxVersionNotificationsPerBlock = ();
+	type VersionDiscoveryPeriod = ();
 	// How to turn locations into accounts
 	type SovereignAccountOf = LocationToAccountId;
 	// A currency to pay for things and its matcher, we are using the relay token
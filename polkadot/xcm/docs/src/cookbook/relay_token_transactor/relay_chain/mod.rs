@@ -92,6 +92,9 @@ impl pallet_message_queue::Config for Runtime {
 	type QueuePausedQuery = ();
 	type WeightInfo = ();
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
+	type MaxDeadLetters = ();
+	type DeadLetterDepositor = ();
+	type DeadLetterConsideration = ();
 }
 
 construct_runtime! {
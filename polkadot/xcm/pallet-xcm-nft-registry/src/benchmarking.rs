@@ -0,0 +1,75 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM NFT registry pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::v2::*;
+use xcm::latest::prelude::*;
+
+/// The location of a foreign collection of a sibling parachain.
+fn foreign_collection_location() -> Location {
+	Location::new(1, [Parachain(2000), PalletInstance(52), GeneralIndex(1)])
+}
+
+/// Returns the `RegisterOrigin` for `location` and a collection owned by its account.
+fn registrar_and_collection<T: Config>(
+	location: &Location,
+) -> Result<(T::RuntimeOrigin, T::CollectionId), BenchmarkError> {
+	let origin = T::RegisterOrigin::try_successful_origin(location)
+		.map_err(|_| BenchmarkError::Weightless)?;
+	let owner = T::RegisterOrigin::ensure_origin(origin.clone(), location)
+		.map_err(|_| BenchmarkError::Weightless)?;
+	Ok((origin, T::BenchmarkHelper::create_collection(&owner)))
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register_collection() -> Result<(), BenchmarkError> {
+		let location = foreign_collection_location();
+		let (origin, collection) = registrar_and_collection::<T>(&location)?;
+		let versioned_location = Box::new(VersionedLocation::from(location.clone()));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, versioned_location, collection.clone());
+
+		assert_eq!(LocationToCollection::<T>::get(&location), Some(collection));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn deregister_collection() -> Result<(), BenchmarkError> {
+		let location = foreign_collection_location();
+		let (origin, collection) = registrar_and_collection::<T>(&location)?;
+		LocationToCollection::<T>::insert(&location, &collection);
+		CollectionToLocation::<T>::insert(&collection, &location);
+		let versioned_location = Box::new(VersionedLocation::from(location.clone()));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, versioned_location);
+
+		assert_eq!(LocationToCollection::<T>::get(&location), None);
+		assert_eq!(CollectionToLocation::<T>::get(&collection), None);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,244 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # XCM NFT Registry Pallet
+//!
+//! A pallet mapping the XCM [`Location`]s of foreign NFT collections to local collections, e.g.
+//! the ones of `pallet-nfts`.
+//!
+//! The `NonFungiblesAdapter` of `xcm-builder` converts the XCM `AssetId` of an NFT to a local
+//! collection id. With the static converters of `xcm-builder`, accepting a new foreign collection
+//! requires a runtime upgrade. This pallet keeps the mapping in storage instead and implements
+//! [`MaybeEquivalence<Location, CollectionId>`](MaybeEquivalence), so it can be used as the
+//! `ConvertAssetId` of `xcm_builder::MatchedConvertedConcreteId`:
+//!
+//! ```ignore
+//! pub type ForeignNftsConvertedConcreteId = MatchedConvertedConcreteId<
+//! 	CollectionId,
+//! 	ItemId,
+//! 	Everything,
+//! 	XcmNftRegistry,
+//! 	TryConvertInto,
+//! >;
+//! ```
+//!
+//! ## Pallet API
+//!
+//! See the [`pallet`] module for more information about the interfaces this pallet exposes,
+//! including its configuration trait, dispatchables, storage items, events and errors.
+//!
+//! ### Dispatchable Functions
+//!
+//! - `register_collection` - Map the location of a foreign collection to a local collection.
+//! - `deregister_collection` - Remove the mapping of the location of a foreign collection.
+//!
+//! Both calls can be dispatched by [`Config::ForceOrigin`], e.g. governance, for any location. The
+//! [`Config::RegisterOrigin`], e.g. `ForeignCreators`, can manage the mapping of the locations it
+//! controls, but may only map them to local collections owned by the account it resolves to.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use alloc::boxed::Box;
+use frame_support::traits::{tokens::nonfungibles_v2::Inspect, EnsureOrigin, EnsureOriginWithArg};
+use sp_runtime::traits::MaybeEquivalence;
+use xcm::{latest::Location, VersionedLocation};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Helper to provide collections in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<CollectionId, AccountId> {
+	/// Creates a new local collection owned by `owner` and returns its id.
+	fn create_collection(owner: &AccountId) -> CollectionId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for the local collections.
+		type CollectionId: Member + Parameter + MaxEncodedLen;
+
+		/// The local collections foreign collections can be mapped to.
+		type Collections: Inspect<Self::AccountId, CollectionId = Self::CollectionId>;
+
+		/// The origin which may manage the mapping of the given location. It resolves to the
+		/// account which must own the local collections the location is mapped to.
+		type RegisterOrigin: EnsureOriginWithArg<
+			Self::RuntimeOrigin,
+			Location,
+			Success = Self::AccountId,
+		>;
+
+		/// The origin which may manage the mapping of any location to any local collection.
+		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper to provide collections in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::CollectionId, Self::AccountId>;
+	}
+
+	/// The local collection the location of a foreign collection is mapped to.
+	#[pallet::storage]
+	pub type LocationToCollection<T: Config> =
+		StorageMap<_, Blake2_128Concat, Location, T::CollectionId, OptionQuery>;
+
+	/// The location of the foreign collection a local collection is mapped to.
+	#[pallet::storage]
+	pub type CollectionToLocation<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, Location, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The location of a foreign collection was mapped to a local collection.
+		CollectionRegistered { location: Location, collection: T::CollectionId },
+		/// The mapping of the location of a foreign collection was removed.
+		CollectionDeregistered { location: Location, collection: T::CollectionId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The given location could not be converted to the latest XCM version.
+		BadLocation,
+		/// The location is already mapped to a local collection.
+		LocationAlreadyRegistered,
+		/// The local collection is already mapped to a location.
+		CollectionAlreadyRegistered,
+		/// The local collection does not exist.
+		UnknownCollection,
+		/// The local collection is not owned by the account of the origin.
+		NoPermission,
+		/// The location is not mapped to a local collection.
+		NotRegistered,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Map the location of a foreign collection to an existing local collection.
+		///
+		/// The dispatch origin for this call must be either [`Config::ForceOrigin`] or
+		/// [`Config::RegisterOrigin`] for `location`. In the latter case the local collection must
+		/// be owned by the account the origin resolves to.
+		///
+		/// Neither `location` nor `collection` may be mapped already.
+		///
+		/// Emits `CollectionRegistered`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_collection())]
+		pub fn register_collection(
+			origin: OriginFor<T>,
+			location: Box<VersionedLocation>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let location: Location =
+				(*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			let maybe_owner = Self::ensure_registrar(origin, &location)?;
+
+			ensure!(
+				!LocationToCollection::<T>::contains_key(&location),
+				Error::<T>::LocationAlreadyRegistered
+			);
+			ensure!(
+				!CollectionToLocation::<T>::contains_key(&collection),
+				Error::<T>::CollectionAlreadyRegistered
+			);
+			let owner = T::Collections::collection_owner(&collection)
+				.ok_or(Error::<T>::UnknownCollection)?;
+			if let Some(who) = maybe_owner {
+				ensure!(owner == who, Error::<T>::NoPermission);
+			}
+
+			LocationToCollection::<T>::insert(&location, &collection);
+			CollectionToLocation::<T>::insert(&collection, &location);
+			Self::deposit_event(Event::CollectionRegistered { location, collection });
+			Ok(())
+		}
+
+		/// Remove the mapping of the location of a foreign collection.
+		///
+		/// The dispatch origin for this call must be either [`Config::ForceOrigin`] or
+		/// [`Config::RegisterOrigin`] for `location`.
+		///
+		/// Emits `CollectionDeregistered`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::deregister_collection())]
+		pub fn deregister_collection(
+			origin: OriginFor<T>,
+			location: Box<VersionedLocation>,
+		) -> DispatchResult {
+			let location: Location =
+				(*location).try_into().map_err(|()| Error::<T>::BadLocation)?;
+			Self::ensure_registrar(origin, &location)?;
+
+			let collection =
+				LocationToCollection::<T>::take(&location).ok_or(Error::<T>::NotRegistered)?;
+			CollectionToLocation::<T>::remove(&collection);
+			Self::deposit_event(Event::CollectionDeregistered { location, collection });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Ensure `origin` may manage the mapping of `location`.
+	///
+	/// Returns the account which must own the mapped collections, or `None` for the
+	/// [`Config::ForceOrigin`].
+	fn ensure_registrar(
+		origin: T::RuntimeOrigin,
+		location: &Location,
+	) -> Result<Option<T::AccountId>, sp_runtime::DispatchError> {
+		match T::ForceOrigin::try_origin(origin) {
+			Ok(_) => Ok(None),
+			Err(origin) => Ok(Some(T::RegisterOrigin::ensure_origin(origin, location)?)),
+		}
+	}
+}
+
+impl<T: Config> MaybeEquivalence<Location, T::CollectionId> for Pallet<T> {
+	fn convert(location: &Location) -> Option<T::CollectionId> {
+		LocationToCollection::<T>::get(location)
+	}
+
+	fn convert_back(collection: &T::CollectionId) -> Option<Location> {
+		CollectionToLocation::<T>::get(collection)
+	}
+}
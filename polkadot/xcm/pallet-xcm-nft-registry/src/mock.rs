@@ -0,0 +1,121 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test environment for the XCM NFT registry pallet.
+
+use crate as pallet_xcm_nft_registry;
+use alloc::collections::BTreeMap;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{EnsureOrigin, EnsureOriginWithArg},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_runtime::BuildStorage;
+use xcm::latest::prelude::*;
+
+pub type AccountId = u64;
+pub type CollectionId = u32;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		XcmNftRegistry: pallet_xcm_nft_registry,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+parameter_types! {
+	/// The owners of the existing local collections.
+	pub static CollectionOwners: BTreeMap<CollectionId, AccountId> = BTreeMap::new();
+}
+
+/// Mock of the local collections, which only knows about their owners.
+pub struct MockCollections;
+impl frame_support::traits::tokens::nonfungibles_v2::Inspect<AccountId> for MockCollections {
+	type ItemId = u32;
+	type CollectionId = CollectionId;
+
+	fn owner(_collection: &CollectionId, _item: &u32) -> Option<AccountId> {
+		None
+	}
+
+	fn collection_owner(collection: &CollectionId) -> Option<AccountId> {
+		CollectionOwners::get().get(collection).cloned()
+	}
+}
+
+/// Creates a local collection owned by `owner`.
+pub fn create_collection(owner: AccountId) -> CollectionId {
+	CollectionOwners::mutate(|owners| {
+		let collection = owners.len() as CollectionId;
+		owners.insert(collection, owner);
+		collection
+	})
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<CollectionId, AccountId> for MockCollections {
+	fn create_collection(owner: &AccountId) -> CollectionId {
+		create_collection(*owner)
+	}
+}
+
+/// Lets the signed account `n` manage the locations of sibling parachain `n`, similar to
+/// `ForeignCreators`.
+pub struct EnsureSiblingAccount;
+impl EnsureOriginWithArg<RuntimeOrigin, Location> for EnsureSiblingAccount {
+	type Success = AccountId;
+
+	fn try_origin(o: RuntimeOrigin, location: &Location) -> Result<AccountId, RuntimeOrigin> {
+		let who = <EnsureSigned<AccountId> as EnsureOrigin<_>>::try_origin(o.clone())?;
+		match location.unpack() {
+			(1, [Parachain(id), ..]) if AccountId::from(*id) == who => Ok(who),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(location: &Location) -> Result<RuntimeOrigin, ()> {
+		match location.unpack() {
+			(1, [Parachain(id), ..]) => Ok(RuntimeOrigin::signed((*id).into())),
+			_ => Err(()),
+		}
+	}
+}
+
+impl pallet_xcm_nft_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = CollectionId;
+	type Collections = MockCollections;
+	type RegisterOrigin = EnsureSiblingAccount;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockCollections;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,213 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests for the XCM NFT registry pallet.
+
+use crate::{mock::*, CollectionToLocation, Error, Event, LocationToCollection};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::MaybeEquivalence, DispatchError};
+use xcm::{latest::prelude::*, VersionedLocation};
+use xcm_builder::MatchedConvertedConcreteId;
+use xcm_executor::traits::{Error as MatchError, MatchesNonFungibles};
+
+/// The location of a collection of sibling parachain `para`.
+fn collection_location(para: u32) -> Location {
+	Location::new(1, [Parachain(para), PalletInstance(52), GeneralIndex(1)])
+}
+
+fn versioned(location: &Location) -> Box<VersionedLocation> {
+	Box::new(location.clone().into())
+}
+
+#[test]
+fn register_collection_works() {
+	new_test_ext().execute_with(|| {
+		let location = collection_location(2000);
+		let collection = create_collection(2000);
+
+		assert_ok!(XcmNftRegistry::register_collection(
+			RuntimeOrigin::signed(2000),
+			versioned(&location),
+			collection
+		));
+		System::assert_last_event(
+			Event::CollectionRegistered { location: location.clone(), collection }.into(),
+		);
+
+		assert_eq!(LocationToCollection::<Test>::get(&location), Some(collection));
+		assert_eq!(CollectionToLocation::<Test>::get(collection), Some(location.clone()));
+		assert_eq!(XcmNftRegistry::convert(&location), Some(collection));
+		assert_eq!(XcmNftRegistry::convert_back(&collection), Some(location));
+	});
+}
+
+#[test]
+fn register_collection_checks_origin_and_ownership() {
+	new_test_ext().execute_with(|| {
+		let location = collection_location(2000);
+		let collection = create_collection(2000);
+		let other_collection = create_collection(2001);
+
+		// Only the sibling may register its collections.
+		assert_noop!(
+			XcmNftRegistry::register_collection(
+				RuntimeOrigin::signed(2001),
+				versioned(&location),
+				collection
+			),
+			DispatchError::BadOrigin
+		);
+		// The sibling may only use its own collections.
+		assert_noop!(
+			XcmNftRegistry::register_collection(
+				RuntimeOrigin::signed(2000),
+				versioned(&location),
+				other_collection
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			XcmNftRegistry::register_collection(
+				RuntimeOrigin::signed(2000),
+				versioned(&location),
+				7
+			),
+			Error::<Test>::UnknownCollection
+		);
+
+		// The force origin may map any location to any collection.
+		assert_ok!(XcmNftRegistry::register_collection(
+			RuntimeOrigin::root(),
+			versioned(&location),
+			other_collection
+		));
+		assert_eq!(LocationToCollection::<Test>::get(&location), Some(other_collection));
+	});
+}
+
+#[test]
+fn register_collection_rejects_duplicates() {
+	new_test_ext().execute_with(|| {
+		let location = collection_location(2000);
+		let other_location =
+			Location::new(1, [Parachain(2000), PalletInstance(52), GeneralIndex(2)]);
+		let collection = create_collection(2000);
+		let other_collection = create_collection(2000);
+
+		assert_ok!(XcmNftRegistry::register_collection(
+			RuntimeOrigin::signed(2000),
+			versioned(&location),
+			collection
+		));
+		assert_noop!(
+			XcmNftRegistry::register_collection(
+				RuntimeOrigin::signed(2000),
+				versioned(&location),
+				other_collection
+			),
+			Error::<Test>::LocationAlreadyRegistered
+		);
+		assert_noop!(
+			XcmNftRegistry::register_collection(
+				RuntimeOrigin::signed(2000),
+				versioned(&other_location),
+				collection
+			),
+			Error::<Test>::CollectionAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn deregister_collection_works() {
+	new_test_ext().execute_with(|| {
+		let location = collection_location(2000);
+		let collection = create_collection(2000);
+		assert_noop!(
+			XcmNftRegistry::deregister_collection(
+				RuntimeOrigin::signed(2000),
+				versioned(&location)
+			),
+			Error::<Test>::NotRegistered
+		);
+
+		assert_ok!(XcmNftRegistry::register_collection(
+			RuntimeOrigin::signed(2000),
+			versioned(&location),
+			collection
+		));
+		assert_noop!(
+			XcmNftRegistry::deregister_collection(
+				RuntimeOrigin::signed(2001),
+				versioned(&location)
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(XcmNftRegistry::deregister_collection(
+			RuntimeOrigin::signed(2000),
+			versioned(&location)
+		));
+		System::assert_last_event(
+			Event::CollectionDeregistered { location: location.clone(), collection }.into(),
+		);
+
+		assert_eq!(LocationToCollection::<Test>::get(&location), None);
+		assert_eq!(CollectionToLocation::<Test>::get(collection), None);
+
+		// The collection can be mapped again.
+		assert_ok!(XcmNftRegistry::register_collection(
+			RuntimeOrigin::root(),
+			versioned(&collection_location(2001)),
+			collection
+		));
+	});
+}
+
+#[test]
+fn registered_collections_match_non_fungibles() {
+	type ConvertedConcreteId = MatchedConvertedConcreteId<
+		CollectionId,
+		u32,
+		frame_support::traits::Everything,
+		XcmNftRegistry,
+		sp_runtime::traits::TryConvertInto,
+	>;
+
+	new_test_ext().execute_with(|| {
+		let location = collection_location(2000);
+		let collection = create_collection(2000);
+		let nft: Asset = (location.clone(), Index(5)).into();
+
+		assert_eq!(
+			<ConvertedConcreteId as MatchesNonFungibles<CollectionId, u32>>::matches_nonfungibles(
+				&nft
+			),
+			Err(MatchError::AssetIdConversionFailed)
+		);
+
+		assert_ok!(XcmNftRegistry::register_collection(
+			RuntimeOrigin::signed(2000),
+			versioned(&location),
+			collection
+		));
+		assert_eq!(
+			<ConvertedConcreteId as MatchesNonFungibles<CollectionId, u32>>::matches_nonfungibles(
+				&nft
+			),
+			Ok((collection, 5))
+		);
+	});
+}
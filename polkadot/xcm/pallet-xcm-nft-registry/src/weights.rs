@@ -0,0 +1,91 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for `pallet_xcm_nft_registry`.
+//!
+//! NOTE: These weights were written by hand and are placeholders until they are generated with the
+//! `pallet_xcm_nft_registry` benchmarks, e.g. with:
+//! `frame-omni-bencher v1 benchmark pallet --pallet pallet_xcm_nft_registry --extrinsic "*"
+//! --template substrate/.maintain/frame-weight-template.hbs
+//! --output polkadot/xcm/pallet-xcm-nft-registry/src/weights.rs`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_xcm_nft_registry`.
+pub trait WeightInfo {
+	fn register_collection() -> Weight;
+	fn deregister_collection() -> Weight;
+}
+
+/// Weights for `pallet_xcm_nft_registry` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `XcmNftRegistry::LocationToCollection` (r:1 w:1)
+	/// Proof: `XcmNftRegistry::LocationToCollection` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `XcmNftRegistry::CollectionToLocation` (r:1 w:1)
+	/// Proof: `XcmNftRegistry::CollectionToLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn register_collection() -> Weight {
+		// Placeholder until the `register_collection` benchmark is run.
+		Weight::from_parts(18_000_000, 4087)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmNftRegistry::LocationToCollection` (r:1 w:1)
+	/// Proof: `XcmNftRegistry::LocationToCollection` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `XcmNftRegistry::CollectionToLocation` (r:0 w:1)
+	/// Proof: `XcmNftRegistry::CollectionToLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	fn deregister_collection() -> Weight {
+		// Placeholder until the `deregister_collection` benchmark is run.
+		Weight::from_parts(15_000_000, 4087)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `XcmNftRegistry::LocationToCollection` (r:1 w:1)
+	/// Proof: `XcmNftRegistry::LocationToCollection` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `XcmNftRegistry::CollectionToLocation` (r:1 w:1)
+	/// Proof: `XcmNftRegistry::CollectionToLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	fn register_collection() -> Weight {
+		// Placeholder until the `register_collection` benchmark is run.
+		Weight::from_parts(18_000_000, 4087)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `XcmNftRegistry::LocationToCollection` (r:1 w:1)
+	/// Proof: `XcmNftRegistry::LocationToCollection` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	/// Storage: `XcmNftRegistry::CollectionToLocation` (r:0 w:1)
+	/// Proof: `XcmNftRegistry::CollectionToLocation` (`max_values`: None, `max_size`: Some(622), added: 3097, mode: `MaxEncodedLen`)
+	fn deregister_collection() -> Weight {
+		// Placeholder until the `deregister_collection` benchmark is run.
+		Weight::from_parts(15_000_000, 4087)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
@@ -78,6 +78,7 @@ use xcm_runtime_apis::{
 	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
 	fees::Error as XcmPaymentApiError,
 	trusted_query::Error as TrustedQueryApiError,
+	versions::VersionMigrationProgress,
 };
 
 mod errors;
@@ -339,6 +340,21 @@ pub mod pallet {
 		/// The ID type for local consumers of remote locks.
 		type RemoteLockConsumerIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
 
+		/// The maximum number of version notifications sent per block while migrating the stored
+		/// XCM version data to the latest XCM version.
+		///
+		/// Limits the burst of messages to subscribers after an upgrade. Zero means no limit
+		/// besides the weight.
+		#[pallet::constant]
+		type MaxVersionNotificationsPerBlock: Get<u32>;
+
+		/// The number of blocks between two requests for the XCM version of a destination in the
+		/// version discovery queue.
+		///
+		/// Zero means a request is sent every block.
+		#[pallet::constant]
+		type VersionDiscoveryPeriod: Get<BlockNumberFor<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -885,6 +901,11 @@ pub mod pallet {
 	pub(super) type CurrentMigration<T: Config> =
 		StorageValue<_, VersionMigrationStage, OptionQuery>;
 
+	/// The progress of the current or latest migration.
+	#[pallet::storage]
+	pub(super) type VersionMigrationMetrics<T: Config> =
+		StorageValue<_, VersionMigrationProgress, ValueQuery>;
+
 	#[derive(Clone, Encode, Decode, Eq, PartialEq, Ord, PartialOrd, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(MaxConsumers))]
 	pub struct RemoteLockedFungibleRecord<ConsumerIdentifier, MaxConsumers: Get<u32>> {
@@ -1003,12 +1024,13 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			let mut weight_used = Weight::zero();
 			if let Some(migration) = CurrentMigration::<T>::get() {
 				// Consume 10% of block at most
 				let max_weight = T::BlockWeights::get().max_block / 10;
 				let (w, maybe_migration) = Self::lazy_migration(migration, max_weight);
+				weight_used.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
 				if maybe_migration.is_none() {
 					Self::deposit_event(Event::VersionMigrationFinished { version: XCM_VERSION });
 				}
@@ -1016,6 +1038,12 @@ pub mod pallet {
 				weight_used.saturating_accrue(w);
 			}
 
+			// Pace the version negotiation requests.
+			let period = T::VersionDiscoveryPeriod::get();
+			if !period.is_zero() && !(n % period).is_zero() {
+				return weight_used
+			}
+
 			// Here we aim to get one successful version negotiation request sent per block, ordered
 			// by the destinations being most sent to.
 			let mut q = VersionDiscoveryQueue::<T>::take().into_inner();
@@ -2803,13 +2831,42 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Start the lazy migration of the stored XCM version data to the latest XCM version.
+	pub(crate) fn start_version_migration() {
+		CurrentMigration::<T>::put(VersionMigrationStage::default());
+		VersionMigrationMetrics::<T>::put(VersionMigrationProgress {
+			ongoing: true,
+			..Default::default()
+		});
+	}
+
 	/// Will always make progress, and will do its best not to use much more than `weight_cutoff`
-	/// in doing so.
+	/// or to send more than [`Config::MaxVersionNotificationsPerBlock`] notifications in doing so.
+	///
+	/// The progress is recorded in [`VersionMigrationMetrics`].
 	pub(crate) fn lazy_migration(
+		stage: VersionMigrationStage,
+		weight_cutoff: Weight,
+	) -> (Weight, Option<VersionMigrationStage>) {
+		let mut progress = VersionMigrationMetrics::<T>::get();
+		let (weight_used, maybe_stage) =
+			Self::do_lazy_migration(stage, weight_cutoff, &mut progress);
+		progress.ongoing = maybe_stage.is_some();
+		progress.blocks.saturating_inc();
+		VersionMigrationMetrics::<T>::put(progress);
+		(weight_used, maybe_stage)
+	}
+
+	fn do_lazy_migration(
 		mut stage: VersionMigrationStage,
 		weight_cutoff: Weight,
+		progress: &mut VersionMigrationProgress,
 	) -> (Weight, Option<VersionMigrationStage>) {
 		let mut weight_used = Weight::zero();
+		let max_notifications = T::MaxVersionNotificationsPerBlock::get();
+		let mut notifications = 0u32;
+		let notification_limit_reached =
+			|n: u32| !max_notifications.is_zero() && n >= max_notifications;
 
 		let sv_migrate_weight = T::WeightInfo::migrate_supported_version();
 		let vn_migrate_weight = T::WeightInfo::migrate_version_notifiers();
@@ -2828,6 +2885,9 @@ impl<T: Config> Pallet<T> {
 				for (old_key, value) in SupportedVersion::<T>::drain_prefix(v) {
 					if let Ok(new_key) = old_key.into_latest() {
 						SupportedVersion::<T>::insert(XCM_VERSION, new_key, value);
+						progress.migrated.saturating_inc();
+					} else {
+						progress.failed.saturating_inc();
 					}
 					weight_used.saturating_accrue(sv_migrate_weight);
 					if weight_used.any_gte(weight_cutoff) {
@@ -2842,6 +2902,9 @@ impl<T: Config> Pallet<T> {
 				for (old_key, value) in VersionNotifiers::<T>::drain_prefix(v) {
 					if let Ok(new_key) = old_key.into_latest() {
 						VersionNotifiers::<T>::insert(XCM_VERSION, new_key, value);
+						progress.migrated.saturating_inc();
+					} else {
+						progress.failed.saturating_inc();
 					}
 					weight_used.saturating_accrue(vn_migrate_weight);
 					if weight_used.any_gte(weight_cutoff) {
@@ -2873,10 +2936,12 @@ impl<T: Config> Pallet<T> {
 				let response = Response::Version(xcm_version);
 				let message =
 					Xcm(vec![QueryResponse { query_id, response, max_weight, querier: None }]);
+				notifications.saturating_inc();
 				let event = match send_xcm::<T::XcmRouter>(new_key.clone(), message) {
 					Ok((message_id, cost)) => {
 						let value = (query_id, max_weight, xcm_version);
 						VersionNotifyTargets::<T>::insert(XCM_VERSION, key, value);
+						progress.notified.saturating_inc();
 						Event::VersionChangeNotified {
 							destination: new_key,
 							result: xcm_version,
@@ -2886,12 +2951,13 @@ impl<T: Config> Pallet<T> {
 					},
 					Err(e) => {
 						VersionNotifyTargets::<T>::remove(XCM_VERSION, key);
+						progress.failed.saturating_inc();
 						Event::NotifyTargetSendFail { location: new_key, query_id, error: e.into() }
 					},
 				};
 				Self::deposit_event(event);
 				weight_used.saturating_accrue(vnt_notify_weight);
-				if weight_used.any_gte(weight_cutoff) {
+				if weight_used.any_gte(weight_cutoff) || notification_limit_reached(notifications) {
 					let last = Some(iter.last_raw_key().into());
 					return (weight_used, Some(NotifyCurrentTargets(last)))
 				}
@@ -2909,6 +2975,7 @@ impl<T: Config> Pallet<T> {
 								location: old_key,
 								query_id: value.0,
 							});
+							progress.failed.saturating_inc();
 							weight_used.saturating_accrue(vnt_migrate_fail_weight);
 							if weight_used.any_gte(weight_cutoff) {
 								return (weight_used, Some(stage))
@@ -2920,6 +2987,7 @@ impl<T: Config> Pallet<T> {
 					let versioned_key = LatestVersionedLocation(&new_key);
 					if target_xcm_version == xcm_version {
 						VersionNotifyTargets::<T>::insert(XCM_VERSION, versioned_key, value);
+						progress.migrated.saturating_inc();
 						weight_used.saturating_accrue(vnt_migrate_weight);
 					} else {
 						// Need to notify target.
@@ -2930,6 +2998,7 @@ impl<T: Config> Pallet<T> {
 							max_weight,
 							querier: None,
 						}]);
+						notifications.saturating_inc();
 						let event = match send_xcm::<T::XcmRouter>(new_key.clone(), message) {
 							Ok((message_id, cost)) => {
 								VersionNotifyTargets::<T>::insert(
@@ -2937,6 +3006,7 @@ impl<T: Config> Pallet<T> {
 									versioned_key,
 									(query_id, max_weight, xcm_version),
 								);
+								progress.notified.saturating_inc();
 								Event::VersionChangeNotified {
 									destination: new_key,
 									result: xcm_version,
@@ -2944,16 +3014,21 @@ impl<T: Config> Pallet<T> {
 									message_id,
 								}
 							},
-							Err(e) => Event::NotifyTargetSendFail {
-								location: new_key,
-								query_id,
-								error: e.into(),
+							Err(e) => {
+								progress.failed.saturating_inc();
+								Event::NotifyTargetSendFail {
+									location: new_key,
+									query_id,
+									error: e.into(),
+								}
 							},
 						};
 						Self::deposit_event(event);
 						weight_used.saturating_accrue(vnt_notify_migrate_weight);
 					}
-					if weight_used.any_gte(weight_cutoff) {
+					if weight_used.any_gte(weight_cutoff) ||
+						notification_limit_reached(notifications)
+					{
 						return (weight_used, Some(stage))
 					}
 				}
@@ -3346,6 +3421,36 @@ impl<T: Config> Pallet<T> {
 		Ok(<T::XcmExecutor as XcmAssetTransfers>::IsTeleporter::contains(&a, &location))
 	}
 
+	/// Returns the progress of the current or latest migration of the XCM version data.
+	///
+	/// Meant to be used in the `xcm_runtime_apis::versions::XcmVersionsApi` runtime API.
+	pub fn version_migration_progress() -> VersionMigrationProgress {
+		VersionMigrationMetrics::<T>::get()
+	}
+
+	/// Returns the XCM version negotiated with `destination`, if known.
+	///
+	/// Meant to be used in the `xcm_runtime_apis::versions::XcmVersionsApi` runtime API.
+	pub fn destination_xcm_version(destination: VersionedLocation) -> Option<XcmVersion> {
+		let destination = Location::try_from(destination).ok()?;
+		Self::get_version_for(&destination)
+	}
+
+	/// Returns all destinations which negotiated an XCM version older than the one advertised by
+	/// this chain, together with their version.
+	///
+	/// Meant to be used in the `xcm_runtime_apis::versions::XcmVersionsApi` runtime API.
+	pub fn outdated_destinations() -> Vec<(VersionedLocation, XcmVersion)> {
+		let advertised_version = T::AdvertisedXcmVersion::get();
+		// entries may not be migrated to the latest version yet, so check all of them
+		SupportedVersion::<T>::iter()
+			.filter(|(_, _, version)| *version < advertised_version)
+			.map(|(_, destination, version)| {
+				(destination.clone().into_version(XCM_VERSION).unwrap_or(destination), version)
+			})
+			.collect()
+	}
+
 	/// Returns locations allowed to alias into and act as `target`.
 	pub fn authorized_aliasers(
 		target: VersionedLocation,
@@ -14,10 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{
-	pallet::CurrentMigration, Config, CurrentXcmVersion, Pallet, VersionMigrationStage,
-	VersionNotifyTargets,
-};
+use crate::{Config, CurrentXcmVersion, Pallet, VersionNotifyTargets};
 use frame_support::{
 	pallet_prelude::*,
 	traits::{OnRuntimeUpgrade, StorageVersion, UncheckedOnRuntimeUpgrade},
@@ -406,7 +403,6 @@ pub mod data {
 
 pub mod v1 {
 	use super::*;
-	use crate::{CurrentMigration, VersionMigrationStage};
 
 	/// Named with the 'VersionUnchecked'-prefix because although this implements some version
 	/// checking, the version checking is not complete as it will begin failing after the upgrade is
@@ -423,8 +419,8 @@ pub mod v1 {
 				return weight
 			}
 
-			weight.saturating_accrue(T::DbWeight::get().writes(1));
-			CurrentMigration::<T>::put(VersionMigrationStage::default());
+			weight.saturating_accrue(T::DbWeight::get().writes(2));
+			Pallet::<T>::start_version_migration();
 
 			let translate = |pre: (u64, u64, u32)| -> Option<(u64, Weight, u32)> {
 				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
@@ -466,8 +462,8 @@ impl<T: Config> OnRuntimeUpgrade for MigrateToLatestXcmVersion<T> {
 		let mut weight = T::DbWeight::get().reads(1);
 
 		// trigger expensive/lazy migration (kind of multi-block)
-		CurrentMigration::<T>::put(VersionMigrationStage::default());
-		weight.saturating_accrue(T::DbWeight::get().writes(1));
+		Pallet::<T>::start_version_migration();
+		weight.saturating_accrue(T::DbWeight::get().writes(2));
 
 		// migrate other operational data to the latest XCM version in-place
 		let latest = CurrentXcmVersion::get();
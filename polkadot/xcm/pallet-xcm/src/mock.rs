@@ -148,6 +148,8 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = MinimumPeriod;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = frame_support::traits::ConstU64<0>;
 	type WeightInfo = ();
 }
 
@@ -563,6 +565,8 @@ pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, A
 
 parameter_types! {
 	pub static AdvertisedXcmVersion: pallet_xcm::XcmVersion = 4;
+	pub static MaxVersionNotificationsPerBlock: u32 = 0;
+	pub static VersionDiscoveryPeriod: u64 = 0;
 	pub const AuthorizeAliasHoldReason: RuntimeHoldReason = RuntimeHoldReason::XcmPallet(pallet_xcm::HoldReason::AuthorizeAlias);
 }
 
@@ -604,6 +608,8 @@ impl pallet_xcm::Config for Test {
 	type MaxLockers = frame_support::traits::ConstU32<8>;
 	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type MaxVersionNotificationsPerBlock = MaxVersionNotificationsPerBlock;
+	type VersionDiscoveryPeriod = VersionDiscoveryPeriod;
 	type WeightInfo = TestWeightInfo;
 	type AuthorizedAliasConsideration =
 		HoldConsideration<AccountId, Balances, AuthorizeAliasHoldReason, ConvertDeposit>;
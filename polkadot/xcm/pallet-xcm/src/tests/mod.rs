@@ -1310,6 +1310,111 @@ fn subscription_side_upgrades_work_with_multistage_notify() {
 	});
 }
 
+#[test]
+fn version_notifications_are_throttled() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		AdvertisedXcmVersion::set(1);
+		MaxVersionNotificationsPerBlock::set(1);
+
+		for (i, para) in [1001u32, 1002, 1003].into_iter().enumerate() {
+			let location = Parachain(para).into_versioned();
+			VersionNotifyTargets::<Test>::insert(
+				XCM_VERSION,
+				location,
+				(70 + i as u64, Weight::zero(), 1),
+			);
+		}
+
+		// New version.
+		AdvertisedXcmVersion::set(4);
+		XcmPallet::start_version_migration();
+		assert!(XcmPallet::version_migration_progress().ongoing);
+
+		// At most one notification is sent per block, regardless of the weight limit.
+		let mut maybe_migration = CurrentMigration::<Test>::take();
+		let mut sent = 0;
+		while let Some(migration) = maybe_migration.take() {
+			let (_, m) = XcmPallet::lazy_migration(migration, Weight::MAX);
+			let sent_now = take_sent_xcm().len();
+			assert!(sent_now <= 1);
+			sent += sent_now;
+			maybe_migration = m;
+		}
+		assert_eq!(sent, 3);
+
+		let progress = XcmPallet::version_migration_progress();
+		assert!(!progress.ongoing);
+		assert_eq!(progress.notified, 3);
+		assert_eq!(progress.failed, 0);
+		assert!(progress.blocks >= 3);
+	});
+}
+
+#[test]
+fn version_discovery_is_paced() {
+	new_test_ext_with_balances_and_xcm_version(vec![], None, vec![]).execute_with(|| {
+		VersionDiscoveryPeriod::set(2);
+		let remote: Location = Parachain(1000).into();
+
+		assert_ok!(XcmPallet::force_default_xcm_version(RuntimeOrigin::root(), Some(3)));
+		let msg = xcm::v3::Xcm::<()>(vec![xcm::v3::Instruction::Trap(0)]);
+		assert_eq!(
+			XcmPallet::wrap_version(&remote, msg.clone()),
+			Ok(VersionedXcm::from(msg.clone())),
+		);
+		let expected = vec![(remote.clone().into(), 1)];
+		assert_eq!(VersionDiscoveryQueue::<Test>::get().into_inner(), expected);
+
+		// Version discovery is skipped outside of the discovery period.
+		XcmPallet::on_initialize(1);
+		assert_eq!(take_sent_xcm(), vec![]);
+		assert_eq!(VersionDiscoveryQueue::<Test>::get().into_inner(), expected);
+
+		XcmPallet::on_initialize(2);
+		assert_eq!(
+			take_sent_xcm(),
+			vec![(
+				remote.clone(),
+				Xcm(vec![SubscribeVersion { query_id: 0, max_response_weight: Weight::zero() }]),
+			)]
+		);
+	});
+}
+
+#[test]
+fn destination_xcm_version_works() {
+	let remote_a: Location = Parachain(1000).into();
+	let remote_b: Location = Parachain(1001).into();
+	let remote_c: Location = Parachain(1002).into();
+
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		assert_ok!(XcmPallet::force_xcm_version(
+			RuntimeOrigin::root(),
+			Box::new(remote_a.clone()),
+			3
+		));
+		assert_ok!(XcmPallet::force_xcm_version(
+			RuntimeOrigin::root(),
+			Box::new(remote_b.clone()),
+			AdvertisedXcmVersion::get()
+		));
+
+		assert_eq!(XcmPallet::destination_xcm_version(remote_a.clone().into_versioned()), Some(3));
+		assert_eq!(
+			XcmPallet::destination_xcm_version(remote_b.clone().into_versioned()),
+			Some(AdvertisedXcmVersion::get())
+		);
+		assert_eq!(XcmPallet::destination_xcm_version(remote_c.into_versioned()), None);
+
+		// Older location versions are accepted as well.
+		let remote_a_v4 = VersionedLocation::from(remote_a.clone()).into_version(4).unwrap();
+		assert_eq!(XcmPallet::destination_xcm_version(remote_a_v4), Some(3));
+
+		// Only `remote_a` is behind the version advertised by this chain.
+		assert_eq!(XcmPallet::outdated_destinations(), vec![(remote_a.into_versioned(), 3)]);
+	});
+}
+
 #[test]
 fn get_and_wrap_version_works() {
 	let remote_a: Location = Parachain(1000).into();
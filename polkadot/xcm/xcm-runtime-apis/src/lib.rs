@@ -33,3 +33,5 @@ pub mod fees;
 /// Exposes runtime API for querying whether a Location is trusted as a reserve or teleporter for a
 /// given Asset.
 pub mod trusted_query;
+/// Runtime APIs for querying the XCM versions known to the chain.
+pub mod versions;
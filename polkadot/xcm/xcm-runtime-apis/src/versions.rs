@@ -0,0 +1,56 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains runtime APIs for querying the XCM versions known to the chain.
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::{MaxEncodedLen, TypeInfo};
+use xcm::{prelude::XcmVersion, VersionedLocation};
+
+/// Progress of the lazy migration of the stored XCM version data to the latest XCM version.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct VersionMigrationProgress {
+	/// Whether the migration is ongoing.
+	pub ongoing: bool,
+	/// The number of blocks in which the migration made progress.
+	pub blocks: u32,
+	/// The number of entries migrated to the latest XCM version.
+	pub migrated: u32,
+	/// The number of version notifications sent to subscribers.
+	pub notified: u32,
+	/// The number of entries which could not be migrated or whose subscribers could not be
+	/// notified.
+	pub failed: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for querying the XCM versions known to the chain.
+	pub trait XcmVersionsApi {
+		/// Returns the progress of the latest migration of the XCM version data.
+		fn version_migration_progress() -> VersionMigrationProgress;
+
+		/// Returns the XCM version negotiated with `destination`.
+		///
+		/// Returns `None` if the version of `destination` is not known (yet).
+		fn destination_xcm_version(destination: VersionedLocation) -> Option<XcmVersion>;
+
+		/// Returns all destinations which negotiated an XCM version older than the one advertised
+		/// by this chain, together with their version.
+		fn outdated_destinations() -> Vec<(VersionedLocation, XcmVersion)>;
+	}
+}
@@ -374,6 +374,8 @@ impl pallet_xcm::Config for TestRuntime {
 	type MaxLockers = ConstU32<0>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type MaxVersionNotificationsPerBlock = ();
+	type VersionDiscoveryPeriod = ();
 	type WeightInfo = TestWeightInfo;
 	type AuthorizedAliasConsideration = Disabled;
 }
@@ -21,21 +21,22 @@ async function run(nodeName, networkInfo) {
 
                 if (event.method == 'NewSession') {
                     new_session_started = true;
-                    console.log(`New session started. Measuring CandidateIncluded events.`);
+                    console.log(`New session started. Measuring CandidateIncludedV2 events.`);
                 }
 
-                if (event.method != 'CandidateIncluded' || !new_session_started) {
+                if (event.method != 'CandidateIncludedV2' || !new_session_started) {
                     return;
                 }
 
-                let included_para_id = parse_pjs_int(event.toHuman().data[0].descriptor.paraId);
-                let relay_parent = event.toHuman().data[0].descriptor.relayParent;
+                let candidate = event.data[0].toHuman();
+                let included_para_id = parse_pjs_int(candidate.descriptor.paraId);
+                let relay_parent = candidate.descriptor.relayParent;
                 if (blocks_per_para[included_para_id] == undefined) {
                     blocks_per_para[included_para_id] = 1;
                 } else {
                     blocks_per_para[included_para_id]++;
                 }
-                console.log(`CandidateIncluded for ${included_para_id}: block_offset=${block_count} relay_parent=${relay_parent}`);
+                console.log(`CandidateIncludedV2 for ${included_para_id}: block_offset=${block_count} relay_parent=${relay_parent}`);
             });
 
             if (block_count == 12) {
@@ -120,6 +120,11 @@ pub fn run() -> Result<()> {
 						let partial = new_partial(&config, None)?;
 						cmd.run(partial.client)
 					},
+					BenchmarkCmd::Pov(cmd) => {
+						// ensure that we keep the task manager alive
+						let partial = new_partial(&config, None)?;
+						cmd.run(partial.client)
+					},
 					#[cfg(not(feature = "runtime-benchmarks"))]
 					BenchmarkCmd::Storage(_) => Err(
 						"Storage benchmarking can be enabled with `--features runtime-benchmarks`."
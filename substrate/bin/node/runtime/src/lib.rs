@@ -398,6 +398,10 @@ impl pallet_multisig::Config for Runtime {
 	type MaxSignatories = ConstU32<100>;
 	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type OffchainSignature = Signature;
+	type SigningPublicKey = <Signature as sp_runtime::traits::Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 parameter_types! {
@@ -487,11 +491,16 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type TransferMeter = ();
+	type MaxAllowanceAssets = ConstU32<0>;
 }
 
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		RuntimeBlockWeights::get().max_block;
+	pub const SchedulerNormalShare: Perbill = Perbill::from_percent(80);
+	pub const SchedulerBulkShare: Perbill = Perbill::from_percent(50);
+	pub const SchedulerMaxPostponement: BlockNumber = 1 * DAYS;
 }
 
 impl pallet_scheduler::Config for Runtime {
@@ -506,6 +515,11 @@ impl pallet_scheduler::Config for Runtime {
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type MaxScheduledPerBlock = ConstU32<50>;
 	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type ClassLimits = pallet_scheduler::WeightShares<
+		SchedulerNormalShare,
+		SchedulerBulkShare,
+		SchedulerMaxPostponement,
+	>;
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
@@ -520,6 +534,7 @@ impl pallet_glutton::Config for Runtime {
 parameter_types! {
 	pub const PreimageHoldReason: RuntimeHoldReason =
 		RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+	pub const PreimageUploadLifetime: BlockNumber = 1 * DAYS;
 }
 
 impl pallet_preimage::Config for Runtime {
@@ -537,6 +552,7 @@ impl pallet_preimage::Config for Runtime {
 			Balance,
 		>,
 	>;
+	type UploadLifetime = PreimageUploadLifetime;
 }
 
 parameter_types! {
@@ -563,12 +579,18 @@ impl pallet_babe::Config for Runtime {
 
 parameter_types! {
 	pub const IndexDeposit: Balance = 1 * DOLLARS;
+	pub const IndexExpiryPeriod: Option<BlockNumber> = Some(365 * DAYS);
+	pub const IndexGracePeriod: BlockNumber = 30 * DAYS;
+	pub const IndexRenewalFee: Balance = 10 * CENTS;
 }
 
 impl pallet_indices::Config for Runtime {
 	type AccountIndex = AccountIndex;
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
+	type ExpiryPeriod = IndexExpiryPeriod;
+	type GracePeriod = IndexGracePeriod;
+	type RenewalFee = IndexRenewalFee;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_indices::weights::SubstrateWeight<Runtime>;
 }
@@ -584,6 +606,7 @@ parameter_types! {
 impl pallet_balances::Config for Runtime {
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type MaxLocks = MaxLocks;
 	type MaxReserves = MaxReserves;
 	type ReserveIdentifier = [u8; 8];
@@ -596,6 +619,8 @@ impl pallet_balances::Config for Runtime {
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = VariantCountOf<RuntimeFreezeReason>;
 	type DoneSlashHandler = ();
+	type Scheduler = Scheduler;
+	type MaxScheduledTransferDelay = ConstU32<{ 30 * DAYS }>;
 }
 
 parameter_types! {
@@ -658,12 +683,15 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = Moment;
 	type OnTimestampSet = Babe;
 	type MinimumPeriod = MinimumPeriod;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = pallet_timestamp::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Babe>;
 	type EventHandler = (Staking, ImOnline);
+	type AuthorStatsWindow = ();
 }
 
 impl_opaque_keys! {
@@ -690,6 +718,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 impl pallet_session::historical::Config for Runtime {
@@ -1328,11 +1358,21 @@ impl pallet_treasury::Config for Runtime {
 	type Paymaster = PayAssetFromAccount<NativeAndAssets, TreasuryAccount>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MaxRecurringSpends = ConstU32<50>;
+	type AssetSwap = ();
+	type MaxSwapSlippage = ();
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = PalletTreasuryArguments;
 }
 
+parameter_types! {
+	pub const AssetRateFeedPeriod: BlockNumber = 1 * HOURS;
+	pub const AssetRateMaxFeedDeviation: Perbill = Perbill::from_percent(10);
+	pub const AssetRateOffchainRepeat: BlockNumber = 5 * MINUTES;
+	pub const AssetRateUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+}
+
 impl pallet_asset_rate::Config for Runtime {
 	type CreateOrigin = EnsureRoot<AccountId>;
 	type RemoveOrigin = EnsureRoot<AccountId>;
@@ -1341,6 +1381,12 @@ impl pallet_asset_rate::Config for Runtime {
 	type AssetKind = NativeOrWithId<u32>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_asset_rate::weights::SubstrateWeight<Runtime>;
+	type FeederId = pallet_asset_rate::sr25519::FeederId;
+	type MaxFeeders = ConstU32<16>;
+	type FeedPeriod = AssetRateFeedPeriod;
+	type MaxFeedDeviation = AssetRateMaxFeedDeviation;
+	type OffchainRepeat = AssetRateOffchainRepeat;
+	type UnsignedPriority = AssetRateUnsignedPriority;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = AssetRateArguments;
 }
@@ -1391,6 +1437,9 @@ impl pallet_message_queue::Config for Runtime {
 	type MaxStale = ConstU32<128>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type MaxDeadLetters = ();
+	type DeadLetterDepositor = ();
+	type DeadLetterConsideration = ();
 }
 
 parameter_types! {
@@ -1754,6 +1803,7 @@ impl pallet_vesting::Config for Runtime {
 	type MinVestedTransfer = MinVestedTransfer;
 	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type SplitOrigin = EnsureSigned<AccountId>;
 	type BlockNumberProvider = System;
 	// `VestingInfo` encode length is 36bytes. 28 schedules gets encoded as 1009 bytes, which is the
 	// highest number of schedules that encodes less than 2^10.
@@ -2313,6 +2363,8 @@ impl pallet_migrations::Config for Runtime {
 	type MigrationStatusHandler = ();
 	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
 	type MaxServiceWeight = MbmServiceWeight;
+	type PauseOrigin = EnsureRoot<AccountId>;
+	type ProgressEventInterval = ConstU32<50>;
 	type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
 }
 
@@ -3547,6 +3599,7 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 		}
 	}
 
+	#[api_version(3)]
 	impl pallet_mmr::primitives::MmrApi<
 		Block,
 		mmr::Hash,
@@ -3577,6 +3630,21 @@ pallet_revive::impl_runtime_apis_plus_revive!(
 			)
 		}
 
+		fn generate_batch_proof_for_range(
+			start_block: BlockNumber,
+			end_block: BlockNumber,
+		) -> Result<(Vec<mmr::EncodableOpaqueLeaf>, mmr::LeafProof<mmr::Hash>), mmr::Error> {
+			Mmr::generate_proof_for_range(start_block, end_block).map(|(leaves, proof)| {
+				(
+					leaves
+						.into_iter()
+						.map(|leaf| mmr::EncodableOpaqueLeaf::from_leaf(&leaf))
+						.collect(),
+					proof,
+				)
+			})
+		}
+
 		fn verify_proof(leaves: Vec<mmr::EncodableOpaqueLeaf>, proof: mmr::LeafProof<mmr::Hash>)
 			-> Result<(), mmr::Error>
 		{
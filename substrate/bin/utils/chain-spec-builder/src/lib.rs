@@ -118,6 +118,7 @@ enum GenesisBuildAction {
 	Full(FullCmd),
 	Default(DefaultCmd),
 	NamedPreset(NamedPresetCmd),
+	Compose(ComposeCmd),
 }
 
 /// Patches the runtime's default genesis config with provided patch.
@@ -146,6 +147,21 @@ struct NamedPresetCmd {
 	preset_name: String,
 }
 
+/// Uses named preset provided by runtime, customized with the given override files, to build the
+/// chain spec.
+///
+/// The overrides are deep merged over the preset in the given order, so later files take
+/// precedence. The resulting genesis config is validated against the runtime before the chain spec
+/// is produced.
+#[derive(Parser, Debug, Clone)]
+struct ComposeCmd {
+	/// The name of the preset the chain spec is based on.
+	preset_name: String,
+	/// The paths to the JSON files overriding parts of the preset.
+	#[arg(required = true)]
+	override_paths: Vec<PathBuf>,
+}
+
 /// Updates the code in the provided input chain spec.
 ///
 /// The code field of the chain spec will be updated with the runtime provided in the
@@ -373,6 +389,26 @@ fn process_action<T: Serialize + Clone + Sync + 'static>(
 				|e| format!("config file {config_path:?} shall contain a valid json: {e}"),
 			)?)
 		},
+		GenesisBuildAction::Compose(ComposeCmd { ref preset_name, ref override_paths }) => {
+			let caller: GenesisConfigBuilderRuntimeCaller =
+				GenesisConfigBuilderRuntimeCaller::new(&code);
+			let mut patch = caller.get_named_preset(Some(preset_name)).map_err(|e| {
+				format!("getting preset {preset_name} from runtime should work: {e}")
+			})?;
+			for override_path in override_paths {
+				let overrides = fs::read(override_path.as_path()).map_err(|e| {
+					format!("override file {override_path:?} shall be readable: {e}")
+				})?;
+				let overrides = serde_json::from_slice::<Value>(&overrides[..]).map_err(|e| {
+					format!("override file {override_path:?} shall contain a valid json: {e}")
+				})?;
+				json_patch::merge(&mut patch, overrides);
+			}
+			caller
+				.get_storage_for_patch(patch.clone())
+				.map_err(|e| format!("composed genesis config is not valid: {e}"))?;
+			builder.with_genesis_config_patch(patch)
+		},
 		GenesisBuildAction::Default(DefaultCmd {}) => {
 			let caller: GenesisConfigBuilderRuntimeCaller =
 				GenesisConfigBuilderRuntimeCaller::new(&code);
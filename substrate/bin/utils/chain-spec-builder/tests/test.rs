@@ -256,6 +256,38 @@ fn test_create_with_properties() {
 	assert_output_eq_expected(true, SUFFIX, "tests/expected/create_with_properties.json");
 }
 
+#[test]
+fn test_create_with_compose() {
+	const SUFFIX: &str = "12";
+	let mut builder = get_builder(
+		SUFFIX,
+		vec![
+			"create",
+			"-r",
+			DUMMY_PATH,
+			"compose",
+			"staging",
+			"tests/input/override_balances.json",
+			"tests/input/override_authorities.json",
+		],
+	);
+	builder.set_create_cmd_runtime_code(substrate_test_runtime::WASM_BINARY.unwrap().into());
+	builder.run().unwrap();
+	assert_output_eq_expected(true, SUFFIX, "tests/expected/create_with_compose.json");
+}
+
+#[test]
+fn test_create_with_compose_rejects_invalid_overrides() {
+	const SUFFIX: &str = "13";
+	let mut builder = get_builder(
+		SUFFIX,
+		vec!["create", "-r", DUMMY_PATH, "compose", "staging", "tests/input/override_invalid.json"],
+	);
+	builder.set_create_cmd_runtime_code(substrate_test_runtime::WASM_BINARY.unwrap().into());
+	let error = builder.run().unwrap_err();
+	assert!(error.starts_with("composed genesis config is not valid"), "{error}");
+}
+
 #[docify::export_content]
 fn cmd_create_default(runtime_path: &str) -> String {
 	bash!(
@@ -346,6 +378,24 @@ fn create_with_named_preset() {
 	)
 }
 
+#[docify::export_content]
+fn cmd_create_with_compose(runtime_path: &str) -> String {
+	bash!(
+		chain-spec-builder -c "/dev/stdout" create -r $runtime_path compose "staging" "tests/input/override_balances.json" "tests/input/override_authorities.json"
+	)
+}
+
+#[test]
+fn create_with_compose() {
+	doc_assert(
+		cmd_create_with_compose(
+			substrate_test_runtime::WASM_BINARY_PATH.expect("to be a valid path. qed"),
+		),
+		"tests/expected/doc/create_with_compose.json",
+		true,
+	)
+}
+
 #[docify::export_content]
 fn cmd_create_with_patch_raw(runtime_path: &str) -> String {
 	bash!(
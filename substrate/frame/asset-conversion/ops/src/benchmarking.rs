@@ -162,5 +162,34 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn migrate_next(n: Linear<1, 10>) {
+		let caller: T::AccountId = whitelisted_caller();
+		create_fee_asset::<T>(&caller);
+
+		for i in 0..n {
+			let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, i + 1);
+			let (_, liquidity1, liquidity2) = create_asset_and_pool::<T>(&caller, &asset1, &asset2);
+			assert_ok!(AssetConversion::<T>::add_liquidity(
+				SystemOrigin::Signed(caller.clone()).into(),
+				Box::new(asset1.clone()),
+				Box::new(asset2.clone()),
+				liquidity1,
+				liquidity2,
+				T::Balance::one(),
+				T::Balance::zero(),
+				caller.clone(),
+			));
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+			UnmigratedPools::<T>::insert(pool_id, ());
+		}
+
+		#[extrinsic_call]
+		_(SystemOrigin::Signed(caller), n);
+
+		assert_eq!(UnmigratedPools::<T>::count(), 0);
+		assert_last_event::<T>(Event::AllPoolsMigrated.into());
+	}
+
 	impl_benchmark_test_suite!(AssetConversionOps, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -28,12 +28,22 @@
 //! - Perform migration to update account ID derivation methods for existing pools. The migration
 //!   operation ensures that the required accounts are created, existing account deposits are
 //!   transferred, and liquidity is moved to the new accounts.
+//!
+//! ## Migration Progress
+//!
+//! The pools which still have to be migrated are tracked in [`UnmigratedPools`], populated by the
+//! [`migration::InitUnmigratedPools`] runtime upgrade and queryable through the
+//! [`AssetConversionMigrationApi`] runtime API. Anyone can migrate the next tracked pools with
+//! [`Pallet::migrate_next`]. Pools which it fails to migrate are moved to [`FailedPools`] and have
+//! to be migrated with [`Pallet::migrate_to_new_account`]. Once no tracked or failed pool is left,
+//! [`Event::AllPoolsMigrated`] is emitted and the pallet can be removed from the runtime.
 
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -44,15 +54,18 @@ pub use weights::WeightInfo;
 
 extern crate alloc;
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use frame_support::traits::{
 	fungible::{Inspect as FungibleInspect, Mutate as FungibleMutate},
 	fungibles::{roles::ResetTeam, Inspect, Mutate, Refund},
 	tokens::{Fortitude, Precision, Preservation},
 	AccountTouch,
 };
-use pallet_asset_conversion::{PoolLocator, Pools};
-use sp_runtime::traits::{TryConvert, Zero};
+use pallet_asset_conversion::{PoolInfo, PoolLocator, Pools};
+use sp_runtime::{
+	traits::{TryConvert, Zero},
+	DispatchError, DispatchResult, Saturating,
+};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -60,7 +73,11 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -113,6 +130,17 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
+	/// Pools which have not been migrated to the new account ID yet.
+	#[pallet::storage]
+	pub type UnmigratedPools<T: Config> =
+		CountedStorageMap<_, Blake2_128Concat, T::PoolId, (), OptionQuery>;
+
+	/// Pools which [`Pallet::migrate_next`] failed to migrate. They have to be migrated with
+	/// [`Pallet::migrate_to_new_account`].
+	#[pallet::storage]
+	pub type FailedPools<T: Config> =
+		CountedStorageMap<_, Blake2_128Concat, T::PoolId, (), OptionQuery>;
+
 	// Pallet's events.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -126,6 +154,16 @@ pub mod pallet {
 			/// Pool's new account ID.
 			new_account: T::AccountId,
 		},
+		/// Indicates that a pool could not be migrated by [`Pallet::migrate_next`]. It is moved to
+		/// [`FailedPools`] and has to be migrated with [`Pallet::migrate_to_new_account`].
+		MigrationFailed {
+			/// Pool's ID.
+			pool_id: T::PoolId,
+			/// The error the migration failed with.
+			error: DispatchError,
+		},
+		/// Indicates that no tracked or failed pool is left to migrate.
+		AllPoolsMigrated,
 	}
 
 	#[pallet::error]
@@ -138,6 +176,8 @@ pub mod pallet {
 		ZeroBalance,
 		/// Indicates a partial transfer of balance to the new account during a migration.
 		PartialTransfer,
+		/// No tracked pool is left to migrate.
+		NothingToMigrate,
 	}
 
 	/// Pallet's callable functions.
@@ -148,7 +188,9 @@ pub mod pallet {
 		///
 		/// Must be signed.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::migrate_to_new_account())]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_to_new_account()
+			// Untracking the pool.
+			.saturating_add(T::DbWeight::get().reads_writes(4, 4)))]
 		pub fn migrate_to_new_account(
 			origin: OriginFor<T>,
 			asset1: Box<T::AssetKind>,
@@ -158,6 +200,91 @@ pub mod pallet {
 
 			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
 				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			Self::do_migrate(pool_id.clone())?;
+			Self::untrack(&pool_id);
+
+			Ok(Pays::No.into())
+		}
+
+		/// Migrates up to `count` of the [`UnmigratedPools`] to the new account ID derivation
+		/// method.
+		///
+		/// Pools which cannot be migrated are moved to [`FailedPools`], see
+		/// [`Event::MigrationFailed`].
+		/// If at least one pool is migrated, transaction fees are refunded to the caller.
+		///
+		/// Must be signed.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_next(*count)
+			// Tracking the failed pools.
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2).saturating_mul((*count).into())))]
+		pub fn migrate_next(origin: OriginFor<T>, count: u32) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let pool_ids: Vec<_> = UnmigratedPools::<T>::iter_keys().take(count as usize).collect();
+			ensure!(!pool_ids.is_empty(), Error::<T>::NothingToMigrate);
+			let processed = pool_ids.len() as u32;
+
+			let mut migrated = 0u32;
+			for pool_id in pool_ids {
+				match frame_support::storage::with_storage_layer(|| {
+					Self::do_migrate(pool_id.clone())
+				}) {
+					Ok(()) => {
+						migrated.saturating_inc();
+						Self::untrack(&pool_id);
+					},
+					Err(error) => {
+						UnmigratedPools::<T>::remove(&pool_id);
+						FailedPools::<T>::insert(&pool_id, ());
+						Self::deposit_event(Event::MigrationFailed { pool_id, error });
+					},
+				}
+			}
+
+			let actual_weight = <T as Config>::WeightInfo::migrate_next(processed).saturating_add(
+				T::DbWeight::get().reads_writes(2, 2).saturating_mul(processed.into()),
+			);
+			let pays_fee = if migrated.is_zero() { Pays::Yes } else { Pays::No };
+			Ok((Some(actual_weight), pays_fee).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Returns the pools which have not been migrated to the new account ID yet, including the
+		/// [`FailedPools`].
+		///
+		/// Should only be used in runtime APIs since it iterates over the whole
+		/// [`UnmigratedPools`] and [`FailedPools`] maps.
+		pub fn unmigrated_pools() -> Vec<T::PoolId> {
+			UnmigratedPools::<T>::iter_keys().chain(FailedPools::<T>::iter_keys()).collect()
+		}
+
+		/// Returns `true` if the liquidity of the given pool is still held by its prior account.
+		pub(crate) fn needs_migration(
+			pool_id: &T::PoolId,
+			info: &PoolInfo<T::PoolAssetId>,
+		) -> bool {
+			Self::addresses(pool_id).map_or(false, |(prior_account, _)| {
+				!T::PoolAssets::total_balance(info.lp_token.clone(), &prior_account).is_zero()
+			})
+		}
+
+		/// Stops tracking the given migrated pool and emits [`Event::AllPoolsMigrated`] once no
+		/// tracked or failed pool is left.
+		fn untrack(pool_id: &T::PoolId) {
+			let tracked = UnmigratedPools::<T>::take(pool_id).is_some();
+			let failed = FailedPools::<T>::take(pool_id).is_some();
+			if (tracked || failed) &&
+				UnmigratedPools::<T>::count() == 0 &&
+				FailedPools::<T>::count() == 0
+			{
+				Self::deposit_event(Event::AllPoolsMigrated);
+			}
+		}
+
+		/// Migrates an existing pool to the new account ID derivation method.
+		fn do_migrate(pool_id: T::PoolId) -> DispatchResult {
 			let info = Pools::<T>::get(&pool_id).ok_or(Error::<T>::PoolNotFound)?;
 
 			let (prior_account, new_account) =
@@ -297,11 +424,9 @@ pub mod pallet {
 				new_account,
 			});
 
-			Ok(Pays::No.into())
+			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
 		/// Returns the prior and new account IDs for a given pool ID. The prior account ID comes
 		/// first in the tuple.
 		#[cfg(not(any(test, feature = "runtime-benchmarks")))]
@@ -335,3 +460,15 @@ pub mod pallet {
 		}
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// This runtime api allows to query the progress of the pools' migration to the new account
+	/// ID derivation method.
+	pub trait AssetConversionMigrationApi<AssetKind>
+	where
+		AssetKind: codec::Codec,
+	{
+		/// Returns the asset pairs of the pools which have not been migrated yet.
+		fn unmigrated_pools() -> Vec<(AssetKind, AssetKind)>;
+	}
+}
@@ -0,0 +1,71 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the Asset Conversion Ops pallet.
+
+use super::*;
+use core::marker::PhantomData;
+use frame_support::{
+	migrations::VersionedMigration,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+};
+
+/// Actual implementation of [`InitUnmigratedPools`].
+pub struct UncheckedInitUnmigratedPools<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedInitUnmigratedPools<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let mut reads = 0u64;
+		let mut tracked = 0u64;
+		for (pool_id, info) in Pools::<T>::iter() {
+			reads.saturating_accrue(2);
+			if Pallet::<T>::needs_migration(&pool_id, &info) {
+				UnmigratedPools::<T>::insert(pool_id, ());
+				tracked.saturating_inc();
+			}
+		}
+		log::info!(
+			target: "runtime::asset-conversion-ops",
+			"Tracking {} pools to migrate to the new account ID",
+			tracked,
+		);
+		// Every insert reads and writes the counter of the map.
+		T::DbWeight::get().reads_writes(reads.saturating_add(tracked), tracked.saturating_mul(2))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		for pool_id in UnmigratedPools::<T>::iter_keys() {
+			frame_support::ensure!(
+				Pools::<T>::contains_key(&pool_id),
+				"Tracked pool does not exist"
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Populate [`UnmigratedPools`] with the pools whose liquidity is still held by their prior
+/// account.
+pub type InitUnmigratedPools<T> = VersionedMigration<
+	0,
+	1,
+	UncheckedInitUnmigratedPools<T>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
@@ -306,3 +306,177 @@ fn migrate_empty_pool_account_id() {
 		);
 	});
 }
+
+/// Creates a pool of the native asset and the asset `id` and provides some liquidity.
+fn create_pool_with_liquidity(
+	user: u64,
+	id: u32,
+) -> <Test as pallet_asset_conversion::Config>::PoolId {
+	type PoolLocator = <Test as pallet_asset_conversion::Config>::PoolLocator;
+	let token_1 = NativeOrWithId::Native;
+	let token_2 = NativeOrWithId::WithId(id);
+
+	assert_ok!(NativeAndAssets::create(token_2.clone(), user, false, 1));
+	assert_ok!(AssetConversion::create_pool(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone())
+	));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), id, user, 1000));
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone()),
+		10000,
+		10,
+		10000,
+		10,
+		user,
+	));
+
+	PoolLocator::pool_id(&token_1, &token_2).unwrap()
+}
+
+#[test]
+fn init_unmigrated_pools_tracks_pools_with_liquidity() {
+	new_test_ext().execute_with(|| {
+		use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+		let user = 1;
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000));
+		let pool_1 = create_pool_with_liquidity(user, 2);
+		let pool_2 = create_pool_with_liquidity(user, 3);
+
+		// a pool without liquidity has nothing to migrate.
+		assert_ok!(NativeAndAssets::create(NativeOrWithId::WithId(4), user, false, 1));
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(NativeOrWithId::Native),
+			Box::new(NativeOrWithId::WithId(4))
+		));
+
+		migration::UncheckedInitUnmigratedPools::<Test>::on_runtime_upgrade();
+
+		assert_eq!(UnmigratedPools::<Test>::count(), 2);
+		let mut pools = AssetConversionOps::unmigrated_pools();
+		pools.sort();
+		let mut expected = vec![pool_1, pool_2];
+		expected.sort();
+		assert_eq!(pools, expected);
+	});
+}
+
+#[test]
+fn migrate_next_migrates_tracked_pools() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000));
+		let pool_1 = create_pool_with_liquidity(user, 2);
+		let pool_2 = create_pool_with_liquidity(user, 3);
+		UnmigratedPools::<Test>::insert(&pool_1, ());
+		UnmigratedPools::<Test>::insert(&pool_2, ());
+
+		assert_ok!(AssetConversionOps::migrate_next(RuntimeOrigin::signed(user), 1));
+		assert_eq!(UnmigratedPools::<Test>::count(), 1);
+		assert!(!System::events().iter().any(|record| {
+			record.event == RuntimeEvent::AssetConversionOps(Event::AllPoolsMigrated)
+		}));
+
+		assert_ok!(AssetConversionOps::migrate_next(RuntimeOrigin::signed(user), 5));
+		assert_eq!(UnmigratedPools::<Test>::count(), 0);
+		System::assert_last_event(Event::<Test>::AllPoolsMigrated.into());
+
+		// the liquidity of both pools has moved to their new accounts.
+		for (pool_id, id) in [(pool_1, 2), (pool_2, 3)] {
+			let (prior_account, new_account) = AssetConversionOps::addresses(&pool_id).unwrap();
+			assert_eq!(NativeAndAssets::balance(NativeOrWithId::WithId(id), &prior_account), 0);
+			assert_eq!(NativeAndAssets::balance(NativeOrWithId::WithId(id), &new_account), 10);
+		}
+
+		assert_noop!(
+			AssetConversionOps::migrate_next(RuntimeOrigin::signed(user), 1),
+			Error::<Test>::NothingToMigrate
+		);
+	});
+}
+
+#[test]
+fn migrate_next_records_failed_pools() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000));
+		let migrated_pool = create_pool_with_liquidity(user, 2);
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(3);
+		type PoolLocator = <Test as pallet_asset_conversion::Config>::PoolLocator;
+		let failed_pool = PoolLocator::pool_id(&token_1, &token_2).unwrap();
+
+		// a pool without liquidity cannot be migrated.
+		assert_ok!(NativeAndAssets::create(token_2.clone(), user, false, 1));
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+		UnmigratedPools::<Test>::insert(&migrated_pool, ());
+		UnmigratedPools::<Test>::insert(&failed_pool, ());
+
+		assert_ok!(AssetConversionOps::migrate_next(RuntimeOrigin::signed(user), 5));
+		System::assert_has_event(
+			Event::<Test>::MigrationFailed {
+				pool_id: failed_pool.clone(),
+				error: Error::<Test>::ZeroBalance.into(),
+			}
+			.into(),
+		);
+		assert!(!System::events().iter().any(|record| {
+			record.event == RuntimeEvent::AssetConversionOps(Event::AllPoolsMigrated)
+		}));
+		assert_eq!(UnmigratedPools::<Test>::count(), 0);
+		assert_eq!(FailedPools::<Test>::count(), 1);
+		assert_eq!(AssetConversionOps::unmigrated_pools(), vec![failed_pool.clone()]);
+		assert_noop!(
+			AssetConversionOps::migrate_next(RuntimeOrigin::signed(user), 1),
+			Error::<Test>::NothingToMigrate
+		);
+
+		// once the failed pool is migrated, all pools are.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 3, user, 1000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			10,
+			10000,
+			10,
+			user,
+		));
+		assert_ok!(AssetConversionOps::migrate_to_new_account(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1),
+			Box::new(token_2),
+		));
+		assert_eq!(FailedPools::<Test>::count(), 0);
+		System::assert_last_event(Event::<Test>::AllPoolsMigrated.into());
+	});
+}
+
+#[test]
+fn migrate_to_new_account_untracks_pool() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 100000));
+		let pool_id = create_pool_with_liquidity(user, 2);
+		UnmigratedPools::<Test>::insert(&pool_id, ());
+
+		assert_ok!(AssetConversionOps::migrate_to_new_account(
+			RuntimeOrigin::signed(user),
+			Box::new(pool_id.0.clone()),
+			Box::new(pool_id.1.clone()),
+		));
+
+		assert_eq!(UnmigratedPools::<Test>::count(), 0);
+		System::assert_last_event(Event::<Test>::AllPoolsMigrated.into());
+	});
+}
@@ -73,6 +73,7 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_asset_conversion_ops`.
 pub trait WeightInfo {
 	fn migrate_to_new_account() -> Weight;
+	fn migrate_next(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_asset_conversion_ops` using the Substrate node and recommended hardware.
@@ -99,6 +100,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(12_u64))
 			.saturating_add(T::DbWeight::get().writes(11_u64))
 	}
+	/// Storage: `AssetConversionMigration::CounterForUnmigratedPools` (r:1 w:1)
+	/// Proof: `AssetConversionMigration::CounterForUnmigratedPools` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversionMigration::UnmigratedPools` (r:11 w:10)
+	/// Proof: `AssetConversionMigration::UnmigratedPools` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::Pools` (r:10 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:40 w:40)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:20 w:20)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:10 w:10)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:20 w:20)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:20 w:20)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn migrate_next(n: u32, ) -> Weight {
+		// Placeholder until the `migrate_next` benchmark is run.
+		Weight::from_parts(16_307_000, 12426)
+			// Standard Error: 5_940_575
+			.saturating_add(Weight::from_parts(237_623_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((13_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((12_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2501).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -124,4 +153,32 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(12_u64))
 			.saturating_add(RocksDbWeight::get().writes(11_u64))
 	}
+	/// Storage: `AssetConversionMigration::CounterForUnmigratedPools` (r:1 w:1)
+	/// Proof: `AssetConversionMigration::CounterForUnmigratedPools` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversionMigration::UnmigratedPools` (r:11 w:10)
+	/// Proof: `AssetConversionMigration::UnmigratedPools` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::Pools` (r:10 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:40 w:40)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Account` (r:20 w:20)
+	/// Proof: `PoolAssets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `PoolAssets::Asset` (r:10 w:10)
+	/// Proof: `PoolAssets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:20 w:20)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:20 w:20)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 10]`.
+	fn migrate_next(n: u32, ) -> Weight {
+		// Placeholder until the `migrate_next` benchmark is run.
+		Weight::from_parts(16_307_000, 12426)
+			// Standard Error: 5_940_575
+			.saturating_add(Weight::from_parts(237_623_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((13_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((12_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2501).saturating_mul(n.into()))
+	}
 }
@@ -361,6 +361,25 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// The reserves of the pool of `asset1` and `asset2`, in the order of the given assets.
+		///
+		/// `None` if the pool does not exist or has no liquidity.
+		pub fn pool_reserves(
+			asset1: T::AssetKind,
+			asset2: T::AssetKind,
+		) -> Option<(T::Balance, T::Balance)> {
+			Self::get_reserves(asset1, asset2).ok()
+		}
+
+		/// The liquidity pool token of the pool of `asset1` and `asset2`, if the pool exists.
+		pub fn pool_lp_token(asset1: T::AssetKind, asset2: T::AssetKind) -> Option<T::PoolAssetId> {
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).ok()?;
+			Pools::<T>::get(pool_id).map(|info| info.lp_token)
+		}
+	}
+
 	/// Pallet's callable functions.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -723,6 +742,112 @@ pub mod pallet {
 			Self::deposit_event(Event::Touched { pool_id, who });
 			Ok(Some(T::WeightInfo::touch(refunds_number)).into())
 		}
+
+		/// Same as [`Pallet::add_liquidity`], but mints the liquidity tokens to the sender.
+		///
+		/// Unlike [`Pallet::add_liquidity`], this call cannot move funds to other accounts, so it
+		/// can be allowed for proxies that must not transfer funds.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::add_liquidity())]
+		pub fn add_liquidity_to_sender(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			amount1_desired: T::Balance,
+			amount2_desired: T::Balance,
+			amount1_min: T::Balance,
+			amount2_min: T::Balance,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin.clone())?;
+			Self::add_liquidity(
+				origin,
+				asset1,
+				asset2,
+				amount1_desired,
+				amount2_desired,
+				amount1_min,
+				amount2_min,
+				sender,
+			)
+		}
+
+		/// Same as [`Pallet::remove_liquidity`], but withdraws the assets to the sender.
+		///
+		/// Unlike [`Pallet::remove_liquidity`], this call cannot move funds to other accounts, so
+		/// it can be allowed for proxies that must not transfer funds.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::remove_liquidity())]
+		pub fn remove_liquidity_to_sender(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			lp_token_burn: T::Balance,
+			amount1_min_receive: T::Balance,
+			amount2_min_receive: T::Balance,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin.clone())?;
+			Self::remove_liquidity(
+				origin,
+				asset1,
+				asset2,
+				lp_token_burn,
+				amount1_min_receive,
+				amount2_min_receive,
+				sender,
+			)
+		}
+
+		/// Same as [`Pallet::swap_exact_tokens_for_tokens`], but sends the acquired asset to the
+		/// sender.
+		///
+		/// Unlike [`Pallet::swap_exact_tokens_for_tokens`], this call cannot move funds to other
+		/// accounts, so it can be allowed for proxies that must not transfer funds.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::swap_exact_tokens_for_tokens(path.len() as u32))]
+		pub fn swap_exact_tokens_for_tokens_to_sender(
+			origin: OriginFor<T>,
+			path: Vec<Box<T::AssetKind>>,
+			amount_in: T::Balance,
+			amount_out_min: T::Balance,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_swap_exact_tokens_for_tokens(
+				sender.clone(),
+				path.into_iter().map(|a| *a).collect(),
+				amount_in,
+				Some(amount_out_min),
+				sender,
+				keep_alive,
+			)?;
+			Ok(())
+		}
+
+		/// Same as [`Pallet::swap_tokens_for_exact_tokens`], but sends the acquired asset to the
+		/// sender.
+		///
+		/// Unlike [`Pallet::swap_tokens_for_exact_tokens`], this call cannot move funds to other
+		/// accounts, so it can be allowed for proxies that must not transfer funds.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::swap_tokens_for_exact_tokens(path.len() as u32))]
+		pub fn swap_tokens_for_exact_tokens_to_sender(
+			origin: OriginFor<T>,
+			path: Vec<Box<T::AssetKind>>,
+			amount_out: T::Balance,
+			amount_in_max: T::Balance,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::do_swap_tokens_for_exact_tokens(
+				sender.clone(),
+				path.into_iter().map(|a| *a).collect(),
+				amount_out,
+				Some(amount_in_max),
+				sender,
+				keep_alive,
+			)?;
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
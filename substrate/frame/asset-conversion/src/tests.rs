@@ -415,6 +415,126 @@ fn can_add_liquidity() {
 	});
 }
 
+#[test]
+fn to_sender_calls_keep_funds_with_the_sender() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+
+		create_tokens(user, vec![token_2.clone()]);
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 20000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 2000));
+
+		assert_ok!(AssetConversion::add_liquidity_to_sender(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			1000,
+			10000,
+			1000,
+		));
+		assert_eq!(pool_balance(user, lp_token), 3062);
+		assert!(events().iter().any(|e| matches!(
+			e,
+			Event::<Test>::LiquidityAdded { who, mint_to, .. } if *who == user && *mint_to == user
+		)));
+
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens_to_sender(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			1000,
+			1,
+			false,
+		));
+		assert!(events().iter().any(|e| matches!(
+			e,
+			Event::<Test>::SwapExecuted { who, send_to, .. } if *who == user && *send_to == user
+		)));
+
+		assert_ok!(AssetConversion::swap_tokens_for_exact_tokens_to_sender(
+			RuntimeOrigin::signed(user),
+			bvec![token_2.clone(), token_1.clone()],
+			100,
+			1000,
+			false,
+		));
+		assert!(events().iter().any(|e| matches!(
+			e,
+			Event::<Test>::SwapExecuted { who, send_to, .. } if *who == user && *send_to == user
+		)));
+
+		assert_ok!(AssetConversion::remove_liquidity_to_sender(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			100,
+			1,
+			1,
+		));
+		assert_eq!(pool_balance(user, lp_token), 2962);
+		assert!(events().iter().any(|e| matches!(
+			e,
+			Event::<Test>::LiquidityRemoved { who, withdraw_to, .. }
+				if *who == user && *withdraw_to == user
+		)));
+	});
+}
+
+#[test]
+fn pool_view_functions_work() {
+	new_test_ext().execute_with(|| {
+		let user = 1;
+		let token_1 = NativeOrWithId::Native;
+		let token_2 = NativeOrWithId::WithId(2);
+
+		create_tokens(user, vec![token_2.clone()]);
+		assert_eq!(AssetConversion::pool_lp_token(token_1.clone(), token_2.clone()), None);
+
+		let lp_token = AssetConversion::get_next_pool_asset_id();
+		assert_ok!(AssetConversion::create_pool(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone())
+		));
+		assert_eq!(
+			AssetConversion::pool_lp_token(token_2.clone(), token_1.clone()),
+			Some(lp_token)
+		);
+		// No liquidity yet.
+		assert_eq!(AssetConversion::pool_reserves(token_1.clone(), token_2.clone()), None);
+
+		let ed = get_native_ed();
+		assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), user, 10000 + ed));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+		assert_ok!(AssetConversion::add_liquidity(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			10000,
+			10,
+			10000,
+			10,
+			user,
+		));
+
+		assert_eq!(
+			AssetConversion::pool_reserves(token_1.clone(), token_2.clone()),
+			Some((10000, 10))
+		);
+		assert_eq!(AssetConversion::pool_reserves(token_2, token_1), Some((10, 10000)));
+	});
+}
+
 #[test]
 fn add_tiny_liquidity_leads_to_insufficient_liquidity_minted_error() {
 	new_test_ext().execute_with(|| {
@@ -20,9 +20,10 @@
 use super::*;
 use crate::{pallet as pallet_asset_rate, Pallet as AssetRate};
 
+use alloc::{vec, vec::Vec};
 use codec::Encode;
 use frame_benchmarking::v2::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, pallet_prelude::*, traits::UnfilteredDispatchable};
 use frame_system::RawOrigin;
 use sp_core::crypto::FromEntropy;
 
@@ -97,5 +98,55 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_feeders(n: Linear<0, { T::MaxFeeders::get() }>) -> Result<(), BenchmarkError> {
+		let feeders: BoundedVec<_, _> = (0..n)
+			.map(|_| T::FeederId::generate_pair(None))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, feeders.clone());
+
+		assert_eq!(pallet_asset_rate::Feeders::<T>::get(), feeders);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn feed() -> Result<(), BenchmarkError> {
+		let asset_kind: T::AssetKind = T::BenchmarkHelper::create_asset_kind(SEED);
+		assert_ok!(AssetRate::<T>::create(
+			RawOrigin::Root.into(),
+			Box::new(asset_kind.clone()),
+			default_conversion_rate()
+		));
+		let feeder = T::FeederId::generate_pair(None);
+		assert_ok!(AssetRate::<T>::set_feeders(
+			RawOrigin::Root.into(),
+			vec![feeder.clone()].try_into().unwrap()
+		));
+		let block_number = frame_system::Pallet::<T>::block_number();
+		// the rate equals the reference rate, hence is applied.
+		let feed = RateFeed {
+			asset_kind: asset_kind.clone(),
+			rate: default_conversion_rate(),
+			block_number,
+			feeder: feeder.clone(),
+		};
+		let signature = feeder.sign(&feed.encode()).ok_or("failed to sign the feed")?;
+		let call = Call::<T>::feed { feed, signature };
+
+		#[block]
+		{
+			AssetRate::<T>::validate_unsigned(TransactionSource::InBlock, &call)
+				.map_err(<&str>::from)?;
+			call.dispatch_bypass_filter(RawOrigin::None.into())?;
+		}
+
+		assert!(pallet_asset_rate::FeedStates::<T>::get(asset_kind).is_some());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! { AssetRate, crate::mock::new_test_ext(), crate::mock::Test }
 }
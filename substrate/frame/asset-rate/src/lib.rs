@@ -39,6 +39,7 @@
 //!
 //! * Providing a soft conversion for the balance of supported assets to a default asset class.
 //! * Updating existing conversion rates.
+//! * Keeping conversion rates up to date with rates fed by whitelisted offchain workers.
 //!
 //! ## Interface
 //!
@@ -47,6 +48,11 @@
 //! * `create`: Creates a new asset conversion rate.
 //! * `remove`: Removes an existing asset conversion rate.
 //! * `update`: Overwrites an existing assert conversion rate.
+//! * `set_feeders`: Sets the offchain worker keys whitelisted to feed conversion rates.
+//!
+//! ### Unsigned Functions
+//!
+//! * `feed`: Updates an existing conversion rate with a rate signed by a whitelisted feeder.
 //!
 //! Please refer to the [`Call`] enum and its associated variants for documentation on each
 //! function.
@@ -56,19 +62,42 @@
 //! * Conversion rates are only used as estimates, and are not designed to be precise or closely
 //!   tracking real world values.
 //! * All conversion rates reflect the ration of some asset to native, e.g. native = asset * rate.
+//!
+//! ## Rate Feeds
+//!
+//! Besides being set by governance, conversion rates can be fed by offchain workers whose keys are
+//! whitelisted with `set_feeders`. The offchain worker of a node holding a whitelisted key in its
+//! keystore reads the rate of each asset kind from the offchain local storage at
+//! [`Pallet::offchain_rate_key`], where it is expected to be put SCALE encoded by some external
+//! oracle, e.g. with the `offchain_localStorageSet` RPC. Differing rates are submitted as signed
+//! payloads in unsigned transactions, at most once every [`Config::OffchainRepeat`] blocks per
+//! asset kind.
+//!
+//! A fed rate may deviate from the rate at the start of the current [`Config::FeedPeriod`] by at
+//! most [`Config::MaxFeedDeviation`]. Fed rates deviating further are not applied and
+//! [`Event::RateFeedAnomaly`] is emitted instead. Rates set with `update` always take effect and
+//! start a new period.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::traits::{
 	fungible::Inspect,
 	tokens::{ConversionFromAssetBalance, ConversionToAssetBalance},
 };
+use frame_system::{
+	offchain::{CreateBare, SubmitTransaction},
+	pallet_prelude::BlockNumberFor,
+};
+use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{CheckedDiv, Zero},
-	FixedPointNumber, FixedU128,
+	app_crypto::RuntimeAppPublic,
+	offchain::storage::StorageValueRef,
+	traits::{CheckedDiv, Saturating, Zero},
+	FixedPointNumber, FixedU128, KeyTypeId, RuntimeDebug,
 };
 
 pub use pallet::*;
@@ -84,24 +113,87 @@ pub mod weights;
 #[cfg(feature = "runtime-benchmarks")]
 pub use benchmarking::AssetKindFactory;
 
+const LOG_TARGET: &str = "runtime::asset-rate";
+
+/// The key type of the keys used by offchain workers to sign conversion rate feeds.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"arat");
+
+/// The prefix of the offchain local storage keys holding the conversion rates to be fed.
+pub const OFFCHAIN_RATE_PREFIX: &[u8] = b"asset-rate::feed";
+
+/// The prefix of the offchain local storage keys holding the block of the last rate feed
+/// submitted for an asset kind.
+const OFFCHAIN_LAST_FEED_PREFIX: &[u8] = b"asset-rate::last-feed";
+
+pub mod sr25519 {
+	mod app_sr25519 {
+		use sp_runtime::app_crypto::{app_crypto, sr25519};
+		app_crypto!(sr25519, crate::KEY_TYPE);
+	}
+
+	/// A rate feeder signature using sr25519 as its crypto.
+	pub type FeederSignature = app_sr25519::Signature;
+
+	/// A rate feeder identifier using sr25519 as its crypto.
+	pub type FeederId = app_sr25519::Public;
+}
+
+/// A conversion rate fed by an offchain worker.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct RateFeed<AssetKind, BlockNumber, FeederId> {
+	/// The asset kind the conversion rate is fed for.
+	pub asset_kind: AssetKind,
+	/// The fed conversion rate to native balance.
+	pub rate: FixedU128,
+	/// The block number at which the rate was fed.
+	pub block_number: BlockNumber,
+	/// The whitelisted feeder signing the rate.
+	pub feeder: FeederId,
+}
+
+/// The state of the conversion rate feed of an asset kind.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FeedState<BlockNumber> {
+	/// The block number at which the current feed period started.
+	pub period_start: BlockNumber,
+	/// The conversion rate at the start of the current feed period, which fed rates may not
+	/// deviate from by more than the maximum deviation.
+	pub reference_rate: FixedU128,
+	/// The block number of the latest accepted feed. Feeds from earlier blocks are stale.
+	pub last_fed_at: BlockNumber,
+}
+
 // Type alias for `frame_system`'s account id.
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 // This pallet's asset kind and balance type.
 type AssetKindOf<T> = <T as Config>::AssetKind;
 // Generic fungible balance type.
 type BalanceOf<T> = <<T as Config>::Currency as Inspect<AccountIdOf<T>>>::Balance;
+// The rate feed type of the pallet.
+type RateFeedOf<T> = RateFeed<AssetKindOf<T>, BlockNumberFor<T>, <T as Config>::FeederId>;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::Perbill;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: CreateBare<Call<Self>> + frame_system::Config {
 		/// The Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -124,6 +216,42 @@ pub mod pallet {
 		/// The type for asset kinds for which the conversion rate to native balance is set.
 		type AssetKind: Parameter + MaxEncodedLen;
 
+		/// The identifier type of the offchain workers feeding conversion rates.
+		type FeederId: Member
+			+ Parameter
+			+ RuntimeAppPublic
+			+ Ord
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen;
+
+		/// The maximum number of whitelisted feeders.
+		#[pallet::constant]
+		type MaxFeeders: Get<u32>;
+
+		/// The number of blocks over which the deviation of fed conversion rates is bounded.
+		#[pallet::constant]
+		type FeedPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum deviation of a fed conversion rate from the rate at the start of the
+		/// current feed period.
+		#[pallet::constant]
+		type MaxFeedDeviation: Get<Perbill>;
+
+		/// The minimal number of blocks between two rate feeds for the same asset kind submitted
+		/// by the offchain worker of a node.
+		///
+		/// A feed is valid in the transaction pool for this many blocks, so a node has at most one
+		/// pending feed per asset kind. Should not exceed [`Config::FeedPeriod`].
+		#[pallet::constant]
+		type OffchainRepeat: Get<BlockNumberFor<Self>>;
+
+		/// A configuration for base priority of unsigned transactions.
+		///
+		/// This is exposed so that it can be tuned for particular runtime, when
+		/// multiple pallets send unsigned transactions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: crate::AssetKindFactory<Self::AssetKind>;
@@ -136,15 +264,46 @@ pub mod pallet {
 	pub type ConversionRateToNative<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetKind, FixedU128, OptionQuery>;
 
+	/// The keys of the offchain workers whitelisted to feed conversion rates.
+	#[pallet::storage]
+	pub type Feeders<T: Config> =
+		StorageValue<_, BoundedVec<T::FeederId, T::MaxFeeders>, ValueQuery>;
+
+	/// The state of the conversion rate feed of an asset kind.
+	#[pallet::storage]
+	pub type FeedStates<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetKind, FeedState<BlockNumberFor<T>>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		// Some `asset_kind` conversion rate was created.
-		AssetRateCreated { asset_kind: T::AssetKind, rate: FixedU128 },
+		AssetRateCreated {
+			asset_kind: T::AssetKind,
+			rate: FixedU128,
+		},
 		// Some `asset_kind` conversion rate was removed.
-		AssetRateRemoved { asset_kind: T::AssetKind },
+		AssetRateRemoved {
+			asset_kind: T::AssetKind,
+		},
 		// Some existing `asset_kind` conversion rate was updated from `old` to `new`.
-		AssetRateUpdated { asset_kind: T::AssetKind, old: FixedU128, new: FixedU128 },
+		AssetRateUpdated {
+			asset_kind: T::AssetKind,
+			old: FixedU128,
+			new: FixedU128,
+		},
+		// The offchain workers whitelisted to feed conversion rates were set.
+		FeedersSet {
+			feeders: BoundedVec<T::FeederId, T::MaxFeeders>,
+		},
+		// A fed `asset_kind` conversion rate deviated too far from the `reference` rate of the
+		// current feed period and was not applied.
+		RateFeedAnomaly {
+			asset_kind: T::AssetKind,
+			feeder: T::FeederId,
+			reference: FixedU128,
+			reported: FixedU128,
+		},
 	}
 
 	#[pallet::error]
@@ -206,6 +365,11 @@ pub mod pallet {
 					Err(Error::<T>::UnknownAssetKind)
 				}
 			})?;
+			// The updated rate overrides any fed rate and is the reference of a new feed period.
+			FeedStates::<T>::mutate_extant(asset_kind.as_ref(), |state| {
+				state.period_start = frame_system::Pallet::<T>::block_number();
+				state.reference_rate = rate;
+			});
 
 			Self::deposit_event(Event::AssetRateUpdated {
 				asset_kind: *asset_kind,
@@ -229,10 +393,202 @@ pub mod pallet {
 				Error::<T>::UnknownAssetKind
 			);
 			ConversionRateToNative::<T>::remove(asset_kind.as_ref());
+			FeedStates::<T>::remove(asset_kind.as_ref());
 
 			Self::deposit_event(Event::AssetRateRemoved { asset_kind: *asset_kind });
 			Ok(())
 		}
+
+		/// Set the keys of the offchain workers whitelisted to feed conversion rates.
+		///
+		/// An empty list disables rate feeds.
+		///
+		/// ## Complexity
+		/// - O(F) where F is the number of feeders.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::set_feeders(feeders.len() as u32))]
+		pub fn set_feeders(
+			origin: OriginFor<T>,
+			feeders: BoundedVec<T::FeederId, T::MaxFeeders>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			Feeders::<T>::put(&feeders);
+
+			Self::deposit_event(Event::FeedersSet { feeders });
+			Ok(())
+		}
+
+		/// Update the conversion rate to native balance for the given asset with a rate fed by a
+		/// whitelisted offchain worker.
+		///
+		/// The rate is not applied if it deviates from the rate at the start of the current feed
+		/// period by more than [`Config::MaxFeedDeviation`], in which case
+		/// [`Event::RateFeedAnomaly`] is emitted.
+		///
+		/// ## Complexity
+		/// - O(1)
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::feed())]
+		pub fn feed(
+			origin: OriginFor<T>,
+			feed: RateFeedOf<T>,
+			// since signature verification is done in `validate_unsigned`
+			// we can skip doing it here again.
+			_signature: <T::FeederId as RuntimeAppPublic>::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let RateFeed { asset_kind, rate, block_number, feeder } = feed;
+			let old = ConversionRateToNative::<T>::get(&asset_kind)
+				.ok_or(Error::<T>::UnknownAssetKind)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut state = FeedStates::<T>::get(&asset_kind).unwrap_or(FeedState {
+				period_start: now,
+				reference_rate: old,
+				last_fed_at: block_number,
+			});
+			if now >= state.period_start.saturating_add(T::FeedPeriod::get()) {
+				state.period_start = now;
+				state.reference_rate = old;
+			}
+			state.last_fed_at = block_number;
+
+			if Self::exceeds_max_deviation(state.reference_rate, rate) {
+				Self::deposit_event(Event::RateFeedAnomaly {
+					asset_kind: asset_kind.clone(),
+					feeder,
+					reference: state.reference_rate,
+					reported: rate,
+				});
+			} else {
+				ConversionRateToNative::<T>::insert(&asset_kind, rate);
+				Self::deposit_event(Event::AssetRateUpdated {
+					asset_kind: asset_kind.clone(),
+					old,
+					new: rate,
+				});
+			}
+			FeedStates::<T>::insert(&asset_kind, state);
+
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			Self::offchain_feed_rates(now);
+		}
+	}
+
+	/// Invalid transaction custom error. Returned when the fed asset kind has no conversion rate.
+	pub(crate) const UNKNOWN_ASSET_KIND: u8 = 10;
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::feed { feed, signature } = call else {
+				return InvalidTransaction::Call.into()
+			};
+
+			let now = frame_system::Pallet::<T>::block_number();
+			if feed.block_number > now {
+				return InvalidTransaction::Future.into()
+			}
+			if feed.block_number.saturating_add(T::FeedPeriod::get()) <= now {
+				return InvalidTransaction::Stale.into()
+			}
+			if FeedStates::<T>::get(&feed.asset_kind)
+				.is_some_and(|state| feed.block_number <= state.last_fed_at)
+			{
+				// a more recent rate was already fed.
+				return InvalidTransaction::Stale.into()
+			}
+			if !ConversionRateToNative::<T>::contains_key(&feed.asset_kind) {
+				return InvalidTransaction::Custom(UNKNOWN_ASSET_KIND).into()
+			}
+			if !Feeders::<T>::get().contains(&feed.feeder) {
+				return InvalidTransaction::BadProof.into()
+			}
+
+			// check signature (this is expensive so we do it last).
+			let signature_valid =
+				feed.using_encoded(|encoded_feed| feed.feeder.verify(&encoded_feed, signature));
+			if !signature_valid {
+				return InvalidTransaction::BadProof.into()
+			}
+
+			ValidTransaction::with_tag_prefix("AssetRateFeed")
+				.priority(T::UnsignedPriority::get())
+				.and_provides((feed.asset_kind.clone(), feed.block_number))
+				.longevity(TryInto::<u64>::try_into(T::OffchainRepeat::get()).unwrap_or(64_u64))
+				.propagate(true)
+				.build()
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The key of the offchain local storage entry holding the SCALE encoded [`FixedU128`]
+	/// conversion rate to be fed for `asset_kind`.
+	pub fn offchain_rate_key(asset_kind: &T::AssetKind) -> Vec<u8> {
+		(OFFCHAIN_RATE_PREFIX, asset_kind).encode()
+	}
+
+	/// Whether `rate` deviates from `reference` by more than [`Config::MaxFeedDeviation`].
+	fn exceeds_max_deviation(reference: FixedU128, rate: FixedU128) -> bool {
+		let max_deviation =
+			reference.saturating_mul(FixedU128::from_perbill(T::MaxFeedDeviation::get()));
+		let deviation = if rate > reference {
+			rate.saturating_sub(reference)
+		} else {
+			reference.saturating_sub(rate)
+		};
+		deviation > max_deviation
+	}
+
+	/// Submit the conversion rates found in the offchain local storage that differ from the
+	/// current ones, if the local keystore holds a whitelisted feeder key.
+	fn offchain_feed_rates(now: BlockNumberFor<T>) {
+		let local_keys = T::FeederId::all();
+		let Some(feeder) = Feeders::<T>::get().into_iter().find(|f| local_keys.contains(f)) else {
+			return
+		};
+
+		for (asset_kind, current) in ConversionRateToNative::<T>::iter() {
+			let rate = match StorageValueRef::persistent(&Self::offchain_rate_key(&asset_kind))
+				.get::<FixedU128>()
+			{
+				Ok(Some(rate)) if rate != current => rate,
+				_ => continue,
+			};
+
+			// Submit at most one feed per asset kind while the last one may still be pending.
+			let last_feed =
+				StorageValueRef::persistent(&(OFFCHAIN_LAST_FEED_PREFIX, &asset_kind).encode());
+			let may_feed = last_feed.mutate::<BlockNumberFor<T>, (), _>(|last| match last {
+				Ok(Some(last)) if now < last.saturating_add(T::OffchainRepeat::get()) => Err(()),
+				_ => Ok(now),
+			});
+			if may_feed.is_err() {
+				continue
+			}
+
+			let feed = RateFeed { asset_kind, rate, block_number: now, feeder: feeder.clone() };
+			let Some(signature) = feeder.sign(&feed.encode()) else {
+				log::warn!(target: LOG_TARGET, "failed to sign the rate feed: {:?}", feed);
+				continue
+			};
+
+			let xt = T::create_bare(Call::feed { feed, signature }.into());
+			if SubmitTransaction::<T, Call<T>>::submit_transaction(xt).is_err() {
+				log::warn!(target: LOG_TARGET, "failed to submit the rate feed at {:?}", now);
+			}
+		}
 	}
 }
 
@@ -18,10 +18,14 @@
 //! The crate's mock.
 
 use crate as pallet_asset_rate;
-use frame_support::derive_impl;
-use sp_runtime::BuildStorage;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use sp_runtime::{testing::UintAuthorityId, BuildStorage, Perbill};
 
 type Block = frame_system::mocking::MockBlock<Test>;
+pub type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
 
 frame_support::construct_runtime!(
 	pub enum Test
@@ -43,6 +47,29 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 }
 
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type RuntimeCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateBare<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_bare(call: Self::RuntimeCall) -> Self::Extrinsic {
+		Extrinsic::new_bare(call)
+	}
+}
+
+parameter_types! {
+	pub const FeedPeriod: u64 = 10;
+	pub const OffchainRepeat: u64 = 3;
+	pub const MaxFeedDeviation: Perbill = Perbill::from_percent(10);
+}
+
 impl pallet_asset_rate::Config for Test {
 	type WeightInfo = ();
 	type RuntimeEvent = RuntimeEvent;
@@ -51,6 +78,12 @@ impl pallet_asset_rate::Config for Test {
 	type UpdateOrigin = frame_system::EnsureRoot<u64>;
 	type Currency = Balances;
 	type AssetKind = u32;
+	type FeederId = UintAuthorityId;
+	type MaxFeeders = ConstU32<10>;
+	type FeedPeriod = FeedPeriod;
+	type MaxFeedDeviation = MaxFeedDeviation;
+	type OffchainRepeat = OffchainRepeat;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -19,9 +19,19 @@
 
 use super::*;
 use crate::pallet as pallet_asset_rate;
-use frame_support::{assert_noop, assert_ok};
-use mock::{new_test_ext, AssetRate, RuntimeOrigin, Test};
-use sp_runtime::FixedU128;
+use frame_support::{
+	assert_noop, assert_ok,
+	pallet_prelude::*,
+	traits::{OffchainWorker, UnfilteredDispatchable},
+};
+use mock::{
+	new_test_ext, AssetRate, Extrinsic, OffchainRepeat, RuntimeCall, RuntimeOrigin, System, Test,
+};
+use sp_core::offchain::{
+	testing::{TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_runtime::{testing::UintAuthorityId, FixedU128};
 
 const ASSET_ID: u32 = 42;
 
@@ -176,3 +186,222 @@ fn convert_overflow_throws() {
 		assert!(conversion.is_err());
 	});
 }
+
+fn rate_feed(rate: FixedU128, block_number: u64, feeder: u64) -> (RateFeedOf<Test>, Call<Test>) {
+	let feed =
+		RateFeed { asset_kind: ASSET_ID, rate, block_number, feeder: UintAuthorityId(feeder) };
+	let signature = feed.feeder.sign(&feed.encode()).unwrap();
+	(feed.clone(), Call::feed { feed, signature })
+}
+
+fn submit_feed(rate: FixedU128, block_number: u64, feeder: u64) -> DispatchResult {
+	let (_, call) = rate_feed(rate, block_number, feeder);
+	AssetRate::validate_unsigned(TransactionSource::External, &call)
+		.map_err(|_| DispatchError::Other("invalid feed"))?;
+	call.dispatch_bypass_filter(RuntimeOrigin::none())
+		.map(|_| ())
+		.map_err(|e| e.error)
+}
+
+fn setup_feed() {
+	System::set_block_number(1);
+	assert_ok!(AssetRate::create(
+		RuntimeOrigin::root(),
+		Box::new(ASSET_ID),
+		FixedU128::from_u32(1)
+	));
+	assert_ok!(AssetRate::set_feeders(
+		RuntimeOrigin::root(),
+		vec![UintAuthorityId(1), UintAuthorityId(2)].try_into().unwrap()
+	));
+}
+
+#[test]
+fn set_feeders_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let feeders: BoundedVec<_, _> = vec![UintAuthorityId(1)].try_into().unwrap();
+		assert_noop!(
+			AssetRate::set_feeders(RuntimeOrigin::signed(1), feeders.clone()),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(AssetRate::set_feeders(RuntimeOrigin::root(), feeders.clone()));
+		assert_eq!(pallet_asset_rate::Feeders::<Test>::get(), feeders);
+		System::assert_last_event(Event::<Test>::FeedersSet { feeders }.into());
+	});
+}
+
+#[test]
+fn feed_works() {
+	new_test_ext().execute_with(|| {
+		setup_feed();
+
+		assert_ok!(submit_feed(FixedU128::from_rational(105, 100), 1, 1));
+		assert_eq!(
+			pallet_asset_rate::ConversionRateToNative::<Test>::get(ASSET_ID),
+			Some(FixedU128::from_rational(105, 100))
+		);
+		System::assert_last_event(
+			Event::<Test>::AssetRateUpdated {
+				asset_kind: ASSET_ID,
+				old: FixedU128::from_u32(1),
+				new: FixedU128::from_rational(105, 100),
+			}
+			.into(),
+		);
+
+		// the deviation is bounded by the rate at the start of the period.
+		System::set_block_number(2);
+		assert_ok!(submit_feed(FixedU128::from_rational(11, 10), 2, 2));
+		System::set_block_number(3);
+		assert_ok!(submit_feed(FixedU128::from_rational(115, 100), 3, 1));
+		assert_eq!(
+			pallet_asset_rate::ConversionRateToNative::<Test>::get(ASSET_ID),
+			Some(FixedU128::from_rational(11, 10))
+		);
+		System::assert_last_event(
+			Event::<Test>::RateFeedAnomaly {
+				asset_kind: ASSET_ID,
+				feeder: UintAuthorityId(1),
+				reference: FixedU128::from_u32(1),
+				reported: FixedU128::from_rational(115, 100),
+			}
+			.into(),
+		);
+
+		// a new period starts from the current rate.
+		System::set_block_number(11);
+		assert_ok!(submit_feed(FixedU128::from_rational(115, 100), 11, 1));
+		assert_eq!(
+			pallet_asset_rate::ConversionRateToNative::<Test>::get(ASSET_ID),
+			Some(FixedU128::from_rational(115, 100))
+		);
+		assert_eq!(
+			pallet_asset_rate::FeedStates::<Test>::get(ASSET_ID),
+			Some(FeedState {
+				period_start: 11,
+				reference_rate: FixedU128::from_rational(11, 10),
+				last_fed_at: 11,
+			})
+		);
+	});
+}
+
+#[test]
+fn update_overrides_fed_rate() {
+	new_test_ext().execute_with(|| {
+		setup_feed();
+		assert_ok!(submit_feed(FixedU128::from_rational(105, 100), 1, 1));
+
+		System::set_block_number(2);
+		assert_ok!(AssetRate::update(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			FixedU128::from_u32(2)
+		));
+		let state = pallet_asset_rate::FeedStates::<Test>::get(ASSET_ID).unwrap();
+		assert_eq!((state.period_start, state.reference_rate), (2, FixedU128::from_u32(2)));
+
+		// fed rates are bounded by the updated rate.
+		assert_ok!(submit_feed(FixedU128::from_rational(21, 10), 2, 1));
+		assert_eq!(
+			pallet_asset_rate::ConversionRateToNative::<Test>::get(ASSET_ID),
+			Some(FixedU128::from_rational(21, 10))
+		);
+
+		assert_ok!(AssetRate::remove(RuntimeOrigin::root(), Box::new(ASSET_ID)));
+		assert!(pallet_asset_rate::FeedStates::<Test>::get(ASSET_ID).is_none());
+	});
+}
+
+#[test]
+fn feed_validation_works() {
+	new_test_ext().execute_with(|| {
+		setup_feed();
+		System::set_block_number(5);
+		let validate =
+			|call: &Call<Test>| AssetRate::validate_unsigned(TransactionSource::External, call);
+		let rate = FixedU128::from_rational(105, 100);
+
+		// not a whitelisted feeder.
+		let (_, call) = rate_feed(rate, 5, 3);
+		assert_eq!(validate(&call), InvalidTransaction::BadProof.into());
+
+		// invalid signature.
+		let (feed, _) = rate_feed(rate, 5, 1);
+		let signature = UintAuthorityId(2).sign(&feed.encode()).unwrap();
+		assert_eq!(validate(&Call::feed { feed, signature }), InvalidTransaction::BadProof.into());
+
+		// fed in the future.
+		let (_, call) = rate_feed(rate, 6, 1);
+		assert_eq!(validate(&call), InvalidTransaction::Future.into());
+
+		// unknown asset kind.
+		let (mut feed, _) = rate_feed(rate, 5, 1);
+		feed.asset_kind = ASSET_ID + 1;
+		let signature = feed.feeder.sign(&feed.encode()).unwrap();
+		assert_eq!(
+			validate(&Call::feed { feed, signature }),
+			InvalidTransaction::Custom(UNKNOWN_ASSET_KIND).into()
+		);
+
+		// valid feed.
+		let (_, call) = rate_feed(rate, 5, 1);
+		assert_ok!(validate(&call));
+
+		// a more recent rate was fed already.
+		assert_ok!(submit_feed(rate, 5, 2));
+		assert_eq!(validate(&call), InvalidTransaction::Stale.into());
+
+		// fed more than a period ago.
+		System::set_block_number(16);
+		let (_, call) = rate_feed(rate, 6, 1);
+		assert_eq!(validate(&call), InvalidTransaction::Stale.into());
+	});
+}
+
+#[test]
+fn offchain_worker_feeds_rates() {
+	let mut ext = new_test_ext();
+	let (offchain, _state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		setup_feed();
+		let rate = FixedU128::from_rational(105, 100);
+		sp_runtime::offchain::storage::StorageValueRef::persistent(&AssetRate::offchain_rate_key(
+			&ASSET_ID,
+		))
+		.set(&rate);
+
+		// no whitelisted key in the keystore.
+		UintAuthorityId::set_all_keys(vec![3]);
+		AssetRate::offchain_worker(1);
+		assert!(pool_state.read().transactions.is_empty());
+
+		UintAuthorityId::set_all_keys(vec![2, 3]);
+		AssetRate::offchain_worker(1);
+		let transaction = pool_state.write().transactions.pop().unwrap();
+		assert!(pool_state.read().transactions.is_empty());
+		let ex: Extrinsic = Decode::decode(&mut &*transaction).unwrap();
+		let (expected, _) = rate_feed(rate, 1, 2);
+		match ex.function {
+			RuntimeCall::AssetRate(crate::Call::feed { feed, .. }) => assert_eq!(feed, expected),
+			e => panic!("Unexpected call: {:?}", e),
+		}
+
+		// no new feed while the last one may still be pending.
+		AssetRate::offchain_worker(3);
+		assert!(pool_state.read().transactions.is_empty());
+		AssetRate::offchain_worker(1 + OffchainRepeat::get());
+		assert_eq!(pool_state.write().transactions.drain(..).count(), 1);
+
+		// no feed once the rate is up to date.
+		assert_ok!(submit_feed(rate, 1, 2));
+		AssetRate::offchain_worker(1 + 2 * OffchainRepeat::get());
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
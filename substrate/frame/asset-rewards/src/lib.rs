@@ -407,6 +407,29 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// The state and configuration of the pool `pool_id`, if it exists.
+		pub fn pool_info(pool_id: PoolId) -> Option<PoolInfoFor<T>> {
+			Pools::<T>::get(pool_id)
+		}
+
+		/// The amount of tokens that `staker` staked in the pool `pool_id`, if any.
+		pub fn staked_amount(pool_id: PoolId, staker: T::AccountId) -> Option<T::Balance> {
+			PoolStakers::<T>::get(pool_id, staker).map(|info| info.amount)
+		}
+
+		/// The rewards that `staker` could harvest from the pool `pool_id` right now, including
+		/// the rewards accrued since the last interaction with the pool.
+		pub fn pending_rewards(pool_id: PoolId, staker: T::AccountId) -> Option<T::Balance> {
+			let pool_info = Pools::<T>::get(pool_id)?;
+			let staker_info = PoolStakers::<T>::get(pool_id, staker)?;
+			Self::update_pool_and_staker_rewards(&pool_info, &staker_info)
+				.ok()
+				.map(|(_, staker_info)| staker_info.rewards)
+		}
+	}
+
 	/// Pallet's callable functions.
 	#[pallet::call(weight(<T as Config>::WeightInfo))]
 	impl<T: Config> Pallet<T> {
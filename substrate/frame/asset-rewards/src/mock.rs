@@ -26,7 +26,7 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		tokens::fungible::{HoldConsideration, NativeFromLeft, NativeOrWithId, UnionOf},
-		AsEnsureOriginWithArg, ConstU128, ConstU32, EnsureOrigin, LinearStoragePrice,
+		AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, EnsureOrigin, LinearStoragePrice,
 	},
 	PalletId,
 };
@@ -71,7 +71,10 @@ impl pallet_balances::Config for MockRuntime {
 	type MaxFreezes = ConstU32<50>;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type DoneSlashHandler = ();
+	type Scheduler = ();
+	type MaxScheduledTransferDelay = ConstU64<100>;
 }
 
 impl pallet_assets::Config<Instance1> for MockRuntime {
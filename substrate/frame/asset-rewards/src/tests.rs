@@ -740,6 +740,28 @@ mod harvest_rewards {
 			);
 		});
 	}
+
+	#[test]
+	fn view_functions_report_pending_rewards() {
+		new_test_ext().execute_with(|| {
+			let staker = 1;
+			let pool_id = 0;
+			assert_eq!(StakingRewards::pool_info(pool_id), None);
+			create_default_pool();
+			assert_eq!(StakingRewards::pool_info(pool_id), Pools::<MockRuntime>::get(pool_id));
+			assert_eq!(StakingRewards::pending_rewards(pool_id, staker), None);
+
+			System::set_block_number(10);
+			assert_ok!(StakingRewards::stake(RuntimeOrigin::signed(staker), pool_id, 1000));
+			assert_eq!(StakingRewards::staked_amount(pool_id, staker), Some(1000));
+			assert_eq!(StakingRewards::pending_rewards(pool_id, staker), Some(0));
+
+			System::set_block_number(20);
+			let pending = 10 * DEFAULT_REWARD_RATE_PER_BLOCK;
+			assert_eq!(StakingRewards::pending_rewards(pool_id, staker), Some(pending));
+			assert_hypothetically_earned(staker, pending, pool_id, DEFAULT_REWARD_ASSET_ID);
+		});
+	}
 }
 
 mod set_pool_admin {
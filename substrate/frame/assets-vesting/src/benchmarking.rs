@@ -0,0 +1,159 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Assets vesting pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame::{benchmarking::prelude::*, traits::fungibles::Create};
+
+const SEED: u32 = 0;
+
+/// Create the benchmarked asset and fund `source` with it.
+fn create_asset<T: Config<I>, I: 'static>(source: &T::AccountId) -> AssetIdOf<T, I>
+where
+	T::Assets: Create<T::AccountId>,
+{
+	let asset = T::BenchmarkHelper::asset_id();
+	let min_balance = 1u32.into();
+	assert_ok!(T::Assets::create(asset.clone(), source.clone(), true, min_balance));
+	assert_ok!(T::Assets::mint_into(
+		asset.clone(),
+		source,
+		BalanceOf::<T, I>::max_value() / 2u32.into()
+	));
+	asset
+}
+
+/// Add `n` schedules of 20 units each, vesting over 20 blocks from block 1, to `target`.
+fn add_vesting_schedules<T: Config<I>, I: 'static>(
+	asset: &AssetIdOf<T, I>,
+	source: &T::AccountId,
+	target: &T::AccountId,
+	n: u32,
+) -> BalanceOf<T, I> {
+	T::BlockNumberProvider::set_block_number(Zero::zero());
+
+	let locked: BalanceOf<T, I> = 20u32.into();
+	let mut total_locked: BalanceOf<T, I> = Zero::zero();
+	for _ in 0..n {
+		let schedule = VestingInfo::new(locked, 1u32.into(), 1u32.into());
+		assert_ok!(Pallet::<T, I>::do_vested_transfer(asset.clone(), source, target, schedule));
+		total_locked += locked;
+	}
+	total_locked
+}
+
+#[instance_benchmarks(where T::Assets: Create<T::AccountId>)]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn vest(s: Linear<1, { T::MaxVestingSchedules::get() }>) {
+		let source: T::AccountId = account("source", 0, SEED);
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T, I>(&source);
+		let total_locked = add_vesting_schedules::<T, I>(&asset, &source, &caller, s);
+
+		// Halfway through the schedules, so the freeze is updated.
+		T::BlockNumberProvider::set_block_number(11u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), asset.clone());
+
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(asset, &caller),
+			Some(total_locked / 2u32.into())
+		);
+	}
+
+	#[benchmark]
+	fn vest_other(s: Linear<1, { T::MaxVestingSchedules::get() }>) {
+		let source: T::AccountId = account("source", 0, SEED);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let caller: T::AccountId = whitelisted_caller();
+		let asset = create_asset::<T, I>(&source);
+		let total_locked = add_vesting_schedules::<T, I>(&asset, &source, &target, s);
+
+		T::BlockNumberProvider::set_block_number(11u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset.clone(), target_lookup);
+
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(asset, &target),
+			Some(total_locked / 2u32.into())
+		);
+	}
+
+	#[benchmark]
+	fn vested_transfer(s: Linear<1, { T::MaxVestingSchedules::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let asset = create_asset::<T, I>(&caller);
+		// Add one schedule less than the maximum, to leave room for the new one.
+		let total_locked = add_vesting_schedules::<T, I>(&asset, &caller, &target, s - 1);
+		let schedule = VestingInfo::new(20u32.into(), 1u32.into(), 1u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), asset.clone(), target_lookup, schedule);
+
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(asset, &target),
+			Some(total_locked + 20u32.into())
+		);
+	}
+
+	#[benchmark]
+	fn force_vested_transfer(s: Linear<1, { T::MaxVestingSchedules::get() }>) {
+		let source: T::AccountId = account("source", 0, SEED);
+		let source_lookup = T::Lookup::unlookup(source.clone());
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let asset = create_asset::<T, I>(&source);
+		let total_locked = add_vesting_schedules::<T, I>(&asset, &source, &target, s - 1);
+		let schedule = VestingInfo::new(20u32.into(), 1u32.into(), 1u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, asset.clone(), source_lookup, target_lookup, schedule);
+
+		assert_eq!(
+			Pallet::<T, I>::vesting_balance(asset, &target),
+			Some(total_locked + 20u32.into())
+		);
+	}
+
+	#[benchmark]
+	fn force_remove_vesting_schedule(s: Linear<1, { T::MaxVestingSchedules::get() }>) {
+		let source: T::AccountId = account("source", 0, SEED);
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let asset = create_asset::<T, I>(&source);
+		let total_locked = add_vesting_schedules::<T, I>(&asset, &source, &target, s);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, asset.clone(), target_lookup, 0);
+
+		let expected = (s > 1).then(|| total_locked - 20u32.into());
+		assert_eq!(Pallet::<T, I>::vesting_balance(asset, &target), expected);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
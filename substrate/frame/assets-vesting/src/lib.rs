@@ -0,0 +1,410 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Assets Vesting Pallet
+//!
+//! A pallet placing linear vesting schedules on balances of fungible assets, such as the ones of
+//! `pallet-assets`. It is the counterpart of `pallet-vesting` for a
+//! [`fungibles`](frame::traits::fungibles) implementation: the unvested balance of an account is
+//! kept in place with a freeze on the vested asset instead of a lock on the native currency.
+//!
+//! Schedules are [`VestingInfo`]s, progressing with the block number of
+//! [`Config::BlockNumberProvider`] in the same way as in `pallet-vesting`. The pallet is
+//! instantiable, so a runtime may vest the assets of several `pallet-assets` instances.
+//!
+//! ## Pallet API
+//!
+//! See the [`pallet`] module for more information about the interfaces this pallet exposes,
+//! including its configuration trait, dispatchables, storage items, events and errors.
+//!
+//! ### Dispatchable Functions
+//!
+//! - `vest` - Update the freeze on an asset, reducing it in line with the amount "vested" so far.
+//! - `vest_other` - Update the freeze on an asset of another account, reducing it in line with the
+//!   amount "vested" so far.
+//! - `vested_transfer` - Transfer an asset to another account under a vesting schedule.
+//! - `force_vested_transfer` - Transfer an asset from any account to another one under a vesting
+//!   schedule.
+//! - `force_remove_vesting_schedule` - Remove a vesting schedule of an account.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use alloc::vec::Vec;
+use frame::{
+	prelude::*,
+	traits::{
+		fungibles::{Inspect, Mutate, MutateFreeze},
+		tokens::Preservation,
+	},
+};
+pub use pallet::*;
+pub use pallet_vesting::VestingInfo;
+pub use weights::WeightInfo;
+
+pub type AssetIdOf<T, I = ()> =
+	<<T as Config<I>>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+pub type BalanceOf<T, I = ()> =
+	<<T as Config<I>>::Assets as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+pub type VestingInfoOf<T, I = ()> = VestingInfo<BalanceOf<T, I>, BlockNumberFor<T>>;
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+/// Helper to provide an asset to vest in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// Returns the id of an asset which does not exist yet.
+	fn asset_id() -> AssetId;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetId: From<u32>> BenchmarkHelper<AssetId> for () {
+	fn asset_id() -> AssetId {
+		1u32.into()
+	}
+}
+
+#[frame::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The assets which can be vested.
+		type Assets: Mutate<Self::AccountId>;
+
+		/// Freezes the unvested balance of the vested assets.
+		type Freezer: MutateFreeze<
+			Self::AccountId,
+			AssetId = AssetIdOf<Self, I>,
+			Balance = BalanceOf<Self, I>,
+			Id = Self::RuntimeFreezeReason,
+		>;
+
+		/// The overarching freeze reason.
+		type RuntimeFreezeReason: From<FreezeReason<I>>;
+
+		/// Convert the block number into a balance.
+		type BlockNumberToBalance: Convert<BlockNumberFor<Self>, BalanceOf<Self, I>>;
+
+		/// Query the current block number.
+		///
+		/// Must return monotonically increasing values when called from consecutive blocks. See
+		/// `pallet_vesting::Config::BlockNumberProvider` for the suggested values.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Maximum number of vesting schedules an account may have for an asset at a given moment.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper to provide an asset to vest in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetIdOf<Self, I>>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(_);
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn integrity_test() {
+			assert!(
+				T::MaxVestingSchedules::get() > 0,
+				"`MaxVestingSchedules` must be greater than 0"
+			);
+		}
+	}
+
+	/// The vesting schedules of an account for a given asset.
+	#[pallet::storage]
+	pub type Vesting<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetIdOf<T, I>,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<VestingInfoOf<T, I>, T::MaxVestingSchedules>,
+	>;
+
+	/// A reason for the pallet placing a freeze on funds.
+	#[pallet::composite_enum]
+	pub enum FreezeReason<I: 'static = ()> {
+		/// The unvested balance of an asset.
+		#[codec(index = 0)]
+		Vesting,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A vesting schedule has been created.
+		VestingCreated { asset: AssetIdOf<T, I>, account: T::AccountId, schedule_index: u32 },
+		/// The amount vested has been updated. This could indicate a change in funds available.
+		/// The balance given is the amount which is left unvested (and thus frozen).
+		VestingUpdated { asset: AssetIdOf<T, I>, account: T::AccountId, unvested: BalanceOf<T, I> },
+		/// An account has become fully vested for an asset.
+		VestingCompleted { asset: AssetIdOf<T, I>, account: T::AccountId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The account given is not vesting the asset.
+		NotVesting,
+		/// The account already has `MaxVestingSchedules` count of schedules for the asset and thus
+		/// cannot add another one.
+		AtMaxVestingSchedules,
+		/// Amount being transferred is below the minimum balance of the asset.
+		AmountLow,
+		/// An index was out of bounds of the vesting schedules.
+		ScheduleIndexOutOfBounds,
+		/// Failed to create a new schedule because some parameter was invalid.
+		InvalidScheduleParams,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Unfreeze any vested funds of an asset of the sender account.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have funds of
+		/// `asset` still frozen under this pallet.
+		///
+		/// Emits either `VestingCompleted` or `VestingUpdated`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::vest(T::MaxVestingSchedules::get()))]
+		pub fn vest(origin: OriginFor<T>, asset: AssetIdOf<T, I>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_vest(asset, who)
+		}
+
+		/// Unfreeze any vested funds of an asset of a `target` account.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `asset`: The vested asset.
+		/// - `target`: The account whose vested funds should be unfrozen. Must have funds of
+		///   `asset` still frozen under this pallet.
+		///
+		/// Emits either `VestingCompleted` or `VestingUpdated`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::vest_other(T::MaxVestingSchedules::get()))]
+		pub fn vest_other(
+			origin: OriginFor<T>,
+			asset: AssetIdOf<T, I>,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(target)?;
+			Self::do_vest(asset, who)
+		}
+
+		/// Create a vested transfer of an asset.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `asset`: The asset to transfer.
+		/// - `target`: The account receiving the vested funds.
+		/// - `schedule`: The vesting schedule attached to the transfer.
+		///
+		/// Emits `VestingCreated`.
+		///
+		/// NOTE: This will unfreeze all schedules of `target` for `asset` through the current
+		/// block.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::vested_transfer(T::MaxVestingSchedules::get()))]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			asset: AssetIdOf<T, I>,
+			target: AccountIdLookupOf<T>,
+			schedule: VestingInfoOf<T, I>,
+		) -> DispatchResult {
+			let transactor = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::do_vested_transfer(asset, &transactor, &target, schedule)
+		}
+
+		/// Force a vested transfer of an asset.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// - `asset`: The asset to transfer.
+		/// - `source`: The account whose funds should be transferred.
+		/// - `target`: The account that should be transferred the vested funds.
+		/// - `schedule`: The vesting schedule attached to the transfer.
+		///
+		/// Emits `VestingCreated`.
+		///
+		/// NOTE: This will unfreeze all schedules of `target` for `asset` through the current
+		/// block.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::force_vested_transfer(T::MaxVestingSchedules::get()))]
+		pub fn force_vested_transfer(
+			origin: OriginFor<T>,
+			asset: AssetIdOf<T, I>,
+			source: AccountIdLookupOf<T>,
+			target: AccountIdLookupOf<T>,
+			schedule: VestingInfoOf<T, I>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let source = T::Lookup::lookup(source)?;
+			Self::do_vested_transfer(asset, &source, &target, schedule)
+		}
+
+		/// Force remove a vesting schedule of an asset.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// - `asset`: The vested asset.
+		/// - `target`: An account that has a vesting schedule for `asset`.
+		/// - `schedule_index`: The vesting schedule index that should be removed.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::force_remove_vesting_schedule(
+			T::MaxVestingSchedules::get()
+		))]
+		pub fn force_remove_vesting_schedule(
+			origin: OriginFor<T>,
+			asset: AssetIdOf<T, I>,
+			target: AccountIdLookupOf<T>,
+			schedule_index: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let who = T::Lookup::lookup(target)?;
+
+			let schedules = Vesting::<T, I>::get(&asset, &who).ok_or(Error::<T, I>::NotVesting)?;
+			let schedules_count = schedules.len() as u32;
+			ensure!(schedule_index < schedules_count, Error::<T, I>::ScheduleIndexOutOfBounds);
+
+			let mut schedules = schedules.into_inner();
+			schedules.remove(schedule_index as usize);
+			Self::update_schedules(asset, &who, schedules)?;
+
+			Ok(Some(T::WeightInfo::force_remove_vesting_schedule(schedules_count)).into())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The vesting schedules of `who` for `asset`.
+	pub fn vesting(
+		asset: AssetIdOf<T, I>,
+		who: T::AccountId,
+	) -> Option<BoundedVec<VestingInfoOf<T, I>, T::MaxVestingSchedules>> {
+		Vesting::<T, I>::get(asset, who)
+	}
+
+	/// The amount of `asset` that is currently being vested and cannot be transferred out of the
+	/// account of `who`.
+	pub fn vesting_balance(asset: AssetIdOf<T, I>, who: &T::AccountId) -> Option<BalanceOf<T, I>> {
+		let schedules = Vesting::<T, I>::get(&asset, who)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		let locked_now = schedules.iter().fold(Zero::zero(), |total: BalanceOf<T, I>, schedule| {
+			schedule.locked_at::<T::BlockNumberToBalance>(now).saturating_add(total)
+		});
+		Some(T::Assets::balance(asset, who).min(locked_now))
+	}
+
+	/// Unfreeze any vested funds of `asset` of `who`.
+	fn do_vest(asset: AssetIdOf<T, I>, who: T::AccountId) -> DispatchResult {
+		let schedules = Vesting::<T, I>::get(&asset, &who).ok_or(Error::<T, I>::NotVesting)?;
+		Self::update_schedules(asset, &who, schedules.into_inner())
+	}
+
+	/// Execute a vested transfer of `asset` from `source` to `target` with the given `schedule`.
+	fn do_vested_transfer(
+		asset: AssetIdOf<T, I>,
+		source: &T::AccountId,
+		target: &T::AccountId,
+		schedule: VestingInfoOf<T, I>,
+	) -> DispatchResult {
+		ensure!(schedule.is_valid(), Error::<T, I>::InvalidScheduleParams);
+		ensure!(
+			schedule.locked() >= T::Assets::minimum_balance(asset.clone()),
+			Error::<T, I>::AmountLow
+		);
+
+		let mut schedules = Vesting::<T, I>::get(&asset, target).unwrap_or_default();
+		ensure!(schedules.try_push(schedule).is_ok(), Error::<T, I>::AtMaxVestingSchedules);
+		let schedule_index = schedules.len() as u32 - 1;
+
+		T::Assets::transfer(
+			asset.clone(),
+			source,
+			target,
+			schedule.locked(),
+			Preservation::Expendable,
+		)?;
+
+		Self::deposit_event(Event::<T, I>::VestingCreated {
+			asset: asset.clone(),
+			account: target.clone(),
+			schedule_index,
+		});
+		Self::update_schedules(asset, target, schedules.into_inner())
+	}
+
+	/// Drop the completed schedules of `who` for `asset`, and write the remaining ones along with
+	/// the freeze of the amount they keep locked at the current block.
+	fn update_schedules(
+		asset: AssetIdOf<T, I>,
+		who: &T::AccountId,
+		schedules: Vec<VestingInfoOf<T, I>>,
+	) -> DispatchResult {
+		let now = T::BlockNumberProvider::current_block_number();
+		let mut locked_now: BalanceOf<T, I> = Zero::zero();
+		let schedules = schedules
+			.into_iter()
+			.filter(|schedule| {
+				let locked = schedule.locked_at::<T::BlockNumberToBalance>(now);
+				locked_now = locked_now.saturating_add(locked);
+				!locked.is_zero()
+			})
+			.collect::<Vec<_>>();
+
+		let reason = FreezeReason::<I>::Vesting.into();
+		if locked_now.is_zero() {
+			Vesting::<T, I>::remove(&asset, who);
+			T::Freezer::thaw(asset.clone(), &reason, who)?;
+			Self::deposit_event(Event::<T, I>::VestingCompleted { asset, account: who.clone() });
+		} else {
+			let schedules: BoundedVec<_, T::MaxVestingSchedules> =
+				schedules.try_into().map_err(|_| Error::<T, I>::AtMaxVestingSchedules)?;
+			Vesting::<T, I>::insert(&asset, who, schedules);
+			T::Freezer::set_freeze(asset.clone(), &reason, who, locked_now)?;
+			Self::deposit_event(Event::<T, I>::VestingUpdated {
+				asset,
+				account: who.clone(),
+				unvested: locked_now,
+			});
+		}
+		Ok(())
+	}
+}
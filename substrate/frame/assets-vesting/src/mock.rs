@@ -0,0 +1,96 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests mock for `pallet-assets-vesting`.
+
+use crate as pallet_assets_vesting;
+pub use crate::*;
+use frame::{deps::sp_runtime::traits::Identity, testing_prelude::*};
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+/// The asset vested in the tests.
+pub const ASSET: AssetId = 0;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		AssetsFreezer: pallet_assets_freezer,
+		AssetsVesting: pallet_assets_vesting,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type Freezer = AssetsFreezer;
+}
+
+impl pallet_assets_freezer::Config for Test {
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeEvent = RuntimeEvent;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Assets = Assets;
+	type Freezer = AssetsFreezer;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type BlockNumberToBalance = Identity;
+	type BlockNumberProvider = System;
+	type MaxVestingSchedules = ConstU32<3>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+pub fn new_test_ext() -> TestState {
+	let t = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: Default::default(),
+		assets: pallet_assets::GenesisConfig {
+			assets: vec![(ASSET, 0, true, 10)],
+			metadata: vec![],
+			accounts: vec![(ASSET, 1, 1_000), (ASSET, 2, 100)],
+			next_asset_id: None,
+		},
+	}
+	.build_storage()
+	.unwrap();
+	let mut ext = TestState::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,158 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for `pallet-assets-vesting`.
+
+use crate::mock::*;
+use frame::testing_prelude::*;
+
+fn frozen(who: AccountId) -> Option<u64> {
+	pallet_assets_freezer::FrozenBalances::<Test>::get(ASSET, who)
+}
+
+#[test]
+fn vested_transfer_freezes_unvested_balance() {
+	new_test_ext().execute_with(|| {
+		// 100 units, unlocking 10 per block from block 1.
+		let schedule = VestingInfo::new(100, 10, 1);
+		assert_ok!(AssetsVesting::vested_transfer(RuntimeOrigin::signed(1), ASSET, 3, schedule));
+		System::assert_has_event(
+			Event::VestingCreated { asset: ASSET, account: 3, schedule_index: 0 }.into(),
+		);
+		System::assert_last_event(
+			Event::VestingUpdated { asset: ASSET, account: 3, unvested: 100 }.into(),
+		);
+		assert_eq!(Assets::balance(ASSET, 3), 100);
+		assert_eq!(frozen(3), Some(100));
+		assert_eq!(AssetsVesting::vesting_balance(ASSET, &3), Some(100));
+		assert!(Assets::transfer(RuntimeOrigin::signed(3), ASSET, 4, 10).is_err());
+
+		System::set_block_number(6);
+		assert_eq!(AssetsVesting::vesting_balance(ASSET, &3), Some(50));
+		// The freeze is only reduced once the account vests.
+		assert_eq!(frozen(3), Some(100));
+		assert_ok!(AssetsVesting::vest(RuntimeOrigin::signed(3), ASSET));
+		assert_eq!(frozen(3), Some(50));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), ASSET, 4, 50));
+
+		System::set_block_number(11);
+		assert_ok!(AssetsVesting::vest_other(RuntimeOrigin::signed(4), ASSET, 3));
+		System::assert_last_event(Event::VestingCompleted { asset: ASSET, account: 3 }.into());
+		assert_eq!(frozen(3), None);
+		assert_eq!(AssetsVesting::vesting(ASSET, 3), None);
+		assert_noop!(
+			AssetsVesting::vest(RuntimeOrigin::signed(3), ASSET),
+			Error::<Test>::NotVesting
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_checks_schedule() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			AssetsVesting::vested_transfer(
+				RuntimeOrigin::signed(1),
+				ASSET,
+				3,
+				VestingInfo::new(100, 0, 1)
+			),
+			Error::<Test>::InvalidScheduleParams
+		);
+		// The asset has a minimum balance of 10.
+		assert_noop!(
+			AssetsVesting::vested_transfer(
+				RuntimeOrigin::signed(1),
+				ASSET,
+				3,
+				VestingInfo::new(5, 1, 1)
+			),
+			Error::<Test>::AmountLow
+		);
+
+		for _ in 0..3 {
+			assert_ok!(AssetsVesting::vested_transfer(
+				RuntimeOrigin::signed(1),
+				ASSET,
+				3,
+				VestingInfo::new(20, 1, 10)
+			));
+		}
+		assert_eq!(frozen(3), Some(60));
+		assert_noop!(
+			AssetsVesting::vested_transfer(
+				RuntimeOrigin::signed(1),
+				ASSET,
+				3,
+				VestingInfo::new(20, 1, 10)
+			),
+			Error::<Test>::AtMaxVestingSchedules
+		);
+	});
+}
+
+#[test]
+fn force_vested_transfer_and_removal_work() {
+	new_test_ext().execute_with(|| {
+		let schedule = VestingInfo::new(50, 5, 10);
+		assert_noop!(
+			AssetsVesting::force_vested_transfer(RuntimeOrigin::signed(1), ASSET, 2, 3, schedule),
+			BadOrigin
+		);
+		assert_ok!(AssetsVesting::force_vested_transfer(
+			RuntimeOrigin::root(),
+			ASSET,
+			2,
+			3,
+			schedule
+		));
+		assert_ok!(AssetsVesting::vested_transfer(
+			RuntimeOrigin::signed(1),
+			ASSET,
+			3,
+			VestingInfo::new(30, 3, 10)
+		));
+		assert_eq!(Assets::balance(ASSET, 2), 50);
+		assert_eq!(frozen(3), Some(80));
+
+		assert_noop!(
+			AssetsVesting::force_remove_vesting_schedule(RuntimeOrigin::root(), ASSET, 3, 2),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+		assert_ok!(AssetsVesting::force_remove_vesting_schedule(
+			RuntimeOrigin::root(),
+			ASSET,
+			3,
+			0
+		));
+		assert_eq!(frozen(3), Some(30));
+		assert_eq!(
+			AssetsVesting::vesting(ASSET, 3).unwrap().into_inner(),
+			vec![VestingInfo::new(30, 3, 10)]
+		);
+
+		assert_ok!(AssetsVesting::force_remove_vesting_schedule(
+			RuntimeOrigin::root(),
+			ASSET,
+			3,
+			0
+		));
+		assert_eq!(frozen(3), None);
+		assert_eq!(AssetsVesting::vesting(ASSET, 3), None);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(3), ASSET, 4, 80));
+	});
+}
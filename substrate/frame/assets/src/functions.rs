@@ -1103,3 +1103,88 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 }
+
+#[cfg(any(test, feature = "try-runtime"))]
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Ensure the correctness of the state of this pallet.
+	///
+	/// For every asset:
+	/// * `supply` equals the sum of the balances and holds of its accounts, unless the asset is
+	///   being destroyed,
+	/// * `accounts`, `sufficients` and `approvals` equal the number of the respective entries.
+	///
+	/// Accounts, approvals and metadata must belong to an existing asset. The reserved balance of
+	/// every depositor must cover the asset, metadata, approval and account deposits placed by it
+	/// in this instance.
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		use alloc::collections::btree_map::BTreeMap;
+
+		let mut deposits = BTreeMap::<T::AccountId, DepositBalanceOf<T, I>>::new();
+		let mut add_deposit = |who: &T::AccountId, deposit: DepositBalanceOf<T, I>| {
+			if !deposit.is_zero() {
+				let total = deposits.entry(who.clone()).or_default();
+				*total = total.saturating_add(deposit);
+			}
+		};
+
+		for (id, details) in Asset::<T, I>::iter() {
+			add_deposit(&details.owner, details.deposit);
+
+			let mut supply = T::Balance::zero();
+			let mut accounts = 0u32;
+			let mut sufficients = 0u32;
+			for (who, account) in Account::<T, I>::iter_prefix(&id) {
+				let held = T::Holder::balance_on_hold(id.clone(), &who).unwrap_or_default();
+				supply = supply
+					.checked_add(&account.balance)
+					.and_then(|supply| supply.checked_add(&held))
+					.ok_or("Sum of the asset balances overflows")?;
+				accounts.saturating_inc();
+				match account.reason {
+					ExistenceReason::Sufficient => sufficients.saturating_inc(),
+					ExistenceReason::DepositHeld(deposit) => add_deposit(&who, deposit),
+					ExistenceReason::DepositFrom(ref depositor, deposit) =>
+						add_deposit(depositor, deposit),
+					ExistenceReason::Consumer | ExistenceReason::DepositRefunded => {},
+				}
+			}
+			// Destroying an asset removes its accounts without decreasing the supply.
+			if details.status != AssetStatus::Destroying {
+				ensure!(details.supply == supply, "Asset supply does not match the balances");
+			}
+			ensure!(details.accounts == accounts, "Asset accounts count mismatch");
+			ensure!(details.sufficients == sufficients, "Asset sufficients count mismatch");
+
+			let mut approvals = 0u32;
+			for ((owner, _), approval) in Approvals::<T, I>::iter_prefix((id.clone(),)) {
+				add_deposit(&owner, approval.deposit);
+				approvals.saturating_inc();
+			}
+			ensure!(details.approvals == approvals, "Asset approvals count mismatch");
+
+			if let Ok(metadata) = Metadata::<T, I>::try_get(&id) {
+				add_deposit(&details.owner, metadata.deposit);
+			}
+		}
+
+		for id in Account::<T, I>::iter_keys().map(|(id, _)| id) {
+			ensure!(Asset::<T, I>::contains_key(&id), "Account of an unknown asset");
+		}
+		for (id, _, _) in Approvals::<T, I>::iter_keys() {
+			ensure!(Asset::<T, I>::contains_key(&id), "Approval of an unknown asset");
+		}
+		for id in Metadata::<T, I>::iter_keys() {
+			ensure!(Asset::<T, I>::contains_key(&id), "Metadata of an unknown asset");
+		}
+
+		// Other pallets and instances may reserve funds too, so the deposits are a lower bound.
+		for (who, deposit) in deposits {
+			ensure!(
+				T::Currency::reserved_balance(&who) >= deposit,
+				"Deposits exceed the reserved balance of the depositor"
+			);
+		}
+
+		Ok(())
+	}
+}
@@ -703,6 +703,46 @@ pub mod pallet {
 		ContainsHolds,
 	}
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The details of the asset `id`, if it exists.
+		pub fn asset_details(
+			id: T::AssetId,
+		) -> Option<AssetDetails<T::Balance, T::AccountId, DepositBalanceOf<T, I>>> {
+			Asset::<T, I>::get(id)
+		}
+
+		/// The metadata of the asset `id`, if any was set.
+		pub fn asset_metadata(
+			id: T::AssetId,
+		) -> Option<AssetMetadata<DepositBalanceOf<T, I>, BoundedVec<u8, T::StringLimit>>> {
+			Metadata::<T, I>::try_get(id).ok()
+		}
+
+		/// The balance of `who` in the asset `id`, if `who` holds an account of it.
+		pub fn asset_balance(id: T::AssetId, who: T::AccountId) -> Option<T::Balance> {
+			Account::<T, I>::get(id, who).map(|account| account.balance)
+		}
+
+		/// The amount of the asset `id` that `delegate` may transfer on behalf of `owner`, if
+		/// approved.
+		pub fn asset_approval(
+			id: T::AssetId,
+			owner: T::AccountId,
+			delegate: T::AccountId,
+		) -> Option<T::Balance> {
+			Approvals::<T, I>::get((id, owner, delegate)).map(|approval| approval.amount)
+		}
+	}
+
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Issue a new class of fungible assets from a public origin.
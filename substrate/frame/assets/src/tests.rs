@@ -341,6 +341,27 @@ fn approval_lifecycle_works() {
 	});
 }
 
+#[test]
+fn view_functions_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Assets::asset_details(0), None);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_eq!(Assets::asset_details(0), Asset::<Test>::get(0));
+		assert_eq!(Assets::asset_balance(0, 1), Some(100));
+		assert_eq!(Assets::asset_balance(0, 2), None);
+
+		assert_eq!(Assets::asset_metadata(0), None);
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::set_metadata(RuntimeOrigin::signed(1), 0, vec![0], vec![1], 12));
+		assert_eq!(Assets::asset_metadata(0).map(|m| m.decimals), Some(12));
+
+		assert_eq!(Assets::asset_approval(0, 1, 2), None);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_eq!(Assets::asset_approval(0, 1, 2), Some(50));
+	});
+}
+
 #[test]
 fn transfer_approved_all_funds() {
 	new_test_ext().execute_with(|| {
@@ -2180,3 +2201,78 @@ fn asset_id_cannot_be_reused() {
 		assert!(Asset::<Test>::contains_key(7));
 	});
 }
+
+#[test]
+fn try_state_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&4, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![0u8; 4],
+			vec![0u8; 4],
+			12
+		));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 30));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::touch(RuntimeOrigin::signed(4), 0));
+		assert_ok!(Assets::touch_other(RuntimeOrigin::signed(1), 0, 3));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 2, 10));
+		set_balance_on_hold(0, 2, 10);
+		Account::<Test>::mutate(0, 2, |account| account.as_mut().unwrap().balance -= 10);
+		assert_ok!(Assets::do_try_state());
+
+		// destroying the asset removes its accounts, but keeps the supply.
+		assert_ok!(Assets::start_destroy(RuntimeOrigin::root(), 999));
+		assert_ok!(Assets::destroy_accounts(RuntimeOrigin::signed(1), 999));
+		assert_ok!(Assets::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_detects_corruption() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Assets::create(RuntimeOrigin::signed(1), 0, 1, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_ok!(Assets::do_try_state());
+
+		let corrupt = |f: fn(), error: &'static str| {
+			// revert the corruption, so that the next one is checked in isolation.
+			assert_ok!(frame_support::storage::with_transaction(|| {
+				f();
+				assert_eq!(Assets::do_try_state(), Err(error.into()));
+				frame_support::storage::TransactionOutcome::Rollback(Ok::<_, DispatchError>(()))
+			}));
+		};
+
+		corrupt(
+			|| Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().supply += 1),
+			"Asset supply does not match the balances",
+		);
+		corrupt(
+			|| Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().accounts += 1),
+			"Asset accounts count mismatch",
+		);
+		corrupt(
+			|| Asset::<Test>::mutate(0, |d| d.as_mut().unwrap().approvals = 0),
+			"Asset approvals count mismatch",
+		);
+		corrupt(
+			|| Metadata::<Test>::insert(1, AssetMetadata::default()),
+			"Metadata of an unknown asset",
+		);
+		corrupt(
+			|| {
+				Balances::unreserve(&1, 1);
+			},
+			"Deposits exceed the reserved balance of the depositor",
+		);
+		assert_ok!(Assets::do_try_state());
+	});
+}
@@ -15,7 +15,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Tests for [`ItemOf`], [`fungible::UnionOf`] and [`fungibles::UnionOf`] set types.
+//! Tests for [`ItemOf`], [`fungible::UnionOf`], [`fungibles::UnionOf`] and their N-ary variants
+//! set types.
 
 use super::*;
 use frame_support::{
@@ -33,7 +34,10 @@ use frame_support::{
 		},
 	},
 };
-use sp_runtime::{traits::ConvertToValue, Either};
+use sp_runtime::{
+	traits::{Convert, ConvertToValue},
+	Either,
+};
 
 const FIRST_ASSET: u32 = 0;
 const UNKNOWN_ASSET: u32 = 10;
@@ -73,6 +77,52 @@ type LeftFungibles<T> = fungibles::UnionOf<RightFungible<T>, T, ConvertToValue<L
 /// implementation provided by the pallet.
 type First<T> = fungibles::UnionOf<T, LeftFungibles<T>, ConvertToValue<RightAsset>, (), u64>;
 
+/// Criterion routing the asset ids `0..10`, `10..20` and `20..` to the first, second and third
+/// set of a three set union respectively, each set starting from the asset id `0`.
+pub struct ByTens;
+impl Convert<u32, fungibles::OneOf3<u32, u32, u32>> for ByTens {
+	fn convert(id: u32) -> fungibles::OneOf3<u32, u32, u32> {
+		match id {
+			0..=9 => fungibles::OneOf3::First(id),
+			10..=19 => fungibles::OneOf3::Second(id - 10),
+			_ => fungibles::OneOf3::Third(id - 20),
+		}
+	}
+}
+
+/// Implementation of `fungibles` traits using [`fungibles::UnionOf3`] over three copies of `T`.
+type Tens<T> = fungibles::UnionOf3<T, T, T, ByTens, u32, u64>;
+
+#[test]
+fn n_ary_union_routes_by_criterion() {
+	new_test_ext().execute_with(|| {
+		let account1 = 1;
+		let account2 = 2;
+
+		for asset in 0..3 {
+			assert_ok!(<Assets as FungiblesCreate<u64>>::create(asset, account1, true, 1));
+		}
+
+		assert_ok!(Tens::<Assets>::mint_into(1, &account1, 100));
+		assert_ok!(Tens::<Assets>::mint_into(12, &account1, 200));
+		assert_ok!(Tens::<Assets>::mint_into(20, &account1, 300));
+
+		assert_eq!(Assets::balance(1, &account1), 100);
+		assert_eq!(Assets::balance(2, &account1), 200);
+		assert_eq!(Assets::balance(0, &account1), 300);
+		assert_eq!(Tens::<Assets>::total_issuance(12), Assets::total_issuance(2));
+		assert_eq!(Tens::<Assets>::balance(3, &account1), 0);
+		assert!(Tens::<Assets>::asset_exists(22));
+		assert!(!Tens::<Assets>::asset_exists(23));
+
+		assert_ok!(Tens::<Assets>::transfer(22, &account1, &account2, 50, Preservation::Preserve));
+		assert_eq!(Assets::balance(2, &account2), 50);
+		assert_eq!(Tens::<Assets>::balance(12, &account2), 50);
+		assert_eq!(Tens::<Assets>::balance(2, &account2), 50);
+		assert_eq!(Tens::<Assets>::balance(2, &account1), 150);
+	});
+}
+
 #[test]
 fn deposit_from_set_types_works() {
 	new_test_ext().execute_with(|| {
@@ -0,0 +1,82 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks for the Aura Pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use alloc::vec;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+use sp_consensus_aura::digests::CompatibleDigestItem;
+use sp_runtime::{traits::Header, Digest};
+
+/// A header of a block in `slot` on top of the genesis block, sealed by `offender`.
+fn sealed_header<T: Config>(
+	offender: &T::AuthorityId,
+	slot: Slot,
+	state_root: T::Hash,
+) -> HeaderFor<T> {
+	let pre_digest = <DigestItem as CompatibleDigestItem<
+		<T::AuthorityId as RuntimeAppPublic>::Signature,
+	>>::aura_pre_digest(slot);
+	let mut header = HeaderFor::<T>::new(
+		1u32.into(),
+		Default::default(),
+		state_root,
+		frame_system::Pallet::<T>::parent_hash(),
+		Digest { logs: vec![pre_digest] },
+	);
+	let signature = offender.sign(&header.hash()).expect("the key was generated; qed");
+	header.digest_mut().push(DigestItem::aura_seal(signature));
+	header
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn report_equivocation(x: Linear<1, { T::MaxAuthorities::get() }>) {
+		// The offender is the last authority, so that it is found last.
+		let authorities = (0..x).map(|_| T::AuthorityId::generate_pair(None)).collect::<Vec<_>>();
+		let offender = authorities.last().cloned().expect("there is at least one authority; qed");
+		Authorities::<T>::put(BoundedVec::truncate_from(authorities));
+
+		let slot = Slot::from(1);
+		CurrentSlot::<T>::put(slot);
+		T::BenchmarkHelper::setup_offender(&offender);
+
+		let equivocation_proof = EquivocationProof {
+			offender: offender.clone(),
+			slot,
+			first_header: sealed_header::<T>(&offender, slot, T::Hash::default()),
+			second_header: sealed_header::<T>(
+				&offender,
+				slot,
+				<T::Hashing as sp_runtime::traits::Hash>::hash(&[1]),
+			),
+		};
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), Box::new(equivocation_proof));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_bench_ext(), crate::mock::Test);
+}
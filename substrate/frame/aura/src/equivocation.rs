@@ -0,0 +1,159 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An opt-in utility module for reporting equivocations.
+//!
+//! This module defines an offence type for Aura equivocations, i.e. an authority authoring two
+//! different blocks on the same parent in the same slot, and a system for reporting these offences
+//! which can be used as [`Config::EquivocationReportSystem`]. Consecutive blocks in the same slot
+//! are not equivocations, since they are allowed with `AllowMultipleBlocksPerSlot`.
+//!
+//! Unlike BABE and GRANDPA, Aura is mostly used by parachains, which usually don't keep the
+//! historical sessions around to prove the key ownership of past sessions. The offender is
+//! therefore looked up among the keys currently registered in `pallet_session` and only
+//! equivocations of the current authorities in the current session can be reported. The reports are submitted as signed
+//! transactions through [`Pallet::report_equivocation`](crate::Pallet::report_equivocation).
+
+use alloc::{vec, vec::Vec};
+use frame_support::traits::ConstU64;
+use frame_system::pallet_prelude::HeaderFor;
+use sp_consensus_aura::{EquivocationProof, Slot};
+use sp_runtime::{
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	DispatchError, Perbill, RuntimeAppPublic,
+};
+use sp_staking::{
+	offence::{Kind, Offence, OffenceReportSystem, ReportOffence},
+	SessionIndex,
+};
+
+use crate::{Config, Error, Pallet};
+
+/// Aura equivocation offence report.
+///
+/// When an authority released two or more blocks at the same slot.
+pub struct EquivocationOffence<Offender> {
+	/// An aura slot in which this incident happened.
+	pub slot: Slot,
+	/// The session index in which the incident happened.
+	pub session_index: SessionIndex,
+	/// The size of the authority set at the time of the offence.
+	pub validator_set_count: u32,
+	/// The authority that produced the equivocation.
+	pub offender: Offender,
+}
+
+impl<Offender: Clone> Offence<Offender> for EquivocationOffence<Offender> {
+	const ID: Kind = *b"aura:equivocatio";
+	type TimeSlot = Slot;
+
+	fn offenders(&self) -> Vec<Offender> {
+		vec![self.offender.clone()]
+	}
+
+	fn session_index(&self) -> SessionIndex {
+		self.session_index
+	}
+
+	fn validator_set_count(&self) -> u32 {
+		self.validator_set_count
+	}
+
+	fn time_slot(&self) -> Self::TimeSlot {
+		self.slot
+	}
+
+	// The formula is min((3k / n)^2, 1)
+	// where k = offenders_number and n = validators_number
+	fn slash_fraction(&self, offenders_count: u32) -> Perbill {
+		// Perbill type domain is [0, 1] by definition
+		Perbill::from_rational(3 * offenders_count, self.validator_set_count).square()
+	}
+}
+
+/// Aura equivocation offence report system.
+///
+/// This type implements `OffenceReportSystem` such that:
+/// - Equivocation reports are only accepted as signed extrinsics, publishing evidence as an
+///   unsigned extrinsic is not supported.
+/// - The offender is the validator which registered the equivocating authority as its session key
+///   in `pallet_session`.
+/// - The offence is reported to the user provided generic type implementing `ReportOffence`, e.g.
+///   `pallet_collator_selection`.
+///
+/// The equivocation proof itself is verified by
+/// [`Pallet::report_equivocation`](crate::Pallet::report_equivocation) before it is processed.
+pub struct EquivocationReportSystem<T, R>(core::marker::PhantomData<(T, R)>);
+
+impl<T, R>
+	OffenceReportSystem<Option<T::AccountId>, EquivocationProof<HeaderFor<T>, T::AuthorityId>>
+	for EquivocationReportSystem<T, R>
+where
+	T: Config + pallet_session::Config,
+	R: ReportOffence<T::AccountId, T::ValidatorId, EquivocationOffence<T::ValidatorId>>,
+{
+	type Longevity = ConstU64<0>;
+
+	fn publish_evidence(
+		_evidence: EquivocationProof<HeaderFor<T>, T::AuthorityId>,
+	) -> Result<(), ()> {
+		Err(())
+	}
+
+	fn check_evidence(
+		evidence: EquivocationProof<HeaderFor<T>, T::AuthorityId>,
+	) -> Result<(), TransactionValidityError> {
+		let offender = Self::key_owner(&evidence.offender).ok_or(InvalidTransaction::BadProof)?;
+
+		// Check if the offence has already been reported, and if so then we can discard the report.
+		if R::is_known_offence(&[offender], &evidence.slot) {
+			Err(InvalidTransaction::Stale.into())
+		} else {
+			Ok(())
+		}
+	}
+
+	fn process_evidence(
+		reporter: Option<T::AccountId>,
+		evidence: EquivocationProof<HeaderFor<T>, T::AuthorityId>,
+	) -> Result<(), DispatchError> {
+		let offender =
+			Self::key_owner(&evidence.offender).ok_or(Error::<T>::InvalidKeyOwnershipProof)?;
+
+		let offence = EquivocationOffence {
+			slot: evidence.slot,
+			session_index: pallet_session::Pallet::<T>::current_index(),
+			validator_set_count: Pallet::<T>::authorities_len() as u32,
+			offender,
+		};
+
+		R::report_offence(reporter.into_iter().collect(), offence)
+			.map_err(|_| Error::<T>::DuplicateOffenceReport)?;
+
+		Ok(())
+	}
+}
+
+impl<T: Config + pallet_session::Config, R> EquivocationReportSystem<T, R> {
+	/// The validator which registered `authority` as its session key.
+	fn key_owner(authority: &T::AuthorityId) -> Option<T::ValidatorId> {
+		pallet_session::Pallet::<T>::key_owner(
+			<T::AuthorityId as RuntimeAppPublic>::ID,
+			&authority.to_raw_vec(),
+		)
+	}
+}
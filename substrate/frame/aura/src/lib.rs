@@ -24,6 +24,10 @@
 //!
 //! The Aura module extends Aura consensus by managing offline reporting.
 //!
+//! Authorities which authored two different blocks in the same slot can be reported with
+//! [`Pallet::report_equivocation`]. See the [`EquivocationReportSystem`] for reporting these
+//! offences to e.g. `pallet_collator_selection`.
+//!
 //! ## Interface
 //!
 //! ### Public Functions
@@ -40,25 +44,33 @@
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	traits::{DisabledValidators, FindAuthor, Get, OnTimestampSet, OneSessionHandler},
 	BoundedSlice, BoundedVec, ConsensusEngineId, Parameter,
 };
+use frame_system::pallet_prelude::HeaderFor;
 use log;
-use sp_consensus_aura::{AuthorityIndex, ConsensusLog, Slot, AURA_ENGINE_ID};
+use sp_consensus_aura::{AuthorityIndex, ConsensusLog, EquivocationProof, Slot, AURA_ENGINE_ID};
 use sp_runtime::{
 	generic::DigestItem,
 	traits::{IsMember, Member, SaturatedConversion, Saturating, Zero},
 	RuntimeAppPublic,
 };
+use sp_staking::offence::OffenceReportSystem;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod equivocation;
 pub mod migrations;
 mod mock;
 mod tests;
+pub mod weights;
 
+pub use equivocation::{EquivocationOffence, EquivocationReportSystem};
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 const LOG_TARGET: &str = "runtime::aura";
 
@@ -118,6 +130,23 @@ pub mod pallet {
 		/// For backwards compatibility either use [`MinimumPeriodTimesTwo`] or a const.
 		#[pallet::constant]
 		type SlotDuration: Get<<Self as pallet_timestamp::Config>::Moment>;
+
+		/// The equivocation handling subsystem, defines methods to check/report an
+		/// offence.
+		///
+		/// Use `()` to disable equivocation reporting, or [`EquivocationReportSystem`] to report
+		/// the offences of the collators, e.g. to `pallet_collator_selection`.
+		type EquivocationReportSystem: OffenceReportSystem<
+			Option<Self::AccountId>,
+			EquivocationProof<HeaderFor<Self>, Self::AuthorityId>,
+		>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper to set up the benchmarks of the pallet.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AuthorityId>;
 	}
 
 	#[pallet::pallet]
@@ -162,6 +191,43 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::error]
+	pub enum Error<T> {
+		/// An equivocation proof provided as part of an equivocation report is invalid.
+		InvalidEquivocationProof,
+		/// The owner of the equivocating authority key could not be found.
+		InvalidKeyOwnershipProof,
+		/// A given equivocation report is valid but already previously reported.
+		DuplicateOffenceReport,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Report an authority which authored two different blocks in the same slot.
+		///
+		/// The equivocation proof is verified and the offence is reported through the
+		/// [`Config::EquivocationReportSystem`]. Only equivocations of the current authorities in
+		/// slots of the current session that already passed can be reported. Valid reports are
+		/// free of charge.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::report_equivocation(Pallet::<T>::authorities_len() as u32))]
+		pub fn report_equivocation(
+			origin: OriginFor<T>,
+			equivocation_proof: Box<EquivocationProof<HeaderFor<T>, T::AuthorityId>>,
+		) -> DispatchResultWithPostInfo {
+			let reporter = ensure_signed(origin)?;
+
+			ensure!(
+				Self::is_valid_equivocation_proof(&equivocation_proof),
+				Error::<T>::InvalidEquivocationProof
+			);
+			T::EquivocationReportSystem::process_evidence(Some(reporter), *equivocation_proof)?;
+
+			// Waive the fee since the report is valid and beneficial
+			Ok(Pays::No.into())
+		}
+	}
+
 	/// The current authority set.
 	#[pallet::storage]
 	pub type Authorities<T: Config> =
@@ -173,6 +239,13 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type CurrentSlot<T: Config> = StorageValue<_, Slot, ValueQuery>;
 
+	/// The first slot of the current session.
+	///
+	/// Only equivocations in this or later slots can be reported, so that the reports of past
+	/// sessions need not be kept around to reject duplicates.
+	#[pallet::storage]
+	pub type SessionStartSlot<T: Config> = StorageValue<_, Slot, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -229,6 +302,45 @@ impl<T: Config> Pallet<T> {
 		Authorities::<T>::decode_len().unwrap_or(0)
 	}
 
+	/// Predict the authorities of the `count` slots following the current slot.
+	///
+	/// Returns the slot together with the index of its author in the current authority set and
+	/// the author itself. Returns an empty list if there are no authorities.
+	pub fn authorship_schedule(count: u32) -> Vec<(Slot, AuthorityIndex, T::AuthorityId)> {
+		let authorities = Authorities::<T>::get();
+		if authorities.is_empty() {
+			return Vec::new()
+		}
+
+		let current_slot = CurrentSlot::<T>::get();
+		(1..=count as u64)
+			.map(|offset| {
+				let slot = current_slot.saturating_add(offset);
+				let index = *slot % authorities.len() as u64;
+				(slot, index as AuthorityIndex, authorities[index as usize].clone())
+			})
+			.collect()
+	}
+
+	/// Return the index of `authority` in the current authority set, if it is an authority.
+	pub fn authority_index_of(authority: &T::AuthorityId) -> Option<AuthorityIndex> {
+		Authorities::<T>::get()
+			.iter()
+			.position(|a| a == authority)
+			.map(|index| index as AuthorityIndex)
+	}
+
+	/// Whether `proof` proves that one of the current authorities authored two different blocks on
+	/// the same parent in a slot of the current session that already passed.
+	fn is_valid_equivocation_proof(
+		proof: &EquivocationProof<HeaderFor<T>, T::AuthorityId>,
+	) -> bool {
+		proof.slot >= SessionStartSlot::<T>::get() &&
+			proof.slot <= CurrentSlot::<T>::get() &&
+			Self::authority_index_of(&proof.offender).is_some() &&
+			sp_consensus_aura::check_equivocation_proof(proof.clone())
+	}
+
 	/// Get the current slot from the pre-runtime digests.
 	fn current_slot_from_digests() -> Option<Slot> {
 		let digest = frame_system::Pallet::<T>::digest();
@@ -297,6 +409,20 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+/// Helper to set up the benchmarks of the pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AuthorityId> {
+	/// Make `authority` an offender which can be reported to the
+	/// [`Config::EquivocationReportSystem`], e.g. by registering it as the session key of a
+	/// collator candidate.
+	fn setup_offender(authority: &AuthorityId);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AuthorityId> BenchmarkHelper<AuthorityId> for () {
+	fn setup_offender(_authority: &AuthorityId) {}
+}
+
 impl<T: Config> sp_runtime::BoundToRuntimeAppPublic for Pallet<T> {
 	type Public = T::AuthorityId;
 }
@@ -316,6 +442,10 @@ impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
 	where
 		I: Iterator<Item = (&'a T::AccountId, T::AuthorityId)>,
 	{
+		// The slot of this block may still be the last slot of the previous session, if the slot
+		// was not updated yet.
+		SessionStartSlot::<T>::put(CurrentSlot::<T>::get().saturating_add(1u64));
+
 		// instant changes
 		if changed {
 			let next_authorities = validators.map(|(_, k)| k).collect::<Vec<_>>();
@@ -389,6 +519,21 @@ impl<T: Config> IsMember<T::AuthorityId> for Pallet<T> {
 	}
 }
 
+/// The start of the current slot, as the moment expected by the timestamp pallet.
+///
+/// Before the first slot is known, no moment is expected.
+pub struct SlotStartMoment<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> pallet_timestamp::ExpectedMoment<T::Moment> for SlotStartMoment<T> {
+	fn expected_moment() -> Option<T::Moment> {
+		let slot = *CurrentSlot::<T>::get();
+		if slot == 0 {
+			return None
+		}
+		Some(T::Moment::saturated_from(slot).saturating_mul(Pallet::<T>::slot_duration()))
+	}
+}
+
 impl<T: Config> OnTimestampSet<T::Moment> for Pallet<T> {
 	fn on_timestamp_set(moment: T::Moment) {
 		let slot_duration = Self::slot_duration();
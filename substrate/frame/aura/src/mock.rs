@@ -24,8 +24,13 @@ use frame_support::{
 	derive_impl, parameter_types,
 	traits::{ConstU32, ConstU64, DisabledValidators},
 };
-use sp_consensus_aura::{ed25519::AuthorityId, AuthorityIndex};
-use sp_runtime::{testing::UintAuthorityId, BuildStorage};
+use frame_system::pallet_prelude::HeaderFor;
+use sp_consensus_aura::{ed25519::AuthorityId, AuthorityIndex, EquivocationProof, Slot};
+use sp_runtime::{
+	testing::UintAuthorityId, transaction_validity::TransactionValidityError, BuildStorage,
+	DispatchError,
+};
+use sp_staking::offence::OffenceReportSystem;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -49,12 +54,15 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
+	type ExpectedMoment = pallet_aura::SlotStartMoment<Self>;
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = ();
 }
 
 parameter_types! {
 	static DisabledValidatorTestValue: Vec<AuthorityIndex> = Default::default();
 	pub static AllowMultipleBlocksPerSlot: bool = false;
+	pub static ReportedEquivocations: Vec<(Option<u64>, Slot)> = Vec::new();
 }
 
 pub struct MockDisabledValidators;
@@ -79,12 +87,45 @@ impl DisabledValidators for MockDisabledValidators {
 	}
 }
 
+/// Records the processed equivocation reports in [`ReportedEquivocations`].
+pub struct MockEquivocationReportSystem;
+
+impl OffenceReportSystem<Option<u64>, EquivocationProof<HeaderFor<Test>, AuthorityId>>
+	for MockEquivocationReportSystem
+{
+	type Longevity = ();
+
+	fn publish_evidence(
+		_evidence: EquivocationProof<HeaderFor<Test>, AuthorityId>,
+	) -> Result<(), ()> {
+		Err(())
+	}
+
+	fn check_evidence(
+		_evidence: EquivocationProof<HeaderFor<Test>, AuthorityId>,
+	) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn process_evidence(
+		reporter: Option<u64>,
+		evidence: EquivocationProof<HeaderFor<Test>, AuthorityId>,
+	) -> Result<(), DispatchError> {
+		ReportedEquivocations::mutate(|r| r.push((reporter, evidence.slot)));
+		Ok(())
+	}
+}
+
 impl pallet_aura::Config for Test {
 	type AuthorityId = AuthorityId;
 	type DisabledValidators = MockDisabledValidators;
 	type MaxAuthorities = ConstU32<10>;
 	type AllowMultipleBlocksPerSlot = AllowMultipleBlocksPerSlot;
 	type SlotDuration = ConstU64<SLOT_DURATION>;
+	type EquivocationReportSystem = MockEquivocationReportSystem;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
 }
 
 fn build_ext(authorities: Vec<u64>) -> sp_io::TestExternalities {
@@ -104,3 +145,12 @@ pub fn build_ext_and_execute_test(authorities: Vec<u64>, test: impl FnOnce() ->
 		Aura::do_try_state().expect("Storage invariants should hold")
 	});
 }
+
+#[cfg(feature = "runtime-benchmarks")]
+pub fn new_bench_ext() -> sp_io::TestExternalities {
+	use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+
+	let mut ext = build_ext(vec![0, 1, 2, 3]);
+	ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+	ext
+}
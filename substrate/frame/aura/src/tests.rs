@@ -20,11 +20,26 @@
 #![cfg(test)]
 
 use super::pallet;
-use crate::mock::{build_ext_and_execute_test, Aura, MockDisabledValidators, System, Test};
+use crate::mock::{
+	build_ext_and_execute_test, Aura, MockDisabledValidators, ReportedEquivocations, RuntimeOrigin,
+	System, Test, Timestamp,
+};
 use codec::Encode;
-use frame_support::traits::OnInitialize;
-use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
-use sp_runtime::{Digest, DigestItem};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::Pays,
+	traits::{OnInitialize, OneSessionHandler},
+	BoundedVec,
+};
+use frame_system::pallet_prelude::HeaderFor;
+use pallet_timestamp::ExpectedMoment;
+use sp_consensus_aura::{
+	digests::CompatibleDigestItem,
+	ed25519::{AuthorityId, AuthorityPair, AuthoritySignature},
+	EquivocationProof, Slot, AURA_ENGINE_ID,
+};
+use sp_core::{Pair, H256};
+use sp_runtime::{testing::UintAuthorityId, traits::Header, Digest, DigestItem};
 
 #[test]
 fn initial_values() {
@@ -117,3 +132,191 @@ fn pallet_always_rejects_decreasing_slot() {
 		Aura::on_initialize(43);
 	});
 }
+
+#[test]
+fn authorship_schedule_works() {
+	build_ext_and_execute_test(vec![0, 1, 2, 3], || {
+		pallet::CurrentSlot::<Test>::put(Slot::from(6));
+
+		let schedule = Aura::authorship_schedule(5);
+		let slots = schedule.iter().map(|(slot, _, _)| u64::from(*slot)).collect::<Vec<_>>();
+		let indices = schedule.iter().map(|(_, index, _)| *index).collect::<Vec<_>>();
+		assert_eq!(slots, vec![7, 8, 9, 10, 11]);
+		assert_eq!(indices, vec![3, 0, 1, 2, 3]);
+		assert!(schedule.iter().all(
+			|(_, index, author)| *author == pallet::Authorities::<Test>::get()[*index as usize]
+		));
+
+		assert!(Aura::authorship_schedule(0).is_empty());
+	});
+}
+
+#[test]
+fn authority_index_of_works() {
+	build_ext_and_execute_test(vec![0, 1, 2, 3], || {
+		let authorities = pallet::Authorities::<Test>::get();
+		assert_eq!(Aura::authority_index_of(&authorities[0]), Some(0));
+		assert_eq!(Aura::authority_index_of(&authorities[3]), Some(3));
+		assert_eq!(Aura::authority_index_of(&UintAuthorityId(42).to_public_key()), None);
+	});
+}
+
+#[test]
+fn slot_start_moment_works() {
+	build_ext_and_execute_test(vec![0, 1, 2, 3], || {
+		type Expected = crate::SlotStartMoment<Test>;
+		assert_eq!(Expected::expected_moment(), None);
+
+		let slot = Slot::from(3);
+		let pre_digest =
+			Digest { logs: vec![DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode())] };
+		System::initialize(&42, &System::parent_hash(), &pre_digest);
+		Aura::on_initialize(42);
+		assert_eq!(Expected::expected_moment(), Some(6));
+
+		// A timestamp later in the slot deviates from its start.
+		assert_ok!(Timestamp::set(RuntimeOrigin::none(), 7));
+		System::assert_last_event(
+			pallet_timestamp::Event::TimestampAnomaly { now: 7, expected: 6, delta: 1 }.into(),
+		);
+	});
+}
+
+fn authority_pair(seed: u8) -> AuthorityPair {
+	AuthorityPair::from_seed(&[seed; 32])
+}
+
+/// A header of block `number` on top of `parent_hash` in `slot`, sealed by `pair`.
+///
+/// Headers with different `state_root`s are different blocks.
+fn sealed_header(
+	pair: &AuthorityPair,
+	slot: u64,
+	number: u64,
+	parent_hash: H256,
+	state_root: H256,
+) -> HeaderFor<Test> {
+	let pre_digest =
+		<DigestItem as CompatibleDigestItem<AuthoritySignature>>::aura_pre_digest(slot.into());
+	let mut header = HeaderFor::<Test>::new(
+		number,
+		Default::default(),
+		state_root,
+		parent_hash,
+		Digest { logs: vec![pre_digest] },
+	);
+	let signature = pair.sign(header.hash().as_ref());
+	header.digest_mut().push(DigestItem::aura_seal(signature));
+	header
+}
+
+/// Two different blocks authored by `pair` in `slot` on the same parent.
+fn equivocation_proof(
+	pair: &AuthorityPair,
+	slot: u64,
+) -> Box<EquivocationProof<HeaderFor<Test>, AuthorityId>> {
+	let parent_hash = H256::repeat_byte(1);
+	Box::new(EquivocationProof {
+		offender: pair.public(),
+		slot: slot.into(),
+		first_header: sealed_header(pair, slot, 1, parent_hash, H256::repeat_byte(1)),
+		second_header: sealed_header(pair, slot, 1, parent_hash, H256::repeat_byte(2)),
+	})
+}
+
+fn set_signing_authorities(pairs: &[AuthorityPair]) {
+	let authorities = pairs.iter().map(|pair| pair.public()).collect::<Vec<_>>();
+	pallet::Authorities::<Test>::put(BoundedVec::truncate_from(authorities));
+}
+
+#[test]
+fn report_equivocation_works() {
+	build_ext_and_execute_test(vec![0, 1, 2, 3], || {
+		let pairs = (0..4).map(authority_pair).collect::<Vec<_>>();
+		set_signing_authorities(&pairs);
+		pallet::CurrentSlot::<Test>::put(Slot::from(10));
+
+		let post_info =
+			Aura::report_equivocation(RuntimeOrigin::signed(5), equivocation_proof(&pairs[1], 7))
+				.unwrap();
+		assert_eq!(post_info.pays_fee, Pays::No);
+		assert_eq!(ReportedEquivocations::get(), vec![(Some(5), Slot::from(7))]);
+	});
+}
+
+#[test]
+fn report_equivocation_rejects_invalid_proofs() {
+	build_ext_and_execute_test(vec![0, 1, 2, 3], || {
+		let pairs = (0..4).map(authority_pair).collect::<Vec<_>>();
+		set_signing_authorities(&pairs);
+		pallet::CurrentSlot::<Test>::put(Slot::from(10));
+
+		let assert_invalid = |proof| {
+			assert_noop!(
+				Aura::report_equivocation(RuntimeOrigin::signed(5), proof),
+				pallet::Error::<Test>::InvalidEquivocationProof,
+			);
+		};
+
+		// The headers are the same.
+		let mut proof = equivocation_proof(&pairs[1], 7);
+		proof.second_header = proof.first_header.clone();
+		assert_invalid(proof);
+
+		// The headers are in a different slot than the proof.
+		let mut proof = equivocation_proof(&pairs[1], 7);
+		proof.slot = 8.into();
+		assert_invalid(proof);
+
+		// The headers are in different slots.
+		let mut proof = equivocation_proof(&pairs[1], 7);
+		proof.second_header =
+			sealed_header(&pairs[1], 8, 1, H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_invalid(proof);
+
+		// A header is sealed by another authority.
+		let mut proof = equivocation_proof(&pairs[1], 7);
+		proof.second_header =
+			sealed_header(&pairs[2], 7, 1, H256::repeat_byte(1), H256::repeat_byte(2));
+		assert_invalid(proof);
+
+		// The headers build on different parents.
+		let mut proof = equivocation_proof(&pairs[1], 7);
+		proof.second_header =
+			sealed_header(&pairs[1], 7, 1, H256::repeat_byte(2), H256::repeat_byte(2));
+		assert_invalid(proof);
+
+		// The headers are consecutive blocks in the same slot.
+		let mut proof = equivocation_proof(&pairs[1], 7);
+		proof.second_header =
+			sealed_header(&pairs[1], 7, 2, proof.first_header.hash(), H256::repeat_byte(2));
+		assert_invalid(proof);
+
+		// The slot is in a previous session.
+		pallet::SessionStartSlot::<Test>::put(Slot::from(8));
+		assert_invalid(equivocation_proof(&pairs[1], 7));
+		pallet::SessionStartSlot::<Test>::kill();
+
+		// The slot did not pass yet.
+		assert_invalid(equivocation_proof(&pairs[1], 11));
+
+		// The offender is not an authority.
+		assert_invalid(equivocation_proof(&authority_pair(42), 7));
+
+		assert!(ReportedEquivocations::get().is_empty());
+	});
+}
+
+#[test]
+fn new_session_sets_session_start_slot() {
+	build_ext_and_execute_test(vec![0, 1, 2, 3], || {
+		pallet::CurrentSlot::<Test>::put(Slot::from(10));
+
+		<Aura as OneSessionHandler<u64>>::on_new_session(
+			false,
+			core::iter::empty::<(&u64, AuthorityId)>(),
+			core::iter::empty(),
+		);
+		assert_eq!(pallet::SessionStartSlot::<Test>::get(), Slot::from(11));
+	});
+}
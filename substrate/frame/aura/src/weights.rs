@@ -0,0 +1,63 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_aura`
+//!
+//! THIS FILE WAS NOT AUTO-GENERATED. The values are placeholders until the benchmarks in
+//! `benchmarking.rs` are run with the benchmark CLI, which regenerates this file:
+//!
+//! `frame-omni-bencher v1 benchmark pallet --pallet=pallet_aura --extrinsic=*
+//! --template=substrate/.maintain/frame-weight-template.hbs`
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+#![allow(dead_code)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_aura`.
+pub trait WeightInfo {
+	fn report_equivocation(x: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_aura` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn report_equivocation(x: u32, ) -> Weight {
+		// The authorities and the session keys are read.
+		Weight::from_parts(220_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 16 * 1024))
+			.saturating_add(Weight::from_parts(20_000, 32).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn report_equivocation(x: u32, ) -> Weight {
+		// The authorities and the session keys are read.
+		Weight::from_parts(220_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 16 * 1024))
+			.saturating_add(Weight::from_parts(20_000, 32).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+}
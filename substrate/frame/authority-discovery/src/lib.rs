@@ -215,6 +215,8 @@ mod tests {
 		type WeightInfo = ();
 		type Currency = Balances;
 		type KeyDeposit = ();
+		type KeyTypeDeposits = ();
+		type MaxScheduledKeysPerSession = ConstU32<100>;
 	}
 
 	pub type BlockNumber = u64;
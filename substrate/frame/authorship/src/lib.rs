@@ -17,14 +17,25 @@
 
 //! Authorship tracking for FRAME runtimes.
 //!
-//! This tracks the current author of the block.
+//! This tracks the current author of the block. Optionally, it also keeps the number of blocks
+//! authored by each account within a rolling window of the most recent blocks, see
+//! [`Config::AuthorStatsWindow`].
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::traits::FindAuthor;
+extern crate alloc;
+
+use frame_support::{
+	traits::{FindAuthor, Get},
+	weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::SaturatedConversion;
 
 pub use pallet::*;
 
+pub mod runtime_api;
+
 /// An event handler for the authorship pallet. There is a dummy implementation
 /// for `()`, which does nothing.
 #[impl_trait_for_tuples::impl_for_tuples(30)]
@@ -33,11 +44,26 @@ pub trait EventHandler<Author, BlockNumber> {
 	fn note_author(author: Author);
 }
 
+/// Provides the number of blocks recently authored by an account.
+///
+/// There is a dummy implementation for `()`, which keeps no statistics.
+pub trait AuthorStats<AccountId> {
+	/// The number of blocks authored by `who` within the tracked window.
+	///
+	/// Returns `None` if no statistics are kept.
+	fn authored_blocks(who: &AccountId) -> Option<u32>;
+}
+
+impl<AccountId> AuthorStats<AccountId> for () {
+	fn authored_blocks(_: &AccountId) -> Option<u32> {
+		None
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -45,6 +71,12 @@ pub mod pallet {
 		type FindAuthor: FindAuthor<Self::AccountId>;
 		/// An event handler for authored blocks.
 		type EventHandler: EventHandler<Self::AccountId, BlockNumberFor<Self>>;
+		/// The number of most recent blocks for which authored-block counts are kept.
+		///
+		/// Zero disables the statistics. When lowering the window, the counts of the blocks that
+		/// fall out of it are not removed, so [`AuthoredBlocks`] should be cleared as well.
+		#[pallet::constant]
+		type AuthorStatsWindow: Get<u32>;
 	}
 
 	#[pallet::pallet]
@@ -52,12 +84,14 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = Weight::zero();
 			if let Some(author) = Self::author() {
+				weight = Self::note_authored_block(now, &author);
 				T::EventHandler::note_author(author);
 			}
 
-			Weight::zero()
+			weight
 		}
 
 		fn on_finalize(_: BlockNumberFor<T>) {
@@ -70,6 +104,15 @@ pub mod pallet {
 	#[pallet::whitelist_storage]
 	/// Author of current block.
 	pub(super) type Author<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// Authors of the blocks within the stats window, keyed by block number modulo the window.
+	#[pallet::storage]
+	pub type RecentAuthors<T: Config> = StorageMap<_, Twox64Concat, u32, T::AccountId, OptionQuery>;
+
+	/// Number of blocks authored by an account within the stats window.
+	#[pallet::storage]
+	pub type AuthoredBlocks<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -89,6 +132,36 @@ impl<T: Config> Pallet<T> {
 			<Author<T>>::put(&a);
 		})
 	}
+
+	/// The number of blocks authored by each account within the stats window.
+	pub fn author_stats() -> alloc::vec::Vec<(T::AccountId, u32)> {
+		AuthoredBlocks::<T>::iter().collect()
+	}
+
+	/// Record `author` as the author of block `now`, evicting the author of the block that falls
+	/// out of the stats window.
+	fn note_authored_block(now: BlockNumberFor<T>, author: &T::AccountId) -> Weight {
+		let window = T::AuthorStatsWindow::get();
+		if window == 0 {
+			return Weight::zero()
+		}
+
+		let index = now.saturated_into::<u32>() % window;
+		if let Some(evicted) = RecentAuthors::<T>::mutate(index, |a| a.replace(author.clone())) {
+			AuthoredBlocks::<T>::mutate_exists(evicted, |count| {
+				*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+			});
+		}
+		AuthoredBlocks::<T>::mutate(author, |count| *count = count.saturating_add(1));
+
+		T::DbWeight::get().reads_writes(3, 3)
+	}
+}
+
+impl<T: Config> AuthorStats<T::AccountId> for Pallet<T> {
+	fn authored_blocks(who: &T::AccountId) -> Option<u32> {
+		(T::AuthorStatsWindow::get() > 0).then(|| AuthoredBlocks::<T>::get(who))
+	}
 }
 
 #[cfg(test)]
@@ -96,7 +169,7 @@ mod tests {
 	use super::*;
 	use crate as pallet_authorship;
 	use codec::{Decode, Encode};
-	use frame_support::{derive_impl, ConsensusEngineId};
+	use frame_support::{derive_impl, parameter_types, traits::Hooks, ConsensusEngineId};
 	use sp_core::H256;
 	use sp_runtime::{
 		generic::DigestItem, testing::Header, traits::Header as HeaderT, BuildStorage,
@@ -117,9 +190,14 @@ mod tests {
 		type Block = Block;
 	}
 
+	parameter_types! {
+		pub static StatsWindow: u32 = 0;
+	}
+
 	impl pallet::Config for Test {
 		type FindAuthor = AuthorGiven;
 		type EventHandler = ();
+		type AuthorStatsWindow = StatsWindow;
 	}
 
 	const TEST_ID: ConsensusEngineId = [1, 2, 3, 4];
@@ -174,4 +252,52 @@ mod tests {
 			assert_eq!(Authorship::author(), Some(author));
 		});
 	}
+
+	fn author_block(number: u64, author: u64) {
+		let header = seal_header(create_header(number, Default::default(), [1; 32].into()), author);
+		System::initialize(&number, &Default::default(), header.digest());
+		Authorship::on_initialize(number);
+		Authorship::on_finalize(number);
+	}
+
+	#[test]
+	fn author_stats_are_kept_for_window() {
+		new_test_ext().execute_with(|| {
+			StatsWindow::set(3);
+
+			author_block(1, 1);
+			author_block(2, 2);
+			author_block(3, 1);
+			assert_eq!(Authorship::authored_blocks(&1), Some(2));
+			assert_eq!(Authorship::authored_blocks(&2), Some(1));
+
+			// block 1 falls out of the window.
+			author_block(4, 2);
+			assert_eq!(Authorship::authored_blocks(&1), Some(1));
+			assert_eq!(Authorship::authored_blocks(&2), Some(2));
+
+			// block 2 falls out of the window.
+			author_block(5, 3);
+			assert_eq!(Authorship::authored_blocks(&2), Some(1));
+			assert_eq!(Authorship::authored_blocks(&3), Some(1));
+
+			// block 3 falls out of the window, accounts without blocks are removed.
+			author_block(6, 2);
+			assert_eq!(Authorship::authored_blocks(&1), Some(0));
+			assert!(!AuthoredBlocks::<Test>::contains_key(1));
+
+			let mut stats = Authorship::author_stats();
+			stats.sort();
+			assert_eq!(stats, vec![(2, 2), (3, 1)]);
+		});
+	}
+
+	#[test]
+	fn author_stats_disabled_without_window() {
+		new_test_ext().execute_with(|| {
+			author_block(1, 1);
+			assert_eq!(Authorship::authored_blocks(&1), None);
+			assert!(Authorship::author_stats().is_empty());
+		});
+	}
 }
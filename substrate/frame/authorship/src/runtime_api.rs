@@ -0,0 +1,35 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the authorship pallet.
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// API to query the authored-block statistics.
+	pub trait AuthorshipApi<AccountId>
+	where
+		AccountId: Codec,
+	{
+		/// The number of most recent blocks covered by the statistics. Zero if disabled.
+		fn author_stats_window() -> u32;
+
+		/// The number of blocks authored by each account within the window.
+		fn author_stats() -> Vec<(AccountId, u32)>;
+	}
+}
@@ -23,7 +23,7 @@ use super::*;
 use crate::Pallet as Balances;
 
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_runtime::traits::Bounded;
 use types::ExtraFlags;
 
@@ -39,6 +39,30 @@ fn minimum_balance<T: Config<I>, I: 'static>() -> T::Balance {
 	}
 }
 
+fn held_for_scheduled_transfers<T: Config<I>, I: 'static>(who: &T::AccountId) -> T::Balance {
+	<Balances<T, I> as fungible::InspectHold<_>>::balance_on_hold(
+		&HoldReason::<I>::ScheduledTransfer.into(),
+		who,
+	)
+}
+
+// Schedule a transfer of the existential deposit from `caller` to `recipient` at block `at`.
+//
+// Transfers cannot be scheduled without a `Config::Scheduler`, so their calls are weightless.
+fn schedule_existential_deposit<T: Config<I>, I: 'static>(
+	caller: &T::AccountId,
+	recipient: T::AccountId,
+	at: BlockNumberFor<T>,
+) -> Result<(), BenchmarkError> {
+	Balances::<T, I>::schedule_transfer(
+		RawOrigin::Signed(caller.clone()).into(),
+		T::Lookup::unlookup(recipient),
+		minimum_balance::<T, I>(),
+		at,
+	)
+	.map_err(|_| BenchmarkError::Weightless)
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -360,6 +384,72 @@ mod benchmarks {
 		assert_eq!(Balances::<T, I>::free_balance(&caller), balance - burn_amount);
 	}
 
+	// Benchmark `schedule_transfer` taking the last slot of a block, which holds the largest
+	// deposit.
+	#[benchmark]
+	fn schedule_transfer() -> Result<(), BenchmarkError> {
+		let existential_deposit: T::Balance = minimum_balance::<T, I>();
+		let caller = whitelisted_caller();
+		let balance = existential_deposit.saturating_mul(ED_MULTIPLIER.into());
+		let _ = <Balances<T, I> as Currency<_>>::make_free_balance_be(&caller, balance);
+
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let at = frame_system::Pallet::<T>::block_number() + T::MaxScheduledTransferDelay::get();
+		schedule_existential_deposit::<T, I>(&caller, recipient.clone(), at)?;
+		ScheduledTransferCount::<T, I>::insert(at, MAX_SCHEDULED_TRANSFERS_PER_BLOCK - 1);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			T::Lookup::unlookup(recipient),
+			existential_deposit,
+			at,
+		);
+
+		assert_eq!(ScheduledTransferCount::<T, I>::get(at), MAX_SCHEDULED_TRANSFERS_PER_BLOCK);
+		assert!(ScheduledTransfers::<T, I>::contains_key(1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_scheduled_transfer() -> Result<(), BenchmarkError> {
+		let existential_deposit: T::Balance = minimum_balance::<T, I>();
+		let caller: T::AccountId = whitelisted_caller();
+		let balance = existential_deposit.saturating_mul(ED_MULTIPLIER.into());
+		let _ = <Balances<T, I> as Currency<_>>::make_free_balance_be(&caller, balance);
+
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let at = frame_system::Pallet::<T>::block_number() + T::MaxScheduledTransferDelay::get();
+		schedule_existential_deposit::<T, I>(&caller, recipient, at)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 0);
+
+		assert_eq!(held_for_scheduled_transfers::<T, I>(&caller), Zero::zero());
+		assert!(!ScheduledTransfers::<T, I>::contains_key(0));
+		Ok(())
+	}
+
+	// Benchmark `execute_scheduled_transfer` with the transfer creating the recipient account.
+	#[benchmark]
+	fn execute_scheduled_transfer() -> Result<(), BenchmarkError> {
+		let existential_deposit: T::Balance = minimum_balance::<T, I>();
+		let caller: T::AccountId = whitelisted_caller();
+		let balance = existential_deposit.saturating_mul(ED_MULTIPLIER.into());
+		let _ = <Balances<T, I> as Currency<_>>::make_free_balance_be(&caller, balance);
+
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		schedule_existential_deposit::<T, I>(&caller, recipient.clone(), at)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0);
+
+		assert_eq!(held_for_scheduled_transfers::<T, I>(&caller), Zero::zero());
+		assert_eq!(Balances::<T, I>::free_balance(&recipient), existential_deposit);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Balances,
 		crate::tests::ExtBuilder::default().build(),
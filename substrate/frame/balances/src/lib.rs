@@ -188,6 +188,7 @@ use sp_runtime::{
 
 pub use types::{
 	AccountData, AdjustmentDirection, BalanceLock, DustCleaner, ExtraFlags, Reasons, ReserveData,
+	ScheduledTransfer,
 };
 pub use weights::WeightInfo;
 
@@ -198,6 +199,12 @@ const LOG_TARGET: &str = "runtime::balances";
 // Default derivation(hard) for development accounts.
 const DEFAULT_ADDRESS_URI: &str = "//Sender//{}";
 
+/// The maximum number of transfers that can be scheduled for the same block.
+pub const MAX_SCHEDULED_TRANSFERS_PER_BLOCK: u32 = 32;
+
+/// The index of a transfer scheduled with `schedule_transfer`.
+pub type ScheduledTransferIndex = u32;
+
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 #[frame_support::pallet]
@@ -206,11 +213,23 @@ pub mod pallet {
 	use codec::HasCompact;
 	use frame_support::{
 		pallet_prelude::*,
-		traits::{fungible::Credit, tokens::Precision, VariantCount, VariantCountOf},
+		traits::{
+			fungible::Credit,
+			schedule::{
+				v3::{Named as ScheduleNamed, TaskName},
+				DispatchTime, LOWEST_PRIORITY,
+			},
+			tokens::{Precision, Restriction},
+			Bounded, OriginTrait, PalletInfoAccess, VariantCount, VariantCountOf,
+		},
 	};
 	use frame_system::pallet_prelude::*;
+	use sp_core::hashing::blake2_256;
 
 	pub type CreditOf<T, I> = Credit<<T as frame_system::Config>::AccountId, Pallet<T, I>>;
+	pub type CallOf<T, I> = <T as Config<I>>::RuntimeCall;
+	pub type PalletsOriginOf<T> =
+		<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
 
 	/// Default implementations of [`DefaultConfig`], which can be used to implement [`Config`].
 	pub mod config_preludes {
@@ -230,6 +249,8 @@ pub mod pallet {
 			type RuntimeHoldReason = ();
 			#[inject_runtime_type]
 			type RuntimeFreezeReason = ();
+			#[inject_runtime_type]
+			type RuntimeCall = ();
 
 			type Balance = u64;
 			type ExistentialDeposit = ConstUint<1>;
@@ -245,6 +266,9 @@ pub mod pallet {
 
 			type WeightInfo = ();
 			type DoneSlashHandler = ();
+
+			type Scheduler = ();
+			type MaxScheduledTransferDelay = ConstUint<{ 30 * 24 * 600 }>;
 		}
 	}
 
@@ -258,12 +282,21 @@ pub mod pallet {
 
 		/// The overarching hold reason.
 		#[pallet::no_default_bounds]
-		type RuntimeHoldReason: Parameter + Member + MaxEncodedLen + Copy + VariantCount;
+		type RuntimeHoldReason: Parameter
+			+ Member
+			+ MaxEncodedLen
+			+ Copy
+			+ VariantCount
+			+ From<HoldReason<I>>;
 
 		/// The overarching freeze reason.
 		#[pallet::no_default_bounds]
 		type RuntimeFreezeReason: VariantCount;
 
+		/// The overarching call type.
+		#[pallet::no_default_bounds]
+		type RuntimeCall: From<Call<Self, I>> + Encode;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -333,6 +366,17 @@ pub mod pallet {
 			Self::AccountId,
 			Self::Balance,
 		>;
+
+		/// The scheduler executing the transfers scheduled with `schedule_transfer`.
+		///
+		/// It has to count blocks like `frame_system`. Use `()` to disable scheduled transfers.
+		#[pallet::no_default_bounds]
+		type Scheduler: ScheduleNamed<BlockNumberFor<Self>, CallOf<Self, I>, PalletsOriginOf<Self>>;
+
+		/// The maximum number of blocks a transfer can be scheduled ahead.
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type MaxScheduledTransferDelay: Get<BlockNumberFor<Self>>;
 	}
 
 	/// The in-code storage version.
@@ -421,6 +465,37 @@ pub mod pallet {
 		},
 		/// Some balance was released from hold.
 		Released { reason: T::RuntimeHoldReason, who: T::AccountId, amount: T::Balance },
+		/// A transfer was scheduled and its `amount` held on the `source` account, together with
+		/// a `deposit` for its slot in block `at`.
+		TransferScheduled {
+			index: ScheduledTransferIndex,
+			source: T::AccountId,
+			dest: T::AccountId,
+			amount: T::Balance,
+			deposit: T::Balance,
+			at: BlockNumberFor<T>,
+		},
+		/// A scheduled transfer was executed.
+		ScheduledTransferExecuted {
+			index: ScheduledTransferIndex,
+			source: T::AccountId,
+			dest: T::AccountId,
+			amount: T::Balance,
+		},
+		/// A scheduled transfer could not be executed at its block. The `amount` was released
+		/// on the `source` account.
+		ScheduledTransferExpired {
+			index: ScheduledTransferIndex,
+			source: T::AccountId,
+			amount: T::Balance,
+		},
+		/// A scheduled transfer was cancelled. The `amount` was released on the `source`
+		/// account.
+		ScheduledTransferCancelled {
+			index: ScheduledTransferIndex,
+			source: T::AccountId,
+			amount: T::Balance,
+		},
 		/// An unexpected/defensive event was triggered.
 		Unexpected(UnexpectedKind),
 	}
@@ -437,6 +512,14 @@ pub mod pallet {
 		FailedToMutateAccount,
 	}
 
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason<I: 'static = ()> {
+		/// The funds and the slot deposit of a transfer scheduled with `schedule_transfer`.
+		#[codec(index = 0)]
+		ScheduledTransfer,
+	}
+
 	#[pallet::error]
 	pub enum Error<T, I = ()> {
 		/// Vesting balance too high to send value.
@@ -463,6 +546,16 @@ pub mod pallet {
 		IssuanceDeactivated,
 		/// The delta cannot be zero.
 		DeltaZero,
+		/// A transfer can only be scheduled for a future block.
+		ScheduledTransferInPast,
+		/// A transfer can be scheduled at most `MaxScheduledTransferDelay` blocks ahead.
+		ScheduledTransferTooFar,
+		/// The block already has `MAX_SCHEDULED_TRANSFERS_PER_BLOCK` scheduled transfers.
+		TooManyScheduledTransfers,
+		/// The scheduled transfer does not exist or was already executed.
+		UnknownScheduledTransfer,
+		/// Only the source of a scheduled transfer can cancel it.
+		NotScheduledTransferSource,
 	}
 
 	/// The total units issued in the system.
@@ -552,6 +645,25 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Transfers scheduled with `schedule_transfer` that are yet to be executed.
+	#[pallet::storage]
+	pub type ScheduledTransfers<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ScheduledTransferIndex,
+		ScheduledTransfer<T::AccountId, T::Balance, BlockNumberFor<T>>,
+	>;
+
+	/// The number of transfers scheduled with `schedule_transfer` for each block.
+	#[pallet::storage]
+	pub type ScheduledTransferCount<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, u32, ValueQuery>;
+
+	/// The index of the next scheduled transfer.
+	#[pallet::storage]
+	pub type NextScheduledTransferIndex<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, ScheduledTransferIndex, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
 		pub balances: Vec<(T::AccountId, T::Balance)>,
@@ -872,9 +984,195 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Transfer `value` to `dest` at the beginning of block `at`.
+		///
+		/// The transfer is executed by `Config::Scheduler`. The funds are held on the caller's
+		/// account until then, together with a deposit of the existential deposit for the slot in
+		/// block `at`. The deposit is released once the transfer is executed, expires or is
+		/// cancelled. If the transfer cannot be executed, for example because it would not create
+		/// `dest`, it expires and the funds are released.
+		///
+		/// The dispatch origin for this call must be `Signed` by the transactor. `value` must be at
+		/// least the existential deposit and `at` must be a future block at most
+		/// `MaxScheduledTransferDelay` blocks ahead, with less than
+		/// `MAX_SCHEDULED_TRANSFERS_PER_BLOCK` transfers already scheduled.
+		///
+		/// Emits `TransferScheduled`.
+		#[pallet::call_index(11)]
+		pub fn schedule_transfer(
+			origin: OriginFor<T>,
+			dest: AccountIdLookupOf<T>,
+			#[pallet::compact] value: T::Balance,
+			at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			ensure!(value >= T::ExistentialDeposit::get(), Error::<T, I>::ExistentialDeposit);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(at > now, Error::<T, I>::ScheduledTransferInPast);
+			ensure!(
+				at <= now.saturating_add(T::MaxScheduledTransferDelay::get()),
+				Error::<T, I>::ScheduledTransferTooFar
+			);
+			let count = ScheduledTransferCount::<T, I>::get(at).saturating_add(1);
+			ensure!(
+				count <= MAX_SCHEDULED_TRANSFERS_PER_BLOCK,
+				Error::<T, I>::TooManyScheduledTransfers
+			);
+
+			let index = NextScheduledTransferIndex::<T, I>::get();
+			let deposit = T::ExistentialDeposit::get();
+			<Self as fungible::MutateHold<_>>::hold(
+				&HoldReason::<I>::ScheduledTransfer.into(),
+				&source,
+				value.saturating_add(deposit),
+			)?;
+			let call: CallOf<T, I> = Call::<T, I>::execute_scheduled_transfer { index }.into();
+			T::Scheduler::schedule_named(
+				Self::scheduled_transfer_task(index),
+				DispatchTime::At(at),
+				None,
+				LOWEST_PRIORITY,
+				frame_system::RawOrigin::Root.into(),
+				// The encoded call is only a few bytes long.
+				Bounded::Inline(BoundedVec::truncate_from(call.encode())),
+			)?;
+			ScheduledTransferCount::<T, I>::insert(at, count);
+			ScheduledTransfers::<T, I>::insert(
+				index,
+				ScheduledTransfer {
+					source: source.clone(),
+					dest: dest.clone(),
+					amount: value,
+					deposit,
+					at,
+				},
+			);
+			NextScheduledTransferIndex::<T, I>::put(index.wrapping_add(1));
+
+			Self::deposit_event(Event::TransferScheduled {
+				index,
+				source,
+				dest,
+				amount: value,
+				deposit,
+				at,
+			});
+			Ok(())
+		}
+
+		/// Cancel a transfer scheduled with `schedule_transfer` that was not executed yet.
+		///
+		/// The dispatch origin for this call must be `Signed` by the source of the transfer. The
+		/// held funds and the slot deposit are released.
+		///
+		/// Emits `ScheduledTransferCancelled`.
+		#[pallet::call_index(12)]
+		pub fn cancel_scheduled_transfer(
+			origin: OriginFor<T>,
+			index: ScheduledTransferIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let transfer = ScheduledTransfers::<T, I>::get(index)
+				.ok_or(Error::<T, I>::UnknownScheduledTransfer)?;
+			ensure!(transfer.source == who, Error::<T, I>::NotScheduledTransferSource);
+
+			T::Scheduler::cancel_named(Self::scheduled_transfer_task(index))?;
+			Self::free_scheduled_transfer_slot(transfer.at);
+			ScheduledTransfers::<T, I>::remove(index);
+			let amount = Self::release_scheduled_transfer(&who, transfer.amount);
+			Self::release_scheduled_transfer(&who, transfer.deposit);
+
+			Self::deposit_event(Event::ScheduledTransferCancelled { index, source: who, amount });
+			Ok(())
+		}
+
+		/// Execute the transfer `index` scheduled with `schedule_transfer`, or release its funds
+		/// if it fails.
+		///
+		/// The dispatch origin for this call must be _Root_. `Config::Scheduler` dispatches it at
+		/// the block the transfer was scheduled for.
+		///
+		/// Emits `ScheduledTransferExecuted` or `ScheduledTransferExpired`.
+		#[pallet::call_index(13)]
+		pub fn execute_scheduled_transfer(
+			origin: OriginFor<T>,
+			index: ScheduledTransferIndex,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let ScheduledTransfer { source, dest, amount, deposit, at } =
+				ScheduledTransfers::<T, I>::take(index)
+					.ok_or(Error::<T, I>::UnknownScheduledTransfer)?;
+			Self::free_scheduled_transfer_slot(at);
+
+			// The held funds may have been slashed in the meantime.
+			let amount = amount.min(<Self as fungible::InspectHold<_>>::balance_on_hold(
+				&HoldReason::<I>::ScheduledTransfer.into(),
+				&source,
+			));
+			match <Self as fungible::MutateHold<_>>::transfer_on_hold(
+				&HoldReason::<I>::ScheduledTransfer.into(),
+				&source,
+				&dest,
+				amount,
+				Precision::Exact,
+				Restriction::Free,
+				Polite,
+			) {
+				Ok(_) => Self::deposit_event(Event::ScheduledTransferExecuted {
+					index,
+					source: source.clone(),
+					dest,
+					amount,
+				}),
+				Err(e) => {
+					log::debug!(
+						target: LOG_TARGET,
+						"Scheduled transfer {:?} expired: {:?}",
+						index,
+						e,
+					);
+					let amount = Self::release_scheduled_transfer(&source, amount);
+					Self::deposit_event(Event::ScheduledTransferExpired {
+						index,
+						source: source.clone(),
+						amount,
+					})
+				},
+			}
+			Self::release_scheduled_transfer(&source, deposit);
+			Ok(())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The name of the scheduler task executing the scheduled transfer `index`.
+		fn scheduled_transfer_task(index: ScheduledTransferIndex) -> TaskName {
+			(b"balances/scheduled_transfer", Self::index() as u32, index).using_encoded(blake2_256)
+		}
+
+		/// Free the slot of a scheduled transfer in block `at`.
+		fn free_scheduled_transfer_slot(at: BlockNumberFor<T>) {
+			ScheduledTransferCount::<T, I>::mutate_exists(at, |count| {
+				*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0)
+			});
+		}
+
+		/// Release up to `amount` of the funds held for scheduled transfers on `who`.
+		///
+		/// Returns the amount released, which is less than `amount` if the held funds were slashed.
+		fn release_scheduled_transfer(who: &T::AccountId, amount: T::Balance) -> T::Balance {
+			let reason = HoldReason::<I>::ScheduledTransfer.into();
+			let amount =
+				amount.min(<Self as fungible::InspectHold<_>>::balance_on_hold(&reason, who));
+			if amount.is_zero() {
+				return amount
+			}
+			<Self as fungible::MutateHold<_>>::release(&reason, who, amount, Precision::Exact)
+				.unwrap_or_default()
+		}
+
 		/// Public function to get the total issuance.
 		pub fn total_issuance() -> T::Balance {
 			TotalIssuance::<T, I>::get()
@@ -1367,8 +1665,10 @@ pub mod pallet {
 			_n: BlockNumberFor<T>,
 		) -> Result<(), sp_runtime::TryRuntimeError> {
 			Self::hold_and_freeze_count()?;
+			Self::holds_and_freezes_decode()?;
 			Self::account_frozen_greater_than_locks()?;
 			Self::account_frozen_greater_than_freezes()?;
+			Self::account_reserved_greater_than_holds()?;
 			Ok(())
 		}
 
@@ -1394,6 +1694,28 @@ pub mod pallet {
 			Ok(())
 		}
 
+		fn holds_and_freezes_decode() -> Result<(), sp_runtime::TryRuntimeError> {
+			Holds::<T, I>::iter_keys().try_for_each(
+				|who| -> Result<(), sp_runtime::TryRuntimeError> {
+					if Holds::<T, I>::try_get(&who).is_err() {
+						log::warn!(target: crate::LOG_TARGET, "Holds of {:?} cannot be decoded", who);
+						Err("Found undecodable `Holds`".into())
+					} else {
+						Ok(())
+					}
+				},
+			)?;
+
+			Freezes::<T, I>::iter_keys().try_for_each(|who| {
+				if Freezes::<T, I>::try_get(&who).is_err() {
+					log::warn!(target: crate::LOG_TARGET, "Freezes of {:?} cannot be decoded", who);
+					Err("Found undecodable `Freezes`".into())
+				} else {
+					Ok(())
+				}
+			})
+		}
+
 		fn account_frozen_greater_than_locks() -> Result<(), sp_runtime::TryRuntimeError> {
 			Locks::<T, I>::iter().try_for_each(|(who, locks)| {
 				let max_locks = locks.iter().map(|l| l.amount).max().unwrap_or_default();
@@ -1431,5 +1753,59 @@ pub mod pallet {
 				}
 			})
 		}
+
+		fn account_reserved_greater_than_holds() -> Result<(), sp_runtime::TryRuntimeError> {
+			Holds::<T, I>::iter().try_for_each(|(who, holds)| {
+				let total_holds = holds
+					.iter()
+					.fold(T::Balance::zero(), |total, hold| total.saturating_add(hold.amount));
+				let reserved = T::AccountStore::get(&who).reserved;
+				if total_holds > reserved {
+					log::warn!(
+						target: crate::LOG_TARGET,
+						"Total holds of {:?} ({:?}) are greater than the reserved balance {:?}",
+						who,
+						total_holds,
+						reserved
+					);
+					Err("bad holds".into())
+				} else {
+					Ok(())
+				}
+			})
+		}
+
+		/// Ensure that [`TotalIssuance`] matches the sum of the balances of all accounts.
+		///
+		/// The balances are stored either in `frame_system` or in [`Account`], see
+		/// [`Config::AccountStore`], so the accounts of both are summed up, each of them once.
+		///
+		/// Not part of the `try_state` hook, since it iterates over all accounts, which is too
+		/// expensive for large chains, and since the tests of this pallet set up balances without
+		/// minting them, e.g. the genesis dev accounts. Runtimes and tests may opt into it by
+		/// calling it explicitly.
+		pub fn try_state_total_issuance() -> Result<(), sp_runtime::TryRuntimeError> {
+			let total = frame_system::Account::<T>::iter_keys()
+				.chain(
+					Account::<T, I>::iter_keys()
+						.filter(|who| !frame_system::Account::<T>::contains_key(who)),
+				)
+				.fold(T::Balance::zero(), |total, who| {
+					let account = T::AccountStore::get(&who);
+					total.saturating_add(account.total())
+				});
+			let total_issuance = TotalIssuance::<T, I>::get();
+			if total != total_issuance {
+				log::warn!(
+					target: crate::LOG_TARGET,
+					"Total issuance {:?} does not match the sum of all balances {:?}",
+					total_issuance,
+					total
+				);
+				Err("bad total issuance".into())
+			} else {
+				Ok(())
+			}
+		}
 	}
 }
@@ -20,10 +20,14 @@
 use super::*;
 use crate::{
 	AdjustmentDirection::{Decrease as Dec, Increase as Inc},
-	Event,
+	Event, ScheduledTransferCount, ScheduledTransfers, MAX_SCHEDULED_TRANSFERS_PER_BLOCK,
 };
-use frame_support::traits::{fungible::Unbalanced, tokens::Preservation::Expendable};
-use fungible::{hold::Mutate as HoldMutate, Inspect, Mutate};
+use frame_support::traits::{
+	fungible::Unbalanced,
+	tokens::{Fortitude, Precision, Preservation::Expendable},
+	Get,
+};
+use fungible::{hold::Mutate as HoldMutate, Inspect, InspectHold, Mutate};
 
 /// Alice account ID for more readable tests.
 const ALICE: u64 = 1;
@@ -382,3 +386,193 @@ fn burn_works() {
 		assert!(Balances::total_balance(&account).is_zero());
 	});
 }
+
+fn held_for_scheduled_transfers(who: AccountId) -> Balance {
+	Balances::balance_on_hold(&TestId::ScheduledTransfer, &who)
+}
+
+#[test]
+fn scheduled_transfer_executes_at_its_block() {
+	ExtBuilder::default()
+		.existential_deposit(10)
+		.monied(true)
+		.build_and_execute_with(|| {
+			assert_ok!(Balances::schedule_transfer(Some(1).into(), 5, 50, 5));
+			System::assert_last_event(RuntimeEvent::Balances(Event::TransferScheduled {
+				index: 0,
+				source: 1,
+				dest: 5,
+				amount: 50,
+				deposit: 10,
+				at: 5,
+			}));
+			assert_eq!(Balances::free_balance(1), 40);
+			assert_eq!(held_for_scheduled_transfers(1), 60);
+			assert_eq!(ScheduledTransferCount::<Test>::get(5), 1);
+			assert_eq!(ScheduledTasks::get().len(), 1);
+
+			run_scheduled_tasks(4);
+			assert_eq!(Balances::free_balance(5), 0);
+
+			run_scheduled_tasks(5);
+			System::assert_has_event(RuntimeEvent::Balances(Event::ScheduledTransferExecuted {
+				index: 0,
+				source: 1,
+				dest: 5,
+				amount: 50,
+			}));
+			assert_eq!(Balances::free_balance(1), 50);
+			assert_eq!(held_for_scheduled_transfers(1), 0);
+			assert_eq!(Balances::free_balance(5), 50);
+			assert!(ScheduledTransfers::<Test>::get(0).is_none());
+			assert!(!ScheduledTransferCount::<Test>::contains_key(5));
+
+			assert_noop!(
+				Balances::cancel_scheduled_transfer(Some(1).into(), 0),
+				Error::<Test>::UnknownScheduledTransfer,
+			);
+			assert_noop!(
+				Balances::execute_scheduled_transfer(RawOrigin::Root.into(), 0),
+				Error::<Test>::UnknownScheduledTransfer,
+			);
+		});
+}
+
+#[test]
+fn scheduled_transfer_expires_with_refund() {
+	ExtBuilder::default()
+		.existential_deposit(10)
+		.monied(true)
+		.build_and_execute_with(|| {
+			assert_ok!(Balances::schedule_transfer(Some(1).into(), 5, 10, 3));
+			assert_eq!(held_for_scheduled_transfers(1), 20);
+			// Most of the held funds are slashed, so that the transfer can no longer create the
+			// destination account.
+			assert_ok!(Balances::burn_held(
+				&TestId::ScheduledTransfer,
+				&1,
+				15,
+				Precision::Exact,
+				Fortitude::Force
+			));
+
+			run_scheduled_tasks(3);
+			System::assert_has_event(RuntimeEvent::Balances(Event::ScheduledTransferExpired {
+				index: 0,
+				source: 1,
+				amount: 5,
+			}));
+			assert_eq!(Balances::free_balance(1), 85);
+			assert_eq!(held_for_scheduled_transfers(1), 0);
+			assert_eq!(Balances::free_balance(5), 0);
+			assert!(ScheduledTransfers::<Test>::get(0).is_none());
+		});
+}
+
+#[test]
+fn cancel_scheduled_transfer_works() {
+	ExtBuilder::default()
+		.existential_deposit(10)
+		.monied(true)
+		.build_and_execute_with(|| {
+			assert_ok!(Balances::schedule_transfer(Some(1).into(), 2, 30, 10));
+			assert_eq!(held_for_scheduled_transfers(1), 40);
+			assert_noop!(
+				Balances::cancel_scheduled_transfer(Some(2).into(), 0),
+				Error::<Test>::NotScheduledTransferSource,
+			);
+
+			assert_ok!(Balances::cancel_scheduled_transfer(Some(1).into(), 0));
+			System::assert_last_event(RuntimeEvent::Balances(Event::ScheduledTransferCancelled {
+				index: 0,
+				source: 1,
+				amount: 30,
+			}));
+			assert_eq!(Balances::free_balance(1), 100);
+			assert_eq!(held_for_scheduled_transfers(1), 0);
+			assert!(!ScheduledTransferCount::<Test>::contains_key(10));
+			assert!(ScheduledTasks::get().is_empty());
+
+			run_scheduled_tasks(10);
+			assert_eq!(Balances::free_balance(1), 100);
+			assert_eq!(Balances::free_balance(2), 200);
+		});
+}
+
+#[test]
+fn schedule_transfer_checks_params() {
+	ExtBuilder::default()
+		.existential_deposit(10)
+		.monied(true)
+		.build_and_execute_with(|| {
+			let now = System::block_number();
+			let max_delay: u64 = <Test as Config>::MaxScheduledTransferDelay::get();
+			assert_noop!(
+				Balances::schedule_transfer(Some(1).into(), 2, 50, now),
+				Error::<Test>::ScheduledTransferInPast,
+			);
+			assert_noop!(
+				Balances::schedule_transfer(Some(1).into(), 2, 50, now + max_delay + 1),
+				Error::<Test>::ScheduledTransferTooFar,
+			);
+			assert_noop!(
+				Balances::schedule_transfer(Some(1).into(), 2, 5, now + 1),
+				Error::<Test>::ExistentialDeposit,
+			);
+			// The slot deposit has to be held as well.
+			assert_noop!(
+				Balances::schedule_transfer(Some(1).into(), 2, 91, now + 1),
+				TokenError::FundsUnavailable,
+			);
+			assert_ok!(Balances::schedule_transfer(Some(1).into(), 2, 50, now + max_delay));
+
+			// Only the scheduler executes transfers.
+			assert_noop!(Balances::execute_scheduled_transfer(Some(1).into(), 0), BadOrigin);
+		});
+}
+
+#[test]
+fn scheduled_transfer_deposit_does_not_depend_on_the_transfers_of_the_block() {
+	ExtBuilder::default()
+		.existential_deposit(10)
+		.monied(true)
+		.build_and_execute_with(|| {
+			Balances::set_balance(&4, 10_000);
+			// Every transfer holds the existential deposit for its slot, however many transfers
+			// were scheduled for the block before.
+			for index in 0..MAX_SCHEDULED_TRANSFERS_PER_BLOCK {
+				assert_ok!(Balances::schedule_transfer(Some(4).into(), 2, 10, 2));
+				System::assert_last_event(RuntimeEvent::Balances(Event::TransferScheduled {
+					index,
+					source: 4,
+					dest: 2,
+					amount: 10,
+					deposit: 10,
+					at: 2,
+				}));
+			}
+			assert_noop!(
+				Balances::schedule_transfer(Some(4).into(), 2, 10, 2),
+				Error::<Test>::TooManyScheduledTransfers,
+			);
+			assert_eq!(held_for_scheduled_transfers(4), 320 + 320);
+
+			// Another block has its own slots.
+			assert_ok!(Balances::schedule_transfer(Some(4).into(), 2, 10, 3));
+			System::assert_last_event(RuntimeEvent::Balances(Event::TransferScheduled {
+				index: 32,
+				source: 4,
+				dest: 2,
+				amount: 10,
+				deposit: 10,
+				at: 3,
+			}));
+
+			// All deposits are released once the transfers are executed.
+			run_scheduled_tasks(2);
+			run_scheduled_tasks(3);
+			assert_eq!(held_for_scheduled_transfers(4), 0);
+			assert_eq!(Balances::free_balance(4), 10_000 - 330);
+			assert!(ScheduledTasks::get().is_empty());
+		});
+}
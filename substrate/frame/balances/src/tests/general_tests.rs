@@ -142,4 +142,37 @@ fn try_state_works() {
 		assert!(format!("{:?}", Balances::try_state(0).unwrap_err())
 			.contains("Found `Freeze` with too many elements"));
 	});
+
+	ExtBuilder::default().auto_try_state(false).build_and_execute_with(|| {
+		// A single hold, which is too short to be decoded.
+		storage::unhashed::put(&Holds::<Test>::hashed_key_for(1), &vec![0u8; 1]);
+
+		assert!(format!("{:?}", Balances::try_state(0).unwrap_err())
+			.contains("Found undecodable `Holds`"));
+	});
+
+	ExtBuilder::default().auto_try_state(false).build_and_execute_with(|| {
+		Balances::set_balance(&1, 100);
+		Holds::<Test>::insert(
+			1,
+			frame_support::BoundedVec::truncate_from(vec![crate::IdAmount {
+				id: TestId::Foo,
+				amount: 10,
+			}]),
+		);
+
+		assert!(format!("{:?}", Balances::try_state(0).unwrap_err()).contains("bad holds"));
+	});
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		Balances::set_balance(&1, 100);
+		TotalIssuance::<Test>::put(100);
+		assert_ok!(Balances::try_state_total_issuance());
+
+		// Opt-in only, the `try_state` hook does not check the total issuance.
+		TotalIssuance::<Test>::put(90);
+		assert_ok!(Balances::try_state(0));
+		assert!(format!("{:?}", Balances::try_state_total_issuance().unwrap_err())
+			.contains("bad total issuance"));
+	});
 }
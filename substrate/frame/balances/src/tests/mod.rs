@@ -29,8 +29,13 @@ use frame_support::{
 	dispatch::{DispatchInfo, GetDispatchInfo},
 	parameter_types,
 	traits::{
-		fungible, ConstU32, ConstU8, Imbalance as ImbalanceT, OnUnbalanced, StorageMapShim,
-		StoredMap, VariantCount, VariantCountOf, WhitelistedStorageKeys,
+		fungible,
+		schedule::{
+			v3::{Named as ScheduleNamed, TaskName},
+			DispatchTime, Period, Priority,
+		},
+		Bounded, ConstU32, ConstU64, ConstU8, Imbalance as ImbalanceT, OnUnbalanced,
+		StorageMapShim, StoredMap, VariantCount, VariantCountOf, WhitelistedStorageKeys,
 	},
 	weights::{IdentityFee, Weight},
 };
@@ -40,7 +45,7 @@ use scale_info::TypeInfo;
 use sp_core::{hexdisplay::HexDisplay, sr25519::Pair as SrPair, Pair};
 use sp_io;
 use sp_runtime::{
-	traits::{BadOrigin, Zero},
+	traits::{BadOrigin, BlakeTwo256, Dispatchable, Zero},
 	ArithmeticError, BuildStorage, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 	TokenError,
 };
@@ -74,10 +79,17 @@ pub enum TestId {
 	Foo,
 	Bar,
 	Baz,
+	ScheduledTransfer,
 }
 
 impl VariantCount for TestId {
-	const VARIANT_COUNT: u32 = 3;
+	const VARIANT_COUNT: u32 = 4;
+}
+
+impl From<crate::HoldReason> for TestId {
+	fn from(_: crate::HoldReason) -> Self {
+		TestId::ScheduledTransfer
+	}
 }
 
 pub(crate) type AccountId = <Test as frame_system::Config>::AccountId;
@@ -129,6 +141,73 @@ impl Config for Test {
 	type RuntimeFreezeReason = TestId;
 	type FreezeIdentifier = TestId;
 	type MaxFreezes = VariantCountOf<TestId>;
+	type Scheduler = MockScheduler;
+	type MaxScheduledTransferDelay = ConstU64<100>;
+}
+
+parameter_types! {
+	/// The tasks scheduled with [`MockScheduler`]: name, block, origin and call.
+	pub static ScheduledTasks: Vec<(TaskName, u64, OriginCaller, RuntimeCall)> = vec![];
+}
+
+/// A scheduler keeping its tasks in memory until [`run_scheduled_tasks`] dispatches them.
+pub struct MockScheduler;
+impl ScheduleNamed<u64, RuntimeCall, OriginCaller> for MockScheduler {
+	type Address = TaskName;
+	type Hasher = BlakeTwo256;
+
+	fn schedule_named(
+		id: TaskName,
+		when: DispatchTime<u64>,
+		_maybe_periodic: Option<Period<u64>>,
+		_priority: Priority,
+		origin: OriginCaller,
+		call: Bounded<RuntimeCall, BlakeTwo256>,
+	) -> Result<TaskName, DispatchError> {
+		let DispatchTime::At(when) = when else { unimplemented!("only `At` is used") };
+		let Bounded::Inline(call) = call else { unimplemented!("only inline calls are used") };
+		let call = RuntimeCall::decode(&mut &call[..]).map_err(|_| DispatchError::Corruption)?;
+		if ScheduledTasks::get().iter().any(|(name, ..)| *name == id) {
+			return Err(DispatchError::Other("task already scheduled"))
+		}
+		ScheduledTasks::mutate(|tasks| tasks.push((id, when, origin, call)));
+		Ok(id)
+	}
+
+	fn cancel_named(id: TaskName) -> Result<(), DispatchError> {
+		ScheduledTasks::mutate(|tasks| {
+			let position = tasks
+				.iter()
+				.position(|(name, ..)| *name == id)
+				.ok_or(DispatchError::Unavailable)?;
+			tasks.remove(position);
+			Ok(())
+		})
+	}
+
+	fn reschedule_named(
+		_id: TaskName,
+		_when: DispatchTime<u64>,
+	) -> Result<TaskName, DispatchError> {
+		unimplemented!("transfers are never rescheduled")
+	}
+
+	fn next_dispatch_time(id: TaskName) -> Result<u64, DispatchError> {
+		ScheduledTasks::get()
+			.into_iter()
+			.find_map(|(name, when, ..)| (name == id).then_some(when))
+			.ok_or(DispatchError::Unavailable)
+	}
+}
+
+/// Dispatch the tasks of [`MockScheduler`] scheduled for block `n`.
+pub fn run_scheduled_tasks(n: u64) {
+	let (due, pending): (Vec<_>, Vec<_>) =
+		ScheduledTasks::take().into_iter().partition(|(_, when, ..)| *when == n);
+	ScheduledTasks::set(pending);
+	for (_, _, origin, call) in due {
+		assert_ok!(call.dispatch(origin.into()));
+	}
 }
 
 #[derive(Clone)]
@@ -169,6 +248,7 @@ impl ExtBuilder {
 	}
 	pub fn build(self) -> sp_io::TestExternalities {
 		self.set_associated_consts();
+		ScheduledTasks::take();
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 		pallet_balances::GenesisConfig::<Test> {
 			balances: if self.monied {
@@ -214,3 +214,28 @@ pub enum AdjustmentDirection {
 	/// Decrease the amount.
 	Decrease,
 }
+
+/// A transfer scheduled with `schedule_transfer`, waiting for its block.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct ScheduledTransfer<AccountId, Balance, BlockNumber> {
+	/// The account the transferred funds are held on until the transfer executes.
+	pub source: AccountId,
+	/// The account receiving the funds.
+	pub dest: AccountId,
+	/// The amount to transfer.
+	pub amount: Balance,
+	/// The deposit held on `source` for the slot in the agenda of block `at`.
+	pub deposit: Balance,
+	/// The block at which the transfer executes.
+	pub at: BlockNumber,
+}
@@ -82,6 +82,8 @@ impl pallet_session::Config for Test {
 	type WeightInfo = ();
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 pub type MmrLeaf = sp_consensus_beefy::mmr::MmrLeaf<
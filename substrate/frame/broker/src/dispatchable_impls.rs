@@ -18,6 +18,7 @@
 use core::cmp;
 
 use super::*;
+use alloc::vec::Vec;
 use frame_support::{
 	pallet_prelude::*,
 	traits::{fungible::Mutate, tokens::Preservation::Expendable, DefensiveResult},
@@ -315,6 +316,80 @@ impl<T: Config> Pallet<T> {
 		Ok(new_region_ids)
 	}
 
+	pub(crate) fn do_split_evenly(
+		region_id: RegionId,
+		maybe_check_owner: Option<T::AccountId>,
+		parts: u32,
+	) -> Result<Vec<RegionId>, Error<T>> {
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		let length = region.end.saturating_sub(region_id.begin);
+		ensure!(parts >= 2 && parts <= length, Error::<T>::InvalidPartCount);
+
+		let (part_length, remainder) = (length / parts, length % parts);
+		let mut new_region_ids = Vec::with_capacity(parts as usize);
+		let mut rest = region_id;
+		for i in 1..parts {
+			let offset = part_length.saturating_add(if i <= remainder { 1 } else { 0 });
+			let (part, next) = Self::do_partition(rest, maybe_check_owner.clone(), offset)?;
+			new_region_ids.push(part);
+			rest = next;
+		}
+		new_region_ids.push(rest);
+
+		Self::deposit_event(Event::RegionSplitEvenly {
+			region_id,
+			new_region_ids: new_region_ids.clone(),
+		});
+		Ok(new_region_ids)
+	}
+
+	pub(crate) fn do_interlace_for_tasks(
+		region_id: RegionId,
+		maybe_check_owner: Option<T::AccountId>,
+		tasks: Vec<TaskId>,
+		finality: Finality,
+	) -> Result<Vec<(RegionId, TaskId)>, Error<T>> {
+		let bits: Vec<u32> = (0..CORE_MASK_BITS as u32)
+			.filter(|&i| !(region_id.mask & CoreMask::void().set(i)).is_void())
+			.collect();
+		let count = tasks.len() as u32;
+		ensure!(count >= 2 && count <= bits.len() as u32, Error::<T>::InvalidTaskCount);
+
+		// Share the set bits out in order, giving one extra bit to each of the earliest tasks
+		// until the remainder is used up.
+		let (per_task, remainder) = (bits.len() as u32 / count, bits.len() as u32 % count);
+		let mut masks = Vec::with_capacity(tasks.len());
+		let mut bits = bits.into_iter();
+		for i in 0..count {
+			let take = per_task.saturating_add(if i < remainder { 1 } else { 0 });
+			let mut mask = CoreMask::void();
+			bits.by_ref().take(take as usize).for_each(|bit| {
+				mask.set(bit);
+			});
+			masks.push(mask);
+		}
+
+		let mut rest = region_id;
+		let mut parts = Vec::with_capacity(tasks.len());
+		for mask in masks.iter().take(masks.len().saturating_sub(1)) {
+			let (part, next) = Self::do_interlace(rest, maybe_check_owner.clone(), *mask)?;
+			parts.push(part);
+			rest = next;
+		}
+		parts.push(rest);
+
+		let assignments: Vec<_> = parts.into_iter().zip(tasks).collect();
+		for (part, task) in assignments.iter() {
+			Self::do_assign(*part, maybe_check_owner.clone(), *task, finality)?;
+		}
+
+		Self::deposit_event(Event::RegionInterlacedForTasks {
+			region_id,
+			assignments: assignments.clone(),
+		});
+		Ok(assignments)
+	}
+
 	pub(crate) fn do_assign(
 		region_id: RegionId,
 		maybe_check_owner: Option<T::AccountId>,
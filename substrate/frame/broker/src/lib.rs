@@ -494,6 +494,21 @@ pub mod pallet {
 		/// This should never happen, given that enable_auto_renew checks for this before enabling
 		/// auto-renewal.
 		AutoRenewalLimitReached,
+		/// A Region has been split into several consecutive Regions of (nearly) equal length.
+		RegionSplitEvenly {
+			/// The Region which was split.
+			region_id: RegionId,
+			/// The new Regions, in chronological order.
+			new_region_ids: Vec<RegionId>,
+		},
+		/// A Region has been interlaced into several Regions, each of which has been assigned to
+		/// a task.
+		RegionInterlacedForTasks {
+			/// The Region which was interlaced.
+			region_id: RegionId,
+			/// The new Regions and the tasks to which they have been assigned.
+			assignments: Vec<(RegionId, TaskId)>,
+		},
 	}
 
 	#[pallet::error]
@@ -577,6 +592,12 @@ pub mod pallet {
 		/// Needed to prevent spam attacks.The amount of credits the user attempted to purchase is
 		/// below `T::MinimumCreditPurchase`.
 		CreditPurchaseTooSmall,
+		/// The number of parts to split a Region into is less than two or greater than the
+		/// Region's length in timeslices.
+		InvalidPartCount,
+		/// The number of tasks to interlace a Region for is less than two or greater than the
+		/// number of interlace bits set in the Region's mask.
+		InvalidTaskCount,
 	}
 
 	#[derive(frame_support::DefaultNoBound)]
@@ -1022,6 +1043,57 @@ pub mod pallet {
 			Self::do_remove_assignment(region_id)
 		}
 
+		/// Split a Bulk Coretime Region into `parts` consecutive non-overlapping Regions of equal
+		/// length.
+		///
+		/// Any remainder of the Region's length is spread over the earliest parts, one timeslice
+		/// each.
+		///
+		/// - `origin`: Must be a Signed origin of the account which owns the Region `region_id`.
+		/// - `region_id`: The Region which should be split.
+		/// - `parts`: The number of Regions to split `region_id` into. Must be at least two and at
+		///   most the length of the Region in timeslices.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::partition().saturating_mul(parts.saturating_sub(1).into()))]
+		pub fn split_evenly(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			parts: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_split_evenly(region_id, Some(who), parts)?;
+			Ok(())
+		}
+
+		/// Interlace a Bulk Coretime Region into one Region per task and assign each of them to
+		/// its task.
+		///
+		/// The interlace bits of the Region's mask are shared out as evenly as possible, in order,
+		/// with any remainder going to the earliest tasks.
+		///
+		/// - `origin`: Must be a Signed origin of the account which owns the Region `region_id`.
+		/// - `region_id`: The Region which should be interlaced.
+		/// - `tasks`: The tasks to assign the new Regions to. Must contain at least two and at most
+		///   as many tasks as there are interlace bits set in the Region's mask.
+		/// - `finality`: Indication of whether the assignments are final or provisional, as in
+		///   [`Pallet::assign`].
+		#[pallet::call_index(28)]
+		#[pallet::weight(
+			T::WeightInfo::interlace()
+				.saturating_mul(tasks.len().saturating_sub(1) as u64)
+				.saturating_add(T::WeightInfo::assign().saturating_mul(tasks.len() as u64))
+		)]
+		pub fn interlace_for_tasks(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			tasks: Vec<TaskId>,
+			finality: Finality,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_interlace_for_tasks(region_id, Some(who), tasks, finality)?;
+			Ok(if finality == Finality::Final { Pays::No } else { Pays::Yes }.into())
+		}
+
 		#[pallet::call_index(99)]
 		#[pallet::weight(T::WeightInfo::swap_leases())]
 		pub fn swap_leases(origin: OriginFor<T>, id: TaskId, other: TaskId) -> DispatchResult {
@@ -1809,6 +1809,111 @@ fn cannot_partition_invalid_offset() {
 	});
 }
 
+#[test]
+fn split_evenly_works() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_eq!(Regions::<Test>::get(region).unwrap().end, 7);
+		assert_ok!(Broker::split_evenly(RuntimeOrigin::signed(1), region, 2));
+
+		// The remaining timeslice goes to the first part.
+		let region1 = region;
+		let region2 = RegionId { begin: 6, ..region };
+		assert_eq!(Regions::<Test>::get(region1).unwrap().end, 6);
+		assert_eq!(Regions::<Test>::get(region2).unwrap().end, 7);
+		System::assert_last_event(
+			Event::RegionSplitEvenly { region_id: region, new_region_ids: vec![region1, region2] }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn cannot_split_evenly_into_invalid_number_of_parts() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_noop!(
+			Broker::split_evenly(RuntimeOrigin::signed(1), region, 1),
+			Error::<Test>::InvalidPartCount
+		);
+		assert_noop!(
+			Broker::split_evenly(RuntimeOrigin::signed(1), region, 4),
+			Error::<Test>::InvalidPartCount
+		);
+		assert_noop!(
+			Broker::split_evenly(RuntimeOrigin::signed(2), region, 3),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn interlace_for_tasks_works() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_ok!(Broker::interlace_for_tasks(
+			RuntimeOrigin::signed(1),
+			region,
+			vec![1001, 1002, 1003],
+			Final
+		));
+		let assignments = vec![
+			(RegionId { mask: CoreMask::from_chunk(0, 27), ..region }, 1001),
+			(RegionId { mask: CoreMask::from_chunk(27, 54), ..region }, 1002),
+			(RegionId { mask: CoreMask::from_chunk(54, 80), ..region }, 1003),
+		];
+		System::assert_last_event(
+			Event::RegionInterlacedForTasks { region_id: region, assignments }.into(),
+		);
+		advance_to(10);
+		assert_eq!(
+			CoretimeTrace::get(),
+			vec![(
+				6,
+				AssignCore {
+					core: 0,
+					begin: 8,
+					assignment: vec![(Task(1001), 19440), (Task(1002), 19440), (Task(1003), 18720),],
+					end_hint: None
+				}
+			),]
+		);
+	});
+}
+
+#[test]
+fn cannot_interlace_for_invalid_number_of_tasks() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_noop!(
+			Broker::interlace_for_tasks(RuntimeOrigin::signed(1), region, vec![1001], Final),
+			Error::<Test>::InvalidTaskCount
+		);
+		let (region1, _) = Broker::do_interlace(region, None, CoreMask::from_chunk(0, 2)).unwrap();
+		assert_noop!(
+			Broker::interlace_for_tasks(
+				RuntimeOrigin::signed(1),
+				region1,
+				vec![1001, 1002, 1003],
+				Final
+			),
+			Error::<Test>::InvalidTaskCount
+		);
+		assert_noop!(
+			Broker::interlace_for_tasks(RuntimeOrigin::signed(2), region1, vec![1001, 1002], Final),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
 #[test]
 fn cannot_interlace_invalid_pivot() {
 	TestExt::new().endow(1, 1000).execute_with(|| {
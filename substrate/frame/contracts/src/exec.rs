@@ -1720,9 +1720,10 @@ mod tests {
 	};
 	use assert_matches::assert_matches;
 	use codec::{Decode, Encode};
-	use frame_support::{assert_err, assert_ok, parameter_types};
+	use frame_support::{assert_err, assert_ok, dispatch::GetDispatchInfo, parameter_types};
 	use frame_system::{EventRecord, Phase};
 	use pallet_contracts_uapi::ReturnFlags;
+	use pallet_utility::BatchItemOutcome;
 	use pretty_assertions::assert_eq;
 	use sp_runtime::{traits::Hash, DispatchError};
 	use std::{cell::RefCell, collections::hash_map::HashMap, rc::Rc};
@@ -3335,6 +3336,18 @@ mod tests {
 			.unwrap();
 
 			let remark_hash = <Test as frame_system::Config>::Hashing::hash(b"Hello");
+			let remark_weight = RuntimeCall::System(frame_system::Call::remark_with_event {
+				remark: b"Hello".to_vec(),
+			})
+			.get_dispatch_info()
+			.call_weight;
+			let transfer_weight =
+				RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+					dest: CHARLIE,
+					value: 22,
+				})
+				.get_dispatch_info()
+				.call_weight;
 			assert_eq!(
 				System::events(),
 				vec![
@@ -3351,6 +3364,23 @@ mod tests {
 						event: MetaEvent::Utility(pallet_utility::Event::ItemCompleted),
 						topics: vec![],
 					},
+					EventRecord {
+						phase: Phase::Initialization,
+						event: MetaEvent::Utility(pallet_utility::Event::BatchResult {
+							outcomes: vec![
+								BatchItemOutcome::Executed { weight: remark_weight },
+								BatchItemOutcome::Failed {
+									error: frame_system::Error::<Test>::CallFiltered.into(),
+									weight: transfer_weight,
+								},
+								BatchItemOutcome::Skipped,
+							]
+							.try_into()
+							.unwrap(),
+							omitted: 0,
+						}),
+						topics: vec![],
+					},
 					EventRecord {
 						phase: Phase::Initialization,
 						event: MetaEvent::Utility(pallet_utility::Event::BatchInterrupted {
@@ -85,6 +85,7 @@ impl frame_system::Config for Test {
 }
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+	pub const FullShare: Perbill = Perbill::from_percent(100);
 }
 
 impl pallet_preimage::Config for Test {
@@ -93,6 +94,7 @@ impl pallet_preimage::Config for Test {
 	type Currency = Balances;
 	type ManagerOrigin = EnsureRoot<u64>;
 	type Consideration = ();
+	type UploadLifetime = ConstU64<10>;
 }
 
 impl pallet_scheduler::Config for Test {
@@ -104,6 +106,7 @@ impl pallet_scheduler::Config for Test {
 	type ScheduleOrigin = EnsureRoot<u64>;
 	type MaxScheduledPerBlock = ConstU32<100>;
 	type WeightInfo = ();
+	type ClassLimits = pallet_scheduler::WeightShares<FullShare, FullShare, ConstU64<{ u64::MAX }>>;
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
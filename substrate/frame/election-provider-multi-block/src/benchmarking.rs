@@ -53,6 +53,7 @@ mod benchmarks {
 
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		assert_eq!(CurrentPhase::<T>::get(), Phase::Snapshot(T::Pages::get()));
@@ -81,6 +82,7 @@ mod benchmarks {
 
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		// roll to the first block of the snapshot.
@@ -118,6 +120,7 @@ mod benchmarks {
 	fn on_initialize_into_signed() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		Pallet::<T>::roll_until_before_matches(|| {
@@ -140,6 +143,7 @@ mod benchmarks {
 	fn on_initialize_into_signed_validation() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		Pallet::<T>::roll_until_before_matches(|| {
@@ -160,6 +164,7 @@ mod benchmarks {
 	fn on_initialize_into_unsigned() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		Pallet::<T>::roll_until_before_matches(|| {
@@ -180,6 +185,7 @@ mod benchmarks {
 	fn export_non_terminal() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		// submit a full solution.
@@ -215,6 +221,7 @@ mod benchmarks {
 	fn export_terminal() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		// submit a full solution.
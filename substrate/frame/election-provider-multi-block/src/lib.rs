@@ -1476,6 +1476,55 @@ where
 		who
 	}
 
+	/// Make sure the data provider has enough targets and voters to fill all snapshot pages.
+	///
+	/// Runtimes, parachains in particular, are often benchmarked without genesis stakers. In that
+	/// case the snapshot would be (almost) empty, and the benchmarks would fail or not capture the
+	/// worst case. Only the stakers that are missing are added, so this is a noop for a data
+	/// provider with enough stakers.
+	#[cfg(feature = "runtime-benchmarks")]
+	pub(crate) fn fill_data_provider() {
+		let bounds = DataProviderBounds::default();
+
+		let mut targets = T::DataProvider::electable_targets_stateless(bounds).unwrap_or_default();
+		while (targets.len() as u32) < T::TargetSnapshotPerBlock::get() {
+			let target: T::AccountId =
+				frame_benchmarking::account("target", targets.len() as u32, 777);
+			T::DataProvider::add_target(target.clone());
+			targets.push(target);
+		}
+
+		// targets might also be voters, so only count the voters after adding them.
+		let wanted_voters = T::VoterSnapshotPerBlock::get().saturating_mul(T::Pages::get());
+		let mut voters =
+			T::DataProvider::electing_voters_stateless(bounds).unwrap_or_default().len() as u32;
+		let votes = (<T::DataProvider as ElectionDataProvider>::MaxVotesPerVoter::get() as usize)
+			.min(targets.len());
+		while voters < wanted_voters {
+			let voter: T::AccountId = frame_benchmarking::account("voter", voters, 777);
+			let voter_targets = targets
+				.iter()
+				.cycle()
+				.skip(voters as usize % targets.len())
+				.take(votes)
+				.cloned()
+				.collect::<Vec<_>>()
+				.try_into()
+				.expect("at most `MaxVotesPerVoter` targets are taken; qed");
+			// vary the stake, so that the solution is not trivial.
+			let stake = 1_000_000 + (voters as VoteWeight % 100) * 1_000;
+			T::DataProvider::add_voter(voter, stake, voter_targets);
+			voters.saturating_inc();
+		}
+
+		let desired_targets = T::DataProvider::desired_targets().unwrap_or_default();
+		if desired_targets.is_zero() || desired_targets as usize > targets.len() {
+			T::DataProvider::set_desired_targets(
+				(targets.len() as u32).min(<T::Verifier as Verifier>::MaxWinnersPerPage::get()),
+			);
+		}
+	}
+
 	/// Roll all pallets forward, for the given number of blocks.
 	pub(crate) fn roll_to(n: BlockNumberFor<T>, with_signed: bool, try_state: bool) {
 		let now = frame_system::Pallet::<T>::block_number();
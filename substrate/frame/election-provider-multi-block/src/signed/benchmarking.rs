@@ -94,6 +94,7 @@ mod benchmarks {
 	fn submit_page() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		crate::Pallet::<T>::roll_until_matches(|| {
@@ -121,6 +122,7 @@ mod benchmarks {
 	fn unset_page() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		crate::Pallet::<T>::roll_until_matches(|| {
@@ -34,6 +34,7 @@ mod benchmarks {
 	fn validate_unsigned() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		crate::Pallet::<T>::roll_until_matches(|| {
@@ -55,6 +56,7 @@ mod benchmarks {
 	fn submit_unsigned() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		// roll to unsigned phase open
@@ -81,6 +83,7 @@ mod benchmarks {
 	fn mine_solution(p: Linear<1, { T::Pages::get() }>) -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		// roll to unsigned phase open
@@ -42,6 +42,7 @@ mod benchmarks {
 	fn on_initialize_valid_non_terminal() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		// roll to signed validation, with a solution stored in the signed pallet
@@ -70,6 +71,7 @@ mod benchmarks {
 	fn on_initialize_valid_terminal() -> Result<(), BenchmarkError> {
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		// roll to signed validation, with a solution stored in the signed pallet
@@ -124,6 +126,7 @@ mod benchmarks {
 
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		// roll to signed validation, with a solution stored in the signed pallet
@@ -186,6 +189,7 @@ mod benchmarks {
 
 		#[cfg(test)]
 		crate::mock::ElectionStart::set(sp_runtime::traits::Bounded::max_value());
+		crate::Pallet::<T>::fill_data_provider();
 		crate::Pallet::<T>::start().unwrap();
 
 		// roll to signed validation, with a solution stored in the signed pallet, but this solution
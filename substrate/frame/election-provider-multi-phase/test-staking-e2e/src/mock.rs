@@ -118,6 +118,8 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = Moment;
 	type OnTimestampSet = ();
 	type MinimumPeriod = traits::ConstU32<5>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = traits::ConstU32<0>;
 	type WeightInfo = ();
 }
 
@@ -147,6 +149,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = ();
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 impl pallet_session::historical::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
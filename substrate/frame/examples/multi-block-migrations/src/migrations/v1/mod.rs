@@ -26,8 +26,10 @@ extern crate alloc;
 use super::PALLET_MIGRATIONS_ID;
 use crate::pallet::{Config, MyMap};
 use frame_support::{
-	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
-	pallet_prelude::PhantomData,
+	migrations::{
+		count_metered, steps_for_weight, MigrationId, SteppedMigration, SteppedMigrationError,
+	},
+	pallet_prelude::{Get, PhantomData},
 	weights::WeightMeter,
 };
 
@@ -73,6 +75,17 @@ impl<T: Config, W: weights::WeightInfo> SteppedMigration for LazyMigrationV1<T,
 		MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
 	}
 
+	/// The number of steps that the migration needs, assuming that every step gets the maximal
+	/// block weight.
+	///
+	/// Only used to report the progress, so it counts all values instead of only the ones after
+	/// the cursor.
+	fn estimated_steps(meter: &mut WeightMeter) -> Option<u32> {
+		let values =
+			count_metered(v0::MyMap::<T>::iter_keys(), T::DbWeight::get().reads(1), meter)?;
+		steps_for_weight(W::step().saturating_mul(values.into()), T::BlockWeights::get().max_block)
+	}
+
 	/// The actual logic of the migration.
 	///
 	/// This function is called repeatedly until it returns `Ok(None)`, indicating that the
@@ -54,6 +54,7 @@ impl pallet_migrations::Config for Runtime {
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
 	type MaxServiceWeight = MigratorServiceWeight;
+	type PauseOrigin = frame_system::EnsureRoot<u64>;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -35,7 +35,7 @@ type Block = frame_system::mocking::MockBlock<MockRuntime>;
 frame_support::construct_runtime!(
 	pub struct MockRuntime {
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>, HoldReason},
 		Example: pallet_example_storage_migration::{Pallet, Call, Storage},
 	}
 );
@@ -288,7 +288,7 @@ frame_support::construct_runtime!(
 	pub struct Runtime
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>, HoldReason},
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
 		Custom: custom::{Pallet, Call, ValidateUnsigned, Inherent},
 		Custom2: custom2::{Pallet, Call, ValidateUnsigned, Inherent},
@@ -413,6 +413,15 @@ impl pallet_balances::WeightInfo for BalancesWeights {
 	fn burn_keep_alive() -> Weight {
 		Weight::zero()
 	}
+	fn schedule_transfer() -> Weight {
+		Weight::zero()
+	}
+	fn cancel_scheduled_transfer() -> Weight {
+		Weight::zero()
+	}
+	fn execute_scheduled_transfer() -> Weight {
+		Weight::zero()
+	}
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
@@ -108,6 +108,8 @@ impl pallet_session::Config for Test {
 	type WeightInfo = ();
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 impl pallet_session::historical::Config for Test {
@@ -119,6 +121,7 @@ impl pallet_session::historical::Config for Test {
 impl pallet_authorship::Config for Test {
 	type FindAuthor = ();
 	type EventHandler = ();
+	type AuthorStatsWindow = ();
 }
 
 type Balance = u128;
@@ -133,6 +136,8 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ConstU64<3>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = ();
 }
 
@@ -182,7 +182,9 @@ pub mod v1 {
 pub mod v2 {
 	use super::*;
 	use frame_support::{
-		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		migrations::{
+			count_metered, steps_for_weight, MigrationId, SteppedMigration, SteppedMigrationError,
+		},
 		weights::WeightMeter,
 	};
 
@@ -239,6 +241,35 @@ pub mod v2 {
 			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 1, version_to: 2 }
 		}
 
+		/// Estimated from the entries of the old storage items, assuming that every step gets the
+		/// maximal block weight.
+		fn estimated_steps(meter: &mut WeightMeter) -> Option<u32> {
+			let read = T::DbWeight::get().reads(1);
+			let authorities =
+				count_metered(types_v1::UsernameAuthorities::<T>::iter_keys(), read, meter)?;
+			let usernames =
+				count_metered(types_v1::AccountOfUsername::<T>::iter_keys(), read, meter)?;
+			let identities = count_metered(IdentityOf::<T>::iter_keys(), read, meter)?;
+			let pending_usernames = count_metered(PendingUsernames::<T>::iter_keys(), read, meter)?;
+
+			let weight = T::WeightInfo::migration_v2_authority_step()
+				.saturating_add(T::WeightInfo::migration_v2_cleanup_authority_step())
+				.saturating_mul(authorities.into())
+				.saturating_add(
+					T::WeightInfo::migration_v2_username_step()
+						.saturating_add(T::WeightInfo::migration_v2_cleanup_username_step())
+						.saturating_mul(usernames.into()),
+				)
+				.saturating_add(
+					T::WeightInfo::migration_v2_identity_step().saturating_mul(identities.into()),
+				)
+				.saturating_add(
+					T::WeightInfo::migration_v2_pending_username_step()
+						.saturating_mul(pending_usernames.into()),
+				);
+			steps_for_weight(weight, T::BlockWeights::get().max_block)
+		}
+
 		fn step(
 			mut cursor: Option<Self::Cursor>,
 			meter: &mut WeightMeter,
@@ -138,6 +138,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = ();
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 impl pallet_session::historical::Config for Runtime {
@@ -149,6 +151,7 @@ impl pallet_session::historical::Config for Runtime {
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = ();
 	type EventHandler = ImOnline;
+	type AuthorStatsWindow = ();
 }
 
 parameter_types! {
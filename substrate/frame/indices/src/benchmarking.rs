@@ -166,6 +166,48 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn renew() -> Result<(), BenchmarkError> {
+		let period = T::ExpiryPeriod::get().ok_or(BenchmarkError::Weightless)?;
+		let account_index = T::AccountIndex::from(SEED);
+		// Setup accounts
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		// Claim the index
+		Pallet::<T>::claim(RawOrigin::Signed(caller.clone()).into(), account_index)?;
+		let expiry = Expiries::<T>::get(account_index).unwrap();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), account_index);
+
+		assert_eq!(Expiries::<T>::get(account_index), Some(expiry.saturating_add(period)));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reap() -> Result<(), BenchmarkError> {
+		T::ExpiryPeriod::get().ok_or(BenchmarkError::Weightless)?;
+		let account_index = T::AccountIndex::from(SEED);
+		// Setup accounts
+		let original: T::AccountId = account("original", 0, SEED);
+		T::Currency::make_free_balance_be(&original, BalanceOf::<T>::max_value());
+		let caller: T::AccountId = whitelisted_caller();
+		// Claim the index and let it expire
+		Pallet::<T>::claim(RawOrigin::Signed(original.clone()).into(), account_index)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		Expiries::<T>::insert(account_index, now);
+		frame_system::Pallet::<T>::set_block_number(
+			now.saturating_add(T::GracePeriod::get()).saturating_add(1u32.into()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), account_index);
+
+		assert_eq!(Accounts::<T>::get(account_index), None);
+		assert_eq!(T::Currency::reserved_balance(&original), Zero::zero());
+		Ok(())
+	}
+
 	// TODO in another PR: lookup and unlookup trait weights (not critical)
 
 	impl_benchmark_test_suite!(Pallet, mock::new_test_ext(), mock::Test);
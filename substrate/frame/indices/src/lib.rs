@@ -17,6 +17,11 @@
 
 //! An index is a short form of an address. This module handles allocation
 //! of indices for a newly created accounts.
+//!
+//! Runtimes can configure an [`Config::ExpiryPeriod`] after which claimed indices expire unless
+//! renewed by their owner. Once the [`Config::GracePeriod`] following the expiry has passed,
+//! anyone can reap the index, returning the deposit to its owner and making the index available
+//! again. Indices assigned at genesis, forcibly or frozen never expire.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -29,7 +34,9 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 use codec::Codec;
-use frame_support::traits::{BalanceStatus::Reserved, Currency, ReservableCurrency};
+use frame_support::traits::{
+	BalanceStatus::Reserved, Currency, ExistenceRequirement, ReservableCurrency, WithdrawReasons,
+};
 use sp_runtime::{
 	traits::{AtLeast32Bit, LookupError, Saturating, StaticLookup, Zero},
 	MultiAddress,
@@ -69,6 +76,22 @@ pub mod pallet {
 		#[pallet::constant]
 		type Deposit: Get<BalanceOf<Self>>;
 
+		/// The period after which a claimed index expires unless renewed.
+		///
+		/// `None` disables expiry, so that indices are claimed forever. Disabling it later also
+		/// stops the indices claimed while it was enabled from being reaped.
+		#[pallet::constant]
+		type ExpiryPeriod: Get<Option<BlockNumberFor<Self>>>;
+
+		/// The period after the expiry of an index during which it can still be renewed before
+		/// anyone can reap it.
+		#[pallet::constant]
+		type GracePeriod: Get<BlockNumberFor<Self>>;
+
+		/// The fee burned from the owner for renewing an index.
+		#[pallet::constant]
+		type RenewalFee: Get<BalanceOf<Self>>;
+
 		/// The overarching event type.
 		#[allow(deprecated)]
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -90,6 +113,8 @@ pub mod pallet {
 		///
 		/// - `index`: the index to be claimed. This must not be in use.
 		///
+		/// If an `ExpiryPeriod` is configured, the index expires after it unless renewed.
+		///
 		/// Emits `IndexAssigned` if successful.
 		///
 		/// ## Complexity
@@ -104,6 +129,10 @@ pub mod pallet {
 				*maybe_value = Some((who.clone(), T::Deposit::get(), false));
 				T::Currency::reserve(&who, T::Deposit::get())
 			})?;
+			if let Some(period) = T::ExpiryPeriod::get() {
+				let now = frame_system::Pallet::<T>::block_number();
+				Expiries::<T>::insert(index, now.saturating_add(period));
+			}
 			Self::deposit_event(Event::IndexAssigned { who, index });
 			Ok(())
 		}
@@ -167,6 +196,7 @@ pub mod pallet {
 				T::Currency::unreserve(&who, amount);
 				Ok(())
 			})?;
+			Expiries::<T>::remove(index);
 			Self::deposit_event(Event::IndexFreed { index });
 			Ok(())
 		}
@@ -201,6 +231,8 @@ pub mod pallet {
 				}
 				*maybe_value = Some((new.clone(), Zero::zero(), freeze));
 			});
+			// Forcibly assigned indices do not expire.
+			Expiries::<T>::remove(index);
 			Self::deposit_event(Event::IndexAssigned { who: new, index });
 			Ok(())
 		}
@@ -230,6 +262,8 @@ pub mod pallet {
 				*maybe_value = Some((account, Zero::zero(), true));
 				Ok(())
 			})?;
+			// Frozen indices do not expire.
+			Expiries::<T>::remove(index);
 			Self::deposit_event(Event::IndexFrozen { index, who });
 			Ok(())
 		}
@@ -291,6 +325,88 @@ pub mod pallet {
 				Ok(Pays::No.into())
 			})
 		}
+
+		/// Renew an expiring index owned by the sender.
+		///
+		/// Payment: `RenewalFee` is burned from the sender account and the deposit is topped up
+		/// to the current `Deposit`, if it has increased.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must own the index.
+		///
+		/// - `index`: the index to be renewed. It can be renewed at any time before being reaped.
+		///
+		/// The expiry is extended by `ExpiryPeriod`, counted from the current block if the index
+		/// has already expired.
+		///
+		/// Emits `IndexRenewed` if successful.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::renew())]
+		pub fn renew(origin: OriginFor<T>, index: T::AccountIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let period = T::ExpiryPeriod::get().ok_or(Error::<T>::NotExpiring)?;
+			let expiry = Expiries::<T>::get(index).ok_or(Error::<T>::NotExpiring)?;
+
+			Accounts::<T>::try_mutate(index, |maybe_value| -> DispatchResult {
+				let (account, amount, perm) =
+					maybe_value.as_mut().ok_or(Error::<T>::NotAssigned)?;
+				ensure!(!*perm, Error::<T>::Permanent);
+				ensure!(*account == who, Error::<T>::NotOwner);
+
+				let fee = T::RenewalFee::get();
+				if !fee.is_zero() {
+					// Dropping the imbalance burns the fee.
+					let _ = T::Currency::withdraw(
+						&who,
+						fee,
+						WithdrawReasons::FEE,
+						ExistenceRequirement::KeepAlive,
+					)?;
+				}
+				let deposit = T::Deposit::get();
+				if deposit > *amount {
+					T::Currency::reserve(&who, deposit.saturating_sub(*amount))?;
+					*amount = deposit;
+				}
+				Ok(())
+			})?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let expiry = expiry.max(now).saturating_add(period);
+			Expiries::<T>::insert(index, expiry);
+			Self::deposit_event(Event::IndexRenewed { index, who, expiry });
+			Ok(())
+		}
+
+		/// Reap an index whose expiry and grace period have passed.
+		///
+		/// Payment: The deposit is unreserved in the account of the owner.
+		///
+		/// The dispatch origin for this call must be _Signed_. Anyone can reap an expired index.
+		///
+		/// - `index`: the index to be reaped.
+		///
+		/// Emits `IndexReaped` if successful.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::reap())]
+		pub fn reap(origin: OriginFor<T>, index: T::AccountIndex) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(T::ExpiryPeriod::get().is_some(), Error::<T>::NotExpiring);
+			let expiry = Expiries::<T>::get(index).ok_or(Error::<T>::NotExpiring)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now > expiry.saturating_add(T::GracePeriod::get()), Error::<T>::NotExpired);
+
+			let (who, amount, _) = Accounts::<T>::take(index).ok_or(Error::<T>::NotAssigned)?;
+			T::Currency::unreserve(&who, amount);
+			Expiries::<T>::remove(index);
+			Self::deposit_event(Event::IndexReaped { index, who });
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -309,6 +425,10 @@ pub mod pallet {
 			old_deposit: BalanceOf<T>,
 			new_deposit: BalanceOf<T>,
 		},
+		/// An account index has been renewed until `expiry`.
+		IndexRenewed { index: T::AccountIndex, who: T::AccountId, expiry: BlockNumberFor<T> },
+		/// An expired account index has been reaped from its owner `who`.
+		IndexReaped { index: T::AccountIndex, who: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -323,6 +443,10 @@ pub mod pallet {
 		NotTransfer,
 		/// The index is permanent and may not be freed/changed.
 		Permanent,
+		/// The index does not expire.
+		NotExpiring,
+		/// The index has not expired or is still within its grace period.
+		NotExpired,
 	}
 
 	/// The lookup from index to account.
@@ -330,6 +454,11 @@ pub mod pallet {
 	pub type Accounts<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountIndex, (T::AccountId, BalanceOf<T>, bool)>;
 
+	/// The block at which an index expires, for indices claimed while expiry is enabled.
+	#[pallet::storage]
+	pub type Expiries<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountIndex, BlockNumberFor<T>>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
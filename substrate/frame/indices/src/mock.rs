@@ -20,13 +20,15 @@
 #![cfg(test)]
 
 use crate::{self as pallet_indices, Config};
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, parameter_types, traits::ConstU64};
 use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
 parameter_types! {
 	pub static IndexDeposit: u64 = 1;
+	pub static IndexExpiryPeriod: Option<u64> = None;
+	pub static IndexRenewalFee: u64 = 0;
 }
 
 frame_support::construct_runtime!(
@@ -55,6 +57,9 @@ impl Config for Test {
 	type AccountIndex = u64;
 	type Currency = Balances;
 	type Deposit = IndexDeposit;
+	type ExpiryPeriod = IndexExpiryPeriod;
+	type GracePeriod = ConstU64<5>;
+	type RenewalFee = IndexRenewalFee;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 }
@@ -235,3 +235,76 @@ fn poke_deposit_should_charge_fee_when_deposit_unchanged() {
 		)));
 	});
 }
+
+#[test]
+fn claimed_indices_do_not_expire_without_expiry_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Indices::claim(Some(1).into(), 0));
+		assert_eq!(Expiries::<Test>::get(0), None);
+		assert_noop!(Indices::renew(Some(1).into(), 0), Error::<Test>::NotExpiring);
+		System::set_block_number(1_000);
+		assert_noop!(Indices::reap(Some(2).into(), 0), Error::<Test>::NotExpiring);
+	});
+}
+
+#[test]
+fn renewing_should_work() {
+	new_test_ext().execute_with(|| {
+		IndexExpiryPeriod::set(Some(10));
+		IndexRenewalFee::set(2);
+		assert_ok!(Indices::claim(Some(1).into(), 0));
+		assert_eq!(Expiries::<Test>::get(0), Some(11));
+
+		assert_noop!(Indices::renew(Some(2).into(), 0), Error::<Test>::NotOwner);
+		assert_noop!(Indices::renew(Some(1).into(), 1), Error::<Test>::NotExpiring);
+
+		// Renewing before the expiry extends it, burns the fee and tops up the deposit.
+		IndexDeposit::set(3);
+		assert_ok!(Indices::renew(Some(1).into(), 0));
+		assert_eq!(Expiries::<Test>::get(0), Some(21));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_eq!(Balances::free_balance(1), 10 - 3 - 2);
+		System::assert_last_event(Event::IndexRenewed { index: 0, who: 1, expiry: 21 }.into());
+
+		// Renewing within the grace period counts from the current block.
+		System::set_block_number(24);
+		assert_ok!(Indices::renew(Some(1).into(), 0));
+		assert_eq!(Expiries::<Test>::get(0), Some(34));
+
+		// Frozen indices no longer expire.
+		assert_ok!(Indices::freeze(Some(1).into(), 0));
+		assert_eq!(Expiries::<Test>::get(0), None);
+		assert_noop!(Indices::renew(Some(1).into(), 0), Error::<Test>::NotExpiring);
+	});
+}
+
+#[test]
+fn reaping_should_work() {
+	new_test_ext().execute_with(|| {
+		IndexExpiryPeriod::set(Some(10));
+		assert_ok!(Indices::claim(Some(1).into(), 0));
+		assert_ok!(Indices::transfer(Some(1).into(), Id(2), 0));
+		assert_eq!(Balances::reserved_balance(2), 1);
+
+		// Not reapable until the grace period after the expiry at block 11 has passed.
+		System::set_block_number(16);
+		assert_noop!(Indices::reap(Some(3).into(), 0), Error::<Test>::NotExpired);
+		assert_eq!(Indices::lookup_index(0), Some(2));
+
+		System::set_block_number(17);
+		assert_ok!(Indices::reap(Some(3).into(), 0));
+		System::assert_last_event(Event::IndexReaped { index: 0, who: 2 }.into());
+		assert_eq!(Indices::lookup_index(0), None);
+		assert_eq!(Expiries::<Test>::get(0), None);
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		// The index can be claimed again.
+		assert_ok!(Indices::claim(Some(3).into(), 0));
+		assert_eq!(Expiries::<Test>::get(0), Some(27));
+
+		// Indices no longer expire once expiry is disabled.
+		IndexExpiryPeriod::set(None);
+		System::set_block_number(100);
+		assert_noop!(Indices::reap(Some(1).into(), 0), Error::<Test>::NotExpiring);
+	});
+}
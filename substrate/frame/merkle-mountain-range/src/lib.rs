@@ -395,6 +395,30 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		mmr.generate_proof(leaf_indices)
 	}
 
+	/// Generate a single MMR proof for all the blocks in `start_block..=end_block`.
+	///
+	/// The proof is generated for the MMR at the current block height. Like
+	/// [`Self::generate_proof`], this method can only be used from an off-chain context.
+	pub fn generate_proof_for_range(
+		start_block: BlockNumberFor<T>,
+		end_block: BlockNumberFor<T>,
+	) -> Result<(Vec<LeafOf<T, I>>, LeafProof<HashOf<T, I>>), Error> {
+		let best_block_number = <frame_system::Pallet<T>>::block_number();
+		if start_block > end_block || end_block > best_block_number {
+			return Err(Error::InvalidBlockRange.log_debug(
+				"The block range must be non-empty and end at or before the best block.",
+			))
+		}
+
+		let leaf_count = Self::block_num_to_leaf_count(best_block_number)?;
+		let start_leaf_index = Self::block_num_to_leaf_index(start_block)
+			.map_err(|_| Error::InvalidBlockRange.log_debug("The range starts before the MMR."))?;
+		let end_leaf_index = Self::block_num_to_leaf_index(end_block)?;
+
+		let mmr: ModuleMmr<mmr::storage::OffchainStorage, T, I> = mmr::Mmr::new(leaf_count);
+		mmr.generate_proof((start_leaf_index..=end_leaf_index).collect())
+	}
+
 	/// Verify MMR proof for given `leaves`.
 	///
 	/// This method is safe to use within the runtime code.
@@ -471,6 +471,50 @@ fn should_generate_batch_proof_correctly() {
 	});
 }
 
+#[test]
+fn should_generate_batch_proof_for_range_correctly() {
+	sp_tracing::init_for_tests();
+	let mut ext = new_test_ext();
+	// given
+	ext.execute_with(|| add_blocks(7));
+	ext.persist_offchain_overlay();
+
+	register_offchain_ext(&mut ext);
+	ext.execute_with(|| {
+		// when generate a proof for a range of blocks
+		let (leaves, proof) = crate::Pallet::<Test>::generate_proof_for_range(2, 5).unwrap();
+		// then it matches the proof for the same blocks
+		assert_eq!(
+			(leaves.clone(), proof.clone()),
+			crate::Pallet::<Test>::generate_proof(vec![2, 3, 4, 5], None).unwrap()
+		);
+		assert_eq!(proof.leaf_indices, vec![1, 2, 3, 4]);
+		assert_eq!(proof.leaf_count, 7);
+		assert_eq!(crate::Pallet::<Test>::verify_leaves(leaves, proof), Ok(()));
+
+		// when the range covers a single block
+		let (leaves, proof) = crate::Pallet::<Test>::generate_proof_for_range(7, 7).unwrap();
+		assert_eq!(leaves.len(), 1);
+		assert_eq!(proof.leaf_indices, vec![6]);
+
+		// when the range is empty
+		assert_eq!(
+			crate::Pallet::<Test>::generate_proof_for_range(5, 2),
+			Err(Error::InvalidBlockRange)
+		);
+		// when the range ends after the best block
+		assert_eq!(
+			crate::Pallet::<Test>::generate_proof_for_range(5, 8),
+			Err(Error::InvalidBlockRange)
+		);
+		// when the range starts before the first MMR block
+		assert_eq!(
+			crate::Pallet::<Test>::generate_proof_for_range(0, 3),
+			Err(Error::InvalidBlockRange)
+		);
+	});
+}
+
 #[test]
 fn should_verify() {
 	sp_tracing::init_for_tests();
@@ -27,6 +27,15 @@ use frame_support::traits::Get;
 use frame_system::RawOrigin;
 use sp_io::hashing::blake2_256;
 
+/// Make sure that the [`Config::DeadLetterDepositor`] of the queue `origin`, if any, can afford the
+/// deposit for a dead letter of maximal length.
+fn fund_dead_letter_depositor<T: Config>(origin: &MessageOriginOf<T>) {
+	if let Some(who) = T::DeadLetterDepositor::convert(origin.clone()) {
+		let footprint = Footprint::from_parts(1, MaxMessageLenOf::<T>::get() as usize);
+		T::DeadLetterConsideration::ensure_successful(&who, footprint);
+	}
+}
+
 #[benchmarks(
 	where
 		<<T as Config>::MessageProcessor as ProcessMessage>::Origin: From<u32> + PartialEq,
@@ -290,6 +299,59 @@ mod benchmarks {
 		assert!(Pages::<T>::contains_key(&origin, 0), "Page must be updated");
 	}
 
+	// Worst case for `note_dead_letter`: a message of maximal length which is kept with a deposit.
+	#[benchmark]
+	fn note_dead_letter() {
+		let origin: MessageOriginOf<T> = 1000.into();
+		let message = vec![0u8; MaxMessageLenOf::<T>::get() as usize];
+		fund_dead_letter_depositor::<T>(&origin);
+		let mut index = None;
+
+		#[block]
+		{
+			index = MessageQueue::<T>::note_dead_letter(
+				&origin,
+				&message,
+				ProcessMessageError::Corrupt,
+			);
+		}
+
+		if !T::MaxDeadLetters::get().is_zero() {
+			assert_eq!(index, Some(0), "Dead letter must be kept");
+		}
+	}
+
+	// Worst case for `reprocess_dead_letter`: a message of maximal length which is processed and
+	// whose deposit is refunded.
+	#[benchmark]
+	fn reprocess_dead_letter() {
+		let origin: MessageOriginOf<T> = 1000.into();
+		fund_dead_letter_depositor::<T>(&origin);
+		insert_dead_letter::<T>(&origin);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(whitelisted_caller()), origin.clone(), 0, Weight::MAX);
+
+		assert_last_event::<T>(
+			Event::DeadLetterReprocessed { origin: origin.clone(), index: 0 }.into(),
+		);
+		assert!(!DeadLetters::<T>::contains_key(&origin, 0), "Dead letter must be removed");
+	}
+
+	// Worst case for `purge_dead_letter`: a message of maximal length whose deposit is refunded.
+	#[benchmark]
+	fn purge_dead_letter() {
+		let origin: MessageOriginOf<T> = 1000.into();
+		fund_dead_letter_depositor::<T>(&origin);
+		insert_dead_letter::<T>(&origin);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, origin.clone(), 0);
+
+		assert_last_event::<T>(Event::DeadLetterPurged { origin: origin.clone(), index: 0 }.into());
+		assert!(!DeadLetters::<T>::contains_key(&origin, 0), "Dead letter must be removed");
+	}
+
 	impl_benchmark_test_suite! {
 		MessageQueue,
 		crate::mock::new_test_ext::<crate::integration_test::Test>(),
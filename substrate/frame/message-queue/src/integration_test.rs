@@ -37,7 +37,7 @@ use crate::{
 };
 
 use crate as pallet_message_queue;
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, parameter_types, traits::ConstU32};
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use rand_distr::Pareto;
 use std::collections::{BTreeMap, BTreeSet};
@@ -74,6 +74,9 @@ impl Config for Test {
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ();
+	type MaxDeadLetters = ConstU32<16>;
+	type DeadLetterDepositor = ();
+	type DeadLetterConsideration = ();
 }
 
 /// The object that does the AHM message prioritization for us.
@@ -212,11 +212,11 @@ use frame_support::{
 	defensive,
 	pallet_prelude::*,
 	traits::{
-		BatchesFootprints, Defensive, DefensiveSaturating, DefensiveTruncateFrom, EnqueueMessage,
-		ExecuteOverweightError, Footprint, ProcessMessage, ProcessMessageError, QueueFootprint,
-		QueueFootprintQuery, QueuePausedQuery, ServiceQueues,
+		BatchesFootprints, Consideration, Defensive, DefensiveSaturating, DefensiveTruncateFrom,
+		EnqueueMessage, ExecuteOverweightError, Footprint, ProcessMessage, ProcessMessageError,
+		QueueFootprint, QueueFootprintQuery, QueuePausedQuery, ServiceQueues,
 	},
-	BoundedSlice, CloneNoBound, DefaultNoBound,
+	BoundedSlice, CloneNoBound, DefaultNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
@@ -224,7 +224,7 @@ use scale_info::TypeInfo;
 use sp_arithmetic::traits::{BaseArithmetic, Unsigned};
 use sp_core::{defer, H256};
 use sp_runtime::{
-	traits::{One, Zero},
+	traits::{Convert, One, Zero},
 	SaturatedConversion, Saturating, TransactionOutcome,
 };
 use sp_weights::WeightMeter;
@@ -473,6 +473,37 @@ impl<MessageOrigin> From<BookState<MessageOrigin>> for QueueFootprint {
 	}
 }
 
+/// The dead letters of a queue.
+#[derive(Clone, Default, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, PartialEq)]
+pub struct DeadLetterBook {
+	/// The number of dead letters stored at present.
+	count: u32,
+	/// The index of the next dead letter.
+	next_index: u32,
+}
+
+/// A message which failed permanently and is kept until it is reprocessed or purged.
+#[derive(
+	CloneNoBound, Encode, Decode, RuntimeDebugNoBound, PartialEqNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxMessageLen))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Ticket: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct DeadLetter<
+	AccountId: Clone + Debug + PartialEq,
+	Ticket: Clone + Debug + PartialEq,
+	BlockNumber: Clone + Debug + PartialEq,
+	MaxMessageLen: Get<u32>,
+> {
+	/// The message.
+	pub message: BoundedVec<u8, MaxMessageLen>,
+	/// The error with which the processing of the message failed.
+	pub error: ProcessMessageError,
+	/// The block in which the processing of the message failed.
+	pub failed_at: BlockNumber,
+	/// The account that paid the deposit for keeping the message, and its ticket.
+	pub deposit: Option<(AccountId, Ticket)>,
+}
+
 /// Handler code for when the items in a queue change.
 pub trait OnQueueChanged<Id> {
 	/// Note that the queue `id` now has `item_count` items in it, taking up `items_size` bytes.
@@ -577,6 +608,29 @@ pub mod pallet {
 		/// If `None`, it will not call `ServiceQueues::service_queues` in `on_idle`.
 		#[pallet::constant]
 		type IdleMaxServiceWeight: Get<Option<Weight>>;
+
+		/// The maximum number of dead letters kept per queue.
+		///
+		/// Messages that fail with [`ProcessMessageError::BadFormat`],
+		/// [`ProcessMessageError::Corrupt`] or [`ProcessMessageError::Unsupported`] are kept as
+		/// dead letters until they are reprocessed or purged. Once a queue holds this many dead
+		/// letters, further failing messages are discarded. Zero disables dead letters.
+		///
+		/// A failing message stays in its queue until there is enough weight to keep it.
+		#[pallet::constant]
+		type MaxDeadLetters: Get<u32>;
+
+		/// The account that pays the deposit for the dead letters of a queue, e.g. its sovereign
+		/// account.
+		///
+		/// The dead letters of queues without an account are kept without a deposit.
+		type DeadLetterDepositor: Convert<MessageOriginOf<Self>, Option<Self::AccountId>>;
+
+		/// The deposit for keeping a dead letter, taken from the [`Config::DeadLetterDepositor`],
+		/// e.g. held with [`HoldReason::DeadLetter`].
+		///
+		/// A failing message is discarded if the deposit cannot be taken.
+		type DeadLetterConsideration: Consideration<Self::AccountId, Footprint>;
 	}
 
 	#[pallet::event]
@@ -628,6 +682,36 @@ pub mod pallet {
 			/// The index of the page.
 			index: PageIndex,
 		},
+		/// A permanently failing message was kept as dead letter.
+		DeadLetterStored {
+			/// The `blake2_256` hash of the message.
+			id: H256,
+			/// The queue of the message.
+			origin: MessageOriginOf<T>,
+			/// The index of the dead letter within its queue.
+			index: u32,
+		},
+		/// A dead letter was processed and removed.
+		DeadLetterReprocessed {
+			/// The queue of the dead letter.
+			origin: MessageOriginOf<T>,
+			/// The index of the dead letter within its queue.
+			index: u32,
+		},
+		/// A dead letter was removed without being processed.
+		DeadLetterPurged {
+			/// The queue of the dead letter.
+			origin: MessageOriginOf<T>,
+			/// The index of the dead letter within its queue.
+			index: u32,
+		},
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The funds are held as deposit for a dead letter.
+		DeadLetter,
 	}
 
 	#[pallet::error]
@@ -656,6 +740,10 @@ pub mod pallet {
 		QueuePaused,
 		/// Another call is in progress and needs to finish before this call can happen.
 		RecursiveDisallowed,
+		/// The referenced dead letter could not be found.
+		NoDeadLetter,
+		/// The dead letter still fails with a permanent error.
+		DeadLetterUnprocessable,
 	}
 
 	/// The index of the first and last (non-empty) pages.
@@ -679,6 +767,23 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The dead-letter book of each queue.
+	#[pallet::storage]
+	pub type DeadLetterBookFor<T: Config> =
+		StorageMap<_, Twox64Concat, MessageOriginOf<T>, DeadLetterBook, ValueQuery>;
+
+	/// The dead letters of each queue by their index.
+	#[pallet::storage]
+	pub type DeadLetters<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MessageOriginOf<T>,
+		Twox64Concat,
+		u32,
+		DeadLetterOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
@@ -757,6 +862,57 @@ pub mod pallet {
 				Self::do_execute_overweight(message_origin, page, index, weight_limit)?;
 			Ok(Some(actual_weight).into())
 		}
+
+		/// Process a dead letter again.
+		///
+		/// The dead letter is removed and its deposit refunded once the message is processed. It
+		/// is kept if processing fails again.
+		///
+		/// - `origin`: Must be `Signed`.
+		/// - `message_origin`: The queue of the dead letter.
+		/// - `index`: The index of the dead letter within its queue.
+		/// - `weight_limit`: The maximum amount of weight allowed to be consumed in the execution
+		///   of the message.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::reprocess_dead_letter().saturating_add(*weight_limit))]
+		pub fn reprocess_dead_letter(
+			origin: OriginFor<T>,
+			message_origin: MessageOriginOf<T>,
+			index: u32,
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let actual_weight =
+				Self::do_reprocess_dead_letter(message_origin, index, weight_limit)?;
+			Ok(Some(actual_weight).into())
+		}
+
+		/// Remove a dead letter without processing it and refund its deposit.
+		///
+		/// - `origin`: Must be `Root` or the account that paid the deposit of the dead letter.
+		/// - `message_origin`: The queue of the dead letter.
+		/// - `index`: The index of the dead letter within its queue.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::purge_dead_letter())]
+		pub fn purge_dead_letter(
+			origin: OriginFor<T>,
+			message_origin: MessageOriginOf<T>,
+			index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed_or_root(origin)?;
+			let letter =
+				DeadLetters::<T>::get(&message_origin, index).ok_or(Error::<T>::NoDeadLetter)?;
+			if let Some(who) = who {
+				ensure!(
+					letter.deposit.as_ref().is_some_and(|(depositor, _)| depositor == &who),
+					DispatchError::BadOrigin
+				);
+			}
+
+			Self::remove_dead_letter(&message_origin, index, letter);
+			Self::deposit_event(Event::<T>::DeadLetterPurged { origin: message_origin, index });
+			Ok(())
+		}
 	}
 }
 
@@ -949,6 +1105,16 @@ impl<T: Config> Pallet<T> {
 			)
 			.saturating_add(T::WeightInfo::service_page_item())
 			.saturating_add(T::WeightInfo::ready_ring_unknit())
+			.saturating_add(Self::dead_letter_overhead())
+	}
+
+	/// The overhead of keeping a permanently failing message as dead letter.
+	fn dead_letter_overhead() -> Weight {
+		if T::MaxDeadLetters::get().is_zero() {
+			Weight::zero()
+		} else {
+			T::WeightInfo::note_dead_letter()
+		}
 	}
 
 	/// Checks invariants of the pallet config.
@@ -1140,6 +1306,109 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Process the dead letter `index` of the queue `origin` again.
+	///
+	/// The dead letter is removed on success. All changes are reverted if processing fails.
+	pub fn do_reprocess_dead_letter(
+		origin: MessageOriginOf<T>,
+		index: u32,
+		weight_limit: Weight,
+	) -> Result<Weight, DispatchError> {
+		match with_service_mutex(|| {
+			storage::with_storage_layer(|| {
+				Self::do_reprocess_dead_letter_inner(origin, index, weight_limit)
+			})
+		}) {
+			Err(()) => Err(Error::<T>::RecursiveDisallowed.into()),
+			Ok(x) => x,
+		}
+	}
+
+	/// Same as `do_reprocess_dead_letter` but must be called while holding the `service_mutex`.
+	fn do_reprocess_dead_letter_inner(
+		origin: MessageOriginOf<T>,
+		index: u32,
+		weight_limit: Weight,
+	) -> Result<Weight, DispatchError> {
+		ensure!(!T::QueuePausedQuery::is_paused(&origin), Error::<T>::QueuePaused);
+		let letter = DeadLetters::<T>::get(&origin, index).ok_or(Error::<T>::NoDeadLetter)?;
+
+		use MessageExecutionStatus::*;
+		let mut weight_counter = WeightMeter::with_limit(weight_limit);
+		match Self::process_message_payload(
+			origin.clone(),
+			// The page and message index are only used for overweight events, which are never
+			// emitted with an unlimited overweight limit.
+			Zero::zero(),
+			Zero::zero(),
+			&letter.message,
+			&mut weight_counter,
+			Weight::MAX,
+		) {
+			Overweight | InsufficientWeight => Err(Error::<T>::InsufficientWeight.into()),
+			StackLimitReached | Unprocessable { permanent: false } =>
+				Err(Error::<T>::TemporarilyUnprocessable.into()),
+			Unprocessable { permanent: true } => Err(Error::<T>::DeadLetterUnprocessable.into()),
+			Processed => {
+				Self::remove_dead_letter(&origin, index, letter);
+				Self::deposit_event(Event::<T>::DeadLetterReprocessed { origin, index });
+				Ok(weight_counter.consumed().saturating_add(T::WeightInfo::reprocess_dead_letter()))
+			},
+		}
+	}
+
+	/// Keep a permanently failing `message` of the queue `origin` as dead letter.
+	///
+	/// Returns the index of the dead letter, or `None` if the message is discarded because dead
+	/// letters are disabled, the queue holds [`Config::MaxDeadLetters`] already or the deposit
+	/// could not be taken.
+	pub(crate) fn note_dead_letter(
+		origin: &MessageOriginOf<T>,
+		message: &[u8],
+		error: ProcessMessageError,
+	) -> Option<u32> {
+		let max_dead_letters = T::MaxDeadLetters::get();
+		if max_dead_letters.is_zero() {
+			return None
+		}
+
+		let mut book = DeadLetterBookFor::<T>::get(origin);
+		if book.count >= max_dead_letters {
+			return None
+		}
+
+		let message = BoundedVec::try_from(message.to_vec()).defensive().ok()?;
+		let footprint = Footprint::from_parts(1, message.len());
+		let deposit = T::DeadLetterDepositor::convert(origin.clone())
+			.map(|who| T::DeadLetterConsideration::new(&who, footprint).map(|t| (who, t)))
+			.transpose()
+			.ok()?;
+
+		let index = book.next_index;
+		let failed_at = frame_system::Pallet::<T>::block_number();
+		DeadLetters::<T>::insert(origin, index, DeadLetter { message, error, failed_at, deposit });
+		book.count.saturating_inc();
+		book.next_index.saturating_inc();
+		DeadLetterBookFor::<T>::insert(origin, book);
+		Some(index)
+	}
+
+	/// Remove the dead letter `index` of the queue `origin` and refund its deposit.
+	fn remove_dead_letter(origin: &MessageOriginOf<T>, index: u32, letter: DeadLetterOf<T>) {
+		DeadLetters::<T>::remove(origin, index);
+		let mut book = DeadLetterBookFor::<T>::get(origin);
+		book.count.defensive_saturating_dec();
+		if book.count.is_zero() {
+			DeadLetterBookFor::<T>::remove(origin);
+		} else {
+			DeadLetterBookFor::<T>::insert(origin, book);
+		}
+
+		if let Some((who, ticket)) = letter.deposit {
+			let _ = ticket.drop(&who).defensive();
+		}
+	}
+
 	/// Remove a stale page or one which has no more messages remaining to be processed.
 	fn do_reap_page(origin: &MessageOriginOf<T>, page_index: PageIndex) -> DispatchResult {
 		match with_service_mutex(|| Self::do_reap_page_inner(origin, page_index)) {
@@ -1435,6 +1704,21 @@ impl<T: Config> Pallet<T> {
 			ensure!(fp.ready_pages <= fp.pages, "There cannot be more ready than total pages");
 		}
 
+		// Every dead-letter book must count the dead letters of its queue
+		for (origin, book) in DeadLetterBookFor::<T>::iter() {
+			ensure!(book.count > 0, "Empty dead-letter books must be removed");
+			ensure!(book.count <= book.next_index, "Dead-letter count exceeds the next index");
+			ensure!(
+				DeadLetters::<T>::iter_prefix(&origin).count() as u32 == book.count,
+				"Dead-letter count must match the stored dead letters"
+			);
+		}
+		ensure!(
+			DeadLetters::<T>::iter_keys()
+				.all(|(origin, _)| DeadLetterBookFor::<T>::contains_key(origin)),
+			"Dead letters must have a dead-letter book"
+		);
+
 		//loop around this origin
 		let Some(starting_origin) = ServiceHead::<T>::get() else { return Ok(()) };
 
@@ -1608,8 +1892,23 @@ impl<T: Config> Pallet<T> {
 				MessageExecutionStatus::Unprocessable { permanent: false }
 			},
 			Err(error @ BadFormat | error @ Corrupt | error @ Unsupported) => {
-				// Permanent error - drop
-				Self::deposit_event(Event::<T>::ProcessingFailed { id: id.into(), origin, error });
+				// Permanent error - keep as dead letter or drop. The message stays in the queue
+				// if the weight to keep it is missing.
+				if meter.try_consume(Self::dead_letter_overhead()).is_err() {
+					return MessageExecutionStatus::InsufficientWeight
+				}
+				Self::deposit_event(Event::<T>::ProcessingFailed {
+					id: id.into(),
+					origin: origin.clone(),
+					error,
+				});
+				if let Some(index) = Self::note_dead_letter(&origin, message, error) {
+					Self::deposit_event(Event::<T>::DeadLetterStored {
+						id: id.into(),
+						origin,
+						index,
+					});
+				}
 				MessageExecutionStatus::Unprocessable { permanent: true }
 			},
 			Err(error @ StackLimitReached) => {
@@ -1738,6 +2037,13 @@ pub type HeapSizeU32Of<T> = IntoU32<<T as Config>::HeapSize, <T as Config>::Size
 pub type PageOf<T> = Page<<T as Config>::Size, <T as Config>::HeapSize>;
 /// The [`BookState`] of this pallet.
 pub type BookStateOf<T> = BookState<MessageOriginOf<T>>;
+/// The [`DeadLetter`] of this pallet.
+pub type DeadLetterOf<T> = DeadLetter<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::DeadLetterConsideration,
+	BlockNumberFor<T>,
+	MaxMessageLenOf<T>,
+>;
 
 /// Converts a [`sp_core::Get`] with returns a type that can be cast into an `u32` into a `Get`
 /// which returns an `u32`.
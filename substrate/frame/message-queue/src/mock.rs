@@ -45,6 +45,7 @@ parameter_types! {
 	pub const HeapSize: u32 = 40;
 	pub const MaxStale: u32 = 2;
 	pub const ServiceWeight: Option<Weight> = Some(Weight::from_parts(100, 100));
+	pub static MaxDeadLetters: u32 = 0;
 }
 
 impl Config for Test {
@@ -58,6 +59,9 @@ impl Config for Test {
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ServiceWeight;
+	type MaxDeadLetters = MaxDeadLetters;
+	type DeadLetterDepositor = MockedDeadLetterDepositor;
+	type DeadLetterConsideration = ();
 }
 
 /// Mocked `WeightInfo` impl with allows to set the weight per call.
@@ -146,6 +150,24 @@ impl crate::weights::WeightInfo for MockedWeightInfo {
 			.copied()
 			.unwrap_or(DefaultWeightForCall::get())
 	}
+	fn note_dead_letter() -> Weight {
+		WeightForCall::get()
+			.get("note_dead_letter")
+			.copied()
+			.unwrap_or(DefaultWeightForCall::get())
+	}
+	fn reprocess_dead_letter() -> Weight {
+		WeightForCall::get()
+			.get("reprocess_dead_letter")
+			.copied()
+			.unwrap_or(DefaultWeightForCall::get())
+	}
+	fn purge_dead_letter() -> Weight {
+		WeightForCall::get()
+			.get("purge_dead_letter")
+			.copied()
+			.unwrap_or(DefaultWeightForCall::get())
+	}
 }
 
 parameter_types! {
@@ -303,6 +325,17 @@ impl QueuePausedQuery<MessageOrigin> for MockedQueuePauser {
 	}
 }
 
+/// Dead letters of `Everywhere(n)` are kept with a deposit of account `n`, all others without.
+pub struct MockedDeadLetterDepositor;
+impl Convert<MessageOrigin, Option<u64>> for MockedDeadLetterDepositor {
+	fn convert(origin: MessageOrigin) -> Option<u64> {
+		match origin {
+			MessageOrigin::Everywhere(n) => Some(n.into()),
+			_ => None,
+		}
+	}
+}
+
 /// Create new test externalities.
 ///
 /// Is generic since it is used by the unit test, integration tests and benchmarks.
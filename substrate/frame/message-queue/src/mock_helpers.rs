@@ -22,7 +22,7 @@
 //! Cannot be put into mock.rs since benchmarks require no-std and mock.rs is std.
 
 use crate::*;
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 use frame_support::traits::Defensive;
 
 /// Converts `Self` into a `Weight` by using `Self` for all components.
@@ -140,6 +140,32 @@ pub fn book_for<T: Config>(page: &PageOf<T>) -> BookStateOf<T> {
 	}
 }
 
+/// Insert a dead letter of maximal length as index `0` of the queue `origin`.
+///
+/// The deposit is taken from the [`Config::DeadLetterDepositor`] of the queue if it can afford it.
+pub fn insert_dead_letter<T: Config>(
+	origin: &<<T as Config>::MessageProcessor as ProcessMessage>::Origin,
+) {
+	let message = vec![0u8; MaxMessageLenOf::<T>::get() as usize];
+	let footprint = Footprint::from_parts(1, message.len());
+	let deposit = T::DeadLetterDepositor::convert(origin.clone()).and_then(|who| {
+		T::DeadLetterConsideration::new(&who, footprint)
+			.ok()
+			.map(|ticket| (who, ticket))
+	});
+	DeadLetters::<T>::insert(
+		origin,
+		0,
+		DeadLetter {
+			message: BoundedVec::defensive_truncate_from(message),
+			error: ProcessMessageError::Corrupt,
+			failed_at: frame_system::Pallet::<T>::block_number(),
+			deposit,
+		},
+	);
+	DeadLetterBookFor::<T>::insert(origin, DeadLetterBook { count: 1, next_index: 1 });
+}
+
 /// Assert the last event that was emitted.
 #[cfg(any(feature = "std", feature = "runtime-benchmarks", test))]
 pub fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
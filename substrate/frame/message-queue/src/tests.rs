@@ -2220,3 +2220,167 @@ fn get_batches_footprints_works() {
 		check_get_batches_footprints(There, &[max_message_len], 1, 0, vec![1]);
 	});
 }
+
+#[test]
+fn permanently_failing_message_is_kept_as_dead_letter() {
+	build_and_execute::<Test>(|| {
+		MaxDeadLetters::set(2);
+		let origin = MessageOrigin::Everywhere(7);
+		MessageQueue::enqueue_message(msg("badformat"), origin);
+		MessageQueue::service_queues(10.into_weight());
+
+		assert_last_event::<Test>(
+			Event::DeadLetterStored { id: blake2_256(b"badformat").into(), origin, index: 0 }
+				.into(),
+		);
+		let letter = DeadLetters::<Test>::get(origin, 0).unwrap();
+		assert_eq!(letter.message.into_inner(), vmsg("badformat"));
+		assert_eq!(letter.error, ProcessMessageError::BadFormat);
+		assert_eq!(letter.failed_at, 1);
+		assert_eq!(letter.deposit, Some((7, ())));
+		assert_eq!(DeadLetterBookFor::<Test>::get(origin).count, 1);
+		// The message itself is gone from the queue.
+		assert_eq!(BookStateFor::<Test>::get(origin).message_count, 0);
+	});
+}
+
+#[test]
+fn permanently_failing_message_stays_queued_without_weight_for_dead_letter() {
+	build_and_execute::<Test>(|| {
+		MaxDeadLetters::set(2);
+		set_weight("note_dead_letter", 2.into_weight());
+		let origin = MessageOrigin::Here;
+		MessageQueue::enqueue_message(msg("corrupt"), origin);
+
+		// The message cannot be kept, so it is not dropped either.
+		MessageQueue::service_queues(1.into_weight());
+		assert!(System::events().iter().all(|record| !matches!(
+			record.event,
+			RuntimeEvent::MessageQueue(Event::ProcessingFailed { .. })
+		)));
+		assert_eq!(BookStateFor::<Test>::get(origin).message_count, 1);
+		assert_eq!(DeadLetters::<Test>::iter().count(), 0);
+
+		MessageQueue::service_queues(2.into_weight());
+		assert_last_event::<Test>(
+			Event::DeadLetterStored { id: blake2_256(b"corrupt").into(), origin, index: 0 }.into(),
+		);
+		assert_eq!(BookStateFor::<Test>::get(origin).message_count, 0);
+	});
+}
+
+#[test]
+fn dead_letters_are_disabled_by_default() {
+	build_and_execute::<Test>(|| {
+		MessageQueue::enqueue_message(msg("corrupt"), MessageOrigin::Here);
+		MessageQueue::service_queues(10.into_weight());
+
+		assert_last_event::<Test>(
+			Event::ProcessingFailed {
+				id: blake2_256(b"corrupt").into(),
+				origin: MessageOrigin::Here,
+				error: ProcessMessageError::Corrupt,
+			}
+			.into(),
+		);
+		assert_eq!(DeadLetters::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn dead_letters_respect_max_dead_letters() {
+	build_and_execute::<Test>(|| {
+		MaxDeadLetters::set(2);
+		for m in ["a badformat", "b corrupt", "c unsupported"] {
+			MessageQueue::enqueue_message(msg(m), MessageOrigin::Here);
+		}
+		MessageQueue::enqueue_message(msg("unsupported"), MessageOrigin::There);
+		MessageQueue::service_queues(100.into_weight());
+
+		// The third message of `Here` is discarded, the queue `There` is not affected.
+		assert_eq!(DeadLetters::<Test>::iter_prefix(MessageOrigin::Here).count(), 2);
+		assert_eq!(DeadLetters::<Test>::iter_prefix(MessageOrigin::There).count(), 1);
+		assert_eq!(DeadLetterBookFor::<Test>::get(MessageOrigin::Here).count, 2);
+		// Dead letters of queues without depositor are kept without deposit.
+		assert!(DeadLetters::<Test>::get(MessageOrigin::There, 0).unwrap().deposit.is_none());
+	});
+}
+
+#[test]
+fn reprocess_dead_letter_works() {
+	build_and_execute::<Test>(|| {
+		MaxDeadLetters::set(1);
+		let origin = MessageOrigin::Here;
+		Callback::set(Box::new(|_, _| Err(())));
+		MessageQueue::enqueue_message(msg("callback=0"), origin);
+		MessageQueue::service_queues(10.into_weight());
+		assert!(DeadLetters::<Test>::contains_key(origin, 0));
+
+		// Still failing.
+		assert_noop!(
+			MessageQueue::reprocess_dead_letter(RuntimeOrigin::signed(1), origin, 0, Weight::MAX),
+			Error::<Test>::DeadLetterUnprocessable
+		);
+		assert_noop!(
+			MessageQueue::reprocess_dead_letter(RuntimeOrigin::signed(1), origin, 1, Weight::MAX),
+			Error::<Test>::NoDeadLetter
+		);
+		assert_noop!(
+			MessageQueue::reprocess_dead_letter(
+				RuntimeOrigin::signed(1),
+				origin,
+				0,
+				Weight::zero()
+			),
+			Error::<Test>::InsufficientWeight
+		);
+		PausedQueues::set(vec![origin]);
+		assert_noop!(
+			MessageQueue::reprocess_dead_letter(RuntimeOrigin::signed(1), origin, 0, Weight::MAX),
+			Error::<Test>::QueuePaused
+		);
+		PausedQueues::take();
+
+		// Processing works once the cause of the failure is gone.
+		Callback::set(Box::new(|_, _| Ok(())));
+		assert_ok!(MessageQueue::reprocess_dead_letter(
+			RuntimeOrigin::signed(1),
+			origin,
+			0,
+			Weight::MAX
+		));
+		assert_last_event::<Test>(Event::DeadLetterReprocessed { origin, index: 0 }.into());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("callback=0"), origin)]);
+		assert!(!DeadLetters::<Test>::contains_key(origin, 0));
+		assert!(!DeadLetterBookFor::<Test>::contains_key(origin));
+	});
+}
+
+#[test]
+fn purge_dead_letter_works() {
+	build_and_execute::<Test>(|| {
+		MaxDeadLetters::set(2);
+		let origin = MessageOrigin::Everywhere(7);
+		MessageQueue::enqueue_message(msg("a corrupt"), origin);
+		MessageQueue::enqueue_message(msg("b corrupt"), origin);
+		MessageQueue::service_queues(10.into_weight());
+
+		// Only root and the depositor can purge.
+		assert_noop!(
+			MessageQueue::purge_dead_letter(RuntimeOrigin::signed(1), origin, 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			MessageQueue::purge_dead_letter(RuntimeOrigin::root(), origin, 2),
+			Error::<Test>::NoDeadLetter
+		);
+		assert_ok!(MessageQueue::purge_dead_letter(RuntimeOrigin::signed(7), origin, 0));
+		assert_last_event::<Test>(Event::DeadLetterPurged { origin, index: 0 }.into());
+		assert_eq!(DeadLetterBookFor::<Test>::get(origin).count, 1);
+
+		assert_ok!(MessageQueue::purge_dead_letter(RuntimeOrigin::root(), origin, 1));
+		assert_eq!(DeadLetters::<Test>::iter().count(), 0);
+		assert!(!DeadLetterBookFor::<Test>::contains_key(origin));
+		assert!(MessagesProcessed::get().is_empty());
+	});
+}
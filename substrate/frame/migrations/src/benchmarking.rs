@@ -191,6 +191,47 @@ mod benches {
 		_(RawOrigin::Root);
 	}
 
+	#[benchmark]
+	fn pause() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Cursor::<T>::set(Some(cursor::<T>()));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(PausedAt::<T>::exists());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resume() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Cursor::<T>::set(Some(cursor::<T>()));
+		PausedAt::<T>::put(BlockNumberFor::<T>::from(0u32));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(!PausedAt::<T>::exists());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn force_skip_current() -> Result<(), BenchmarkError> {
+		let origin =
+			T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Cursor::<T>::set(Some(cursor::<T>()));
+		ActiveSteps::<T>::put((0, 1));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(!ActiveSteps::<T>::exists());
+		Ok(())
+	}
+
 	#[benchmark]
 	fn clear_historic(n: Linear<0, { DEFAULT_HISTORIC_BATCH_CLEAR_SIZE * 2 }>) {
 		let id_max_len = <T as Config>::IdentifierMaxLen::get();
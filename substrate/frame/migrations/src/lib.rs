@@ -148,6 +148,7 @@ mod benchmarking;
 pub mod migrations;
 mod mock;
 pub mod mock_helpers;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
@@ -171,7 +172,7 @@ use frame_system::{
 	pallet_prelude::{BlockNumberFor, *},
 	Pallet as System,
 };
-use sp_runtime::Saturating;
+use sp_runtime::{Percent, Saturating};
 
 /// Points to the next migration to execute.
 #[derive(
@@ -281,6 +282,80 @@ impl<Id> HistoricCleanupSelector<Id> {
 	}
 }
 
+/// The state of a migration, as reported by [`runtime_api::MigrationsApi`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo)]
+pub enum MigrationState {
+	/// The migration has not started yet.
+	Pending,
+	/// The migration is currently being executed.
+	Ongoing,
+	/// The migration completed or was skipped since it was already executed in the past.
+	Completed,
+	/// The upgrade got stuck before completing the migration.
+	Stuck,
+}
+
+/// The status of a migration, as reported by [`runtime_api::MigrationsApi`].
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo)]
+pub struct MigrationStatus {
+	/// The index of the migration within the [`Config::Migrations`] list.
+	pub index: u32,
+	/// The encoded identifier of the migration.
+	pub id: Vec<u8>,
+	/// The state of the migration.
+	pub state: MigrationState,
+	/// The number of steps taken so far. Only tracked for the ongoing migration.
+	pub steps: u32,
+	/// The estimated total number of steps of the migration, if known.
+	pub estimated_steps: Option<u32>,
+	/// The completion percentage of the migration, if known.
+	pub progress: Option<Percent>,
+}
+
+/// The outcome of dry-running a migration, as reported by [`runtime_api::MigrationsDryRunApi`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo)]
+pub enum DryRunOutcome {
+	/// The migration completed.
+	Completed,
+	/// The migration was skipped since it was already executed in the past.
+	Skipped,
+	/// The migration did not complete within the step limit of the dry-run.
+	Incomplete,
+	/// The migration failed. The upgrade would fail as well.
+	Failed,
+}
+
+/// The dry-run of a single migration, as reported by [`runtime_api::MigrationsDryRunApi`].
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo)]
+pub struct MigrationDryRun {
+	/// The index of the migration within the [`Config::Migrations`] list.
+	pub index: u32,
+	/// The encoded identifier of the migration.
+	pub id: Vec<u8>,
+	/// The outcome of the migration.
+	pub outcome: DryRunOutcome,
+	/// The number of steps that were executed.
+	pub steps: u32,
+	/// The maximal number of steps that the migration is allowed to take, if any.
+	pub max_steps: Option<u32>,
+	/// The weight consumed by each executed step.
+	pub step_weights: Vec<Weight>,
+}
+
+/// The dry-run of all migrations, as reported by [`runtime_api::MigrationsDryRunApi`].
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo)]
+pub struct DryRunReport {
+	/// The dry-run of each migration, in execution order.
+	///
+	/// Ends with the first migration that did not complete.
+	pub migrations: Vec<MigrationDryRun>,
+	/// The projected number of blocks that the upgrade takes.
+	///
+	/// Assumes that every step fits into the block it is executed in, which the dry-run ensures
+	/// for its own steps.
+	pub projected_blocks: u32,
+}
+
 /// Convenience alias for [`MigrationCursor`].
 pub type CursorOf<T> = MigrationCursor<RawCursorOf<T>, BlockNumberFor<T>>;
 
@@ -375,6 +450,16 @@ pub mod pallet {
 		/// The maximum weight to spend each block to execute migrations.
 		type MaxServiceWeight: Get<Weight>;
 
+		/// Origin that can pause, resume and skip the ongoing migration.
+		#[pallet::no_default]
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Emit a `MigrationProgress` event every this many steps of a migration.
+		///
+		/// Zero disables the event.
+		#[pallet::constant]
+		type ProgressEventInterval: Get<u32>;
+
 		/// Weight information for the calls and functions of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -414,6 +499,7 @@ pub mod pallet {
 			type MigrationStatusHandler = ();
 			type FailedMigrationHandler = FreezeChainOnFailedMigration;
 			type MaxServiceWeight = TestMaxServiceWeight;
+			type ProgressEventInterval = ConstU32<0>;
 			type WeightInfo = ();
 		}
 	}
@@ -431,6 +517,18 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Historic<T: Config> = StorageMap<_, Twox64Concat, IdentifierOf<T>, (), OptionQuery>;
 
+	/// The index of the ongoing migration and the number of steps it has taken so far.
+	///
+	/// Used to report the progress of the migration.
+	#[pallet::storage]
+	pub type ActiveSteps<T: Config> = StorageValue<_, (u32, u32), OptionQuery>;
+
+	/// The block number at which the ongoing migration was paused.
+	///
+	/// `None` indicates that the migrations are not paused.
+	#[pallet::storage]
+	pub type PausedAt<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -464,6 +562,17 @@ pub mod pallet {
 			/// The number of blocks that this migration took so far.
 			took: BlockNumberFor<T>,
 		},
+		/// A migration made progress.
+		///
+		/// Emitted every [`Config::ProgressEventInterval`] steps of a migration.
+		MigrationProgress {
+			/// The index of the migration within the [`Config::Migrations`] list.
+			index: u32,
+			/// The number of steps that this migration took so far.
+			steps: u32,
+			/// The estimated total number of steps of the migration, if known.
+			estimated_steps: Option<u32>,
+		},
 		/// A Migration completed.
 		MigrationCompleted {
 			/// The index of the migration within the [`Config::Migrations`] list.
@@ -485,12 +594,35 @@ pub mod pallet {
 			/// Should be passed to `clear_historic` in a successive call.
 			next_cursor: Option<Vec<u8>>,
 		},
+		/// The ongoing migration was paused and will not make progress until resumed.
+		UpgradePaused {
+			/// The index of the paused migration within the [`Config::Migrations`] list.
+			index: u32,
+		},
+		/// The ongoing migration was resumed.
+		UpgradeResumed {
+			/// The index of the resumed migration within the [`Config::Migrations`] list.
+			index: u32,
+		},
+		/// The ongoing migration was forcefully skipped without completing it.
+		///
+		/// Storage touched by the migration may be in an inconsistent state.
+		MigrationForceSkipped {
+			/// The index of the skipped migration within the [`Config::Migrations`] list.
+			index: u32,
+		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The operation cannot complete since some MBMs are ongoing.
 		Ongoing,
+		/// There is no active migration.
+		NoActiveMigration,
+		/// The migrations are already paused.
+		AlreadyPaused,
+		/// The migrations are not paused.
+		NotPaused,
 	}
 
 	#[pallet::hooks]
@@ -633,6 +765,70 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Pause the ongoing migration.
+		///
+		/// No further steps are executed until [`Self::resume`] is called. The cursor is
+		/// preserved and the chain stays in migration mode while paused, so that no transaction
+		/// sees partially migrated storage.
+		///
+		/// Since only inherents are included while migrating, this call and [`Self::resume`] have
+		/// to be dispatched through a path that runs during migrations, such as a referendum
+		/// enacted by the scheduler or an XCM message.
+		#[pallet::call_index(4)]
+		pub fn pause(origin: OriginFor<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			let cursor = Self::active_cursor()?;
+			ensure!(!PausedAt::<T>::exists(), Error::<T>::AlreadyPaused);
+			PausedAt::<T>::put(System::<T>::block_number());
+
+			log::warn!("Migration #{} paused", cursor.index);
+			Self::deposit_event(Event::UpgradePaused { index: cursor.index });
+
+			Ok(())
+		}
+
+		/// Resume the paused migration.
+		///
+		/// The blocks spent paused do not count towards the maximum steps of the migration.
+		#[pallet::call_index(5)]
+		pub fn resume(origin: OriginFor<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			let mut cursor = Self::active_cursor()?;
+			let paused_at = PausedAt::<T>::take().ok_or(Error::<T>::NotPaused)?;
+			let paused_for = System::<T>::block_number().saturating_sub(paused_at);
+			cursor.started_at.saturating_accrue(paused_for);
+
+			Self::deposit_event(Event::UpgradeResumed { index: cursor.index });
+			Cursor::<T>::put(MigrationCursor::Active(cursor));
+
+			Ok(())
+		}
+
+		/// Skip the ongoing migration and continue with the next one.
+		///
+		/// This is an escape hatch for misbehaving migrations. The skipped migration is not
+		/// recorded as [`Historic`], and the storage it touched may be left half-migrated. Does
+		/// not resume paused migrations.
+		#[pallet::call_index(6)]
+		pub fn force_skip_current(origin: OriginFor<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			let mut cursor = Self::active_cursor()?;
+			let index = cursor.index;
+			// If paused, the next migration starts once resumed.
+			let started_at = PausedAt::<T>::get().unwrap_or_else(System::<T>::block_number);
+			cursor.goto_next_migration(started_at);
+			ActiveSteps::<T>::kill();
+
+			log::error!("Migration #{index} forcefully skipped - storage may be inconsistent");
+			Self::deposit_event(Event::MigrationForceSkipped { index });
+			Cursor::<T>::put(MigrationCursor::Active(cursor));
+
+			Ok(())
+		}
 	}
 }
 
@@ -651,6 +847,8 @@ impl<T: Config> Pallet<T> {
 
 		let migrations = T::Migrations::len();
 		log::debug!("Onboarding {migrations} new MBM migrations");
+		// A pause does not outlive the upgrade that it was issued for.
+		PausedAt::<T>::kill();
 
 		if migrations > 0 {
 			// Set the cursor to the first migration:
@@ -679,6 +877,10 @@ impl<T: Config> Pallet<T> {
 				log::trace!("[Block {n:?}] Waiting for cursor to become `Some`.");
 				return meter.consumed()
 			},
+			Some(MigrationCursor::Active(cursor)) if PausedAt::<T>::exists() => {
+				log::warn!("[Block {n:?}] MBM #{} paused. Waiting to be resumed.", cursor.index);
+				return meter.consumed()
+			},
 			Some(MigrationCursor::Active(cursor)) => {
 				log::debug!("Progressing MBM #{}", cursor.index);
 				cursor
@@ -734,6 +936,7 @@ impl<T: Config> Pallet<T> {
 			defensive_assert!(cursor.index == T::Migrations::len(), "Inconsistent MBMs tuple");
 			Self::deposit_event(Event::UpgradeCompleted);
 			Cursor::<T>::kill();
+			ActiveSteps::<T>::kill();
 			T::MigrationStatusHandler::completed();
 			return None;
 		};
@@ -783,6 +986,7 @@ impl<T: Config> Pallet<T> {
 				};
 
 				Self::deposit_event(Event::MigrationAdvanced { index: cursor.index, took });
+				Self::note_step(cursor.index, meter);
 				cursor.inner_cursor = Some(bound_next_cursor);
 
 				if max_steps.is_some_and(|max| took > max.into()) {
@@ -807,6 +1011,7 @@ impl<T: Config> Pallet<T> {
 				.expect("Post-upgrade failed.");
 
 				Self::deposit_event(Event::MigrationCompleted { index: cursor.index, took });
+				ActiveSteps::<T>::kill();
 				Historic::<T>::insert(&bounded_id, ());
 				cursor.goto_next_migration(System::<T>::block_number());
 				Some(ControlFlow::Continue(cursor))
@@ -836,6 +1041,7 @@ impl<T: Config> Pallet<T> {
 	fn upgrade_failed(migration: Option<u32>) {
 		use FailedMigrationHandling::*;
 		Self::deposit_event(Event::UpgradeFailed);
+		ActiveSteps::<T>::kill();
 
 		if cfg!(feature = "try-runtime") {
 			panic!("Migration with index {migration:?} failed.");
@@ -848,6 +1054,180 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The cursor of the active migration.
+	fn active_cursor() -> Result<ActiveCursorOf<T>, Error<T>> {
+		match Cursor::<T>::get() {
+			Some(MigrationCursor::Active(cursor)) => Ok(cursor),
+			_ => Err(Error::<T>::NoActiveMigration),
+		}
+	}
+
+	/// Count a step of the migration at `index` and periodically report its progress.
+	///
+	/// The access to [`ActiveSteps`] is part of [`Self::exec_migration_max_weight`], the estimate
+	/// of the total steps is charged to `meter`.
+	fn note_step(index: u32, meter: &mut WeightMeter) {
+		let steps = match ActiveSteps::<T>::get() {
+			Some((active, steps)) if active == index => steps.saturating_add(1),
+			_ => 1,
+		};
+		ActiveSteps::<T>::put((index, steps));
+
+		let interval = T::ProgressEventInterval::get();
+		if interval > 0 && steps % interval == 0 {
+			let estimated_steps = T::Migrations::nth_estimated_steps(index, meter).flatten();
+			Self::deposit_event(Event::MigrationProgress { index, steps, estimated_steps });
+		}
+	}
+
+	/// The status of each migration in [`Config::Migrations`], in execution order.
+	pub fn migrations_status() -> Vec<MigrationStatus> {
+		let cursor = Cursor::<T>::get();
+		let active_steps = ActiveSteps::<T>::get();
+
+		(0..T::Migrations::len())
+			.filter_map(|index| {
+				let id = T::Migrations::nth_id(index)?;
+				// Runtime APIs are not weighed.
+				let estimated_steps =
+					T::Migrations::nth_estimated_steps(index, &mut WeightMeter::new()).flatten();
+				let historic =
+					IdentifierOf::<T>::try_from(id.clone()).is_ok_and(Historic::<T>::contains_key);
+
+				let state = match &cursor {
+					_ if historic => MigrationState::Completed,
+					None => MigrationState::Pending,
+					Some(MigrationCursor::Stuck) => MigrationState::Stuck,
+					Some(MigrationCursor::Active(active)) => match index.cmp(&active.index) {
+						core::cmp::Ordering::Less => MigrationState::Completed,
+						core::cmp::Ordering::Equal => MigrationState::Ongoing,
+						core::cmp::Ordering::Greater => MigrationState::Pending,
+					},
+				};
+				let steps = match (state, active_steps) {
+					(MigrationState::Ongoing, Some((active, steps))) if active == index => steps,
+					_ => 0,
+				};
+				let progress = match state {
+					MigrationState::Pending => Some(Percent::zero()),
+					MigrationState::Completed => Some(Percent::one()),
+					MigrationState::Ongoing => estimated_steps
+						.map(|total| Percent::from_rational(steps.min(total), total.max(1))),
+					MigrationState::Stuck => None,
+				};
+
+				Some(MigrationStatus { index, id, state, steps, estimated_steps, progress })
+			})
+			.collect()
+	}
+
+	/// Execute all migrations in [`Config::Migrations`] against the current state, without
+	/// committing any changes.
+	///
+	/// Every step gets the weight that it would get in a block. At most `max_steps` steps are
+	/// executed per migration, and a migration fails when it exceeds its own
+	/// [`SteppedMigration::max_steps`]. No events are deposited and no status handlers are called.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn dry_run_migrations(max_steps: u32) -> DryRunReport {
+		use frame_support::storage::{with_transaction, TransactionOutcome};
+
+		with_transaction(|| {
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(Self::do_dry_run_migrations(
+				max_steps,
+			)))
+		})
+		.unwrap_or_else(|_| {
+			defensive!("Only fails when nested too deeply; qed");
+			DryRunReport { migrations: Vec::new(), projected_blocks: 0 }
+		})
+	}
+
+	/// Implementation of [`Self::dry_run_migrations`], which commits its changes.
+	#[cfg(any(feature = "try-runtime", test))]
+	fn do_dry_run_migrations(max_steps: u32) -> DryRunReport {
+		let limit = T::MaxServiceWeight::get()
+			.saturating_sub(T::WeightInfo::progress_mbms_none())
+			.saturating_sub(Self::exec_migration_max_weight());
+		let mut migrations = Vec::new();
+		// The block in which the last migration completes.
+		let mut projected_blocks = 1u32;
+
+		for index in 0..T::Migrations::len() {
+			let Some(id) = T::Migrations::nth_id(index) else { break };
+			let historic =
+				IdentifierOf::<T>::try_from(id.clone()).is_ok_and(Historic::<T>::contains_key);
+			let mut dry_run = MigrationDryRun {
+				index,
+				id,
+				outcome: DryRunOutcome::Skipped,
+				steps: 0,
+				max_steps: T::Migrations::nth_max_steps(index).flatten(),
+				step_weights: Vec::new(),
+			};
+			if historic {
+				migrations.push(dry_run);
+				continue
+			}
+
+			#[cfg(feature = "try-runtime")]
+			let pre_upgrade = match T::Migrations::nth_pre_upgrade(index) {
+				Some(Ok(bytes)) => bytes,
+				_ => {
+					dry_run.outcome = DryRunOutcome::Failed;
+					migrations.push(dry_run);
+					break
+				},
+			};
+
+			let mut cursor = None;
+			dry_run.outcome = DryRunOutcome::Incomplete;
+			while dry_run.steps < max_steps {
+				let mut meter = WeightMeter::with_limit(limit);
+				let next_cursor = T::Migrations::nth_transactional_step(index, cursor, &mut meter);
+				dry_run.steps.saturating_inc();
+				dry_run.step_weights.push(meter.consumed());
+
+				match next_cursor {
+					// Like `exec_migration`, fail once the migration took more steps than it may.
+					Some(Ok(Some(_)))
+						if dry_run.max_steps.is_some_and(|max| dry_run.steps > max) =>
+					{
+						dry_run.outcome = DryRunOutcome::Failed;
+						break
+					},
+					Some(Ok(Some(next_cursor))) => {
+						// A migration cannot progress more than one step per block.
+						projected_blocks.saturating_inc();
+						cursor = Some(next_cursor);
+					},
+					Some(Ok(None)) => {
+						dry_run.outcome = DryRunOutcome::Completed;
+						break
+					},
+					_ => {
+						dry_run.outcome = DryRunOutcome::Failed;
+						break
+					},
+				}
+			}
+
+			#[cfg(feature = "try-runtime")]
+			if dry_run.outcome == DryRunOutcome::Completed &&
+				!matches!(T::Migrations::nth_post_upgrade(index, pre_upgrade), Some(Ok(())))
+			{
+				dry_run.outcome = DryRunOutcome::Failed;
+			}
+
+			let completed = dry_run.outcome == DryRunOutcome::Completed;
+			migrations.push(dry_run);
+			if !completed {
+				break
+			}
+		}
+
+		DryRunReport { migrations, projected_blocks }
+	}
+
 	/// The maximal weight of calling the private `Self::exec_migration` function.
 	pub fn exec_migration_max_weight() -> Weight {
 		T::WeightInfo::exec_migration_complete()
@@ -855,6 +1235,8 @@ impl<T: Config> Pallet<T> {
 			.max(T::WeightInfo::exec_migration_skipped_historic())
 			.max(T::WeightInfo::exec_migration_advance())
 			.max(T::WeightInfo::exec_migration_fail())
+			// Each branch reads and writes `ActiveSteps` at most once to track the progress.
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
 	}
 }
 
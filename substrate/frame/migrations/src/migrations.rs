@@ -19,7 +19,11 @@ use crate::{weights::WeightInfo, Config};
 use codec::Encode;
 use core::marker::PhantomData;
 use frame_support::{
-	migrations::{SteppedMigration, SteppedMigrationError, StoreInCodeStorageVersion},
+	migrations::{
+		count_metered, steps_for_weight, SteppedMigration, SteppedMigrationError,
+		StoreInCodeStorageVersion,
+	},
+	storage::KeyPrefixIterator,
 	traits::{GetStorageVersion, PalletInfoAccess},
 	weights::WeightMeter,
 };
@@ -67,6 +71,23 @@ where
 		("RemovePallet::", P::name()).using_encoded(twox_128)
 	}
 
+	/// Estimated from the keys left under the prefix of the pallet, so it decreases while the
+	/// keys are removed.
+	fn estimated_steps(meter: &mut WeightMeter) -> Option<u32> {
+		let prefix = P::name_hash().to_vec();
+		let keys = KeyPrefixIterator::new(prefix.clone(), prefix, |_| Ok(()));
+		let keys = count_metered(keys, T::DbWeight::get().reads(1), meter)?;
+
+		let base_weight = T::WeightInfo::reset_pallet_migration(0);
+		let weight_per_key = T::WeightInfo::reset_pallet_migration(1).saturating_sub(base_weight);
+		let steps = steps_for_weight(
+			weight_per_key.saturating_mul(keys.into()),
+			T::MaxServiceWeight::get().saturating_sub(base_weight),
+		)?;
+		// The storage version is written in a separate step.
+		Some(steps.saturating_add(1))
+	}
+
 	fn step(
 		cursor: Option<Self::Cursor>,
 		meter: &mut WeightMeter,
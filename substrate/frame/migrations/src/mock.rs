@@ -54,6 +54,8 @@ impl crate::Config for Test {
 	type Migrations = MockedMigrations;
 	type MigrationStatusHandler = MockedMigrationStatusHandler;
 	type FailedMigrationHandler = MockedFailedMigrationHandler;
+	type ProgressEventInterval = ProgressEventInterval;
+	type PauseOrigin = frame_system::EnsureRoot<u64>;
 }
 
 frame_support::parameter_types! {
@@ -65,6 +67,8 @@ frame_support::parameter_types! {
 	pub static UpgradesFailed: Vec<Option<u32>> = vec![];
 	/// Return value of [`MockedFailedMigrationHandler::failed`].
 	pub static FailedUpgradeResponse: FailedMigrationHandling = FailedMigrationHandling::KeepStuck;
+	/// Value of [`crate::Config::ProgressEventInterval`]. Disabled by default.
+	pub static ProgressEventInterval: u32 = 0;
 }
 
 /// Records all started and completed upgrades in `UpgradesStarted` and `UpgradesCompleted`.
@@ -57,6 +57,9 @@ pub fn mocked_id(kind: MockedMigrationKind, steps: u32) -> MockedIdentifier {
 	(b"MockedMigration", kind, steps).encode().try_into().unwrap()
 }
 
+/// The weight that the mocked migrations consume to estimate their total steps.
+pub const ESTIMATED_STEPS_WEIGHT: Weight = Weight::from_parts(1_000, 0);
+
 frame_support::parameter_types! {
 	/// The configs for the migrations to run.
 	storage MIGRATIONS: Vec<(MockedMigrationKind, u32)> = vec![];
@@ -123,6 +126,11 @@ impl SteppedMigrations for MockedMigrations {
 		MIGRATIONS::get().get(n as usize).map(|(_, s)| Some(*s))
 	}
 
+	fn nth_estimated_steps(n: u32, meter: &mut WeightMeter) -> Option<Option<u32>> {
+		let (_, steps) = MIGRATIONS::get().get(n as usize).copied()?;
+		Some(meter.try_consume(ESTIMATED_STEPS_WEIGHT).is_ok().then_some(steps))
+	}
+
 	#[cfg(feature = "try-runtime")]
 	fn nth_pre_upgrade(n: u32) -> Option<Result<Vec<u8>, sp_runtime::TryRuntimeError>> {
 		let (kind, _) = MIGRATIONS::get()[n as usize];
@@ -0,0 +1,39 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the FRAME migrations pallet.
+
+use crate::{DryRunReport, MigrationStatus};
+use alloc::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// API to track the progress of multi-block migrations.
+	pub trait MigrationsApi {
+		/// The status of each migration of the current runtime, in execution order.
+		fn migrations_status() -> Vec<MigrationStatus>;
+	}
+
+	/// API to simulate the multi-block migrations of the current runtime.
+	///
+	/// Only meant to be implemented by `try-runtime` builds of a runtime.
+	pub trait MigrationsDryRunApi {
+		/// Execute the migrations against the current state, without committing any changes.
+		///
+		/// At most `max_steps` steps are executed per migration.
+		fn dry_run_migrations(max_steps: u32) -> DryRunReport;
+	}
+}
@@ -17,13 +17,18 @@
 
 #![cfg(test)]
 
-use frame_support::{pallet_prelude::Weight, traits::OnRuntimeUpgrade};
+use frame_support::{
+	migrations::MultiStepMigrator, pallet_prelude::Weight, traits::OnRuntimeUpgrade,
+};
 
 use crate::{
 	mock::{Test as T, *},
 	mock_helpers::{MockedMigrationKind::*, *},
-	Cursor, Event, FailedMigrationHandling, MigrationCursor,
+	Cursor, DryRunOutcome, DryRunReport, Error, Event, FailedMigrationHandling, Historic,
+	MigrationCursor, MigrationDryRun, MigrationState, PausedAt,
 };
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Percent;
 
 #[docify::export]
 #[test]
@@ -86,6 +91,202 @@ fn simple_multiple_works() {
 	});
 }
 
+#[test]
+fn progress_is_reported() {
+	use Event::*;
+	test_closure(|| {
+		ProgressEventInterval::set(2);
+		MockedMigrations::set(vec![(SucceedAfter, 0), (SucceedAfter, 4)]);
+
+		let status = |index: usize| Migrations::migrations_status()[index].clone();
+		assert_eq!(status(0).state, MigrationState::Pending);
+		assert_eq!(status(1).progress, Some(Percent::zero()));
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(3);
+
+		assert_eq!(status(0).state, MigrationState::Completed);
+		assert_eq!(status(1).state, MigrationState::Ongoing);
+		assert_eq!(status(1).steps, 2);
+		assert_eq!(status(1).estimated_steps, Some(4));
+		assert_eq!(status(1).progress, Some(Percent::from_percent(50)));
+
+		run_to_block(10);
+		assert_eq!(status(1).state, MigrationState::Completed);
+		assert_eq!(status(1).progress, Some(Percent::one()));
+
+		assert_events(vec![
+			UpgradeStarted { migrations: 2 },
+			MigrationCompleted { index: 0, took: 1 },
+			MigrationAdvanced { index: 1, took: 0 },
+			MigrationAdvanced { index: 1, took: 1 },
+			MigrationProgress { index: 1, steps: 2, estimated_steps: Some(4) },
+			MigrationAdvanced { index: 1, took: 2 },
+			MigrationAdvanced { index: 1, took: 3 },
+			MigrationProgress { index: 1, steps: 4, estimated_steps: Some(4) },
+			MigrationCompleted { index: 1, took: 4 },
+			UpgradeCompleted,
+		]);
+	});
+}
+
+#[test]
+fn progress_estimate_is_weighed() {
+	test_closure(|| {
+		ProgressEventInterval::set(2);
+		MockedMigrations::set(vec![(SucceedAfter, 4)]);
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+
+		// Only the second step reports the progress and estimates the total steps.
+		let without_estimate = <Migrations as MultiStepMigrator>::step();
+		System::set_block_number(2);
+		let with_estimate = <Migrations as MultiStepMigrator>::step();
+		assert_eq!(with_estimate, without_estimate + ESTIMATED_STEPS_WEIGHT);
+	});
+}
+
+#[test]
+fn dry_run_reports_steps_without_committing() {
+	test_closure(|| {
+		MockedMigrations::set(vec![
+			(SucceedAfter, 0),
+			(SucceedAfter, 2),
+			(FailAfter, 1),
+			(SucceedAfter, 0),
+		]);
+		Historic::<T>::insert(mocked_id(SucceedAfter, 0), ());
+		System::set_block_number(1);
+
+		let dry_run = |index, id, outcome, steps, max_steps| MigrationDryRun {
+			index,
+			id,
+			outcome,
+			steps,
+			max_steps: Some(max_steps),
+			step_weights: vec![Weight::zero(); steps as usize],
+		};
+		assert_eq!(
+			Migrations::dry_run_migrations(10),
+			DryRunReport {
+				migrations: vec![
+					dry_run(
+						0,
+						mocked_id(SucceedAfter, 0).into_inner(),
+						DryRunOutcome::Skipped,
+						0,
+						0
+					),
+					dry_run(
+						1,
+						mocked_id(SucceedAfter, 2).into_inner(),
+						DryRunOutcome::Completed,
+						3,
+						2
+					),
+					dry_run(2, mocked_id(FailAfter, 1).into_inner(), DryRunOutcome::Failed, 2, 1),
+				],
+				// Two steps of the second and one of the third migration advance to the next block.
+				projected_blocks: 4,
+			}
+		);
+
+		// Nothing was committed.
+		assert_eq!(historic(), vec![mocked_id(SucceedAfter, 0)]);
+		assert!(Cursor::<T>::get().is_none());
+		assert!(System::events().is_empty());
+
+		// Migrations which take too many steps are incomplete.
+		let report = Migrations::dry_run_migrations(2);
+		assert_eq!(report.migrations[1].outcome, DryRunOutcome::Incomplete);
+		assert_eq!(report.migrations[1].steps, 2);
+		assert_eq!(report.migrations.len(), 2);
+
+		// Migrations which exceed their own step limit fail, like they would on-chain.
+		MockedMigrations::set(vec![(TimeoutAfter, 3)]);
+		let report = Migrations::dry_run_migrations(10);
+		assert_eq!(report.migrations[0].outcome, DryRunOutcome::Failed);
+		assert_eq!(report.migrations[0].steps, 4);
+	});
+}
+
+#[test]
+fn pause_and_resume_works() {
+	use Event::*;
+	test_closure(|| {
+		// Times out after 3 blocks.
+		MockedMigrations::set(vec![(SucceedAfter, 3)]);
+		assert_noop!(Migrations::pause(RuntimeOrigin::root()), Error::<T>::NoActiveMigration);
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(2);
+
+		assert_noop!(
+			Migrations::pause(RuntimeOrigin::signed(1)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(Migrations::resume(RuntimeOrigin::root()), Error::<T>::NotPaused);
+		assert_ok!(Migrations::pause(RuntimeOrigin::root()));
+		assert_noop!(Migrations::pause(RuntimeOrigin::root()), Error::<T>::AlreadyPaused);
+
+		// No progress is made while paused.
+		run_to_block(10);
+		assert!(Cursor::<T>::exists());
+		assert_eq!(PausedAt::<T>::get(), Some(2));
+		// Transactions stay blocked while paused.
+		assert!(<Migrations as MultiStepMigrator>::ongoing());
+
+		// The paused blocks do not count towards the timeout.
+		assert_ok!(Migrations::resume(RuntimeOrigin::root()));
+		run_to_block(20);
+		assert_eq!(historic(), vec![mocked_id(SucceedAfter, 3)]);
+
+		assert_events(vec![
+			UpgradeStarted { migrations: 1 },
+			MigrationAdvanced { index: 0, took: 1 },
+			UpgradePaused { index: 0 },
+			UpgradeResumed { index: 0 },
+			MigrationAdvanced { index: 0, took: 2 },
+			MigrationAdvanced { index: 0, took: 3 },
+			MigrationCompleted { index: 0, took: 4 },
+			UpgradeCompleted,
+		]);
+	});
+}
+
+#[test]
+fn force_skip_current_works() {
+	use Event::*;
+	test_closure(|| {
+		MockedMigrations::set(vec![(SucceedAfter, 5), (SucceedAfter, 0)]);
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(2);
+
+		assert_ok!(Migrations::pause(RuntimeOrigin::root()));
+		assert_ok!(Migrations::force_skip_current(RuntimeOrigin::root()));
+		// Skipping does not resume.
+		run_to_block(5);
+		assert_ok!(Migrations::resume(RuntimeOrigin::root()));
+		run_to_block(10);
+
+		// Only the second migration is recorded.
+		assert_eq!(historic(), vec![mocked_id(SucceedAfter, 0)]);
+		assert_events(vec![
+			UpgradeStarted { migrations: 2 },
+			MigrationAdvanced { index: 0, took: 1 },
+			UpgradePaused { index: 0 },
+			MigrationForceSkipped { index: 0 },
+			UpgradeResumed { index: 1 },
+			MigrationCompleted { index: 1, took: 1 },
+			UpgradeCompleted,
+		]);
+	});
+}
+
 #[test]
 #[cfg_attr(feature = "try-runtime", should_panic)]
 fn failing_migration_sets_cursor_to_stuck() {
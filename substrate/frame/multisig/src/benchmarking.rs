@@ -359,5 +359,83 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// `z`: Transaction Length
+	/// `s`: Signatories, all of which sign
+	#[benchmark]
+	fn as_multi_with_signatures(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+		z: Linear<0, 10_000>,
+	) -> Result<(), BenchmarkError> {
+		// The signers, sorted by account, with the index of their key.
+		let mut signers: Vec<(T::AccountId, u32)> = (0..s)
+			.map(|i| (T::BenchmarkHelper::sign_message(i, &[]).0.into_account(), i))
+			.collect();
+		signers.sort();
+		let signatories: Vec<T::AccountId> = signers.iter().map(|(who, _)| who.clone()).collect();
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s as u16);
+
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![0; z as usize] }.into();
+		let call_hash = call.using_encoded(blake2_256);
+		let payload = Multisig::<T>::signing_payload(&multi_account_id, &call_hash, 0);
+		let signatures = signers
+			.into_iter()
+			.map(|(who, i)| (who, T::BenchmarkHelper::sign_message(i, &payload).1))
+			.collect();
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			s as u16,
+			signatories,
+			Box::new(call),
+			signatures,
+			Weight::MAX,
+		);
+
+		assert_eq!(SignatureNonces::<T>::get(&multi_account_id), 1);
+		Ok(())
+	}
+
+	/// `s`: Signatories, need at least 2 people
+	#[benchmark]
+	fn approve_cancel_as_multi(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+	) -> Result<(), BenchmarkError> {
+		// The call is neither in storage or an argument, so just use any:
+		let call_len = 10_000;
+		let (mut signatories, call) = setup_multi::<T>(s, call_len)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let depositor = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(depositor.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories.clone(), None, call, Weight::zero())?;
+		// Enough signatories approve the cancellation for the caller's approval to cancel it.
+		let caller = signatories.remove(0);
+		let needed = Multisig::<T>::cancel_approvals_needed(s as u16, s - 1) as usize;
+		let deposit = T::DepositFactor::get();
+		let mut approvals = BoundedVec::<_, T::MaxSignatories>::new();
+		for approving in signatories[..needed - 1].iter() {
+			T::Currency::reserve(approving, deposit)?;
+			approvals.try_push((approving.clone(), deposit)).map_err(|_| "too many approvals")?;
+		}
+		CancelApprovals::<T>::insert(&multi_account_id, call_hash, approvals);
+		signatories.push(depositor);
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s as u16, signatories, timepoint, call_hash);
+
+		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		assert!(!CancelApprovals::<T>::contains_key(multi_account_id, call_hash));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
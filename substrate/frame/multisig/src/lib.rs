@@ -39,6 +39,10 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `approve_cancel_as_multi` - Approve the cancellation of a call from a composite origin by a
+//!   signatory other than its depositor. Cancels the call once enough signatories approved.
+//! * `as_multi_with_signatures` - Dispatch a call from a composite origin in a single transaction,
+//!   using signatures of the approving signatories made off-chain.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -51,6 +55,7 @@ pub mod weights;
 extern crate alloc;
 use alloc::{boxed::Box, vec, vec::Vec};
 use frame::{
+	deps::sp_runtime::traits::Verify,
 	prelude::*,
 	traits::{Currency, ReservableCurrency},
 };
@@ -133,6 +138,9 @@ where
 
 type CallHash = [u8; 32];
 
+/// The context prepended to the payload signed for `as_multi_with_signatures`.
+pub const SIGNATURE_CONTEXT: &[u8] = b"multisig_as_multi_with_signatures";
+
 enum CallOrHash<T: Config> {
 	Call(<T as Config>::RuntimeCall),
 	Hash([u8; 32]),
@@ -141,6 +149,28 @@ enum CallOrHash<T: Config> {
 #[frame::pallet]
 pub mod pallet {
 	use super::*;
+	#[cfg(feature = "runtime-benchmarks")]
+	use frame::deps::sp_runtime::{MultiSignature, MultiSigner};
+
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<Public, Signature> {
+		/// Sign `message` with the `i`-th of a set of distinct keys.
+		fn sign_message(i: u32, message: &[u8]) -> (Public, Signature);
+	}
+	#[cfg(feature = "runtime-benchmarks")]
+	impl BenchmarkHelper<MultiSigner, MultiSignature> for () {
+		fn sign_message(i: u32, message: &[u8]) -> (MultiSigner, MultiSignature) {
+			use alloc::format;
+			use frame::deps::sp_io;
+
+			let seed = format!("//Multisig{}", i).into_bytes();
+			let public = sp_io::crypto::sr25519_generate(0.into(), Some(seed));
+			let signature = MultiSignature::Sr25519(
+				sp_io::crypto::sr25519_sign(0.into(), &public, message).unwrap(),
+			);
+			(public.into(), signature)
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -202,6 +232,18 @@ pub mod pallet {
 		///     providers can be used. Relay provider can be a bit better in cases where the
 		///     parachain is lagging its block production to avoid clock skew.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// Off-chain signature type used by `as_multi_with_signatures`.
+		///
+		/// Can verify whether a `Self::SigningPublicKey` created a signature.
+		type OffchainSignature: Verify<Signer = Self::SigningPublicKey> + Parameter;
+
+		/// Public key that corresponds to an on-chain `Self::AccountId`.
+		type SigningPublicKey: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// A set of helper functions for benchmarking.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::SigningPublicKey, Self::OffchainSignature>;
 	}
 
 	/// The in-code storage version.
@@ -222,6 +264,26 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The signatories, other than the depositor, who approved cancelling an open multisig
+	/// operation, together with the deposit each of them holds for their approval.
+	#[pallet::storage]
+	pub type CancelApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		BoundedVec<(T::AccountId, BalanceOf<T>), T::MaxSignatories>,
+		ValueQuery,
+	>;
+
+	/// The nonce of the next call of a multisig account dispatched with
+	/// `as_multi_with_signatures`. Part of the signed payload, so that signatures cannot be
+	/// replayed.
+	#[pallet::storage]
+	pub type SignatureNonces<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -253,6 +315,16 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// Fewer valid signatures than the threshold were given.
+		NotEnoughSignatures,
+		/// The signers were provided out of order; they should be ordered.
+		SignersOutOfOrder,
+		/// A signer is not one of the signatories.
+		SignerNotSignatory,
+		/// A signature is not valid for the call and the multisig account.
+		InvalidSignature,
+		/// The depositor can cancel the multisig operation directly with `cancel_as_multi`.
+		IsDepositor,
 	}
 
 	#[pallet::event]
@@ -282,6 +354,20 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// The cancellation of a multisig operation has been approved by a signatory.
+		MultisigCancelApproval {
+			approving: T::AccountId,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
+		/// A multisig operation has been cancelled by signatories other than its depositor.
+		MultisigCancelledBySignatories {
+			cancelling: BoundedVec<T::AccountId, T::MaxSignatories>,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
 		/// The deposit for a multisig operation has been updated/poked.
 		DepositPoked {
 			who: T::AccountId,
@@ -536,6 +622,7 @@ pub mod pallet {
 			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
 			debug_assert!(err_amount.is_zero());
 			<Multisigs<T>>::remove(&id, &call_hash);
+			Self::clear_cancel_approvals(&id, &call_hash);
 
 			Self::deposit_event(Event::MultisigCancelled {
 				cancelling: who,
@@ -623,6 +710,195 @@ pub mod pallet {
 				},
 			)
 		}
+
+		/// Dispatch a call from a deterministic composite account in a single transaction, using
+		/// signatures of at least `threshold` of the `signatories` made off-chain.
+		///
+		/// Each signer signs [`Pallet::signing_payload`] for the multisig account, the hash of the
+		/// call and the current nonce of the account in [`SignatureNonces`], which is bumped
+		/// when the call is dispatched. No deposit is taken, since nothing is stored.
+		///
+		/// The dispatch origin for this call must be _Signed_, but need not be a signatory.
+		///
+		/// - `threshold`: The number of signatures needed for this dispatch. Must be at least 2.
+		/// - `signatories`: All accounts of the multisig, sorted. Unlike other calls, the sender
+		/// is not implied.
+		/// - `call`: The call to be executed.
+		/// - `signatures`: The signers and their signatures, sorted by signer.
+		/// - `max_weight`: The maximum weight the call may use.
+		///
+		/// ## Complexity
+		/// - `O(S + Z + Call)`.
+		/// - Up to `S` signature verifications, where `S` is the number of signatories.
+		/// - One call encode & hash, both of complexity `O(Z)` where `Z` is tx-len.
+		/// - I/O: 1 read, 1 mutate.
+		/// - One event.
+		/// - The weight of the `call`.
+		#[pallet::call_index(5)]
+		#[pallet::weight({
+			let s = signatories.len() as u32;
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			T::WeightInfo::as_multi_with_signatures(s, z)
+				// AccountData for inner call origin accountdata.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(*max_weight)
+		})]
+		pub fn as_multi_with_signatures(
+			origin: OriginFor<T>,
+			threshold: u16,
+			signatories: Vec<T::AccountId>,
+			call: Box<<T as Config>::RuntimeCall>,
+			signatures: Vec<(T::AccountId, T::OffchainSignature)>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+			ensure!(signatories.len() >= 2, Error::<T>::TooFewSignatories);
+			ensure!(
+				signatories.len() <= T::MaxSignatories::get() as usize,
+				Error::<T>::TooManySignatories
+			);
+			ensure!(signatories.windows(2).all(|w| w[0] < w[1]), Error::<T>::SignatoriesOutOfOrder);
+			ensure!(signatures.len() >= threshold as usize, Error::<T>::NotEnoughSignatures);
+			ensure!(signatures.len() <= signatories.len(), Error::<T>::TooManySignatories);
+			ensure!(signatures.windows(2).all(|w| w[0].0 < w[1].0), Error::<T>::SignersOutOfOrder);
+
+			let id = Self::multi_account_id(&signatories, threshold);
+			let (call_len, call_hash) = call.using_encoded(|c| (c.len(), blake2_256(c)));
+			let payload = Self::signing_payload(&id, &call_hash, SignatureNonces::<T>::get(&id));
+			for (signer, signature) in signatures.iter() {
+				ensure!(signatories.binary_search(signer).is_ok(), Error::<T>::SignerNotSignatory);
+				ensure!(signature.verify(&payload[..], signer), Error::<T>::InvalidSignature);
+			}
+
+			ensure!(
+				call.get_dispatch_info().call_weight.all_lte(max_weight),
+				Error::<T>::MaxWeightTooLow
+			);
+
+			// Bump the nonce before executing the call, so the signatures cannot be used again,
+			// even from within the call.
+			SignatureNonces::<T>::mutate(&id, |nonce| *nonce = nonce.wrapping_add(1));
+
+			let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
+			Self::deposit_event(Event::MultisigExecuted {
+				approving: who,
+				timepoint: Self::timepoint(),
+				multisig: id,
+				call_hash,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+
+			Ok(get_result_weight(result)
+				.map(|actual_weight| {
+					T::WeightInfo::as_multi_with_signatures(
+						signatories.len() as u32,
+						call_len as u32,
+					)
+					.saturating_add(actual_weight)
+				})
+				.into())
+		}
+
+		/// Approve the cancellation of a pre-existing, on-going multisig transaction as a
+		/// signatory other than its depositor.
+		///
+		/// Each approval holds [`Config::DepositFactor`] of the approving signatory until the
+		/// operation is executed or cancelled. Once as many signatories other than the depositor
+		/// as needed to execute the operation, but at least a strict majority of them, approved,
+		/// the operation is cancelled and all deposits are unreserved. This allows
+		/// cleaning up operations whose depositor is absent or compromised.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must not be the depositor.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// Emits `MultisigCancelApproval`, or `MultisigCancelledBySignatories` once the
+		/// operation is cancelled.
+		///
+		/// ## Complexity
+		/// - `O(S)`.
+		/// - One balance-reserve and up to `S` balance-unreserve operations.
+		/// - One passthrough operation, one binary search and insert, all `O(S)` where `S` is the
+		///   number of signatories. `S` is capped by `MaxSignatories`, with weight being
+		///   proportional.
+		/// - One encode & hash, both of complexity `O(S)`.
+		/// - One event.
+		/// - I/O: 2 reads `O(S)`, one mutate `O(S)` or two removes.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::approve_cancel_as_multi(other_signatories.len() as u32))]
+		pub fn approve_cancel_as_multi(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			let other_signatories_len = other_signatories.len();
+			ensure!(other_signatories_len < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+
+			let id = Self::multi_account_id(&signatories, threshold);
+
+			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			ensure!(m.depositor != who, Error::<T>::IsDepositor);
+
+			let mut approvals = <CancelApprovals<T>>::get(&id, call_hash);
+			let pos = approvals
+				.binary_search_by(|(approving, _)| approving.cmp(&who))
+				.err()
+				.ok_or(Error::<T>::AlreadyApproved)?;
+			let deposit = T::DepositFactor::get();
+			T::Currency::reserve(&who, deposit)?;
+			approvals
+				.try_insert(pos, (who.clone(), deposit))
+				.map_err(|_| Error::<T>::TooManySignatories)?;
+
+			// The depositor is one of the signatories, so there are exactly as many others as
+			// given in `other_signatories`.
+			let needed = Self::cancel_approvals_needed(threshold, other_signatories_len as u32);
+			if (approvals.len() as u32) < needed {
+				<CancelApprovals<T>>::insert(&id, call_hash, approvals);
+				Self::deposit_event(Event::MultisigCancelApproval {
+					approving: who,
+					timepoint,
+					multisig: id,
+					call_hash,
+				});
+				return Ok(())
+			}
+
+			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
+			debug_assert!(err_amount.is_zero());
+			<Multisigs<T>>::remove(&id, &call_hash);
+			<CancelApprovals<T>>::remove(&id, &call_hash);
+			for (approving, deposit) in &approvals {
+				let err_amount = T::Currency::unreserve(approving, *deposit);
+				debug_assert!(err_amount.is_zero());
+			}
+			let approvals = BoundedVec::truncate_from(
+				approvals.into_iter().map(|(approving, _)| approving).collect(),
+			);
+
+			Self::deposit_event(Event::MultisigCancelledBySignatories {
+				cancelling: approvals,
+				timepoint,
+				multisig: id,
+				call_hash,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -689,6 +965,7 @@ impl<T: Config> Pallet<T> {
 				// Clean up storage before executing call to avoid an possibility of reentrancy
 				// attack.
 				<Multisigs<T>>::remove(&id, call_hash);
+				Self::clear_cancel_approvals(&id, &call_hash);
 				T::Currency::unreserve(&m.depositor, m.deposit);
 
 				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
@@ -766,6 +1043,18 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The payload signatories sign off-chain to approve the call with hash `call_hash` through
+	/// `as_multi_with_signatures` from the multisig account `id`.
+	///
+	/// `nonce` is the current value of [`SignatureNonces`] for `id`. The payload also commits to
+	/// the genesis hash of the chain, so that signatures cannot be replayed on other chains.
+	pub fn signing_payload(id: &T::AccountId, call_hash: &[u8; 32], nonce: u32) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(
+			frame_system::pallet_prelude::BlockNumberFor::<T>::zero(),
+		);
+		(SIGNATURE_CONTEXT, genesis_hash, id, call_hash, nonce).encode()
+	}
+
 	/// The current `Timepoint`.
 	pub fn timepoint() -> Timepoint<BlockNumberFor<T>> {
 		Timepoint {
@@ -774,6 +1063,24 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The number of signatories other than the depositor who need to approve the cancellation
+	/// of an operation of a `threshold` multisig with `other_signatories` such signatories.
+	///
+	/// This is the `threshold`, capped at `other_signatories`, but at least a strict majority of
+	/// them, so that a single co-signer can never cancel on their own unless they are the only
+	/// other signatory.
+	pub(crate) fn cancel_approvals_needed(threshold: u16, other_signatories: u32) -> u32 {
+		(threshold as u32).min(other_signatories).max(other_signatories / 2 + 1)
+	}
+
+	/// Remove the approvals to cancel an operation and unreserve their deposits.
+	fn clear_cancel_approvals(id: &T::AccountId, call_hash: &[u8; 32]) {
+		for (approving, deposit) in <CancelApprovals<T>>::take(id, call_hash) {
+			let err_amount = T::Currency::unreserve(&approving, deposit);
+			debug_assert!(err_amount.is_zero());
+		}
+	}
+
 	/// Check that signatories is sorted and doesn't contain sender, then insert sender.
 	fn ensure_sorted_and_insert(
 		other_signatories: Vec<T::AccountId>,
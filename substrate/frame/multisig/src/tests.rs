@@ -21,7 +21,12 @@
 
 use super::*;
 use crate as pallet_multisig;
-use frame::{prelude::*, runtime::prelude::*, testing_prelude::*};
+use frame::{
+	deps::sp_runtime::testing::{TestSignature, UintAuthorityId},
+	prelude::*,
+	runtime::prelude::*,
+	testing_prelude::*,
+};
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
 
@@ -73,6 +78,20 @@ impl Config for Test {
 	type MaxSignatories = ConstU32<3>;
 	type WeightInfo = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type OffchainSignature = TestSignature;
+	type SigningPublicKey = UintAuthorityId;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<UintAuthorityId, TestSignature> for TestBenchmarkHelper {
+	fn sign_message(i: u32, message: &[u8]) -> (UintAuthorityId, TestSignature) {
+		let who = 100 + i as u64;
+		(UintAuthorityId(who), TestSignature(who, message.to_vec()))
+	}
 }
 
 use pallet_balances::{Call as BalancesCall, Error as BalancesError};
@@ -98,6 +117,13 @@ fn call_transfer(dest: u64, value: u64) -> Box<RuntimeCall> {
 	Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest, value }))
 }
 
+fn sign(signers: &[u64], multi: u64, call: &RuntimeCall) -> Vec<(u64, TestSignature)> {
+	let call_hash = call.using_encoded(blake2_256);
+	let payload =
+		Multisig::signing_payload(&multi, &call_hash, SignatureNonces::<Test>::get(multi));
+	signers.iter().map(|who| (*who, TestSignature(*who, payload.clone()))).collect()
+}
+
 #[test]
 fn multisig_deposit_is_taken_and_returned() {
 	new_test_ext().execute_with(|| {
@@ -322,6 +348,174 @@ fn cancel_multisig_works() {
 	});
 }
 
+#[test]
+fn cancel_multisig_by_signatories_works() {
+	new_test_ext().execute_with(|| {
+		let call = call_transfer(6, 15).encode();
+		let hash = blake2_256(&call);
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(Balances::reserved_balance(1), 4);
+
+		assert_noop!(
+			Multisig::approve_cancel_as_multi(RuntimeOrigin::signed(1), 3, vec![2, 3], now(), hash),
+			Error::<Test>::IsDepositor,
+		);
+		assert_ok!(Multisig::approve_cancel_as_multi(
+			RuntimeOrigin::signed(2),
+			3,
+			vec![1, 3],
+			now(),
+			hash
+		));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigCancelApproval {
+				approving: 2,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+			}
+			.into(),
+		);
+		assert_noop!(
+			Multisig::approve_cancel_as_multi(RuntimeOrigin::signed(2), 3, vec![1, 3], now(), hash),
+			Error::<Test>::AlreadyApproved,
+		);
+		assert!(Multisigs::<Test>::contains_key(multi, hash));
+		// The approval holds a deposit.
+		assert_eq!(Balances::reserved_balance(2), 1);
+
+		assert_ok!(Multisig::approve_cancel_as_multi(
+			RuntimeOrigin::signed(3),
+			3,
+			vec![1, 2],
+			now(),
+			hash
+		));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigCancelledBySignatories {
+				cancelling: vec![2, 3].try_into().unwrap(),
+				timepoint: now(),
+				multisig: multi,
+				call_hash: hash,
+			}
+			.into(),
+		);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert!(!CancelApprovals::<Test>::contains_key(multi, hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
+#[test]
+fn cancel_multisig_by_signatories_respects_threshold() {
+	new_test_ext().execute_with(|| {
+		let call = call_transfer(6, 15).encode();
+		let hash = blake2_256(&call);
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_noop!(
+			Multisig::approve_cancel_as_multi(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![1, 3],
+				now(),
+				[0; 32]
+			),
+			Error::<Test>::NotFound,
+		);
+
+		// A single co-signer of a 2-of-3 multisig cannot cancel the operation on their own.
+		assert_ok!(Multisig::approve_cancel_as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			now(),
+			hash
+		));
+		assert!(Multisigs::<Test>::contains_key(multi, hash));
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		assert_ok!(Multisig::approve_cancel_as_multi(
+			RuntimeOrigin::signed(3),
+			2,
+			vec![1, 2],
+			now(),
+			hash
+		));
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn cancel_approvals_needed_is_derived_from_threshold() {
+	// As many as needed to execute, excluding the depositor.
+	assert_eq!(Multisig::cancel_approvals_needed(2, 2), 2);
+	assert_eq!(Multisig::cancel_approvals_needed(3, 2), 2);
+	assert_eq!(Multisig::cancel_approvals_needed(4, 5), 4);
+	// But at least a strict majority of the other signatories.
+	assert_eq!(Multisig::cancel_approvals_needed(2, 4), 3);
+	assert_eq!(Multisig::cancel_approvals_needed(2, 9), 5);
+	assert_eq!(Multisig::cancel_approvals_needed(2, 1), 1);
+}
+
+#[test]
+fn executing_multisig_clears_cancel_approvals() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 15);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::approve_cancel_as_multi(
+			RuntimeOrigin::signed(3),
+			2,
+			vec![1, 2],
+			now(),
+			hash
+		));
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 15);
+		assert!(!CancelApprovals::<Test>::contains_key(multi, hash));
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
 #[test]
 fn multisig_2_of_3_as_multi_works() {
 	new_test_ext().execute_with(|| {
@@ -1015,3 +1209,114 @@ fn poke_deposit_handles_insufficient_balance() {
 		);
 	});
 }
+
+#[test]
+fn as_multi_with_signatures_works() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+
+		let call = call_transfer(6, 4);
+		let signatures = sign(&[1, 3], multi, &call);
+		// Submitted by someone who is not a signatory.
+		assert_ok!(Multisig::as_multi_with_signatures(
+			RuntimeOrigin::signed(4),
+			2,
+			vec![1, 2, 3],
+			call.clone(),
+			signatures.clone(),
+			call.get_dispatch_info().call_weight,
+		));
+		assert_eq!(Balances::free_balance(6), 4);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(SignatureNonces::<Test>::get(multi), 1);
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigExecuted {
+				approving: 4,
+				timepoint: now(),
+				multisig: multi,
+				call_hash: call.using_encoded(blake2_256),
+				result: Ok(()),
+			}
+			.into(),
+		);
+
+		// The same signatures cannot be used again.
+		assert_noop!(
+			Multisig::as_multi_with_signatures(
+				RuntimeOrigin::signed(4),
+				2,
+				vec![1, 2, 3],
+				call.clone(),
+				signatures,
+				call.get_dispatch_info().call_weight,
+			),
+			Error::<Test>::InvalidSignature,
+		);
+		assert_ok!(Multisig::as_multi_with_signatures(
+			RuntimeOrigin::signed(4),
+			2,
+			vec![1, 2, 3],
+			call.clone(),
+			sign(&[2, 3], multi, &call),
+			call.get_dispatch_info().call_weight,
+		));
+		assert_eq!(Balances::free_balance(6), 8);
+	});
+}
+
+#[test]
+fn as_multi_with_signatures_checks_signatures() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let call = call_transfer(6, 4);
+		let weight = call.get_dispatch_info().call_weight;
+		let as_multi = |signatories: Vec<u64>, signatures, max_weight| {
+			Multisig::as_multi_with_signatures(
+				RuntimeOrigin::signed(4),
+				2,
+				signatories,
+				call.clone(),
+				signatures,
+				max_weight,
+			)
+		};
+
+		assert_noop!(
+			as_multi(vec![1, 2, 3], sign(&[1], multi, &call), weight),
+			Error::<Test>::NotEnoughSignatures,
+		);
+		assert_noop!(
+			as_multi(vec![1, 3, 2], sign(&[1, 2], multi, &call), weight),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			as_multi(vec![1, 2, 3], sign(&[2, 1], multi, &call), weight),
+			Error::<Test>::SignersOutOfOrder,
+		);
+		assert_noop!(
+			as_multi(vec![1, 2, 3], sign(&[1, 1], multi, &call), weight),
+			Error::<Test>::SignersOutOfOrder,
+		);
+		assert_noop!(
+			as_multi(vec![1, 2, 3], sign(&[1, 5], multi, &call), weight),
+			Error::<Test>::SignerNotSignatory,
+		);
+		// Signed for another multisig account.
+		let other = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		assert_noop!(
+			as_multi(vec![1, 2, 3], sign(&[1, 2], other, &call), weight),
+			Error::<Test>::InvalidSignature,
+		);
+		// Signed for another call.
+		assert_noop!(
+			as_multi(vec![1, 2, 3], sign(&[1, 2], multi, &call_transfer(6, 5)), weight),
+			Error::<Test>::InvalidSignature,
+		);
+		assert_noop!(
+			as_multi(vec![1, 2, 3], sign(&[1, 2], multi, &call), Weight::zero()),
+			Error::<Test>::MaxWeightTooLow,
+		);
+	});
+}
@@ -79,6 +79,8 @@ pub trait WeightInfo {
 	fn approve_as_multi_approve(s: u32, ) -> Weight;
 	fn cancel_as_multi(s: u32, ) -> Weight;
 	fn poke_deposit(s: u32, ) -> Weight;
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight;
+	fn approve_cancel_as_multi(s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_multisig` using the Substrate node and recommended hardware.
@@ -212,6 +214,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::SignatureNonces` (r:1 w:1)
+	/// Proof: `Multisig::SignatureNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3517`
+		// Minimum execution time: 28_420_000 picoseconds.
+		Weight::from_parts(29_604_517, 3517)
+			// Standard Error: 1_207_798
+			.saturating_add(Weight::from_parts(48_311_926, 0).saturating_mul(s.into()))
+			// Standard Error: 10
+			.saturating_add(Weight::from_parts(412, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancelApprovals` (r:1 w:1)
+	/// Proof: `Multisig::CancelApprovals` (`max_values`: None, `max_size`: Some(4862), added: 7337, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:99 w:99)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_cancel_as_multi(s: u32, ) -> Weight {
+		// Placeholder until the `approve_cancel_as_multi` benchmark is run.
+		Weight::from_parts(36_214_883, 8411)
+			// Standard Error: 4_285
+			.saturating_add(Weight::from_parts(171_406, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(s.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -344,4 +382,40 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `System::BlockHash` (r:1 w:0)
+	/// Proof: `System::BlockHash` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::SignatureNonces` (r:1 w:1)
+	/// Proof: `Multisig::SignatureNonces` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 10000]`.
+	fn as_multi_with_signatures(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3517`
+		// Minimum execution time: 28_420_000 picoseconds.
+		Weight::from_parts(29_604_517, 3517)
+			// Standard Error: 1_207_798
+			.saturating_add(Weight::from_parts(48_311_926, 0).saturating_mul(s.into()))
+			// Standard Error: 10
+			.saturating_add(Weight::from_parts(412, 0).saturating_mul(z.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::CancelApprovals` (r:1 w:1)
+	/// Proof: `Multisig::CancelApprovals` (`max_values`: None, `max_size`: Some(4862), added: 7337, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:99 w:99)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn approve_cancel_as_multi(s: u32, ) -> Weight {
+		// Placeholder until the `approve_cancel_as_multi` benchmark is run.
+		Weight::from_parts(36_214_883, 8411)
+			// Standard Error: 4_285
+			.saturating_add(Weight::from_parts(171_406, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads_writes(1, 1).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(s.into()))
+	}
 }
@@ -70,7 +70,10 @@ impl pallet_balances::Config<pallet_balances::Instance1> for Test {
 	type MaxFreezes = ();
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type DoneSlashHandler = ();
+	type Scheduler = ();
+	type MaxScheduledTransferDelay = ConstU64<100>;
 }
 
 impl pallet_balances::Config<pallet_balances::Instance2> for Test {
@@ -89,9 +92,12 @@ impl pallet_balances::Config<pallet_balances::Instance2> for Test {
 	type ReserveIdentifier = [u8; 8];
 	type FreezeIdentifier = ();
 	type MaxFreezes = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
+	type RuntimeCall = RuntimeCall;
 	type DoneSlashHandler = ();
+	type Scheduler = ();
+	type MaxScheduledTransferDelay = ConstU64<100>;
 }
 
 parameter_types! {
@@ -58,6 +58,35 @@ fn insert_old_unrequested<T: Config>(s: u32) -> <T as frame_system::Config>::Has
 	hash
 }
 
+/// Insert a complete upload of a preimage of `s` bytes in about `c` chunks for `caller`.
+///
+/// The upload is inserted directly, since `start_preimage` rejects uploads which would be too
+/// heavy to finalize in the runtime.
+fn uploaded_preimage<T: Config>(
+	caller: &T::AccountId,
+	c: u32,
+	s: u32,
+) -> Result<<T as frame_system::Config>::Hash, BenchmarkError> {
+	let (preimage, hash) = sized_preimage_and_hash::<T>(s);
+	let chunk_len = s.div_ceil(c).min(MAX_CHUNK_SIZE);
+	let mut chunks = 0;
+	for chunk in preimage.chunks(chunk_len as usize) {
+		let chunk: BoundedVec<_, _> = chunk.to_vec().try_into().map_err(|_| "chunk too large")?;
+		UploadChunks::<T>::insert((caller, hash), chunks, chunk);
+		chunks += 1;
+	}
+	let footprint = Footprint::from_parts(chunks as usize + 1, s as usize);
+	let ticket = T::Consideration::new(caller, footprint)?;
+	let expires_at =
+		frame_system::Pallet::<T>::block_number().saturating_add(T::UploadLifetime::get());
+	PendingUploads::<T>::insert(
+		caller,
+		hash,
+		PendingUpload { ticket, len: s, received: s, chunks, expires_at },
+	);
+	Ok(hash)
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -258,6 +287,72 @@ mod benchmarks {
 		assert_eq!(c, 0);
 	}
 
+	#[benchmark]
+	fn start_preimage() {
+		let caller = funded_account::<T>();
+		let (_, hash) = preimage_and_hash::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), hash, MAX_CHUNK_SIZE);
+
+		assert!(PendingUploads::<T>::contains_key(&caller, &hash));
+	}
+
+	#[benchmark]
+	fn append_chunk(s: Linear<1, MAX_CHUNK_SIZE>) {
+		let caller = funded_account::<T>();
+		let (preimage, hash) = sized_preimage_and_hash::<T>(s);
+		assert_ok!(Pallet::<T>::start_preimage(RawOrigin::Signed(caller.clone()).into(), hash, s));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), hash, preimage);
+
+		assert_eq!(PendingUploads::<T>::get(&caller, &hash).map(|u| u.received), Some(s));
+	}
+
+	// The chunks are read in full, so their proof size is measured rather than estimated from
+	// their maximum size.
+	#[benchmark(pov_mode = MaxEncodedLen {
+		Preimage::UploadChunks: Measured
+	})]
+	fn finalize_preimage(
+		c: Linear<1, MAX_UPLOAD_CHUNKS>,
+		s: Linear<MAX_UPLOAD_CHUNKS, MAX_SIZE>,
+	) -> Result<(), BenchmarkError> {
+		let caller = funded_account::<T>();
+		let hash = uploaded_preimage::<T>(&caller, c, s)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), hash, s);
+
+		assert!(Pallet::<T>::have_preimage(&hash));
+		assert!(!PendingUploads::<T>::contains_key(&caller, &hash));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_upload(c: Linear<0, MAX_UPLOAD_CHUNKS>) {
+		let caller = funded_account::<T>();
+		let (_, hash) = preimage_and_hash::<T>();
+		assert_ok!(Pallet::<T>::start_preimage(
+			RawOrigin::Signed(caller.clone()).into(),
+			hash,
+			MAX_UPLOAD_CHUNKS
+		));
+		for _ in 0..c {
+			assert_ok!(Pallet::<T>::append_chunk(
+				RawOrigin::Signed(caller.clone()).into(),
+				hash,
+				vec![0],
+			));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), caller.clone(), hash);
+
+		assert!(!PendingUploads::<T>::contains_key(&caller, &hash));
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::new_test_ext(),
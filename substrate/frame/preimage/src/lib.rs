@@ -108,9 +108,36 @@ pub enum RequestStatus<AccountId, Ticket> {
 	Requested { maybe_ticket: Option<(AccountId, Ticket)>, count: u32, maybe_len: Option<u32> },
 }
 
+/// An upload of a preimage in chunks which has been started but not yet finalized.
+#[derive(
+	Clone,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+	RuntimeDebug,
+	DecodeWithMemTracking,
+)]
+pub struct PendingUpload<Ticket, BlockNumber> {
+	/// The ticket for the deposit taken for the chunks received so far.
+	pub ticket: Ticket,
+	/// The length of the preimage, as declared when the upload was started.
+	pub len: u32,
+	/// The number of bytes received so far.
+	pub received: u32,
+	/// The number of chunks received so far.
+	pub chunks: u32,
+	/// The block after which anyone may clear the upload.
+	pub expires_at: BlockNumber,
+}
+
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 pub type TicketOf<T> = <T as Config>::Consideration;
+pub type PendingUploadOf<T> =
+	PendingUpload<TicketOf<T>, frame_system::pallet_prelude::BlockNumberFor<T>>;
 
 /// Maximum size of preimage we can store is 4mb.
 pub const MAX_SIZE: u32 = 4 * 1024 * 1024;
@@ -118,6 +145,10 @@ pub const MAX_SIZE: u32 = 4 * 1024 * 1024;
 ///
 /// Exists only for benchmarking purposes.
 pub const MAX_HASH_UPGRADE_BULK_COUNT: u32 = 1024;
+/// Maximum size of a single chunk of a chunked upload is 512kb.
+pub const MAX_CHUNK_SIZE: u32 = 512 * 1024;
+/// Hard-limit on the number of chunks a chunked upload may consist of.
+pub const MAX_UPLOAD_CHUNKS: u32 = 64;
 
 #[frame_support::pallet]
 #[allow(deprecated)]
@@ -146,6 +177,10 @@ pub mod pallet {
 
 		/// A means of providing some cost while data is stored on-chain.
 		type Consideration: Consideration<Self::AccountId, Footprint>;
+
+		/// The number of blocks a chunked upload may stay unfinalized before anyone can clear it.
+		#[pallet::constant]
+		type UploadLifetime: Get<BlockNumberFor<Self>>;
 	}
 
 	#[pallet::pallet]
@@ -161,6 +196,10 @@ pub mod pallet {
 		Requested { hash: T::Hash },
 		/// A preimage has ben cleared.
 		Cleared { hash: T::Hash },
+		/// A chunked upload of a preimage has been started.
+		UploadStarted { hash: T::Hash, who: T::AccountId, len: u32 },
+		/// A chunked upload of a preimage has been cleared without being finalized.
+		UploadCleared { hash: T::Hash, who: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -181,6 +220,22 @@ pub mod pallet {
 		TooMany,
 		/// Too few hashes were requested to be upgraded (i.e. zero).
 		TooFew,
+		/// The sender is already uploading the preimage in chunks.
+		UploadInProgress,
+		/// There is no chunked upload of the preimage in progress.
+		NoUpload,
+		/// The chunk is empty, too large, or exceeds the declared length of the preimage.
+		BadChunk,
+		/// The upload already consists of `MAX_UPLOAD_CHUNKS` chunks.
+		TooManyChunks,
+		/// Not all bytes of the preimage have been uploaded yet.
+		Incomplete,
+		/// The uploaded bytes do not hash to the declared hash.
+		HashMismatch,
+		/// The upload has not expired yet and may only be cleared by its owner.
+		NotExpired,
+		/// The length does not match the length declared when the upload was started.
+		WrongLength,
 	}
 
 	/// A reason for this pallet placing a hold on funds.
@@ -205,12 +260,31 @@ pub mod pallet {
 	pub type PreimageFor<T: Config> =
 		StorageMap<_, Identity, (T::Hash, u32), BoundedVec<u8, ConstU32<MAX_SIZE>>>;
 
+	/// Chunked uploads of preimages which have not yet been finalized, by uploader and hash.
+	#[pallet::storage]
+	pub type PendingUploads<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Identity, T::Hash, PendingUploadOf<T>>;
+
+	/// The chunks received so far for a pending upload, keyed by their index.
+	#[pallet::storage]
+	pub type UploadChunks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(T::AccountId, T::Hash),
+		Twox64Concat,
+		u32,
+		BoundedVec<u8, ConstU32<MAX_CHUNK_SIZE>>,
+	>;
+
 	#[pallet::call(weight = T::WeightInfo)]
 	impl<T: Config> Pallet<T> {
 		/// Register a preimage on-chain.
 		///
 		/// If the preimage was previously requested, no fees or deposits are taken for providing
 		/// the preimage. Otherwise, a deposit is taken proportional to the size of the preimage.
+		///
+		/// Noting a preimage again which the sender already noted recalculates its deposit at the
+		/// current price.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::note_preimage(bytes.len() as u32))]
 		pub fn note_preimage(origin: OriginFor<T>, bytes: Vec<u8>) -> DispatchResultWithPostInfo {
@@ -276,6 +350,132 @@ pub mod pallet {
 			let pays: Pays = (ratio < Perbill::from_percent(90)).into();
 			Ok(pays.into())
 		}
+
+		/// Start uploading a preimage too large for a single extrinsic in chunks.
+		///
+		/// A deposit is taken for the upload and grows with every chunk appended. The upload must
+		/// be finalized with `finalize_preimage` within `UploadLifetime` blocks, after which
+		/// anyone may clear it.
+		///
+		/// Fails with `TooBig` if finalizing a preimage of `len` bytes would not fit into a block.
+		///
+		/// - `hash`: The hash of the preimage to be uploaded.
+		/// - `len`: The length of the preimage to be uploaded.
+		#[pallet::call_index(5)]
+		pub fn start_preimage(origin: OriginFor<T>, hash: T::Hash, len: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(len > 0 && len <= MAX_SIZE, Error::<T>::TooBig);
+			ensure!(Self::can_finalize(len), Error::<T>::TooBig);
+			ensure!(!Self::have(&hash), Error::<T>::AlreadyNoted);
+			ensure!(!PendingUploads::<T>::contains_key(&who, hash), Error::<T>::UploadInProgress);
+
+			let ticket = T::Consideration::new(&who, Footprint::from_parts(1, 0))?;
+			let expires_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::UploadLifetime::get());
+			PendingUploads::<T>::insert(
+				&who,
+				hash,
+				PendingUpload { ticket, len, received: 0, chunks: 0, expires_at },
+			);
+			Self::deposit_event(Event::UploadStarted { hash, who, len });
+			Ok(())
+		}
+
+		/// Append the next chunk to a chunked upload started by the sender.
+		///
+		/// The deposit of the upload is increased to account for the chunk.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::append_chunk(bytes.len() as u32))]
+		pub fn append_chunk(origin: OriginFor<T>, hash: T::Hash, bytes: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut upload = PendingUploads::<T>::get(&who, hash).ok_or(Error::<T>::NoUpload)?;
+			ensure!(upload.chunks < MAX_UPLOAD_CHUNKS, Error::<T>::TooManyChunks);
+
+			let len = bytes.len() as u32;
+			ensure!(
+				len > 0 && upload.received.saturating_add(len) <= upload.len,
+				Error::<T>::BadChunk
+			);
+			let chunk: BoundedVec<u8, ConstU32<MAX_CHUNK_SIZE>> =
+				bytes.try_into().map_err(|_| Error::<T>::BadChunk)?;
+
+			UploadChunks::<T>::insert((&who, hash), upload.chunks, chunk);
+			upload.chunks.saturating_inc();
+			upload.received.saturating_accrue(len);
+			upload.ticket = upload.ticket.update(
+				&who,
+				Footprint::from_parts(
+					upload.chunks.saturating_add(1) as usize,
+					upload.received as usize,
+				),
+			)?;
+			PendingUploads::<T>::insert(&who, hash, upload);
+			Ok(())
+		}
+
+		/// Finalize a chunked upload started by the sender and note the preimage.
+		///
+		/// The uploaded bytes must hash to the hash given in `start_preimage`. The preimage is
+		/// noted as with `note_preimage` and the deposit of the upload is reduced to the deposit
+		/// of the preimage, or released if no deposit is needed. If another account noted the
+		/// preimage in the meantime, the deposit of the upload is released.
+		///
+		/// - `hash`: The hash of the preimage.
+		/// - `len`: The length of the preimage, as given in `start_preimage`.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::finalize_preimage(MAX_UPLOAD_CHUNKS, *len))]
+		pub fn finalize_preimage(
+			origin: OriginFor<T>,
+			hash: T::Hash,
+			len: u32,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let upload = PendingUploads::<T>::take(&who, hash).ok_or(Error::<T>::NoUpload)?;
+			ensure!(upload.len == len, Error::<T>::WrongLength);
+			ensure!(upload.received == upload.len, Error::<T>::Incomplete);
+
+			let mut preimage = Vec::with_capacity(upload.len as usize);
+			for index in 0..upload.chunks {
+				let chunk =
+					UploadChunks::<T>::take((&who, hash), index).ok_or(Error::<T>::Incomplete)?;
+				preimage.extend_from_slice(&chunk);
+			}
+			ensure!(T::Hashing::hash(&preimage) == hash, Error::<T>::HashMismatch);
+
+			let (system_requested, _) =
+				Self::do_note_bytes(preimage.into(), Some((&who, Some(upload.ticket))))?;
+			let actual_weight = T::WeightInfo::finalize_preimage(upload.chunks, upload.len);
+			let pays = if system_requested { Pays::No } else { Pays::Yes };
+			Ok((Some(actual_weight), pays).into())
+		}
+
+		/// Clear a chunked upload without finalizing it, releasing its deposit to its owner.
+		///
+		/// The owner may clear the upload at any time. Anyone else may clear it once it has
+		/// expired, in which case no fee is paid.
+		///
+		/// - `owner`: The account which started the upload.
+		/// - `hash`: The hash of the preimage being uploaded.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::clear_upload(MAX_UPLOAD_CHUNKS))]
+		pub fn clear_upload(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let upload = PendingUploads::<T>::get(&owner, hash).ok_or(Error::<T>::NoUpload)?;
+			let is_owner = owner == who;
+			ensure!(
+				is_owner || frame_system::Pallet::<T>::block_number() > upload.expires_at,
+				Error::<T>::NotExpired
+			);
+
+			let chunks = Self::do_clear_upload(owner, &hash, upload);
+			let actual_weight = T::WeightInfo::clear_upload(chunks);
+			let pays = if is_owner { Pays::Yes } else { Pays::No };
+			Ok((Some(actual_weight), pays).into())
+		}
 	}
 }
 
@@ -327,6 +527,28 @@ impl<T: Config> Pallet<T> {
 		true
 	}
 
+	/// Remove a pending upload of `owner` and its chunks, releasing its deposit.
+	///
+	/// Returns the number of chunks removed.
+	fn do_clear_upload(owner: T::AccountId, hash: &T::Hash, upload: PendingUploadOf<T>) -> u32 {
+		for index in 0..upload.chunks {
+			UploadChunks::<T>::remove((&owner, hash), index);
+		}
+		PendingUploads::<T>::remove(&owner, hash);
+		let _ = upload.ticket.drop(&owner);
+		Self::deposit_event(Event::UploadCleared { hash: *hash, who: owner });
+		upload.chunks
+	}
+
+	/// Whether finalizing an upload of a preimage of `len` bytes fits into a block.
+	fn can_finalize(len: u32) -> bool {
+		let max_extrinsic = T::BlockWeights::get()
+			.get(DispatchClass::Normal)
+			.max_extrinsic
+			.unwrap_or_else(|| T::BlockWeights::get().max_block);
+		T::WeightInfo::finalize_preimage(MAX_UPLOAD_CHUNKS, len).all_lte(max_extrinsic)
+	}
+
 	/// Ensure that the origin is either the `ManagerOrigin` or a signed origin.
 	fn ensure_signed_or_manager(
 		origin: T::RuntimeOrigin,
@@ -345,9 +567,22 @@ impl<T: Config> Pallet<T> {
 	/// We verify that the preimage is within the bounds of what the pallet supports.
 	///
 	/// If the preimage was requested to be uploaded, then the user pays no deposits or tx fees.
+	///
+	/// If the preimage was already noted by the same depositor, its deposit is recalculated.
 	fn note_bytes(
 		preimage: Cow<[u8]>,
 		maybe_depositor: Option<&T::AccountId>,
+	) -> Result<(bool, T::Hash), DispatchError> {
+		Self::do_note_bytes(preimage, maybe_depositor.map(|depositor| (depositor, None)))
+	}
+
+	/// Same as [`Self::note_bytes`], except that the depositor may already hold a ticket.
+	///
+	/// If a deposit is taken, the ticket is updated to the footprint of the preimage, settling
+	/// only the difference. Otherwise it is dropped.
+	fn do_note_bytes(
+		preimage: Cow<[u8]>,
+		maybe_depositor: Option<(&T::AccountId, Option<TicketOf<T>>)>,
 	) -> Result<(bool, T::Hash), DispatchError> {
 		let hash = T::Hashing::hash(&preimage);
 		let len = preimage.len() as u32;
@@ -357,8 +592,28 @@ impl<T: Config> Pallet<T> {
 		// We take a deposit only if there is a provided depositor and the preimage was not
 		// previously requested. This also allows the tx to pay no fee.
 		let status = match (RequestStatusFor::<T>::get(hash), maybe_depositor) {
-			(Some(RequestStatus::Requested { maybe_ticket, count, .. }), _) =>
-				RequestStatus::Requested { maybe_ticket, count, maybe_len: Some(len) },
+			(Some(RequestStatus::Requested { maybe_ticket, count, .. }), maybe_depositor) => {
+				if let Some((depositor, Some(ticket))) = maybe_depositor {
+					let _ = ticket.drop(depositor);
+				}
+				RequestStatus::Requested { maybe_ticket, count, maybe_len: Some(len) }
+			},
+			(
+				Some(RequestStatus::Unrequested { ticket: (owner, ticket), len }),
+				Some((depositor, maybe_ticket)),
+			) if owner == *depositor => {
+				// Noted again by its owner: recalculate the deposit at the current price.
+				if let Some(ticket) = maybe_ticket {
+					let _ = ticket.drop(depositor);
+				}
+				let ticket = ticket.update(depositor, Footprint::from_parts(1, len as usize))?;
+				RequestStatus::Unrequested { ticket: (owner, ticket), len }
+			},
+			(Some(RequestStatus::Unrequested { ticket, len }), Some((depositor, Some(upload)))) => {
+				// Noted by someone else while uploading: the upload is no longer needed.
+				let _ = upload.drop(depositor);
+				RequestStatus::Unrequested { ticket, len }
+			},
 			(Some(RequestStatus::Unrequested { .. }), Some(_)) =>
 				return Err(Error::<T>::AlreadyNoted.into()),
 			(Some(RequestStatus::Unrequested { ticket, len }), None) => RequestStatus::Requested {
@@ -368,9 +623,12 @@ impl<T: Config> Pallet<T> {
 			},
 			(None, None) =>
 				RequestStatus::Requested { maybe_ticket: None, count: 1, maybe_len: Some(len) },
-			(None, Some(depositor)) => {
-				let ticket =
-					T::Consideration::new(depositor, Footprint::from_parts(1, len as usize))?;
+			(None, Some((depositor, maybe_ticket))) => {
+				let footprint = Footprint::from_parts(1, len as usize);
+				let ticket = match maybe_ticket {
+					Some(ticket) => ticket.update(depositor, footprint)?,
+					None => T::Consideration::new(depositor, footprint)?,
+				};
 				RequestStatus::Unrequested { ticket: (depositor.clone(), ticket), len }
 			},
 		};
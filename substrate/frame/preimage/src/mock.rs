@@ -60,12 +60,13 @@ ord_parameter_types! {
 
 parameter_types! {
 	pub const PreimageHoldReason: RuntimeHoldReason = RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+	pub storage ByteDeposit: u64 = 1;
 }
 
 pub struct ConvertDeposit;
 impl Convert<Footprint, u64> for ConvertDeposit {
 	fn convert(a: Footprint) -> u64 {
-		a.count * 2 + a.size
+		a.count * 2 + a.size * ByteDeposit::get()
 	}
 }
 
@@ -75,6 +76,7 @@ impl Config for Test {
 	type Currency = Balances;
 	type ManagerOrigin = EnsureSignedBy<One, u64>;
 	type Consideration = HoldConsideration<u64, Balances, PreimageHoldReason, ConvertDeposit>;
+	type UploadLifetime = ConstU64<10>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -66,7 +66,7 @@ fn user_note_preimage_works() {
 		assert_eq!(Preimage::get_preimage(&h), Some(vec![1]));
 
 		assert_noop!(
-			Preimage::note_preimage(RuntimeOrigin::signed(2), vec![1]),
+			Preimage::note_preimage(RuntimeOrigin::signed(3), vec![1]),
 			Error::<Test>::AlreadyNoted,
 		);
 		assert_noop!(
@@ -76,6 +76,29 @@ fn user_note_preimage_works() {
 	});
 }
 
+#[test]
+fn user_renote_preimage_recalculates_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![1]));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 3);
+
+		ByteDeposit::set(&5);
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![1]));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 7);
+		assert_eq!(Balances::free_balance(2), 93);
+
+		ByteDeposit::set(&1);
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), vec![1]));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 3);
+		assert_eq!(Balances::free_balance(2), 97);
+
+		let h = hashed([1]);
+		assert_eq!(Preimage::get_preimage(&h), Some(vec![1]));
+		assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(2), h));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 0);
+	});
+}
+
 #[test]
 fn manager_note_preimage_works() {
 	new_test_ext().execute_with(|| {
@@ -520,3 +543,204 @@ fn ensure_updated_works() {
 		}
 	});
 }
+
+#[test]
+fn chunked_upload_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let data: Vec<u8> = (1..=10).collect();
+		let hash = hashed(&data);
+		let held = || Balances::balance_on_hold(&PreimageHoldReason::get(), &2);
+
+		assert_ok!(Preimage::start_preimage(RuntimeOrigin::signed(2), hash, 10));
+		assert_eq!(held(), 2);
+		assert_noop!(
+			Preimage::start_preimage(RuntimeOrigin::signed(2), hash, 10),
+			Error::<Test>::UploadInProgress
+		);
+
+		// Every chunk increases the deposit.
+		assert_ok!(Preimage::append_chunk(RuntimeOrigin::signed(2), hash, data[..4].to_vec()));
+		assert_eq!(held(), 8);
+		assert_ok!(Preimage::append_chunk(RuntimeOrigin::signed(2), hash, data[4..].to_vec()));
+		assert_eq!(held(), 16);
+
+		assert_ok!(Preimage::finalize_preimage(RuntimeOrigin::signed(2), hash, 10));
+		System::assert_last_event(Event::Noted { hash }.into());
+		assert!(Preimage::have_preimage(&hash));
+		assert_eq!(Preimage::get_preimage(&hash), Some(data));
+		// Only the deposit for the noted preimage is held, the difference is released.
+		assert_eq!(held(), 12);
+		System::assert_has_event(
+			pallet_balances::Event::Released {
+				reason: PreimageHoldReason::get(),
+				who: 2,
+				amount: 4,
+			}
+			.into(),
+		);
+		assert!(!PendingUploads::<Test>::contains_key(2, hash));
+		assert_eq!(UploadChunks::<Test>::iter().count(), 0);
+
+		assert_noop!(
+			Preimage::start_preimage(RuntimeOrigin::signed(2), hash, 10),
+			Error::<Test>::AlreadyNoted
+		);
+	});
+}
+
+#[test]
+fn chunked_uploads_of_the_same_preimage_do_not_interfere() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = (1..=10).collect();
+		let hash = hashed(&data);
+
+		assert_ok!(Preimage::start_preimage(RuntimeOrigin::signed(2), hash, 10));
+		assert_ok!(Preimage::start_preimage(RuntimeOrigin::signed(3), hash, 10));
+		assert_ok!(Preimage::append_chunk(RuntimeOrigin::signed(2), hash, data[..5].to_vec()));
+		assert_ok!(Preimage::append_chunk(RuntimeOrigin::signed(3), hash, vec![0; 10]));
+		assert_ok!(Preimage::append_chunk(RuntimeOrigin::signed(2), hash, data[5..].to_vec()));
+
+		// A bogus upload of another account does not prevent the preimage from being noted.
+		assert_noop!(
+			Preimage::finalize_preimage(RuntimeOrigin::signed(3), hash, 10),
+			Error::<Test>::HashMismatch
+		);
+		assert_ok!(Preimage::finalize_preimage(RuntimeOrigin::signed(2), hash, 10));
+		assert_eq!(Preimage::get_preimage(&hash), Some(data));
+
+		assert_ok!(Preimage::clear_upload(RuntimeOrigin::signed(3), 3, hash));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &3), 0);
+		assert_eq!(PendingUploads::<Test>::iter().count(), 0);
+		assert_eq!(UploadChunks::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn chunked_upload_of_requested_preimage_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = (1..=10).collect();
+		let hash = hashed(&data);
+		let held = || Balances::balance_on_hold(&PreimageHoldReason::get(), &2);
+
+		assert_ok!(Preimage::start_preimage(RuntimeOrigin::signed(2), hash, 10));
+		assert_ok!(Preimage::append_chunk(RuntimeOrigin::signed(2), hash, data.clone()));
+		assert_eq!(held(), 14);
+
+		assert_ok!(Preimage::request_preimage(RuntimeOrigin::signed(1), hash));
+		let res = Preimage::finalize_preimage(RuntimeOrigin::signed(2), hash, 10).unwrap();
+		assert_eq!(res.pays_fee, Pays::No);
+		assert_eq!(Preimage::get_preimage(&hash), Some(data));
+		assert_eq!(held(), 0);
+	});
+}
+
+#[test]
+fn chunked_upload_of_noted_preimage_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = (1..=10).collect();
+		let hash = hashed(&data);
+		let held = |who| Balances::balance_on_hold(&PreimageHoldReason::get(), &who);
+
+		assert_ok!(Preimage::start_preimage(RuntimeOrigin::signed(2), hash, 10));
+		assert_ok!(Preimage::append_chunk(RuntimeOrigin::signed(2), hash, data.clone()));
+		assert_eq!(held(2), 14);
+
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(3), data.clone()));
+		assert_eq!(held(3), 12);
+
+		// The upload is no longer needed, the preimage stays with the deposit of account 3.
+		assert_ok!(Preimage::finalize_preimage(RuntimeOrigin::signed(2), hash, 10));
+		assert_eq!(held(2), 0);
+		assert_eq!(held(3), 12);
+		assert_eq!(Preimage::get_preimage(&hash), Some(data));
+		assert!(!PendingUploads::<Test>::contains_key(2, hash));
+	});
+}
+
+#[test]
+fn chunked_upload_checks_chunks_and_hash() {
+	new_test_ext().execute_with(|| {
+		let data: Vec<u8> = (1..=10).collect();
+		let hash = hashed(&data);
+
+		assert_noop!(
+			Preimage::start_preimage(RuntimeOrigin::signed(2), hash, MAX_SIZE + 1),
+			Error::<Test>::TooBig
+		);
+		assert_noop!(
+			Preimage::append_chunk(RuntimeOrigin::signed(2), hash, data.clone()),
+			Error::<Test>::NoUpload
+		);
+
+		assert_ok!(Preimage::start_preimage(RuntimeOrigin::signed(2), hash, 10));
+		// Uploads are kept per account.
+		assert_noop!(
+			Preimage::append_chunk(RuntimeOrigin::signed(3), hash, data.clone()),
+			Error::<Test>::NoUpload
+		);
+		assert_noop!(
+			Preimage::append_chunk(RuntimeOrigin::signed(2), hash, vec![0; 11]),
+			Error::<Test>::BadChunk
+		);
+		assert_noop!(
+			Preimage::append_chunk(RuntimeOrigin::signed(2), hash, vec![]),
+			Error::<Test>::BadChunk
+		);
+
+		assert_ok!(Preimage::append_chunk(RuntimeOrigin::signed(2), hash, vec![0; 5]));
+		assert_noop!(
+			Preimage::finalize_preimage(RuntimeOrigin::signed(2), hash, 10),
+			Error::<Test>::Incomplete
+		);
+		assert_ok!(Preimage::append_chunk(RuntimeOrigin::signed(2), hash, vec![0; 5]));
+		assert_noop!(
+			Preimage::finalize_preimage(RuntimeOrigin::signed(3), hash, 10),
+			Error::<Test>::NoUpload
+		);
+		assert_noop!(
+			Preimage::finalize_preimage(RuntimeOrigin::signed(2), hash, 9),
+			Error::<Test>::WrongLength
+		);
+		// The uploaded bytes do not match the declared hash.
+		assert_noop!(
+			Preimage::finalize_preimage(RuntimeOrigin::signed(2), hash, 10),
+			Error::<Test>::HashMismatch
+		);
+		assert!(!Preimage::have_preimage(&hash));
+	});
+}
+
+#[test]
+fn abandoned_upload_can_be_cleared() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let hash = hashed([1; 10]);
+
+		assert_ok!(Preimage::start_preimage(RuntimeOrigin::signed(2), hash, 10));
+		assert_ok!(Preimage::append_chunk(RuntimeOrigin::signed(2), hash, vec![1; 5]));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 9);
+
+		// Others may only clear the upload once it expired.
+		assert_noop!(
+			Preimage::clear_upload(RuntimeOrigin::signed(3), 2, hash),
+			Error::<Test>::NotExpired
+		);
+		System::set_block_number(12);
+		let res = Preimage::clear_upload(RuntimeOrigin::signed(3), 2, hash).unwrap();
+		assert_eq!(res.pays_fee, Pays::No);
+		System::assert_last_event(Event::UploadCleared { hash, who: 2 }.into());
+
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 0);
+		assert!(!PendingUploads::<Test>::contains_key(2, hash));
+		assert_eq!(UploadChunks::<Test>::iter().count(), 0);
+
+		// The owner may clear the upload at any time.
+		assert_ok!(Preimage::start_preimage(RuntimeOrigin::signed(2), hash, 10));
+		assert_ok!(Preimage::clear_upload(RuntimeOrigin::signed(2), 2, hash));
+		assert_noop!(
+			Preimage::clear_upload(RuntimeOrigin::signed(2), 2, hash),
+			Error::<Test>::NoUpload
+		);
+	});
+}
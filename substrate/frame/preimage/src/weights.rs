@@ -85,6 +85,10 @@ pub trait WeightInfo {
 	fn unrequest_unnoted_preimage() -> Weight;
 	fn unrequest_multi_referenced_preimage() -> Weight;
 	fn ensure_updated(n: u32, ) -> Weight;
+	fn start_preimage() -> Weight;
+	fn append_chunk(s: u32, ) -> Weight;
+	fn finalize_preimage(c: u32, s: u32, ) -> Weight;
+	fn clear_upload(c: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_preimage` using the Substrate node and recommended hardware.
@@ -297,6 +301,91 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2902).saturating_mul(n.into()))
 	}
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PendingUploads` (r:1 w:1)
+	/// Proof: `Preimage::PendingUploads` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	fn start_preimage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3892`
+		// Minimum execution time: 36_589_000 picoseconds.
+		Weight::from_parts(38_114_000, 3892)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Preimage::PendingUploads` (r:1 w:1)
+	/// Proof: `Preimage::PendingUploads` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::UploadChunks` (r:0 w:1)
+	/// Proof: `Preimage::UploadChunks` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 524288]`.
+	fn append_chunk(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `197`
+		//  Estimated: `3892`
+		// Minimum execution time: 39_717_000 picoseconds.
+		Weight::from_parts(41_372_000, 3892)
+			// Standard Error: 40
+			.saturating_add(Weight::from_parts(1_612, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Preimage::PendingUploads` (r:1 w:1)
+	/// Proof: `Preimage::PendingUploads` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::UploadChunks` (r:64 w:64)
+	/// Proof: `Preimage::UploadChunks` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `Measured`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageFor` (r:0 w:1)
+	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 64]`.
+	/// The range of component `s` is `[64, 4194304]`.
+	fn finalize_preimage(c: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229 + c * (93 ±0) + s * (1 ±0)`
+		//  Estimated: `3892 + c * (2568 ±0) + s * (1 ±0)`
+		// Minimum execution time: 69_989_000 picoseconds.
+		Weight::from_parts(72_905_000, 3892)
+			// Standard Error: 130_325
+			.saturating_add(Weight::from_parts(5_213_000, 0).saturating_mul(c.into()))
+			// Standard Error: 355
+			.saturating_add(Weight::from_parts(14_207, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2568).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Preimage::PendingUploads` (r:1 w:1)
+	/// Proof: `Preimage::PendingUploads` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::UploadChunks` (r:0 w:64)
+	/// Proof: `Preimage::UploadChunks` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 64]`.
+	fn clear_upload(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229`
+		//  Estimated: `3892`
+		// Minimum execution time: 34_003_000 picoseconds.
+		Weight::from_parts(35_420_000, 3892)
+			// Standard Error: 54_725
+			.saturating_add(Weight::from_parts(2_189_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -508,4 +597,89 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2902).saturating_mul(n.into()))
 	}
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PendingUploads` (r:1 w:1)
+	/// Proof: `Preimage::PendingUploads` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	fn start_preimage() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3892`
+		// Minimum execution time: 36_589_000 picoseconds.
+		Weight::from_parts(38_114_000, 3892)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Preimage::PendingUploads` (r:1 w:1)
+	/// Proof: `Preimage::PendingUploads` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::UploadChunks` (r:0 w:1)
+	/// Proof: `Preimage::UploadChunks` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 524288]`.
+	fn append_chunk(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `197`
+		//  Estimated: `3892`
+		// Minimum execution time: 39_717_000 picoseconds.
+		Weight::from_parts(41_372_000, 3892)
+			// Standard Error: 40
+			.saturating_add(Weight::from_parts(1_612, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Preimage::PendingUploads` (r:1 w:1)
+	/// Proof: `Preimage::PendingUploads` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::UploadChunks` (r:64 w:64)
+	/// Proof: `Preimage::UploadChunks` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `Measured`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageFor` (r:0 w:1)
+	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 64]`.
+	/// The range of component `s` is `[64, 4194304]`.
+	fn finalize_preimage(c: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229 + c * (93 ±0) + s * (1 ±0)`
+		//  Estimated: `3892 + c * (2568 ±0) + s * (1 ±0)`
+		// Minimum execution time: 69_989_000 picoseconds.
+		Weight::from_parts(72_905_000, 3892)
+			// Standard Error: 130_325
+			.saturating_add(Weight::from_parts(5_213_000, 0).saturating_mul(c.into()))
+			// Standard Error: 355
+			.saturating_add(Weight::from_parts(14_207, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 2568).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(s.into()))
+	}
+	/// Storage: `Preimage::PendingUploads` (r:1 w:1)
+	/// Proof: `Preimage::PendingUploads` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(427), added: 2902, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::UploadChunks` (r:0 w:64)
+	/// Proof: `Preimage::UploadChunks` (`max_values`: None, `max_size`: Some(524384), added: 526859, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 64]`.
+	fn clear_upload(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229`
+		//  Estimated: `3892`
+		// Minimum execution time: 34_003_000 picoseconds.
+		Weight::from_parts(35_420_000, 3892)
+			// Standard Error: 54_725
+			.saturating_add(Weight::from_parts(2_189_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
 }
@@ -21,7 +21,7 @@
 
 use super::*;
 use crate::Pallet as Proxy;
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use frame::benchmarking::prelude::{
 	account, benchmarks, impl_test_function, whitelisted_caller, BenchmarkError, RawOrigin,
 };
@@ -36,6 +36,20 @@ fn assert_has_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_has_event(generic_event.into());
 }
 
+/// Assert that the proxied call was executed, or filtered because the `TransferMeter` does not know
+/// it. In both cases the allowance of the proxy was read.
+fn assert_proxy_executed<T: Config>() {
+	let last_event = frame_system::Pallet::<T>::events()
+		.pop()
+		.expect("an event was emitted; qed")
+		.event;
+	let executed: [<T as Config>::RuntimeEvent; 2] = [
+		Event::ProxyExecuted { result: Ok(()) }.into(),
+		Event::ProxyExecuted { result: Err(frame_system::Error::<T>::CallFiltered.into()) }.into(),
+	];
+	assert!(executed.into_iter().any(|event| event.into() == last_event));
+}
+
 fn add_proxies<T: Config>(n: u32, maybe_who: Option<T::AccountId>) -> Result<(), &'static str> {
 	let caller = maybe_who.unwrap_or_else(whitelisted_caller);
 	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
@@ -84,6 +98,41 @@ fn add_announcements<T: Config>(
 	Ok(())
 }
 
+/// Give all proxies of `who` an expiry in the future, so that the expiry of each proxy is read
+/// and removed.
+fn add_expiries<T: Config>(who: &T::AccountId) -> Result<(), &'static str> {
+	let expiry = T::BlockNumberProvider::current_block_number() + 1000u32.into();
+	for def in Proxies::<T>::get(who).0 {
+		let deposit = Proxy::<T>::expiry_deposit();
+		T::Currency::reserve(who, deposit)?;
+		ProxyExpiries::<T>::insert(who, def, (expiry, deposit));
+	}
+	Ok(())
+}
+
+fn allowance_limits<T: Config>(n: u32) -> AllowanceLimitsOf<T> {
+	(0..n)
+		.map(|i| (T::TransferMeter::benchmark_asset(i), BalanceOf::<T>::max_value()))
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("`n` is at most `MaxAllowanceAssets`; qed")
+}
+
+/// Give all proxies of `who` an allowance limiting the maximum number of assets.
+fn add_allowances<T: Config>(who: &T::AccountId) -> Result<(), &'static str> {
+	for def in Proxies::<T>::get(who).0 {
+		Proxy::<T>::set_proxy_allowance(
+			RawOrigin::Signed(who.clone()).into(),
+			T::Lookup::unlookup(def.delegate),
+			def.proxy_type,
+			def.delay,
+			allowance_limits::<T>(T::MaxAllowanceAssets::get()),
+			10u32.into(),
+		)?;
+	}
+	Ok(())
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -96,6 +145,8 @@ mod benchmarks {
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		// ... and "real" is the traditional caller. This is not a typo.
 		let real: T::AccountId = whitelisted_caller();
+		add_expiries::<T>(&real)?;
+		add_allowances::<T>(&real)?;
 		let real_lookup = T::Lookup::unlookup(real);
 		let call: <T as Config>::RuntimeCall =
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
@@ -103,7 +154,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), real_lookup, Some(T::ProxyType::default()), Box::new(call));
 
-		assert_last_event::<T>(Event::ProxyExecuted { result: Ok(()) }.into());
+		assert_proxy_executed::<T>();
 
 		Ok(())
 	}
@@ -121,6 +172,8 @@ mod benchmarks {
 		T::Currency::make_free_balance_be(&delegate, BalanceOf::<T>::max_value() / 2u32.into());
 		// ... and "real" is the traditional caller. This is not a typo.
 		let real: T::AccountId = whitelisted_caller();
+		add_expiries::<T>(&real)?;
+		add_allowances::<T>(&real)?;
 		let real_lookup = T::Lookup::unlookup(real);
 		let call: <T as Config>::RuntimeCall =
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
@@ -140,7 +193,7 @@ mod benchmarks {
 			Box::new(call),
 		);
 
-		assert_last_event::<T>(Event::ProxyExecuted { result: Ok(()) }.into());
+		assert_proxy_executed::<T>();
 
 		Ok(())
 	}
@@ -217,6 +270,7 @@ mod benchmarks {
 		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
 		// ... and "real" is the traditional caller. This is not a typo.
 		let real: T::AccountId = whitelisted_caller();
+		add_expiries::<T>(&real)?;
 		let real_lookup = T::Lookup::unlookup(real.clone());
 		add_announcements::<T>(a, Some(caller.clone()), None)?;
 		let call: <T as Config>::RuntimeCall =
@@ -255,6 +309,8 @@ mod benchmarks {
 	fn remove_proxy(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
 		add_proxies::<T>(p, None)?;
 		let caller: T::AccountId = whitelisted_caller();
+		add_expiries::<T>(&caller)?;
+		add_allowances::<T>(&caller)?;
 		let delegate = T::Lookup::unlookup(account("target", 0, SEED));
 
 		#[extrinsic_call]
@@ -271,16 +327,141 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn add_proxy_with_expiry(
+		p: Linear<1, { T::MaxProxies::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let real = T::Lookup::unlookup(account("target", T::MaxProxies::get(), SEED));
+		let expiry = T::BlockNumberProvider::current_block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			real,
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+			expiry,
+		);
+
+		let (proxies, _) = Proxies::<T>::get(&caller);
+		assert_eq!(proxies.len() as u32, p + 1);
+		assert_eq!(ProxyExpiries::<T>::iter_prefix(&caller).count(), 1);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reap_expired_proxy(
+		p: Linear<1, { T::MaxProxies::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		add_proxies::<T>(p, None)?;
+		let delegator: T::AccountId = whitelisted_caller();
+		add_allowances::<T>(&delegator)?;
+		let delegate: T::AccountId = account("target", 0, SEED);
+		let def = ProxyDefinition {
+			delegate: delegate.clone(),
+			proxy_type: T::ProxyType::default(),
+			delay: BlockNumberFor::<T>::zero(),
+		};
+		// Expires at the current block.
+		let deposit = Proxy::<T>::expiry_deposit();
+		T::Currency::reserve(&delegator, deposit)?;
+		ProxyExpiries::<T>::insert(
+			&delegator,
+			&def,
+			(T::BlockNumberProvider::current_block_number(), deposit),
+		);
+		let reaper: T::AccountId = account("reaper", 0, SEED);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(reaper),
+			T::Lookup::unlookup(delegator.clone()),
+			T::Lookup::unlookup(delegate),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+		);
+
+		let (proxies, _) = Proxies::<T>::get(&delegator);
+		assert_eq!(proxies.len() as u32, p - 1);
+		assert!(!ProxyExpiries::<T>::contains_key(&delegator, &def));
+		assert!(!ProxyAllowances::<T>::contains_key(&delegator, &def));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_proxy_allowance(
+		a: Linear<0, { T::MaxAllowanceAssets::get() }>,
+	) -> Result<(), BenchmarkError> {
+		add_proxies::<T>(T::MaxProxies::get(), None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", 0, SEED);
+		let limits: AllowanceLimitsOf<T> = allowance_limits::<T>(a);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			T::Lookup::unlookup(delegate.clone()),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+			limits,
+			10u32.into(),
+		);
+
+		let def = ProxyDefinition {
+			delegate,
+			proxy_type: T::ProxyType::default(),
+			delay: BlockNumberFor::<T>::zero(),
+		};
+		assert_eq!(ProxyAllowances::<T>::get(&caller, &def).unwrap().assets.len() as u32, a);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_proxy_allowance() -> Result<(), BenchmarkError> {
+		add_proxies::<T>(T::MaxProxies::get(), None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", 0, SEED);
+		Proxy::<T>::set_proxy_allowance(
+			RawOrigin::Signed(caller.clone()).into(),
+			T::Lookup::unlookup(delegate.clone()),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+			allowance_limits::<T>(T::MaxAllowanceAssets::get()),
+			10u32.into(),
+		)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			T::Lookup::unlookup(delegate),
+			T::ProxyType::default(),
+			BlockNumberFor::<T>::zero(),
+		);
+
+		assert_eq!(ProxyAllowances::<T>::iter_prefix(&caller).count(), 0);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn remove_proxies(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
 		add_proxies::<T>(p, None)?;
 		let caller: T::AccountId = whitelisted_caller();
+		add_expiries::<T>(&caller)?;
+		add_allowances::<T>(&caller)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()));
 
-		let (proxies, _) = Proxies::<T>::get(caller);
+		let (proxies, _) = Proxies::<T>::get(&caller);
 		assert_eq!(proxies.len() as u32, 0);
+		assert_eq!(ProxyExpiries::<T>::iter_prefix(&caller).count(), 0);
+		assert_eq!(ProxyAllowances::<T>::iter_prefix(&caller).count(), 0);
 
 		Ok(())
 	}
@@ -331,6 +512,8 @@ mod benchmarks {
 
 		add_proxies::<T>(p, Some(pure_account.clone()))?;
 		ensure!(Proxies::<T>::contains_key(&pure_account), "pure proxy not created");
+		add_expiries::<T>(&pure_account)?;
+		add_allowances::<T>(&pure_account)?;
 
 		#[extrinsic_call]
 		_(
@@ -343,6 +526,8 @@ mod benchmarks {
 		);
 
 		assert!(!Proxies::<T>::contains_key(&pure_account));
+		assert_eq!(ProxyExpiries::<T>::iter_prefix(&pure_account).count(), 0);
+		assert_eq!(ProxyAllowances::<T>::iter_prefix(&pure_account).count(), 0);
 
 		Ok(())
 	}
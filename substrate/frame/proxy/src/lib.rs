@@ -30,14 +30,16 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
 extern crate alloc;
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, rc::Rc, vec, vec::Vec};
+use core::cell::RefCell;
 use frame::{
 	prelude::*,
-	traits::{Currency, InstanceFilter, ReservableCurrency},
+	traits::{Currency, InstanceFilter, InstanceFilterMatrix, PalletCalls, ReservableCurrency},
 };
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -52,6 +54,71 @@ pub type BlockNumberFor<T> =
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+type AllowanceAssetOf<T> = <<T as Config>::TransferMeter as TransferMeter<
+	<T as Config>::RuntimeCall,
+	BalanceOf<T>,
+>>::Asset;
+
+type AllowanceLimitsOf<T> =
+	BoundedVec<(AllowanceAssetOf<T>, BalanceOf<T>), <T as Config>::MaxAllowanceAssets>;
+
+type ProxyAllowanceOf<T> = ProxyAllowance<
+	BoundedVec<
+		AssetAllowance<AllowanceAssetOf<T>, BalanceOf<T>>,
+		<T as Config>::MaxAllowanceAssets,
+	>,
+	BalanceOf<T>,
+	BlockNumberFor<T>,
+>;
+
+/// What a call transfers out of the account it is dispatched from, as reported by a
+/// [`TransferMeter`].
+#[derive(Clone, Eq, PartialEq, RuntimeDebug)]
+pub enum Transferred<Asset, Balance> {
+	/// The call does not transfer anything itself. Calls that only dispatch other calls with the
+	/// same origin, like `Utility::batch`, belong here, since the dispatched calls are metered on
+	/// their own.
+	Nothing,
+	/// The call transfers at most the given amount of the asset.
+	Amount(Asset, Balance),
+	/// The call may transfer funds in a way that the meter does not measure.
+	Unknown,
+}
+
+/// Determines the asset and amount a call transfers out of the account it is dispatched from, so
+/// that allowances of proxies can be metered.
+///
+/// Proxies with an allowance may only dispatch calls which are reported as [`Transferred::Nothing`]
+/// or [`Transferred::Amount`], whatever their proxy type is. Everything else, including asset
+/// swaps, XCM transfers or contract calls with value, must be reported as
+/// [`Transferred::Unknown`].
+pub trait TransferMeter<Call, Balance> {
+	/// The kind of asset an allowance can be given for.
+	type Asset: Parameter + Member + MaxEncodedLen;
+
+	/// What `call` transfers.
+	///
+	/// Calls whose amount is not known up front, like `transfer_all`, should report the maximum
+	/// balance.
+	fn transferred(call: &Call) -> Transferred<Self::Asset, Balance>;
+
+	/// Return the `i`-th asset to be used in benchmarks.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn benchmark_asset(i: u32) -> Self::Asset;
+}
+
+/// Does not meter anything, so proxies with an allowance cannot dispatch any call.
+impl<Call, Balance> TransferMeter<Call, Balance> for () {
+	type Asset = ();
+
+	fn transferred(_: &Call) -> Transferred<(), Balance> {
+		Transferred::Unknown
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn benchmark_asset(_: u32) {}
+}
+
 /// The parameters under which a particular account has a proxy relationship with some other
 /// account.
 #[derive(
@@ -78,6 +145,50 @@ pub struct ProxyDefinition<AccountId, ProxyType, BlockNumber> {
 	pub delay: BlockNumber,
 }
 
+/// The amount of an asset a proxy may transfer per allowance period.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct AssetAllowance<Asset, Balance> {
+	/// The asset the allowance is given for.
+	pub asset: Asset,
+	/// The maximum amount that may be transferred per period.
+	pub limit: Balance,
+	/// The amount transferred in the current period.
+	pub spent: Balance,
+}
+
+/// The spending allowance of a proxy, metered over consecutive periods.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct ProxyAllowance<Assets, Balance, BlockNumber> {
+	/// The allowance of each asset. Transfers of assets which are not listed are not allowed.
+	pub assets: Assets,
+	/// The length of a period, after which the spent amounts are reset.
+	pub period: BlockNumber,
+	/// The block at which the current period started.
+	pub period_start: BlockNumber,
+	/// The amount held on deposit for this allowance.
+	pub deposit: Balance,
+}
+
 /// Details surrounding a specific instance of an announcement to make a call.
 #[derive(
 	Encode,
@@ -120,6 +231,18 @@ pub enum DepositKind {
 	Announcements,
 }
 
+/// The calls that a proxy type may dispatch.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ProxyCapabilities {
+	/// The proxy type may dispatch exactly these calls, as pallet index and call indices of the
+	/// pallet.
+	Calls(Vec<(u8, Vec<u8>)>),
+	/// The calls cannot be determined statically, since the filter of the proxy type has custom
+	/// logic, e.g. depending on the arguments of a call, or is unknown. Whether a specific call is
+	/// allowed has to be checked with [`Pallet::check_permissions`].
+	Custom,
+}
+
 #[frame::pallet]
 pub mod pallet {
 	use super::*;
@@ -223,6 +346,16 @@ pub mod pallet {
 		///     providers can be used. Relay provider can be a bit better in cases where the
 		///     parachain is lagging its block production to avoid clock skew.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// Determines which calls transfer funds, and how much, for metering proxy allowances.
+		///
+		/// Proxies with an allowance cannot dispatch calls that it reports as
+		/// [`Transferred::Unknown`].
+		type TransferMeter: TransferMeter<<Self as Config>::RuntimeCall, BalanceOf<Self>>;
+
+		/// The maximum number of assets a single proxy allowance may limit.
+		#[pallet::constant]
+		type MaxAllowanceAssets: Get<u32>;
 	}
 
 	#[pallet::call]
@@ -242,6 +375,8 @@ pub mod pallet {
 			(T::WeightInfo::proxy(T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // Spent allowance of the proxy, if any.
+				.saturating_add(T::DbWeight::get().writes(1))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -404,6 +539,8 @@ pub mod pallet {
 			ensure!(proxy == who, Error::<T>::NoPermission);
 
 			let (_, deposit) = Proxies::<T>::take(&who);
+			Self::remove_all_expiries(&who);
+			Self::remove_all_allowances(&who);
 			T::Currency::unreserve(&spawner, deposit);
 
 			Self::deposit_event(Event::PureKilled {
@@ -440,17 +577,18 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let real = T::Lookup::lookup(real)?;
-			Proxies::<T>::get(&real)
-				.0
-				.into_iter()
-				.find(|x| x.delegate == who)
-				.ok_or(Error::<T>::NotProxy)?;
-
-			let announcement = Announcement {
-				real: real.clone(),
-				call_hash,
-				height: T::BlockNumberProvider::current_block_number(),
-			};
+			let now = T::BlockNumberProvider::current_block_number();
+			Self::find_proxy(&real, &who, None)?;
+			// The announced call can only be executed once the delay has passed, which must happen
+			// before the proxy it is executed through expires. Any proxy of `who` may be used.
+			let usable = Proxies::<T>::get(&real).0.into_iter().any(|def| {
+				def.delegate == who &&
+					ProxyExpiries::<T>::get(&real, &def)
+						.map_or(true, |(expiry, _)| now.saturating_add(def.delay) < expiry)
+			});
+			ensure!(usable, Error::<T>::Expired);
+
+			let announcement = Announcement { real: real.clone(), call_hash, height: now };
 
 			Announcements::<T>::try_mutate(&who, |(ref mut pending, ref mut deposit)| {
 				pending.try_push(announcement).map_err(|_| Error::<T>::TooMany)?;
@@ -544,6 +682,8 @@ pub mod pallet {
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get())
 				 // AccountData for inner call origin accountdata.
 				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // Spent allowance of the proxy, if any.
+				.saturating_add(T::DbWeight::get().writes(1))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -671,6 +811,195 @@ pub mod pallet {
 
 			Ok(if deposit_updated { Pays::No.into() } else { Pays::Yes.into() })
 		}
+
+		/// Register a proxy account for the sender that is able to make calls on its behalf until
+		/// the given `expiry`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that the `caller` would like to make a proxy.
+		/// - `proxy_type`: The permissions allowed for this proxy account.
+		/// - `delay`: The announcement period required of the initial proxy. Will generally be
+		/// zero.
+		/// - `expiry`: The block number, according to `BlockNumberProvider`, from which the proxy
+		/// can no longer be used. Once reached, anyone may remove the proxy with
+		/// `reap_expired_proxy`.
+		///
+		/// On top of the proxy deposit, a deposit of `ProxyDepositFactor` is held for the expiry.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::add_proxy_with_expiry(T::MaxProxies::get()))]
+		pub fn add_proxy_with_expiry(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			ensure!(expiry > T::BlockNumberProvider::current_block_number(), Error::<T>::Expired);
+			Self::add_proxy_delegate(&who, delegate.clone(), proxy_type.clone(), delay)?;
+
+			let def = ProxyDefinition { delegate: delegate.clone(), proxy_type, delay };
+			let deposit = Self::expiry_deposit();
+			T::Currency::reserve(&who, deposit)?;
+			ProxyExpiries::<T>::insert(&who, &def, (expiry, deposit));
+			Self::deposit_event(Event::ProxyExpirySet {
+				delegator: who,
+				delegatee: delegate,
+				proxy_type: def.proxy_type,
+				delay,
+				expiry,
+			});
+
+			Ok(())
+		}
+
+		/// Remove an expired proxy of `delegator`, returning the deposits held for it.
+		///
+		/// The dispatch origin for this call must be _Signed_. Any account may call it.
+		///
+		/// Parameters:
+		/// - `delegator`: The account which registered the proxy.
+		/// - `delegate`: The account which was registered as a proxy.
+		/// - `proxy_type`: The permissions of the expired proxy.
+		/// - `delay`: The announcement period of the expired proxy.
+		///
+		/// Fails with `NotExpired` if the proxy has no expiry or has not expired yet.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::reap_expired_proxy(T::MaxProxies::get()))]
+		pub fn reap_expired_proxy(
+			origin: OriginFor<T>,
+			delegator: AccountIdLookupOf<T>,
+			delegate: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let delegator = T::Lookup::lookup(delegator)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			let def = ProxyDefinition { delegate: delegate.clone(), proxy_type, delay };
+			let (expiry, _) =
+				ProxyExpiries::<T>::get(&delegator, &def).ok_or(Error::<T>::NotExpired)?;
+			ensure!(
+				T::BlockNumberProvider::current_block_number() >= expiry,
+				Error::<T>::NotExpired
+			);
+
+			Self::remove_proxy_delegate(&delegator, delegate, def.proxy_type, delay)
+		}
+
+		/// Limit the amounts a proxy of the sender may transfer per period.
+		///
+		/// Transfers are reported by `TransferMeter`. Once an allowance is set, the proxy may only
+		/// transfer the listed assets, up to their limit per `period`, and may not dispatch calls
+		/// the `TransferMeter` cannot measure, whatever its proxy type allows. The allowance is
+		/// consumed when a transfer passes the proxy's filter and is only given back if the
+		/// proxied call as a whole fails.
+		///
+		/// Replaces any existing allowance of the proxy and starts a new period. A deposit is
+		/// taken for each limited asset.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account registered as a proxy.
+		/// - `proxy_type`: The permissions of the proxy.
+		/// - `delay`: The announcement period of the proxy.
+		/// - `limits`: The maximum amount of each asset the proxy may transfer per period.
+		/// - `period`: The length of a period, in blocks of `BlockNumberProvider`.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_proxy_allowance(limits.len() as u32))]
+		pub fn set_proxy_allowance(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			limits: AllowanceLimitsOf<T>,
+			period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			ensure!(!period.is_zero(), Error::<T>::ZeroPeriod);
+
+			let def = ProxyDefinition { delegate: delegate.clone(), proxy_type, delay };
+			Proxies::<T>::get(&who)
+				.0
+				.binary_search(&def)
+				.map_err(|_| Error::<T>::NotFound)?;
+
+			let old_deposit =
+				ProxyAllowances::<T>::get(&who, &def).map_or(Zero::zero(), |a| a.deposit);
+			let new_deposit = Self::allowance_deposit(limits.len() as u32);
+			if new_deposit > old_deposit {
+				T::Currency::reserve(&who, new_deposit - old_deposit)?;
+			} else if new_deposit < old_deposit {
+				T::Currency::unreserve(&who, old_deposit - new_deposit);
+			}
+
+			let assets = limits
+				.iter()
+				.cloned()
+				.map(|(asset, limit)| AssetAllowance { asset, limit, spent: Zero::zero() })
+				.collect::<Vec<_>>()
+				.try_into()
+				.expect("same length as `limits`, which is bounded by `MaxAllowanceAssets`; qed");
+			ProxyAllowances::<T>::insert(
+				&who,
+				&def,
+				ProxyAllowance {
+					assets,
+					period,
+					period_start: T::BlockNumberProvider::current_block_number(),
+					deposit: new_deposit,
+				},
+			);
+			Self::deposit_event(Event::ProxyAllowanceSet {
+				delegator: who,
+				delegatee: delegate,
+				proxy_type: def.proxy_type,
+				delay,
+				limits,
+				period,
+			});
+
+			Ok(())
+		}
+
+		/// Remove the allowance of a proxy of the sender, lifting the limit on its transfers and
+		/// returning the deposit.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account registered as a proxy.
+		/// - `proxy_type`: The permissions of the proxy.
+		/// - `delay`: The announcement period of the proxy.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::remove_proxy_allowance())]
+		pub fn remove_proxy_allowance(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			let def = ProxyDefinition { delegate: delegate.clone(), proxy_type, delay };
+			let allowance = ProxyAllowances::<T>::take(&who, &def).ok_or(Error::<T>::NotFound)?;
+			T::Currency::unreserve(&who, allowance.deposit);
+			Self::deposit_event(Event::ProxyAllowanceRemoved {
+				delegator: who,
+				delegatee: delegate,
+				proxy_type: def.proxy_type,
+				delay,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -715,6 +1044,30 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			delay: BlockNumberFor<T>,
 		},
+		/// A proxy was given an expiry, after which it can no longer be used.
+		ProxyExpirySet {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			expiry: BlockNumberFor<T>,
+		},
+		/// The transfers of a proxy were limited to the given amounts per period.
+		ProxyAllowanceSet {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			limits: AllowanceLimitsOf<T>,
+			period: BlockNumberFor<T>,
+		},
+		/// The allowance of a proxy was removed.
+		ProxyAllowanceRemoved {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+		},
 		/// A deposit stored for proxies or announcements was poked / updated.
 		DepositPoked {
 			who: T::AccountId,
@@ -742,6 +1095,12 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The proxy has expired or would expire before the action could take effect.
+		Expired,
+		/// The proxy has no expiry or has not expired yet.
+		NotExpired,
+		/// The allowance period must not be zero.
+		ZeroPeriod,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -761,6 +1120,32 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The block number, according to `BlockNumberProvider`, from which a proxy of an account can
+	/// no longer be used, and the deposit held for it. Proxies without an entry never expire.
+	#[pallet::storage]
+	pub type ProxyExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		(BlockNumberFor<T>, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	/// The spending allowances of proxies of an account. Proxies without an entry are only
+	/// restricted by their proxy type.
+	#[pallet::storage]
+	pub type ProxyAllowances<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		ProxyAllowanceOf<T>,
+		OptionQuery,
+	>;
+
 	/// The announcements made by the proxy (key).
 	#[pallet::storage]
 	pub type Announcements<T: Config> = StorageMap<
@@ -788,6 +1173,44 @@ pub mod pallet {
 		pub fn is_superset(to_check: T::ProxyType, against: T::ProxyType) -> bool {
 			to_check.is_superset(&against)
 		}
+
+		/// The amount of each asset a proxy of `real` may still transfer in the current period,
+		/// or `None` if the proxy has no allowance.
+		pub fn remaining_allowance(
+			real: T::AccountId,
+			delegate: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+		) -> Option<Vec<(AllowanceAssetOf<T>, BalanceOf<T>)>> {
+			let def = ProxyDefinition { delegate, proxy_type, delay };
+			Self::current_allowance(&real, &def).map(|allowance| {
+				allowance
+					.assets
+					.into_iter()
+					.map(|a| (a.asset, a.limit.saturating_sub(a.spent)))
+					.collect()
+			})
+		}
+	}
+}
+
+impl<T: Config> Pallet<T>
+where
+	T::ProxyType: InstanceFilterMatrix,
+{
+	/// The calls out of `pallets` that a proxy of type `proxy_type` may dispatch.
+	///
+	/// Returns [`ProxyCapabilities::Custom`] if the filter of `proxy_type` has custom logic or is
+	/// missing from the filter matrix.
+	pub fn proxy_capabilities(
+		proxy_type: T::ProxyType,
+		pallets: &[PalletCalls],
+	) -> ProxyCapabilities {
+		T::ProxyType::filter_matrix()
+			.into_iter()
+			.find(|entry| entry.instance == proxy_type)
+			.and_then(|entry| entry.filter.call_indices(pallets))
+			.map_or(ProxyCapabilities::Custom, ProxyCapabilities::Calls)
 	}
 }
 
@@ -905,6 +1328,12 @@ impl<T: Config> Pallet<T> {
 			};
 			let i = proxies.binary_search(&proxy_def).ok().ok_or(Error::<T>::NotFound)?;
 			proxies.remove(i);
+			if let Some((_, deposit)) = ProxyExpiries::<T>::take(delegator, &proxy_def) {
+				T::Currency::unreserve(delegator, deposit);
+			}
+			if let Some(allowance) = ProxyAllowances::<T>::take(delegator, &proxy_def) {
+				T::Currency::unreserve(delegator, allowance.deposit);
+			}
 			let new_deposit = Self::deposit(proxies.len() as u32);
 			if new_deposit > old_deposit {
 				T::Currency::reserve(delegator, new_deposit - old_deposit)?;
@@ -924,6 +1353,72 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// The deposit held for the expiry of a proxy.
+	pub fn expiry_deposit() -> BalanceOf<T> {
+		T::ProxyDepositFactor::get()
+	}
+
+	/// The deposit held for an allowance limiting `num_assets` assets.
+	pub fn allowance_deposit(num_assets: u32) -> BalanceOf<T> {
+		T::ProxyDepositFactor::get() * num_assets.saturating_add(1).into()
+	}
+
+	/// The allowance of the proxy `def` of `real`, with the spent amounts reset if the period
+	/// they were spent in is over.
+	fn current_allowance(
+		real: &T::AccountId,
+		def: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+	) -> Option<ProxyAllowanceOf<T>> {
+		let mut allowance = ProxyAllowances::<T>::get(real, def)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		let elapsed = now.saturating_sub(allowance.period_start);
+		if elapsed >= allowance.period {
+			allowance.period_start = now.saturating_sub(elapsed % allowance.period);
+			allowance.assets.iter_mut().for_each(|a| a.spent = Zero::zero());
+		}
+		Some(allowance)
+	}
+
+	/// Consume the `allowance` for the transfer made by `call`, if any.
+	///
+	/// Returns `false` if the transfer exceeds the allowance or cannot be metered.
+	fn meter_allowance(
+		allowance: &mut ProxyAllowanceOf<T>,
+		call: &<T as Config>::RuntimeCall,
+	) -> bool {
+		let (asset, amount) = match T::TransferMeter::transferred(call) {
+			Transferred::Nothing => return true,
+			Transferred::Amount(asset, amount) => (asset, amount),
+			Transferred::Unknown => return false,
+		};
+		let Some(entry) = allowance.assets.iter_mut().find(|a| a.asset == asset) else {
+			return false
+		};
+		match entry.spent.checked_add(&amount) {
+			Some(spent) if spent <= entry.limit => entry.spent = spent,
+			_ => return false,
+		}
+		true
+	}
+
+	/// Remove all expiries of proxies of `delegator` and return their deposits.
+	///
+	/// There is at most one expiry per proxy, so this touches at most `MaxProxies` items.
+	fn remove_all_expiries(delegator: &T::AccountId) {
+		for (_, (_, deposit)) in ProxyExpiries::<T>::drain_prefix(delegator) {
+			T::Currency::unreserve(delegator, deposit);
+		}
+	}
+
+	/// Remove all allowances of proxies of `delegator` and return their deposits.
+	///
+	/// There is at most one allowance per proxy, so this touches at most `MaxProxies` items.
+	fn remove_all_allowances(delegator: &T::AccountId) {
+		for (_, allowance) in ProxyAllowances::<T>::drain_prefix(delegator) {
+			T::Currency::unreserve(delegator, allowance.deposit);
+		}
+	}
+
 	pub fn deposit(num_proxies: u32) -> BalanceOf<T> {
 		if num_proxies == 0 {
 			Zero::zero()
@@ -984,41 +1479,81 @@ impl<T: Config> Pallet<T> {
 		delegate: &T::AccountId,
 		force_proxy_type: Option<T::ProxyType>,
 	) -> Result<ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
 		let f = |x: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>| -> bool {
 			&x.delegate == delegate &&
-				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y)
+				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y) &&
+				!Self::is_expired(real, x, now)
 		};
 		Ok(Proxies::<T>::get(real).0.into_iter().find(f).ok_or(Error::<T>::NotProxy)?)
 	}
 
+	/// Whether the proxy `def` of `real` has an expiry which is not after `now`.
+	pub fn is_expired(
+		real: &T::AccountId,
+		def: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		now: BlockNumberFor<T>,
+	) -> bool {
+		ProxyExpiries::<T>::get(real, def).map_or(false, |(expiry, _)| now >= expiry)
+	}
+
 	fn do_proxy(
 		def: ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
 		real: T::AccountId,
 		call: <T as Config>::RuntimeCall,
 	) {
 		use frame::traits::{InstanceFilter as _, OriginTrait as _};
+		// The allowance is metered in memory for all calls dispatched by the proxied call, e.g. in
+		// a batch, and only written back once.
+		let allowance = Self::current_allowance(&real, &def).map(|a| Rc::new(RefCell::new(a)));
+		let has_allowance = allowance.is_some();
+		let metered_allowance = allowance.clone();
 		// This is a freshly authenticated new account, the origin restrictions doesn't apply.
-		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real).into();
+		let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real.clone()).into();
 		origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
 			let c = <T as Config>::RuntimeCall::from_ref(c);
 			// We make sure the proxy call does access this pallet to change modify proxies.
 			match c.is_sub_type() {
+				// Proxy call with an allowance cannot modify proxies or allowances, since it could
+				// otherwise escape its allowance.
+				Some(Call::add_proxy { .. }) |
+				Some(Call::add_proxy_with_expiry { .. }) |
+				Some(Call::remove_proxy { .. }) |
+				Some(Call::remove_proxies { .. }) |
+				Some(Call::create_pure { .. }) |
+				Some(Call::kill_pure { .. }) |
+				Some(Call::set_proxy_allowance { .. }) |
+				Some(Call::remove_proxy_allowance { .. })
+					if has_allowance =>
+					false,
 				// Proxy call cannot add or remove a proxy with more permissions than it already
 				// has.
 				Some(Call::add_proxy { ref proxy_type, .. }) |
+				Some(Call::add_proxy_with_expiry { ref proxy_type, .. }) |
 				Some(Call::remove_proxy { ref proxy_type, .. })
 					if !def.proxy_type.is_superset(proxy_type) =>
 					false,
 				// Proxy call cannot remove all proxies or kill pure proxies unless it has full
 				// permissions.
-				Some(Call::remove_proxies { .. }) | Some(Call::kill_pure { .. })
+				Some(Call::remove_proxies { .. }) |
+				Some(Call::kill_pure { .. }) |
+				Some(Call::set_proxy_allowance { .. }) |
+				Some(Call::remove_proxy_allowance { .. })
 					if def.proxy_type != T::ProxyType::default() =>
 					false,
-				_ => def.proxy_type.filter(c),
+				_ =>
+					def.proxy_type.filter(c) &&
+						metered_allowance.as_ref().map_or(true, |allowance| {
+							Self::meter_allowance(&mut allowance.borrow_mut(), c)
+						}),
 			}
 		});
-		let e = call.dispatch(origin);
-		Self::deposit_event(Event::ProxyExecuted { result: e.map(|_| ()).map_err(|e| e.error) });
+		let result = call.dispatch(origin).map(|_| ()).map_err(|e| e.error);
+		// The consumed allowance is given back if the call fails.
+		if let (Ok(()), Some(allowance)) = (&result, allowance) {
+			ProxyAllowances::<T>::insert(&real, &def, allowance.borrow().clone());
+		}
+		Self::deposit_event(Event::ProxyExecuted { result });
 	}
 
 	/// Removes all proxy delegates for a given delegator.
@@ -1027,6 +1562,8 @@ impl<T: Config> Pallet<T> {
 	/// - `delegator`: The delegator account.
 	pub fn remove_all_proxy_delegates(delegator: &T::AccountId) {
 		let (_, old_deposit) = Proxies::<T>::take(&delegator);
+		Self::remove_all_expiries(delegator);
+		Self::remove_all_allowances(delegator);
 		T::Currency::unreserve(&delegator, old_deposit);
 	}
 }
@@ -0,0 +1,40 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the proxy pallet.
+
+use crate::ProxyCapabilities;
+use codec::Codec;
+use frame::deps::sp_api;
+
+sp_api::decl_runtime_apis! {
+	/// API to query the calls that proxies may dispatch.
+	pub trait ProxyApi<ProxyType, Call>
+	where
+		ProxyType: Codec,
+		Call: Codec,
+	{
+		/// Whether a proxy of type `proxy_type` may dispatch `call`.
+		fn proxy_allows(proxy_type: ProxyType, call: Call) -> bool;
+
+		/// The calls that a proxy of type `proxy_type` may dispatch.
+		///
+		/// [`ProxyCapabilities::Custom`] if they cannot be determined statically, in which case
+		/// `proxy_allows` tells whether a specific call is allowed.
+		fn proxy_capabilities(proxy_type: ProxyType) -> ProxyCapabilities;
+	}
+}
@@ -108,6 +108,33 @@ impl Contains<RuntimeCall> for BaseFilter {
 	}
 }
 
+pub struct BalancesTransferMeter;
+impl TransferMeter<RuntimeCall, u64> for BalancesTransferMeter {
+	type Asset = u32;
+
+	fn transferred(call: &RuntimeCall) -> Transferred<u32, u64> {
+		match call {
+			RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+				value, ..
+			}) |
+			RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { value, .. }) =>
+				Transferred::Amount(0, *value),
+			RuntimeCall::Balances(pallet_balances::Call::transfer_all { .. }) =>
+				Transferred::Amount(0, u64::MAX),
+			RuntimeCall::System(SystemCall::remark { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::force_batch { .. }) => Transferred::Nothing,
+			_ => Transferred::Unknown,
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn benchmark_asset(i: u32) -> u32 {
+		i
+	}
+}
+
 parameter_types! {
 	pub static ProxyDepositBase: u64 = 1;
 	pub static ProxyDepositFactor: u64 = 1;
@@ -129,6 +156,8 @@ impl Config for Test {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type TransferMeter = BalancesTransferMeter;
+	type MaxAllowanceAssets = ConstU32<2>;
 }
 
 use super::{Call as ProxyCall, Event as ProxyEvent};
@@ -610,6 +639,333 @@ fn pure_works() {
 	});
 }
 
+#[test]
+fn expiring_proxy_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Proxy::add_proxy_with_expiry(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0, 1),
+			Error::<Test>::Expired
+		);
+		assert_ok!(Proxy::add_proxy_with_expiry(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0, 3));
+		System::assert_last_event(
+			ProxyEvent::ProxyExpirySet {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+				expiry: 3,
+			}
+			.into(),
+		);
+		// The proxy deposit and the expiry deposit.
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		let call = Box::new(call_transfer(6, 1));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call.clone()));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_noop!(
+			Proxy::reap_expired_proxy(RuntimeOrigin::signed(3), 1, 2, ProxyType::Any, 0),
+			Error::<Test>::NotExpired
+		);
+
+		System::set_block_number(3);
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, call),
+			Error::<Test>::NotProxy
+		);
+
+		// Anyone can reap the expired proxy, the deposit goes back to the delegator.
+		assert_ok!(Proxy::reap_expired_proxy(RuntimeOrigin::signed(3), 1, 2, ProxyType::Any, 0));
+		System::assert_last_event(
+			ProxyEvent::ProxyRemoved {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(Proxies::<Test>::get(1).0.is_empty());
+		assert_eq!(ProxyExpiries::<Test>::iter_prefix(1).count(), 0);
+
+		// Proxies without expiry cannot be reaped.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_noop!(
+			Proxy::reap_expired_proxy(RuntimeOrigin::signed(3), 1, 2, ProxyType::Any, 0),
+			Error::<Test>::NotExpired
+		);
+	});
+}
+
+#[test]
+fn removing_expiring_proxies_clears_expiry() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy_with_expiry(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0, 5));
+		assert_ok!(Proxy::add_proxy_with_expiry(
+			RuntimeOrigin::signed(1),
+			3,
+			ProxyType::JustTransfer,
+			0,
+			5
+		));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(ProxyExpiries::<Test>::iter_prefix(1).count(), 1);
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(1)));
+		assert_eq!(ProxyExpiries::<Test>::iter_prefix(1).count(), 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// Re-adding the proxy without expiry makes it permanent.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		System::set_block_number(5);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 1))));
+	});
+}
+
+#[test]
+fn announcements_respect_proxy_expiry() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy_with_expiry(RuntimeOrigin::signed(1), 3, ProxyType::Any, 2, 4));
+		let call = Box::new(call_transfer(6, 1));
+		let call_hash = BlakeTwo256::hash_of(&call);
+
+		// The call could be executed at block 3, before the expiry.
+		assert_ok!(Proxy::announce(RuntimeOrigin::signed(3), 1, call_hash));
+
+		// From block 2 on, the delay would end at or after the expiry.
+		System::set_block_number(2);
+		assert_noop!(
+			Proxy::announce(RuntimeOrigin::signed(3), 1, call_hash),
+			Error::<Test>::Expired
+		);
+
+		System::set_block_number(4);
+		assert_noop!(
+			Proxy::proxy_announced(RuntimeOrigin::signed(0), 3, 1, None, call),
+			Error::<Test>::NotProxy
+		);
+		assert_noop!(
+			Proxy::announce(RuntimeOrigin::signed(3), 1, call_hash),
+			Error::<Test>::NotProxy
+		);
+	});
+}
+
+#[test]
+fn announcements_may_use_any_unexpired_proxy() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy_with_expiry(RuntimeOrigin::signed(1), 3, ProxyType::Any, 2, 4));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::JustTransfer, 2));
+		let call = Box::new(call_transfer(6, 1));
+		let call_hash = BlakeTwo256::hash_of(&call);
+
+		// The expiring proxy would expire before the delay ends, the other one does not expire.
+		System::set_block_number(2);
+		assert_ok!(Proxy::announce(RuntimeOrigin::signed(3), 1, call_hash));
+
+		System::set_block_number(4);
+		assert_ok!(Proxy::proxy_announced(
+			RuntimeOrigin::signed(0),
+			3,
+			1,
+			Some(ProxyType::JustTransfer),
+			call
+		));
+	});
+}
+
+#[test]
+fn proxy_allowance_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::JustTransfer, 0));
+		assert_noop!(
+			Proxy::set_proxy_allowance(
+				RuntimeOrigin::signed(1),
+				2,
+				ProxyType::JustTransfer,
+				0,
+				vec![(0, 3)].try_into().unwrap(),
+				0
+			),
+			Error::<Test>::ZeroPeriod
+		);
+		assert_noop!(
+			Proxy::set_proxy_allowance(
+				RuntimeOrigin::signed(1),
+				2,
+				ProxyType::Any,
+				0,
+				vec![(0, 3)].try_into().unwrap(),
+				10
+			),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(Proxy::set_proxy_allowance(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::JustTransfer,
+			0,
+			vec![(0, 3)].try_into().unwrap(),
+			10
+		));
+		System::assert_last_event(
+			ProxyEvent::ProxyAllowanceSet {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::JustTransfer,
+				delay: 0,
+				limits: vec![(0, 3)].try_into().unwrap(),
+				period: 10,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 4);
+
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 2))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 2))));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 1))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 3);
+		assert_eq!(
+			Proxy::remaining_allowance(1, 2, ProxyType::JustTransfer, 0),
+			Some(vec![(0, 0)])
+		);
+
+		// The allowance is refreshed once the period is over.
+		System::set_block_number(14);
+		assert_eq!(
+			Proxy::remaining_allowance(1, 2, ProxyType::JustTransfer, 0),
+			Some(vec![(0, 3)])
+		);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 2))));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(
+			ProxyAllowances::<Test>::get(
+				1,
+				ProxyDefinition { delegate: 2, proxy_type: ProxyType::JustTransfer, delay: 0 }
+			)
+			.unwrap()
+			.period_start,
+			11
+		);
+
+		assert_ok!(Proxy::remove_proxy_allowance(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::JustTransfer,
+			0
+		));
+		System::assert_last_event(
+			ProxyEvent::ProxyAllowanceRemoved {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::JustTransfer,
+				delay: 0,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert_eq!(Proxy::remaining_allowance(1, 2, ProxyType::JustTransfer, 0), None);
+	});
+}
+
+#[test]
+fn proxy_allowance_is_given_back_on_failure() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_proxy_allowance(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			vec![(0, 100)].try_into().unwrap(),
+			10
+		));
+
+		// Not enough free balance, the call fails.
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 50))));
+		assert_eq!(Proxy::remaining_allowance(1, 2, ProxyType::Any, 0), Some(vec![(0, 100)]));
+
+		// Transfers in a batch are metered too.
+		let batch = Box::new(RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![call_transfer(6, 1), call_transfer(6, 2)],
+		}));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, batch));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Proxy::remaining_allowance(1, 2, ProxyType::Any, 0), Some(vec![(0, 97)]));
+	});
+}
+
+#[test]
+fn proxy_with_allowance_cannot_escape_it() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_proxy_allowance(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			vec![(1, 5)].try_into().unwrap(),
+			10
+		));
+
+		// Assets without allowance cannot be transferred.
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 1))));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+
+		// Calls the meter does not know cannot be made, even if the proxy type allows them.
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(2),
+			1,
+			None,
+			Box::new(RuntimeCall::Balances(BalancesCall::burn { value: 1, keep_alive: true }))
+		));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(2),
+			1,
+			None,
+			Box::new(RuntimeCall::System(SystemCall::remark { remark: vec![] }))
+		));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+
+		// Proxies and allowances cannot be changed.
+		for call in [
+			RuntimeCall::Proxy(ProxyCall::add_proxy {
+				delegate: 3,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+			}),
+			RuntimeCall::Proxy(ProxyCall::remove_proxy_allowance {
+				delegate: 2,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+			}),
+			RuntimeCall::Proxy(ProxyCall::remove_proxies {}),
+		] {
+			assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call)));
+			System::assert_last_event(
+				ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+			);
+		}
+
+		// Removing the proxy removes the allowance and returns all deposits.
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(ProxyAllowances::<Test>::iter_prefix(1).count(), 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
 #[test]
 fn poke_deposit_works_for_proxy_deposits() {
 	new_test_ext().execute_with(|| {
@@ -58,6 +58,7 @@ impl Contains<RuntimeCall> for BaseFilter {
 
 parameter_types! {
 	pub MaxWeight: Weight = Weight::from_parts(2_000_000_000_000, u64::MAX);
+	pub const FullShare: Perbill = Perbill::from_percent(100);
 }
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
@@ -71,6 +72,7 @@ impl pallet_preimage::Config for Test {
 	type Currency = Balances;
 	type ManagerOrigin = EnsureRoot<u64>;
 	type Consideration = ();
+	type UploadLifetime = ConstU64<10>;
 }
 impl pallet_scheduler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
@@ -81,6 +83,7 @@ impl pallet_scheduler::Config for Test {
 	type ScheduleOrigin = EnsureRoot<u64>;
 	type MaxScheduledPerBlock = ConstU32<100>;
 	type WeightInfo = ();
+	type ClassLimits = pallet_scheduler::WeightShares<FullShare, FullShare, ConstU64<{ u64::MAX }>>;
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
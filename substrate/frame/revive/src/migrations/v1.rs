@@ -24,8 +24,10 @@ extern crate alloc;
 use super::PALLET_MIGRATIONS_ID;
 use crate::{weights::WeightInfo, AccountInfo, AccountInfoOf, Config, H160};
 use frame_support::{
-	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
-	pallet_prelude::PhantomData,
+	migrations::{
+		count_metered, steps_for_weight, MigrationId, SteppedMigration, SteppedMigrationError,
+	},
+	pallet_prelude::{Get, PhantomData},
 	weights::WeightMeter,
 };
 
@@ -57,6 +59,19 @@ impl<T: Config> SteppedMigration for Migration<T> {
 		MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
 	}
 
+	/// Estimated from the contracts left to migrate and the accounts migrated already, assuming
+	/// that every step gets the maximal block weight.
+	fn estimated_steps(meter: &mut WeightMeter) -> Option<u32> {
+		let read = T::DbWeight::get().reads(1);
+		let left = count_metered(old::ContractInfoOf::<T>::iter_keys(), read, meter)?;
+		let migrated = count_metered(AccountInfoOf::<T>::iter_keys(), read, meter)?;
+		steps_for_weight(
+			<T as Config>::WeightInfo::v1_migration_step()
+				.saturating_mul(left.saturating_add(migrated).into()),
+			T::BlockWeights::get().max_block,
+		)
+	}
+
 	fn step(
 		mut cursor: Option<Self::Cursor>,
 		meter: &mut WeightMeter,
@@ -144,6 +159,9 @@ fn migrate_to_v1() {
 			);
 		}
 
+		// Ten contracts fit into a single block.
+		assert_eq!(Migration::<Test>::estimated_steps(&mut WeightMeter::new()), Some(1));
+
 		let mut cursor = None;
 		let mut weight_meter = WeightMeter::new();
 		while let Some(new_cursor) = Migration::<Test>::step(cursor, &mut weight_meter).unwrap() {
@@ -86,6 +86,12 @@ fn make_task<T: Config>(
 	Scheduled { maybe_id, priority, call, maybe_periodic, origin, _phantom: PhantomData }
 }
 
+/// Make the named task `id` recur.
+fn add_recurrence<T: Config>(id: TaskName) {
+	let recurrence = RecurrenceConfig { period: One::one(), remaining: None, end: None };
+	Recurrences::<T>::insert(id, recurrence);
+}
+
 fn bounded<T: Config>(len: u32) -> Option<BoundedCallOf<T>> {
 	let call =
 		<<T as Config>::RuntimeCall>::from(SystemCall::remark { remark: vec![0; len as usize] });
@@ -213,6 +219,8 @@ mod benchmarks {
 	fn service_task_named() {
 		let now = BLOCK_NUMBER.into();
 		let task = make_task::<T>(false, true, false, None, 0);
+		// The recurrence of a named task is read and, since it is not dispatched, removed.
+		add_recurrence::<T>(u32_to_name(0));
 		// prevent any tasks from actually being executed as we only want the surrounding weight.
 		let mut counter = WeightMeter::with_limit(Weight::zero());
 		let _result;
@@ -222,7 +230,7 @@ mod benchmarks {
 			_result = Pallet::<T>::service_task(&mut counter, now, now, 0, true, task);
 		}
 
-		// assert!(result.is_ok());
+		assert!(!Recurrences::<T>::contains_key(u32_to_name(0)));
 	}
 
 	// `service_task` when the task is a periodic, non-named, non-fetched call which is not
@@ -304,6 +312,7 @@ mod benchmarks {
 		let when = BLOCK_NUMBER.into();
 
 		fill_schedule::<T>(when, s)?;
+		add_recurrence::<T>(u32_to_name(0));
 		assert_eq!(Agenda::<T>::get(when).len(), s as usize);
 		let schedule_origin =
 			T::ScheduleOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
@@ -311,6 +320,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		_(schedule_origin as SystemOrigin<T>, when, 0);
 
+		ensure!(!Recurrences::<T>::contains_key(u32_to_name(0)), "didn't remove the recurrence");
 		ensure!(
 			s == 1 || Lookup::<T>::get(u32_to_name(0)).is_none(),
 			"didn't remove from lookup if more than 1 task scheduled for `when`"
@@ -356,10 +366,12 @@ mod benchmarks {
 		let when = BLOCK_NUMBER.into();
 
 		fill_schedule::<T>(when, s)?;
+		add_recurrence::<T>(u32_to_name(0));
 
 		#[extrinsic_call]
 		_(RawOrigin::Root, u32_to_name(0));
 
+		ensure!(!Recurrences::<T>::contains_key(u32_to_name(0)), "didn't remove the recurrence");
 		ensure!(
 			s == 1 || Lookup::<T>::get(u32_to_name(0)).is_none(),
 			"didn't remove from lookup if more than 1 task scheduled for `when`"
@@ -506,6 +518,118 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn schedule_named_recurring(
+		s: Linear<0, { T::MaxScheduledPerBlock::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let id = u32_to_name(s);
+		let when = BLOCK_NUMBER.into();
+		let period = BlockNumberFor::<T>::one();
+		let priority = 0;
+		// Essentially a no-op call.
+		let call = Box::new(SystemCall::set_storage { items: vec![] }.into());
+
+		fill_schedule::<T>(when, s)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, id, when, period, Some(100), Some(1_000u32.into()), priority, call);
+
+		ensure!(Agenda::<T>::get(when).len() == s as usize + 1, "didn't add to schedule");
+		ensure!(Recurrences::<T>::contains_key(id), "didn't set recurrence");
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_recurrence_named() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s - 1)?;
+		let name = u32_to_name(s - 1);
+		let origin: <T as Config>::PalletsOrigin = frame_system::RawOrigin::Root.into();
+		let address = Pallet::<T>::do_schedule_named(
+			name,
+			DispatchTime::At(when),
+			None,
+			0,
+			origin,
+			make_call::<T>(None),
+		)?;
+		let period = BlockNumberFor::<T>::one();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, name, period, Some(10), None);
+
+		assert_eq!(
+			Recurrences::<T>::get(name),
+			Some(RecurrenceConfig { period, remaining: Some(9), end: None })
+		);
+		assert_last_event::<T>(
+			Event::RecurrenceSet { task: address, id: name, period, remaining: Some(9), end: None }
+				.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_recurrence_named() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s - 1)?;
+		let name = u32_to_name(s - 1);
+		let origin: <T as Config>::PalletsOrigin = frame_system::RawOrigin::Root.into();
+		let address = Pallet::<T>::do_schedule_named(
+			name,
+			DispatchTime::At(when),
+			None,
+			0,
+			origin,
+			make_call::<T>(None),
+		)?;
+		let period = BlockNumberFor::<T>::one();
+		assert!(Pallet::<T>::set_recurrence_named(
+			RawOrigin::Root.into(),
+			name,
+			period,
+			None,
+			None
+		)
+		.is_ok());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, name);
+
+		assert!(!Recurrences::<T>::contains_key(name));
+		assert_last_event::<T>(Event::RecurrenceCancelled { task: address, id: name }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reschedule_named_call() -> Result<(), BenchmarkError> {
+		let s = T::MaxScheduledPerBlock::get();
+		let when = BLOCK_NUMBER.into();
+
+		fill_schedule::<T>(when, s)?;
+		let name = u32_to_name(s - 1);
+		let address = Lookup::<T>::get(name).unwrap();
+		let call: <T as Config>::RuntimeCall = SystemCall::set_storage { items: vec![] }.into();
+		let new_call_hash = T::Preimages::bound(call.clone())?.hash();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, name, Box::new(call));
+
+		assert_eq!(Lookup::<T>::get(name), Some(address));
+		let (when, index) = address;
+		let task = Agenda::<T>::get(when)[index as usize].clone().unwrap();
+		assert_eq!(task.call.hash(), new_call_hash);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::new_test_ext(),
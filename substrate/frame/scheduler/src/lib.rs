@@ -41,6 +41,19 @@
 //! If a call is scheduled using proxy or whatever mechanism which adds filter, then those filter
 //! will not be used when dispatching the schedule runtime call.
 //!
+//! Named tasks can be made recurring with [`Pallet::schedule_named_recurring`] or
+//! [`Pallet::set_recurrence_named`]. Unlike periodic tasks, which are placed `period` blocks after
+//! the block they were executed in, a recurring task is placed at the next multiple of its period
+//! after the block it was scheduled for. This keeps it aligned to its schedule even if it runs late
+//! or the [`Config::BlockNumberProvider`] skips blocks, as a relay chain provider on a parachain
+//! does. Executions which were missed entirely are skipped.
+//!
+//! Tasks belong to a [`PriorityClass`] depending on their priority. Tasks of the critical class may
+//! use all of [`Config::MaximumWeight`], while [`Config::ClassLimits`] can restrict the share of it
+//! which the other classes may use, reserving the rest for the classes above them. It can also
+//! limit for how long the tasks of the other classes may be postponed before they are dropped.
+//! Postponing a critical task is reported with [`Event::CriticalTaskDelayed`].
+//!
 //! ### Examples
 //!
 //! 1. Scheduling a runtime call at a specific block.
@@ -104,8 +117,10 @@ use frame_system::{self as system};
 use scale_info::TypeInfo;
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{BadOrigin, BlockNumberProvider, Dispatchable, One, Saturating, Zero},
-	BoundedVec, DispatchError, RuntimeDebug,
+	traits::{
+		AtLeast32BitUnsigned, BadOrigin, BlockNumberProvider, Dispatchable, One, Saturating, Zero,
+	},
+	BoundedVec, DispatchError, Perbill, RuntimeDebug,
 };
 
 pub use pallet::*;
@@ -147,6 +162,139 @@ pub struct RetryConfig<Period> {
 	pub period: Period,
 }
 
+/// Tasks with a priority of this value or higher belong to [`PriorityClass::Bulk`].
+pub const BULK_PRIORITY: schedule::Priority = 192;
+
+/// The class of a scheduled task, derived from its priority.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub enum PriorityClass {
+	/// Tasks with a priority up to [`schedule::HARD_DEADLINE`], e.g. governance enactments.
+	Critical,
+	/// Tasks with a priority between the critical and the bulk ones.
+	Normal,
+	/// Tasks with a priority of [`BULK_PRIORITY`] or higher.
+	Bulk,
+}
+
+impl PriorityClass {
+	/// The class of a task with the given `priority`.
+	pub fn of(priority: schedule::Priority) -> Self {
+		if priority <= schedule::HARD_DEADLINE {
+			Self::Critical
+		} else if priority < BULK_PRIORITY {
+			Self::Normal
+		} else {
+			Self::Bulk
+		}
+	}
+}
+
+/// How the tasks of a [`PriorityClass`] are limited.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+pub struct ClassLimit<BlockNumber> {
+	/// The share of [`Config::MaximumWeight`] which may be used while servicing tasks of the
+	/// class. The rest is reserved for the classes above it.
+	///
+	/// Tasks which do not fit into this share on their own are permanently overweight.
+	pub weight_share: Perbill,
+	/// For how many blocks the tasks of the class may be postponed before they are dropped.
+	pub max_postponement: BlockNumber,
+}
+
+/// Provides the limits of the [`PriorityClass`]es.
+pub trait ClassLimits<BlockNumber> {
+	/// The limit of the given `class`.
+	///
+	/// Never consulted for [`PriorityClass::Critical`], whose tasks are not limited.
+	fn limit(class: PriorityClass) -> ClassLimit<BlockNumber>;
+}
+
+/// Limits the normal and the bulk class to `NormalShare` and `BulkShare` of
+/// [`Config::MaximumWeight`], and postpones their tasks for at most `MaxPostponement` blocks.
+pub struct WeightShares<NormalShare, BulkShare, MaxPostponement>(
+	PhantomData<(NormalShare, BulkShare, MaxPostponement)>,
+);
+
+impl<BlockNumber, NormalShare, BulkShare, MaxPostponement> ClassLimits<BlockNumber>
+	for WeightShares<NormalShare, BulkShare, MaxPostponement>
+where
+	NormalShare: Get<Perbill>,
+	BulkShare: Get<Perbill>,
+	MaxPostponement: Get<BlockNumber>,
+{
+	fn limit(class: PriorityClass) -> ClassLimit<BlockNumber> {
+		let weight_share = match class {
+			PriorityClass::Critical => Perbill::one(),
+			PriorityClass::Normal => NormalShare::get(),
+			PriorityClass::Bulk => BulkShare::get(),
+		};
+		ClassLimit { weight_share, max_postponement: MaxPostponement::get() }
+	}
+}
+
+/// The recurrence of a named task.
+#[derive(
+	Clone,
+	Copy,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+pub struct RecurrenceConfig<BlockNumber> {
+	/// Period of time between two executions.
+	pub period: BlockNumber,
+	/// Amount of executions left after the next one, if limited.
+	pub remaining: Option<u32>,
+	/// Last block at which the task may be executed, if any.
+	pub end: Option<BlockNumber>,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> RecurrenceConfig<BlockNumber> {
+	/// Create a recurrence for a task next executed at `when`, with `maybe_count` executions
+	/// in total from then on.
+	///
+	/// Returns `None` if it would never recur, because the period is zero, the count below two or
+	/// the end before `when`.
+	pub fn new(
+		when: BlockNumber,
+		period: BlockNumber,
+		maybe_count: Option<u32>,
+		end: Option<BlockNumber>,
+	) -> Option<Self> {
+		if period.is_zero() || end.map_or(false, |end| end < when) {
+			return None
+		}
+		let remaining = match maybe_count {
+			Some(count) if count < 2 => return None,
+			maybe_count => maybe_count.map(|count| count - 1),
+		};
+		Some(Self { period, remaining, end })
+	}
+
+	/// The block of the next execution after the one scheduled for `when` was serviced at `now`,
+	/// together with the recurrence from then on.
+	///
+	/// The next execution is the first one after `now` in multiples of the period from `when`.
+	/// Returns `None` if the task does not recur anymore.
+	pub fn next(self, when: BlockNumber, now: BlockNumber) -> Option<(BlockNumber, Self)> {
+		let remaining = match self.remaining {
+			Some(0) => return None,
+			remaining => remaining.map(|r| r - 1),
+		};
+		let periods = now.saturating_sub(when) / self.period + One::one();
+		let next = when.saturating_add(self.period.saturating_mul(periods));
+		if next <= now || self.end.map_or(false, |end| next > end) {
+			return None
+		}
+		Some((next, Self { remaining, ..self }))
+	}
+}
+
 #[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
 #[derive(Clone, RuntimeDebug, Encode, Decode)]
 struct ScheduledV1<Call, BlockNumber> {
@@ -312,6 +460,11 @@ pub mod pallet {
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
+		/// The limits of the non-critical [`PriorityClass`]es.
+		///
+		/// [`WeightShares`] can be used to configure them with constants.
+		type ClassLimits: ClassLimits<BlockNumberFor<Self>>;
+
 		/// The preimage provider with which we look up call hashes to get the call.
 		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
 
@@ -369,6 +522,11 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Recurrence configurations for named tasks, indexed by task name.
+	#[pallet::storage]
+	pub type Recurrences<T: Config> =
+		StorageMap<_, Blake2_128Concat, TaskName, RecurrenceConfig<BlockNumberFor<T>>, OptionQuery>;
+
 	/// Lookup from a name to the block number and index of the task.
 	///
 	/// For v3 -> v4 the previously unbounded identities are Blake2-256 hashed to form the v4
@@ -400,6 +558,23 @@ pub mod pallet {
 		},
 		/// Cancel a retry configuration for some task.
 		RetryCancelled { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// Set a recurrence configuration for some named task.
+		RecurrenceSet {
+			task: TaskAddress<BlockNumberFor<T>>,
+			id: TaskName,
+			period: BlockNumberFor<T>,
+			remaining: Option<u32>,
+			end: Option<BlockNumberFor<T>>,
+		},
+		/// Cancel the recurrence configuration of some named task.
+		RecurrenceCancelled { task: TaskAddress<BlockNumberFor<T>>, id: TaskName },
+		/// Replaced the call of some named task.
+		CallUpdated {
+			task: TaskAddress<BlockNumberFor<T>>,
+			id: TaskName,
+			old_call_hash: T::Hash,
+			new_call_hash: T::Hash,
+		},
 		/// The call for the provided hash was not found so the task has been aborted.
 		CallUnavailable { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// The given task was unable to be renewed since the agenda is full at that block.
@@ -409,6 +584,10 @@ pub mod pallet {
 		RetryFailed { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// The given task can never be executed since it is overweight.
 		PermanentlyOverweight { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// The given critical task was postponed due to the lack of weight.
+		CriticalTaskDelayed { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// The given task was dropped since it was postponed longer than its class allows.
+		PostponementExpired { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// Agenda is incomplete from `when`.
 		AgendaIncomplete { when: BlockNumberFor<T> },
 	}
@@ -425,6 +604,10 @@ pub mod pallet {
 		RescheduleNoChange,
 		/// Attempt to use a non-named function on a named task.
 		Named,
+		/// The recurrence would never repeat the task.
+		InvalidRecurrence,
+		/// Attempt to make a periodic task recurring.
+		Periodic,
 	}
 
 	#[pallet::hooks]
@@ -454,6 +637,13 @@ pub mod pallet {
 			let medium_lookup = lookup_weight::<T>(1024);
 			assert!(medium_lookup.all_lte(limit), "Must be possible to submit a medium lookup");
 
+			let normal = T::ClassLimits::limit(PriorityClass::Normal).weight_share;
+			let bulk = T::ClassLimits::limit(PriorityClass::Bulk).weight_share;
+			assert!(
+				bulk <= normal,
+				"The bulk class must not get a larger share than the normal one"
+			);
+
 			let large_lookup = lookup_weight::<T>(1024 * 1024);
 			assert!(large_lookup.all_lte(limit), "Must be possible to submit a large lookup");
 		}
@@ -677,6 +867,127 @@ pub mod pallet {
 			Self::deposit_event(Event::RetryCancelled { task, id: Some(id) });
 			Ok(())
 		}
+
+		/// Schedule a named task recurring every `period` blocks, first executed at `when`.
+		///
+		/// - `maybe_count`: The total number of executions, if limited.
+		/// - `maybe_end`: The last block at which the task may be executed, if any.
+		///
+		/// Each execution is placed at the next multiple of `period` after `when` which is still
+		/// in the future, so executions which were missed entirely are skipped.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_named_recurring(T::MaxScheduledPerBlock::get()))]
+		pub fn schedule_named_recurring(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: BlockNumberFor<T>,
+			period: BlockNumberFor<T>,
+			maybe_count: Option<u32>,
+			maybe_end: Option<BlockNumberFor<T>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let recurrence = RecurrenceConfig::new(when, period, maybe_count, maybe_end)
+				.ok_or(Error::<T>::InvalidRecurrence)?;
+			let task = Self::do_schedule_named(
+				id,
+				DispatchTime::At(when),
+				None,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Recurrences::<T>::insert(id, recurrence);
+			Self::deposit_event(Event::RecurrenceSet {
+				task,
+				id,
+				period,
+				remaining: recurrence.remaining,
+				end: recurrence.end,
+			});
+			Ok(())
+		}
+
+		/// Set or replace the recurrence configuration of a named task which is not periodic.
+		///
+		/// - `maybe_count`: The total number of executions from the next one on, if limited.
+		/// - `maybe_end`: The last block at which the task may be executed, if any.
+		///
+		/// Subsequent executions are aligned to the block the task is currently scheduled for.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_recurrence_named())]
+		pub fn set_recurrence_named(
+			origin: OriginFor<T>,
+			id: TaskName,
+			period: BlockNumberFor<T>,
+			maybe_count: Option<u32>,
+			maybe_end: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let (when, agenda_index) = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			let agenda = Agenda::<T>::get(when);
+			let scheduled = agenda
+				.get(agenda_index as usize)
+				.and_then(Option::as_ref)
+				.ok_or(Error::<T>::NotFound)?;
+			Self::ensure_privilege(origin.caller(), &scheduled.origin)?;
+			ensure!(scheduled.maybe_periodic.is_none(), Error::<T>::Periodic);
+			let recurrence = RecurrenceConfig::new(when, period, maybe_count, maybe_end)
+				.ok_or(Error::<T>::InvalidRecurrence)?;
+			Recurrences::<T>::insert(id, recurrence);
+			Self::deposit_event(Event::RecurrenceSet {
+				task: (when, agenda_index),
+				id,
+				period,
+				remaining: recurrence.remaining,
+				end: recurrence.end,
+			});
+			Ok(())
+		}
+
+		/// Cancel the recurrence configuration of a named task.
+		///
+		/// The task is still executed at the block it is currently scheduled for, but not again.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_recurrence_named())]
+		pub fn cancel_recurrence_named(origin: OriginFor<T>, id: TaskName) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			let (when, agenda_index) = Lookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+			let agenda = Agenda::<T>::get(when);
+			let scheduled = agenda
+				.get(agenda_index as usize)
+				.and_then(Option::as_ref)
+				.ok_or(Error::<T>::NotFound)?;
+			Self::ensure_privilege(origin.caller(), &scheduled.origin)?;
+			Recurrences::<T>::take(id).ok_or(Error::<T>::NotFound)?;
+			Self::deposit_event(Event::RecurrenceCancelled { task: (when, agenda_index), id });
+			Ok(())
+		}
+
+		/// Replace the call of a named task.
+		///
+		/// The task keeps its place in the agenda, its priority, origin, periodicity, retry and
+		/// recurrence configuration. The origin must have at least the privilege of the task's
+		/// origin.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::reschedule_named_call())]
+		pub fn reschedule_named_call(
+			origin: OriginFor<T>,
+			id: TaskName,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_reschedule_named_call(
+				Some(origin.caller().clone()),
+				id,
+				T::Preimages::bound(*call)?,
+			)
+		}
 	}
 }
 
@@ -1084,6 +1395,7 @@ impl<T: Config> Pallet<T> {
 			T::Preimages::drop(&s.call);
 			if let Some(id) = s.maybe_id {
 				Lookup::<T>::remove(id);
+				Recurrences::<T>::remove(id);
 			}
 			Retries::<T>::remove((when, index));
 			Self::cleanup_agenda(when);
@@ -1171,6 +1483,7 @@ impl<T: Config> Pallet<T> {
 					}
 					Ok(())
 				})?;
+				Recurrences::<T>::remove(id);
 				Self::cleanup_agenda(when);
 				Self::deposit_event(Event::Canceled { when, index });
 				Ok(())
@@ -1202,6 +1515,38 @@ impl<T: Config> Pallet<T> {
 		Self::place_task(new_time, task).map_err(|x| x.0)
 	}
 
+	fn do_reschedule_named_call(
+		origin: Option<T::PalletsOrigin>,
+		id: TaskName,
+		call: BoundedCallOf<T>,
+	) -> DispatchResult {
+		let (when, index) = Lookup::<T>::get(id).ok_or(Error::<T>::NotFound)?;
+		let new_call_hash = call.hash();
+		let maybe_lookup_hash = call.lookup_hash();
+		let old_call = Agenda::<T>::try_mutate(when, |agenda| {
+			let task = agenda
+				.get_mut(index as usize)
+				.and_then(Option::as_mut)
+				.ok_or(Error::<T>::NotFound)?;
+			if let Some(ref o) = origin {
+				Self::ensure_privilege(o, &task.origin)?;
+			}
+			Ok::<_, DispatchError>(core::mem::replace(&mut task.call, call))
+		})?;
+		if let Some(hash) = maybe_lookup_hash {
+			// Request the call to be made available.
+			T::Preimages::request(&hash);
+		}
+		T::Preimages::drop(&old_call);
+		Self::deposit_event(Event::CallUpdated {
+			task: (when, index),
+			id,
+			old_call_hash: old_call.hash(),
+			new_call_hash,
+		});
+		Ok(())
+	}
+
 	fn do_cancel_retry(
 		origin: &T::PalletsOrigin,
 		(when, index): TaskAddress<BlockNumberFor<T>>,
@@ -1288,34 +1633,56 @@ impl<T: Config> Pallet<T> {
 		// Items which we don't know can ever be executed.
 		let mut dropped = 0;
 
-		for (agenda_index, _) in ordered.into_iter().take(max as usize) {
+		let mut ordered = ordered.into_iter().take(max as usize);
+		for (agenda_index, priority) in ordered.by_ref() {
 			let Some(task) = agenda[agenda_index as usize].take() else { continue };
 			let base_weight = T::WeightInfo::service_task(
 				task.call.lookup_len().map(|x| x as usize),
 				task.maybe_id.is_some(),
 				task.maybe_periodic.is_some(),
 			);
-			if !weight.can_consume(base_weight) {
-				postponed += 1;
-				agenda[agenda_index as usize] = Some(task);
+			// Tasks only use the share of the weight which their class is allowed to.
+			let mut class_weight = match PriorityClass::of(priority) {
+				PriorityClass::Critical => WeightMeter::with_limit(weight.limit()),
+				class => WeightMeter::with_limit(
+					T::ClassLimits::limit(class).weight_share * weight.limit(),
+				),
+			};
+			if !class_weight.can_consume(weight.consumed().saturating_add(base_weight)) {
+				agenda[agenda_index as usize] =
+					Self::postpone_task(weight, now, when, agenda_index, task)
+						.inspect(|_| postponed += 1);
 				break
 			}
-			let result = Self::service_task(weight, now, when, agenda_index, is_first, task);
+			class_weight.consume(weight.consumed());
+			// The first task is permanently overweight if it does not fit into the share of its
+			// class, as it would never be executed otherwise.
+			let result =
+				Self::service_task(&mut class_weight, now, when, agenda_index, is_first, task);
+			weight.consume(class_weight.consumed().saturating_sub(weight.consumed()));
 			agenda[agenda_index as usize] = match result {
 				Err((Unavailable, slot)) => {
 					dropped += 1;
 					slot
 				},
-				Err((Overweight, slot)) => {
-					postponed += 1;
-					slot
-				},
+				Err((Overweight, slot)) => slot.and_then(|task| {
+					Self::postpone_task(weight, now, when, agenda_index, task)
+						.inspect(|_| postponed += 1)
+				}),
 				Ok(()) => {
 					is_first = false;
 					None
 				},
 			};
 		}
+		// The tasks which were not reached are postponed as well.
+		for (agenda_index, _) in ordered {
+			if let Some(task) = agenda[agenda_index as usize].take() {
+				agenda[agenda_index as usize] =
+					Self::postpone_task(weight, now, when, agenda_index, task)
+						.inspect(|_| postponed += 1);
+			}
+		}
 		if postponed > 0 || dropped > 0 {
 			Agenda::<T>::insert(when, agenda);
 		} else {
@@ -1325,6 +1692,53 @@ impl<T: Config> Pallet<T> {
 		postponed == 0
 	}
 
+	/// Handle a `task` of the agenda at `when` which could not be serviced at `now` due to the
+	/// lack of weight.
+	///
+	/// Returns the task if it is postponed, or `None` if it was dropped because it was postponed
+	/// longer than its class allows. Neither the event nor the removal happen if `weight` cannot
+	/// afford them, in which case the task is postponed and revisited in a later block.
+	fn postpone_task(
+		weight: &mut WeightMeter,
+		now: BlockNumberFor<T>,
+		when: BlockNumberFor<T>,
+		agenda_index: u32,
+		task: ScheduledOf<T>,
+	) -> Option<ScheduledOf<T>> {
+		let address = (when, agenda_index);
+		let max_postponement = match PriorityClass::of(task.priority) {
+			PriorityClass::Critical => {
+				if weight.try_consume(T::DbWeight::get().writes(1)).is_ok() {
+					Self::deposit_event(Event::CriticalTaskDelayed {
+						task: address,
+						id: task.maybe_id,
+					});
+				}
+				return Some(task)
+			},
+			class => T::ClassLimits::limit(class).max_postponement,
+		};
+		if now.saturating_sub(when) < max_postponement {
+			return Some(task)
+		}
+		// Dropping the task does the same as cancelling it.
+		if weight
+			.try_consume(T::WeightInfo::cancel_named(T::MaxScheduledPerBlock::get()))
+			.is_err()
+		{
+			return Some(task)
+		}
+
+		T::Preimages::drop(&task.call);
+		Retries::<T>::remove(address);
+		if let Some(id) = task.maybe_id {
+			Lookup::<T>::remove(id);
+			Recurrences::<T>::remove(id);
+		}
+		Self::deposit_event(Event::PostponementExpired { task: address, id: task.maybe_id });
+		None
+	}
+
 	/// Service (i.e. execute) the given task, being careful not to overflow the `weight` counter.
 	///
 	/// This involves:
@@ -1354,6 +1768,9 @@ impl<T: Config> Pallet<T> {
 				// It was not available when we needed it, so we don't need to have requested it
 				// anymore.
 				T::Preimages::drop(&task.call);
+				if let Some(id) = task.maybe_id {
+					Recurrences::<T>::remove(id);
+				}
 
 				// We don't know why `peek` failed, thus we most account here for the "full weight".
 				let _ = weight.try_consume(T::WeightInfo::service_task(
@@ -1366,15 +1783,19 @@ impl<T: Config> Pallet<T> {
 			},
 		};
 
+		let maybe_recurrence = task.maybe_id.and_then(Recurrences::<T>::get);
 		let _ = weight.try_consume(T::WeightInfo::service_task(
 			lookup_len.map(|x| x as usize),
 			task.maybe_id.is_some(),
-			task.maybe_periodic.is_some(),
+			task.maybe_periodic.is_some() || maybe_recurrence.is_some(),
 		));
 
 		match Self::execute_dispatch(weight, task.origin.clone(), call) {
 			Err(()) if is_first => {
 				T::Preimages::drop(&task.call);
+				if let Some(id) = task.maybe_id {
+					Recurrences::<T>::remove(id);
+				}
 				Self::deposit_event(Event::PermanentlyOverweight {
 					task: (when, agenda_index),
 					id: task.maybe_id,
@@ -1398,7 +1819,16 @@ impl<T: Config> Pallet<T> {
 					_ => {},
 				}
 
-				if let &Some((period, count)) = &task.maybe_periodic {
+				if let Some(recurrence) = maybe_recurrence {
+					Self::schedule_recurrence(
+						now,
+						when,
+						agenda_index,
+						task,
+						recurrence,
+						maybe_retry_config,
+					);
+				} else if let &Some((period, count)) = &task.maybe_periodic {
 					if count > 1 {
 						task.maybe_periodic = Some((period, count - 1));
 					} else {
@@ -1515,6 +1945,39 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Place the next execution of a recurring task, or clean it up if it does not recur anymore.
+	fn schedule_recurrence(
+		now: BlockNumberFor<T>,
+		when: BlockNumberFor<T>,
+		agenda_index: u32,
+		task: ScheduledOf<T>,
+		recurrence: RecurrenceConfig<BlockNumberFor<T>>,
+		maybe_retry_config: Option<RetryConfig<BlockNumberFor<T>>>,
+	) {
+		let Some(id) = task.maybe_id else { return };
+		let Some((wake, recurrence)) = recurrence.next(when, now) else {
+			Recurrences::<T>::remove(id);
+			T::Preimages::drop(&task.call);
+			return
+		};
+		match Self::place_task(wake, task) {
+			Ok(new_address) => {
+				Recurrences::<T>::insert(id, recurrence);
+				if let Some(retry_config) = maybe_retry_config {
+					Retries::<T>::insert(new_address, retry_config);
+				}
+			},
+			Err((_, task)) => {
+				Recurrences::<T>::remove(id);
+				T::Preimages::drop(&task.call);
+				Self::deposit_event(Event::PeriodicFailed {
+					task: (when, agenda_index),
+					id: task.maybe_id,
+				});
+			},
+		}
+	}
+
 	/// Ensure that `left` has at least the same level of privilege or higher than `right`.
 	///
 	/// Returns an error if `left` has a lower level of privilege or the two cannot be compared.
@@ -22,7 +22,7 @@ use super::*;
 use crate as scheduler;
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU32, Contains, EitherOfDiverse, EqualPrivilegeOnly},
+	traits::{ConstU32, ConstU64, Contains, EitherOfDiverse, EqualPrivilegeOnly},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_runtime::{BuildStorage, Perbill};
@@ -156,6 +156,7 @@ impl pallet_preimage::Config for Test {
 	type Currency = ();
 	type ManagerOrigin = EnsureRoot<u64>;
 	type Consideration = ();
+	type UploadLifetime = ConstU64<10>;
 }
 
 pub struct TestWeightInfo;
@@ -211,12 +212,43 @@ impl WeightInfo for TestWeightInfo {
 	fn cancel_retry_named() -> Weight {
 		Weight::from_parts(50, 0)
 	}
+	fn schedule_named_recurring(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn set_recurrence_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel_recurrence_named() -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn reschedule_named_call() -> Weight {
+		Weight::from_parts(50, 0)
+	}
 }
 parameter_types! {
 	pub storage MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
 }
 
+parameter_types! {
+	pub static NormalClassLimit: ClassLimit<u64> = UNLIMITED;
+	pub static BulkClassLimit: ClassLimit<u64> = UNLIMITED;
+}
+
+pub const UNLIMITED: ClassLimit<u64> =
+	ClassLimit { weight_share: Perbill::one(), max_postponement: u64::MAX };
+
+pub struct TestClassLimits;
+impl ClassLimits<u64> for TestClassLimits {
+	fn limit(class: PriorityClass) -> ClassLimit<u64> {
+		match class {
+			PriorityClass::Critical => UNLIMITED,
+			PriorityClass::Normal => NormalClassLimit::get(),
+			PriorityClass::Bulk => BulkClassLimit::get(),
+		}
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
@@ -227,6 +259,7 @@ impl Config for Test {
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type MaxScheduledPerBlock = ConstU32<10>;
 	type WeightInfo = TestWeightInfo;
+	type ClassLimits = TestClassLimits;
 	type Preimages = Preimage;
 	type BlockNumberProvider = frame_system::Pallet<Self>;
 }
@@ -180,6 +180,206 @@ fn periodic_scheduling_works() {
 	});
 }
 
+fn log_call(i: u32) -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::Logger(logger::Call::log { i, weight: Weight::from_parts(10, 0) }))
+}
+
+#[test]
+fn recurring_scheduling_works() {
+	new_test_ext().execute_with(|| {
+		let id = [1u8; 32];
+		// at #4, every 3 blocks, 3 times.
+		assert_ok!(Scheduler::schedule_named_recurring(
+			root().into(),
+			id,
+			4,
+			3,
+			Some(3),
+			None,
+			127,
+			log_call(42)
+		));
+		assert_eq!(
+			Recurrences::<Test>::get(id),
+			Some(RecurrenceConfig { period: 3, remaining: Some(2), end: None })
+		);
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		assert_eq!(Lookup::<Test>::get(id), Some((7, 0)));
+		System::run_to_block::<AllPalletsWithSystem>(7);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32)]);
+		System::run_to_block::<AllPalletsWithSystem>(10);
+		assert_eq!(logger::log(), vec![(root(), 42u32), (root(), 42u32), (root(), 42u32)]);
+		assert!(Lookup::<Test>::get(id).is_none());
+		assert!(Recurrences::<Test>::get(id).is_none());
+		System::run_to_block::<AllPalletsWithSystem>(100);
+		assert_eq!(logger::log().len(), 3);
+	});
+}
+
+#[test]
+fn recurring_scheduling_stays_aligned() {
+	new_test_ext().execute_with(|| {
+		let id = [1u8; 32];
+		// at #4, every 3 blocks, until #20.
+		assert_ok!(Scheduler::schedule_named_recurring(
+			root().into(),
+			id,
+			4,
+			3,
+			None,
+			Some(20),
+			127,
+			log_call(42)
+		));
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(Lookup::<Test>::get(id), Some((7, 0)));
+
+		// The block number provider skips to #9, so the task due at #7 runs late. Its next run
+		// stays aligned to the schedule instead of being delayed.
+		System::set_block_number(9);
+		Scheduler::on_initialize(9);
+		assert_eq!(logger::log().len(), 2);
+		assert_eq!(Lookup::<Test>::get(id), Some((10, 0)));
+
+		// Runs which were missed entirely are skipped.
+		System::set_block_number(17);
+		Scheduler::on_initialize(17);
+		assert_eq!(logger::log().len(), 3);
+		assert_eq!(Lookup::<Test>::get(id), Some((19, 0)));
+
+		// The next run would be after the end.
+		System::run_to_block::<AllPalletsWithSystem>(19);
+		assert_eq!(logger::log().len(), 4);
+		assert!(Lookup::<Test>::get(id).is_none());
+		assert!(Recurrences::<Test>::get(id).is_none());
+	});
+}
+
+#[test]
+fn set_and_cancel_recurrence_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let id = [1u8; 32];
+		assert_ok!(Scheduler::schedule_named(root().into(), id, 4, None, 127, log_call(42)));
+		assert_noop!(
+			Scheduler::set_recurrence_named(root().into(), id, 0, None, None),
+			Error::<Test>::InvalidRecurrence,
+		);
+		assert_noop!(
+			Scheduler::set_recurrence_named(root().into(), id, 2, Some(1), None),
+			Error::<Test>::InvalidRecurrence,
+		);
+		assert_noop!(
+			Scheduler::set_recurrence_named(root().into(), id, 2, None, Some(3)),
+			Error::<Test>::InvalidRecurrence,
+		);
+		assert_noop!(
+			Scheduler::set_recurrence_named(root().into(), [2u8; 32], 2, None, None),
+			Error::<Test>::NotFound,
+		);
+		assert_ok!(Scheduler::set_recurrence_named(root().into(), id, 2, None, None));
+		System::assert_last_event(
+			Event::RecurrenceSet { task: (4, 0), id, period: 2, remaining: None, end: None }.into(),
+		);
+
+		System::run_to_block::<AllPalletsWithSystem>(6);
+		assert_eq!(logger::log().len(), 2);
+		assert_ok!(Scheduler::cancel_recurrence_named(root().into(), id));
+		System::assert_last_event(Event::RecurrenceCancelled { task: (8, 0), id }.into());
+		assert_noop!(
+			Scheduler::cancel_recurrence_named(root().into(), id),
+			Error::<Test>::NotFound,
+		);
+
+		// The task still runs once more.
+		System::run_to_block::<AllPalletsWithSystem>(100);
+		assert_eq!(logger::log().len(), 3);
+		assert!(Lookup::<Test>::get(id).is_none());
+	});
+}
+
+#[test]
+fn recurrence_is_removed_with_its_task() {
+	new_test_ext().execute_with(|| {
+		let id = [1u8; 32];
+		assert_ok!(Scheduler::schedule_named(
+			root().into(),
+			id,
+			4,
+			Some((3, 3)),
+			127,
+			log_call(42)
+		));
+		assert_noop!(
+			Scheduler::set_recurrence_named(root().into(), id, 2, None, None),
+			Error::<Test>::Periodic,
+		);
+		assert_ok!(Scheduler::cancel_named(root().into(), id));
+
+		assert_ok!(Scheduler::schedule_named_recurring(
+			root().into(),
+			id,
+			4,
+			3,
+			None,
+			None,
+			127,
+			log_call(42)
+		));
+		assert!(Recurrences::<Test>::contains_key(id));
+		assert_ok!(Scheduler::cancel_named(root().into(), id));
+		assert!(!Recurrences::<Test>::contains_key(id));
+		System::run_to_block::<AllPalletsWithSystem>(100);
+		assert!(logger::log().is_empty());
+	});
+}
+
+#[test]
+fn reschedule_named_call_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let id = [1u8; 32];
+		assert_ok!(Scheduler::schedule_named(
+			root().into(),
+			id,
+			4,
+			Some((3, 2)),
+			127,
+			log_call(42)
+		));
+		assert_ok!(Scheduler::schedule_named(root().into(), [2u8; 32], 4, None, 127, log_call(1)));
+		assert_ok!(Scheduler::set_retry_named(root().into(), id, 1, 1));
+
+		assert_noop!(
+			Scheduler::reschedule_named_call(root().into(), [3u8; 32], log_call(69)),
+			Error::<Test>::NotFound,
+		);
+		assert_noop!(
+			Scheduler::reschedule_named_call(system::RawOrigin::Signed(1).into(), id, log_call(69)),
+			BadOrigin,
+		);
+		assert_ok!(Scheduler::reschedule_named_call(root().into(), id, log_call(69)));
+		System::assert_last_event(
+			Event::CallUpdated {
+				task: (4, 0),
+				id,
+				old_call_hash: <Test as frame_system::Config>::Hashing::hash_of(&log_call(42)),
+				new_call_hash: <Test as frame_system::Config>::Hashing::hash_of(&log_call(69)),
+			}
+			.into(),
+		);
+		// The task keeps its address, retry and periodic configuration.
+		assert_eq!(Lookup::<Test>::get(id), Some((4, 0)));
+		assert!(Retries::<Test>::contains_key((4, 0)));
+
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 1u32)]);
+		System::run_to_block::<AllPalletsWithSystem>(100);
+		assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 1u32), (root(), 69u32)]);
+	});
+}
+
 #[test]
 fn retry_scheduling_works() {
 	new_test_ext().execute_with(|| {
@@ -1290,6 +1490,144 @@ fn scheduler_respects_weight_limits() {
 	});
 }
 
+#[test]
+fn priority_classes_are_derived_from_the_priority() {
+	assert_eq!(PriorityClass::of(0), PriorityClass::Critical);
+	assert_eq!(PriorityClass::of(schedule::HARD_DEADLINE), PriorityClass::Critical);
+	assert_eq!(PriorityClass::of(schedule::HARD_DEADLINE + 1), PriorityClass::Normal);
+	assert_eq!(PriorityClass::of(BULK_PRIORITY - 1), PriorityClass::Normal);
+	assert_eq!(PriorityClass::of(BULK_PRIORITY), PriorityClass::Bulk);
+	assert_eq!(PriorityClass::of(schedule::LOWEST_PRIORITY), PriorityClass::Bulk);
+}
+
+#[test]
+fn bulk_tasks_respect_their_weight_share() {
+	new_test_ext().execute_with(|| {
+		BulkClassLimit::set(ClassLimit { weight_share: Perbill::from_percent(50), ..UNLIMITED });
+		let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+		for (i, when, priority) in [(1, 4, 200), (2, 4, 200), (3, 5, 0)] {
+			let call = RuntimeCall::Logger(LoggerCall::log { i, weight: max_weight / 3 });
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(when),
+				None,
+				priority,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
+		}
+
+		// Both bulk tasks would fit into the block, but not into the share of the bulk class.
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log(), vec![(root(), 1u32)]);
+		assert_eq!(Agenda::<Test>::get(4).iter().flatten().count(), 1);
+
+		// The weight reserved for the critical class is available to the critical task.
+		System::run_to_block::<AllPalletsWithSystem>(5);
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 2u32), (root(), 3u32)]);
+		assert_eq!(Agenda::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn delaying_critical_tasks_is_reported() {
+	new_test_ext().execute_with(|| {
+		let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+		for i in [1, 2] {
+			let call = RuntimeCall::Logger(LoggerCall::log { i, weight: max_weight / 3 * 2 });
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(4),
+				None,
+				schedule::HARD_DEADLINE,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
+		}
+
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log(), vec![(root(), 1u32)]);
+		System::assert_has_event(
+			Event::<Test>::CriticalTaskDelayed { task: (4, 1), id: None }.into(),
+		);
+
+		System::run_to_block::<AllPalletsWithSystem>(5);
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 2u32)]);
+	});
+}
+
+#[test]
+fn postponed_tasks_expire_after_their_class_limit() {
+	new_test_ext().execute_with(|| {
+		NormalClassLimit::set(ClassLimit {
+			weight_share: Perbill::from_percent(50),
+			max_postponement: 1,
+		});
+		let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+		for (i, when) in [(1, 4), (2, 4), (3, 5)] {
+			let call = RuntimeCall::Logger(LoggerCall::log { i, weight: max_weight / 3 });
+			assert_ok!(Scheduler::do_schedule(
+				DispatchTime::At(when),
+				None,
+				127,
+				root(),
+				Preimage::bound(call).unwrap(),
+			));
+		}
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 3 });
+		let bound = Preimage::bound(call).unwrap();
+		assert_ok!(Scheduler::do_schedule_named(
+			[1u8; 32],
+			DispatchTime::At(4),
+			None,
+			128,
+			root(),
+			bound.clone(),
+		));
+
+		// Only one task fits into the share of the normal class.
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert_eq!(logger::log(), vec![(root(), 1u32)]);
+		assert_eq!(Agenda::<Test>::get(4).iter().flatten().count(), 2);
+
+		// The named task is crowded out again and has been postponed for too long.
+		System::run_to_block::<AllPalletsWithSystem>(5);
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 2u32)]);
+		System::assert_has_event(
+			Event::<Test>::PostponementExpired { task: (4, 2), id: Some([1u8; 32]) }.into(),
+		);
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), None);
+		assert!(Agenda::<Test>::get(4).is_empty());
+		assert!(!Preimage::is_requested(&bound.hash()));
+
+		System::run_to_block::<AllPalletsWithSystem>(6);
+		assert_eq!(logger::log(), vec![(root(), 1u32), (root(), 2u32), (root(), 3u32)]);
+		assert_eq!(Agenda::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn tasks_heavier_than_their_class_share_are_permanently_overweight() {
+	new_test_ext().execute_with(|| {
+		NormalClassLimit::set(ClassLimit { weight_share: Perbill::from_percent(50), ..UNLIMITED });
+		let max_weight: Weight = <Test as Config>::MaximumWeight::get();
+		// The call fits into the block, but never into the share of the normal class.
+		let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: max_weight / 3 * 2 });
+		assert_ok!(Scheduler::do_schedule(
+			DispatchTime::At(4),
+			None,
+			127,
+			root(),
+			Preimage::bound(call).unwrap(),
+		));
+
+		System::run_to_block::<AllPalletsWithSystem>(4);
+		assert!(logger::log().is_empty());
+		System::assert_last_event(
+			Event::<Test>::PermanentlyOverweight { task: (4, 0), id: None }.into(),
+		);
+		assert_eq!(IncompleteSince::<Test>::get(), Some(5));
+	});
+}
+
 #[test]
 fn retry_respects_weight_limits() {
 	new_test_ext().execute_with(|| {
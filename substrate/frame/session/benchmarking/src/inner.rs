@@ -97,6 +97,42 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_keys_at() -> Result<(), BenchmarkError> {
+		let n = MaxNominationsOf::<T>::get();
+		let (v_stash, _) = create_validator_with_nominators::<T>(
+			n,
+			MaxNominationsOf::<T>::get(),
+			false,
+			true,
+			RewardDestination::Staked,
+		)?;
+		let v_controller = pallet_staking::Pallet::<T>::bonded(&v_stash).ok_or("not stash")?;
+		let keys = T::Keys::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+		let proof: Vec<u8> = vec![0, 1, 2, 3];
+		Session::<T>::set_keys(
+			RawOrigin::Signed(v_controller.clone()).into(),
+			keys.clone(),
+			proof.clone(),
+		)?;
+		// Worst case: a previously scheduled rotation is replaced.
+		let session_index = CurrentIndex::<T>::get() + 2;
+		Session::<T>::set_keys_at(
+			RawOrigin::Signed(v_controller.clone()).into(),
+			session_index,
+			keys.clone(),
+			proof.clone(),
+		)?;
+		// Whitelist controller account from further DB operations.
+		let v_controller_key = frame_system::Account::<T>::hashed_key_for(&v_controller);
+		frame_benchmarking::benchmarking::add_to_whitelist(v_controller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(v_controller), session_index + 1, keys, proof);
+
+		Ok(())
+	}
+
 	#[benchmark(extra)]
 	fn check_membership_proof_current_session(n: Linear<2, MAX_VALIDATORS>) {
 		let (key, key_owner_proof1) = check_membership_proof_setup::<T>(n);
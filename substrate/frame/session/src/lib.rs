@@ -130,6 +130,7 @@ use frame_support::{
 	ensure,
 	traits::{
 		fungible::{hold::Mutate as HoldMutate, Inspect},
+		tokens::Precision,
 		Defensive, EstimateNextNewSession, EstimateNextSessionRotation, FindAuthor, Get,
 		OneSessionHandler, ValidatorRegistration, ValidatorSet,
 	},
@@ -138,7 +139,7 @@ use frame_support::{
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, Convert, Member, One, OpaqueKeys, Zero},
+	traits::{AtLeast32BitUnsigned, Convert, Hash, Member, One, OpaqueKeys, Saturating, Zero},
 	ConsensusEngineId, DispatchError, KeyTypeId, Permill, RuntimeAppPublic,
 };
 use sp_staking::{offence::OffenceSeverity, SessionIndex};
@@ -146,6 +147,10 @@ use sp_staking::{offence::OffenceSeverity, SessionIndex};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// The balance type of the currency used for the session keys deposit.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[cfg(any(feature = "try-runtime"))]
 use sp_runtime::TryRuntimeError;
 
@@ -394,7 +399,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -452,9 +457,22 @@ pub mod pallet {
 
 		/// The amount to be held when setting keys.
 		#[pallet::constant]
-		type KeyDeposit: Get<
-			<<Self as Config>::Currency as Inspect<<Self as frame_system::Config>::AccountId>>::Balance,
-		>;
+		type KeyDeposit: Get<BalanceOf<Self>>;
+
+		/// The additional amount to be held when setting keys, per type of session key.
+		///
+		/// The deposit for a key bundle is [`Config::KeyDeposit`] plus the amounts of the key types
+		/// contained in [`Config::Keys`]. Key types which aren't listed don't add to the deposit.
+		#[pallet::constant]
+		type KeyTypeDeposits: Get<Vec<(KeyTypeId, BalanceOf<Self>)>>;
+
+		/// The maximum number of key rotations that can be scheduled with `set_keys_at` for a
+		/// single session.
+		///
+		/// All of them are enacted when the session before it is planned, so this bounds the work
+		/// of a session rotation.
+		#[pallet::constant]
+		type MaxScheduledKeysPerSession: Get<u32>;
 	}
 
 	#[pallet::genesis_config]
@@ -575,6 +593,38 @@ pub mod pallet {
 	pub type KeyOwner<T: Config> =
 		StorageMap<_, Twox64Concat, (KeyTypeId, Vec<u8>), T::ValidatorId, OptionQuery>;
 
+	/// The deposit held for the session keys registered by an account.
+	///
+	/// Accounts which registered their keys before deposits were tracked have no entry. Their
+	/// deposit isn't adjusted when they change their keys.
+	#[pallet::storage]
+	pub type KeyDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+	/// Session keys scheduled with `set_keys_at`, by the session in which they become active.
+	#[pallet::storage]
+	pub type ScheduledKeys<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		T::ValidatorId,
+		T::Keys,
+		OptionQuery,
+	>;
+
+	/// The session in which the scheduled keys of a validator become active.
+	#[pallet::storage]
+	pub type ScheduledKeysSession<T: Config> =
+		StorageMap<_, Twox64Concat, T::ValidatorId, SessionIndex, OptionQuery>;
+
+	/// The number of key rotations scheduled for a session.
+	///
+	/// At most [`Config::MaxScheduledKeysPerSession`].
+	#[pallet::storage]
+	pub type ScheduledKeysCount<T: Config> =
+		StorageMap<_, Twox64Concat, SessionIndex, u32, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -588,6 +638,24 @@ pub mod pallet {
 		ValidatorDisabled { validator: T::ValidatorId },
 		/// Validator has been re-enabled.
 		ValidatorReenabled { validator: T::ValidatorId },
+		/// A validator scheduled its session keys to rotate, becoming active in `session_index`.
+		KeysRotationScheduled {
+			validator: T::ValidatorId,
+			session_index: SessionIndex,
+			old_keys_hash: T::Hash,
+			new_keys_hash: T::Hash,
+		},
+		/// The scheduled session keys of a validator were queued and become active in
+		/// `session_index`.
+		KeysRotated {
+			validator: T::ValidatorId,
+			session_index: SessionIndex,
+			old_keys_hash: T::Hash,
+			new_keys_hash: T::Hash,
+		},
+		/// The scheduled session keys of a validator couldn't be set, because another validator
+		/// registered one of them in the meantime.
+		KeysRotationFailed { validator: T::ValidatorId, session_index: SessionIndex },
 	}
 
 	/// Error for the session pallet.
@@ -603,6 +671,10 @@ pub mod pallet {
 		NoKeys,
 		/// Key setting account is not live, so it's impossible to associate keys.
 		NoAccount,
+		/// The session is too early to schedule a key rotation for, use `set_keys` instead.
+		SessionTooEarly,
+		/// Too many key rotations are scheduled for the session already.
+		TooManyScheduledKeys,
 	}
 
 	#[pallet::hooks]
@@ -611,6 +683,8 @@ pub mod pallet {
 		/// block of the current session.
 		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
 			if T::ShouldEndSession::should_end_session(n) {
+				// The rotation is accounted as a full block. `integrity_test` ensures that this
+				// covers enacting the scheduled key rotations.
 				Self::rotate_session();
 				T::BlockWeights::get().max_block
 			} else {
@@ -625,6 +699,14 @@ pub mod pallet {
 		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
 			Self::do_try_state()
 		}
+
+		fn integrity_test() {
+			assert!(
+				Self::enact_scheduled_keys_weight(T::MaxScheduledKeysPerSession::get())
+					.all_lte(T::BlockWeights::get().max_block),
+				"`MaxScheduledKeysPerSession` scheduled key rotations don't fit into a block",
+			);
+		}
 	}
 
 	#[pallet::call]
@@ -667,6 +749,34 @@ pub mod pallet {
 			Self::do_purge_keys(&who)?;
 			Ok(())
 		}
+
+		/// Schedules the session key(s) of the function caller to rotate to `keys`, such that they
+		/// become active in session `session_index`.
+		///
+		/// Unlike `set_keys`, the rotation is announced through the `KeysRotationScheduled` event
+		/// ahead of time, and the `KeysRotated` event is emitted when the keys are queued. The
+		/// caller must have session keys set already, and `session_index` must be at least two
+		/// sessions after the current one. Scheduling again replaces the previous rotation, and
+		/// purging the keys cancels it.
+		///
+		/// The dispatch origin of this function must be signed.
+		///
+		/// ## Complexity
+		/// - `O(1)`. Actual cost depends on the number of length of `T::Keys::key_ids()` which is
+		///   fixed.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_keys_at())]
+		pub fn set_keys_at(
+			origin: OriginFor<T>,
+			session_index: SessionIndex,
+			keys: T::Keys,
+			proof: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(keys.ownership_proof_is_valid(&proof), Error::<T>::InvalidProof);
+
+			Self::do_schedule_keys(&who, session_index, keys)
+		}
 	}
 }
 
@@ -740,6 +850,9 @@ impl<T: Config> Pallet<T> {
 				(Validators::<T>::get(), false)
 			};
 
+		// Set the keys which were scheduled to become active in the next session.
+		Self::enact_scheduled_keys(session_index + 1);
+
 		// Queue next session keys.
 		let (queued_amalgamated, next_changed) = {
 			// until we are certain there has been a change, iterate the prior
@@ -828,6 +941,8 @@ impl<T: Config> Pallet<T> {
 			Some(new_keys)
 		});
 
+		ScheduledKeys::<T>::translate::<Old, _>(|_, val, old_keys| Some(upgrade(val, old_keys)));
+
 		let _ = QueuedKeys::<T>::translate::<Vec<(T::ValidatorId, Old)>, _>(|k| {
 			k.map(|k| {
 				k.into_iter()
@@ -848,22 +963,45 @@ impl<T: Config> Pallet<T> {
 		ensure!(frame_system::Pallet::<T>::can_inc_consumer(account), Error::<T>::NoAccount);
 
 		let old_keys = Self::inner_set_keys(&who, keys)?;
+		let deposit = Self::keys_deposit();
 
 		// Place deposit on hold if this is a new registration (i.e. old_keys is None).
 		// The hold call itself will return an error if funds are insufficient.
 		if old_keys.is_none() {
-			let deposit = T::KeyDeposit::get();
 			if !deposit.is_zero() {
 				T::Currency::hold(&HoldReason::Keys.into(), account, deposit)?;
 			}
+			KeyDeposits::<T>::insert(account, deposit);
 
 			let assertion = frame_system::Pallet::<T>::inc_consumers(account).is_ok();
 			debug_assert!(assertion, "can_inc_consumer() returned true; no change since; qed");
+		} else if let Some(held) = KeyDeposits::<T>::get(account) {
+			// The deposit may have changed since the keys were registered.
+			if deposit > held {
+				T::Currency::hold(&HoldReason::Keys.into(), account, deposit - held)?;
+			} else if held > deposit {
+				T::Currency::release(
+					&HoldReason::Keys.into(),
+					account,
+					held - deposit,
+					Precision::BestEffort,
+				)?;
+			}
+			KeyDeposits::<T>::insert(account, deposit);
 		}
 
 		Ok(())
 	}
 
+	/// The deposit to hold for the session keys of a validator.
+	pub fn keys_deposit() -> BalanceOf<T> {
+		let key_ids = T::Keys::key_ids();
+		T::KeyTypeDeposits::get()
+			.into_iter()
+			.filter(|(id, _)| key_ids.contains(id))
+			.fold(T::KeyDeposit::get(), |deposit, (_, amount)| deposit.saturating_add(amount))
+	}
+
 	/// Perform the set_key operation, checking for duplicates. Does not set `Changed`.
 	///
 	/// The old keys for this validator are returned, or `None` if there were none.
@@ -904,6 +1042,101 @@ impl<T: Config> Pallet<T> {
 		Ok(old_keys)
 	}
 
+	/// Schedule the keys of the validator of `account` to rotate to `keys` in `session_index`.
+	fn do_schedule_keys(
+		account: &T::AccountId,
+		session_index: SessionIndex,
+		keys: T::Keys,
+	) -> DispatchResult {
+		let who = T::ValidatorIdOf::convert(account.clone())
+			.ok_or(Error::<T>::NoAssociatedValidatorId)?;
+
+		// The keys of the next session are queued already.
+		ensure!(
+			session_index > CurrentIndex::<T>::get().saturating_add(1),
+			Error::<T>::SessionTooEarly
+		);
+		let old_keys = Self::load_keys(&who).ok_or(Error::<T>::NoKeys)?;
+
+		for id in T::Keys::key_ids() {
+			ensure!(
+				Self::key_owner(*id, keys.get_raw(*id)).map_or(true, |owner| owner == who),
+				Error::<T>::DuplicatedKey,
+			);
+		}
+
+		let previous = ScheduledKeysSession::<T>::get(&who);
+		if previous != Some(session_index) {
+			ensure!(
+				ScheduledKeysCount::<T>::get(session_index) < T::MaxScheduledKeysPerSession::get(),
+				Error::<T>::TooManyScheduledKeys
+			);
+			if let Some(previous) = previous {
+				Self::unschedule_keys(previous, &who);
+			}
+			ScheduledKeysCount::<T>::mutate(session_index, |count| count.saturating_inc());
+		}
+		ScheduledKeys::<T>::insert(session_index, &who, &keys);
+		ScheduledKeysSession::<T>::insert(&who, session_index);
+
+		Self::deposit_event(Event::KeysRotationScheduled {
+			validator: who,
+			session_index,
+			old_keys_hash: T::Hashing::hash_of(&old_keys),
+			new_keys_hash: T::Hashing::hash_of(&keys),
+		});
+		Ok(())
+	}
+
+	/// Remove the key rotation of `who` scheduled for `session_index`.
+	fn unschedule_keys(session_index: SessionIndex, who: &T::ValidatorId) {
+		ScheduledKeys::<T>::remove(session_index, who);
+		ScheduledKeysCount::<T>::mutate_exists(session_index, |count| {
+			*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+		});
+	}
+
+	/// The weight of enacting `count` scheduled key rotations.
+	///
+	/// Enacting a rotation does a subset of the work of `set_keys`, plus removing it from the
+	/// schedule.
+	pub fn enact_scheduled_keys_weight(count: u32) -> Weight {
+		T::WeightInfo::set_keys()
+			.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+			.saturating_mul(count.into())
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Set the keys which were scheduled to become active in `session_index`.
+	///
+	/// There are at most [`Config::MaxScheduledKeysPerSession`] of them.
+	fn enact_scheduled_keys(session_index: SessionIndex) {
+		ScheduledKeysCount::<T>::remove(session_index);
+		for (who, keys) in ScheduledKeys::<T>::drain_prefix(session_index) {
+			ScheduledKeysSession::<T>::remove(&who);
+
+			let new_keys_hash = T::Hashing::hash_of(&keys);
+			match Self::inner_set_keys(&who, keys) {
+				Ok(old_keys) => Self::deposit_event(Event::KeysRotated {
+					validator: who,
+					session_index,
+					// Purging the keys cancels the rotation, so there are always old keys.
+					old_keys_hash: old_keys
+						.map(|keys| T::Hashing::hash_of(&keys))
+						.defensive_unwrap_or_default(),
+					new_keys_hash,
+				}),
+				Err(e) => {
+					log!(warn, "failed to rotate the session keys of {:?}: {:?}", who, e);
+					Self::deposit_event(Event::KeysRotationFailed {
+						validator: who,
+						session_index,
+					});
+				},
+			}
+		}
+	}
+
 	fn do_purge_keys(account: &T::AccountId) -> DispatchResult {
 		let who = T::ValidatorIdOf::convert(account.clone())
 			// `purge_keys` may not have a controller-stash pair any more. If so then we expect the
@@ -913,17 +1146,33 @@ impl<T: Config> Pallet<T> {
 			.ok_or(Error::<T>::NoAssociatedValidatorId)?;
 
 		let old_keys = Self::take_keys(&who).ok_or(Error::<T>::NoKeys)?;
+		if let Some(session_index) = ScheduledKeysSession::<T>::take(&who) {
+			Self::unschedule_keys(session_index, &who);
+		}
 		for id in T::Keys::key_ids() {
 			let key_data = old_keys.get_raw(*id);
 			Self::clear_key_owner(*id, key_data);
 		}
 
-		// Use release_all to handle the case where the exact amount might not be available
-		let _ = T::Currency::release_all(
-			&HoldReason::Keys.into(),
-			account,
-			frame_support::traits::tokens::Precision::BestEffort,
-		);
+		match KeyDeposits::<T>::take(account) {
+			// Refund exactly what was held for the keys.
+			Some(deposit) => {
+				let _ = T::Currency::release(
+					&HoldReason::Keys.into(),
+					account,
+					deposit,
+					Precision::BestEffort,
+				);
+			},
+			// Use release_all to handle the case where the exact amount might not be available
+			None => {
+				let _ = T::Currency::release_all(
+					&HoldReason::Keys.into(),
+					account,
+					Precision::BestEffort,
+				);
+			},
+		}
 
 		frame_system::Pallet::<T>::dec_consumers(account);
 
@@ -1059,6 +1308,23 @@ impl<T: Config> Pallet<T> {
 			DisabledValidators::<T>::get().windows(2).all(|pair| pair[0].0 <= pair[1].0),
 			"DisabledValidators is not sorted"
 		);
+		// Ensure that every scheduled rotation is indexed by its validator
+		ensure!(
+			ScheduledKeys::<T>::iter_keys().all(|(session_index, validator)| {
+				ScheduledKeysSession::<T>::get(validator) == Some(session_index)
+			}) && ScheduledKeysSession::<T>::iter().all(|(validator, session_index)| {
+				ScheduledKeys::<T>::contains_key(session_index, validator)
+			}),
+			"ScheduledKeys and ScheduledKeysSession are inconsistent"
+		);
+		// Ensure that the scheduled rotations are counted and bounded
+		ensure!(
+			ScheduledKeysCount::<T>::iter().all(|(session_index, count)| {
+				count <= T::MaxScheduledKeysPerSession::get() &&
+					ScheduledKeys::<T>::iter_key_prefix(session_index).count() == count as usize
+			}),
+			"ScheduledKeysCount is inconsistent"
+		);
 		Ok(())
 	}
 }
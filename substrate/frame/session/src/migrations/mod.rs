@@ -23,3 +23,4 @@
 #[cfg(feature = "historical")]
 pub mod historical;
 pub mod v1;
+pub mod v2;
@@ -0,0 +1,97 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Version 2.
+//!
+//! Version 2 tracks the deposit held for the session keys of each account in
+//! [`KeyDeposits`](crate::KeyDeposits), so that `purge_keys` refunds exactly what was held. This
+//! migration records the deposits which are held already for the registered keys.
+
+use crate::{Config, HoldReason, KeyDeposits, NextKeys, Pallet};
+use frame_support::{
+	migrations::VersionedMigration,
+	pallet_prelude::{Get, Weight},
+	traits::{fungible::InspectHold, UncheckedOnRuntimeUpgrade},
+};
+use sp_runtime::{
+	traits::{MaybeConvert, Zero},
+	Saturating,
+};
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Records the deposits held for the keys registered in [`NextKeys`].
+///
+/// `ValidatorAccount` returns the account which registered the keys of a validator. Accounts
+/// without a held deposit aren't recorded, so their keys stay exempt from the deposit.
+pub struct VersionUncheckedMigrateV1ToV2<T, ValidatorAccount>(
+	core::marker::PhantomData<(T, ValidatorAccount)>,
+);
+
+impl<T, ValidatorAccount> UncheckedOnRuntimeUpgrade
+	for VersionUncheckedMigrateV1ToV2<T, ValidatorAccount>
+where
+	T: Config,
+	ValidatorAccount: MaybeConvert<T::ValidatorId, T::AccountId>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let (mut reads, mut writes) = (0u64, 0u64);
+		for validator in NextKeys::<T>::iter_keys() {
+			reads.saturating_inc();
+			let Some(account) = ValidatorAccount::maybe_convert(validator) else { continue };
+
+			reads.saturating_inc();
+			let held = T::Currency::balance_on_hold(&HoldReason::Keys.into(), &account);
+			if !held.is_zero() {
+				KeyDeposits::<T>::insert(&account, held);
+				writes.saturating_inc();
+			}
+		}
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(KeyDeposits::<T>::iter().next().is_none(), "KeyDeposits must be empty");
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		for (account, deposit) in KeyDeposits::<T>::iter() {
+			ensure!(
+				T::Currency::balance_on_hold(&HoldReason::Keys.into(), &account) == deposit,
+				"Recorded deposit doesn't match the held amount"
+			);
+		}
+		Ok(())
+	}
+}
+
+pub type MigrateV1ToV2<T, ValidatorAccount> = VersionedMigration<
+	1,
+	2,
+	VersionUncheckedMigrateV1ToV2<T, ValidatorAccount>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
@@ -21,7 +21,10 @@ use super::*;
 use crate as pallet_session;
 #[cfg(feature = "historical")]
 use crate::historical as pallet_session_historical;
-use frame_support::{derive_impl, parameter_types, traits::ConstU64};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
 use pallet_balances::{self, AccountData};
 use sp_core::crypto::key_types::DUMMY;
 use sp_runtime::{
@@ -80,6 +83,7 @@ frame_support::construct_runtime!(
 		Session: pallet_session,
 		Balances: pallet_balances,
 		Historical: pallet_session_historical,
+		Timestamp: pallet_timestamp,
 	}
 );
 
@@ -90,6 +94,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Session: pallet_session,
 		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
 	}
 );
 
@@ -107,6 +112,7 @@ parameter_types! {
 	pub static BeforeSessionEndCalled: bool = false;
 	pub static ValidatorAccounts: BTreeMap<u64, u64> = BTreeMap::new();
 	pub static KeyDeposit: u64 = 10;
+	pub static KeyTypeDeposits: Vec<(KeyTypeId, u64)> = vec![];
 }
 
 pub struct TestShouldEndSession;
@@ -267,6 +273,8 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ConstU64<5>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = ();
 }
 
@@ -303,6 +311,8 @@ impl Config for Test {
 	type WeightInfo = ();
 	type Currency = pallet_balances::Pallet<Test>;
 	type KeyDeposit = KeyDeposit;
+	type KeyTypeDeposits = KeyTypeDeposits;
+	type MaxScheduledKeysPerSession = ConstU32<2>;
 }
 
 #[cfg(feature = "historical")]
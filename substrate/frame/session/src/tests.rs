@@ -21,9 +21,9 @@ use super::*;
 use crate::mock::{
 	authorities, before_session_end_called, force_new_session, new_test_ext,
 	reset_before_session_end_called, session_changed, session_events_since_last_call, session_hold,
-	set_next_validators, set_session_length, Balances, KeyDeposit, MockSessionKeys,
-	PreUpgradeMockSessionKeys, RuntimeOrigin, Session, SessionChanged, System, Test,
-	TestSessionChanged, TestValidatorIdOf, ValidatorAccounts,
+	set_next_validators, set_session_length, Balances, KeyDeposit, KeyTypeDeposits,
+	MockSessionKeys, PreUpgradeMockSessionKeys, RuntimeOrigin, Session, SessionChanged, System,
+	Test, TestSessionChanged, TestValidatorIdOf, ValidatorAccounts, KEY_ID_A,
 };
 
 use codec::Decode;
@@ -563,6 +563,73 @@ fn purge_keys_should_unhold_funds() {
 	});
 }
 
+#[test]
+fn key_type_deposits_are_held_and_refunded() {
+	new_test_ext().execute_with(|| {
+		// `KEY_ID_A` isn't part of the session keys, so it doesn't add to the deposit.
+		KeyTypeDeposits::set(vec![(DUMMY, 5), (KEY_ID_A, 50)]);
+		assert_eq!(Session::keys_deposit(), KeyDeposit::get() + 5);
+
+		let account_id = 1000;
+		ValidatorAccounts::mutate(|m| {
+			m.insert(account_id, account_id);
+		});
+		frame_system::Pallet::<Test>::inc_providers(&account_id);
+
+		assert_ok!(Session::set_keys(
+			RuntimeOrigin::signed(account_id),
+			UintAuthorityId(account_id).into(),
+			vec![]
+		));
+		assert_eq!(session_hold(account_id), 15);
+		assert_eq!(KeyDeposits::<Test>::get(account_id), Some(15));
+
+		// the deposit follows the configuration when the keys are changed.
+		KeyTypeDeposits::set(vec![(DUMMY, 20)]);
+		assert_ok!(Session::set_keys(
+			RuntimeOrigin::signed(account_id),
+			UintAuthorityId(1001).into(),
+			vec![]
+		));
+		assert_eq!(session_hold(account_id), 30);
+		assert_eq!(KeyDeposits::<Test>::get(account_id), Some(30));
+
+		KeyTypeDeposits::set(vec![]);
+		assert_ok!(Session::set_keys(
+			RuntimeOrigin::signed(account_id),
+			UintAuthorityId(1002).into(),
+			vec![]
+		));
+		assert_eq!(session_hold(account_id), 10);
+
+		// purging refunds exactly what was held.
+		assert_ok!(Session::purge_keys(RuntimeOrigin::signed(account_id)));
+		assert_eq!(session_hold(account_id), 0);
+		assert_eq!(KeyDeposits::<Test>::get(account_id), None);
+	});
+}
+
+#[test]
+fn migration_v2_records_held_deposits() {
+	use crate::migrations::v2::VersionUncheckedMigrateV1ToV2;
+	use frame_support::traits::{fungible::MutateHold, UncheckedOnRuntimeUpgrade};
+
+	new_test_ext().execute_with(|| {
+		// 1 registered its keys with a deposit before deposits were tracked, 2 and 3 without.
+		assert_ok!(Balances::hold(&HoldReason::Keys.into(), &1, 10));
+
+		VersionUncheckedMigrateV1ToV2::<Test, sp_runtime::traits::ConvertInto>::on_runtime_upgrade(
+		);
+
+		assert_eq!(KeyDeposits::<Test>::get(1), Some(10));
+		assert_eq!(KeyDeposits::<Test>::get(2), None);
+		assert_eq!(KeyDeposits::<Test>::get(3), None);
+
+		assert_ok!(Session::purge_keys(RuntimeOrigin::signed(1)));
+		assert_eq!(session_hold(1), 0);
+	});
+}
+
 #[test]
 fn existing_validators_without_hold_are_except() {
 	// upon addition of `SessionDeposit`, a runtime may have some old validators without any held
@@ -590,6 +657,179 @@ fn existing_validators_without_hold_are_except() {
 	});
 }
 
+fn keys_hash(keys: &MockSessionKeys) -> <Test as frame_system::Config>::Hash {
+	<Test as frame_system::Config>::Hashing::hash_of(keys)
+}
+
+#[test]
+fn set_keys_at_rotates_keys_in_the_chosen_session() {
+	new_test_ext().execute_with(|| {
+		TestValidatorIdOf::set(vec![(1, 1), (2, 2), (3, 3)].into_iter().collect());
+		initialize_block(1);
+		assert_eq!(CurrentIndex::<Test>::get(), 0);
+
+		let old_keys: MockSessionKeys = UintAuthorityId(1).into();
+		let new_keys: MockSessionKeys = UintAuthorityId(10).into();
+
+		// the keys of session 1 are queued already.
+		assert_noop!(
+			Session::set_keys_at(RuntimeOrigin::signed(1), 1, new_keys.clone(), vec![]),
+			Error::<Test>::SessionTooEarly,
+		);
+		// keys owned by another validator can't be scheduled.
+		assert_noop!(
+			Session::set_keys_at(RuntimeOrigin::signed(1), 3, UintAuthorityId(2).into(), vec![]),
+			Error::<Test>::DuplicatedKey,
+		);
+		// validators without keys can't schedule a rotation.
+		TestValidatorIdOf::set(vec![(1, 1), (2, 2), (3, 3), (4, 4)].into_iter().collect());
+		assert_noop!(
+			Session::set_keys_at(RuntimeOrigin::signed(4), 3, UintAuthorityId(4).into(), vec![]),
+			Error::<Test>::NoKeys,
+		);
+
+		assert_ok!(Session::set_keys_at(RuntimeOrigin::signed(1), 3, new_keys.clone(), vec![]));
+		assert_eq!(
+			session_events_since_last_call(),
+			vec![Event::KeysRotationScheduled {
+				validator: 1,
+				session_index: 3,
+				old_keys_hash: keys_hash(&old_keys),
+				new_keys_hash: keys_hash(&new_keys),
+			}]
+		);
+		assert_eq!(ScheduledKeys::<Test>::get(3, 1), Some(new_keys.clone()));
+		assert_eq!(ScheduledKeysSession::<Test>::get(1), Some(3));
+
+		// nothing changes while session 2 is planned.
+		force_new_session();
+		initialize_block(2);
+		assert_eq!(CurrentIndex::<Test>::get(), 1);
+		assert_eq!(Session::load_keys(&1), Some(old_keys.clone()));
+
+		// the new keys are queued when session 3 is planned.
+		force_new_session();
+		initialize_block(3);
+		assert_eq!(CurrentIndex::<Test>::get(), 2);
+		assert_eq!(Session::load_keys(&1), Some(new_keys.clone()));
+		assert!(QueuedKeys::<Test>::get().contains(&(1, new_keys.clone())));
+		assert!(session_events_since_last_call().contains(&Event::KeysRotated {
+			validator: 1,
+			session_index: 3,
+			old_keys_hash: keys_hash(&old_keys),
+			new_keys_hash: keys_hash(&new_keys),
+		}));
+		assert_eq!(ScheduledKeysSession::<Test>::get(1), None);
+		assert_eq!(ScheduledKeys::<Test>::iter().count(), 0);
+
+		// and become active in session 3.
+		force_new_session();
+		initialize_block(4);
+		assert_eq!(CurrentIndex::<Test>::get(), 3);
+		assert_eq!(
+			authorities(),
+			vec![UintAuthorityId(10), UintAuthorityId(2), UintAuthorityId(3)]
+		);
+	});
+}
+
+#[test]
+fn scheduled_keys_rotation_can_be_replaced_and_cancelled() {
+	new_test_ext().execute_with(|| {
+		TestValidatorIdOf::set(vec![(1, 1), (2, 2), (3, 3)].into_iter().collect());
+		initialize_block(1);
+
+		assert_ok!(Session::set_keys_at(
+			RuntimeOrigin::signed(1),
+			3,
+			UintAuthorityId(10).into(),
+			vec![]
+		));
+		// scheduling again replaces the rotation.
+		assert_ok!(Session::set_keys_at(
+			RuntimeOrigin::signed(1),
+			4,
+			UintAuthorityId(11).into(),
+			vec![]
+		));
+		assert_eq!(ScheduledKeys::<Test>::get(3, 1), None);
+		assert_eq!(ScheduledKeys::<Test>::get(4, 1), Some(UintAuthorityId(11).into()));
+		assert_eq!(ScheduledKeysSession::<Test>::get(1), Some(4));
+
+		// purging the keys cancels the rotation.
+		assert_ok!(Session::purge_keys(RuntimeOrigin::signed(1)));
+		assert_eq!(ScheduledKeys::<Test>::get(4, 1), None);
+		assert_eq!(ScheduledKeysSession::<Test>::get(1), None);
+	});
+}
+
+#[test]
+fn scheduled_keys_rotations_per_session_are_bounded() {
+	new_test_ext().execute_with(|| {
+		TestValidatorIdOf::set(vec![(1, 1), (2, 2), (3, 3)].into_iter().collect());
+		initialize_block(1);
+		assert_eq!(<Test as Config>::MaxScheduledKeysPerSession::get(), 2);
+
+		let schedule = |who: u64, session_index: SessionIndex| {
+			Session::set_keys_at(
+				RuntimeOrigin::signed(who),
+				session_index,
+				UintAuthorityId(10 + who).into(),
+				vec![],
+			)
+		};
+		assert_ok!(schedule(1, 3));
+		assert_ok!(schedule(2, 3));
+		assert_noop!(schedule(3, 3), Error::<Test>::TooManyScheduledKeys);
+		// replacing a rotation in the same session doesn't take another slot.
+		assert_ok!(schedule(2, 3));
+		assert_eq!(ScheduledKeysCount::<Test>::get(3), 2);
+
+		// moving a rotation to another session frees its slot.
+		assert_ok!(schedule(2, 4));
+		assert_eq!(ScheduledKeysCount::<Test>::get(3), 1);
+		assert_ok!(schedule(3, 3));
+
+		// as does purging the keys.
+		assert_ok!(Session::purge_keys(RuntimeOrigin::signed(2)));
+		assert!(!ScheduledKeysCount::<Test>::contains_key(4));
+
+		// enacting the rotations clears the count.
+		force_new_session();
+		initialize_block(2);
+		force_new_session();
+		initialize_block(3);
+		assert_eq!(Session::load_keys(&3), Some(UintAuthorityId(13).into()));
+		assert!(!ScheduledKeysCount::<Test>::contains_key(3));
+	});
+}
+
+#[test]
+fn scheduled_keys_rotation_fails_if_keys_were_taken() {
+	new_test_ext().execute_with(|| {
+		TestValidatorIdOf::set(vec![(1, 1), (2, 2), (3, 3)].into_iter().collect());
+		initialize_block(1);
+
+		assert_ok!(Session::set_keys_at(
+			RuntimeOrigin::signed(1),
+			2,
+			UintAuthorityId(10).into(),
+			vec![]
+		));
+		// 2 registers the scheduled keys in the meantime.
+		assert_ok!(Session::set_keys(RuntimeOrigin::signed(2), UintAuthorityId(10).into(), vec![]));
+		session_events_since_last_call();
+
+		force_new_session();
+		initialize_block(2);
+		assert_eq!(CurrentIndex::<Test>::get(), 1);
+		assert_eq!(Session::load_keys(&1), Some(UintAuthorityId(1).into()));
+		assert!(session_events_since_last_call()
+			.contains(&Event::KeysRotationFailed { validator: 1, session_index: 2 }));
+		assert_eq!(ScheduledKeysSession::<Test>::get(1), None);
+	});
+}
+
 mod disabling_byzantine_threshold {
 	use super::*;
 	use crate::disabling::{DisablingStrategy, UpToLimitDisablingStrategy};
@@ -117,6 +117,8 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ConstU64<3>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = ();
 }
 
@@ -189,6 +191,8 @@ impl pallet_session::Config for Runtime {
 	type WeightInfo = ();
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = ConstU32<100>;
 }
 
 parameter_types! {
@@ -208,6 +212,7 @@ impl FindAuthor<AccountId> for GetAuthor {
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = GetAuthor;
 	type EventHandler = StakingAhClient;
+	type AuthorStatsWindow = ();
 }
 
 parameter_types! {
@@ -239,4 +239,12 @@ impl<T: frame_system::Config> cumulus_pallet_xcmp_queue::WeightInfo for WeightIn
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
+	/// Storage: `XcmpQueue::DeliveryFeeOverrides` (r:0 w:1)
+	/// Proof: `XcmpQueue::DeliveryFeeOverrides` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn set_delivery_fee_override() -> Weight {
+		// Placeholder until the `set_delivery_fee_override` benchmark is run.
+		Weight::from_parts(4_383_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -79,18 +79,30 @@ impl<T: frame_system::Config> polkadot_runtime_parachains::disputes::slashing::W
 	/// Proof: `Staking::OffenceQueue` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `Staking::OffenceQueueEras` (r:1 w:1)
 	/// Proof: `Staking::OffenceQueueEras` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	/// Storage: `ParasSlashing::ReportedSlashes` (r:1 w:1)
+	/// Proof: `ParasSlashing::ReportedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `n` is `[4, 300]`.
 	fn report_dispute_lost_unsigned(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `2024 + n * (33 ±0)`
-		//  Estimated: `5386 + n * (34 ±0)`
-		// Minimum execution time: 88_786_000 picoseconds.
+		// Placeholder until the `report_dispute_lost_unsigned` benchmark is run.
 		Weight::from_parts(127_346_367, 0)
 			.saturating_add(Weight::from_parts(0, 5386))
-			// Standard Error: 3_530
 			.saturating_add(Weight::from_parts(144_389, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(14))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(8))
 			.saturating_add(Weight::from_parts(0, 34).saturating_mul(n.into()))
 	}
+	/// Storage: `ParasSlashing::UnappliedSlashes` (r:1 w:1)
+	/// Proof: `ParasSlashing::UnappliedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParasSlashing::ReportedSlashes` (r:1 w:1)
+	/// Proof: `ParasSlashing::ReportedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `[1, 300]`.
+	fn exonerate(n: u32, ) -> Weight {
+		// Placeholder until the `exonerate` benchmark is run.
+		Weight::from_parts(14_862_000, 0)
+			.saturating_add(Weight::from_parts(0, 3722))
+			.saturating_add(Weight::from_parts(318_407, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 33).saturating_mul(n.into()))
+	}
 }
@@ -381,4 +381,33 @@ impl<T: frame_system::Config> polkadot_runtime_parachains::hrmp::WeightInfo for
 			.saturating_add(T::DbWeight::get().reads(23))
 			.saturating_add(T::DbWeight::get().writes(11))
 	}
+	/// Storage: `Hrmp::HrmpDepositRefundBeneficiary` (r:0 w:1)
+	/// Proof: `Hrmp::HrmpDepositRefundBeneficiary` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_deposit_refund_beneficiary() -> Weight {
+		// Placeholder until the `set_deposit_refund_beneficiary` benchmark is run.
+		Weight::from_parts(9_214_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Hrmp::HrmpDepositRefundBeneficiary` (r:1 w:0)
+	/// Proof: `Hrmp::HrmpDepositRefundBeneficiary` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Dmp::DeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `Dmp::DeliveryFeeFactor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmPallet::SupportedVersion` (r:1 w:0)
+	/// Proof: `XcmPallet::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DownwardMessageQueues` (r:1 w:1)
+	/// Proof: `Dmp::DownwardMessageQueues` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Paras::Heads` (r:1 w:0)
+	/// Proof: `Paras::Heads` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Dmp::DownwardMessageQueueHeads` (r:1 w:1)
+	/// Proof: `Dmp::DownwardMessageQueueHeads` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn refund_deposit() -> Weight {
+		// Placeholder until the `refund_deposit` benchmark is run.
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3677))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
@@ -153,6 +153,8 @@ impl pallet_session::Config for Test {
 	type WeightInfo = ();
 	type Currency = Balances;
 	type KeyDeposit = ();
+	type KeyTypeDeposits = ();
+	type MaxScheduledKeysPerSession = frame_support::traits::ConstU32<100>;
 }
 
 impl pallet_session::historical::Config for Test {
@@ -163,12 +165,15 @@ impl pallet_session::historical::Config for Test {
 impl pallet_authorship::Config for Test {
 	type FindAuthor = Author11;
 	type EventHandler = ();
+	type AuthorStatsWindow = ();
 }
 
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ConstU64<5>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = ();
 }
 
@@ -96,6 +96,7 @@ pub mod pallet {
 	use sp_runtime::{
 		self,
 		traits::{Saturating, Zero},
+		PerThing, Perbill,
 	};
 
 	pub(crate) type BalanceOf<T> =
@@ -429,6 +430,44 @@ pub mod pallet {
 		pub item: u32,
 	}
 
+	/// The bounds within which the limits of the automatic migration are throttled.
+	///
+	/// The limits scale linearly from `ceiling`, when the previous block was empty, down to
+	/// `floor`, when the previous block was full.
+	#[derive(
+		Clone,
+		Copy,
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		scale_info::TypeInfo,
+		Default,
+		Debug,
+		PartialEq,
+		Eq,
+		MaxEncodedLen,
+	)]
+	pub struct ThrottleLimits {
+		/// The limits used when the previous block was full.
+		pub floor: MigrationLimits,
+		/// The limits used when the previous block was empty.
+		pub ceiling: MigrationLimits,
+	}
+
+	impl ThrottleLimits {
+		/// The limits for a block following a block with the given `utilization`.
+		pub fn limits_for(&self, utilization: Perbill) -> MigrationLimits {
+			let free = utilization.left_from_one();
+			let scale = |floor: u32, ceiling: u32| {
+				floor.saturating_add(free * ceiling.saturating_sub(floor))
+			};
+			MigrationLimits {
+				size: scale(self.floor.size, self.ceiling.size),
+				item: scale(self.floor.item, self.ceiling.item),
+			}
+		}
+	}
+
 	/// How a migration was computed.
 	#[derive(
 		Clone,
@@ -586,6 +625,20 @@ pub mod pallet {
 	#[pallet::getter(fn signed_migration_max_limits)]
 	pub type SignedMigrationMaxLimits<T> = StorageValue<_, MigrationLimits, OptionQuery>;
 
+	/// The bounds within which the automatic migration is throttled.
+	///
+	/// If set, the limits of the automatic migration are derived from the utilization of the
+	/// previous block instead of [`AutoLimits`]. [`AutoLimits`] must still be set for the automatic
+	/// migration to run.
+	#[pallet::storage]
+	pub type AutoThrottle<T> = StorageValue<_, ThrottleLimits, OptionQuery>;
+
+	/// The utilization of the last block by non-mandatory dispatches.
+	///
+	/// Only tracked while the automatic migration is throttled.
+	#[pallet::storage]
+	pub type LastBlockUtilization<T> = StorageValue<_, Perbill, ValueQuery>;
+
 	#[pallet::error]
 	#[derive(Clone, PartialEq)]
 	pub enum Error<T> {
@@ -607,6 +660,8 @@ pub mod pallet {
 		SignedMigrationNotAllowed,
 		/// Bad child root provided.
 		BadChildRoot,
+		/// The floor of the throttle limits is above their ceiling.
+		BadThrottleLimits,
 	}
 
 	#[pallet::call]
@@ -865,12 +920,46 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Set the bounds within which the automatic migration is throttled.
+		///
+		/// The limits of the automatic migration then scale with the utilization of the previous
+		/// block, measured by the weight and proof size of its non-mandatory dispatches. `None`
+		/// disables throttling, in which case [`AutoLimits`] is used as is.
+		///
+		/// The dispatch origin of this call must be [`Config::ControlOrigin`].
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_auto_throttle(
+			origin: OriginFor<T>,
+			maybe_limits: Option<ThrottleLimits>,
+		) -> DispatchResult {
+			T::ControlOrigin::ensure_origin(origin)?;
+			if let Some(limits) = maybe_limits {
+				ensure!(
+					limits.floor.size <= limits.ceiling.size &&
+						limits.floor.item <= limits.ceiling.item,
+					Error::<T>::BadThrottleLimits
+				);
+			}
+			AutoThrottle::<T>::set(maybe_limits);
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
 			if let Some(limits) = Self::auto_limits() {
+				let (limits, throttle_weight) = match AutoThrottle::<T>::get() {
+					Some(throttle) => (
+						throttle.limits_for(LastBlockUtilization::<T>::get()),
+						// Reading the throttle here, and recording the utilization in
+						// `on_finalize`.
+						T::DbWeight::get().reads_writes(5, 1),
+					),
+					None => (limits, T::DbWeight::get().reads(3)),
+				};
 				let mut task = Self::migration_process();
 				if let Err(e) = task.migrate_until_exhaustion(limits) {
 					Self::halt(e);
@@ -898,9 +987,16 @@ pub mod pallet {
 
 				MigrationProcess::<T>::put(task);
 
-				weight
+				weight.saturating_add(throttle_weight)
 			} else {
-				T::DbWeight::get().reads(1)
+				// Also accounts for the read in `on_finalize`.
+				T::DbWeight::get().reads(2)
+			}
+		}
+
+		fn on_finalize(_: BlockNumberFor<T>) {
+			if Self::auto_limits().is_some() && AutoThrottle::<T>::exists() {
+				LastBlockUtilization::<T>::put(Self::block_utilization());
 			}
 		}
 	}
@@ -916,6 +1012,26 @@ pub mod pallet {
 				.saturating_add(T::WeightInfo::process_top_key(size))
 		}
 
+		/// The utilization of the current block by non-mandatory dispatches.
+		///
+		/// This is the larger of the ref time and proof size utilization.
+		fn block_utilization() -> Perbill {
+			let consumed = frame_system::Pallet::<T>::block_weight();
+			let used = consumed
+				.get(DispatchClass::Normal)
+				.saturating_add(*consumed.get(DispatchClass::Operational));
+			let max = T::BlockWeights::get().max_block;
+
+			let ratio = |used: u64, max: u64| {
+				if max.is_zero() {
+					Perbill::zero()
+				} else {
+					Perbill::from_rational(used, max)
+				}
+			};
+			ratio(used.ref_time(), max.ref_time()).max(ratio(used.proof_size(), max.proof_size()))
+		}
+
 		/// Put a stop to all ongoing migrations and logs an error.
 		fn halt(error: Error<T>) {
 			log!(error, "migration halted due to: {:?}", error);
@@ -1352,8 +1468,12 @@ mod mock {
 #[cfg(test)]
 mod test {
 	use super::{mock::*, *};
-	use frame_support::assert_ok;
-	use sp_runtime::{bounded_vec, traits::Bounded, StateVersion};
+	use frame_support::{
+		assert_noop, assert_ok,
+		dispatch::DispatchClass,
+		traits::{Get, Hooks},
+	};
+	use sp_runtime::{bounded_vec, traits::Bounded, Perbill, StateVersion};
 
 	#[test]
 	fn fails_if_no_migration() {
@@ -1534,6 +1654,51 @@ mod test {
 		);
 	}
 
+	#[test]
+	fn auto_migrate_throttles_with_block_utilization() {
+		new_test_ext(StateVersion::V0, true, None, None).execute_with(|| {
+			System::set_block_number(1);
+			let throttle = ThrottleLimits {
+				floor: MigrationLimits { item: 1, size: 10_000 },
+				ceiling: MigrationLimits { item: 11, size: 20_000 },
+			};
+			assert_eq!(throttle.limits_for(Perbill::zero()), throttle.ceiling);
+			assert_eq!(throttle.limits_for(Perbill::one()), throttle.floor);
+			assert_eq!(
+				throttle.limits_for(Perbill::from_percent(50)),
+				MigrationLimits { item: 6, size: 15_000 }
+			);
+
+			let inverted = ThrottleLimits { floor: throttle.ceiling, ceiling: throttle.floor };
+			assert_noop!(
+				StateTrieMigration::set_auto_throttle(RuntimeOrigin::root(), Some(inverted)),
+				Error::<Test>::BadThrottleLimits
+			);
+			assert_ok!(StateTrieMigration::set_auto_throttle(
+				RuntimeOrigin::root(),
+				Some(throttle)
+			));
+			AutoLimits::<Test>::put(Some(throttle.ceiling));
+
+			// Only non-mandatory dispatches count towards the utilization.
+			let block_weights: frame_system::limits::BlockWeights =
+				<Test as frame_system::Config>::BlockWeights::get();
+			let max_block = block_weights.max_block;
+			System::register_extra_weight_unchecked(max_block, DispatchClass::Mandatory);
+			System::register_extra_weight_unchecked(max_block.div(2), DispatchClass::Normal);
+			StateTrieMigration::on_finalize(1);
+			assert_eq!(LastBlockUtilization::<Test>::get(), Perbill::from_percent(50));
+
+			// A full block throttles the migration down to the floor.
+			LastBlockUtilization::<Test>::put(Perbill::one());
+			StateTrieMigration::on_initialize(2);
+			System::assert_last_event(
+				Event::<Test>::Migrated { top: 1, child: 0, compute: MigrationCompute::Auto }
+					.into(),
+			);
+		});
+	}
+
 	#[test]
 	fn signed_migrate_works() {
 		new_test_ext(StateVersion::V0, true, None, None).execute_with(|| {
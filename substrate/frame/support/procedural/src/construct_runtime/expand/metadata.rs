@@ -15,7 +15,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License
 
-use crate::construct_runtime::{parse::PalletPath, Pallet};
+use crate::construct_runtime::{
+	parse::{PalletPath, ReservedIndex},
+	Pallet,
+};
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Ident;
@@ -26,6 +29,7 @@ pub fn expand_runtime_metadata(
 	scrate: &TokenStream,
 	extrinsic: &TokenStream,
 	system_path: &PalletPath,
+	reserved_indices: &[ReservedIndex],
 ) -> TokenStream {
 	let pallets = pallet_declarations
 		.iter()
@@ -72,6 +76,15 @@ pub fn expand_runtime_metadata(
 		})
 		.collect::<Vec<_>>();
 
+	let reserved_indices = reserved_indices.iter().map(|ReservedIndex { name, index }| {
+		quote! {
+			#scrate::__private::metadata_ir::ReservedPalletIndexIR {
+				name: stringify!(#name),
+				index: #index,
+			}
+		}
+	});
+
 	quote! {
 		impl #runtime {
 			#[allow(deprecated)]
@@ -145,6 +158,7 @@ pub fn expand_runtime_metadata(
 						event_enum_ty: #scrate::__private::scale_info::meta_type::<RuntimeEvent>(),
 						error_enum_ty: #scrate::__private::scale_info::meta_type::<RuntimeError>(),
 					},
+					reserved_pallet_indices: #scrate::__private::vec![ #(#reserved_indices),* ],
 				}
 			}
 
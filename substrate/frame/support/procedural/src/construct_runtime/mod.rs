@@ -348,7 +348,13 @@ fn construct_runtime_explicit_to_explicit_expanded(
 fn construct_runtime_final_expansion(
 	definition: ExplicitRuntimeDeclaration,
 ) -> Result<TokenStream2> {
-	let ExplicitRuntimeDeclaration { name, pallets, pallets_token, where_section } = definition;
+	let ExplicitRuntimeDeclaration {
+		name,
+		pallets,
+		pallets_token,
+		where_section,
+		reserved_indices,
+	} = definition;
 
 	let system_pallet =
 		pallets.iter().find(|decl| decl.name == SYSTEM_PALLET_NAME).ok_or_else(|| {
@@ -407,6 +413,7 @@ fn construct_runtime_final_expansion(
 		&scrate,
 		&unchecked_extrinsic,
 		&system_pallet.path,
+		&reserved_indices,
 	);
 	let outer_config = expand::expand_outer_config(&name, &pallets, &scrate);
 	let inherent =
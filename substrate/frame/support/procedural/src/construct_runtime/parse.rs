@@ -49,6 +49,7 @@ mod keyword {
 	syn::custom_keyword!(exclude_parts);
 	syn::custom_keyword!(use_parts);
 	syn::custom_keyword!(expanded);
+	syn::custom_keyword!(reserved_indices);
 }
 
 /// Declaration of a runtime.
@@ -76,6 +77,7 @@ pub struct ExplicitRuntimeDeclaration {
 	pub where_section: Option<WhereSection>,
 	pub pallets: Vec<Pallet>,
 	pub pallets_token: token::Brace,
+	pub reserved_indices: Vec<ReservedIndex>,
 }
 
 impl Parse for RuntimeDeclaration {
@@ -94,8 +96,16 @@ impl Parse for RuntimeDeclaration {
 		let pallets =
 			input.parse::<ext::Braces<ext::Punctuated<PalletDeclaration, Token![,]>>>()?;
 		let pallets_token = pallets.token;
+		let reserved_indices = if input.peek(keyword::reserved_indices) {
+			let _: keyword::reserved_indices = input.parse()?;
+			let reserved =
+				input.parse::<ext::Braces<ext::Punctuated<ReservedIndex, Token![,]>>>()?;
+			check_reserved_indices(reserved.content.inner.into_iter().collect())?
+		} else {
+			Vec::new()
+		};
 
-		match convert_pallets(pallets.content.inner.into_iter().collect())? {
+		match convert_pallets(pallets.content.inner.into_iter().collect(), &reserved_indices)? {
 			PalletsConversion::Implicit(pallets) =>
 				Ok(RuntimeDeclaration::Implicit(ImplicitRuntimeDeclaration { pallets })),
 			PalletsConversion::Explicit(pallets) =>
@@ -104,6 +114,7 @@ impl Parse for RuntimeDeclaration {
 					where_section,
 					pallets,
 					pallets_token,
+					reserved_indices,
 				})),
 			PalletsConversion::ExplicitExpanded(pallets) =>
 				Ok(RuntimeDeclaration::ExplicitExpanded(ExplicitRuntimeDeclaration {
@@ -111,6 +122,7 @@ impl Parse for RuntimeDeclaration {
 					where_section,
 					pallets,
 					pallets_token,
+					reserved_indices,
 				})),
 		}
 	}
@@ -305,6 +317,47 @@ impl Parse for PalletDeclaration {
 	}
 }
 
+/// An index reserved for a pallet which must not be used by any other pallet, e.g.
+/// `RandomnessCollectiveFlip = 2` for a removed pallet.
+#[derive(Debug, Clone)]
+pub struct ReservedIndex {
+	/// The name of the pallet the index is reserved for.
+	pub name: Ident,
+	/// The reserved index.
+	pub index: u8,
+}
+
+impl Parse for ReservedIndex {
+	fn parse(input: ParseStream) -> Result<Self> {
+		let name = input.parse()?;
+		let _: Token![=] = input.parse()?;
+		let index = input.parse::<syn::LitInt>()?.base10_parse::<u8>()?;
+		Ok(Self { name, index })
+	}
+}
+
+/// Check that no index and no pallet name is reserved twice.
+fn check_reserved_indices(reserved_indices: Vec<ReservedIndex>) -> Result<Vec<ReservedIndex>> {
+	let mut indices = HashMap::new();
+	let mut names = HashSet::new();
+	for reserved in &reserved_indices {
+		if let Some(other) = indices.insert(reserved.index, reserved.name.clone()) {
+			let msg = format!(
+				"Reserved indices are conflicting: Both {} and {} reserve index {}",
+				other, reserved.name, reserved.index,
+			);
+			let mut err = syn::Error::new(other.span(), &msg);
+			err.combine(syn::Error::new(reserved.name.span(), msg));
+			return Err(err);
+		}
+		if !names.insert(reserved.name.clone()) {
+			let msg = format!("Index reserved twice for {}", reserved.name);
+			return Err(syn::Error::new(reserved.name.span(), msg));
+		}
+	}
+	Ok(reserved_indices)
+}
+
 /// A struct representing a path to a pallet. `PalletPath` is almost identical to the standard
 /// Rust path with a few restrictions:
 /// - No leading colons allowed
@@ -661,7 +714,12 @@ enum PalletsConversion {
 /// Check if all pallet have explicit declaration of their parts, if so then assign index to each
 /// pallet using same rules as rust for fieldless enum. I.e. implicit are assigned number
 /// incrementally from last explicit or 0.
-fn convert_pallets(pallets: Vec<PalletDeclaration>) -> syn::Result<PalletsConversion> {
+///
+/// Pallets must not use any of the `reserved_indices`.
+fn convert_pallets(
+	pallets: Vec<PalletDeclaration>,
+	reserved_indices: &[ReservedIndex],
+) -> syn::Result<PalletsConversion> {
 	if pallets.iter().any(|pallet| pallet.pallet_parts.is_none()) {
 		return Ok(PalletsConversion::Implicit(pallets));
 	}
@@ -684,6 +742,16 @@ fn convert_pallets(pallets: Vec<PalletDeclaration>) -> syn::Result<PalletsConver
 
 			last_index = Some(final_index);
 
+			if let Some(reserved) = reserved_indices.iter().find(|r| r.index == final_index) {
+				let msg = format!(
+					"Pallet index {} of pallet {} is reserved for {}",
+					final_index, pallet.name, reserved.name,
+				);
+				let mut err = syn::Error::new(pallet.name.span(), &msg);
+				err.combine(syn::Error::new(reserved.name.span(), msg));
+				return Err(err);
+			}
+
 			if let Some(used_pallet) = indices.insert(final_index, pallet.name.clone()) {
 				let msg = format!(
 					"Pallet indices are conflicting: Both pallets {} and {} are at index {}",
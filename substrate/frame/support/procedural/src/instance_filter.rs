@@ -0,0 +1,303 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Implementation of the `instance_filter` attribute macro.
+
+use frame_support_procedural_tools::generate_access_from_frame_or_crate;
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeMap;
+use syn::{spanned::Spanned, Error, Ident, ItemEnum, Path, Result};
+
+/// The calls let through by a single variant.
+enum Rule {
+	Any,
+	Nothing,
+	Allow(Vec<Path>),
+	Deny(Vec<Path>),
+	With(Path),
+}
+
+/// A variant together with its parsed `#[filter(..)]` attributes.
+struct Variant {
+	ident: Ident,
+	rule: Rule,
+	superset_of: Vec<Ident>,
+}
+
+/// Parse the `#[filter(..)]` attributes of a variant, removing them from the variant.
+fn parse_variant(variant: &mut syn::Variant) -> Result<Variant> {
+	if !matches!(variant.fields, syn::Fields::Unit) {
+		return Err(Error::new(variant.fields.span(), "Only unit variants are supported"))
+	}
+
+	let mut rule = None;
+	let mut superset_of = Vec::new();
+	let mut set_rule = |span: proc_macro2::Span, new: Rule| {
+		if rule.replace(new).is_some() {
+			return Err(Error::new(
+				span,
+				"Only one of `any`, `nothing`, `allow`, `deny` or `with` can be given",
+			))
+		}
+		Ok(())
+	};
+
+	let (filter_attrs, attrs) = variant
+		.attrs
+		.drain(..)
+		.partition::<Vec<_>, _>(|attr| attr.path().is_ident("filter"));
+	variant.attrs = attrs;
+	for attr in filter_attrs {
+		attr.parse_nested_meta(|meta| {
+			let span = meta.path.span();
+			if meta.path.is_ident("any") {
+				set_rule(span, Rule::Any)
+			} else if meta.path.is_ident("nothing") {
+				set_rule(span, Rule::Nothing)
+			} else if meta.path.is_ident("allow") || meta.path.is_ident("deny") {
+				let mut calls = Vec::new();
+				meta.parse_nested_meta(|call| {
+					if call.path.segments.len() > 2 {
+						return Err(call.error("Expected `Pallet` or `Pallet::call`"))
+					}
+					calls.push(call.path);
+					Ok(())
+				})?;
+				if meta.path.is_ident("allow") {
+					set_rule(span, Rule::Allow(calls))
+				} else {
+					set_rule(span, Rule::Deny(calls))
+				}
+			} else if meta.path.is_ident("with") {
+				set_rule(span, Rule::With(meta.value()?.parse()?))
+			} else if meta.path.is_ident("superset_of") {
+				meta.parse_nested_meta(|other| {
+					superset_of.push(other.path.require_ident()?.clone());
+					Ok(())
+				})
+			} else {
+				Err(meta.error(
+					"Expected one of `any`, `nothing`, `allow`, `deny`, `with` or `superset_of`",
+				))
+			}
+		})?;
+	}
+
+	let rule = rule.ok_or_else(|| {
+		Error::new(
+			variant.ident.span(),
+			"Missing `#[filter(..)]` declaring the calls this variant lets through",
+		)
+	})?;
+	Ok(Variant { ident: variant.ident.clone(), rule, superset_of })
+}
+
+/// The matched calls of every matched pallet, with `None` matching all calls of the pallet.
+fn group_by_pallet(calls: &[Path]) -> BTreeMap<String, (Ident, Option<Vec<Ident>>)> {
+	let mut pallets = BTreeMap::<String, (Ident, Option<Vec<Ident>>)>::new();
+	for call in calls {
+		let pallet = call.segments[0].ident.clone();
+		let entry = pallets.entry(pallet.to_string()).or_insert((pallet, Some(Vec::new())));
+		match (call.segments.get(1), &mut entry.1) {
+			(Some(name), Some(names)) => names.push(name.ident.clone()),
+			(Some(_), None) => (),
+			(None, names) => *names = None,
+		}
+	}
+	pallets
+}
+
+/// Expression evaluating to whether the call `c` is one of the `calls`.
+///
+/// The calls are matched by their variants, so unknown pallets and calls fail to compile.
+fn matches_calls(
+	calls: &[Path],
+	call_ty: &Path,
+	runtime: &Path,
+	frame_support: &Path,
+) -> TokenStream {
+	let arms = group_by_pallet(calls).into_values().map(|(pallet, names)| match names {
+		None => quote!(#call_ty::#pallet(..) => true,),
+		Some(names) => quote! {
+			#(#call_ty::#pallet(
+				#frame_support::dispatch::CallableCallFor::<#pallet, #runtime>::#names { .. }
+			))|* => true,
+		},
+	});
+	quote! {
+		match c {
+			#(#arms)*
+			_ => false,
+		}
+	}
+}
+
+/// The `CallMatcher`s of the `calls`.
+fn call_matchers(calls: &[Path], frame_support: &Path) -> TokenStream {
+	let matchers = calls.iter().map(|call| {
+		let pallet = call.segments[0].ident.to_string();
+		let call = match call.segments.get(1) {
+			Some(name) => {
+				let name = name.ident.to_string();
+				quote!(Some(#name.into()))
+			},
+			None => quote!(None),
+		};
+		quote! {
+			#frame_support::traits::CallMatcher { pallet: #pallet.into(), call: #call }
+		}
+	});
+	quote!(#frame_support::__private::vec![#(#matchers),*])
+}
+
+/// Human readable list of the `calls`, for the docs of a variant.
+fn describe_calls(calls: &[Path]) -> String {
+	calls
+		.iter()
+		.map(|call| {
+			let segments =
+				call.segments.iter().map(|s| s.ident.to_string()).collect::<Vec<_>>().join("::");
+			format!("`{}`", segments)
+		})
+		.collect::<Vec<_>>()
+		.join(", ")
+}
+
+/// The arguments of the attribute: the call type and the runtime.
+struct Args {
+	call_ty: Path,
+	runtime: Path,
+}
+
+impl syn::parse::Parse for Args {
+	fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+		let call_ty = input.parse()?;
+		input.parse::<syn::Token![,]>()?;
+		let runtime = input.parse()?;
+		let _ = input.parse::<Option<syn::Token![,]>>()?;
+		Ok(Self { call_ty, runtime })
+	}
+}
+
+pub fn instance_filter(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
+	let Args { call_ty, runtime } = syn::parse2(attr)?;
+	let mut item: ItemEnum = syn::parse2(item)?;
+	if !item.generics.params.is_empty() {
+		return Err(Error::new(item.generics.span(), "Generic enums are not supported"))
+	}
+	let frame_support = generate_access_from_frame_or_crate("frame-support")?;
+
+	let variants = item.variants.iter_mut().map(parse_variant).collect::<Result<Vec<_>>>()?;
+
+	// Document the filter of every variant, which makes it part of the metadata.
+	for (variant, parsed) in item.variants.iter_mut().zip(&variants) {
+		let description = match &parsed.rule {
+			Rule::Any => "Lets through every call.".to_string(),
+			Rule::Nothing => "Lets through no call.".to_string(),
+			Rule::Allow(calls) => format!("Lets through only: {}.", describe_calls(calls)),
+			Rule::Deny(calls) => format!("Lets through every call but: {}.", describe_calls(calls)),
+			Rule::With(_) => "Lets through calls according to custom logic.".to_string(),
+		};
+		let description = format!(" Proxy filter: {}", description);
+		if !variant.attrs.iter().any(|attr| attr.path().is_ident("doc")) {
+			variant.attrs.push(syn::parse_quote!(#[doc = #description]));
+		} else {
+			variant.attrs.push(syn::parse_quote!(#[doc = ""]));
+			variant.attrs.push(syn::parse_quote!(#[doc = #description]));
+		}
+	}
+
+	let name = &item.ident;
+	let filter_arms = variants.iter().map(|Variant { ident, rule, .. }| {
+		let body = match rule {
+			Rule::Any => quote!(true),
+			Rule::Nothing => quote!(false),
+			Rule::Allow(calls) => matches_calls(calls, &call_ty, &runtime, &frame_support),
+			Rule::Deny(calls) => {
+				let matches = matches_calls(calls, &call_ty, &runtime, &frame_support);
+				quote!(!(#matches))
+			},
+			Rule::With(filter) => quote!(#filter(c)),
+		};
+		quote!(Self::#ident => #body,)
+	});
+
+	let any_variants = variants
+		.iter()
+		.filter(|variant| matches!(variant.rule, Rule::Any))
+		.map(|variant| &variant.ident)
+		.collect::<Vec<_>>();
+	let any_arm =
+		(!any_variants.is_empty()).then(|| quote!((#(Self::#any_variants)|*, _) => true,));
+	let superset_arms = variants.iter().filter(|variant| !variant.superset_of.is_empty()).map(
+		|Variant { ident, superset_of, .. }| quote!((Self::#ident, #(Self::#superset_of)|*) => true,),
+	);
+
+	let entries = variants.iter().map(|Variant { ident, rule, superset_of }| {
+		let filter = match rule {
+			Rule::Any => quote!(#frame_support::traits::CallFilter::Any),
+			Rule::Nothing => quote!(#frame_support::traits::CallFilter::Nothing),
+			Rule::Allow(calls) => {
+				let matchers = call_matchers(calls, &frame_support);
+				quote!(#frame_support::traits::CallFilter::Allow(#matchers))
+			},
+			Rule::Deny(calls) => {
+				let matchers = call_matchers(calls, &frame_support);
+				quote!(#frame_support::traits::CallFilter::Deny(#matchers))
+			},
+			Rule::With(_) => quote!(#frame_support::traits::CallFilter::Custom),
+		};
+		quote! {
+			#frame_support::traits::InstanceFilterEntry {
+				instance: Self::#ident,
+				filter: #filter,
+				superset_of: #frame_support::__private::vec![#(Self::#superset_of),*],
+			}
+		}
+	});
+
+	Ok(quote! {
+		#item
+
+		impl #frame_support::traits::InstanceFilter<#call_ty> for #name {
+			// The catch-all arms are unreachable if all pallets are matched.
+			#[allow(unreachable_patterns)]
+			fn filter(&self, c: &#call_ty) -> bool {
+				match self {
+					#(#filter_arms)*
+				}
+			}
+
+			fn is_superset(&self, o: &Self) -> bool {
+				match (self, o) {
+					(x, y) if x == y => true,
+					#any_arm
+					#(#superset_arms)*
+					_ => false,
+				}
+			}
+		}
+
+		impl #frame_support::traits::InstanceFilterMatrix for #name {
+			fn filter_matrix(
+			) -> #frame_support::__private::Vec<#frame_support::traits::InstanceFilterEntry<Self>> {
+				#frame_support::__private::vec![#(#entries),*]
+			}
+		}
+	})
+}
@@ -27,6 +27,7 @@ mod deprecation;
 mod derive_impl;
 mod dummy_part_checker;
 mod dynamic_params;
+mod instance_filter;
 mod key_prefix;
 mod match_and_insert;
 mod no_bound;
@@ -178,6 +179,26 @@ fn counter_prefix(prefix: &str) -> String {
 ///   pallet4 .., // Here pallet4 is given index 1
 ///   ```
 ///
+/// # Reserved indices
+///
+/// Indices of removed pallets can be reserved in an optional `reserved_indices` section after the
+/// pallets, so that no other pallet can accidentally reuse them and misdecode their leftovers:
+///
+/// ```ignore
+/// construct_runtime!(
+///     pub enum Runtime {
+///         System: frame_system = 0,
+///         Balances: pallet_balances = 10,
+///     }
+///     reserved_indices {
+///         RandomnessCollectiveFlip = 2,
+///     }
+/// )
+/// ```
+///
+/// A pallet using a reserved index is a compile error. The reserved indices are exposed in the
+/// custom metadata under the `reserved_pallet_indices` key.
+///
 /// # Note
 ///
 /// The population of the genesis storage depends on the order of pallets. So, if one of your
@@ -476,6 +497,17 @@ pub fn storage_alias(attributes: TokenStream, input: TokenStream) -> TokenStream
 		.into()
 }
 
+///
+/// ---
+///
+/// Documentation for this macro can be found at `frame_support::instance_filter`.
+#[proc_macro_attribute]
+pub fn instance_filter(attributes: TokenStream, input: TokenStream) -> TokenStream {
+	instance_filter::instance_filter(attributes.into(), input.into())
+		.unwrap_or_else(|r| r.into_compile_error())
+		.into()
+}
+
 /// This attribute can be used to derive a full implementation of a trait based on a local partial
 /// impl and an external impl containing defaults that can be overridden in the local impl.
 ///
@@ -21,8 +21,9 @@
 
 extern crate alloc;
 
-use alloc::{format, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
 use frame_support::traits::BuildGenesisConfig;
+use serde_json::Value;
 use sp_genesis_builder::{PresetId, Result as BuildResult};
 
 /// Build `GenesisConfig` from a JSON blob not using any defaults and store it in the storage. For
@@ -54,3 +55,109 @@ where
 		preset_for_name,
 	)
 }
+
+/// Get the named preset `name` with the JSON blob `patch` merged over it.
+///
+/// The patched preset is checked to deserialize into a `GenesisConfig` when merged over the
+/// default one. Query of named presets is delegated to provided `preset_for_name` closure. For more
+/// info refer to [`sp_genesis_builder::GenesisBuilder::get_preset_with_patch`].
+pub fn get_preset_with_patch<GC>(
+	name: &PresetId,
+	patch: Vec<u8>,
+	preset_for_name: impl FnOnce(&PresetId) -> Option<Vec<u8>>,
+) -> Result<Vec<u8>, String>
+where
+	GC: BuildGenesisConfig + Default,
+{
+	let preset = preset_for_name(name).ok_or_else(|| format!("Unknown preset: {}", name))?;
+	let mut preset = serde_json::from_slice::<Value>(&preset)
+		.map_err(|e| format!("Invalid preset `{}`: {}", name, e))?;
+	let patch = serde_json::from_slice::<Value>(&patch)
+		.map_err(|e| format!("Invalid JSON patch: {}", e))?;
+	merge_json(&mut preset, patch);
+
+	let mut config = serde_json::to_value(&GC::default())
+		.expect("serialization to json is expected to work. qed.");
+	merge_json(&mut config, preset.clone());
+	serde_json::from_value::<GC>(config).map_err(|e| format!("Invalid patched preset: {}", e))?;
+
+	Ok(serde_json::to_string(&preset)
+		.expect("serialization to json is expected to work. qed.")
+		.into_bytes())
+}
+
+/// Recursively merge the JSON object `patch` into `base`.
+///
+/// Values of `patch` override the values of `base` with the same key, objects are merged key by
+/// key.
+fn merge_json(base: &mut Value, patch: Value) {
+	match (base, patch) {
+		(Value::Object(base), Value::Object(patch)) =>
+			for (key, value) in patch {
+				merge_json(base.entry(key).or_insert(Value::Null), value);
+			},
+		(base, patch) => *base = patch,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde::{Deserialize, Serialize};
+	use serde_json::json;
+
+	#[derive(Default, Serialize, Deserialize)]
+	#[serde(rename_all = "camelCase", deny_unknown_fields)]
+	struct Config {
+		endowed_accounts: Vec<u64>,
+		collators: Vec<u64>,
+		para_id: u32,
+	}
+
+	impl BuildGenesisConfig for Config {
+		fn build(&self) {}
+	}
+
+	fn preset_for_name(name: &PresetId) -> Option<Vec<u8>> {
+		(name == "dev")
+			.then(|| json!({ "collators": [1], "paraId": 1000 }).to_string().into_bytes())
+	}
+
+	#[test]
+	fn get_preset_with_patch_works() {
+		let patched = get_preset_with_patch::<Config>(
+			&"dev".into(),
+			json!({ "endowedAccounts": [1, 2], "paraId": 2000 }).to_string().into_bytes(),
+			preset_for_name,
+		)
+		.unwrap();
+		assert_eq!(
+			serde_json::from_slice::<Value>(&patched).unwrap(),
+			json!({ "collators": [1], "endowedAccounts": [1, 2], "paraId": 2000 })
+		);
+	}
+
+	#[test]
+	fn get_preset_with_patch_rejects_invalid_patches() {
+		let patch = |patch: Value| {
+			get_preset_with_patch::<Config>(
+				&"dev".into(),
+				patch.to_string().into_bytes(),
+				preset_for_name,
+			)
+		};
+		assert!(patch(json!({ "unknown": 1 }))
+			.unwrap_err()
+			.starts_with("Invalid patched preset"));
+		assert!(patch(json!({ "paraId": "x" }))
+			.unwrap_err()
+			.starts_with("Invalid patched preset"));
+		assert_eq!(
+			get_preset_with_patch::<Config>(&"local".into(), b"{}".to_vec(), preset_for_name),
+			Err("Unknown preset: local".into())
+		);
+		assert!(get_preset_with_patch::<Config>(&"dev".into(), b"{".to_vec(), preset_for_name)
+			.unwrap_err()
+			.starts_with("Invalid JSON patch"));
+	}
+}
@@ -533,6 +533,43 @@ pub use frame_support_procedural::{
 
 pub use frame_support_procedural::runtime;
 
+/// Implement [`InstanceFilter`](traits::InstanceFilter) and
+/// [`InstanceFilterMatrix`](traits::InstanceFilterMatrix) for a proxy type enum from declarative
+/// `#[filter(..)]` attributes on its variants.
+///
+/// The attribute takes the call type being filtered and the runtime. Every variant must be a unit
+/// variant and declare exactly one of:
+///
+/// - `any`: lets through every call;
+/// - `nothing`: lets through no call;
+/// - `allow(Pallet, Pallet::call, ..)`: lets through only the listed pallets and calls;
+/// - `deny(Pallet, Pallet::call, ..)`: lets through every call but the listed pallets and calls;
+/// - `with = path::to::function`: lets through the calls for which `fn(&Call) -> bool` returns
+///   `true`. The matrix only records such a filter as [`CallFilter::Custom`](traits::CallFilter).
+///
+/// A variant can additionally declare `superset_of(OtherVariant, ..)`. Every variant is a superset
+/// of itself and `any` variants are supersets of all variants.
+///
+/// Pallet names are the names given in `construct_runtime`, call names are the names of the
+/// dispatchables. Calls are matched by their variants, so misspelled names fail to compile. A
+/// description of the filter is appended to the docs of every variant, so it is also part of the
+/// metadata.
+///
+/// ```ignore
+/// #[frame_support::instance_filter(RuntimeCall, Runtime)]
+/// #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
+/// #[derive(MaxEncodedLen, TypeInfo)]
+/// pub enum ProxyType {
+/// 	#[filter(any)]
+/// 	Any,
+/// 	#[filter(deny(Balances), superset_of(CancelProxy))]
+/// 	NonTransfer,
+/// 	#[filter(allow(Proxy::reject_announcement, Utility))]
+/// 	CancelProxy,
+/// }
+/// ```
+pub use frame_support_procedural::instance_filter;
+
 #[doc(hidden)]
 pub use frame_support_procedural::{__create_tt_macro, __generate_dummy_part_checker};
 
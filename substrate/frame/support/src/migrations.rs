@@ -504,6 +504,16 @@ pub trait SteppedMigration {
 		None
 	}
 
+	/// An estimate of the total number of steps that this migration takes.
+	///
+	/// Only used to report the progress of the migration. It may read storage, for example to
+	/// count the entries left to migrate, as long as it consumes the weight of doing so from
+	/// `meter`. `None` means that no estimate is available, including when `meter` does not
+	/// have enough weight left.
+	fn estimated_steps(_meter: &mut WeightMeter) -> Option<u32> {
+		None
+	}
+
 	/// Try to migrate as much as possible with the given weight.
 	///
 	/// **ANY STORAGE CHANGES MUST BE ROLLED-BACK BY THE CALLER UPON ERROR.** This is necessary
@@ -573,6 +583,41 @@ pub enum SteppedMigrationError {
 	Failed,
 }
 
+/// Count the items of `items`, consuming `weight_per_item` from `meter` for each of them.
+///
+/// Returns `None` if `meter` runs out of weight. Meant to implement
+/// [`SteppedMigration::estimated_steps`], e.g. with the weight of reading a storage key.
+pub fn count_metered(
+	mut items: impl Iterator,
+	weight_per_item: Weight,
+	meter: &mut WeightMeter,
+) -> Option<u32> {
+	let mut count = 0u32;
+	loop {
+		meter.try_consume(weight_per_item).ok()?;
+		if items.next().is_none() {
+			return Some(count)
+		}
+		count = count.saturating_add(1);
+	}
+}
+
+/// The number of steps that a [`SteppedMigration`] needs to consume `weight`, if every step gets
+/// `weight_per_step`.
+///
+/// At least one step is needed to complete a migration. Returns `None` if `weight` does not fit
+/// into any number of steps.
+pub fn steps_for_weight(weight: Weight, weight_per_step: Weight) -> Option<u32> {
+	let div_ceil = |total: u64, per_step: u64| match (total, per_step) {
+		(0, _) => Some(0),
+		(_, 0) => None,
+		(total, per_step) => Some(total.div_ceil(per_step)),
+	};
+	let steps = div_ceil(weight.ref_time(), weight_per_step.ref_time())?
+		.max(div_ceil(weight.proof_size(), weight_per_step.proof_size())?);
+	Some(u32::try_from(steps).unwrap_or(u32::MAX).max(1))
+}
+
 /// A generic migration identifier that can be used by MBMs.
 ///
 /// It is not required that migrations use this identifier type, but it can help.
@@ -701,6 +746,13 @@ pub trait SteppedMigrations {
 	/// Is guaranteed to return `Some` if `n < Self::len()`.
 	fn nth_max_steps(n: u32) -> Option<Option<u32>>;
 
+	/// The [`SteppedMigration::estimated_steps`] of the `n`th migration.
+	///
+	/// Is guaranteed to return `Some` if `n < Self::len()`.
+	fn nth_estimated_steps(n: u32, _meter: &mut WeightMeter) -> Option<Option<u32>> {
+		Self::nth_id(n).map(|_| None)
+	}
+
 	/// Do a [`SteppedMigration::step`] on the `n`th migration.
 	///
 	/// Is guaranteed to return `Some` if `n < Self::len()`.
@@ -836,6 +888,12 @@ impl<T: SteppedMigration> SteppedMigrations for T {
 			.defensive_proof("nth_max_steps should only be called with n==0")
 	}
 
+	fn nth_estimated_steps(n: u32, meter: &mut WeightMeter) -> Option<Option<u32>> {
+		n.is_zero()
+			.then(|| T::estimated_steps(meter))
+			.defensive_proof("nth_estimated_steps should only be called with n==0")
+	}
+
 	fn nth_step(
 		n: u32,
 		cursor: Option<Vec<u8>>,
@@ -1006,6 +1064,20 @@ impl SteppedMigrations for Tuple {
 		None
 	}
 
+	fn nth_estimated_steps(n: u32, meter: &mut WeightMeter) -> Option<Option<u32>> {
+		let mut i = 0;
+
+		for_tuples!( #(
+			if (i + Tuple::len()) > n {
+				return Tuple::nth_estimated_steps(n - i, meter)
+			}
+
+			i += Tuple::len();
+		)* );
+
+		None
+	}
+
 	fn cursor_max_encoded_len() -> usize {
 		let mut max_len = 0;
 
@@ -1204,4 +1276,21 @@ mod tests {
 			.is_err());
 		});
 	}
+
+	#[test]
+	fn estimate_helpers_work() {
+		let item = Weight::from_parts(10, 1);
+		let mut meter = WeightMeter::with_limit(Weight::from_parts(40, 4));
+		assert_eq!(count_metered(0..3, item, &mut meter), Some(3));
+		assert!(meter.remaining().is_zero());
+		let mut meter = WeightMeter::with_limit(Weight::from_parts(30, 3));
+		assert_eq!(count_metered(0..3, item, &mut meter), None);
+
+		let step = Weight::from_parts(100, 10);
+		assert_eq!(steps_for_weight(Weight::zero(), step), Some(1));
+		assert_eq!(steps_for_weight(Weight::from_parts(100, 1), step), Some(1));
+		assert_eq!(steps_for_weight(Weight::from_parts(101, 1), step), Some(2));
+		assert_eq!(steps_for_weight(Weight::from_parts(1, 31), step), Some(4));
+		assert_eq!(steps_for_weight(Weight::from_parts(1, 1), Weight::from_parts(100, 0)), None);
+	}
 }
@@ -52,7 +52,10 @@ mod error;
 pub use error::PalletError;
 
 mod filter;
-pub use filter::{ClearFilterGuard, FilterStack, FilterStackGuard, InstanceFilter};
+pub use filter::{
+	CallFilter, CallMatcher, ClearFilterGuard, FilterStack, FilterStackGuard, InstanceFilter,
+	InstanceFilterEntry, InstanceFilterMatrix, PalletCalls,
+};
 
 mod misc;
 pub use misc::{
@@ -99,7 +102,7 @@ pub use storage::MaybeConsideration;
 pub use storage::{
 	Consideration, ConstantStoragePrice, Disabled, Footprint, Incrementable, Instance,
 	LinearStoragePrice, PartialStorageInfoTrait, StorageInfo, StorageInfoTrait, StorageInstance,
-	TrackedStorageKey, WhitelistedStorageKeys,
+	TieredStoragePrice, TrackedStorageKey, WhitelistedStorageKeys,
 };
 
 mod dispatch;
@@ -18,7 +18,13 @@
 //! Traits and associated utilities for dealing with abstract constraint filters.
 
 pub use super::members::Contains;
+use super::GetCallMetadata;
+use alloc::{format, string::String, vec::Vec};
+use codec::{Decode, Encode};
 use core::marker::PhantomData;
+use scale_info::TypeInfo;
+use sp_metadata_ir::MetadataIR;
+use sp_runtime::RuntimeDebug;
 
 /// Trait to add a constraint onto the filter.
 pub trait FilterStack<T>: Contains<T> {
@@ -96,6 +102,141 @@ impl<T> InstanceFilter<T> for () {
 	}
 }
 
+/// Matches all calls of a pallet, or a single call of it.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CallMatcher {
+	/// The name of the pallet in the runtime.
+	pub pallet: String,
+	/// The name of the call, or `None` to match all calls of the pallet.
+	pub call: Option<String>,
+}
+
+/// Declarative description of the calls an instance of an [`InstanceFilter`] lets through.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum CallFilter {
+	/// Every call is allowed.
+	Any,
+	/// No call is allowed.
+	Nothing,
+	/// Only the matched calls are allowed.
+	Allow(Vec<CallMatcher>),
+	/// Every call but the matched ones is allowed.
+	Deny(Vec<CallMatcher>),
+	/// The calls are allowed by custom logic which cannot be described declaratively.
+	Custom,
+}
+
+/// The filter of a single instance of an [`InstanceFilter`], as described by
+/// [`InstanceFilterMatrix`].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct InstanceFilterEntry<Instance> {
+	/// The instance.
+	pub instance: Instance,
+	/// The calls the instance lets through.
+	pub filter: CallFilter,
+	/// The other instances this instance is declared a superset of.
+	pub superset_of: Vec<Instance>,
+}
+
+/// An [`InstanceFilter`] whose instances can describe the calls they let through.
+///
+/// Usually implemented with [`instance_filter`](crate::instance_filter).
+pub trait InstanceFilterMatrix: Sized {
+	/// The filters of all instances.
+	fn filter_matrix() -> Vec<InstanceFilterEntry<Self>>;
+
+	/// Ensure that every pallet and call named in the [`Self::filter_matrix`] exists in `Call`.
+	///
+	/// Meant to be called from tests of hand-written implementations, to catch calls which were
+	/// renamed or removed. Implementations generated by [`instance_filter`](crate::instance_filter)
+	/// already fail to compile in that case.
+	fn ensure_matrix_matches<Call: GetCallMetadata>() -> Result<(), String> {
+		let matchers = Self::filter_matrix().into_iter().flat_map(|entry| match entry.filter {
+			CallFilter::Allow(matchers) | CallFilter::Deny(matchers) => matchers,
+			CallFilter::Any | CallFilter::Nothing | CallFilter::Custom => Vec::new(),
+		});
+		for CallMatcher { pallet, call } in matchers {
+			if !Call::get_module_names().contains(&pallet.as_str()) {
+				return Err(format!("Unknown pallet `{}`", pallet));
+			}
+			if let Some(call) = call {
+				if !Call::get_call_names(&pallet).contains(&call.as_str()) {
+					return Err(format!("Unknown call `{}::{}`", pallet, call));
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// The names and indices of the calls of a pallet.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PalletCalls {
+	/// The name of the pallet in the runtime.
+	pub name: &'static str,
+	/// The index of the pallet in the runtime.
+	pub index: u8,
+	/// The name and index of every call of the pallet.
+	pub calls: Vec<(&'static str, u8)>,
+}
+
+impl PalletCalls {
+	/// The calls of all pallets described by `metadata` which have calls.
+	pub fn from_metadata(metadata: &MetadataIR) -> Vec<Self> {
+		metadata
+			.pallets
+			.iter()
+			.filter_map(|pallet| {
+				let scale_info::TypeDef::Variant(def) =
+					pallet.calls.as_ref()?.ty.type_info().type_def
+				else {
+					return None
+				};
+				let calls = def.variants.iter().map(|call| (call.name, call.index)).collect();
+				Some(Self { name: pallet.name, index: pallet.index, calls })
+			})
+			.collect()
+	}
+}
+
+impl CallFilter {
+	/// The calls out of `pallets` that this filter lets through, as pallet index and call
+	/// indices. Pallets with no allowed call are omitted.
+	///
+	/// Returns `None` for [`CallFilter::Custom`], whose calls cannot be determined statically.
+	pub fn call_indices(&self, pallets: &[PalletCalls]) -> Option<Vec<(u8, Vec<u8>)>> {
+		let matches = |matchers: &[CallMatcher], pallet: &PalletCalls, call: &str| {
+			matchers.iter().any(|matcher| {
+				matcher.pallet == pallet.name && matcher.call.as_ref().map_or(true, |c| c == call)
+			})
+		};
+		let allowed = |pallet: &PalletCalls, call: &str| match self {
+			CallFilter::Any => true,
+			CallFilter::Nothing | CallFilter::Custom => false,
+			CallFilter::Allow(matchers) => matches(matchers, pallet, call),
+			CallFilter::Deny(matchers) => !matches(matchers, pallet, call),
+		};
+
+		if *self == CallFilter::Custom {
+			return None
+		}
+		Some(
+			pallets
+				.iter()
+				.filter_map(|pallet| {
+					let calls = pallet
+						.calls
+						.iter()
+						.filter(|(name, _)| allowed(pallet, name))
+						.map(|(_, index)| *index)
+						.collect::<Vec<_>>();
+					(!calls.is_empty()).then_some((pallet.index, calls))
+				})
+				.collect(),
+		)
+	}
+}
+
 #[macro_export]
 macro_rules! impl_filter_stack {
 	($target:ty, $base:ty, $call:ty, $module:ident) => {
@@ -501,6 +501,38 @@ pub mod v3 {
 		/// Will return an `Unavailable` error if the `id` is invalid.
 		fn next_dispatch_time(id: TaskName) -> Result<BlockNumber, DispatchError>;
 	}
+
+	/// A scheduler which cannot schedule any task.
+	impl<BlockNumber, Call, Origin> Named<BlockNumber, Call, Origin> for () {
+		type Address = ();
+		type Hasher = sp_runtime::traits::BlakeTwo256;
+
+		fn schedule_named(
+			_id: TaskName,
+			_when: DispatchTime<BlockNumber>,
+			_maybe_periodic: Option<Period<BlockNumber>>,
+			_priority: Priority,
+			_origin: Origin,
+			_call: Bounded<Call, Self::Hasher>,
+		) -> Result<Self::Address, DispatchError> {
+			Err(DispatchError::Unavailable)
+		}
+
+		fn cancel_named(_id: TaskName) -> Result<(), DispatchError> {
+			Err(DispatchError::Unavailable)
+		}
+
+		fn reschedule_named(
+			_id: TaskName,
+			_when: DispatchTime<BlockNumber>,
+		) -> Result<Self::Address, DispatchError> {
+			Err(DispatchError::Unavailable)
+		}
+
+		fn next_dispatch_time(_id: TaskName) -> Result<BlockNumber, DispatchError> {
+			Err(DispatchError::Unavailable)
+		}
+	}
 }
 
 #[allow(deprecated)]
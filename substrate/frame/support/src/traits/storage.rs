@@ -201,6 +201,35 @@ where
 	}
 }
 
+/// A storage price that increases linearly with the number of elements and their size, with a
+/// steeper slope for the bytes above a threshold.
+///
+/// The first `Threshold` bytes of the footprint are priced at `Slope` per byte and every byte
+/// above at `SteepSlope`, so that large items pay for their disproportionate impact on the proof
+/// size. With a `SteepSlope` equal to `Slope` this is the same as [`LinearStoragePrice`].
+pub struct TieredStoragePrice<Base, Slope, Threshold, SteepSlope, Balance>(
+	PhantomData<(Base, Slope, Threshold, SteepSlope, Balance)>,
+);
+impl<Base, Slope, Threshold, SteepSlope, Balance> Convert<Footprint, Balance>
+	for TieredStoragePrice<Base, Slope, Threshold, SteepSlope, Balance>
+where
+	Base: Get<Balance>,
+	Slope: Get<Balance>,
+	Threshold: Get<u64>,
+	SteepSlope: Get<Balance>,
+	Balance: From<u64> + sp_runtime::Saturating,
+{
+	fn convert(a: Footprint) -> Balance {
+		let total = a.count.saturating_mul(a.size);
+		let below = total.min(Threshold::get());
+		let above = total.saturating_sub(below);
+		Balance::from(below)
+			.saturating_mul(Slope::get())
+			.saturating_add(Balance::from(above).saturating_mul(SteepSlope::get()))
+			.saturating_add(Base::get())
+	}
+}
+
 /// Constant `Price` regardless of the given [`Footprint`].
 pub struct ConstantStoragePrice<Price, Balance>(PhantomData<(Price, Balance)>);
 impl<Price, Balance> Convert<Footprint, Balance> for ConstantStoragePrice<Price, Balance>
@@ -253,6 +282,10 @@ pub trait Consideration<AccountId, Footprint>:
 	/// Optionally consume an old ticket and alter the footprint, enforcing the new cost to `who`
 	/// and returning the new ticket (or an error if there was an issue).
 	///
+	/// The implementations in `frame_support` only settle the difference in cost: if the `new`
+	/// footprint is smaller, the excess is returned to `who`; if it is larger, only the extra cost
+	/// is exacted. Prefer this over dropping the old ticket and creating a new one.
+	///
 	/// For creating tickets and dropping them, you can use the simpler `new` and `drop` instead.
 	fn update(self, who: &AccountId, new: Footprint) -> Result<Self, DispatchError>;
 
@@ -372,6 +405,23 @@ mod tests {
 		assert_eq!(p(u64::MAX, u64::MAX), u64::MAX);
 	}
 
+	#[test]
+	fn tiered_storage_price_works() {
+		type Tiered = TieredStoragePrice<ConstU64<7>, ConstU64<3>, ConstU64<10>, ConstU64<5>, u64>;
+		let p = |count, size| Tiered::convert(Footprint { count, size });
+
+		assert_eq!(p(0, 0), 7);
+		assert_eq!(p(1, 1), 10);
+		assert_eq!(p(1, 10), 37);
+		assert_eq!(p(2, 5), 37);
+
+		assert_eq!(p(1, 11), 42);
+		assert_eq!(p(1, 20), 87);
+		assert_eq!(p(4, 5), 87);
+
+		assert_eq!(p(u64::MAX, u64::MAX), u64::MAX);
+	}
+
 	#[test]
 	fn footprint_from_mel_works() {
 		let footprint = Footprint::from_mel::<(u8, BoundedVec<u8, ConstU32<9>>)>();
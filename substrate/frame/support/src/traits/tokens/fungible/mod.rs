@@ -185,7 +185,7 @@ pub use regular::{
 use sp_arithmetic::traits::Zero;
 use sp_core::Get;
 use sp_runtime::{traits::Convert, DispatchError};
-pub use union_of::{NativeFromLeft, NativeOrWithId, UnionOf};
+pub use union_of::{NativeFromLeft, NativeOrWithId, UnionOf, UnionOf3, UnionOf4, UnionOf5};
 
 #[cfg(feature = "experimental")]
 use crate::traits::MaybeConsideration;
@@ -989,3 +989,75 @@ impl<
 		}
 	}
 }
+
+/// Type to combine a `fungible::*` and two `fungibles::*` implementations into one union
+/// `fungibles::*` implementation.
+///
+/// Unlike nesting [`UnionOf`] and [`fungibles::UnionOf`] by hand, a single `Criterion`
+/// classifies the asset into one of the sets. The resulting type implements the same traits as
+/// [`UnionOf`].
+///
+/// ### Parameters:
+/// - `Native` is the `fungible::*` implementation incorporated into the union.
+/// - `B` and `C` are the `fungibles::*` implementations incorporated into the union.
+/// - `Criterion` converts the `AssetKind` into the [`fungibles::OneOf3`] choice of the set it
+///   belongs to, with `()` for the `Native` set.
+/// - `AssetKind` is a superset type encompassing asset kinds from all sets.
+/// - `AccountId` is an account identifier type.
+pub type UnionOf3<Native, B, C, Criterion, AssetKind, AccountId> = UnionOf<
+	Native,
+	fungibles::union_of::InnerUnionOf2<B, C, AccountId>,
+	fungibles::SplitFirstBy<
+		Criterion,
+		fungibles::OneOf3<
+			(),
+			fungibles::union_of::IdOf<B, AccountId>,
+			fungibles::union_of::IdOf<C, AccountId>,
+		>,
+	>,
+	AssetKind,
+	AccountId,
+>;
+
+/// Type to combine a `fungible::*` and three `fungibles::*` implementations into one union
+/// `fungibles::*` implementation.
+///
+/// Same as [`UnionOf3`], with `Criterion` converting the `AssetKind` into a
+/// [`fungibles::OneOf4`].
+pub type UnionOf4<Native, B, C, D, Criterion, AssetKind, AccountId> = UnionOf<
+	Native,
+	fungibles::union_of::InnerUnionOf3<B, C, D, AccountId>,
+	fungibles::SplitFirstBy<
+		Criterion,
+		fungibles::OneOf4<
+			(),
+			fungibles::union_of::IdOf<B, AccountId>,
+			fungibles::union_of::IdOf<C, AccountId>,
+			fungibles::union_of::IdOf<D, AccountId>,
+		>,
+	>,
+	AssetKind,
+	AccountId,
+>;
+
+/// Type to combine a `fungible::*` and four `fungibles::*` implementations into one union
+/// `fungibles::*` implementation.
+///
+/// Same as [`UnionOf3`], with `Criterion` converting the `AssetKind` into a
+/// [`fungibles::OneOf5`].
+pub type UnionOf5<Native, B, C, D, E, Criterion, AssetKind, AccountId> = UnionOf<
+	Native,
+	fungibles::union_of::InnerUnionOf4<B, C, D, E, AccountId>,
+	fungibles::SplitFirstBy<
+		Criterion,
+		fungibles::OneOf5<
+			(),
+			fungibles::union_of::IdOf<B, AccountId>,
+			fungibles::union_of::IdOf<C, AccountId>,
+			fungibles::union_of::IdOf<D, AccountId>,
+			fungibles::union_of::IdOf<E, AccountId>,
+		>,
+	>,
+	AssetKind,
+	AccountId,
+>;
@@ -35,7 +35,7 @@ mod lifetime;
 pub mod metadata;
 mod regular;
 pub mod roles;
-mod union_of;
+pub(crate) mod union_of;
 
 pub use enumerable::Inspect as InspectEnumerable;
 pub use freeze::{Inspect as InspectFreeze, Mutate as MutateFreeze};
@@ -48,4 +48,7 @@ pub use lifetime::{Create, Destroy, Refund};
 pub use regular::{
 	Balanced, DecreaseIssuance, Dust, IncreaseIssuance, Inspect, Mutate, Unbalanced,
 };
-pub use union_of::UnionOf;
+pub use union_of::{
+	OneOf2, OneOf3, OneOf4, OneOf5, SplitChoice, SplitFirst, SplitFirstBy, UnionOf, UnionOf3,
+	UnionOf4, UnionOf5,
+};
@@ -15,10 +15,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Type to combine two `fungibles::*` implementations into one union `fungibles::*` implementation.
+//! Types to combine two or more `fungibles::*` implementations into one union `fungibles::*`
+//! implementation.
 //!
 //! See the [`crate::traits::fungibles`] doc for more information about fungibles traits.
 
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
 use frame_support::traits::{
 	tokens::{
 		fungibles, fungibles::imbalance, AssetId, DepositConsequence, Fortitude, Precision,
@@ -26,10 +29,12 @@ use frame_support::traits::{
 	},
 	AccountTouch,
 };
+use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::Convert,
 	DispatchError, DispatchResult, Either,
 	Either::{Left, Right},
+	RuntimeDebug,
 };
 
 /// Type to combine two `fungibles::*` implementations into one union `fungibles::*` implementation.
@@ -976,3 +981,261 @@ impl<
 		}
 	}
 }
+
+/// The asset id of a `fungibles::*` implementation.
+pub(crate) type IdOf<T, AccountId> = <T as fungibles::Inspect<AccountId>>::AssetId;
+
+/// Splits the first set off the choice of an N-ary union.
+///
+/// N-ary unions, like [`UnionOf3`], are nested [`UnionOf`]s. Each level separates the first set
+/// from the union of the remaining sets.
+pub trait SplitFirst {
+	/// The asset id within the first set.
+	type First;
+	/// The choice among the remaining sets.
+	type Rest;
+
+	/// Split the choice into the first set or the remaining sets.
+	fn split(self) -> Either<Self::First, Self::Rest>;
+}
+
+/// The set of a union of two sets that an asset belongs to, together with its id in that set.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub enum OneOf2<A, B> {
+	/// The asset belongs to the first set.
+	First(A),
+	/// The asset belongs to the second set.
+	Second(B),
+}
+
+impl<A, B> SplitFirst for OneOf2<A, B> {
+	type First = A;
+	type Rest = B;
+
+	fn split(self) -> Either<A, B> {
+		match self {
+			Self::First(a) => Left(a),
+			Self::Second(b) => Right(b),
+		}
+	}
+}
+
+/// The set of a union of three sets that an asset belongs to, together with its id in that set.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub enum OneOf3<A, B, C> {
+	/// The asset belongs to the first set.
+	First(A),
+	/// The asset belongs to the second set.
+	Second(B),
+	/// The asset belongs to the third set.
+	Third(C),
+}
+
+impl<A, B, C> SplitFirst for OneOf3<A, B, C> {
+	type First = A;
+	type Rest = OneOf2<B, C>;
+
+	fn split(self) -> Either<A, OneOf2<B, C>> {
+		match self {
+			Self::First(a) => Left(a),
+			Self::Second(b) => Right(OneOf2::First(b)),
+			Self::Third(c) => Right(OneOf2::Second(c)),
+		}
+	}
+}
+
+/// The set of a union of four sets that an asset belongs to, together with its id in that set.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub enum OneOf4<A, B, C, D> {
+	/// The asset belongs to the first set.
+	First(A),
+	/// The asset belongs to the second set.
+	Second(B),
+	/// The asset belongs to the third set.
+	Third(C),
+	/// The asset belongs to the fourth set.
+	Fourth(D),
+}
+
+impl<A, B, C, D> SplitFirst for OneOf4<A, B, C, D> {
+	type First = A;
+	type Rest = OneOf3<B, C, D>;
+
+	fn split(self) -> Either<A, OneOf3<B, C, D>> {
+		match self {
+			Self::First(a) => Left(a),
+			Self::Second(b) => Right(OneOf3::First(b)),
+			Self::Third(c) => Right(OneOf3::Second(c)),
+			Self::Fourth(d) => Right(OneOf3::Third(d)),
+		}
+	}
+}
+
+/// The set of a union of five sets that an asset belongs to, together with its id in that set.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub enum OneOf5<A, B, C, D, E> {
+	/// The asset belongs to the first set.
+	First(A),
+	/// The asset belongs to the second set.
+	Second(B),
+	/// The asset belongs to the third set.
+	Third(C),
+	/// The asset belongs to the fourth set.
+	Fourth(D),
+	/// The asset belongs to the fifth set.
+	Fifth(E),
+}
+
+impl<A, B, C, D, E> SplitFirst for OneOf5<A, B, C, D, E> {
+	type First = A;
+	type Rest = OneOf4<B, C, D, E>;
+
+	fn split(self) -> Either<A, OneOf4<B, C, D, E>> {
+		match self {
+			Self::First(a) => Left(a),
+			Self::Second(b) => Right(OneOf4::First(b)),
+			Self::Third(c) => Right(OneOf4::Second(c)),
+			Self::Fourth(d) => Right(OneOf4::Third(d)),
+			Self::Fifth(e) => Right(OneOf4::Fourth(e)),
+		}
+	}
+}
+
+/// Criterion for the outermost [`UnionOf`] of an N-ary union.
+///
+/// Classifies the asset with the single `Criterion` of the union and splits the first set off the
+/// resulting `Choice`.
+pub struct SplitFirstBy<Criterion, Choice>(PhantomData<(Criterion, Choice)>);
+impl<AssetKind, Choice: SplitFirst, Criterion: Convert<AssetKind, Choice>>
+	Convert<AssetKind, Either<Choice::First, Choice::Rest>> for SplitFirstBy<Criterion, Choice>
+{
+	fn convert(asset: AssetKind) -> Either<Choice::First, Choice::Rest> {
+		Criterion::convert(asset).split()
+	}
+}
+
+/// Criterion for the inner [`UnionOf`]s of an N-ary union, which are keyed by the choice among
+/// their sets.
+pub struct SplitChoice;
+impl<Choice: SplitFirst> Convert<Choice, Either<Choice::First, Choice::Rest>> for SplitChoice {
+	fn convert(choice: Choice) -> Either<Choice::First, Choice::Rest> {
+		choice.split()
+	}
+}
+
+/// Union of two `fungibles::*` implementations keyed by [`OneOf2`].
+pub(crate) type InnerUnionOf2<A, B, AccountId> =
+	UnionOf<A, B, SplitChoice, OneOf2<IdOf<A, AccountId>, IdOf<B, AccountId>>, AccountId>;
+
+/// Union of three `fungibles::*` implementations keyed by [`OneOf3`].
+pub(crate) type InnerUnionOf3<A, B, C, AccountId> = UnionOf<
+	A,
+	InnerUnionOf2<B, C, AccountId>,
+	SplitChoice,
+	OneOf3<IdOf<A, AccountId>, IdOf<B, AccountId>, IdOf<C, AccountId>>,
+	AccountId,
+>;
+
+/// Union of four `fungibles::*` implementations keyed by [`OneOf4`].
+pub(crate) type InnerUnionOf4<A, B, C, D, AccountId> = UnionOf<
+	A,
+	InnerUnionOf3<B, C, D, AccountId>,
+	SplitChoice,
+	OneOf4<IdOf<A, AccountId>, IdOf<B, AccountId>, IdOf<C, AccountId>, IdOf<D, AccountId>>,
+	AccountId,
+>;
+
+/// Type to combine three `fungibles::*` implementations into one union `fungibles::*`
+/// implementation.
+///
+/// Unlike nesting [`UnionOf`]s by hand, a single `Criterion` classifies the asset into one of the
+/// sets. The resulting type implements the same traits as [`UnionOf`].
+///
+/// ### Parameters:
+/// - `A`, `B` and `C` are the `fungibles::*` implementations incorporated into the union.
+/// - `Criterion` converts the `AssetKind` into the [`OneOf3`] choice of the set it belongs to.
+/// - `AssetKind` is a superset type encompassing asset kinds from all sets.
+/// - `AccountId` is an account identifier type.
+pub type UnionOf3<A, B, C, Criterion, AssetKind, AccountId> = UnionOf<
+	A,
+	InnerUnionOf2<B, C, AccountId>,
+	SplitFirstBy<Criterion, OneOf3<IdOf<A, AccountId>, IdOf<B, AccountId>, IdOf<C, AccountId>>>,
+	AssetKind,
+	AccountId,
+>;
+
+/// Type to combine four `fungibles::*` implementations into one union `fungibles::*`
+/// implementation.
+///
+/// Same as [`UnionOf3`], with `Criterion` converting the `AssetKind` into a [`OneOf4`].
+pub type UnionOf4<A, B, C, D, Criterion, AssetKind, AccountId> = UnionOf<
+	A,
+	InnerUnionOf3<B, C, D, AccountId>,
+	SplitFirstBy<
+		Criterion,
+		OneOf4<IdOf<A, AccountId>, IdOf<B, AccountId>, IdOf<C, AccountId>, IdOf<D, AccountId>>,
+	>,
+	AssetKind,
+	AccountId,
+>;
+
+/// Type to combine five `fungibles::*` implementations into one union `fungibles::*`
+/// implementation.
+///
+/// Same as [`UnionOf3`], with `Criterion` converting the `AssetKind` into a [`OneOf5`].
+pub type UnionOf5<A, B, C, D, E, Criterion, AssetKind, AccountId> = UnionOf<
+	A,
+	InnerUnionOf4<B, C, D, E, AccountId>,
+	SplitFirstBy<
+		Criterion,
+		OneOf5<
+			IdOf<A, AccountId>,
+			IdOf<B, AccountId>,
+			IdOf<C, AccountId>,
+			IdOf<D, AccountId>,
+			IdOf<E, AccountId>,
+		>,
+	>,
+	AssetKind,
+	AccountId,
+>;
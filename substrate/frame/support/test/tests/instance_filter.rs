@@ -0,0 +1,185 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the `instance_filter` attribute macro.
+
+use codec::{Decode, Encode};
+use frame_support::{
+	derive_impl,
+	traits::{
+		CallFilter, CallMatcher, InstanceFilter, InstanceFilterEntry, InstanceFilterMatrix,
+		PalletCalls,
+	},
+};
+use sp_core::sr25519;
+use sp_runtime::{
+	generic,
+	traits::{BlakeTwo256, Verify},
+};
+
+#[frame_support::pallet(dev_mode)]
+mod module {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		pub fn foo(_origin: OriginFor<T>) -> DispatchResult {
+			Ok(())
+		}
+
+		pub fn bar(_origin: OriginFor<T>) -> DispatchResult {
+			Ok(())
+		}
+	}
+}
+
+pub type BlockNumber = u64;
+pub type Signature = sr25519::Signature;
+pub type AccountId = <Signature as Verify>::Signer;
+pub type Header = generic::Header<BlockNumber, BlakeTwo256>;
+pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<u32, RuntimeCall, Signature, ()>;
+pub type Block = generic::Block<Header, UncheckedExtrinsic>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime
+	{
+		System: frame_system,
+		Module: module,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = Block;
+}
+
+impl module::Config for Runtime {}
+
+fn only_foo(c: &RuntimeCall) -> bool {
+	matches!(c, RuntimeCall::Module(module::Call::foo {}))
+}
+
+#[frame_support::instance_filter(RuntimeCall, Runtime)]
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, Debug)]
+pub enum ProxyType {
+	#[filter(any)]
+	Any,
+	#[filter(nothing)]
+	Nothing,
+	#[filter(deny(Module::bar), superset_of(Module, Foo))]
+	NonBar,
+	#[filter(allow(Module))]
+	Module,
+	/// Only `foo`.
+	#[filter(allow(Module::foo, System::remark))]
+	Foo,
+	#[filter(with = only_foo)]
+	Custom,
+}
+
+#[test]
+fn filter_works() {
+	let foo = RuntimeCall::Module(module::Call::foo {});
+	let bar = RuntimeCall::Module(module::Call::bar {});
+	let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+
+	let allowed =
+		|proxy_type: ProxyType| [&foo, &bar, &remark].map(|call| proxy_type.filter(call)).to_vec();
+	assert_eq!(allowed(ProxyType::Any), vec![true, true, true]);
+	assert_eq!(allowed(ProxyType::Nothing), vec![false, false, false]);
+	assert_eq!(allowed(ProxyType::NonBar), vec![true, false, true]);
+	assert_eq!(allowed(ProxyType::Module), vec![true, true, false]);
+	assert_eq!(allowed(ProxyType::Foo), vec![true, false, true]);
+	assert_eq!(allowed(ProxyType::Custom), vec![true, false, false]);
+}
+
+#[test]
+fn is_superset_works() {
+	assert!(ProxyType::Any.is_superset(&ProxyType::Foo));
+	assert!(ProxyType::Foo.is_superset(&ProxyType::Foo));
+	assert!(ProxyType::NonBar.is_superset(&ProxyType::Module));
+	assert!(ProxyType::NonBar.is_superset(&ProxyType::Foo));
+	assert!(!ProxyType::NonBar.is_superset(&ProxyType::Custom));
+	assert!(!ProxyType::Foo.is_superset(&ProxyType::Any));
+	assert!(!ProxyType::Module.is_superset(&ProxyType::Foo));
+}
+
+#[test]
+fn filter_matrix_works() {
+	let matcher = |pallet: &str, call: Option<&str>| CallMatcher {
+		pallet: pallet.into(),
+		call: call.map(Into::into),
+	};
+	let entry =
+		|instance, filter, superset_of| InstanceFilterEntry { instance, filter, superset_of };
+
+	assert_eq!(
+		ProxyType::filter_matrix(),
+		vec![
+			entry(ProxyType::Any, CallFilter::Any, vec![]),
+			entry(ProxyType::Nothing, CallFilter::Nothing, vec![]),
+			entry(
+				ProxyType::NonBar,
+				CallFilter::Deny(vec![matcher("Module", Some("bar"))]),
+				vec![ProxyType::Module, ProxyType::Foo],
+			),
+			entry(ProxyType::Module, CallFilter::Allow(vec![matcher("Module", None)]), vec![]),
+			entry(
+				ProxyType::Foo,
+				CallFilter::Allow(vec![
+					matcher("Module", Some("foo")),
+					matcher("System", Some("remark")),
+				]),
+				vec![],
+			),
+			entry(ProxyType::Custom, CallFilter::Custom, vec![]),
+		]
+	);
+
+	assert_eq!(ProxyType::ensure_matrix_matches::<RuntimeCall>(), Ok(()));
+}
+
+#[test]
+fn call_indices_works() {
+	let pallets = PalletCalls::from_metadata(&Runtime::metadata_ir());
+	let module = pallets.iter().find(|pallet| pallet.name == "Module").unwrap();
+	assert_eq!(module.index, 1);
+	assert_eq!(module.calls, vec![("foo", 0), ("bar", 1)]);
+	let remark = pallets[0].calls.iter().find(|(name, _)| *name == "remark").unwrap().1;
+
+	let call_indices = |proxy_type: ProxyType| {
+		let entry = ProxyType::filter_matrix()
+			.into_iter()
+			.find(|entry| entry.instance == proxy_type)
+			.unwrap();
+		entry.filter.call_indices(&pallets)
+	};
+	assert_eq!(call_indices(ProxyType::Nothing), Some(vec![]));
+	assert_eq!(call_indices(ProxyType::Module), Some(vec![(1, vec![0, 1])]));
+	assert_eq!(call_indices(ProxyType::Foo), Some(vec![(0, vec![remark]), (1, vec![0])]));
+	assert_eq!(call_indices(ProxyType::Custom), None);
+	let non_bar = call_indices(ProxyType::NonBar).unwrap();
+	assert_eq!(non_bar.len(), 2);
+	assert_eq!(non_bar[1], (1, vec![0]));
+}
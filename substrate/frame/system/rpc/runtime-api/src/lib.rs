@@ -34,3 +34,13 @@ sp_api::decl_runtime_apis! {
 		fn account_nonce(account: AccountId) -> Nonce;
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the author of a block.
+	pub trait BlockAuthorApi<AccountId> where
+		AccountId: codec::Codec,
+	{
+		/// Get the author of the block, if the runtime stores it.
+		fn block_author() -> Option<AccountId>;
+	}
+}
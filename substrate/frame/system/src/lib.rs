@@ -136,7 +136,7 @@ use frame_support::{
 	pallet_prelude::Pays,
 	storage::{self, StorageStreamIter},
 	traits::{
-		ConstU32, Contains, EnsureOrigin, EnsureOriginWithArg, Get, HandleLifetime,
+		ConstU32, Contains, EnsureOrigin, EnsureOriginWithArg, FindAuthor, Get, HandleLifetime,
 		OnKilledAccount, OnNewAccount, OnRuntimeUpgrade, OriginTrait, PalletInfo, SortedMembers,
 		StoredMap, TypedGet,
 	},
@@ -372,6 +372,7 @@ pub mod pallet {
 			type PreInherents = ();
 			type PostInherents = ();
 			type PostTransactions = ();
+			type FindBlockAuthor = ();
 		}
 
 		/// Default configurations of this pallet in a solochain environment.
@@ -474,6 +475,7 @@ pub mod pallet {
 			type PreInherents = ();
 			type PostInherents = ();
 			type PostTransactions = ();
+			type FindBlockAuthor = ();
 		}
 
 		/// Default configurations of this pallet in a relay-chain environment.
@@ -690,6 +692,12 @@ pub mod pallet {
 		///
 		/// See `frame_executive::block_flowchart` for a in-depth explanation when it runs.
 		type PostTransactions: PostTransactions;
+
+		/// Find the author of a block from its pre-runtime digests.
+		///
+		/// If set, the author is stored in [`BlockAuthor`] when the block is initialized. The
+		/// default `()` finds no author, so nothing is stored.
+		type FindBlockAuthor: FindAuthor<Self::AccountId>;
 	}
 
 	#[pallet::pallet]
@@ -1077,6 +1085,14 @@ pub mod pallet {
 	pub(super) type EventTopics<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::Hash, Vec<(BlockNumberFor<T>, EventIndex)>, ValueQuery>;
 
+	/// The author of the current block, as found by [`Config::FindBlockAuthor`].
+	///
+	/// Set when the block is initialized and kept until the next block, so that it can also be
+	/// read from the state of a finished block.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub type BlockAuthor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
 	/// Stores the `spec_version` and `spec_name` of when the last runtime upgrade happened.
 	#[pallet::storage]
 	#[pallet::unbounded]
@@ -1884,6 +1900,11 @@ impl<T: Config> Pallet<T> {
 		AllExtrinsicsLen::<T>::get().unwrap_or_default()
 	}
 
+	/// Gets the author of the current block, if [`Config::FindBlockAuthor`] found one.
+	pub fn block_author() -> Option<T::AccountId> {
+		BlockAuthor::<T>::get()
+	}
+
 	/// Inform the system pallet of some additional weight that should be accounted for, in the
 	/// current block.
 	///
@@ -1917,6 +1938,12 @@ impl<T: Config> Pallet<T> {
 		<ParentHash<T>>::put(parent_hash);
 		<BlockHash<T>>::insert(*number - One::one(), parent_hash);
 
+		let pre_runtime_digests = digest.logs().iter().filter_map(|d| d.as_pre_runtime());
+		match T::FindBlockAuthor::find_author(pre_runtime_digests) {
+			Some(author) => <BlockAuthor<T>>::put(author),
+			None => <BlockAuthor<T>>::kill(),
+		}
+
 		// Remove previous block data from storage
 		BlockWeight::<T>::kill();
 	}
@@ -17,7 +17,7 @@
 
 use crate::{self as frame_system, *};
 use frame_support::{derive_impl, parameter_types};
-use sp_runtime::{type_with_default::TypeWithDefault, BuildStorage, Perbill};
+use sp_runtime::{type_with_default::TypeWithDefault, BuildStorage, ConsensusEngineId, Perbill};
 
 type Block = mocking::MockBlock<Test>;
 
@@ -96,6 +96,23 @@ impl Config for Test {
 	type OnKilledAccount = RecordKilled;
 	type MultiBlockMigrator = MockedMigrator;
 	type Nonce = TypeWithDefault<u64, DefaultNonceProvider>;
+	type FindBlockAuthor = AuthorFromDigest;
+}
+
+/// The engine id of the pre-runtime digest carrying the block author in tests.
+pub const TEST_ENGINE_ID: ConsensusEngineId = *b"test";
+
+pub struct AuthorFromDigest;
+impl FindAuthor<u64> for AuthorFromDigest {
+	fn find_author<'a, I>(digests: I) -> Option<u64>
+	where
+		I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+	{
+		digests
+			.into_iter()
+			.find(|(id, _)| *id == TEST_ENGINE_ID)
+			.and_then(|(_, mut data)| u64::decode(&mut data).ok())
+	}
 }
 
 parameter_types! {
@@ -49,6 +49,23 @@ fn check_whitelist() {
 	assert!(whitelist.contains("26aa394eea5630e07c48ae0c9558cef734abf5cb34d6244378cddbf18e849d96"));
 }
 
+#[test]
+fn block_author_is_stored_on_initialize() {
+	new_test_ext().execute_with(|| {
+		let mut digest = generic::Digest::default();
+		digest.push(generic::DigestItem::PreRuntime(TEST_ENGINE_ID, 7u64.encode()));
+		System::initialize(&1, &[0u8; 32].into(), &digest);
+		assert_eq!(System::block_author(), Some(7));
+
+		// The author is kept in the state of the finished block.
+		System::finalize();
+		assert_eq!(System::block_author(), Some(7));
+
+		System::initialize(&2, &[1u8; 32].into(), &Default::default());
+		assert_eq!(System::block_author(), None);
+	});
+}
+
 #[test]
 fn origin_works() {
 	let o = RuntimeOrigin::from(RawOrigin::<u64>::Signed(1u64));
@@ -134,13 +134,25 @@ mod tests;
 pub mod weights;
 
 use core::{cmp, result};
-use frame_support::traits::{OnTimestampSet, Time, UnixTime};
+use frame_support::traits::{Get, OnTimestampSet, Time, UnixTime};
 use sp_runtime::traits::{AtLeast32Bit, SaturatedConversion, Scale, Zero};
 use sp_timestamp::{InherentError, InherentType, INHERENT_IDENTIFIER};
 pub use weights::WeightInfo;
 
 pub use pallet::*;
 
+/// Provides the moment the current block is expected to have, e.g. the start of its slot.
+pub trait ExpectedMoment<Moment> {
+	/// The expected moment of the current block, or `None` if it is not known.
+	fn expected_moment() -> Option<Moment>;
+}
+
+impl<Moment> ExpectedMoment<Moment> for () {
+	fn expected_moment() -> Option<Moment> {
+		None
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -162,6 +174,8 @@ pub mod pallet {
 			type Moment = u64;
 			type OnTimestampSet = ();
 			type MinimumPeriod = ConstUint<1>;
+			type ExpectedMoment = ();
+			type AnomalyTolerance = ConstUint<0>;
 			type WeightInfo = ();
 		}
 	}
@@ -193,6 +207,16 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinimumPeriod: Get<Self::Moment>;
 
+		/// The moment the current block is expected to have, usually derived from its slot.
+		///
+		/// Set this to `()` to not check the timestamp against an expected moment.
+		type ExpectedMoment: ExpectedMoment<Self::Moment>;
+
+		/// The deviation of the timestamp from the [`Config::ExpectedMoment`] above which an
+		/// [`Event::TimestampAnomaly`] is emitted.
+		#[pallet::constant]
+		type AnomalyTolerance: Get<Self::Moment>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -211,6 +235,21 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type DidUpdate<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The timestamp deviates from the expected moment by more than the
+		/// [`Config::AnomalyTolerance`].
+		TimestampAnomaly {
+			/// The timestamp of the block.
+			now: T::Moment,
+			/// The moment the block was expected to have.
+			expected: T::Moment,
+			/// The absolute difference between `now` and `expected`.
+			delta: T::Moment,
+		},
+	}
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// A dummy `on_initialize` to return the amount of weight that `on_finalize` requires to
@@ -268,6 +307,8 @@ pub mod pallet {
 			Now::<T>::put(now);
 			DidUpdate::<T>::put(true);
 
+			Self::check_anomaly(now);
+
 			<T::OnTimestampSet as OnTimestampSet<_>>::on_timestamp_set(now);
 
 			Ok(())
@@ -341,6 +382,32 @@ impl<T: Config> Pallet<T> {
 		Now::<T>::get()
 	}
 
+	/// The deviation of `now` from the [`Config::ExpectedMoment`], along with the expected moment.
+	///
+	/// Returns `None` if no moment is expected.
+	pub fn deviation(now: T::Moment) -> Option<(T::Moment, T::Moment)> {
+		let expected = T::ExpectedMoment::expected_moment()?;
+		let delta = if now > expected { now - expected } else { expected - now };
+		Some((expected, delta))
+	}
+
+	/// Emit an [`Event::TimestampAnomaly`] if `now` deviates from the expected moment by more
+	/// than the [`Config::AnomalyTolerance`].
+	fn check_anomaly(now: T::Moment) {
+		if let Some((expected, delta)) = Self::deviation(now) {
+			if delta > T::AnomalyTolerance::get() {
+				log::warn!(
+					target: "runtime::timestamp",
+					"Timestamp {:?} deviates from the expected moment {:?} by {:?}",
+					now,
+					expected,
+					delta,
+				);
+				Self::deposit_event(Event::TimestampAnomaly { now, expected, delta });
+			}
+		}
+	}
+
 	/// Set the timestamp to something in particular. Only used for tests.
 	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
 	pub fn set_timestamp(now: T::Moment) {
@@ -51,10 +51,22 @@ impl OnTimestampSet<Moment> for MockOnTimestampSet {
 	}
 }
 
+parameter_types! {
+	pub static MockExpectedMoment: Option<Moment> = None;
+}
+
+impl ExpectedMoment<Moment> for MockExpectedMoment {
+	fn expected_moment() -> Option<Moment> {
+		MockExpectedMoment::get()
+	}
+}
+
 impl Config for Test {
 	type Moment = Moment;
 	type OnTimestampSet = MockOnTimestampSet;
 	type MinimumPeriod = ConstU64<5>;
+	type ExpectedMoment = MockExpectedMoment;
+	type AnomalyTolerance = ConstU64<10>;
 	type WeightInfo = ();
 }
 
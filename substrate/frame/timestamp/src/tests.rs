@@ -51,3 +51,39 @@ fn block_period_minimum_enforced() {
 		let _ = Timestamp::set(RuntimeOrigin::none(), 46);
 	});
 }
+
+#[test]
+fn timestamp_anomaly_is_reported() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		crate::Now::<Test>::put(40);
+
+		// No expected moment, no anomaly.
+		assert_ok!(Timestamp::set(RuntimeOrigin::none(), 100));
+		assert!(System::events().is_empty());
+		assert_eq!(Timestamp::deviation(100), None);
+
+		// Within the tolerance.
+		crate::DidUpdate::<Test>::kill();
+		MockExpectedMoment::set(Some(105));
+		assert_ok!(Timestamp::set(RuntimeOrigin::none(), 115));
+		assert!(System::events().is_empty());
+		assert_eq!(Timestamp::deviation(115), Some((105, 10)));
+
+		// Ahead of the expected moment.
+		crate::DidUpdate::<Test>::kill();
+		MockExpectedMoment::set(Some(120));
+		assert_ok!(Timestamp::set(RuntimeOrigin::none(), 131));
+		System::assert_last_event(
+			crate::Event::TimestampAnomaly { now: 131, expected: 120, delta: 11 }.into(),
+		);
+
+		// Behind the expected moment.
+		crate::DidUpdate::<Test>::kill();
+		MockExpectedMoment::set(Some(200));
+		assert_ok!(Timestamp::set(RuntimeOrigin::none(), 150));
+		System::assert_last_event(
+			crate::Event::TimestampAnomaly { now: 150, expected: 200, delta: 50 }.into(),
+		);
+	});
+}
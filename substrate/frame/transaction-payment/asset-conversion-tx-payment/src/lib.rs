@@ -25,7 +25,9 @@
 //! [`OnChargeAssetTransaction`] implementation analogous to [`pallet-transaction-payment`]. The
 //! included [`SwapAssetAdapter`] (implementing [`OnChargeAssetTransaction`]) determines the
 //! fee amount by converting the fee calculated by [`pallet-transaction-payment`] in the native
-//! asset into the amount required of the specified asset.
+//! asset into the amount required of the specified asset. The tip is given in the native asset,
+//! unless [`ChargeAssetTxPaymentWithAssetTip`] is used instead, in which case it is given in the
+//! specified asset and swapped together with the fee.
 //!
 //! ## Pallet API
 //!
@@ -55,8 +57,8 @@ use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
-		AsSystemOriginSigner, DispatchInfoOf, Dispatchable, PostDispatchInfoOf, RefundWeight,
-		TransactionExtension, ValidateResult, Zero,
+		AsSystemOriginSigner, DispatchInfoOf, Dispatchable, Implication, PostDispatchInfoOf,
+		RefundWeight, TransactionExtension, ValidateResult, Zero,
 	},
 	transaction_validity::{InvalidTransaction, TransactionValidityError, ValidTransaction},
 };
@@ -429,3 +431,120 @@ where
 		}
 	}
 }
+
+/// Require payment for transaction inclusion like [`ChargeAssetTxPayment`], with the tip given in
+/// the asset used to pay the fee.
+///
+/// The tip is converted into the native asset by
+/// [`OnChargeAssetTransaction::tip_to_native`] and swapped together with the fee, so the priority
+/// of the transaction is still computed from the native value of the tip. An asset ID of `None`
+/// pays both the fee and the tip in the native currency.
+///
+/// This is a separate extension rather than an option of [`ChargeAssetTxPayment`], so the
+/// encoding of the latter, which signers rely on, is unchanged.
+#[derive(Encode, Decode, DecodeWithMemTracking, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeAssetTxPaymentWithAssetTip<T: Config> {
+	#[codec(compact)]
+	tip: BalanceOf<T>,
+	asset_id: Option<T::AssetId>,
+}
+
+impl<T: Config> ChargeAssetTxPaymentWithAssetTip<T>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	/// Utility constructor. Used only in client/factory code.
+	pub fn from(tip: BalanceOf<T>, asset_id: Option<T::AssetId>) -> Self {
+		Self { tip, asset_id }
+	}
+
+	/// The equivalent [`ChargeAssetTxPayment`], with the tip converted into the native asset.
+	fn with_native_tip(&self) -> Result<ChargeAssetTxPayment<T>, TransactionValidityError> {
+		let tip = match &self.asset_id {
+			Some(asset_id) =>
+				T::OnChargeAssetTransaction::tip_to_native(asset_id.clone(), self.tip)
+					.ok_or(InvalidTransaction::Payment)?,
+			None => self.tip,
+		};
+		Ok(ChargeAssetTxPayment::from(tip, self.asset_id.clone()))
+	}
+}
+
+impl<T: Config> core::fmt::Debug for ChargeAssetTxPaymentWithAssetTip<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "ChargeAssetTxPaymentWithAssetTip<{:?}, {:?}>", self.tip, self.asset_id.encode())
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config> TransactionExtension<T::RuntimeCall> for ChargeAssetTxPaymentWithAssetTip<T>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	BalanceOf<T>: Send + Sync + From<u64>,
+	T::AssetId: Send + Sync,
+	<T::RuntimeCall as Dispatchable>::RuntimeOrigin: AsSystemOriginSigner<T::AccountId> + Clone,
+{
+	const IDENTIFIER: &'static str = "ChargeAssetTxPaymentWithAssetTip";
+	type Implicit = ();
+	type Val = Val<T>;
+	type Pre = Pre<T>;
+
+	fn weight(&self, call: &T::RuntimeCall) -> Weight {
+		ChargeAssetTxPayment::<T>::from(self.tip, self.asset_id.clone()).weight(call)
+	}
+
+	fn validate(
+		&self,
+		origin: <T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+		self_implicit: Self::Implicit,
+		inherited_implication: &impl Implication,
+		source: TransactionSource,
+	) -> ValidateResult<Self::Val, T::RuntimeCall> {
+		if origin.as_system_origin_signer().is_none() {
+			return Ok((ValidTransaction::default(), Val::NoCharge, origin))
+		}
+		self.with_native_tip()?.validate(
+			origin,
+			call,
+			info,
+			len,
+			self_implicit,
+			inherited_implication,
+			source,
+		)
+	}
+
+	fn prepare(
+		self,
+		val: Self::Val,
+		origin: &<T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		// The tip was already converted into the native asset when validating.
+		let tip = match &val {
+			Val::Charge { tip, .. } => *tip,
+			Val::NoCharge => Zero::zero(),
+		};
+		ChargeAssetTxPayment::<T>::from(tip, self.asset_id).prepare(val, origin, call, info, len)
+	}
+
+	fn post_dispatch_details(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<Weight, TransactionValidityError> {
+		ChargeAssetTxPayment::<T>::post_dispatch_details(pre, info, post_info, len, result)
+	}
+}
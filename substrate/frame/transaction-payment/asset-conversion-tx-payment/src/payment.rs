@@ -80,6 +80,12 @@ pub trait OnChargeAssetTransaction<T: Config> {
 		asset_id: Self::AssetId,
 		already_withdraw: Self::LiquidityInfo,
 	) -> Result<BalanceOf<T>, TransactionValidityError>;
+
+	/// Convert a `tip` denominated in `asset_id` into the native asset.
+	///
+	/// Used for tips paid in the asset used for the fee payment, which are swapped together with
+	/// the fee. Returns `None` if the tip cannot be converted.
+	fn tip_to_native(asset_id: Self::AssetId, tip: Self::Balance) -> Option<Self::Balance>;
 }
 
 /// Means to withdraw, correct and deposit fees in the asset accepted by the system.
@@ -320,4 +326,12 @@ where
 		OU::on_unbalanceds(Some(fee).into_iter().chain(Some(tip)));
 		Ok(fee_in_asset)
 	}
+
+	fn tip_to_native(asset_id: Self::AssetId, tip: Self::Balance) -> Option<Self::Balance> {
+		if asset_id == A::get() || tip.is_zero() {
+			return Some(tip)
+		}
+		// The tip is swapped together with the fee, so quote it including the swap fee.
+		S::quote_price_exact_tokens_for_tokens(asset_id, A::get(), tip, true)
+	}
 }
@@ -32,6 +32,7 @@ use mock::{ExtrinsicBaseWeight, *};
 use pallet_balances::Call as BalancesCall;
 use sp_runtime::{
 	traits::{DispatchTransaction, StaticLookup},
+	transaction_validity::TransactionSource::External,
 	BuildStorage,
 };
 
@@ -513,6 +514,101 @@ fn asset_transaction_payment_with_tip_and_refund() {
 		});
 }
 
+#[test]
+fn asset_transaction_payment_with_tip_in_asset() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// create the asset
+			let asset_id = 1;
+			let min_balance = 2;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				min_balance,
+			));
+
+			setup_lp(asset_id, balance_factor);
+
+			// mint into the caller account
+			let caller = 2;
+			let beneficiary = <Runtime as system::Config>::Lookup::unlookup(caller);
+			let balance = 10000;
+			assert_ok!(Assets::mint_into(asset_id.into(), &beneficiary, balance));
+
+			// The tip is given in the asset and converted into the native asset.
+			let asset_tip = 50;
+			let tip = AssetConversion::quote_price_exact_tokens_for_tokens(
+				NativeOrWithId::WithId(asset_id),
+				NativeOrWithId::Native,
+				asset_tip,
+				true,
+			)
+			.unwrap();
+			assert!(tip > 0);
+
+			let len = 10;
+			let mut info = info_from_weight(WEIGHT_100);
+			let ext =
+				ChargeAssetTxPaymentWithAssetTip::<Runtime>::from(asset_tip, Some(asset_id.into()));
+			info.extension_weight = ext.weight(CALL);
+			let fee_in_native = base_weight +
+				WEIGHT_100.ref_time() +
+				info.extension_weight.ref_time() +
+				len as u64 + tip;
+			let fee_in_asset = AssetConversion::quote_price_tokens_for_exact_tokens(
+				NativeOrWithId::WithId(asset_id),
+				NativeOrWithId::Native,
+				fee_in_native,
+				true,
+			)
+			.unwrap();
+
+			// The priority is the same as for the equivalent tip in the native asset.
+			let native_tip_ext = ChargeAssetTxPayment::<Runtime>::from(tip, Some(asset_id.into()));
+			assert_eq!(
+				ext.validate_only(Some(caller).into(), CALL, &info, len, External, 0)
+					.unwrap()
+					.0
+					.priority,
+				native_tip_ext
+					.validate_only(Some(caller).into(), CALL, &info, len, External, 0)
+					.unwrap()
+					.0
+					.priority,
+			);
+
+			// The tip is swapped together with the fee.
+			let (pre, _) =
+				ext.validate_and_prepare(Some(caller).into(), CALL, &info, len, 0).unwrap();
+			assert_eq!(Assets::balance(asset_id, caller), balance - fee_in_asset);
+
+			let post_info = post_info_from_weight(WEIGHT_100.saturating_add(info.extension_weight));
+			assert_ok!(ChargeAssetTxPaymentWithAssetTip::<Runtime>::post_dispatch_details(
+				pre,
+				&info,
+				&post_info,
+				len,
+				&Ok(()),
+			));
+			assert_eq!(TipUnbalancedAmount::get(), tip);
+
+			// The tip in the asset does not change the encoding of `ChargeAssetTxPayment`.
+			assert_eq!(
+				ChargeAssetTxPayment::<Runtime>::from(tip, Some(asset_id.into())).encode(),
+				(codec::Compact(tip), Some(NativeOrWithId::<u32>::WithId(asset_id))).encode(),
+			);
+		});
+}
+
 #[test]
 fn payment_from_account_with_only_assets() {
 	let base_weight = 5;
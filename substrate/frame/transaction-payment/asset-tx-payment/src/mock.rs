@@ -164,6 +164,7 @@ impl FindAuthor<AccountId> for HardcodedAuthor {
 impl pallet_authorship::Config for Runtime {
 	type FindAuthor = HardcodedAuthor;
 	type EventHandler = ();
+	type AuthorStatsWindow = ();
 }
 
 pub struct CreditToBlockAuthor;
@@ -22,7 +22,9 @@
 use codec::Codec;
 use sp_runtime::traits::MaybeDisplay;
 
-pub use pallet_transaction_payment::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+pub use pallet_transaction_payment::{
+	FeeBreakdown, FeeDetails, FeeItem, FeeItemKind, InclusionFee, RuntimeDispatchInfo,
+};
 
 sp_api::decl_runtime_apis! {
 	#[api_version(4)]
@@ -53,4 +55,31 @@ sp_api::decl_runtime_apis! {
 		/// Query the output of the current `LengthToFee` given some input.
 		fn query_length_to_fee(length: u32) -> Balance;
 	}
+
+	/// Runtime API to itemize the fee of a transaction by the transaction extension charging it.
+	pub trait TransactionPaymentFeeBreakdownApi<Balance, AssetId>
+	where
+		Balance: Codec + MaybeDisplay,
+		AssetId: Codec,
+	{
+		/// Query the fee of `uxt` of encoded length `len`, itemized by the transaction extension
+		/// charging it, when paid in the native asset with a tip of `tip`.
+		fn query_fee_breakdown(
+			uxt: Block::Extrinsic,
+			len: u32,
+			tip: Balance,
+		) -> FeeBreakdown<Balance>;
+
+		/// Query the fee of `uxt` of encoded length `len`, itemized by the transaction extension
+		/// charging it, when paid in `asset` with a tip of `tip` in the native asset.
+		///
+		/// All amounts are expressed in `asset`. Returns `None` if the fee cannot be paid in
+		/// `asset`.
+		fn query_fee_breakdown_in_asset(
+			uxt: Block::Extrinsic,
+			len: u32,
+			tip: Balance,
+			asset: AssetId,
+		) -> Option<FeeBreakdown<Balance>>;
+	}
 }
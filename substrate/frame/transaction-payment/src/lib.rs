@@ -47,6 +47,8 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
@@ -69,7 +71,9 @@ use sp_runtime::{
 	transaction_validity::{TransactionPriority, TransactionValidityError, ValidTransaction},
 	FixedPointNumber, FixedU128, Perbill, Perquintill, RuntimeDebug,
 };
-pub use types::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+pub use types::{
+	FeeBreakdown, FeeDetails, FeeItem, FeeItemKind, InclusionFee, RuntimeDispatchInfo,
+};
 pub use weights::WeightInfo;
 
 #[cfg(test)]
@@ -208,32 +212,7 @@ where
 		let max_multiplier = X::get();
 		let previous = previous.max(min_multiplier);
 
-		let weights = T::BlockWeights::get();
-		// the computed ratio is only among the normal class.
-		let normal_max_weight =
-			weights.get(DispatchClass::Normal).max_total.unwrap_or(weights.max_block);
-		let current_block_weight = frame_system::Pallet::<T>::block_weight();
-		let normal_block_weight =
-			current_block_weight.get(DispatchClass::Normal).min(normal_max_weight);
-
-		// Normalize dimensions so they can be compared. Ensure (defensive) max weight is non-zero.
-		let normalized_ref_time = Perbill::from_rational(
-			normal_block_weight.ref_time(),
-			normal_max_weight.ref_time().max(1),
-		);
-		let normalized_proof_size = Perbill::from_rational(
-			normal_block_weight.proof_size(),
-			normal_max_weight.proof_size().max(1),
-		);
-
-		// Pick the limiting dimension. If the proof size is the limiting dimension, then the
-		// multiplier is adjusted by the proof size. Otherwise, it is adjusted by the ref time.
-		let (normal_limiting_dimension, max_limiting_dimension) =
-			if normalized_ref_time < normalized_proof_size {
-				(normal_block_weight.proof_size(), normal_max_weight.proof_size())
-			} else {
-				(normal_block_weight.ref_time(), normal_max_weight.ref_time())
-			};
+		let (normal_limiting_dimension, max_limiting_dimension) = normal_limiting_dimension::<T>();
 
 		let target_block_fullness = S::get();
 		let adjustment_variable = V::get();
@@ -267,6 +246,120 @@ where
 	}
 }
 
+/// The weight of the normal class in the current block and the maximum weight of the normal class,
+/// both in the limiting dimension.
+///
+/// The limiting dimension is the dimension of the weight which is used the most relative to its
+/// maximum, see [`TargetedFeeAdjustment`].
+fn normal_limiting_dimension<T: frame_system::Config>() -> (u64, u64) {
+	let weights = T::BlockWeights::get();
+	// the computed ratio is only among the normal class.
+	let normal_max_weight =
+		weights.get(DispatchClass::Normal).max_total.unwrap_or(weights.max_block);
+	let current_block_weight = frame_system::Pallet::<T>::block_weight();
+	let normal_block_weight =
+		current_block_weight.get(DispatchClass::Normal).min(normal_max_weight);
+
+	// Normalize dimensions so they can be compared. Ensure (defensive) max weight is non-zero.
+	let normalized_ref_time =
+		Perbill::from_rational(normal_block_weight.ref_time(), normal_max_weight.ref_time().max(1));
+	let normalized_proof_size = Perbill::from_rational(
+		normal_block_weight.proof_size(),
+		normal_max_weight.proof_size().max(1),
+	);
+
+	// Pick the limiting dimension. If the proof size is the limiting dimension, then the
+	// multiplier is adjusted by the proof size. Otherwise, it is adjusted by the ref time.
+	if normalized_ref_time < normalized_proof_size {
+		(normal_block_weight.proof_size(), normal_max_weight.proof_size())
+	} else {
+		(normal_block_weight.ref_time(), normal_max_weight.ref_time())
+	}
+}
+
+/// A struct to update the weight multiplier per block in the style of the EIP-1559 base fee.
+///
+/// Like [`TargetedFeeAdjustment`], the multiplier is adjusted by the fullness of the _normal
+/// capacity_ of the previous block in the limiting dimension. However, the change is relative to
+/// the target and bounded per block, so sustained congestion raises the fees quickly:
+///
+/// given:
+/// 	s = previous block weight
+/// 	s'= target block weight
+/// 	c = maximum change per block, e.g. 1/8
+/// 		diff = min(|s - s'| / s', 1)
+/// 	then:
+/// 	next_multiplier = prev_multiplier * (1 ± c * diff)
+///
+/// where the sign is positive if the block is fuller than the target. The multiplier changes by at
+/// most `c` per block and stays within `[M, X]`.
+///
+/// `S` provides the target fullness `s'` as a portion of the normal capacity of the block and `C`
+/// the maximum change `c`. Since any `Get` implementation can be used, they can be backed by
+/// storage, e.g. with `parameter_types! { pub storage .. }` or the `pallet-parameters`, to tune
+/// them without a runtime upgrade. A runtime should ensure that `M` is large enough for `c * M` to
+/// not round down to zero, otherwise the multiplier can never recover from the minimum.
+///
+/// For example, given `c == 1/8` and full blocks, the fees double in 6 blocks.
+pub struct BaseFeeAdjustment<T, S, C, M, X>(core::marker::PhantomData<(T, S, C, M, X)>);
+
+impl<T, S, C, M, X> MultiplierUpdate for BaseFeeAdjustment<T, S, C, M, X>
+where
+	T: frame_system::Config,
+	S: Get<Perquintill>,
+	C: Get<Multiplier>,
+	M: Get<Multiplier>,
+	X: Get<Multiplier>,
+{
+	fn min() -> Multiplier {
+		M::get()
+	}
+	fn max() -> Multiplier {
+		X::get()
+	}
+	fn target() -> Perquintill {
+		S::get()
+	}
+	fn variability() -> Multiplier {
+		C::get()
+	}
+}
+
+impl<T, S, C, M, X> Convert<Multiplier, Multiplier> for BaseFeeAdjustment<T, S, C, M, X>
+where
+	T: frame_system::Config,
+	S: Get<Perquintill>,
+	C: Get<Multiplier>,
+	M: Get<Multiplier>,
+	X: Get<Multiplier>,
+{
+	fn convert(previous: Multiplier) -> Multiplier {
+		// Defensive only, see `TargetedFeeAdjustment`.
+		let min_multiplier = M::get();
+		let max_multiplier = X::get();
+		let previous = previous.max(min_multiplier);
+
+		let (normal_limiting_dimension, max_limiting_dimension) = normal_limiting_dimension::<T>();
+
+		let target_weight = (S::get() * max_limiting_dimension) as u128;
+		let block_weight = normal_limiting_dimension as u128;
+
+		let positive = block_weight >= target_weight;
+		let diff_abs = block_weight.max(target_weight) - block_weight.min(target_weight);
+
+		// A zero target is exceeded by any weight, which changes the multiplier the most.
+		let diff = Multiplier::saturating_from_rational(diff_abs, target_weight.max(1))
+			.min(Multiplier::one());
+		let change = C::get().saturating_mul(diff).saturating_mul(previous);
+
+		if positive {
+			previous.saturating_add(change).clamp(min_multiplier, max_multiplier)
+		} else {
+			previous.saturating_sub(change).clamp(min_multiplier, max_multiplier)
+		}
+	}
+}
+
 /// A struct to make the fee multiplier a constant
 pub struct ConstFeeMultiplier<M: Get<Multiplier>>(core::marker::PhantomData<M>);
 
@@ -313,6 +406,9 @@ impl Default for Releases {
 /// NextFeeMultiplierOnEmpty() to provide a value when none exists in storage.
 const MULTIPLIER_DEFAULT_VALUE: Multiplier = Multiplier::from_u32(1);
 
+/// The identifier of the [`ChargeTransactionPayment`] extension.
+const EXTENSION_IDENTIFIER: &str = "ChargeTransactionPayment";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::pallet_prelude::*;
@@ -553,6 +649,28 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Query the fee of a given extrinsic, itemized by the transaction extension charging it.
+	///
+	/// `tip` is the tip the sender intends to offer.
+	pub fn query_fee_breakdown<Extrinsic: sp_runtime::traits::ExtrinsicLike + GetDispatchInfo>(
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+		tip: BalanceOf<T>,
+	) -> FeeBreakdown<BalanceOf<T>>
+	where
+		T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	{
+		let details = if unchecked_extrinsic.is_bare() {
+			// Bare extrinsics pay neither an inclusion fee nor a tip.
+			FeeDetails { inclusion_fee: None, tip: Zero::zero() }
+		} else {
+			let dispatch_info =
+				<Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+			Self::compute_fee_details(len, &dispatch_info, tip)
+		};
+		FeeBreakdown::from_fee_details(EXTENSION_IDENTIFIER.as_bytes(), details)
+	}
+
 	/// Query information of a dispatch class, weight, and fee of a given encoded `Call`.
 	pub fn query_call_info(call: T::RuntimeCall, len: u32) -> RuntimeDispatchInfo<BalanceOf<T>>
 	where
@@ -579,6 +697,22 @@ impl<T: Config> Pallet<T> {
 		Self::compute_fee_details(len, &dispatch_info, tip)
 	}
 
+	/// Query the fee of a given encoded `Call`, itemized by the transaction extension charging it.
+	pub fn query_call_fee_breakdown(
+		call: T::RuntimeCall,
+		len: u32,
+		tip: BalanceOf<T>,
+	) -> FeeBreakdown<BalanceOf<T>>
+	where
+		T::RuntimeCall: Dispatchable<Info = DispatchInfo> + GetDispatchInfo,
+	{
+		let dispatch_info = <T::RuntimeCall as GetDispatchInfo>::get_dispatch_info(&call);
+		FeeBreakdown::from_fee_details(
+			EXTENSION_IDENTIFIER.as_bytes(),
+			Self::compute_fee_details(len, &dispatch_info, tip),
+		)
+	}
+
 	/// Compute the final fee value for a particular transaction.
 	pub fn compute_fee(
 		len: u32,
@@ -902,7 +1036,7 @@ impl<T: Config> TransactionExtension<T::RuntimeCall> for ChargeTransactionPaymen
 where
 	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
 {
-	const IDENTIFIER: &'static str = "ChargeTransactionPayment";
+	const IDENTIFIER: &'static str = EXTENSION_IDENTIFIER;
 	type Implicit = ();
 	type Val = Val<T>;
 	type Pre = Pre<T>;
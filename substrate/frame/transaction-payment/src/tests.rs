@@ -383,6 +383,73 @@ fn query_call_info_and_fee_details_works() {
 		});
 }
 
+#[test]
+fn query_fee_breakdown_works() {
+	let call = RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest: 2, value: 69 });
+	let origin = 111111;
+	let extra = ();
+	let xt = UncheckedExtrinsic::<u64, _, (), ()>::new_signed(call.clone(), origin, (), extra);
+	let info = xt.get_dispatch_info();
+	let len = xt.encode().len() as u32;
+	let unsigned_xt = UncheckedExtrinsic::<u64, _, (), ()>::new_bare(call);
+	let identifier = b"ChargeTransactionPayment".to_vec();
+
+	ExtBuilder::default()
+		.base_weight(Weight::from_parts(5, 0))
+		.weight_fee(2)
+		.build()
+		.execute_with(|| {
+			let weight_fee =
+				info.total_weight().min(BlockWeights::get().max_block).ref_time() as u64 * 2;
+			let breakdown = TransactionPayment::query_fee_breakdown(xt, len, 7);
+			assert_eq!(
+				breakdown.items,
+				vec![
+					FeeItem {
+						extension: identifier.clone(),
+						kind: FeeItemKind::BaseFee,
+						amount: 5 * 2
+					},
+					FeeItem {
+						extension: identifier.clone(),
+						kind: FeeItemKind::LengthFee,
+						amount: len as u64
+					},
+					FeeItem {
+						extension: identifier.clone(),
+						kind: FeeItemKind::WeightFee,
+						amount: weight_fee
+					},
+					FeeItem { extension: identifier.clone(), kind: FeeItemKind::Tip, amount: 7 },
+				],
+			);
+			assert_eq!(breakdown.total(), 10 + len as u64 + weight_fee + 7);
+
+			// Bare extrinsics pay nothing.
+			assert!(TransactionPayment::query_fee_breakdown(unsigned_xt, len, 7).items.is_empty());
+
+			// Paying in an asset worth half the native asset, with a swap premium.
+			let in_asset = breakdown
+				.clone()
+				.in_asset(
+					b"ChargeAssetTxPayment",
+					|amount| Some(amount * 2),
+					breakdown.total() * 2 + 3,
+				)
+				.unwrap();
+			assert_eq!(in_asset.total(), breakdown.total() * 2 + 3);
+			assert_eq!(
+				in_asset.items.last(),
+				Some(&FeeItem {
+					extension: b"ChargeAssetTxPayment".to_vec(),
+					kind: FeeItemKind::AssetSwapPremium,
+					amount: 3
+				}),
+			);
+			assert_eq!(breakdown.in_asset(b"ChargeAssetTxPayment", |_| None, 0), None);
+		});
+}
+
 #[test]
 fn compute_fee_works_without_multiplier() {
 	ExtBuilder::default()
@@ -915,3 +982,42 @@ fn fungible_adapter_no_zero_refund_action() {
 		);
 	});
 }
+
+#[test]
+fn base_fee_adjustment_works() {
+	frame_support::parameter_types! {
+		pub Target: Perquintill = Perquintill::from_percent(50);
+		pub MaxChange: Multiplier = Multiplier::saturating_from_rational(1, 8);
+		pub MinMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 2);
+		pub MaxMultiplier: Multiplier = Multiplier::saturating_from_integer(2);
+	}
+	type Update = BaseFeeAdjustment<Runtime, Target, MaxChange, MinMultiplier, MaxMultiplier>;
+	let next = |weight: u64, previous: Multiplier| {
+		System::set_block_consumed_resources(Weight::from_parts(weight, 0), 0);
+		Update::convert(previous)
+	};
+
+	ExtBuilder::default().build().execute_with(|| {
+		let one = Multiplier::one();
+		// The normal class can use at most 1024, so the target is 512.
+		assert_eq!(next(512, one), one);
+		assert_eq!(next(1024, one), Multiplier::saturating_from_rational(9, 8));
+		assert_eq!(next(768, one), Multiplier::saturating_from_rational(17, 16));
+		assert_eq!(next(0, one), Multiplier::saturating_from_rational(7, 8));
+		assert_eq!(next(256, one), Multiplier::saturating_from_rational(15, 16));
+
+		// The change is relative to the previous multiplier.
+		let previous = Multiplier::saturating_from_rational(3, 2);
+		assert_eq!(next(1024, previous), Multiplier::saturating_from_rational(27, 16));
+
+		// The multiplier stays within its bounds.
+		assert_eq!(next(1024, MaxMultiplier::get()), MaxMultiplier::get());
+		assert_eq!(next(0, MinMultiplier::get()), MinMultiplier::get());
+		assert_eq!(next(512, Multiplier::zero()), MinMultiplier::get());
+
+		// Full blocks double the fees in 6 blocks.
+		let full_blocks = |n| (0..n).fold(one, |multiplier, _| next(1024, multiplier));
+		assert!(full_blocks(5) < Multiplier::saturating_from_integer(2));
+		assert_eq!(full_blocks(6), Multiplier::saturating_from_integer(2));
+	});
+}
@@ -17,6 +17,7 @@
 
 //! Types for transaction-payment RPC.
 
+use alloc::vec::Vec;
 use codec::{Decode, Encode};
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -90,6 +91,124 @@ impl<Balance: AtLeast32BitUnsigned + Copy> FeeDetails<Balance> {
 	}
 }
 
+/// The kind of charge a [`FeeItem`] accounts for.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum FeeItemKind {
+	/// The base fee, see [`InclusionFee::base_fee`].
+	BaseFee,
+	/// The length fee, see [`InclusionFee::len_fee`].
+	LengthFee,
+	/// The adjusted weight fee, see [`InclusionFee::adjusted_weight_fee`].
+	WeightFee,
+	/// The tip offered by the sender.
+	Tip,
+	/// The premium paid on top of the spot price when the fee is paid by swapping another asset.
+	AssetSwapPremium,
+}
+
+/// A single item of a [`FeeBreakdown`].
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "Balance: std::fmt::Display",
+		deserialize = "Balance: std::str::FromStr"
+	))
+)]
+pub struct FeeItem<Balance> {
+	/// The identifier of the transaction extension charging this item.
+	pub extension: Vec<u8>,
+	/// What this item is charged for.
+	pub kind: FeeItemKind,
+	/// The amount charged.
+	#[cfg_attr(feature = "std", serde(with = "serde_balance"))]
+	pub amount: Balance,
+}
+
+/// The fee of a transaction, itemized by the transaction extension charging it.
+///
+/// Unlike [`FeeDetails`], extensions charging on top of the inclusion fee, e.g. the premium of
+/// paying the fee in another asset, can add their own items.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+#[cfg_attr(
+	feature = "std",
+	serde(bound(
+		serialize = "Balance: std::fmt::Display",
+		deserialize = "Balance: std::str::FromStr"
+	))
+)]
+pub struct FeeBreakdown<Balance> {
+	/// The fee items, in the order in which they are charged.
+	pub items: Vec<FeeItem<Balance>>,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> FeeBreakdown<Balance> {
+	/// Itemize `details` as charged by the extension identified by `extension`.
+	///
+	/// Zero items are omitted.
+	pub fn from_fee_details(extension: &[u8], details: FeeDetails<Balance>) -> Self {
+		let mut breakdown = Self { items: Vec::new() };
+		if let Some(inclusion_fee) = details.inclusion_fee {
+			breakdown.push(extension, FeeItemKind::BaseFee, inclusion_fee.base_fee);
+			breakdown.push(extension, FeeItemKind::LengthFee, inclusion_fee.len_fee);
+			breakdown.push(extension, FeeItemKind::WeightFee, inclusion_fee.adjusted_weight_fee);
+		}
+		breakdown.push(extension, FeeItemKind::Tip, details.tip);
+		breakdown
+	}
+
+	/// Add an item of `amount` charged by `extension`, unless `amount` is zero.
+	pub fn push(&mut self, extension: &[u8], kind: FeeItemKind, amount: Balance) {
+		if !amount.is_zero() {
+			self.items.push(FeeItem { extension: extension.to_vec(), kind, amount });
+		}
+	}
+
+	/// Attribute all items to the extension identified by `extension`.
+	///
+	/// Used by extensions charging the fee computed by this pallet on its behalf.
+	pub fn charged_by(mut self, extension: &[u8]) -> Self {
+		self.items.iter_mut().for_each(|item| item.extension = extension.to_vec());
+		self
+	}
+
+	/// Express the breakdown in another asset used to pay the fee.
+	///
+	/// `to_spot` converts an amount at the spot price, i.e. without any swap fees, and `charged`
+	/// is the amount of the other asset actually charged for [`Self::total`]. The difference
+	/// between `charged` and the converted items is added as a
+	/// [`FeeItemKind::AssetSwapPremium`] item of `extension`.
+	///
+	/// Returns `None` if an item cannot be converted.
+	pub fn in_asset(
+		self,
+		extension: &[u8],
+		to_spot: impl Fn(Balance) -> Option<Balance>,
+		charged: Balance,
+	) -> Option<Self> {
+		let mut converted = Self { items: Vec::with_capacity(self.items.len() + 1) };
+		for item in self.items {
+			converted.push(&item.extension, item.kind, to_spot(item.amount)?);
+		}
+		let premium = charged.saturating_sub(converted.total());
+		converted.push(extension, FeeItemKind::AssetSwapPremium, premium);
+		Some(converted)
+	}
+
+	/// Returns the sum of all items.
+	pub fn total(&self) -> Balance {
+		self.items
+			.iter()
+			.fold(Zero::zero(), |total, item| total.saturating_add(item.amount))
+	}
+}
+
 /// Information related to a dispatchable's class, weight, and fee that can be queried from the
 /// runtime.
 #[derive(Eq, PartialEq, Encode, Decode, Default, TypeInfo)]
@@ -350,6 +350,135 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// This benchmark is short-circuited if `SpendOrigin` cannot provide
+	/// a successful origin, in which case `spend_recurring` is un-callable and can use weight=0.
+	#[benchmark]
+	fn spend_recurring() -> Result<(), BenchmarkError> {
+		let origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+			create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let period: BlockNumberFor<T, I> = 10u32.into();
+		let end = RecurringSpendEnd::AfterPayouts(2);
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(asset_kind.clone()),
+			amount,
+			Box::new(beneficiary_lookup),
+			None,
+			period,
+			end,
+		);
+
+		let first_payout_at = T::BlockNumberProvider::current_block_number();
+		assert_last_event::<T, I>(
+			Event::RecurringSpendApproved {
+				index: 0,
+				asset_kind,
+				amount,
+				beneficiary,
+				first_payout_at,
+				period,
+				end,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	/// This benchmark is short-circuited if `SpendOrigin` cannot provide
+	/// a successful origin, in which case `cancel_recurring_spend` is un-callable and can use
+	/// weight=0.
+	#[benchmark]
+	fn cancel_recurring_spend() -> Result<(), BenchmarkError> {
+		let origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		Treasury::<T, _>::spend_recurring(
+			origin.clone(),
+			Box::new(asset_kind),
+			amount,
+			Box::new(beneficiary_lookup),
+			None,
+			10u32.into(),
+			RecurringSpendEnd::AfterPayouts(2),
+		)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0u32);
+
+		assert_last_event::<T, I>(Event::RecurringSpendCancelled { index: 0 }.into());
+		assert!(RecurringSpends::<T, I>::get(0).is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn on_initialize_recurring_spends(
+		r: Linear<0, { T::MaxRecurringSpends::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let spender = T::SpendOrigin::try_successful_origin();
+		if let Ok(origin) = &spender {
+			for i in 0..r {
+				let (asset_kind, amount, beneficiary, beneficiary_lookup) =
+					create_spend_arguments::<T, _>(i);
+				T::BalanceConverter::ensure_successful(asset_kind.clone());
+				T::Paymaster::ensure_successful(&beneficiary, asset_kind.clone(), amount);
+				Treasury::<T, _>::spend_recurring(
+					origin.clone(),
+					Box::new(asset_kind),
+					amount,
+					Box::new(beneficiary_lookup),
+					None,
+					10u32.into(),
+					RecurringSpendEnd::AfterPayouts(2),
+				)?;
+			}
+		}
+		let now = T::BlockNumberProvider::current_block_number();
+
+		#[block]
+		{
+			Treasury::<T, _>::process_recurring_spends(now);
+		}
+
+		if spender.is_ok() {
+			ensure!(SpendCount::<T, I>::get() == r, "Not all payouts issued");
+		}
+		Ok(())
+	}
+
+	/// This benchmark is short-circuited if `SpendOrigin` cannot provide
+	/// a successful origin, in which case `spend_with_conversion` is un-callable and can use
+	/// weight=0.
+	#[benchmark]
+	fn spend_with_conversion() -> Result<(), BenchmarkError> {
+		let origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (asset_kind, amount, _, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		let requested_asset_kind = T::BenchmarkHelper::create_asset_kind(SEED + 1);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		T::BalanceConverter::ensure_successful(requested_asset_kind.clone());
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(asset_kind),
+			amount,
+			Box::new(requested_asset_kind.clone()),
+			Box::new(beneficiary_lookup),
+			None,
+		);
+
+		assert_last_event::<T, I>(
+			Event::SpendConversionApproved { index: 0, requested_asset_kind }.into(),
+		);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
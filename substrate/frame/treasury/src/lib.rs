@@ -69,6 +69,11 @@
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
 //! [`pallet::Config::PayoutPeriod`].
+//!
+//! The `spend_recurring` dispatchable approves a spend of any asset kind that is paid out
+//! automatically once per period until its end condition is met. Each payout is issued at the start
+//! of the block in which it is due and is recorded as a regular spend, so its status is followed up
+//! and failed payments are retried exactly like spends created with the `spend` dispatchable.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -87,21 +92,25 @@ extern crate alloc;
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
-use alloc::{boxed::Box, collections::btree_map::BTreeMap};
+use alloc::{boxed::Box, collections::btree_map::BTreeMap, vec::Vec};
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
 	traits::{
-		AccountIdConversion, BlockNumberProvider, CheckedAdd, One, Saturating, StaticLookup,
-		UniqueSaturatedInto, Zero,
+		AccountIdConversion, AtLeast32BitUnsigned, BlockNumberProvider, CheckedAdd, One,
+		Saturating, StaticLookup, UniqueSaturatedInto, Zero,
 	},
-	PerThing, Permill, RuntimeDebug,
+	DispatchError, PerThing, Permill, Rounding, RuntimeDebug,
 };
 
 use frame_support::{
 	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	dispatch_context::with_context,
 	ensure, print,
 	traits::{
-		tokens::Pay, Currency, ExistenceRequirement::KeepAlive, Get, Imbalance, OnUnbalanced,
-		ReservableCurrency, WithdrawReasons,
+		tokens::{ConversionFromAssetBalance, Pay},
+		Currency,
+		ExistenceRequirement::KeepAlive,
+		Get, Imbalance, OnUnbalanced, ReservableCurrency, WithdrawReasons,
 	},
 	weights::Weight,
 	BoundedVec, PalletId,
@@ -146,6 +155,38 @@ pub trait SpendFunds<T: Config<I>, I: 'static = ()> {
 	);
 }
 
+/// A trait to allow the Treasury Pallet to swap its funds into another asset kind before paying
+/// out a spend.
+///
+/// The swap is expected to withdraw the funds from and deposit the acquired funds into the account
+/// the [`Config::Paymaster`] pays out from.
+pub trait SpendAssetSwap<AssetKind, Balance> {
+	/// Swap exactly `amount_in` of `asset_kind` for at least `min_amount_out` of
+	/// `requested_asset_kind`.
+	///
+	/// If successful, returns the amount of `requested_asset_kind` acquired. This operation is
+	/// expected to be atomic.
+	fn swap(
+		asset_kind: AssetKind,
+		requested_asset_kind: AssetKind,
+		amount_in: Balance,
+		min_amount_out: Balance,
+	) -> Result<Balance, DispatchError>;
+
+	/// The worst case weight of a [`Self::swap`].
+	fn weight() -> Weight;
+}
+
+/// Swapping is not supported, spends can only be paid out in the approved asset kind.
+impl<AssetKind, Balance> SpendAssetSwap<AssetKind, Balance> for () {
+	fn swap(_: AssetKind, _: AssetKind, _: Balance, _: Balance) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 /// An index of a proposal. Just a `u32`.
 pub type ProposalIndex = u32;
 
@@ -226,14 +267,87 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
+/// The condition under which a recurring treasury spend completes.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub enum RecurringSpendEnd<BlockNumber> {
+	/// The spend completes once the given number of payouts has been made.
+	AfterPayouts(u32),
+	/// The spend completes at the given block. No payout is due at or after this block, but the
+	/// payouts due before it are still caught up with after it.
+	AtBlock(BlockNumber),
+}
+
+/// Info regarding an approved recurring treasury spend.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Clone,
+	PartialEq,
+	Eq,
+	MaxEncodedLen,
+	RuntimeDebug,
+	TypeInfo,
+)]
+pub struct RecurringSpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber> {
+	/// The kind of asset to be spent.
+	pub asset_kind: AssetKind,
+	/// The asset amount paid out every period.
+	pub amount: AssetBalance,
+	/// The beneficiary of the spend.
+	pub beneficiary: Beneficiary,
+	/// The number of blocks between two successive payouts.
+	pub period: BlockNumber,
+	/// The block number from which the next payout is due.
+	pub next_payout_at: BlockNumber,
+	/// The condition under which the spend completes.
+	pub end: RecurringSpendEnd<BlockNumber>,
+	/// The number of payouts made so far.
+	pub payouts_made: u32,
+}
+
+impl<AssetKind, AssetBalance, Beneficiary, BlockNumber>
+	RecurringSpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy,
+{
+	/// The number of payouts that are still to be made before the spend completes.
+	pub fn remaining_payouts(&self) -> u32 {
+		match self.end {
+			RecurringSpendEnd::AfterPayouts(total) => total.saturating_sub(self.payouts_made),
+			RecurringSpendEnd::AtBlock(end) => {
+				if end <= self.next_payout_at || self.period.is_zero() {
+					return 0
+				}
+				// Payouts happen at `next_payout_at + k * period` for all `k` that keep the
+				// payout block strictly below `end`.
+				let remaining = (end - self.next_payout_at - One::one()) / self.period;
+				remaining.saturating_add(One::one()).unique_saturated_into()
+			},
+		}
+	}
+}
+
+/// Index of an approved recurring treasury spend.
+pub type RecurringSpendIndex = u32;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{
-		dispatch_context::with_context,
-		pallet_prelude::*,
-		traits::tokens::{ConversionFromAssetBalance, PaymentStatus},
-	};
+	use frame_support::{pallet_prelude::*, traits::tokens::PaymentStatus};
 	use frame_system::pallet_prelude::{ensure_signed, OriginFor};
 
 	#[pallet::pallet]
@@ -312,6 +426,18 @@ pub mod pallet {
 		#[pallet::constant]
 		type PayoutPeriod: Get<BlockNumberFor<Self, I>>;
 
+		/// The maximum number of recurring spends that can be active at the same time.
+		#[pallet::constant]
+		type MaxRecurringSpends: Get<u32>;
+
+		/// Type for swapping the funds of a spend into the asset kind requested for its payout.
+		type AssetSwap: SpendAssetSwap<Self::AssetKind, AssetBalanceOf<Self, I>>;
+
+		/// The maximum slippage tolerated when swapping the funds of a spend, relative to the
+		/// amount expected from the conversion rates of the [`Self::BalanceConverter`].
+		#[pallet::constant]
+		type MaxSwapSlippage: Get<Permill>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: ArgumentsFactory<Self::AssetKind, Self::Beneficiary>;
@@ -386,6 +512,43 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type LastSpendPeriod<T, I = ()> = StorageValue<_, BlockNumberFor<T, I>, OptionQuery>;
 
+	/// The count of recurring spends that have been made.
+	#[pallet::storage]
+	pub type RecurringSpendCount<T, I = ()> = StorageValue<_, RecurringSpendIndex, ValueQuery>;
+
+	/// Recurring spends that have been approved and not yet completed or cancelled.
+	// Hasher: Twox safe since `RecurringSpendIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type RecurringSpends<T: Config<I>, I: 'static = ()> = CountedStorageMap<
+		_,
+		Twox64Concat,
+		RecurringSpendIndex,
+		RecurringSpendStatus<
+			T::AssetKind,
+			AssetBalanceOf<T, I>,
+			T::Beneficiary,
+			BlockNumberFor<T, I>,
+		>,
+		OptionQuery,
+	>;
+
+	/// The asset kinds that spends have to be paid out in, if different from the approved asset
+	/// kind.
+	///
+	/// The funds of such a spend are swapped into the requested asset kind at payout time, after
+	/// which the entry is removed.
+	// Hasher: Twox safe since `SpendIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type SpendConversions<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, SpendIndex, T::AssetKind, OptionQuery>;
+
+	/// The earliest block number at which any of the [`RecurringSpends`] is due for a payout.
+	///
+	/// Allows `on_initialize` to skip iterating the recurring spends when none of them is due.
+	#[pallet::storage]
+	pub type NextRecurringPayout<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T, I>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -444,6 +607,31 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// A new recurring asset spend has been approved.
+		RecurringSpendApproved {
+			index: RecurringSpendIndex,
+			asset_kind: T::AssetKind,
+			amount: AssetBalanceOf<T, I>,
+			beneficiary: T::Beneficiary,
+			first_payout_at: BlockNumberFor<T, I>,
+			period: BlockNumberFor<T, I>,
+			end: RecurringSpendEnd<BlockNumberFor<T, I>>,
+		},
+		/// A payout of a recurring spend has been issued as the spend at `spend_index`.
+		RecurringSpendPayout { index: RecurringSpendIndex, spend_index: SpendIndex },
+		/// A recurring spend has made all of its payouts and was removed from the storage.
+		RecurringSpendCompleted { index: RecurringSpendIndex },
+		/// A recurring spend was cancelled before it completed.
+		RecurringSpendCancelled { index: RecurringSpendIndex },
+		/// An approved spend has to be paid out in another asset kind.
+		SpendConversionApproved { index: SpendIndex, requested_asset_kind: T::AssetKind },
+		/// The funds of a spend were swapped into the requested asset kind for its payout.
+		SpendConverted {
+			index: SpendIndex,
+			requested_asset_kind: T::AssetKind,
+			amount_in: AssetBalanceOf<T, I>,
+			amount_out: AssetBalanceOf<T, I>,
+		},
 	}
 
 	/// Error for the treasury pallet.
@@ -472,6 +660,13 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// The recurring spend has a zero period or its end condition allows no payout.
+		InvalidRecurringSpend,
+		/// Too many recurring spends are active.
+		TooManyRecurringSpends,
+		/// The funds of the spend could not be swapped into the requested asset kind within the
+		/// tolerated slippage.
+		SwapFailed,
 	}
 
 	#[pallet::hooks]
@@ -507,11 +702,14 @@ pub mod pallet {
 				blocks_since_last_spend_period % safe_spend_period,
 			);
 			let new_last_spend_period = block_number.saturating_sub(extra_blocks);
-			if spend_periods_passed > BlockNumberFor::<T, I>::zero() {
+			let mut weight = if spend_periods_passed > BlockNumberFor::<T, I>::zero() {
 				Self::spend_funds(spend_periods_passed, new_last_spend_period)
 			} else {
 				Weight::zero()
-			}
+			};
+
+			weight.saturating_accrue(Self::process_recurring_spends(block_number));
+			weight
 		}
 
 		#[cfg(feature = "try-runtime")]
@@ -522,8 +720,8 @@ pub mod pallet {
 	}
 
 	#[derive(Default)]
-	struct SpendContext<Balance> {
-		spend_in_context: BTreeMap<Balance, Balance>,
+	pub(crate) struct SpendContext<Balance> {
+		pub(crate) spend_in_context: BTreeMap<Balance, Balance>,
 	}
 
 	#[pallet::call]
@@ -557,27 +755,7 @@ pub mod pallet {
 			beneficiary: AccountIdLookupOf<T>,
 		) -> DispatchResult {
 			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
-			ensure!(amount <= max_amount, Error::<T, I>::InsufficientPermission);
-
-			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
-				let context = v.or_default();
-
-				// We group based on `max_amount`, to distinguish between different kind of
-				// origins. (assumes that all origins have different `max_amount`)
-				//
-				// Worst case is that we reject some "valid" request.
-				let spend = context.spend_in_context.entry(max_amount).or_default();
-
-				// Ensure that we don't overflow nor use more than `max_amount`
-				if spend.checked_add(&amount).map(|s| s > max_amount).unwrap_or(true) {
-					Err(Error::<T, I>::InsufficientPermission)
-				} else {
-					*spend = spend.saturating_add(amount);
-
-					Ok(())
-				}
-			})
-			.unwrap_or(Ok(()))?;
+			Self::ensure_within_spend_limit(max_amount, amount)?;
 
 			let beneficiary = T::Lookup::lookup(beneficiary)?;
 			#[allow(deprecated)]
@@ -684,57 +862,7 @@ pub mod pallet {
 			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
 			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
 
-			let now = T::BlockNumberProvider::current_block_number();
-			let valid_from = valid_from.unwrap_or(now);
-			let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
-			ensure!(expire_at > now, Error::<T, I>::SpendExpired);
-
-			let native_amount =
-				T::BalanceConverter::from_asset_balance(amount, *asset_kind.clone())
-					.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
-
-			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
-
-			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
-				let context = v.or_default();
-				// We group based on `max_amount`, to distinguish between different kind of
-				// origins. (assumes that all origins have different `max_amount`)
-				//
-				// Worst case is that we reject some "valid" request.
-				let spend = context.spend_in_context.entry(max_amount).or_default();
-
-				// Ensure that we don't overflow nor use more than `max_amount`
-				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
-					Err(Error::<T, I>::InsufficientPermission)
-				} else {
-					*spend = spend.saturating_add(native_amount);
-					Ok(())
-				}
-			})
-			.unwrap_or(Ok(()))?;
-
-			let index = SpendCount::<T, I>::get();
-			Spends::<T, I>::insert(
-				index,
-				SpendStatus {
-					asset_kind: *asset_kind.clone(),
-					amount,
-					beneficiary: beneficiary.clone(),
-					valid_from,
-					expire_at,
-					status: PaymentState::Pending,
-				},
-			);
-			SpendCount::<T, I>::put(index + 1);
-
-			Self::deposit_event(Event::AssetSpendApproved {
-				index,
-				asset_kind: *asset_kind,
-				amount,
-				beneficiary,
-				valid_from,
-				expire_at,
-			});
+			Self::do_spend(max_amount, *asset_kind, amount, beneficiary, valid_from)?;
 			Ok(())
 		}
 
@@ -751,15 +879,21 @@ pub mod pallet {
 		/// In case of a payout failure, the spend status must be updated with the `check_status`
 		/// dispatchable before retrying with the current function.
 		///
+		/// If the spend has to be paid out in another asset kind, its funds are swapped into the
+		/// requested asset kind first, and the payout fails if the swap fails. Once swapped, the
+		/// spend is updated to the acquired asset kind and amount, so a retried payout does not
+		/// swap the funds again.
+		///
 		/// ### Parameters
 		/// - `index`: The spend index.
 		///
 		/// ## Events
 		///
 		/// Emits [`Event::Paid`] if successful.
+		/// Emits [`Event::SpendConverted`] if the funds of the spend were swapped.
 		#[pallet::call_index(6)]
-		#[pallet::weight(T::WeightInfo::payout())]
-		pub fn payout(origin: OriginFor<T>, index: SpendIndex) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::payout().saturating_add(T::AssetSwap::weight()))]
+		pub fn payout(origin: OriginFor<T>, index: SpendIndex) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
 			let mut spend = Spends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
 			let now = T::BlockNumberProvider::current_block_number();
@@ -770,6 +904,13 @@ pub mod pallet {
 				Error::<T, I>::AlreadyAttempted
 			);
 
+			let conversion = SpendConversions::<T, I>::take(index);
+			if let Some(requested_asset_kind) = conversion.clone() {
+				// from now on the spend is paid out in the acquired funds.
+				spend.amount = Self::swap_spend_funds(index, &spend, requested_asset_kind.clone())?;
+				spend.asset_kind = requested_asset_kind;
+			}
+
 			let id = T::Paymaster::pay(&spend.beneficiary, spend.asset_kind.clone(), spend.amount)
 				.map_err(|_| Error::<T, I>::PayoutError)?;
 
@@ -779,7 +920,11 @@ pub mod pallet {
 
 			Self::deposit_event(Event::<T, I>::Paid { index, payment_id: id });
 
-			Ok(())
+			if conversion.is_some() {
+				Ok(().into())
+			} else {
+				Ok(Some(T::WeightInfo::payout()).into())
+			}
 		}
 
 		/// Check the status of the spend and remove it from the storage if processed.
@@ -814,6 +959,7 @@ pub mod pallet {
 			if now > spend.expire_at && !matches!(spend.status, State::Attempted { .. }) {
 				// spend has expired and no further status update is expected.
 				Spends::<T, I>::remove(index);
+				SpendConversions::<T, I>::remove(index);
 				Self::deposit_event(Event::<T, I>::SpendProcessed { index });
 				return Ok(Pays::No.into())
 			}
@@ -866,9 +1012,192 @@ pub mod pallet {
 			);
 
 			Spends::<T, I>::remove(index);
+			SpendConversions::<T, I>::remove(index);
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Propose and approve a recurring spend of treasury funds.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least the total
+		/// `amount` of `asset_kind` paid out over the lifetime of the spend, in the native asset.
+		/// The amount of `asset_kind` is converted for assertion using the
+		/// [`Config::BalanceConverter`].
+		///
+		/// ## Details
+		///
+		/// Create an approved spend that transfers `amount` of `asset_kind` to a designated
+		/// beneficiary once every `period` blocks, until the `end` condition is met. Payouts are
+		/// issued automatically at the start of the block in which they are due. Every payout is
+		/// recorded as a regular spend, whose status has to be followed up with the
+		/// `check_status` dispatchable and which can be retried with the `payout` dispatchable
+		/// if it fails.
+		///
+		/// ### Parameters
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `amount`: The amount to be transferred from the treasury to the `beneficiary` every
+		///   period.
+		/// - `beneficiary`: The beneficiary of the spend.
+		/// - `valid_from`: The block number from which the first payout is due. If `None`, the
+		///   first payout is made at the next block. If in the past, the payouts due since then are
+		///   caught up with, one per block.
+		/// - `period`: The number of blocks between two successive payouts.
+		/// - `end`: The condition under which the spend completes.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendApproved`] if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::spend_recurring())]
+		pub fn spend_recurring(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			#[pallet::compact] amount: AssetBalanceOf<T, I>,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+			valid_from: Option<BlockNumberFor<T, I>>,
+			period: BlockNumberFor<T, I>,
+			end: RecurringSpendEnd<BlockNumberFor<T, I>>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+			ensure!(
+				RecurringSpends::<T, I>::count() < T::MaxRecurringSpends::get(),
+				Error::<T, I>::TooManyRecurringSpends
+			);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			// Payouts due in the past are caught up with, so the schedule is kept for the `AtBlock`
+			// end condition.
+			let first_payout_at = valid_from.unwrap_or(now);
+			let spend = RecurringSpendStatus {
+				asset_kind: *asset_kind,
+				amount,
+				beneficiary,
+				period,
+				next_payout_at: first_payout_at,
+				end,
+				payouts_made: 0,
+			};
+			let payouts = spend.remaining_payouts();
+			ensure!(!period.is_zero() && payouts > 0, Error::<T, I>::InvalidRecurringSpend);
+
+			let native_amount = Self::native_amount(&spend.asset_kind, spend.amount)?
+				.saturating_mul(payouts.into());
+			Self::ensure_within_spend_limit(max_amount, native_amount)?;
+
+			let index = RecurringSpendCount::<T, I>::get();
+			RecurringSpends::<T, I>::insert(index, spend.clone());
+			RecurringSpendCount::<T, I>::put(index + 1);
+			NextRecurringPayout::<T, I>::mutate(|next| {
+				*next = Some(next.map_or(first_payout_at, |n| n.min(first_payout_at)))
+			});
+
+			Self::deposit_event(Event::RecurringSpendApproved {
+				index,
+				asset_kind: spend.asset_kind,
+				amount,
+				beneficiary: spend.beneficiary,
+				first_payout_at,
+				period,
+				end,
+			});
+			Ok(())
+		}
+
+		/// Cancel a previously approved recurring spend.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least the total
+		/// amount of the payouts still to be made, in the native asset.
+		///
+		/// ## Details
+		///
+		/// No further payouts are made for the recurring spend. Payouts that have already been
+		/// issued are not affected and can be voided with the `void_spend` dispatchable.
+		///
+		/// ### Parameters
+		/// - `index`: The recurring spend index.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::RecurringSpendCancelled`] if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::cancel_recurring_spend())]
+		pub fn cancel_recurring_spend(
+			origin: OriginFor<T>,
+			index: RecurringSpendIndex,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let spend = RecurringSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+
+			let native_amount = Self::native_amount(&spend.asset_kind, spend.amount)?
+				.saturating_mul(spend.remaining_payouts().into());
+			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+
+			RecurringSpends::<T, I>::remove(index);
+			Self::deposit_event(Event::<T, I>::RecurringSpendCancelled { index });
+			Ok(())
+		}
+
+		/// Propose and approve a spend of treasury funds that is paid out in another asset kind.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least
+		/// `amount` of `asset_kind` in the native asset. The amount of `asset_kind` is converted
+		/// for assertion using the [`Config::BalanceConverter`].
+		///
+		/// ## Details
+		///
+		/// Create an approved spend for transferring a specific `amount` of `asset_kind` to a
+		/// designated beneficiary, exactly like the `spend` dispatchable. At payout time, the funds
+		/// are swapped into `requested_asset_kind` with the [`Config::AssetSwap`] and the acquired
+		/// amount is paid out to the beneficiary. The swap must acquire at least the amount
+		/// expected from the conversion rates of the [`Config::BalanceConverter`], reduced by the
+		/// [`Config::MaxSwapSlippage`], otherwise the payout fails and can be retried later.
+		///
+		/// ### Parameters
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `amount`: The amount of `asset_kind` to be spent.
+		/// - `requested_asset_kind`: The asset class the `beneficiary` is paid out in.
+		/// - `beneficiary`: The beneficiary of the spend.
+		/// - `valid_from`: The block number from which the spend can be claimed. It can refer to
+		///   the past if the resulting spend has not yet expired according to the
+		///   [`Config::PayoutPeriod`]. If `None`, the spend can be claimed immediately after
+		///   approval.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::AssetSpendApproved`] and [`Event::SpendConversionApproved`] if
+		/// successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::spend_with_conversion())]
+		pub fn spend_with_conversion(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			#[pallet::compact] amount: AssetBalanceOf<T, I>,
+			requested_asset_kind: Box<T::AssetKind>,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+			valid_from: Option<BlockNumberFor<T, I>>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+
+			// Ensure the requested asset kind has a conversion rate.
+			Self::min_swap_amount_out(&asset_kind, &requested_asset_kind, amount)?;
+
+			let index = Self::do_spend(max_amount, *asset_kind, amount, beneficiary, valid_from)?;
+			SpendConversions::<T, I>::insert(index, requested_asset_kind.as_ref());
+
+			Self::deposit_event(Event::SpendConversionApproved {
+				index,
+				requested_asset_kind: *requested_asset_kind,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -1020,6 +1349,217 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		total_weight
 	}
 
+	/// Issue the payouts of all recurring spends that are due at block `now`.
+	///
+	/// At most one payout is issued per recurring spend and block. Payouts missed because of
+	/// skipped blocks are caught up with in the following blocks.
+	fn process_recurring_spends(now: BlockNumberFor<T, I>) -> Weight {
+		match NextRecurringPayout::<T, I>::get() {
+			Some(next) if next <= now => (),
+			_ => return T::DbWeight::get().reads(1),
+		}
+
+		let spends: Vec<_> = RecurringSpends::<T, I>::iter().collect();
+		let count = spends.len() as u32;
+		let mut next_payout: Option<BlockNumberFor<T, I>> = None;
+
+		for (index, mut spend) in spends {
+			if spend.next_payout_at <= now {
+				let spend_index = Self::issue_recurring_payout(&spend, now);
+				Self::deposit_event(Event::<T, I>::RecurringSpendPayout { index, spend_index });
+
+				spend.payouts_made.saturating_inc();
+				spend.next_payout_at = spend.next_payout_at.saturating_add(spend.period);
+				if spend.remaining_payouts().is_zero() {
+					RecurringSpends::<T, I>::remove(index);
+					Self::deposit_event(Event::<T, I>::RecurringSpendCompleted { index });
+					continue
+				}
+				RecurringSpends::<T, I>::insert(index, &spend);
+			}
+			next_payout =
+				Some(next_payout.map_or(spend.next_payout_at, |n| n.min(spend.next_payout_at)));
+		}
+
+		NextRecurringPayout::<T, I>::set(next_payout);
+		T::WeightInfo::on_initialize_recurring_spends(count)
+	}
+
+	/// Record a payout of the recurring `spend` as a regular spend and attempt to pay it.
+	///
+	/// A failed payment leaves the spend in the [`PaymentState::Failed`] state, from which it
+	/// can be retried with the `payout` dispatchable until it expires.
+	fn issue_recurring_payout(
+		spend: &RecurringSpendStatus<
+			T::AssetKind,
+			AssetBalanceOf<T, I>,
+			T::Beneficiary,
+			BlockNumberFor<T, I>,
+		>,
+		now: BlockNumberFor<T, I>,
+	) -> SpendIndex {
+		let index = SpendCount::<T, I>::get();
+		let status =
+			match T::Paymaster::pay(&spend.beneficiary, spend.asset_kind.clone(), spend.amount) {
+				Ok(payment_id) => {
+					Self::deposit_event(Event::<T, I>::Paid { index, payment_id });
+					PaymentState::Attempted { id: payment_id }
+				},
+				Err(_) => PaymentState::Failed,
+			};
+		Spends::<T, I>::insert(
+			index,
+			SpendStatus {
+				asset_kind: spend.asset_kind.clone(),
+				amount: spend.amount,
+				beneficiary: spend.beneficiary.clone(),
+				valid_from: now,
+				expire_at: now.saturating_add(T::PayoutPeriod::get()),
+				status,
+			},
+		);
+		SpendCount::<T, I>::put(index + 1);
+		index
+	}
+
+	/// Ensure that the spend origin with the given `max_amount` may spend `amount`, and note the
+	/// spend.
+	///
+	/// The spends of one origin within the same context, e.g. a batch, are summed up, so that
+	/// together they cannot exceed `max_amount` either.
+	fn ensure_within_spend_limit(
+		max_amount: BalanceOf<T, I>,
+		amount: BalanceOf<T, I>,
+	) -> Result<(), Error<T, I>> {
+		ensure!(amount <= max_amount, Error::<T, I>::InsufficientPermission);
+
+		with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
+			let context = v.or_default();
+			// We group based on `max_amount`, to distinguish between different kind of
+			// origins. (assumes that all origins have different `max_amount`)
+			//
+			// Worst case is that we reject some "valid" request.
+			let spend = context.spend_in_context.entry(max_amount).or_default();
+
+			// Ensure that we don't overflow nor use more than `max_amount`
+			if spend.checked_add(&amount).map(|s| s > max_amount).unwrap_or(true) {
+				Err(Error::<T, I>::InsufficientPermission)
+			} else {
+				*spend = spend.saturating_add(amount);
+				Ok(())
+			}
+		})
+		.unwrap_or(Ok(()))
+	}
+
+	/// Create an approved spend, asserting its value against the `max_amount` of the spend origin.
+	fn do_spend(
+		max_amount: BalanceOf<T, I>,
+		asset_kind: T::AssetKind,
+		amount: AssetBalanceOf<T, I>,
+		beneficiary: T::Beneficiary,
+		valid_from: Option<BlockNumberFor<T, I>>,
+	) -> Result<SpendIndex, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let valid_from = valid_from.unwrap_or(now);
+		let expire_at = valid_from.saturating_add(T::PayoutPeriod::get());
+		ensure!(expire_at > now, Error::<T, I>::SpendExpired);
+
+		let native_amount = Self::native_amount(&asset_kind, amount)?;
+		Self::ensure_within_spend_limit(max_amount, native_amount)?;
+
+		let index = SpendCount::<T, I>::get();
+		Spends::<T, I>::insert(
+			index,
+			SpendStatus {
+				asset_kind: asset_kind.clone(),
+				amount,
+				beneficiary: beneficiary.clone(),
+				valid_from,
+				expire_at,
+				status: PaymentState::Pending,
+			},
+		);
+		SpendCount::<T, I>::put(index + 1);
+
+		Self::deposit_event(Event::AssetSpendApproved {
+			index,
+			asset_kind,
+			amount,
+			beneficiary,
+			valid_from,
+			expire_at,
+		});
+		Ok(index)
+	}
+
+	/// Swap the funds of the spend at `index` into `requested_asset_kind`, returning the amount
+	/// acquired.
+	fn swap_spend_funds(
+		index: SpendIndex,
+		spend: &SpendStatus<
+			T::AssetKind,
+			AssetBalanceOf<T, I>,
+			T::Beneficiary,
+			BlockNumberFor<T, I>,
+			<T::Paymaster as Pay>::Id,
+		>,
+		requested_asset_kind: T::AssetKind,
+	) -> Result<AssetBalanceOf<T, I>, DispatchError> {
+		let min_amount_out =
+			Self::min_swap_amount_out(&spend.asset_kind, &requested_asset_kind, spend.amount)?;
+		let amount_out = T::AssetSwap::swap(
+			spend.asset_kind.clone(),
+			requested_asset_kind.clone(),
+			spend.amount,
+			min_amount_out,
+		)
+		.map_err(|_| Error::<T, I>::SwapFailed)?;
+
+		Self::deposit_event(Event::<T, I>::SpendConverted {
+			index,
+			requested_asset_kind,
+			amount_in: spend.amount,
+			amount_out,
+		});
+		Ok(amount_out)
+	}
+
+	/// The minimum amount of `requested_asset_kind` to be acquired when swapping `amount` of
+	/// `asset_kind`.
+	///
+	/// The expected amount is derived from the native value of both asset kinds according to the
+	/// [`Config::BalanceConverter`] and reduced by the [`Config::MaxSwapSlippage`].
+	fn min_swap_amount_out(
+		asset_kind: &T::AssetKind,
+		requested_asset_kind: &T::AssetKind,
+		amount: AssetBalanceOf<T, I>,
+	) -> Result<AssetBalanceOf<T, I>, Error<T, I>> {
+		let native_amount_in = Self::native_amount(asset_kind, amount)?;
+		// The native value of `amount` units of the requested asset kind.
+		let native_amount_out = Self::native_amount(requested_asset_kind, amount)?;
+		let expected_amount_out: AssetBalanceOf<T, I> = multiply_by_rational_with_rounding(
+			amount.unique_saturated_into(),
+			native_amount_in.unique_saturated_into(),
+			native_amount_out.unique_saturated_into(),
+			Rounding::Down,
+		)
+		.ok_or(Error::<T, I>::FailedToConvertBalance)?
+		.unique_saturated_into();
+
+		Ok(T::MaxSwapSlippage::get().left_from_one() * expected_amount_out)
+	}
+
+	/// Convert `amount` of `asset_kind` into the native asset using the
+	/// [`Config::BalanceConverter`].
+	fn native_amount(
+		asset_kind: &T::AssetKind,
+		amount: AssetBalanceOf<T, I>,
+	) -> Result<BalanceOf<T, I>, Error<T, I>> {
+		T::BalanceConverter::from_asset_balance(amount, asset_kind.clone())
+			.map_err(|_| Error::<T, I>::FailedToConvertBalance)
+	}
+
 	/// Return the amount of money in the pot.
 	// The existential deposit is not part of the pot so treasury account never gets deleted.
 	pub fn pot() -> BalanceOf<T, I> {
@@ -1033,6 +1573,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
 		Self::try_state_proposals()?;
 		Self::try_state_spends()?;
+		Self::try_state_recurring_spends()?;
+		Self::try_state_spend_conversions()?;
 
 		Ok(())
 	}
@@ -1106,6 +1648,58 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// ## Invariants of spend conversion storage items
+	///
+	/// 1. Each key of [`SpendConversions`] should also be a key of [`Spends`].
+	#[cfg(any(feature = "try-runtime", test))]
+	fn try_state_spend_conversions() -> Result<(), sp_runtime::TryRuntimeError> {
+		SpendConversions::<T, I>::iter_keys().try_for_each(|spend_index| -> DispatchResult {
+			ensure!(
+				Spends::<T, I>::contains_key(spend_index),
+				"Spend indices in `SpendConversions` must also be contained in `Spends`."
+			);
+			Ok(())
+		})?;
+
+		Ok(())
+	}
+
+	/// ## Invariants of recurring spend storage items
+	///
+	/// 1. [`RecurringSpendCount`] >= Number of elements in [`RecurringSpends`].
+	/// 2. Each entry in [`RecurringSpends`] should be saved under a key strictly less than current
+	/// [`RecurringSpendCount`].
+	/// 3. Each entry in [`RecurringSpends`] has a non-zero period and at least one payout left.
+	/// 4. [`NextRecurringPayout`] is set and not later than the next payout of any entry in
+	/// [`RecurringSpends`].
+	#[cfg(any(feature = "try-runtime", test))]
+	fn try_state_recurring_spends() -> Result<(), sp_runtime::TryRuntimeError> {
+		let current_recurring_spend_count = RecurringSpendCount::<T, I>::get();
+		ensure!(
+			current_recurring_spend_count >= RecurringSpends::<T, I>::count(),
+			"Actual number of recurring spends exceeds `RecurringSpendCount`."
+		);
+
+		let next_payout = NextRecurringPayout::<T, I>::get();
+		RecurringSpends::<T, I>::iter().try_for_each(|(index, spend)| -> DispatchResult {
+			ensure!(
+				current_recurring_spend_count > index,
+				"`RecurringSpendCount` should by strictly greater than any RecurringSpendIndex used as a key for `RecurringSpends`."
+			);
+			ensure!(
+				!spend.period.is_zero() && !spend.remaining_payouts().is_zero(),
+				"Recurring spend must have a non-zero period and payouts left."
+			);
+			ensure!(
+				next_payout.map_or(false, |next| next <= spend.next_payout_at),
+				"`NextRecurringPayout` must not be later than the next payout of any recurring spend."
+			);
+			Ok(())
+		})?;
+
+		Ok(())
+	}
 }
 
 impl<T: Config<I>, I: 'static> OnUnbalanced<NegativeImbalanceOf<T, I>> for Pallet<T, I> {
@@ -77,6 +77,7 @@ thread_local! {
 	pub static PAID: RefCell<BTreeMap<(u128, u32), u64>> = RefCell::new(BTreeMap::new());
 	pub static STATUS: RefCell<BTreeMap<u64, PaymentStatus>> = RefCell::new(BTreeMap::new());
 	pub static LAST_ID: RefCell<u64> = RefCell::new(0u64);
+	pub static SWAP_RATE: RefCell<Permill> = RefCell::new(Permill::one());
 
 	#[cfg(feature = "runtime-benchmarks")]
 	pub static TEST_SPEND_ORIGIN_TRY_SUCCESFUL_ORIGIN_ERR: RefCell<bool> = RefCell::new(false);
@@ -134,11 +135,34 @@ impl Pay for TestPay {
 	}
 }
 
+/// set the rate at which any asset is swapped into any other asset
+fn set_swap_rate(rate: Permill) {
+	SWAP_RATE.with(|r| *r.borrow_mut() = rate);
+}
+
+pub struct TestSwap;
+impl SpendAssetSwap<u32, u64> for TestSwap {
+	fn swap(
+		_asset_kind: u32,
+		_requested_asset_kind: u32,
+		amount_in: u64,
+		min_amount_out: u64,
+	) -> Result<u64, DispatchError> {
+		let amount_out = SWAP_RATE.with(|r| *r.borrow()) * amount_in;
+		ensure!(amount_out >= min_amount_out, DispatchError::Other("slippage exceeded"));
+		Ok(amount_out)
+	}
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 parameter_types! {
 	pub const Burn: Permill = Permill::from_percent(50);
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub TreasuryAccount: u128 = Treasury::account_id();
 	pub const SpendPayoutPeriod: u64 = 5;
+	pub const MaxSwapSlippage: Permill = Permill::from_percent(5);
 }
 
 pub struct TestSpendOrigin;
@@ -195,6 +219,9 @@ impl Config for Test {
 	type Paymaster = TestPay;
 	type BalanceConverter = MulBy<ConstU64<2>>;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MaxRecurringSpends = ConstU32<10>;
+	type AssetSwap = TestSwap;
+	type MaxSwapSlippage = MaxSwapSlippage;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -1046,3 +1073,476 @@ fn multiple_spend_periods_work() {
 		assert_eq!(LastSpendPeriod::<Test>::get(), Some(8));
 	});
 }
+
+#[test]
+fn spend_recurring_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// approve `2` coins of asset `1` to beneficiary `6` every `3` blocks, `3` times.
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			2,
+			Box::new(6),
+			Some(2),
+			3,
+			RecurringSpendEnd::AfterPayouts(3),
+		));
+		assert_eq!(RecurringSpendCount::<Test, _>::get(), 1);
+		assert_eq!(
+			RecurringSpends::<Test, _>::get(0).unwrap(),
+			RecurringSpendStatus {
+				asset_kind: 1,
+				amount: 2,
+				beneficiary: 6,
+				period: 3,
+				next_payout_at: 2,
+				end: RecurringSpendEnd::AfterPayouts(3),
+				payouts_made: 0,
+			}
+		);
+		assert_eq!(NextRecurringPayout::<Test, _>::get(), Some(2));
+		System::assert_last_event(
+			Event::<Test, _>::RecurringSpendApproved {
+				index: 0,
+				asset_kind: 1,
+				amount: 2,
+				beneficiary: 6,
+				first_payout_at: 2,
+				period: 3,
+				end: RecurringSpendEnd::AfterPayouts(3),
+			}
+			.into(),
+		);
+
+		// first payout is issued at block `2`.
+		go_to_block(2);
+		assert_eq!(paid(6, 1), 2);
+		assert_eq!(
+			Spends::<Test, _>::get(0).unwrap(),
+			SpendStatus {
+				asset_kind: 1,
+				amount: 2,
+				beneficiary: 6,
+				valid_from: 2,
+				expire_at: 7,
+				status: PaymentState::Attempted { id: 0 },
+			}
+		);
+		System::assert_has_event(
+			Event::<Test, _>::RecurringSpendPayout { index: 0, spend_index: 0 }.into(),
+		);
+		assert_eq!(NextRecurringPayout::<Test, _>::get(), Some(5));
+
+		// nothing is paid until the next period.
+		go_to_block(4);
+		assert_eq!(paid(6, 1), 2);
+		go_to_block(5);
+		assert_eq!(paid(6, 1), 4);
+
+		// the last payout completes the recurring spend.
+		go_to_block(8);
+		assert_eq!(paid(6, 1), 6);
+		assert_eq!(SpendCount::<Test, _>::get(), 3);
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+		assert_eq!(NextRecurringPayout::<Test, _>::get(), None);
+		System::assert_last_event(Event::<Test, _>::RecurringSpendCompleted { index: 0 }.into());
+
+		// payouts are processed as regular spends.
+		set_status(2, PaymentStatus::Success);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 2));
+		System::assert_last_event(Event::<Test, _>::SpendProcessed { index: 2 }.into());
+
+		go_to_block(11);
+		assert_eq!(paid(6, 1), 6);
+		assert_ok!(Treasury::do_try_state());
+	});
+}
+
+#[test]
+fn spend_recurring_until_block_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// payouts are due at blocks `1`, `3` and `5`, none at the end block `7`.
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			1,
+			Box::new(6),
+			None,
+			2,
+			RecurringSpendEnd::AtBlock(7),
+		));
+		for n in 2..=8 {
+			go_to_block(n);
+		}
+		assert_eq!(paid(6, 1), 3);
+		assert_eq!(SpendCount::<Test, _>::get(), 3);
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+	});
+}
+
+#[test]
+fn spend_recurring_catches_up_skipped_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			1,
+			Box::new(6),
+			Some(2),
+			2,
+			RecurringSpendEnd::AfterPayouts(3),
+		));
+
+		// jump past all three payout blocks, a single payout is issued per block.
+		go_to_block(10);
+		assert_eq!(paid(6, 1), 1);
+		go_to_block(11);
+		assert_eq!(paid(6, 1), 2);
+		go_to_block(12);
+		assert_eq!(paid(6, 1), 3);
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+	});
+}
+
+#[test]
+fn spend_recurring_until_block_catches_up_past_payouts() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(5);
+		// payouts are due at blocks `1`, `3`, `5` and `7`, the first three of them in the past.
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			1,
+			Box::new(6),
+			Some(1),
+			2,
+			RecurringSpendEnd::AtBlock(9),
+		));
+		System::assert_last_event(
+			Event::RecurringSpendApproved {
+				index: 0,
+				asset_kind: 1,
+				amount: 1,
+				beneficiary: 6,
+				first_payout_at: 1,
+				period: 2,
+				end: RecurringSpendEnd::AtBlock(9),
+			}
+			.into(),
+		);
+
+		// the payouts due in the past are caught up with, one per block, also after the end.
+		for n in 6..=12 {
+			go_to_block(n);
+		}
+		assert_eq!(paid(6, 1), 4);
+		assert_eq!(SpendCount::<Test, _>::get(), 4);
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+	});
+}
+
+#[test]
+fn spend_recurring_origin_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Treasury::spend_recurring(
+				RuntimeOrigin::signed(1),
+				Box::new(1),
+				1,
+				Box::new(6),
+				None,
+				1,
+				RecurringSpendEnd::AfterPayouts(1),
+			),
+			BadOrigin
+		);
+		// the total amount of all payouts is checked against the origin's limit: 2 * 3 * 2 > 10.
+		assert_noop!(
+			Treasury::spend_recurring(
+				RuntimeOrigin::signed(11),
+				Box::new(1),
+				2,
+				Box::new(6),
+				None,
+				1,
+				RecurringSpendEnd::AfterPayouts(3),
+			),
+			Error::<Test, _>::InsufficientPermission
+		);
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			2,
+			Box::new(6),
+			None,
+			1,
+			RecurringSpendEnd::AfterPayouts(2),
+		));
+	});
+}
+
+#[test]
+fn spend_recurring_rejects_invalid_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(5);
+		assert_noop!(
+			Treasury::spend_recurring(
+				RuntimeOrigin::root(),
+				Box::new(1),
+				1,
+				Box::new(6),
+				None,
+				0,
+				RecurringSpendEnd::AfterPayouts(1),
+			),
+			Error::<Test, _>::InvalidRecurringSpend
+		);
+		assert_noop!(
+			Treasury::spend_recurring(
+				RuntimeOrigin::root(),
+				Box::new(1),
+				1,
+				Box::new(6),
+				None,
+				1,
+				RecurringSpendEnd::AfterPayouts(0),
+			),
+			Error::<Test, _>::InvalidRecurringSpend
+		);
+		assert_noop!(
+			Treasury::spend_recurring(
+				RuntimeOrigin::root(),
+				Box::new(1),
+				1,
+				Box::new(6),
+				None,
+				1,
+				RecurringSpendEnd::AtBlock(5),
+			),
+			Error::<Test, _>::InvalidRecurringSpend
+		);
+	});
+}
+
+#[test]
+fn spend_recurring_respects_max_recurring_spends() {
+	ExtBuilder::default().build().execute_with(|| {
+		let max = <Test as Config>::MaxRecurringSpends::get();
+		for _ in 0..max {
+			assert_ok!(Treasury::spend_recurring(
+				RuntimeOrigin::root(),
+				Box::new(1),
+				1,
+				Box::new(6),
+				None,
+				1,
+				RecurringSpendEnd::AfterPayouts(1),
+			));
+		}
+		assert_noop!(
+			Treasury::spend_recurring(
+				RuntimeOrigin::root(),
+				Box::new(1),
+				1,
+				Box::new(6),
+				None,
+				1,
+				RecurringSpendEnd::AfterPayouts(1),
+			),
+			Error::<Test, _>::TooManyRecurringSpends
+		);
+	});
+}
+
+#[test]
+fn cancel_recurring_spend_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(12),
+			Box::new(1),
+			2,
+			Box::new(6),
+			Some(2),
+			2,
+			RecurringSpendEnd::AfterPayouts(4),
+		));
+		go_to_block(2);
+		assert_eq!(paid(6, 1), 2);
+
+		// the remaining payouts are worth `2 * 3 * 2` and exceed the limit of the origin.
+		assert_noop!(
+			Treasury::cancel_recurring_spend(RuntimeOrigin::signed(11), 0),
+			Error::<Test, _>::InsufficientPermission
+		);
+		assert_noop!(
+			Treasury::cancel_recurring_spend(RuntimeOrigin::signed(12), 1),
+			Error::<Test, _>::InvalidIndex
+		);
+		assert_ok!(Treasury::cancel_recurring_spend(RuntimeOrigin::signed(12), 0));
+		System::assert_last_event(Event::<Test, _>::RecurringSpendCancelled { index: 0 }.into());
+		assert_eq!(RecurringSpends::<Test, _>::get(0), None);
+
+		// no further payouts are issued, the issued one is unaffected.
+		go_to_block(4);
+		assert_eq!(paid(6, 1), 2);
+		assert_eq!(SpendCount::<Test, _>::get(), 1);
+		assert_eq!(NextRecurringPayout::<Test, _>::get(), None);
+	});
+}
+
+#[test]
+fn try_state_recurring_spends_invariant_works() {
+	use frame_support::pallet_prelude::DispatchError::Other;
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Treasury::spend_recurring(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			1,
+			Box::new(6),
+			Some(5),
+			1,
+			RecurringSpendEnd::AfterPayouts(1),
+		));
+		assert_ok!(Treasury::do_try_state());
+		NextRecurringPayout::<Test, _>::put(6);
+		assert_eq!(
+			Treasury::do_try_state(),
+			Err(Other(
+				"`NextRecurringPayout` must not be later than the next payout of any recurring spend."
+			))
+		);
+	});
+}
+
+#[test]
+fn spend_with_conversion_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// approve a `100` coins spend of asset `1`, paid out in asset `2` to beneficiary `6`.
+		assert_ok!(Treasury::spend_with_conversion(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			100,
+			Box::new(2),
+			Box::new(6),
+			None
+		));
+		assert_eq!(SpendConversions::<Test, _>::get(0), Some(2));
+		System::assert_last_event(
+			Event::<Test, _>::SpendConversionApproved { index: 0, requested_asset_kind: 2 }.into(),
+		);
+
+		// the funds are swapped and paid out in the requested asset.
+		set_swap_rate(Permill::from_percent(98));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 0);
+		assert_eq!(paid(6, 2), 98);
+		System::assert_has_event(
+			Event::<Test, _>::SpendConverted {
+				index: 0,
+				requested_asset_kind: 2,
+				amount_in: 100,
+				amount_out: 98,
+			}
+			.into(),
+		);
+		assert_eq!(SpendConversions::<Test, _>::get(0), None);
+		let spend = Spends::<Test, _>::get(0).unwrap();
+		assert_eq!((spend.asset_kind, spend.amount), (2, 98));
+
+		// a failed payout is retried in the acquired funds, without swapping again.
+		let payment_id = get_payment_id(0).expect("no payment attempt");
+		set_status(payment_id, PaymentStatus::Failure);
+		unpay(6, 2, 98);
+		assert_ok!(Treasury::check_status(RuntimeOrigin::signed(1), 0));
+		set_swap_rate(Permill::zero());
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 0);
+		assert_eq!(paid(6, 2), 98);
+		assert_ok!(Treasury::do_try_state());
+	});
+}
+
+#[test]
+fn spend_with_conversion_fails_on_slippage() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_with_conversion(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			100,
+			Box::new(2),
+			Box::new(6),
+			None
+		));
+
+		// the swap would acquire less than the `5%` slippage allows.
+		set_swap_rate(Permill::from_percent(90));
+		assert_noop!(Treasury::payout(RuntimeOrigin::signed(1), 0), Error::<Test, _>::SwapFailed);
+
+		// the payout can be retried once there is enough liquidity.
+		set_swap_rate(Permill::from_percent(95));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 2), 95);
+	});
+}
+
+#[test]
+fn spend_with_conversion_origin_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		// the native value of the approved amount is checked against the origin's limit.
+		assert_noop!(
+			Treasury::spend_with_conversion(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				3,
+				Box::new(2),
+				Box::new(6),
+				None
+			),
+			Error::<Test, _>::InsufficientPermission
+		);
+		assert_ok!(Treasury::spend_with_conversion(
+			RuntimeOrigin::signed(10),
+			Box::new(1),
+			2,
+			Box::new(2),
+			Box::new(6),
+			None
+		));
+	});
+}
+
+#[test]
+fn void_spend_removes_conversion() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Treasury::spend_with_conversion(
+			RuntimeOrigin::signed(14),
+			Box::new(1),
+			100,
+			Box::new(2),
+			Box::new(6),
+			None
+		));
+		assert_ok!(Treasury::void_spend(RuntimeOrigin::root(), 0));
+		assert_eq!(SpendConversions::<Test, _>::get(0), None);
+		assert_ok!(Treasury::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_spend_conversions_invariant_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		use frame_support::pallet_prelude::DispatchError::Other;
+
+		SpendConversions::<Test, _>::insert(0, 2);
+		assert_eq!(
+			Treasury::do_try_state(),
+			Err(Other("Spend indices in `SpendConversions` must also be contained in `Spends`."))
+		);
+	});
+}
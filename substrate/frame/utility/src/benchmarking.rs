@@ -31,6 +31,19 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+fn assert_has_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_has_event(generic_event.into());
+}
+
+/// Assert that the outcomes of `c` successful remarks were reported.
+fn assert_batch_result<T: Config>(c: u32) {
+	let call: <T as Config>::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+	let outcome = BatchItemOutcome::Executed { weight: call.get_dispatch_info().call_weight };
+	let listed = c.min(MAX_BATCH_RESULT_ITEMS);
+	let outcomes = vec![outcome; listed as usize].try_into().expect("at most the bound; qed");
+	assert_has_event::<T>(Event::BatchResult { outcomes, omitted: c - listed }.into());
+}
+
 #[benchmarks]
 mod benchmark {
 	use super::*;
@@ -43,6 +56,7 @@ mod benchmark {
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), calls);
 
+		assert_batch_result::<T>(c);
 		assert_last_event::<T>(Event::BatchCompleted.into());
 	}
 
@@ -66,6 +80,7 @@ mod benchmark {
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), calls);
 
+		assert_batch_result::<T>(c);
 		assert_last_event::<T>(Event::BatchCompleted.into());
 	}
 
@@ -89,6 +104,7 @@ mod benchmark {
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), calls);
 
+		assert_batch_result::<T>(c);
 		assert_last_event::<T>(Event::BatchCompleted.into());
 	}
 
@@ -59,22 +59,77 @@ pub mod weights;
 extern crate alloc;
 
 use alloc::{boxed::Box, vec::Vec};
-use codec::{Decode, Encode};
+use codec::{Decode, DecodeWithMemTracking, Encode};
 use frame_support::{
 	dispatch::{
 		extract_actual_weight,
 		DispatchClass::{Normal, Operational},
-		GetDispatchInfo, PostDispatchInfo,
+		DispatchResultWithPostInfo, GetDispatchInfo, PostDispatchInfo,
 	},
-	traits::{IsSubType, OriginTrait, UnfilteredDispatchable},
+	traits::{ConstU32, IsSubType, OriginTrait, UnfilteredDispatchable},
+	weights::Weight,
+	BoundedVec,
 };
+use scale_info::TypeInfo;
 use sp_core::TypeId;
 use sp_io::hashing::blake2_256;
-use sp_runtime::traits::{BadOrigin, Dispatchable, TrailingZeroInput};
+use sp_runtime::{
+	traits::{BadOrigin, Dispatchable, TrailingZeroInput},
+	DispatchError, RuntimeDebug, Saturating,
+};
 pub use weights::WeightInfo;
 
 pub use pallet::*;
 
+/// The maximum number of items listed in [`Event::BatchResult`].
+pub const MAX_BATCH_RESULT_ITEMS: u32 = 256;
+
+/// The outcome of a single item of a batch, as reported by [`Event::BatchResult`].
+#[derive(Clone, Decode, DecodeWithMemTracking, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum BatchItemOutcome {
+	/// The item was dispatched successfully and used `weight`.
+	Executed { weight: Weight },
+	/// The item was dispatched, failed with `error` and used `weight`.
+	Failed { error: DispatchError, weight: Weight },
+	/// The item was not dispatched, since an earlier item interrupted the batch.
+	Skipped,
+}
+
+/// The outcomes of the items of a batch, collected for [`Event::BatchResult`].
+#[derive(Default)]
+struct BatchOutcomes {
+	outcomes: BoundedVec<BatchItemOutcome, ConstU32<MAX_BATCH_RESULT_ITEMS>>,
+	omitted: u32,
+}
+
+impl BatchOutcomes {
+	/// Record the outcome of the next item, given the result and dispatch weight of the item.
+	fn record(&mut self, result: &DispatchResultWithPostInfo, weight: Weight) {
+		let outcome = match result {
+			Ok(_) => BatchItemOutcome::Executed { weight },
+			Err(e) => BatchItemOutcome::Failed { error: e.error, weight },
+		};
+		self.push(outcome);
+	}
+
+	/// Record the next `count` items as skipped.
+	fn skip(&mut self, count: usize) {
+		for _ in 0..count {
+			self.push(BatchItemOutcome::Skipped);
+		}
+	}
+
+	fn push(&mut self, outcome: BatchItemOutcome) {
+		if self.outcomes.try_push(outcome).is_err() {
+			self.omitted.saturating_inc();
+		}
+	}
+
+	fn into_event(self) -> Event {
+		Event::BatchResult { outcomes: self.outcomes, omitted: self.omitted }
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -129,6 +184,15 @@ pub mod pallet {
 		IfElseMainSuccess,
 		/// The fallback call was dispatched.
 		IfElseFallbackCalled { main_error: DispatchError },
+		/// The outcome of each item of a batch, in order.
+		///
+		/// Deposited right before `BatchCompleted`, `BatchCompletedWithErrors` or
+		/// `BatchInterrupted`. Only the first `MAX_BATCH_RESULT_ITEMS` items are listed and
+		/// `omitted` counts the remaining ones.
+		BatchResult {
+			outcomes: BoundedVec<BatchItemOutcome, ConstU32<MAX_BATCH_RESULT_ITEMS>>,
+			omitted: u32,
+		},
 	}
 
 	// Align the call size to 1KB. As we are currently compiling the runtime for native/wasm
@@ -190,6 +254,8 @@ pub mod pallet {
 		/// `BatchInterrupted` event is deposited, along with the number of successful calls made
 		/// and the error of the failed call. If all were successful, then the `BatchCompleted`
 		/// event is deposited.
+		///
+		/// Either way, it is preceded by a `BatchResult` event with the outcome of each call.
 		#[pallet::call_index(0)]
 		#[pallet::weight({
 			let (dispatch_weight, dispatch_class) = Pallet::<T>::weight_and_dispatch_class(&calls);
@@ -211,6 +277,7 @@ pub mod pallet {
 
 			// Track the actual weight of each of the batch calls.
 			let mut weight = Weight::zero();
+			let mut outcomes = BatchOutcomes::default();
 			for (index, call) in calls.into_iter().enumerate() {
 				let info = call.get_dispatch_info();
 				// If origin is root, don't apply any dispatch filters; root can call anything.
@@ -220,19 +287,25 @@ pub mod pallet {
 					call.dispatch(origin.clone())
 				};
 				// Add the weight of this call.
-				weight = weight.saturating_add(extract_actual_weight(&result, &info));
+				let call_weight = extract_actual_weight(&result, &info);
+				weight = weight.saturating_add(call_weight);
+				outcomes.record(&result, call_weight);
 				if let Err(e) = result {
+					outcomes.skip(calls_len.saturating_sub(index.saturating_add(1)));
+					Self::deposit_event(outcomes.into_event());
 					Self::deposit_event(Event::BatchInterrupted {
 						index: index as u32,
 						error: e.error,
 					});
-					// Take the weight of this function itself into account.
-					let base_weight = T::WeightInfo::batch(index.saturating_add(1) as u32);
+					// Take the weight of this function itself into account. The outcome of every
+					// call is reported, including the skipped ones.
+					let base_weight = T::WeightInfo::batch(calls_len as u32);
 					// Return the actual used weight + base_weight of this call.
 					return Ok(Some(base_weight.saturating_add(weight)).into())
 				}
 				Self::deposit_event(Event::ItemCompleted);
 			}
+			Self::deposit_event(outcomes.into_event());
 			Self::deposit_event(Event::BatchCompleted);
 			let base_weight = T::WeightInfo::batch(calls_len as u32);
 			Ok(Some(base_weight.saturating_add(weight)).into())
@@ -320,6 +393,7 @@ pub mod pallet {
 
 			// Track the actual weight of each of the batch calls.
 			let mut weight = Weight::zero();
+			let mut outcomes = BatchOutcomes::default();
 			for (index, call) in calls.into_iter().enumerate() {
 				let info = call.get_dispatch_info();
 				// If origin is root, bypass any dispatch filter; root can call anything.
@@ -337,7 +411,9 @@ pub mod pallet {
 					call.dispatch(filtered_origin)
 				};
 				// Add the weight of this call.
-				weight = weight.saturating_add(extract_actual_weight(&result, &info));
+				let call_weight = extract_actual_weight(&result, &info);
+				weight = weight.saturating_add(call_weight);
+				outcomes.record(&result, call_weight);
 				result.map_err(|mut err| {
 					// Take the weight of this function itself into account.
 					let base_weight = T::WeightInfo::batch_all(index.saturating_add(1) as u32);
@@ -347,6 +423,7 @@ pub mod pallet {
 				})?;
 				Self::deposit_event(Event::ItemCompleted);
 			}
+			Self::deposit_event(outcomes.into_event());
 			Self::deposit_event(Event::BatchCompleted);
 			let base_weight = T::WeightInfo::batch_all(calls_len as u32);
 			Ok(Some(base_weight.saturating_add(weight)).into())
@@ -418,6 +495,7 @@ pub mod pallet {
 			let mut weight = Weight::zero();
 			// Track failed dispatch occur.
 			let mut has_error: bool = false;
+			let mut outcomes = BatchOutcomes::default();
 			for call in calls.into_iter() {
 				let info = call.get_dispatch_info();
 				// If origin is root, don't apply any dispatch filters; root can call anything.
@@ -427,7 +505,9 @@ pub mod pallet {
 					call.dispatch(origin.clone())
 				};
 				// Add the weight of this call.
-				weight = weight.saturating_add(extract_actual_weight(&result, &info));
+				let call_weight = extract_actual_weight(&result, &info);
+				weight = weight.saturating_add(call_weight);
+				outcomes.record(&result, call_weight);
 				if let Err(e) = result {
 					has_error = true;
 					Self::deposit_event(Event::ItemFailed { error: e.error });
@@ -435,6 +515,7 @@ pub mod pallet {
 					Self::deposit_event(Event::ItemCompleted);
 				}
 			}
+			Self::deposit_event(outcomes.into_event());
 			if has_error {
 				Self::deposit_event(Event::BatchCompletedWithErrors);
 			} else {
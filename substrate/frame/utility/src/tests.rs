@@ -165,6 +165,8 @@ impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
 	type MinimumPeriod = ConstU64<3>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = ();
 }
 
@@ -545,8 +547,8 @@ fn batch_handles_weight_refund() {
 		);
 		assert_eq!(
 			extract_actual_weight(&result, &info),
-			// Real weight is 2 calls at end_weight
-			<Test as Config>::WeightInfo::batch(2) + end_weight * 2,
+			// Real weight is 2 calls at end_weight, the outcome of all 3 calls is reported
+			<Test as Config>::WeightInfo::batch(3) + end_weight * 2,
 		);
 	});
 }
@@ -749,6 +751,86 @@ fn force_batch_works() {
 	});
 }
 
+#[test]
+fn batch_result_event_works() {
+	new_test_ext().execute_with(|| {
+		let calls = vec![
+			call_foobar(false, Weight::from_parts(10, 0), Some(Weight::from_parts(5, 0))),
+			call_foobar(true, Weight::from_parts(20, 0), Some(Weight::from_parts(7, 0))),
+			call_foobar(false, Weight::from_parts(30, 0), None),
+		];
+		let executed = BatchItemOutcome::Executed { weight: Weight::from_parts(5, 0) };
+		let failed = BatchItemOutcome::Failed {
+			error: DispatchError::Other(""),
+			weight: Weight::from_parts(7, 0),
+		};
+
+		assert_ok!(Utility::batch(RuntimeOrigin::signed(1), calls.clone()));
+		System::assert_has_event(
+			utility::Event::BatchResult {
+				outcomes: vec![executed.clone(), failed.clone(), BatchItemOutcome::Skipped]
+					.try_into()
+					.unwrap(),
+				omitted: 0,
+			}
+			.into(),
+		);
+
+		System::reset_events();
+		assert_ok!(Utility::force_batch(RuntimeOrigin::signed(1), calls.clone()));
+		assert_eq!(
+			utility_events()[3..],
+			[
+				utility::Event::BatchResult {
+					outcomes: vec![
+						executed.clone(),
+						failed,
+						BatchItemOutcome::Executed { weight: Weight::from_parts(30, 0) },
+					]
+					.try_into()
+					.unwrap(),
+					omitted: 0,
+				},
+				utility::Event::BatchCompletedWithErrors,
+			]
+		);
+
+		System::reset_events();
+		assert_ok!(Utility::batch_all(RuntimeOrigin::signed(1), vec![calls[0].clone()]));
+		assert_eq!(
+			utility_events()[1..],
+			[
+				utility::Event::BatchResult {
+					outcomes: vec![executed].try_into().unwrap(),
+					omitted: 0,
+				},
+				utility::Event::BatchCompleted,
+			]
+		);
+	});
+}
+
+#[test]
+fn batch_result_event_is_bounded() {
+	new_test_ext().execute_with(|| {
+		let calls =
+			vec![call_foobar(false, Weight::zero(), None); MAX_BATCH_RESULT_ITEMS as usize + 2];
+		assert_ok!(Utility::force_batch(RuntimeOrigin::signed(1), calls));
+		assert_eq!(
+			utility_events().iter().rev().nth(1),
+			Some(&utility::Event::BatchResult {
+				outcomes: vec![
+					BatchItemOutcome::Executed { weight: Weight::zero() };
+					MAX_BATCH_RESULT_ITEMS as usize
+				]
+				.try_into()
+				.unwrap(),
+				omitted: 2,
+			})
+		);
+	});
+}
+
 #[test]
 fn none_origin_does_not_work() {
 	new_test_ext().execute_with(|| {
@@ -20,7 +20,7 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use frame_benchmarking::{v2::*, BenchmarkError};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::EnsureOrigin};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_runtime::traits::{Bounded, CheckedDiv, CheckedMul};
 
@@ -435,6 +435,79 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn split_schedule(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
+		s: Linear<1, T::MAX_VESTING_SCHEDULES>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::SplitOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::SplitOrigin::ensure_origin(origin.clone()).unwrap();
+		T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance());
+		add_locks::<T>(&caller, l as u8);
+		add_vesting_schedules::<T>(&caller, s)?;
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		// Give target existing locks and one less than max vesting schedules.
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		add_locks::<T>(&target, l as u8);
+		let target_locked = add_vesting_schedules::<T>(&target, s - 1)?;
+
+		// Half way through the schedules, half of the locked balance of each is left.
+		T::BlockNumberProvider::set_block_number(11_u32.into());
+		let amount = T::MinVestedTransfer::get() * 5_u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, s - 1, target_lookup, amount);
+
+		assert_eq!(
+			Pallet::<T>::vesting_balance(&target),
+			Some(target_locked / 2_u32.into() + amount),
+			"Schedule not split to target",
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reassign_schedule(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
+		s: Linear<1, T::MAX_VESTING_SCHEDULES>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::SplitOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let caller = T::SplitOrigin::ensure_origin(origin.clone()).unwrap();
+		T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance());
+		add_locks::<T>(&caller, l as u8);
+		add_vesting_schedules::<T>(&caller, s)?;
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		// Give target existing locks and one less than max vesting schedules.
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		add_locks::<T>(&target, l as u8);
+		let target_locked = add_vesting_schedules::<T>(&target, s - 1)?;
+
+		T::BlockNumberProvider::set_block_number(11_u32.into());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, s - 1, target_lookup);
+
+		assert_eq!(
+			Vesting::<T>::get(&caller).map(|schedules| schedules.len()).unwrap_or_default(),
+			(s - 1) as usize,
+			"Schedule not removed from caller",
+		);
+		assert_eq!(
+			Pallet::<T>::vesting_balance(&target),
+			Some((target_locked + T::MinVestedTransfer::get() * 20_u32.into()) / 2_u32.into()),
+			"Schedule not reassigned to target",
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::ExtBuilder::default().existential_deposit(256).build(),
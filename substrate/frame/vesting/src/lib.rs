@@ -42,6 +42,9 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `split_schedule` - Move part of the sender's remaining vesting to another account, keeping the
+//!   unlock curve of the original schedule.
+//! - `reassign_schedule` - Move all of the remaining vesting of a schedule to another account.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -204,6 +207,13 @@ pub mod pallet {
 		///     parachain is lagging its block production to avoid clock skew.
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
 
+		/// Origin that may split or reassign its own vesting schedules.
+		///
+		/// The returned account is the owner of the schedules. Use `EnsureSigned` to let every
+		/// account move its vesting, or `EnsureNever` to disable `split_schedule` and
+		/// `reassign_schedule`.
+		type SplitOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
 		/// Maximum number of vesting schedules an account may have at a given moment.
 		const MAX_VESTING_SCHEDULES: u32;
 	}
@@ -293,6 +303,8 @@ pub mod pallet {
 		VestingUpdated { account: T::AccountId, unvested: BalanceOf<T> },
 		/// An \[account\] has become fully vested.
 		VestingCompleted { account: T::AccountId },
+		/// Part of a vesting schedule of `source` has been moved to `target`.
+		VestingScheduleSplit { source: T::AccountId, target: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	/// Error for the vesting pallet.
@@ -488,6 +500,63 @@ pub mod pallet {
 			))
 			.into())
 		}
+
+		/// Move `amount` of the still locked balance of one of the sender's vesting schedules to
+		/// `target`.
+		///
+		/// The dispatch origin for this call must be `T::SplitOrigin`.
+		///
+		/// Both the remaining and the new schedule keep the original unlock curve: they unlock
+		/// linearly from `MAX(schedule.starting_block, current_block)` and end at the same block
+		/// as the original schedule.
+		///
+		/// - `schedule_index`: The index of the sender's schedule to split.
+		/// - `target`: The account receiving the split off vesting funds.
+		/// - `amount`: The locked amount moved to `target`. Must be at least `MinVestedTransfer`
+		///   and at most the amount still locked by the schedule.
+		///
+		/// Emits `VestingScheduleSplit`.
+		///
+		/// NOTE: This will unlock all schedules of the sender through the current block.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::split_schedule(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn split_schedule(
+			origin: OriginFor<T>,
+			schedule_index: u32,
+			target: AccountIdLookupOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = T::SplitOrigin::ensure_origin(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::do_split_schedule(&who, schedule_index, &target, Some(amount))
+		}
+
+		/// Move all of the still locked balance of one of the sender's vesting schedules to
+		/// `target`, keeping its unlock curve.
+		///
+		/// The dispatch origin for this call must be `T::SplitOrigin`.
+		///
+		/// - `schedule_index`: The index of the sender's schedule to reassign.
+		/// - `target`: The account receiving the vesting funds.
+		///
+		/// Emits `VestingScheduleSplit`.
+		///
+		/// NOTE: This will unlock all schedules of the sender through the current block.
+		#[pallet::call_index(7)]
+		#[pallet::weight(
+			T::WeightInfo::reassign_schedule(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn reassign_schedule(
+			origin: OriginFor<T>,
+			schedule_index: u32,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = T::SplitOrigin::ensure_origin(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::do_split_schedule(&who, schedule_index, &target, None)
+		}
 	}
 }
 
@@ -549,6 +618,99 @@ impl<T: Config> Pallet<T> {
 		Some(schedule)
 	}
 
+	// Split `amount` off the balance still locked by `schedule` at block `now`.
+	// Returns the schedule left to the owner, if any, and the split off schedule. Both unlock
+	// from the later of `now` and the original starting block until the original ending block.
+	fn split_vesting_info(
+		now: BlockNumberFor<T>,
+		schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		amount: BalanceOf<T>,
+	) -> Result<
+		(
+			Option<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+			VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		),
+		DispatchError,
+	> {
+		let locked_now = schedule.locked_at::<T::BlockNumberToBalance>(now);
+		ensure!(!amount.is_zero() && amount <= locked_now, Error::<T>::InvalidScheduleParams);
+		ensure!(amount >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
+
+		let starting_block = now.max(schedule.starting_block());
+		let duration = schedule
+			.ending_block_as_balance::<T::BlockNumberToBalance>()
+			.saturating_sub(T::BlockNumberToBalance::convert(starting_block))
+			.max(One::one());
+		// Round up, so that neither part unlocks later than the original schedule.
+		let per_block = |locked: BalanceOf<T>| {
+			let per_block = locked / duration;
+			if (locked % duration).is_zero() {
+				per_block
+			} else {
+				per_block.saturating_add(One::one())
+			}
+		};
+
+		let remaining = locked_now.saturating_sub(amount);
+		let kept = (!remaining.is_zero())
+			.then(|| VestingInfo::new(remaining, per_block(remaining), starting_block));
+		let split = VestingInfo::new(amount, per_block(amount), starting_block);
+
+		Ok((kept, split))
+	}
+
+	// Move `amount`, or everything still locked when `None`, of the schedule of `who` at
+	// `schedule_index` to `target`.
+	fn do_split_schedule(
+		who: &T::AccountId,
+		schedule_index: u32,
+		target: &T::AccountId,
+		amount: Option<BalanceOf<T>>,
+	) -> DispatchResult {
+		let schedules = Vesting::<T>::get(who).ok_or(Error::<T>::NotVesting)?;
+		let schedule = *schedules
+			.get(schedule_index as usize)
+			.ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+
+		let now = T::BlockNumberProvider::current_block_number();
+		let amount = amount.unwrap_or_else(|| schedule.locked_at::<T::BlockNumberToBalance>(now));
+		let (kept, split) = Self::split_vesting_info(now, schedule, amount)?;
+
+		// Check we can add to the target prior to any storage writes.
+		Self::can_add_vesting_schedule(
+			target,
+			split.locked(),
+			split.per_block(),
+			split.starting_block(),
+		)?;
+
+		// Release the split off amount from the lock of `who`, so that it can be transferred.
+		let remove_action = VestingAction::Remove { index: schedule_index as usize };
+		let (mut schedules, mut locked_now) = Self::exec_action(schedules.to_vec(), remove_action)?;
+		if let Some(kept) = kept {
+			schedules.push(kept);
+			locked_now = locked_now.saturating_add(kept.locked_at::<T::BlockNumberToBalance>(now));
+		}
+		Self::write_vesting(who, schedules)?;
+		Self::write_lock(who, locked_now);
+
+		T::Currency::transfer(who, target, amount, ExistenceRequirement::AllowDeath)?;
+		Self::add_vesting_schedule(
+			target,
+			split.locked(),
+			split.per_block(),
+			split.starting_block(),
+		)?;
+
+		Self::deposit_event(Event::<T>::VestingScheduleSplit {
+			source: who.clone(),
+			target: target.clone(),
+			amount,
+		});
+
+		Ok(())
+	}
+
 	// Execute a vested transfer from `source` to `target` with the given `schedule`.
 	fn do_vested_transfer(
 		source: &T::AccountId,
@@ -57,6 +57,7 @@ impl Config for Test {
 	type MinVestedTransfer = MinVestedTransfer;
 	type WeightInfo = ();
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type SplitOrigin = frame_system::EnsureSigned<u64>;
 	type BlockNumberProvider = System;
 }
 
@@ -1262,3 +1262,93 @@ fn vested_transfer_impl_works() {
 		);
 	});
 }
+
+#[test]
+fn split_schedule_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// Account 2 vests 20 * ED over blocks 10 to 30.
+		let user2_vesting_schedule = VestingInfo::new(ED * 20, ED, 10);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![user2_vesting_schedule]);
+		assert_eq!(VestingStorage::<Test>::get(&4), None);
+
+		assert_ok!(Vesting::split_schedule(Some(2).into(), 0, 4, ED * 10));
+		System::assert_last_event(
+			Event::VestingScheduleSplit { source: 2, target: 4, amount: ED * 10 }.into(),
+		);
+
+		// Both halves keep unlocking over blocks 10 to 30.
+		let half_schedule = VestingInfo::new(ED * 10, ED / 2, 10);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![half_schedule]);
+		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![half_schedule]);
+		assert_eq!(Balances::free_balance(&2), ED * 10);
+		assert_eq!(Balances::free_balance(&4), ED * 50);
+		assert_eq!(Vesting::vesting_balance(&2), Some(ED * 10));
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 10));
+
+		// Half way through, together they have vested as much as the original schedule.
+		System::set_block_number(20);
+		assert_eq!(user2_vesting_schedule.locked_at::<Identity>(20), ED * 10);
+		assert_eq!(Vesting::vesting_balance(&2), Some(ED * 5));
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 5));
+
+		System::set_block_number(30);
+		vest_and_assert_no_vesting::<Test>(2);
+		vest_and_assert_no_vesting::<Test>(4);
+	});
+}
+
+#[test]
+fn reassign_schedule_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		System::set_block_number(20);
+		assert_eq!(Vesting::vesting_balance(&2), Some(ED * 10));
+
+		assert_ok!(Vesting::reassign_schedule(Some(2).into(), 0, 4));
+		System::assert_has_event(Event::VestingCompleted { account: 2 }.into());
+		System::assert_last_event(
+			Event::VestingScheduleSplit { source: 2, target: 4, amount: ED * 10 }.into(),
+		);
+
+		// The remaining 10 * ED now vest for account 4 over blocks 20 to 30.
+		assert_eq!(VestingStorage::<Test>::get(&2), None);
+		assert_eq!(
+			VestingStorage::<Test>::get(&4).unwrap(),
+			vec![VestingInfo::new(ED * 10, ED, 20)]
+		);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 10));
+		assert_eq!(Balances::free_balance(&2), ED * 10);
+		assert_ok!(Balances::transfer_allow_death(Some(2).into(), 3, ED * 10));
+
+		System::set_block_number(25);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 5));
+	});
+}
+
+#[test]
+fn split_schedule_fails_with_invalid_params() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		assert_noop!(Vesting::split_schedule(RawOrigin::Root.into(), 0, 4, ED * 5), BadOrigin);
+		assert_noop!(
+			Vesting::split_schedule(Some(3).into(), 0, 4, ED * 5),
+			Error::<Test>::NotVesting
+		);
+		assert_noop!(
+			Vesting::split_schedule(Some(2).into(), 1, 4, ED * 5),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+		// More than is still locked.
+		assert_noop!(
+			Vesting::split_schedule(Some(2).into(), 0, 4, ED * 21),
+			Error::<Test>::InvalidScheduleParams
+		);
+		// Less than `MinVestedTransfer`.
+		assert_noop!(Vesting::split_schedule(Some(2).into(), 0, 4, ED), Error::<Test>::AmountLow);
+
+		// Once a schedule has fully vested there is nothing left to reassign.
+		System::set_block_number(30);
+		assert_noop!(
+			Vesting::reassign_schedule(Some(2).into(), 0, 4),
+			Error::<Test>::InvalidScheduleParams
+		);
+	});
+}
@@ -81,6 +81,8 @@ pub trait WeightInfo {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight;
+	fn split_schedule(l: u32, s: u32, ) -> Weight;
+	fn reassign_schedule(l: u32, s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_vesting` using the Substrate node and recommended hardware.
@@ -285,6 +287,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Vesting` (r:2 w:2)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:2)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn split_schedule(l: u32, s: u32, ) -> Weight {
+		// Placeholder until the `split_schedule` benchmark is run.
+		Weight::from_parts(71_840_000, 8538)
+			.saturating_add(Weight::from_parts(74_212, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(163_457, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 50).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 72).saturating_mul(s.into()))
+	}
+	/// Storage: `Vesting::Vesting` (r:2 w:2)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:2)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn reassign_schedule(l: u32, s: u32, ) -> Weight {
+		// Placeholder until the `reassign_schedule` benchmark is run.
+		Weight::from_parts(70_392_000, 8538)
+			.saturating_add(Weight::from_parts(73_906, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(160_118, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 50).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 72).saturating_mul(s.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -488,4 +530,44 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Vesting` (r:2 w:2)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:2)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn split_schedule(l: u32, s: u32, ) -> Weight {
+		// Placeholder until the `split_schedule` benchmark is run.
+		Weight::from_parts(71_840_000, 8538)
+			.saturating_add(Weight::from_parts(74_212, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(163_457, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 50).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 72).saturating_mul(s.into()))
+	}
+	/// Storage: `Vesting::Vesting` (r:2 w:2)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:2 w:2)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:2 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn reassign_schedule(l: u32, s: u32, ) -> Weight {
+		// Placeholder until the `reassign_schedule` benchmark is run.
+		Weight::from_parts(70_392_000, 8538)
+			.saturating_add(Weight::from_parts(73_906, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(160_118, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 50).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 72).saturating_mul(s.into()))
+	}
 }
@@ -50,6 +50,7 @@ impl pallet_preimage::Config for Test {
 	type Currency = Balances;
 	type ManagerOrigin = EnsureRoot<Self::AccountId>;
 	type Consideration = ();
+	type UploadLifetime = ConstU64<10>;
 	type WeightInfo = ();
 }
 
@@ -23,7 +23,8 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 use codec::{Codec, Decode, Encode};
-use sp_runtime::ConsensusEngineId;
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Header, ConsensusEngineId};
 
 pub mod digests;
 pub mod inherents;
@@ -64,7 +65,7 @@ pub mod ed25519 {
 	pub type AuthorityId = app_ed25519::Public;
 }
 
-pub use sp_consensus_slots::{Slot, SlotDuration};
+pub use sp_consensus_slots::{EquivocationProof, Slot, SlotDuration};
 
 /// The `ConsensusEngineId` of AuRa.
 pub const AURA_ENGINE_ID: ConsensusEngineId = [b'a', b'u', b'r', b'a'];
@@ -83,6 +84,88 @@ pub enum ConsensusLog<AuthorityId: Codec> {
 	OnDisabled(AuthorityIndex),
 }
 
+/// The authority expected to author a block in an upcoming slot.
+#[derive(Clone, Debug, PartialEq, Eq, Decode, Encode, TypeInfo)]
+pub struct SlotAuthor<AuthorityId, AccountId> {
+	/// The slot.
+	pub slot: Slot,
+	/// The index of the authority in the current authority set.
+	pub authority_index: AuthorityIndex,
+	/// The authority owning the slot.
+	pub authority: AuthorityId,
+	/// The account that registered the authority as its session key, if known.
+	pub account: Option<AccountId>,
+}
+
+/// Verifies the equivocation proof by making sure that both headers have
+/// different hashes, build on the same parent, are targeting the same slot, and have valid
+/// signatures by the same authority.
+///
+/// Two blocks in the same slot are not an equivocation by themselves, since authors may build
+/// several consecutive blocks in the same slot, e.g. with `AllowMultipleBlocksPerSlot`. Only two
+/// different blocks with the same parent, i.e. a fork, are.
+pub fn check_equivocation_proof<H, AuthorityId>(proof: EquivocationProof<H, AuthorityId>) -> bool
+where
+	H: Header,
+	AuthorityId: sp_application_crypto::RuntimeAppPublic,
+{
+	use digests::CompatibleDigestItem;
+
+	let find_pre_digest =
+		|header: &H| {
+			header.digest().logs().iter().find_map(|log| {
+				CompatibleDigestItem::<AuthorityId::Signature>::as_aura_pre_digest(log)
+			})
+		};
+
+	let verify_seal_signature = |mut header: H, offender: &AuthorityId| {
+		let seal = header.digest_mut().pop()?.as_aura_seal()?;
+		let pre_hash = header.hash();
+
+		if !offender.verify(&pre_hash.as_ref(), &seal) {
+			return None
+		}
+
+		Some(())
+	};
+
+	let verify_proof = || {
+		// we must have different headers for the equivocation to be valid
+		if proof.first_header.hash() == proof.second_header.hash() {
+			return None
+		}
+
+		// both headers must build on the same parent, otherwise they may be consecutive blocks
+		// authored in the same slot.
+		if proof.first_header.number() != proof.second_header.number() ||
+			proof.first_header.parent_hash() != proof.second_header.parent_hash()
+		{
+			return None
+		}
+
+		let first_slot = find_pre_digest(&proof.first_header)?;
+		let second_slot = find_pre_digest(&proof.second_header)?;
+
+		// both headers must be targeting the same slot and it must
+		// be the same as the one in the proof.
+		if proof.slot != first_slot || first_slot != second_slot {
+			return None
+		}
+
+		// we finally verify that the expected authority has signed both headers and
+		// that the signature is valid.
+		verify_seal_signature(proof.first_header, &proof.offender)?;
+		verify_seal_signature(proof.second_header, &proof.offender)?;
+
+		Some(())
+	};
+
+	// NOTE: we isolate the verification code into an helper function that
+	// returns `Option<()>` so that we can use `?` to deal with any intermediate
+	// errors and discard the proof as invalid.
+	verify_proof().is_some()
+}
+
 sp_api::decl_runtime_apis! {
 	/// API necessary for block authorship with aura.
 	pub trait AuraApi<AuthorityId: Codec> {
@@ -93,5 +176,23 @@ sp_api::decl_runtime_apis! {
 
 		/// Return the current set of authorities.
 		fn authorities() -> Vec<AuthorityId>;
+
+		/// Returns the slot of the current block.
+		#[api_version(2)]
+		fn current_slot() -> Slot;
+
+		/// Returns the index of `authority` in the current set of authorities, or `None` if it
+		/// isn't an authority.
+		#[api_version(2)]
+		fn authority_index_of(authority: AuthorityId) -> Option<AuthorityIndex>;
+	}
+
+	/// API to predict which authorities are going to author the upcoming slots.
+	pub trait AuraScheduleApi<AuthorityId: Codec, AccountId: Codec> {
+		/// Returns the authors of the `count` slots following the current slot.
+		///
+		/// The prediction is based on the current authority set and is only accurate as long as
+		/// the authority set does not change.
+		fn authorship_schedule(count: u32) -> Vec<SlotAuthor<AuthorityId, AccountId>>;
 	}
 }
@@ -35,7 +35,11 @@
 //!    `RuntimeGenesisConfig::default()` value into JSON format). This is used as a base for
 //!    applying patches / presets.
 
-//! 3. [`GenesisBuilder::build_state`]: Given a JSON blob, this method should deserialize it and
+//! 3. [`GenesisBuilder::get_preset_with_patch`]: Given a `PresetId` and a JSON patch, the runtime
+//!    returns the named preset with the patch merged over it, after checking that the result is a
+//!    valid `RuntimeGenesisConfig`. [`GenesisBuilder::presets_metadata`] lists the parameters each
+//!    preset expects to be overridden this way, e.g. the endowed accounts or the collator keys.
+//! 4. [`GenesisBuilder::build_state`]: Given a JSON blob, this method should deserialize it and
 //!    enact it (using `frame_support::traits::BuildGenesisConfig` for Frame-based runtime),
 //!    essentially writing it to the state.
 //!
@@ -74,6 +78,8 @@
 
 extern crate alloc;
 use alloc::{string::String, vec::Vec};
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
 
 /// The result type alias, used in build methods. `Err` contains formatted error message.
 pub type Result = core::result::Result<(), String>;
@@ -93,8 +99,22 @@ pub const DEV_RUNTIME_PRESET: &'static str = "development";
 /// (Recommended for local testing with multiple nodes)
 pub const LOCAL_TESTNET_RUNTIME_PRESET: &'static str = "local_testnet";
 
+/// Describes a named preset of [`GenesisBuilder`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct PresetMetadata {
+	/// The identifier of the preset.
+	pub id: PresetId,
+	/// The parameters of the preset, which are meant to be customized with
+	/// [`GenesisBuilder::get_preset_with_patch`].
+	///
+	/// Every parameter is the dot separated path of a field of the JSON representation of the
+	/// `RuntimeGenesisConfig`, e.g. `balances.balances`.
+	pub parameters: Vec<String>,
+}
+
 sp_api::decl_runtime_apis! {
 	/// API to interact with `RuntimeGenesisConfig` for the runtime
+	#[api_version(2)]
 	pub trait GenesisBuilder {
 		/// Build `RuntimeGenesisConfig` from a JSON blob not using any defaults and store it in the
 		/// storage.
@@ -129,5 +149,25 @@ sp_api::decl_runtime_apis! {
 		/// The presets from the list can be queried with [`GenesisBuilder::get_preset`] method. If
 		/// no named presets are provided by the runtime the list is empty.
 		fn preset_names() -> Vec<PresetId>;
+
+		/// Returns the built-in `RuntimeGenesisConfig` preset identified by `id` with the JSON blob
+		/// `patch` merged over it.
+		///
+		/// Allows to customize a preset, e.g. the endowed accounts or the collator keys, without
+		/// forking the runtime. The result is a patch just like the one returned by
+		/// [`GenesisBuilder::get_preset`]. An error is returned if the preset does not exist, the
+		/// patch is not valid JSON or the patched preset is not a valid `RuntimeGenesisConfig`.
+		#[api_version(2)]
+		fn get_preset_with_patch(
+			id: &PresetId,
+			patch: Vec<u8>,
+		) -> core::result::Result<Vec<u8>, String>;
+
+		/// Returns the metadata of the builtin `RuntimeGenesisConfig` presets.
+		///
+		/// Lists the same presets as [`GenesisBuilder::preset_names`], together with the parameters
+		/// they expect to be customized with [`GenesisBuilder::get_preset_with_patch`].
+		#[api_version(2)]
+		fn presets_metadata() -> Vec<PresetMetadata>;
 	}
 }
@@ -410,6 +410,9 @@ pub enum Error {
 	/// The provided best know block number is invalid.
 	#[cfg_attr(feature = "std", error("Provided best known block number invalid"))]
 	InvalidBestKnownBlock,
+	/// The provided block range is invalid.
+	#[cfg_attr(feature = "std", error("Provided block range invalid"))]
+	InvalidBlockRange,
 }
 
 impl Error {
@@ -454,6 +457,26 @@ sp_api::decl_runtime_apis! {
 			best_known_block_number: Option<BlockNumber>
 		) -> Result<(Vec<EncodableOpaqueLeaf>, LeafProof<Hash>), Error>;
 
+		/// Generate a single MMR proof for all the blocks in `start_block..=end_block`, using the
+		/// current MMR state.
+		///
+		/// Returns the leaves of the range, in order, together with the batched proof. The range
+		/// must be non-empty, start at or after the first MMR block and end at or before the current
+		/// block, otherwise [`Error::InvalidBlockRange`] is returned.
+		///
+		/// Like [`Self::generate_proof`], this reads the MMR nodes from the offchain database, so the
+		/// node must run with offchain indexing enabled. Nodes of canonicalized blocks are looked up
+		/// by position, and nodes of blocks which aren't canonicalized yet fall back to the
+		/// fork-aware keys. If offchain indexing is disabled, or the range reaches into blocks the
+		/// MMR gadget didn't process yet, [`Error::LeafNotFound`] is returned. Callers may then
+		/// retry with a range ending at the last finalized block, or use [`Self::generate_proof`]
+		/// against a node with offchain indexing enabled.
+		#[api_version(3)]
+		fn generate_batch_proof_for_range(
+			start_block: BlockNumber,
+			end_block: BlockNumber
+		) -> Result<(Vec<EncodableOpaqueLeaf>, LeafProof<Hash>), Error>;
+
 		/// Verify MMR proof against on-chain MMR for a batch of leaves.
 		///
 		/// Note this function will use on-chain MMR root hash and check if the proof matches the hash.
@@ -43,6 +43,36 @@ const V15: u32 = 15;
 /// Unstable metadata V16.
 const V16: u32 = 16;
 
+/// Key of the pallet indices reserved by the runtime in the custom metadata.
+///
+/// The value is a SCALE encoded `Vec<(String, u8)>` of the pallet names and their reserved
+/// indices. The key is only present if the runtime reserves any index.
+pub const RESERVED_PALLET_INDICES_KEY: &str = "reserved_pallet_indices";
+
+/// Build the custom metadata, available since metadata V15, from the IR.
+fn custom_metadata(
+	reserved_pallet_indices: &[ReservedPalletIndexIR],
+) -> frame_metadata::v15::CustomMetadata {
+	use codec::Encode;
+	use frame_metadata::v15::{CustomMetadata, CustomValueMetadata};
+
+	let mut map = alloc::collections::BTreeMap::new();
+	if !reserved_pallet_indices.is_empty() {
+		let value = reserved_pallet_indices
+			.iter()
+			.map(|reserved| (reserved.name, reserved.index))
+			.collect::<alloc::vec::Vec<_>>();
+		map.insert(
+			RESERVED_PALLET_INDICES_KEY,
+			CustomValueMetadata {
+				ty: scale_info::meta_type::<alloc::vec::Vec<(alloc::string::String, u8)>>(),
+				value: value.encode(),
+			},
+		);
+	}
+	CustomMetadata { map }
+}
+
 /// Transform the IR to the specified version.
 ///
 /// Use [`supported_versions`] to find supported versions.
@@ -128,6 +158,7 @@ mod test {
 				event_enum_ty: meta_type::<()>(),
 				error_enum_ty: meta_type::<()>(),
 			},
+			reserved_pallet_indices: vec![],
 		}
 	}
 
@@ -160,4 +191,24 @@ mod test {
 
 		assert!(matches!(metadata.1, RuntimeMetadata::V16(_)));
 	}
+
+	#[test]
+	fn reserved_pallet_indices_are_custom_metadata() {
+		use codec::Decode;
+
+		let mut ir = ir_metadata();
+		ir.reserved_pallet_indices = vec![ReservedPalletIndexIR { name: "Removed", index: 2 }];
+		let metadata = into_version(ir, V15).expect("Should return prefixed metadata");
+
+		let RuntimeMetadata::V15(metadata) = metadata.1 else { panic!("Expected V15 metadata") };
+		let value = &metadata.custom.map[RESERVED_PALLET_INDICES_KEY].value;
+		assert_eq!(
+			<Vec<(String, u8)>>::decode(&mut &value[..]).unwrap(),
+			vec![("Removed".to_string(), 2)]
+		);
+
+		let metadata = into_version(ir_metadata(), V15).expect("Should return prefixed metadata");
+		let RuntimeMetadata::V15(metadata) = metadata.1 else { panic!("Expected V15 metadata") };
+		assert!(metadata.custom.map.is_empty());
+	}
 }
@@ -41,6 +41,19 @@ pub struct MetadataIR<T: Form = MetaForm> {
 	pub apis: Vec<RuntimeApiMetadataIR<T>>,
 	/// The outer enums types as found in the runtime.
 	pub outer_enums: OuterEnumsIR<T>,
+	/// Pallet indices reserved by the runtime.
+	pub reserved_pallet_indices: Vec<ReservedPalletIndexIR>,
+}
+
+/// A pallet index reserved by the runtime, usually because the pallet using it was removed.
+///
+/// Exposed in the custom metadata under [`crate::RESERVED_PALLET_INDICES_KEY`].
+#[derive(Clone, PartialEq, Eq, Encode, Debug)]
+pub struct ReservedPalletIndexIR {
+	/// Name of the pallet the index is reserved for.
+	pub name: &'static str,
+	/// The reserved index.
+	pub index: u8,
 }
 
 /// Metadata of a runtime trait.
@@ -23,9 +23,8 @@ use super::types::{
 };
 
 use frame_metadata::v15::{
-	CustomMetadata, ExtrinsicMetadata, OuterEnums, PalletMetadata, RuntimeApiMetadata,
-	RuntimeApiMethodMetadata, RuntimeApiMethodParamMetadata, RuntimeMetadataV15,
-	SignedExtensionMetadata,
+	ExtrinsicMetadata, OuterEnums, PalletMetadata, RuntimeApiMetadata, RuntimeApiMethodMetadata,
+	RuntimeApiMethodParamMetadata, RuntimeMetadataV15, SignedExtensionMetadata,
 };
 use scale_info::{IntoPortable, Registry};
 
@@ -39,7 +38,8 @@ impl From<MetadataIR> for RuntimeMetadataV15 {
 		let apis =
 			registry.map_into_portable(ir.apis.into_iter().map(Into::<RuntimeApiMetadata>::into));
 		let outer_enums = Into::<OuterEnums>::into(ir.outer_enums).into_portable(&mut registry);
-		let custom = CustomMetadata { map: Default::default() };
+		let custom =
+			crate::custom_metadata(&ir.reserved_pallet_indices).into_portable(&mut registry);
 
 		Self { types: registry.into(), pallets, extrinsic, ty, apis, outer_enums, custom }
 	}
@@ -30,11 +30,11 @@ use super::types::{
 };
 
 use frame_metadata::v16::{
-	CustomMetadata, EnumDeprecationInfo, ExtrinsicMetadata, FunctionParamMetadata,
-	ItemDeprecationInfo, PalletAssociatedTypeMetadata, PalletCallMetadata, PalletConstantMetadata,
-	PalletErrorMetadata, PalletEventMetadata, PalletMetadata, PalletStorageMetadata,
-	PalletViewFunctionMetadata, RuntimeApiMetadata, RuntimeApiMethodMetadata, RuntimeMetadataV16,
-	StorageEntryMetadata, TransactionExtensionMetadata, VariantDeprecationInfo,
+	EnumDeprecationInfo, ExtrinsicMetadata, FunctionParamMetadata, ItemDeprecationInfo,
+	PalletAssociatedTypeMetadata, PalletCallMetadata, PalletConstantMetadata, PalletErrorMetadata,
+	PalletEventMetadata, PalletMetadata, PalletStorageMetadata, PalletViewFunctionMetadata,
+	RuntimeApiMetadata, RuntimeApiMethodMetadata, RuntimeMetadataV16, StorageEntryMetadata,
+	TransactionExtensionMetadata, VariantDeprecationInfo,
 };
 
 use codec::Compact;
@@ -47,9 +47,7 @@ impl From<MetadataIR> for RuntimeMetadataV16 {
 			ir.extrinsic.into_v16_with_call_ty(ir.outer_enums.call_enum_ty),
 			ir.apis.into_iter().map(Into::into).collect(),
 			ir.outer_enums.into(),
-			// Substrate does not collect yet the custom metadata fields.
-			// This allows us to extend the V16 easily.
-			CustomMetadata { map: Default::default() },
+			crate::custom_metadata(&ir.reserved_pallet_indices),
 		)
 	}
 }
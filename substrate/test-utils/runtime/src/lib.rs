@@ -430,7 +430,10 @@ impl pallet_balances::Config for Runtime {
 	type MaxFreezes = ();
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeCall = RuntimeCall;
 	type DoneSlashHandler = ();
+	type Scheduler = ();
+	type MaxScheduledTransferDelay = ConstU64<100>;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -447,9 +450,19 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Babe;
 	type MinimumPeriod = ConstU64<500>;
+	type ExpectedMoment = ();
+	type AnomalyTolerance = ConstU64<0>;
 	type WeightInfo = pallet_timestamp::weights::SubstrateWeight<Runtime>;
 }
 
+// The timestamp pallet is not part of the runtime, and without an expected moment it never emits
+// any of its events.
+impl From<pallet_timestamp::Event<Runtime>> for RuntimeEvent {
+	fn from(_: pallet_timestamp::Event<Runtime>) -> Self {
+		unreachable!("The timestamp pallet is not part of the runtime; qed")
+	}
+}
+
 parameter_types! {
 	pub const EpochDuration: u64 = 6;
 }
@@ -22,6 +22,7 @@ mod extrinsic;
 mod machine;
 mod overhead;
 mod pallet;
+mod pov;
 mod shared;
 mod storage;
 
@@ -33,6 +34,7 @@ pub use overhead::{
 	OpaqueBlock, OverheadCmd,
 };
 pub use pallet::PalletCmd;
+pub use pov::PovCmd;
 pub use sc_service::BasePath;
 pub use storage::StorageCmd;
 
@@ -49,6 +51,7 @@ pub enum BenchmarkCmd {
 	Block(BlockCmd),
 	Machine(MachineCmd),
 	Extrinsic(ExtrinsicCmd),
+	Pov(PovCmd),
 }
 
 /// Unwraps a [`BenchmarkCmd`] into its concrete sub-command.
@@ -65,6 +68,7 @@ macro_rules! unwrap_cmd {
 			BenchmarkCmd::Block($cmd) => $code,
 			BenchmarkCmd::Machine($cmd) => $code,
 			BenchmarkCmd::Extrinsic($cmd) => $code,
+			BenchmarkCmd::Pov($cmd) => $code,
 		}
 	}
 }
@@ -140,6 +140,15 @@ pub struct PalletCmd {
 	#[arg(long, default_value("median-slopes"))]
 	pub output_pov_analysis: Option<String>,
 
+	/// Emit the measured proof size next to the estimated proof size of the weights.
+	///
+	/// The proof size is measured by recording the storage proof of every benchmark case. It does
+	/// not rely on worst-case storage bounds, but only covers the state of the benchmark setup, so
+	/// the weights keep using the estimated worst case. The measured base proof size and slopes
+	/// are added as comments, which helps to spot storage bounds that are far too pessimistic.
+	#[arg(long)]
+	pub emit_measured_proof_size: bool,
+
 	/// The PoV estimation mode of a benchmark if no `pov_mode` attribute is present.
 	#[arg(long, default_value("max-encoded-len"), value_enum)]
 	pub default_pov_mode: command::PovEstimationMode,
@@ -80,6 +80,8 @@ struct BenchmarkData {
 	component_writes: Vec<ComponentSlope>,
 	component_calculated_proof_size: Vec<ComponentSlope>,
 	component_recorded_proof_size: Vec<ComponentSlope>,
+	// Whether the recorded proof size is emitted next to the calculated one of the weight.
+	emit_measured_proof_size: bool,
 	component_ranges: Vec<ComponentRange>,
 	comments: Vec<String>,
 	#[serde(serialize_with = "string_serialize")]
@@ -142,6 +144,7 @@ fn map_results(
 	pov_analysis_choice: &AnalysisChoice,
 	worst_case_map_values: u32,
 	additional_trie_layers: u8,
+	emit_measured_proof_size: bool,
 ) -> Result<HashMap<(String, String), Vec<BenchmarkData>>, std::io::Error> {
 	// Skip if batches is empty.
 	if batches.is_empty() {
@@ -168,6 +171,7 @@ fn map_results(
 			pov_analysis_choice,
 			worst_case_map_values,
 			additional_trie_layers,
+			emit_measured_proof_size,
 		);
 		let pallet_benchmarks = all_benchmarks.entry((pallet_name, instance_name)).or_default();
 		pallet_benchmarks.push(benchmark_data);
@@ -198,6 +202,7 @@ fn get_benchmark_data(
 	pov_analysis_choice: &AnalysisChoice,
 	worst_case_map_values: u32,
 	additional_trie_layers: u8,
+	emit_measured_proof_size: bool,
 ) -> BenchmarkData {
 	// Analyze benchmarks to get the linear regression.
 	let analysis_function = match analysis_choice {
@@ -370,6 +375,7 @@ fn get_benchmark_data(
 		component_writes: used_writes,
 		component_calculated_proof_size: used_calculated_proof_size,
 		component_recorded_proof_size: used_recorded_proof_size,
+		emit_measured_proof_size,
 		component_ranges,
 		comments,
 		min_execution_time: extrinsic_time.minimum,
@@ -452,6 +458,7 @@ pub(crate) fn write_results(
 		&pov_analysis_choice,
 		cmd.worst_case_map_values,
 		cmd.additional_trie_layers,
+		cmd.emit_measured_proof_size,
 	)?;
 	let mut created_files = Vec::new();
 
@@ -962,6 +969,7 @@ mod test {
 			&AnalysisChoice::MedianSlopes,
 			1_000_000,
 			0,
+			false,
 		)
 		.unwrap();
 		let result =
@@ -1019,6 +1027,7 @@ mod test {
 			&AnalysisChoice::MedianSlopes,
 			1_000_000,
 			0,
+			false,
 		)
 		.unwrap();
 		let result =
@@ -1076,6 +1085,7 @@ mod test {
 			&AnalysisChoice::MedianSlopes,
 			1_000_000,
 			0,
+			false,
 		)
 		.unwrap();
 		let result =
@@ -1131,6 +1141,7 @@ mod test {
 			&AnalysisChoice::MedianSlopes,
 			1_000_000,
 			0,
+			false,
 		)
 		.unwrap();
 		let result =
@@ -1188,6 +1199,7 @@ mod test {
 			&AnalysisChoice::MedianSlopes,
 			1_000_000,
 			0,
+			false,
 		)
 		.unwrap();
 		let result =
@@ -1216,6 +1228,7 @@ mod test {
 			&AnalysisChoice::MedianSlopes,
 			1_000_000,
 			0,
+			false,
 		)
 		.unwrap();
 
@@ -1263,6 +1276,7 @@ mod test {
 			&AnalysisChoice::MedianSlopes,
 			1_000_000,
 			2,
+			false,
 		)
 		.unwrap();
 		let with_layer = &mapped_results
@@ -1278,6 +1292,7 @@ mod test {
 			&AnalysisChoice::MedianSlopes,
 			1_000_000,
 			0,
+			false,
 		)
 		.unwrap();
 		let without_layer = &mapped_results
@@ -1295,6 +1310,56 @@ mod test {
 		);
 	}
 
+	#[test]
+	fn measured_proof_size_works() {
+		let render = |emit_measured_proof_size| {
+			let results = map_results(
+				&[test_data(b"first", b"first", BenchmarkParameter::a, 10, 3)],
+				&test_storage_info(),
+				&Default::default(),
+				Default::default(),
+				PovEstimationMode::MaxEncodedLen,
+				&AnalysisChoice::default(),
+				&AnalysisChoice::MedianSlopes,
+				1_000_000,
+				0,
+				emit_measured_proof_size,
+			)
+			.unwrap()
+			.get(&("first_pallet".to_string(), "instance".to_string()))
+			.unwrap()
+			.clone();
+
+			let mut handlebars = handlebars::Handlebars::new();
+			handlebars.register_helper("underscore", Box::new(UnderscoreHelper));
+			handlebars.register_helper("join", Box::new(JoinHelper));
+			handlebars.register_escape_fn(|s| -> String { s.to_string() });
+			let hbs_data = TemplateData { benchmarks: results.clone(), ..Default::default() };
+			(results[0].clone(), handlebars.render_template(&TEMPLATE, &hbs_data).unwrap())
+		};
+
+		// By default, only the calculated proof size is emitted.
+		let (estimated, output) = render(false);
+		assert!(!output.contains("Measured proof size"));
+
+		// Otherwise the recorded one is emitted next to it, but the weight stays the same.
+		let (measured, measured_output) = render(true);
+		assert_eq!(measured.base_calculated_proof_size, estimated.base_calculated_proof_size);
+		assert_eq!(
+			measured.component_calculated_proof_size,
+			estimated.component_calculated_proof_size
+		);
+		assert!(measured_output.contains("// Measured proof size: `1024`"));
+		assert!(measured_output.contains("// Measured proof size per `a`: `1024`"));
+		assert_eq!(
+			measured_output
+				.lines()
+				.filter(|l| !l.contains("Measured proof size"))
+				.collect::<Vec<_>>(),
+			output.lines().collect::<Vec<_>>()
+		);
+	}
+
 	#[test]
 	fn template_works() {
 		let all_results = map_results(
@@ -1311,6 +1376,7 @@ mod test {
 			&AnalysisChoice::MedianSlopes,
 			1_000_000,
 			0,
+			false,
 		)
 		.unwrap();
 
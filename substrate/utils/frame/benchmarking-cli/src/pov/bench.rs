@@ -0,0 +1,308 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the core benchmarking logic.
+
+use codec::{Decode, Encode};
+use sc_block_builder::BlockBuilderApi;
+use sc_cli::{Error, Result};
+use sc_client_api::BlockBackend;
+use sp_api::{ApiExt, Core, Metadata, ProvideRuntimeApi};
+use sp_blockchain::{Error::RuntimeApiError, HeaderBackend};
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, Header as HeaderT, One},
+	DigestItem, OpaqueExtrinsic,
+};
+use sp_trie::proof_size_extension::ProofSizeExt;
+
+use clap::Args;
+use log::{info, warn};
+use serde::Serialize;
+use std::{collections::BTreeMap, fs, marker::PhantomData, path::PathBuf, sync::Arc};
+use subxt::{ext::subxt_core::blocks::decode_from, SubstrateConfig};
+use thousands::Separable;
+
+/// Log target for printing proof size info.
+const LOG_TARGET: &str = "benchmark::pov";
+
+/// The latest stable metadata version, used to resolve pallet names.
+const METADATA_VERSION: u32 = 15;
+
+/// Parameters for modifying the benchmark behaviour.
+#[derive(Debug, Default, Serialize, Clone, PartialEq, Args)]
+pub struct PovParams {
+	/// Number of the block whose extrinsics are measured.
+	#[arg(long, required_unless_present = "extrinsics")]
+	pub block: Option<u32>,
+
+	/// Hex encoded extrinsics to measure, separated by commas.
+	///
+	/// They are applied in the given order on top of the state of the `--at` block.
+	#[arg(long, value_delimiter = ',', conflicts_with = "block")]
+	pub extrinsics: Vec<String>,
+
+	/// Number of the block whose state `--extrinsics` are applied to.
+	///
+	/// Defaults to the best block.
+	#[arg(long, conflicts_with = "block")]
+	pub at: Option<u32>,
+
+	/// Write the results in JSON format into the given file.
+	#[arg(long)]
+	pub json_file: Option<PathBuf>,
+}
+
+/// The storage proof size of a single extrinsic.
+#[derive(Debug, Serialize)]
+struct ExtrinsicProofSize {
+	/// The index of the extrinsic in the measured list.
+	index: usize,
+	/// The name of the pallet the extrinsic calls into.
+	pallet: String,
+	/// Whether the extrinsic was applied and dispatched successfully.
+	success: bool,
+	/// The number of proof bytes added by the extrinsic.
+	proof_size: u64,
+}
+
+/// The results of a *pov* benchmark.
+#[derive(Debug, Serialize)]
+struct PovResults {
+	/// The number of proof bytes recorded while initializing the block.
+	initialization: u64,
+	/// The proof size of each extrinsic.
+	extrinsics: Vec<ExtrinsicProofSize>,
+	/// The total proof size per pallet.
+	pallets: BTreeMap<String, u64>,
+	/// The total proof size, including the block initialization.
+	total: u64,
+}
+
+/// Convenience closure for the [`Benchmark::run()`] function.
+pub struct Benchmark<Block, C> {
+	client: Arc<C>,
+	params: PovParams,
+	_p: PhantomData<Block>,
+}
+
+impl<Block, C> Benchmark<Block, C>
+where
+	Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+	C: ProvideRuntimeApi<Block> + BlockBackend<Block> + HeaderBackend<Block>,
+	C::Api: ApiExt<Block> + BlockBuilderApi<Block> + Metadata<Block>,
+{
+	/// Returns a new [`Self`] from the arguments.
+	pub fn new(client: Arc<C>, params: PovParams) -> Self {
+		Self { client, params, _p: PhantomData }
+	}
+
+	/// Measure the proof size of the selected extrinsics and log the results.
+	pub fn run(&self) -> Result<()> {
+		let (header, extrinsics) = match self.params.block {
+			Some(0) => return Err("Cannot benchmark the genesis block".into()),
+			Some(number) => self.historic_block(number)?,
+			None => self.given_extrinsics()?,
+		};
+
+		let results = self.measure(header, extrinsics)?;
+		self.log_results(&results);
+
+		if let Some(path) = &self.params.json_file {
+			let json = serde_json::to_string_pretty(&results)
+				.map_err(|e| format!("Serializing into JSON: {:?}", e))?;
+			fs::write(path, json)?;
+		}
+
+		Ok(())
+	}
+
+	/// Returns the unsealed header and the extrinsics of a historic block.
+	fn historic_block(&self, number: u32) -> Result<(Block::Header, Vec<Block::Extrinsic>)> {
+		let block_num = BlockId::Number(number.into());
+		let hash = self.client.expect_block_hash_from_id(&block_num)?;
+		let block = self.client.block(hash)?.ok_or(format!("Block {} not found", block_num))?;
+		let (mut header, extrinsics) = block.block.deconstruct();
+		header.digest_mut().logs.retain(|item| !matches!(item, DigestItem::Seal(_, _)));
+
+		Ok((header, extrinsics))
+	}
+
+	/// Returns a header on top of the `--at` block and the decoded `--extrinsics`.
+	fn given_extrinsics(&self) -> Result<(Block::Header, Vec<Block::Extrinsic>)> {
+		let parent_hash = match self.params.at {
+			Some(number) =>
+				self.client.expect_block_hash_from_id(&BlockId::Number(number.into()))?,
+			None => self.client.info().best_hash,
+		};
+		let parent_number = *self.client.expect_header(parent_hash)?.number();
+
+		let extrinsics = self
+			.params
+			.extrinsics
+			.iter()
+			.map(|ext| {
+				let bytes = array_bytes::hex2bytes(ext)
+					.map_err(|e| format!("Extrinsic `{}` is not valid hex: {:?}", ext, e))?;
+				OpaqueExtrinsic::decode(&mut &bytes[..])
+					.map_err(|e| format!("Extrinsic `{}` could not be decoded: {}", ext, e).into())
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		let header = Block::Header::new(
+			parent_number + One::one(),
+			Default::default(),
+			Default::default(),
+			parent_hash,
+			Default::default(),
+		);
+
+		Ok((header, extrinsics))
+	}
+
+	/// Applies the `extrinsics` on top of the parent of `header` and records the proof size each
+	/// of them adds.
+	fn measure(
+		&self,
+		header: Block::Header,
+		extrinsics: Vec<Block::Extrinsic>,
+	) -> Result<PovResults> {
+		let parent_hash = *header.parent_hash();
+		let metadata = self.metadata(parent_hash);
+
+		let mut runtime_api = self.client.runtime_api();
+		runtime_api.record_proof();
+		let recorder = runtime_api
+			.proof_recorder()
+			.expect("Proof recording is enabled in the line above; qed.");
+		runtime_api.register_extension(ProofSizeExt::new(recorder.clone()));
+
+		runtime_api
+			.initialize_block(parent_hash, &header)
+			.map_err(|e| Error::Client(RuntimeApiError(e)))?;
+		let initialization = recorder.estimate_encoded_size() as u64;
+
+		let mut recorded = initialization;
+		let mut results = Vec::with_capacity(extrinsics.len());
+		for (index, extrinsic) in extrinsics.into_iter().enumerate() {
+			let pallet = metadata
+				.as_ref()
+				.and_then(|metadata| pallet_name(metadata, &extrinsic))
+				.unwrap_or_else(|| "unknown".into());
+
+			let success = match runtime_api
+				.apply_extrinsic(parent_hash, extrinsic)
+				.map_err(|e| Error::Client(RuntimeApiError(e)))?
+			{
+				Ok(Ok(())) => true,
+				Ok(Err(e)) => {
+					warn!(target: LOG_TARGET, "Extrinsic {} failed to dispatch: {:?}", index, e);
+					false
+				},
+				Err(e) => {
+					warn!(target: LOG_TARGET, "Extrinsic {} is not valid: {:?}", index, e);
+					false
+				},
+			};
+
+			let now = recorder.estimate_encoded_size() as u64;
+			results.push(ExtrinsicProofSize {
+				index,
+				pallet,
+				success,
+				proof_size: now.saturating_sub(recorded),
+			});
+			recorded = now;
+		}
+
+		let mut pallets = BTreeMap::<String, u64>::new();
+		for ext in &results {
+			*pallets.entry(ext.pallet.clone()).or_default() += ext.proof_size;
+		}
+
+		Ok(PovResults { initialization, extrinsics: results, pallets, total: recorded })
+	}
+
+	/// Fetches the runtime metadata at `hash`, used to resolve the pallet of an extrinsic.
+	fn metadata(&self, hash: Block::Hash) -> Option<subxt::Metadata> {
+		let runtime_api = self.client.runtime_api();
+		let opaque = match runtime_api.metadata_at_version(hash, METADATA_VERSION) {
+			Ok(Some(metadata)) => metadata,
+			Ok(None) => runtime_api.metadata(hash).ok()?,
+			Err(e) => {
+				warn!(target: LOG_TARGET, "Failed to fetch the runtime metadata: {:?}", e);
+				return None
+			},
+		};
+
+		subxt::Metadata::decode(&mut (*opaque).as_slice())
+			.map_err(
+				|e| warn!(target: LOG_TARGET, "Failed to decode the runtime metadata: {:?}", e),
+			)
+			.ok()
+	}
+
+	/// Prints the proof size info to the console.
+	fn log_results(&self, results: &PovResults) {
+		info!(
+			target: LOG_TARGET,
+			"Block initialization used {: >10} proof bytes",
+			results.initialization.separate_with_commas(),
+		);
+
+		for ext in &results.extrinsics {
+			let msg = format!(
+				"Extrinsic {: >5} ({}) used {: >10} proof bytes",
+				ext.index,
+				ext.pallet,
+				ext.proof_size.separate_with_commas(),
+			);
+
+			if ext.success {
+				info!(target: LOG_TARGET, "{}", msg);
+			} else {
+				warn!(target: LOG_TARGET, "{} - FAILED!", msg);
+			}
+		}
+
+		for (pallet, proof_size) in &results.pallets {
+			info!(
+				target: LOG_TARGET,
+				"Pallet {} used {: >10} proof bytes",
+				pallet,
+				proof_size.separate_with_commas(),
+			);
+		}
+
+		info!(
+			target: LOG_TARGET,
+			"Total proof size: {} bytes",
+			results.total.separate_with_commas(),
+		);
+	}
+}
+
+/// Returns the name of the pallet that `extrinsic` calls into.
+fn pallet_name(metadata: &subxt::Metadata, extrinsic: &OpaqueExtrinsic) -> Option<String> {
+	decode_from::<SubstrateConfig>(vec![extrinsic.encode()], metadata.clone())
+		.ok()?
+		.iter()
+		.next()?
+		.pallet_name()
+		.ok()
+		.map(Into::into)
+}
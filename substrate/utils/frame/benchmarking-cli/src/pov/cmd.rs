@@ -0,0 +1,83 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contains the [`PovCmd`] as entry point for the CLI to execute
+//! the *pov* benchmark.
+
+use sc_block_builder::BlockBuilderApi;
+use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
+use sc_client_api::BlockBackend;
+use sp_api::{ApiExt, Metadata, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{traits::Block as BlockT, OpaqueExtrinsic};
+
+use clap::Parser;
+use std::sync::Arc;
+
+use super::bench::{Benchmark, PovParams};
+
+/// Measure the storage proof size of extrinsics.
+///
+/// Executes the extrinsics of a historic block, or extrinsics given on the command line, with
+/// proof recording enabled and reports how many bytes of storage proof each extrinsic and each
+/// pallet added. This is the real proof size cost of the extrinsics on the given state, as
+/// opposed to the worst case assumed by their benchmarked weight. Example:
+///
+/// $ polkadot-omni-node benchmark pov --chain asset-hub.json -d /tmp/ah --block 1000
+///
+/// Extrinsics given via `--extrinsics` are applied on top of the state of the `--at` block
+/// without any inherents, so they must not depend on inherent data of their block.
+#[derive(Debug, Parser)]
+pub struct PovCmd {
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub params: PovParams,
+}
+
+impl PovCmd {
+	/// Measure the storage proof size of the selected extrinsics.
+	///
+	/// Output will be printed to console.
+	pub fn run<Block, C>(&self, client: Arc<C>) -> Result<()>
+	where
+		Block: BlockT<Extrinsic = OpaqueExtrinsic>,
+		C: BlockBackend<Block> + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+		C::Api: ApiExt<Block> + BlockBuilderApi<Block> + Metadata<Block>,
+	{
+		// Put everything in the benchmark type to have the generic types handy.
+		Benchmark::new(client, self.params.clone()).run()
+	}
+}
+
+// Boilerplate
+impl CliConfiguration for PovCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
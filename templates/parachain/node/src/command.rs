@@ -197,6 +197,10 @@ pub fn run() -> Result<()> {
 					let partials = new_partial(&config)?;
 					cmd.run(partials.client)
 				}),
+				BenchmarkCmd::Pov(cmd) => runner.sync_run(|config| {
+					let partials = new_partial(&config)?;
+					cmd.run(partials.client)
+				}),
 				#[cfg(not(feature = "runtime-benchmarks"))]
 				BenchmarkCmd::Storage(_) => Err(sc_cli::Error::Input(
 					"Compile with --features=runtime-benchmarks \
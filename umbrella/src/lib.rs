@@ -340,6 +340,10 @@ pub use pallet_assets_freezer;
 #[cfg(feature = "pallet-assets-holder")]
 pub use pallet_assets_holder;
 
+/// Provides vesting schedules for `pallet-assets` assets.
+#[cfg(feature = "pallet-assets-vesting")]
+pub use pallet_assets_vesting;
+
 /// FRAME atomic swap pallet.
 #[cfg(feature = "pallet-atomic-swap")]
 pub use pallet_atomic_swap;
@@ -479,6 +483,10 @@ pub use pallet_elections_phragmen;
 #[cfg(feature = "pallet-fast-unstake")]
 pub use pallet_fast_unstake;
 
+/// Per-block accounting of the flows of the transaction fees.
+#[cfg(feature = "pallet-fee-accounting")]
+pub use pallet_fee_accounting;
+
 /// FRAME pallet for pushing a chain to its weight limits.
 #[cfg(feature = "pallet-glutton")]
 pub use pallet_glutton;
@@ -791,6 +799,10 @@ pub use pallet_xcm_bridge_hub;
 #[cfg(feature = "pallet-xcm-bridge-hub-router")]
 pub use pallet_xcm_bridge_hub_router;
 
+/// A pallet mapping XCM locations of foreign NFT collections to local collections.
+#[cfg(feature = "pallet-xcm-nft-registry")]
+pub use pallet_xcm_nft_registry;
+
 /// Logic which is common to all parachain runtimes.
 #[cfg(feature = "parachains-common")]
 pub use parachains_common;